@@ -1,19 +1,23 @@
 use crate::ClippyConfiguration;
 use crate::types::{
-    DisallowedPath, MacroMatcher, MatchLintBehaviour, PubUnderscoreFieldsBehaviour, Rename, SourceItemOrdering,
-    SourceItemOrderingCategory, SourceItemOrderingModuleItemGroupings, SourceItemOrderingModuleItemKind,
-    SourceItemOrderingTraitAssocItemKind, SourceItemOrderingTraitAssocItemKinds,
+    ConfRegex, DisallowedPath, FloatAssertionStyle, MacroMatcher, MatchLintBehaviour, PubUnderscoreFieldsBehaviour,
+    Rename, SourceItemOrdering, SourceItemOrderingCategory, SourceItemOrderingModuleItemGroupings,
+    SourceItemOrderingModuleItemKind, SourceItemOrderingTraitAssocItemKind, SourceItemOrderingTraitAssocItemKinds,
+    StringComparisonType, StructUpdateDefaultBehavior, default_string_comparison_types,
 };
 use clippy_utils::msrvs::Msrv;
 use rustc_errors::Applicability;
 use rustc_session::Session;
+use rustc_session::lint::{Level, Lint};
 use rustc_span::edit_distance::edit_distance;
+use rustc_span::source_map::{FilePathMapping, SourceMap};
 use rustc_span::{BytePos, Pos, SourceFile, Span, SyntaxContext};
-use serde::de::{IgnoredAny, IntoDeserializer, MapAccess, Visitor};
+use serde::de::{DeserializeOwned, DeserializeSeed, IgnoredAny, IntoDeserializer, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::Range;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::OnceLock;
 use std::{cmp, env, fmt, fs, io};
@@ -51,6 +55,20 @@ const DEFAULT_ALLOWED_IDENTS_BELOW_MIN_CHARS: &[&str] = &["i", "j", "x", "y", "z
 const DEFAULT_ALLOWED_PREFIXES: &[&str] = &["to", "as", "into", "from", "try_into", "try_from"];
 const DEFAULT_ALLOWED_TRAITS_WITH_RENAMED_PARAMS: &[&str] =
     &["core::convert::From", "core::convert::TryFrom", "core::str::FromStr"];
+const DEFAULT_ITER_ON_LOCKED_DATA_SLOW_CALLS: &[&str] = &[
+    "std::fs",
+    "std::io::BufRead",
+    "std::io::Read",
+    "std::io::Write",
+    "std::net",
+    "std::process",
+    "std::thread::sleep",
+];
+const DEFAULT_STREAM_TRAITS: &[&str] = &["futures_util::stream::StreamExt", "tokio_stream::StreamExt"];
+const DEFAULT_BOXED_FUTURE_ALIASES: &[&str] = &[
+    "futures_core::future::BoxFuture",
+    "futures_core::future::LocalBoxFuture",
+];
 const DEFAULT_MODULE_ITEM_ORDERING_GROUPS: &[(&str, &[SourceItemOrderingModuleItemKind])] = {
     #[allow(clippy::enum_glob_use)] // Very local glob use for legibility.
     use SourceItemOrderingModuleItemKind::*;
@@ -79,6 +97,8 @@ const DEFAULT_SOURCE_ITEM_ORDERING: &[SourceItemOrderingCategory] = {
 #[derive(Default)]
 struct TryConf {
     conf: Conf,
+    /// The `lints` table of the `[build-script]` table, only set for build scripts.
+    build_script_lints: BuildScriptLints,
     errors: Vec<ConfError>,
     warnings: Vec<ConfError>,
 }
@@ -87,6 +107,7 @@ impl TryConf {
     fn from_toml_error(file: &SourceFile, error: &toml::de::Error) -> Self {
         Self {
             conf: Conf::default(),
+            build_script_lints: BuildScriptLints::new(),
             errors: vec![ConfError::from_toml(file, error)],
             warnings: vec![],
         }
@@ -106,6 +127,35 @@ impl ConfError {
         Self::spanned(file, error.message(), None, span)
     }
 
+    /// Creates the error for a value that failed to deserialize. The value is deserialized again
+    /// from its source text to find the span of the offending part, e.g. an element of an array or
+    /// a key of an inline table. It's wrapped in a document since only those errors have spans.
+    fn from_value_error<T: DeserializeOwned>(file: &SourceFile, error: &toml::de::Error, span: Range<usize>) -> Self {
+        #[derive(Deserialize)]
+        struct Wrapper<T> {
+            #[expect(dead_code)]
+            value: T,
+        }
+
+        const PREFIX: &str = "value = ";
+        let src = format!("{PREFIX}{}", &file.src.as_ref().unwrap()[span.clone()]);
+        let (message, span) = match toml::from_str::<Wrapper<T>>(&src) {
+            Err(inner) => {
+                let inner_span = inner
+                    .span()
+                    .filter(|inner| inner.start >= PREFIX.len())
+                    .map_or(span.clone(), |inner| {
+                        span.start + inner.start - PREFIX.len()..span.start + inner.end - PREFIX.len()
+                    });
+                (inner.message().to_owned(), inner_span)
+            },
+            Ok(_) => (error.message().to_owned(), span),
+        };
+        let message = message.replace('\n', " ");
+        let suggestion = unknown_key_suggestion(&message);
+        Self::spanned(file, message.trim(), suggestion, span)
+    }
+
     fn spanned(
         file: &SourceFile,
         message: impl Into<String>,
@@ -198,59 +248,132 @@ macro_rules! define_Conf {
         #[derive(Deserialize)]
         #[serde(field_identifier, rename_all = "kebab-case")]
         #[allow(non_camel_case_types)]
-        enum Field { $($name,)* third_party, }
+        enum Field { $($name,)* build_script, third_party, }
+
+        /// The values set in the configuration file, or in its `[build-script]` table.
+        #[derive(Default)]
+        struct ConfValues {
+            $($name: Option<$ty>,)*
+            build_script: Option<Box<ConfValues>>,
+            build_script_lints: Option<BuildScriptLints>,
+            errors: Vec<ConfError>,
+            warnings: Vec<ConfError>,
+        }
+
+        impl ConfValues {
+            /// Fills in the default for every value which isn't set. The values of the
+            /// `[build-script]` table take precedence if `build_script` is set.
+            fn into_conf(self, build_script: bool) -> TryConf {
+                let mut conf = Conf { $($name: self.$name.unwrap_or_else(defaults::$name),)* };
+                let mut build_script_lints = BuildScriptLints::new();
+                let mut errors = self.errors;
+                let mut warnings = self.warnings;
+                if let Some(overrides) = self.build_script {
+                    errors.extend(overrides.errors);
+                    warnings.extend(overrides.warnings);
+                    if build_script {
+                        $(if let Some(value) = overrides.$name {
+                            conf.$name = value;
+                        })*
+                        build_script_lints = overrides.build_script_lints.unwrap_or_default();
+                    }
+                }
+                TryConf { conf, build_script_lints, errors, warnings }
+            }
+        }
+
+        struct ConfVisitor<'a> {
+            file: &'a SourceFile,
+            /// Whether this is the `[build-script]` table.
+            build_script_table: bool,
+        }
 
-        struct ConfVisitor<'a>(&'a SourceFile);
+        impl<'de> DeserializeSeed<'de> for ConfVisitor<'_> {
+            type Value = ConfValues;
+
+            fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error> where D: Deserializer<'de> {
+                deserializer.deserialize_map(self)
+            }
+        }
 
         impl<'de> Visitor<'de> for ConfVisitor<'_> {
-            type Value = TryConf;
+            type Value = ConfValues;
 
             fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("Conf")
             }
 
             fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error> where V: MapAccess<'de> {
-                let mut errors = Vec::new();
-                let mut warnings = Vec::new();
-                $(let mut $name = None;)*
+                let mut values = ConfValues::default();
                 // could get `Field` here directly, but get `String` first for diagnostics
                 while let Some(name) = map.next_key::<toml::Spanned<String>>()? {
+                    if self.build_script_table && name.get_ref() == "lints" {
+                        let raw_value = map.next_value::<toml::Spanned<toml::Value>>()?;
+                        let value_span = raw_value.span();
+                        match BuildScriptLints::deserialize(raw_value.into_inner()) {
+                            Err(e) => {
+                                let error = ConfError::from_value_error::<BuildScriptLints>(self.file, &e, value_span);
+                                values.errors.push(error);
+                            },
+                            Ok(lints) => values.build_script_lints = Some(lints),
+                        }
+                        continue;
+                    }
                     match Field::deserialize(name.get_ref().as_str().into_deserializer()) {
                         Err(e) => {
                             let e: FieldError = e;
-                            errors.push(ConfError::spanned(self.0, e.error, e.suggestion, name.span()));
+                            values.errors.push(ConfError::spanned(self.file, e.error, e.suggestion, name.span()));
                         }
                         $(Ok(Field::$name) => {
-                            $(warnings.push(ConfError::spanned(self.0, format!("deprecated field `{}`. {}", name.get_ref(), $dep), None, name.span()));)?
+                            $(values.warnings.push(ConfError::spanned(
+                                self.file,
+                                format!("deprecated field `{}`. {}", name.get_ref(), $dep),
+                                Some(Suggestion {
+                                    message: "use the new name",
+                                    suggestion: stringify!($new_conf).replace('_', "-"),
+                                }),
+                                name.span(),
+                            ));)?
                             let raw_value = map.next_value::<toml::Spanned<toml::Value>>()?;
                             let value_span = raw_value.span();
                             match <$ty>::deserialize(raw_value.into_inner()) {
-                                Err(e) => errors.push(ConfError::spanned(self.0, e.to_string().replace('\n', " ").trim(), None, value_span)),
-                                Ok(value) => match $name {
+                                Err(e) => {
+                                    values.errors.push(ConfError::from_value_error::<$ty>(self.file, &e, value_span));
+                                },
+                                Ok(value) => match values.$name {
                                     Some(_) => {
-                                        errors.push(ConfError::spanned(self.0, format!("duplicate field `{}`", name.get_ref()), None, name.span()));
+                                        values.errors.push(ConfError::spanned(self.file, format!("duplicate field `{}`", name.get_ref()), None, name.span()));
                                     }
                                     None => {
-                                        $name = Some(value);
+                                        values.$name = Some(value);
                                         // $new_conf is the same as one of the defined `$name`s, so
-                                        // this variable is defined in line 2 of this function.
-                                        $(match $new_conf {
-                                            Some(_) => errors.push(ConfError::spanned(self.0, concat!(
+                                        // this value is a field of `ConfValues`.
+                                        $(match values.$new_conf {
+                                            Some(_) => values.errors.push(ConfError::spanned(self.file, concat!(
                                                 "duplicate field `", stringify!($new_conf),
                                                 "` (provided as `", stringify!($name), "`)"
                                             ), None, name.span())),
-                                            None => $new_conf = $name.clone(),
+                                            None => values.$new_conf = values.$name.clone(),
                                         })?
                                     },
                                 }
                             }
                         })*
+                        Ok(Field::build_script) if self.build_script_table => {
+                            values.errors.push(ConfError::spanned(self.file, "`build-script` tables can't be nested", None, name.span()));
+                            drop(map.next_value::<IgnoredAny>());
+                        }
+                        Ok(Field::build_script) => {
+                            values.build_script = Some(Box::new(map.next_value_seed(ConfVisitor {
+                                file: self.file,
+                                build_script_table: true,
+                            })?));
+                        }
                         // ignore contents of the third_party key
                         Ok(Field::third_party) => drop(map.next_value::<IgnoredAny>())
                     }
                 }
-                let conf = Conf { $($name: $name.unwrap_or_else(defaults::$name),)* };
-                Ok(TryConf { conf, errors, warnings })
+                Ok(values)
             }
         }
 
@@ -282,6 +405,9 @@ define_Conf! {
     /// Whether to accept a safety comment to be placed above the statement containing the `unsafe` block
     #[lints(undocumented_unsafe_blocks)]
     accept_comment_above_statement: bool = true,
+    /// Whether to allow collecting an iterator into `Result<(), E>` instead of using `try_for_each`
+    #[lints(iterator_collect_into_unit)]
+    allow_collect_into_result_unit: bool = false,
     /// Don't lint when comparing the result of a modulo operation to zero.
     #[lints(modulo_arithmetic)]
     allow_comparison_to_zero: bool = true,
@@ -294,6 +420,9 @@ define_Conf! {
     /// Whether `indexing_slicing` should be allowed in test functions or `#[cfg(test)]`
     #[lints(indexing_slicing)]
     allow_indexing_slicing_in_tests: bool = false,
+    /// Whether `.lock().unwrap()` on a mutex stored in a struct field should be allowed in binary crates
+    #[lints(mutex_in_struct_without_poison_handling)]
+    allow_lock_unwrap_in_binaries: bool = true,
     /// Whether to allow mixed uninlined format args, e.g. `format!("{} {}", a, foo.bar)`
     #[lints(uninlined_format_args)]
     allow_mixed_uninlined_format_args: bool = true,
@@ -325,12 +454,18 @@ define_Conf! {
     #[lints(renamed_function_params)]
     allow_renamed_params_for: Vec<String> =
         DEFAULT_ALLOWED_TRAITS_WITH_RENAMED_PARAMS.iter().map(ToString::to_string).collect(),
+    /// Whether to allow shadowing a binding with a value of a different type derived from it, e.g. `let x = x.parse()?;`
+    #[lints(shadow_reuse, shadow_same)]
+    allow_type_changing_shadows: bool = false,
     /// Whether `unwrap` should be allowed in test functions or `#[cfg(test)]`
     #[lints(unwrap_used)]
     allow_unwrap_in_tests: bool = false,
     /// Whether `useless_vec` should ignore test functions or `#[cfg(test)]`
     #[lints(useless_vec)]
     allow_useless_vec_in_tests: bool = false,
+    /// Whether to allow path literals using `\` as a separator or a drive prefix, e.g. in crates only targeting Windows
+    #[lints(unportable_path_literal)]
+    allow_windows_path_literals: bool = false,
     /// Additional dotfiles (files or directories starting with a dot) to allow
     #[lints(path_ends_with_ext)]
     allowed_dotfiles: Vec<String> = Vec::default(),
@@ -430,6 +565,7 @@ define_Conf! {
         enum_variant_names,
         large_types_passed_by_value,
         linkedlist,
+        module_inception_depth,
         needless_pass_by_ref_mut,
         option_option,
         rc_buffer,
@@ -439,10 +575,12 @@ define_Conf! {
         single_call_fn,
         trivially_copy_pass_by_ref,
         unnecessary_box_returns,
+        unnecessary_rc_refcell_for_immutable_data,
         unnecessary_wraps,
         unused_self,
         upper_case_acronyms,
         vec_box,
+        vec_of_boxed_trait_objects_iteration_dyn_dispatch,
         wrong_self_convention,
     )]
     avoid_breaking_exported_api: bool = true,
@@ -454,6 +592,12 @@ define_Conf! {
     /// Use the Disallowed Names lint instead
     #[conf_deprecated("Please use `disallowed-names` instead", disallowed_names)]
     blacklisted_names: Vec<String> = Vec::new(),
+    /// Paths to type aliases of boxed futures, like `futures::future::BoxFuture`. Functions returning one of them
+    /// built from a single `async` block are linted. The value `".."` can be used as part of the list to indicate
+    /// that the configured values should be appended to the default configuration of Clippy. By default, any
+    /// configuration will replace the default value.
+    #[lints(async_fn_returning_boxed_future_alias)]
+    boxed_future_aliases: Vec<String> = DEFAULT_BOXED_FUTURE_ALIASES.iter().map(ToString::to_string).collect(),
     /// For internal testing only, ignores the current `publish` settings in the Cargo manifest.
     #[lints(cargo_common_metadata)]
     cargo_ignore_publish: bool = false,
@@ -468,6 +612,23 @@ define_Conf! {
     /// Use the Cognitive Complexity lint instead.
     #[conf_deprecated("Please use `cognitive-complexity-threshold` instead", cognitive_complexity_threshold)]
     cyclomatic_complexity_threshold: u64 = 25,
+    /// The list of lints which are promoted to `deny` when Clippy runs in CI, as detected by the environment
+    /// variable named in `deny-in-ci-env`. In CI they take precedence over the lint levels from the command line
+    /// and from the `lints` table of `Cargo.toml`. Outside of CI the lint levels are left unchanged.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// deny-in-ci = ["clippy::dbg_macro", "clippy::todo"]
+    /// ```
+    deny_in_ci: Vec<String> = Vec::new(),
+    /// The environment variable which signals that Clippy runs in CI for `deny-in-ci`. It is considered set if
+    /// it has any value other than an empty string, `0` or `false`.
+    deny_in_ci_env: String = String::from("CI"),
+    /// The list of disallowed attributes, written as paths. Attribute and derive macros are written as fully
+    /// qualified paths.
+    #[lints(disallowed_attributes)]
+    disallowed_attributes: Vec<DisallowedPath> = Vec::new(),
     /// The list of disallowed macros, written as fully qualified paths.
     #[lints(disallowed_macros)]
     disallowed_macros: Vec<DisallowedPath> = Vec::new(),
@@ -479,7 +640,9 @@ define_Conf! {
     /// default configuration of Clippy. By default, any configuration will replace the default value.
     #[lints(disallowed_names)]
     disallowed_names: Vec<String> = DEFAULT_DISALLOWED_NAMES.iter().map(ToString::to_string).collect(),
-    /// The list of disallowed types, written as fully qualified paths.
+    /// The list of disallowed types, written as fully qualified paths. Generic arguments can be given to
+    /// only disallow some instantiations of a type, with `*` matching any type, e.g.
+    /// `std::collections::HashMap<std::string::String, *>`.
     #[lints(disallowed_types)]
     disallowed_types: Vec<DisallowedPath> = Vec::new(),
     /// The list of words this lint should not consider as identifiers needing ticks. The value
@@ -489,6 +652,11 @@ define_Conf! {
     /// * `doc-valid-idents = ["ClipPy", ".."]` would append `ClipPy` to the default list.
     #[lints(doc_markdown)]
     doc_valid_idents: Vec<String> = DEFAULT_DOC_VALID_IDENTS.iter().map(ToString::to_string).collect(),
+    /// Regular expressions matching words this lint should not consider as identifiers needing ticks, in
+    /// addition to `doc-valid-idents`. A pattern has to match the whole word. For example:
+    /// * `doc-valid-idents-regex = ["[A-Z][a-z]+OS"]` would allow `ChromeOS` and `RiscOS`.
+    #[lints(doc_markdown)]
+    doc_valid_idents_regex: Vec<ConfRegex> = Vec::new(),
     /// Whether to apply the raw pointer heuristic to determine if a type is `Send`.
     #[lints(non_send_fields_in_send_ty)]
     enable_raw_pointer_heuristic_for_send: bool = true,
@@ -523,14 +691,25 @@ define_Conf! {
     /// The maximum amount of nesting a block can reside in
     #[lints(excessive_nesting)]
     excessive_nesting_threshold: u64 = 0,
+    /// How `imprecise_float_equality_in_tests` suggests comparing floats: `"Epsilon"` compares their
+    /// difference with a constant, `"Approx"` uses `approx::assert_ulps_eq!`.
+    #[lints(imprecise_float_equality_in_tests)]
+    float_assertion_style: FloatAssertionStyle = FloatAssertionStyle::Epsilon,
     /// The maximum byte size a `Future` can have, before it triggers the `clippy::large_futures` lint
-    #[lints(large_futures)]
+    #[lints(large_futures, unnecessary_box_pin)]
     future_size_threshold: u64 = 16 * 1024,
     /// A list of paths to types that should be treated as if they do not contain interior mutability
     #[lints(borrow_interior_mutable_const, declare_interior_mutable_const, ifs_same_cond, mutable_key_type)]
     ignore_interior_mutability: Vec<String> = Vec::from(["bytes::Bytes".into()]),
+    /// Paths to functions, methods, types, traits and modules whose functions may block for a long time,
+    /// e.g. because they do I/O. A `for` loop holding a lock is linted if it calls one of them. The value
+    /// `".."` can be used as part of the list to indicate that the configured values should be appended to
+    /// the default configuration of Clippy. By default, any configuration will replace the default value.
+    #[lints(iter_on_locked_data)]
+    iter_on_locked_data_slow_calls: Vec<String> =
+        DEFAULT_ITER_ON_LOCKED_DATA_SLOW_CALLS.iter().map(ToString::to_string).collect(),
     /// The maximum size of the `Err`-variant in a `Result` returned from a function
-    #[lints(result_large_err)]
+    #[lints(result_large_err, result_large_err_map_err_boxing)]
     large_error_threshold: u64 = 128,
     /// Whether to suggest reordering constructor fields when initializers are present.
     ///
@@ -552,6 +731,16 @@ define_Conf! {
     /// [from rust-clippy#11846]: https://github.com/rust-lang/rust-clippy/issues/11846#issuecomment-1820747924
     #[lints(inconsistent_struct_constructor)]
     lint_inconsistent_struct_field_initializers: bool = false,
+    /// The list of lints which should not be emitted in test functions or `#[cfg(test)]`. Lint groups, e.g.
+    /// `restriction`, allow all of their lints. This replaces the individual `allow-*-in-tests` options, which
+    /// are still respected.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// lints-allowed-in-tests = ["unwrap_used", "expect_used", "indexing_slicing"]
+    /// ```
+    lints_allowed_in_tests: Vec<String> = Vec::new(),
     /// The lower bound for linting decimal literals
     #[lints(decimal_literal_representation)]
     literal_representation_threshold: u64 = 16384,
@@ -565,6 +754,10 @@ define_Conf! {
     /// The maximum size of a file included via `include_bytes!()` or `include_str!()`, in bytes
     #[lints(large_include_file)]
     max_include_file_size: u64 = 1_000_000,
+    /// The maximum number of directly nested modules which can have the same name, e.g. `a::a`
+    /// has two
+    #[lints(module_inception_depth)]
+    max_module_inception_depth: u64 = 2,
     /// The maximum number of bool fields a struct can have
     #[lints(struct_excessive_bools)]
     max_struct_bools: u64 = 3,
@@ -594,15 +787,18 @@ define_Conf! {
         almost_complete_range,
         approx_constant,
         assigning_clones,
+        async_fn_returning_boxed_future_alias,
         borrow_as_ptr,
         cast_abs_to_unsigned,
         checked_conversions,
         cloned_instead_of_copied,
         collapsible_match,
         collapsible_str_replace,
+        comparison_to_empty,
         deprecated_cfg_attr,
         derivable_impls,
         err_expect,
+        explicit_deref_in_closure_for_copied,
         filter_map_next,
         from_over_into,
         if_then_some_else_none,
@@ -623,6 +819,7 @@ define_Conf! {
         manual_split_once,
         manual_str_repeat,
         manual_strip,
+        manual_strip_prefix_suffix_chain,
         manual_try_fold,
         map_clone,
         map_unwrap_or,
@@ -638,6 +835,7 @@ define_Conf! {
         redundant_static_lifetimes,
         seek_from_current,
         seek_rewind,
+        slice_fill_with_copy_from_slice_of_repeat,
         transmute_ptr_to_ref,
         tuple_array_conversions,
         type_repetition_in_bounds,
@@ -647,8 +845,13 @@ define_Conf! {
         unnested_or_patterns,
         unused_trait_names,
         use_self,
+        wrong_transmute_ptr_to_integer_roundtrip,
     )]
     msrv: Msrv = Msrv::empty(),
+    /// Whether `option_if_let_else` also lints branches with side effects: allocations, IO, `.await` or
+    /// early returns such as `?`.
+    #[lints(option_if_let_else)]
+    option_if_let_else_allow_effects: bool = false,
     /// The minimum size (in bytes) to consider a type for passing by reference instead of by value.
     #[lints(large_types_passed_by_value)]
     pass_by_value_size_limit: u64 = 256,
@@ -656,6 +859,11 @@ define_Conf! {
     /// exported visibility, or whether they are marked as "pub".
     #[lints(pub_underscore_fields)]
     pub_underscore_fields_behavior: PubUnderscoreFieldsBehaviour = PubUnderscoreFieldsBehaviour::PubliclyExported,
+    /// Paths to types whose overloaded operators and `Deref` implementations have no side effects. Statements using
+    /// operators of other types are only linted if the implementation is a `const fn` or comes from the standard
+    /// library.
+    #[lints(no_effect, unnecessary_operation)]
+    pure_operator_types: Vec<String> = Vec::new(),
     /// Whether to lint only if it's multiline.
     #[lints(semicolon_inside_block)]
     semicolon_inside_block_ignore_singleline: bool = false,
@@ -678,9 +886,23 @@ define_Conf! {
     /// `crate_name::macro_name` and one with just the macro name.
     #[lints(nonstandard_macro_braces)]
     standard_macro_braces: Vec<MacroMatcher> = Vec::new(),
+    /// Paths to the traits providing the `next` method of streams, e.g. `StreamExt`. A `while let` loop
+    /// awaiting `next` on a stream created in its condition is linted if the method comes from one of them.
+    #[lints(while_let_on_iterator)]
+    stream_traits: Vec<String> = DEFAULT_STREAM_TRAITS.iter().map(ToString::to_string).collect(),
+    /// Types which should be compared to string literals directly rather than being converted to a `String`
+    /// first. A type can be added like so `{ path = "crate_name::Type", method = "as_str" }`, where `method` is
+    /// a cheap method whose result can be compared to a `&str`. Without a `method` the value itself is compared.
+    #[lints(slow_string_comparisons)]
+    string_comparison_types: Vec<StringComparisonType> = default_string_comparison_types(),
     /// The minimum number of struct fields for the lints about field names to trigger
     #[lints(struct_field_names)]
     struct_field_name_threshold: u64 = 3,
+    /// Which struct expressions with `..Default::default()` are linted: `"NonCopyPrivateFields"` lints them if a
+    /// private field which isn't `Copy` is left to its default value, `"AllLocalStructs"` also lints them for every
+    /// struct defined in the crate, whose fields added later are silently left to their default value.
+    #[lints(struct_update_syntax_resets_fields)]
+    struct_update_default_behavior: StructUpdateDefaultBehavior = StructUpdateDefaultBehavior::NonCopyPrivateFields,
     /// Whether to suppress a restriction lint in constant code. In same
     /// cases the restructured operation might not be unavoidable, as the
     /// suggested counterparts are unavailable in constant code. This
@@ -691,6 +913,30 @@ define_Conf! {
     /// The maximum size of objects (in bytes) that will be linted. Larger objects are ok on the heap
     #[lints(boxed_local, useless_vec)]
     too_large_for_stack: u64 = 200,
+    /// Whether the `self` parameter of methods counts towards `too-many-arguments-threshold`
+    #[lints(too_many_arguments)]
+    too_many_arguments_count_self: bool = true,
+    /// Attributes which exempt a function from `too_many_arguments`, or a parameter from being
+    /// counted, e.g. attributes of builder generating macros. Attributes are matched by the last
+    /// segment of their path.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// too-many-arguments-ignored-attributes = [ "builder" ]
+    /// ```
+    #[lints(too_many_arguments)]
+    too_many_arguments_ignored_attributes: Vec<String> = Vec::new(),
+    /// Names of functions and methods which are not checked by `too_many_arguments`, e.g.
+    /// constructors. `*` matches any sequence of characters.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// too-many-arguments-ignored-names = [ "new", "builder", "with_*" ]
+    /// ```
+    #[lints(too_many_arguments)]
+    too_many_arguments_ignored_names: Vec<String> = Vec::new(),
     /// The maximum number of argument a function or method can have
     #[lints(too_many_arguments)]
     too_many_arguments_threshold: u64 = 7,
@@ -708,6 +954,15 @@ define_Conf! {
     /// The maximum complexity a type can have
     #[lints(type_complexity)]
     type_complexity_threshold: u64 = 250,
+    /// Lints and lint groups which can be allowed or expected without a reason or a trailing
+    /// comment, e.g. `["pedantic", "too_many_lines"]`
+    #[lints(undocumented_clippy_allow)]
+    undocumented_clippy_allow_exempt: Vec<String> = Vec::new(),
+    /// Paths of modules in which the handles of spawned threads may be dropped without joining
+    /// them, relative to the crate root, e.g. `["workers", "server::background"]`. Their submodules
+    /// are allowed as well.
+    #[lints(unjoined_thread_handle)]
+    unjoined_thread_handle_allowed_modules: Vec<String> = Vec::new(),
     /// The byte size a `T` in `Box<T>` can have, below which it triggers the `clippy::unnecessary_box` lint
     #[lints(unnecessary_box_returns)]
     unnecessary_box_size: u64 = 128,
@@ -723,6 +978,9 @@ define_Conf! {
     /// The maximum allowed size of a bit mask before suggesting to use 'trailing_zeros'
     #[lints(verbose_bit_mask)]
     verbose_bit_mask_threshold: u64 = 1,
+    /// Whether to add a note to every lint with its group and the first sentence of the "Why is this bad?" section of
+    /// its documentation.
+    verbose_diagnostics: bool = false,
     /// Whether to allow certain wildcard imports (prelude, super in tests).
     #[lints(wildcard_imports)]
     warn_on_all_wildcard_imports: bool = false,
@@ -731,7 +989,9 @@ define_Conf! {
     warn_unsafe_macro_metavars_in_private_macros: bool = false,
 }
 
-/// Search for the configuration file.
+/// Search for the configuration file. A `Cargo.toml` with a `[package.metadata.clippy]` or
+/// `[workspace.metadata.clippy]` table is used as the configuration file as well, unless there is
+/// a `clippy.toml` in the same directory.
 ///
 /// # Errors
 ///
@@ -751,6 +1011,14 @@ pub fn lookup_conf_file() -> io::Result<(Option<PathBuf>, Vec<String>)> {
     let mut warnings = vec![];
 
     loop {
+        // A `Cargo.toml` with a `[package.metadata.clippy]` or `[workspace.metadata.clippy]` table
+        // is used if there is no configuration file in the same directory
+        let found_manifest = current
+            .join(MANIFEST_NAME)
+            .canonicalize()
+            .ok()
+            .filter(|manifest| has_manifest_conf(manifest));
+
         for config_file_name in &CONFIG_FILE_NAMES {
             if let Ok(config_file) = current.join(config_file_name).canonicalize() {
                 match fs::metadata(&config_file) {
@@ -773,8 +1041,18 @@ pub fn lookup_conf_file() -> io::Result<(Option<PathBuf>, Vec<String>)> {
             }
         }
 
-        if found_config.is_some() {
-            return Ok((found_config, warnings));
+        if let Some(found_config) = found_config {
+            if let Some(found_manifest) = found_manifest {
+                warnings.push(format!(
+                    "using config file `{}`, the configuration in `{}` will be ignored",
+                    found_config.display(),
+                    found_manifest.display()
+                ));
+            }
+            return Ok((Some(found_config), warnings));
+        }
+        if found_manifest.is_some() {
+            return Ok((found_manifest, warnings));
         }
 
         // If the current directory has no parent, we're done searching.
@@ -784,15 +1062,114 @@ pub fn lookup_conf_file() -> io::Result<(Option<PathBuf>, Vec<String>)> {
     }
 }
 
-fn deserialize(file: &SourceFile) -> TryConf {
-    match toml::de::Deserializer::new(file.src.as_ref().unwrap()).deserialize_map(ConfVisitor(file)) {
-        Ok(mut conf) => {
+const MANIFEST_NAME: &str = "Cargo.toml";
+
+fn is_manifest(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == MANIFEST_NAME)
+}
+
+/// The tables of a `Cargo.toml` which can contain Clippy's configuration, i.e.
+/// `[package.metadata.clippy]` and `[workspace.metadata.clippy]`. Each element lists the keys
+/// accepted at one level, the table of the package takes precedence if both exist.
+const MANIFEST_CONF_PATH: &[&[&str]] = &[&["package", "workspace"], &["metadata"], &["clippy"]];
+
+/// Checks if the `Cargo.toml` at `path` contains Clippy's configuration.
+fn has_manifest_conf(path: &Path) -> bool {
+    load_early(path, |file| matches!(deserialize_manifest(file), Ok(Some(_)))) == Some(true)
+}
+
+/// Visits the tables of a `Cargo.toml` along `path`, see [`MANIFEST_CONF_PATH`], and deserializes
+/// the last one as Clippy's configuration. All other keys are ignored.
+struct ManifestVisitor<'a> {
+    file: &'a SourceFile,
+    path: &'a [&'a [&'a str]],
+}
+
+impl<'de> DeserializeSeed<'de> for ManifestVisitor<'_> {
+    type Value = Option<ConfValues>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ManifestVisitor<'_> {
+    type Value = Option<ConfValues>;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a table")
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        // The position of the key in the accepted keys and the values of its table
+        let mut found: Option<(usize, ConfValues)> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            let [keys, rest @ ..] = self.path else {
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            };
+            let Some(pos) = keys.iter().position(|table| key == *table) else {
+                map.next_value::<IgnoredAny>()?;
+                continue;
+            };
+            let values = if rest.is_empty() {
+                Some(map.next_value_seed(ConfVisitor {
+                    file: self.file,
+                    build_script_table: false,
+                })?)
+            } else {
+                map.next_value_seed(ManifestVisitor {
+                    file: self.file,
+                    path: rest,
+                })?
+            };
+            if let Some(values) = values
+                && found.as_ref().is_none_or(|&(found_pos, _)| pos < found_pos)
+            {
+                found = Some((pos, values));
+            }
+        }
+        Ok(found.map(|(_, values)| values))
+    }
+}
+
+/// Deserializes Clippy's configuration from a `Cargo.toml`. Returns `None` if it has none.
+fn deserialize_manifest(file: &SourceFile) -> Result<Option<ConfValues>, toml::de::Error> {
+    toml::de::Deserializer::new(file.src.as_ref().unwrap()).deserialize_map(ManifestVisitor {
+        file,
+        path: MANIFEST_CONF_PATH,
+    })
+}
+
+fn deserialize(file: &SourceFile, manifest: bool, build_script: bool) -> TryConf {
+    let values = if manifest {
+        deserialize_manifest(file).map(Option::unwrap_or_default)
+    } else {
+        toml::de::Deserializer::new(file.src.as_ref().unwrap()).deserialize_map(ConfVisitor {
+            file,
+            build_script_table: false,
+        })
+    };
+    match values {
+        Ok(values) => {
+            let mut conf = values.into_conf(build_script);
             extend_vec_if_indicator_present(&mut conf.conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
             extend_vec_if_indicator_present(&mut conf.conf.allowed_prefixes, DEFAULT_ALLOWED_PREFIXES);
             extend_vec_if_indicator_present(
                 &mut conf.conf.allow_renamed_params_for,
                 DEFAULT_ALLOWED_TRAITS_WITH_RENAMED_PARAMS,
             );
+            extend_vec_if_indicator_present(&mut conf.conf.boxed_future_aliases, DEFAULT_BOXED_FUTURE_ALIASES);
+            extend_vec_if_indicator_present(
+                &mut conf.conf.iter_on_locked_data_slow_calls,
+                DEFAULT_ITER_ON_LOCKED_DATA_SLOW_CALLS,
+            );
             // TODO: THIS SHOULD BE TESTED, this comment will be gone soon
             if conf.conf.allowed_idents_below_min_chars.iter().any(|e| e == "..") {
                 conf.conf
@@ -823,6 +1200,39 @@ impl Conf {
         CONF.get_or_init(|| Conf::read_inner(sess, path))
     }
 
+    /// Returns the lints from `deny-in-ci` if Clippy runs in CI, or an empty list otherwise. The
+    /// names are normalized to the form `clippy::lint_name`.
+    pub fn denied_in_ci(&self) -> Vec<String> {
+        if is_ci(&self.deny_in_ci_env) {
+            self.deny_in_ci.iter().map(|lint| normalize_lint_name(lint)).collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Checks if `lint` should not be emitted in test functions or `#[cfg(test)]`, either because
+    /// it is listed in `lints-allowed-in-tests` or because its dedicated `allow-*-in-tests`
+    /// option is set.
+    pub fn is_allowed_in_tests(&self, lint: &'static Lint) -> bool {
+        let name = lint.name_lower();
+        let name = name.strip_prefix("clippy::").unwrap_or(&name);
+        let legacy = match name {
+            "dbg_macro" => self.allow_dbg_in_tests,
+            "expect_used" => self.allow_expect_in_tests,
+            "indexing_slicing" => self.allow_indexing_slicing_in_tests,
+            "panic" => self.allow_panic_in_tests,
+            "print_stderr" | "print_stdout" => self.allow_print_in_tests,
+            "unwrap_used" => self.allow_unwrap_in_tests,
+            "useless_vec" => self.allow_useless_vec_in_tests,
+            _ => false,
+        };
+        legacy
+            || self.lints_allowed_in_tests.iter().any(|allowed| {
+                let allowed = allowed.to_ascii_lowercase().replace('-', "_");
+                allowed.strip_prefix("clippy::").unwrap_or(&allowed) == name
+            })
+    }
+
     fn read_inner(sess: &Session, path: &io::Result<(Option<PathBuf>, Vec<String>)>) -> Conf {
         match path {
             Ok((_, warnings)) => {
@@ -840,9 +1250,14 @@ impl Conf {
             mut conf,
             errors,
             warnings,
+            ..
         } = match path {
             Ok((Some(path), _)) => match sess.source_map().load_file(path) {
-                Ok(file) => deserialize(&file),
+                Ok(file) => deserialize(
+                    &file,
+                    is_manifest(path),
+                    is_build_script(sess.opts.crate_name.as_deref()),
+                ),
                 Err(error) => {
                     sess.dcx().err(format!("failed to read `{}`: {error}", path.display()));
                     TryConf::default()
@@ -868,16 +1283,116 @@ impl Conf {
         }
 
         for warning in warnings {
-            sess.dcx().span_warn(
+            let mut diag = sess.dcx().struct_span_warn(
                 warning.span,
                 format!("error reading Clippy's configuration file: {}", warning.message),
             );
+
+            if let Some(sugg) = warning.suggestion {
+                diag.span_suggestion(
+                    warning.span,
+                    sugg.message,
+                    sugg.suggestion,
+                    Applicability::MaybeIncorrect,
+                );
+            }
+
+            diag.emit();
         }
 
         conf
     }
 }
 
+/// Configuration of `deny-in-ci` which the driver needs before the session is created.
+pub struct DenyInCi {
+    /// The environment variable used to detect CI, so that the driver can track it.
+    pub env_var: String,
+    /// The lints to deny in the form `clippy::lint_name`, empty outside of CI.
+    pub lints: Vec<String>,
+}
+
+/// Reads the `deny-in-ci` configuration from the configuration file at `path`.
+///
+/// This runs before the session exists and is thus unable to report errors. Any errors in the
+/// configuration file are instead reported when the full configuration is read by [`Conf::read`].
+pub fn read_deny_in_ci(path: &io::Result<(Option<PathBuf>, Vec<String>)>) -> DenyInCi {
+    let conf = read_early(path, false).conf;
+    let lints = conf.denied_in_ci();
+    DenyInCi {
+        env_var: conf.deny_in_ci_env,
+        lints,
+    }
+}
+
+/// Reads the configuration file at `path` for the settings the driver needs before the session
+/// is created. Errors are ignored, they are reported by [`Conf::read`].
+fn read_early(path: &io::Result<(Option<PathBuf>, Vec<String>)>, build_script: bool) -> TryConf {
+    let Ok((Some(path), _)) = path else {
+        return TryConf::default();
+    };
+    load_early(path, |file| deserialize(file, is_manifest(path), build_script)).unwrap_or_default()
+}
+
+/// Loads the file at `path` before the session exists and passes it to `f`. Returns `None` if the
+/// file can't be read.
+fn load_early<T>(path: &Path, f: impl FnOnce(&SourceFile) -> T) -> Option<T> {
+    // The spans and symbols created while deserializing need the session globals
+    rustc_span::create_session_if_not_set_then(rustc_span::edition::DEFAULT_EDITION, |_| {
+        let file = SourceMap::new(FilePathMapping::empty()).load_file(path).ok()?;
+        Some(f(&file))
+    })
+}
+
+/// The lint levels of the `lints` table in the `[build-script]` table, e.g.
+/// `unwrap_used = "allow"`.
+type BuildScriptLints = BTreeMap<String, BuildScriptLintLevel>;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum BuildScriptLintLevel {
+    Allow,
+    Warn,
+    Deny,
+    Forbid,
+}
+
+/// Whether the crate being compiled is a build script. Cargo names the crates of build scripts
+/// `build_script_` followed by the file name of the script, e.g. `build_script_build`.
+pub fn is_build_script(crate_name: Option<&str>) -> bool {
+    crate_name.is_some_and(|name| name.starts_with("build_script_"))
+}
+
+/// Reads the lint levels of the `[build-script.lints]` table from the configuration file at
+/// `path`. The names are normalized to the form `clippy::lint_name`.
+///
+/// Like [`read_deny_in_ci`], this runs before the session exists. Errors are ignored here and
+/// reported when the full configuration is read by [`Conf::read`].
+pub fn read_build_script_lints(path: &io::Result<(Option<PathBuf>, Vec<String>)>) -> Vec<(String, Level)> {
+    read_early(path, true)
+        .build_script_lints
+        .into_iter()
+        .map(|(lint, level)| {
+            let level = match level {
+                BuildScriptLintLevel::Allow => Level::Allow,
+                BuildScriptLintLevel::Warn => Level::Warn,
+                BuildScriptLintLevel::Deny => Level::Deny,
+                BuildScriptLintLevel::Forbid => Level::Forbid,
+            };
+            (normalize_lint_name(&lint), level)
+        })
+        .collect()
+}
+
+fn is_ci(env_var: &str) -> bool {
+    env::var(env_var).is_ok_and(|value| !matches!(value.as_str(), "" | "0" | "false"))
+}
+
+fn normalize_lint_name(lint: &str) -> String {
+    let lint = lint.strip_prefix("clippy::").unwrap_or(lint);
+    format!("clippy::{}", lint.to_lowercase().replace('-', "_"))
+}
+
 const SEPARATOR_WIDTH: usize = 4;
 
 #[derive(Debug)]
@@ -889,7 +1404,7 @@ struct FieldError {
 #[derive(Debug)]
 struct Suggestion {
     message: &'static str,
-    suggestion: &'static str,
+    suggestion: String,
 }
 
 impl std::error::Error for FieldError {}
@@ -937,13 +1452,30 @@ impl serde::de::Error for FieldError {
             .min_by_key(|&(dist, _)| dist)
             .map(|(_, suggestion)| Suggestion {
                 message: "perhaps you meant",
-                suggestion,
+                suggestion: (*suggestion).to_string(),
             });
 
         Self { error: msg, suggestion }
     }
 }
 
+/// Suggests the closest expected key for serde's `unknown field` errors of nested tables, e.g.
+/// `` unknown field `reson`, expected one of `path`, `reason` ``.
+fn unknown_key_suggestion(message: &str) -> Option<Suggestion> {
+    let rest = message.strip_prefix("unknown field `")?;
+    let (field, expected) = rest.split_once('`')?;
+    expected
+        .split('`')
+        .skip(1)
+        .step_by(2)
+        .filter_map(|expected| Some((edit_distance(field, expected, 4)?, expected)))
+        .min_by_key(|&(dist, _)| dist)
+        .map(|(_, suggestion)| Suggestion {
+            message: "perhaps you meant",
+            suggestion: suggestion.to_string(),
+        })
+}
+
 fn calculate_dimensions(fields: &[&str]) -> (usize, Vec<usize>) {
     let columns = env::var("CLIPPY_TERMINAL_WIDTH")
         .ok()
@@ -13,6 +13,7 @@
     rustc::untranslatable_diagnostic
 )]
 
+extern crate rustc_data_structures;
 extern crate rustc_errors;
 extern crate rustc_hir;
 extern crate rustc_middle;
@@ -23,5 +24,8 @@ mod conf;
 mod metadata;
 pub mod types;
 
-pub use conf::{Conf, get_configuration_metadata, lookup_conf_file, sanitize_explanation};
+pub use conf::{
+    Conf, DenyInCi, get_configuration_metadata, is_build_script, lookup_conf_file, read_build_script_lints,
+    read_deny_in_ci, sanitize_explanation,
+};
 pub use metadata::ClippyConfiguration;
@@ -22,15 +22,22 @@ impl fmt::Display for ClippyConfiguration {
 
 impl ClippyConfiguration {
     pub fn to_markdown_paragraph(&self) -> String {
-        format!(
-            "## `{}`\n{}\n\n**Default Value:** `{}`\n\n---\n**Affected lints:**\n{}\n\n",
+        let mut paragraph = format!(
+            "## `{}`\n{}\n\n**Default Value:** `{}`\n\n",
             self.name,
             self.doc.lines().map(|x| x.strip_prefix(' ').unwrap_or(x)).join("\n"),
             self.default,
-            self.lints.iter().format_with("\n", |name, f| f(&format_args!(
-                "* [`{name}`](https://rust-lang.github.io/rust-clippy/master/index.html#{name})"
-            ))),
-        )
+        );
+        // Options which apply to every lint don't list them
+        if !self.lints.is_empty() {
+            paragraph += &format!(
+                "---\n**Affected lints:**\n{}\n\n",
+                self.lints.iter().format_with("\n", |name, f| f(&format_args!(
+                    "* [`{name}`](https://rust-lang.github.io/rust-clippy/master/index.html#{name})"
+                ))),
+            );
+        }
+        paragraph
     }
 
     pub fn to_markdown_link(&self) -> String {
@@ -1,4 +1,5 @@
 use clippy_utils::def_path_def_ids;
+use rustc_data_structures::sync::IntoDynSyncSend;
 use rustc_hir::def_id::DefIdMap;
 use rustc_middle::ty::TyCtxt;
 use serde::de::{self, Deserializer, Visitor};
@@ -12,6 +13,34 @@ pub struct Rename {
     pub rename: String,
 }
 
+/// A type which should be compared to strings without converting it to a `String` first.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StringComparisonType {
+    pub path: String,
+    /// A method returning a value which can be compared to a `&str` directly, or `None` if the
+    /// type itself can be compared to a `&str`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+}
+
+impl StringComparisonType {
+    fn new(path: &str, method: Option<&str>) -> Self {
+        Self {
+            path: path.to_string(),
+            method: method.map(ToString::to_string),
+        }
+    }
+}
+
+pub fn default_string_comparison_types() -> Vec<StringComparisonType> {
+    vec![
+        StringComparisonType::new("std::ffi::OsStr", None),
+        StringComparisonType::new("std::ffi::OsString", None),
+        StringComparisonType::new("std::path::Path", Some("as_os_str")),
+        StringComparisonType::new("std::path::PathBuf", Some("as_os_str")),
+    ]
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum DisallowedPath {
@@ -46,6 +75,49 @@ pub fn create_disallowed_map(
         .collect()
 }
 
+/// A regular expression which has to match a whole string, checked for validity when the
+/// configuration is read.
+pub struct ConfRegex {
+    pattern: String,
+    // `Regex` has interior caches which the compiler can't see are thread safe
+    regex: IntoDynSyncSend<regex::Regex>,
+}
+
+impl fmt::Debug for ConfRegex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConfRegex").field(&self.pattern).finish()
+    }
+}
+
+impl ConfRegex {
+    pub fn is_match(&self, text: &str) -> bool {
+        self.regex.is_match(text)
+    }
+}
+
+impl<'de> Deserialize<'de> for ConfRegex {
+    fn deserialize<D>(deser: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let pattern = String::deserialize(deser)?;
+        let regex = regex::Regex::new(&format!("^(?:{pattern})$")).map_err(de::Error::custom)?;
+        Ok(Self {
+            pattern,
+            regex: IntoDynSyncSend(regex),
+        })
+    }
+}
+
+impl Serialize for ConfRegex {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_str(&self.pattern)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum MatchLintBehaviour {
     AllTypes,
@@ -446,3 +518,15 @@ pub enum PubUnderscoreFieldsBehaviour {
     PubliclyExported,
     AllPubFields,
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum FloatAssertionStyle {
+    Epsilon,
+    Approx,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum StructUpdateDefaultBehavior {
+    NonCopyPrivateFields,
+    AllLocalStructs,
+}
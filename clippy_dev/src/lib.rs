@@ -8,6 +8,8 @@
     unused_qualifications
 )]
 #![allow(clippy::missing_panics_doc)]
+// The library is only used by the `cargo dev` binary, which exits on errors.
+#![allow(clippy::exit_in_library_code)]
 
 // The `rustc_driver` crate seems to be required in order to use the `rust_lexer` crate.
 #[allow(unused_extern_crates)]
@@ -19,6 +21,7 @@ pub mod fmt;
 pub mod lint;
 pub mod new_lint;
 pub mod release;
+pub mod rev_build;
 pub mod serve;
 pub mod setup;
 pub mod sync;
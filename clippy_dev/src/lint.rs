@@ -1,15 +1,23 @@
+use crate::rev_build::RevBuild;
 use crate::utils::{cargo_clippy_path, exit_if_err};
-use std::process::{self, Command};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::{self, Command, Output};
 use std::{env, fs};
 
-pub fn run<'a>(path: &str, args: impl Iterator<Item = &'a String>) {
-    let is_file = match fs::metadata(path) {
+fn is_file(path: &str) -> bool {
+    match fs::metadata(path) {
         Ok(metadata) => metadata.is_file(),
         Err(e) => {
             eprintln!("Failed to read {path}: {e:?}");
             process::exit(1);
         },
-    };
+    }
+}
+
+pub fn run<'a>(path: &str, args: impl Iterator<Item = &'a String>) {
+    let is_file = is_file(path);
 
     if is_file {
         exit_if_err(
@@ -42,3 +50,150 @@ pub fn run<'a>(path: &str, args: impl Iterator<Item = &'a String>) {
         exit_if_err(status);
     }
 }
+
+/// Runs the current Clippy and Clippy built at `base_rev` on `path` and prints the differences in
+/// the emitted warnings, grouped by lint.
+pub fn compare(path: &str, base_rev: &str, args: &[String]) {
+    let is_file = is_file(path);
+    let path = fs::canonicalize(path).expect("failed to canonicalize path");
+
+    exit_if_err(
+        Command::new(env::var("CARGO").unwrap_or("cargo".into()))
+            .arg("build")
+            .status(),
+    );
+    // The worktree of the base revision is removed when `base` is dropped at the end of the
+    // closure, before exiting on an error.
+    let outputs = RevBuild::new(base_rev).and_then(|base| run_base_and_head(&base, &path, is_file, args));
+    match outputs {
+        Ok((base_output, head_output)) => {
+            print_diff(base_rev, &parse_warnings(&base_output), &parse_warnings(&head_output));
+        },
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        },
+    }
+}
+
+/// Runs both Clippy builds on `path`, returning their outputs.
+fn run_base_and_head(base: &RevBuild, path: &Path, is_file: bool, args: &[String]) -> Result<(Output, Output), String> {
+    if is_file {
+        let mut base_cmd = base.cargo();
+        base_cmd.args(["run", "--quiet", "--bin", "clippy-driver", "--"]);
+        base_cmd.arg("-L").arg(base.artifacts_dir());
+
+        let mut head_cmd = Command::new(env::var("CARGO").unwrap_or("cargo".into()));
+        head_cmd.args(["run", "--quiet", "--bin", "clippy-driver", "--"]);
+        head_cmd.args(["-L", "./target/debug"]);
+
+        let out_dir = base.temp_dir().join("out");
+        for cmd in [&mut base_cmd, &mut head_cmd] {
+            cmd.args(["-Z", "no-codegen"])
+                .args(["--edition", "2021"])
+                .arg("--error-format=json")
+                .arg("--out-dir")
+                .arg(&out_dir)
+                .arg(path)
+                .args(args);
+        }
+        Ok((run_for_output(&mut base_cmd)?, run_for_output(&mut head_cmd)?))
+    } else {
+        let mut base_cmd = base.cargo_clippy();
+        let mut head_cmd = Command::new(cargo_clippy_path());
+
+        // Use separate target directories so that cargo doesn't replay the cached output of the
+        // other Clippy build.
+        for (cmd, target_dir) in [(&mut base_cmd, "base"), (&mut head_cmd, "head")] {
+            cmd.arg("clippy")
+                .arg("--message-format=json")
+                .arg("--target-dir")
+                .arg(base.temp_dir().join(target_dir))
+                .args(args)
+                .current_dir(path);
+        }
+        Ok((run_for_output(&mut base_cmd)?, run_for_output(&mut head_cmd)?))
+    }
+}
+
+fn run_for_output(cmd: &mut Command) -> Result<Output, String> {
+    // Prevent rustc from creating `rustc-ice-*` files the console output is enough.
+    cmd.env("RUSTC_ICE", "0")
+        .output()
+        .map_err(|e| format!("Failed to run {cmd:?}: {e}"))
+}
+
+/// Collects the Clippy warnings from the JSON output of `cargo` or `clippy-driver` as a map from
+/// lint names to the locations and messages of the warnings.
+fn parse_warnings(output: &Output) -> BTreeMap<String, Vec<String>> {
+    let mut warnings = BTreeMap::<String, Vec<String>>::new();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    for line in stdout.lines().chain(stderr.lines()) {
+        let Ok(value) = serde_json::from_str::<Value>(line) else {
+            continue;
+        };
+        // `cargo` wraps the diagnostics emitted by the compiler
+        let diag = if value["reason"] == "compiler-message" {
+            &value["message"]
+        } else {
+            &value
+        };
+        let Some(lint) = diag["code"]["code"]
+            .as_str()
+            .filter(|code| code.starts_with("clippy::"))
+        else {
+            continue;
+        };
+
+        let location = diag["spans"]
+            .as_array()
+            .and_then(|spans| spans.iter().find(|span| span["is_primary"] == true))
+            .map_or_else(String::new, |span| {
+                format!(
+                    "{}:{}:{}: ",
+                    span["file_name"].as_str().unwrap_or_default(),
+                    span["line_start"],
+                    span["column_start"]
+                )
+            });
+        let message = diag["message"].as_str().unwrap_or_default();
+        warnings
+            .entry(lint.to_string())
+            .or_default()
+            .push(format!("{location}{message}"));
+    }
+
+    for lint_warnings in warnings.values_mut() {
+        lint_warnings.sort_unstable();
+        lint_warnings.dedup();
+    }
+    warnings
+}
+
+fn print_diff(base_rev: &str, base: &BTreeMap<String, Vec<String>>, head: &BTreeMap<String, Vec<String>>) {
+    let mut changed = 0;
+    for lint in base.keys().chain(head.keys().filter(|lint| !base.contains_key(*lint))) {
+        let base_warnings = base.get(lint).map_or(&[][..], Vec::as_slice);
+        let head_warnings = head.get(lint).map_or(&[][..], Vec::as_slice);
+        if base_warnings == head_warnings {
+            continue;
+        }
+
+        changed += 1;
+        println!("{lint}: {} -> {}", base_warnings.len(), head_warnings.len());
+        for removed in base_warnings.iter().filter(|w| !head_warnings.contains(w)) {
+            println!("  - {removed}");
+        }
+        for added in head_warnings.iter().filter(|w| !base_warnings.contains(w)) {
+            println!("  + {added}");
+        }
+    }
+
+    if changed == 0 {
+        println!("No differences to `{base_rev}`");
+    } else {
+        println!("{changed} lint(s) changed compared to `{base_rev}`");
+    }
+}
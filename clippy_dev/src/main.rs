@@ -5,6 +5,7 @@
 use clap::{Args, Parser, Subcommand};
 use clippy_dev::{dogfood, fmt, lint, new_lint, release, serve, setup, sync, update_lints, utils};
 use std::convert::Infallible;
+use std::path::PathBuf;
 
 fn main() {
     let dev = Dev::parse();
@@ -34,7 +35,15 @@ fn main() {
             category,
             r#type,
             msrv,
-        } => match new_lint::create(&pass, &name, &category, r#type.as_deref(), msrv) {
+            with_fixture,
+        } => match new_lint::create(
+            &pass,
+            &name,
+            &category,
+            r#type.as_deref(),
+            msrv,
+            with_fixture.as_deref(),
+        ) {
             Ok(()) => update_lints::update(utils::UpdateMode::Change),
             Err(e) => eprintln!("Unable to create lint: {e}"),
         },
@@ -68,7 +77,10 @@ fn main() {
             RemoveSubcommand::VscodeTasks => setup::vscode::remove_tasks(),
         },
         DevCommand::Serve { port, lint } => serve::run(port, lint),
-        DevCommand::Lint { path, args } => lint::run(&path, args.iter()),
+        DevCommand::Lint { path, compare, args } => match compare {
+            Some(base_rev) => lint::compare(&path, &base_rev, &args),
+            None => lint::run(&path, args.iter()),
+        },
         DevCommand::RenameLint {
             old_name,
             new_name,
@@ -173,6 +185,10 @@ enum DevCommand {
         #[arg(long)]
         /// Add MSRV config code to the lint
         msrv: bool,
+        #[arg(long, value_name = "PATH")]
+        /// Use the code in this file as the test, and seed the lint with a pattern matching its
+        /// first statement generated by `#[clippy::author]`
+        with_fixture: Option<PathBuf>,
     },
     /// Support for setting up your personal development environment
     Setup(SetupCommand),
@@ -205,9 +221,16 @@ enum DevCommand {
     /// Set lint levels: {n}
     ///     cargo dev lint file.rs -- -W clippy::pedantic {n}
     ///     cargo dev lint ~/my-project -- -- -W clippy::pedantic
+    ///
+    /// Compare the warnings to Clippy built from another revision: {n}
+    ///     cargo dev lint ~/my-project --compare master
     Lint {
         /// The path to a file or package directory to lint
         path: String,
+        #[arg(long, value_name = "BASE_REV")]
+        /// Build Clippy at the given git revision and print the differences in the emitted
+        /// warnings
+        compare: Option<String>,
         /// Pass extra arguments to cargo/clippy-driver
         args: Vec<String>,
     },
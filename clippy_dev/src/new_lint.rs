@@ -1,17 +1,22 @@
 use crate::utils::{clippy_project_root, clippy_version};
 use indoc::{formatdoc, writedoc};
-use std::fmt;
 use std::fmt::Write as _;
 use std::fs::{self, OpenOptions};
 use std::io::prelude::*;
 use std::io::{self, ErrorKind};
 use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::{env, fmt};
 
 struct LintData<'a> {
     pass: &'a str,
     name: &'a str,
     category: &'a str,
     ty: Option<&'a str>,
+    /// The code given with `--with-fixture`, with a `main` function added if it lacks one.
+    fixture: Option<String>,
+    /// The pattern generated by `#[clippy::author]` for the first statement of the fixture.
+    author_pattern: Option<String>,
     project_root: PathBuf,
 }
 
@@ -35,19 +40,44 @@ impl<T> Context for io::Result<T> {
 ///
 /// # Errors
 ///
-/// This function errors out if the files couldn't be created or written to.
-pub fn create(pass: &str, name: &str, category: &str, mut ty: Option<&str>, msrv: bool) -> io::Result<()> {
+/// This function errors out if the fixture couldn't be read, or if the files couldn't be created or
+/// written to.
+pub fn create(
+    pass: &str,
+    name: &str,
+    category: &str,
+    mut ty: Option<&str>,
+    msrv: bool,
+    fixture: Option<&Path>,
+) -> io::Result<()> {
     if category == "cargo" && ty.is_none() {
         // `cargo` is a special category, these lints should always be in `clippy_lints/src/cargo`
         ty = Some("cargo");
     }
 
+    let project_root = clippy_project_root();
+    let fixture = match fixture {
+        Some(path) => Some(
+            fs::read_to_string(path)
+                .map(|fixture| with_main(&fixture))
+                .context(format!("Unable to read the fixture `{}`", path.display()))?,
+        ),
+        None => None,
+    };
+    // The pattern only makes sense for late lint passes
+    let author_pattern = fixture
+        .as_deref()
+        .filter(|_| pass == "late")
+        .and_then(|fixture| author_pattern(&project_root, name, fixture));
+
     let lint = LintData {
         pass,
         name,
         category,
         ty,
-        project_root: clippy_project_root(),
+        fixture,
+        author_pattern,
+        project_root,
     };
 
     create_lint(&lint, msrv).context("Unable to create lint implementation")?;
@@ -55,6 +85,8 @@ pub fn create(pass: &str, name: &str, category: &str, mut ty: Option<&str>, msrv
 
     if lint.ty.is_none() {
         add_lint(&lint, msrv).context("Unable to add lint to clippy_lints/src/lib.rs")?;
+    } else if let Some(pattern) = &lint.author_pattern {
+        println!("\nPattern generated by `#[clippy::author]` for the first statement of the fixture:\n{pattern}");
     }
 
     if pass == "early" {
@@ -87,7 +119,7 @@ fn create_test(lint: &LintData<'_>, msrv: bool) -> io::Result<()> {
         location: P,
         case: &str,
         hint: &str,
-        msrv: bool,
+        contents: String,
     ) -> io::Result<()> {
         let mut path = location.into().join(case);
         fs::create_dir(&path)?;
@@ -95,11 +127,16 @@ fn create_test(lint: &LintData<'_>, msrv: bool) -> io::Result<()> {
 
         path.push("src");
         fs::create_dir(&path)?;
-        write_file(path.join("main.rs"), get_test_file_contents(lint_name, msrv))?;
+        write_file(path.join("main.rs"), contents)?;
 
         Ok(())
     }
 
+    let test_contents = match &lint.fixture {
+        Some(fixture) => get_fixture_test_contents(lint.name, fixture),
+        None => get_test_file_contents(lint.name, msrv),
+    };
+
     if lint.category == "cargo" {
         let relative_test_dir = format!("tests/ui-cargo/{}", lint.name);
         let test_dir = lint.project_root.join(&relative_test_dir);
@@ -110,23 +147,30 @@ fn create_test(lint: &LintData<'_>, msrv: bool) -> io::Result<()> {
             &test_dir,
             "fail",
             "Content that triggers the lint goes here",
-            msrv,
+            test_contents,
         )?;
         create_project_layout(
             lint.name,
             &test_dir,
             "pass",
             "This file should not trigger the lint",
-            false,
+            get_test_file_contents(lint.name, false),
         )?;
 
         println!("Generated test directories: `{relative_test_dir}/pass`, `{relative_test_dir}/fail`");
     } else {
         let test_path = format!("tests/ui/{}.rs", lint.name);
-        let test_contents = get_test_file_contents(lint.name, msrv);
-        write_file(lint.project_root.join(&test_path), test_contents)?;
+        write_file(lint.project_root.join(&test_path), &test_contents)?;
 
         println!("Generated test file: `{test_path}`");
+
+        if lint.fixture.is_some() {
+            // A stub to be edited to contain the code after the suggestions are applied
+            let fixed_path = format!("tests/ui/{}.fixed", lint.name);
+            write_file(lint.project_root.join(&fixed_path), &test_contents)?;
+
+            println!("Generated fixed file stub: `{fixed_path}`");
+        }
     }
 
     Ok(())
@@ -224,6 +268,119 @@ fn get_test_file_contents(lint_name: &str, msrv: bool) -> String {
     test
 }
 
+fn get_fixture_test_contents(lint_name: &str, fixture: &str) -> String {
+    format!("#![warn(clippy::{lint_name})]\n\n{fixture}")
+}
+
+/// Adds an empty `main` function to the code if it doesn't have one, as required by the tests.
+fn with_main(code: &str) -> String {
+    let mut code = code.trim_end().to_owned();
+    if !code.lines().any(|line| line.trim_start().starts_with("fn main(")) {
+        code.push_str("\n\nfn main() {}");
+    }
+    code.push('\n');
+    code
+}
+
+/// Adds `#[clippy::author]` to the first statement of the first function with a body.
+fn annotate_first_statement(code: &str) -> Option<String> {
+    let lines: Vec<_> = code.lines().collect();
+    let stmt = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| {
+            let line = line.trim();
+            !line.starts_with("//") && line.contains("fn ") && line.ends_with('{')
+        })
+        .find_map(|(fn_line, _)| {
+            let offset = lines[fn_line + 1..].iter().position(|line| {
+                let line = line.trim();
+                !line.is_empty() && !line.starts_with("//")
+            })?;
+            let stmt = fn_line + 1 + offset;
+            (!lines[stmt].trim_start().starts_with('}')).then_some(stmt)
+        })?;
+
+    let indent = &lines[stmt][..lines[stmt].len() - lines[stmt].trim_start().len()];
+    // The first statement may be the tail expression of the body
+    let mut annotated = String::from("#![feature(stmt_expr_attributes)]\n");
+    for (i, line) in lines.iter().enumerate() {
+        if i == stmt {
+            let _: fmt::Result = writeln!(annotated, "{indent}#[clippy::author]");
+        }
+        let _: fmt::Result = writeln!(annotated, "{line}");
+    }
+    Some(annotated)
+}
+
+/// Runs `#[clippy::author]` on the first statement of the fixture and returns the generated
+/// pattern. Failures are reported as warnings, as the lint can still be created without it.
+fn author_pattern(project_root: &Path, lint_name: &str, fixture: &str) -> Option<String> {
+    let Some(annotated) = annotate_first_statement(fixture) else {
+        eprintln!("warning: no statement found in the fixture to generate a pattern for");
+        return None;
+    };
+
+    let out_dir = env::temp_dir();
+    let path = out_dir.join(format!("{lint_name}_fixture.rs"));
+    if let Err(e) = fs::write(&path, annotated) {
+        eprintln!("warning: unable to write `{}`: {e}", path.display());
+        return None;
+    }
+    let output = Command::new(env::var("CARGO").unwrap_or("cargo".into()))
+        .args(["run", "--quiet", "--bin", "clippy-driver", "--"])
+        .args(["-L", "./target/debug"])
+        .args(["-Z", "no-codegen"])
+        .args(["--edition", "2021"])
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .arg(&path)
+        // Prevent rustc from creating `rustc-ice-*` files the console output is enough.
+        .env("RUSTC_ICE", "0")
+        .current_dir(project_root)
+        .output();
+    let _ = fs::remove_file(&path);
+
+    match output {
+        Ok(output) if output.status.success() && !output.stdout.is_empty() => {
+            Some(String::from_utf8_lossy(&output.stdout).into_owned())
+        },
+        Ok(output) => {
+            eprintln!(
+                "warning: unable to generate a pattern from the fixture:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+            None
+        },
+        Err(e) => {
+            eprintln!("warning: unable to run `clippy-driver`: {e}");
+            None
+        },
+    }
+}
+
+/// Returns a `check_stmt` or `check_expr` implementation, depending on the node the attribute
+/// ended up on, containing the pattern generated by `#[clippy::author]`. It's indented to be
+/// inserted in the lint pass implementation.
+fn get_author_check(pattern: &str) -> String {
+    // Attributes on expression statements apply to the expression
+    let (node, node_ty) = if pattern.lines().next().is_some_and(|line| line.contains(" stmt.")) {
+        ("stmt", "Stmt")
+    } else {
+        ("expr", "Expr")
+    };
+    let mut check = format!(
+        "\n    fn check_{node}(&mut self, cx: &LateContext<'_>, {node}: &{node_ty}<'_>) {{\n        \
+        // TODO: Generalize this pattern, generated by `#[clippy::author]` from the first statement of the\n        \
+        // fixture, and import the items it uses\n"
+    );
+    for line in pattern.lines() {
+        let _: fmt::Result = writeln!(check, "        {line}");
+    }
+    check.push_str("    }\n");
+    check
+}
+
 fn get_manifest_contents(lint_name: &str, hint: &str) -> String {
     formatdoc!(
         r#"
@@ -254,6 +411,10 @@ fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
     let category = lint.category;
     let name_camel = to_camel_case(lint.name);
     let name_upper = lint_name.to_uppercase();
+    let pass_body = lint
+        .author_pattern
+        .as_deref()
+        .map_or_else(String::new, get_author_check);
 
     result.push_str(&if enable_msrv {
         formatdoc!(
@@ -296,7 +457,7 @@ fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
 
             impl {pass_type}{pass_lifetimes} for {name_camel} {{
                 extract_msrv_attr!({context_import});
-            }}
+            {pass_body}}}
 
             // TODO: Add MSRV level to `clippy_config/src/msrvs.rs` if needed.
             // TODO: Update msrv config comment in `clippy_config/src/conf.rs`
@@ -307,7 +468,7 @@ fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
             r"
             declare_lint_pass!({name_camel} => [{name_upper}]);
 
-            impl {pass_type}{pass_lifetimes} for {name_camel} {{}}
+            impl {pass_type}{pass_lifetimes} for {name_camel} {{{pass_body}}}
         "
         )
     });
@@ -579,3 +740,15 @@ fn test_camel_case() {
     let name4 = to_camel_case(name3);
     assert_eq!(name4, "LintName");
 }
+
+#[test]
+fn test_annotate_first_statement() {
+    let code = "fn empty() {}\n\nfn f(x: u32) -> u32 {\n    // comment\n    let y = x * 2;\n    y\n}\n";
+    assert_eq!(
+        annotate_first_statement(code).unwrap(),
+        "#![feature(stmt_expr_attributes)]\nfn empty() {}\n\nfn f(x: u32) -> u32 {\n    // comment\n    \
+        #[clippy::author]\n    let y = x * 2;\n    y\n}\n"
+    );
+
+    assert_eq!(annotate_first_statement("fn main() {\n}\n"), None);
+}
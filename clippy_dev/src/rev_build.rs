@@ -0,0 +1,116 @@
+use crate::utils::{CARGO_CLIPPY_EXE, clippy_project_root};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::{env, fs};
+
+/// A build of Clippy at another git revision.
+///
+/// The revision is checked out into a temporary git worktree and built into a separate target
+/// directory, both of which are removed again when this is dropped.
+pub struct RevBuild {
+    /// The temporary directory containing the worktree and the target directory
+    dir: PathBuf,
+    worktree: PathBuf,
+    target_dir: PathBuf,
+    /// The toolchain from the `rust-toolchain` file of the revision
+    toolchain: Option<String>,
+}
+
+impl RevBuild {
+    /// Checks out and builds Clippy at `rev`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message if checking out or building the revision fails.
+    pub fn new(rev: &str) -> Result<Self, String> {
+        let dir = env::temp_dir().join(format!("clippy-dev-{}", process::id()));
+        // Created before checking out the revision so that the worktree is removed again if any of
+        // the following steps fail.
+        let worktree = dir.join("clippy");
+        let target_dir = dir.join("target");
+        let mut build = Self {
+            dir,
+            worktree,
+            target_dir,
+            toolchain: None,
+        };
+
+        let status = Command::new("git")
+            .current_dir(clippy_project_root())
+            .args(["worktree", "add", "--detach"])
+            .arg(&build.worktree)
+            .arg(rev)
+            .status();
+        if !status.is_ok_and(|status| status.success()) {
+            return Err(format!("Failed to check out `{rev}`"));
+        }
+        build.toolchain = read_toolchain(&build.worktree);
+
+        println!("Building Clippy at `{rev}`");
+        let status = build.cargo().arg("build").status();
+        if !status.is_ok_and(|status| status.success()) {
+            return Err(format!("Failed to build Clippy at `{rev}`"));
+        }
+
+        Ok(build)
+    }
+
+    /// Returns a `cargo` command which runs in the worktree of this revision, using its toolchain
+    /// and target directory.
+    #[must_use]
+    pub fn cargo(&self) -> Command {
+        // Not using `$CARGO` here, it points to the cargo of the current toolchain rather than the
+        // rustup proxy.
+        let mut cmd = Command::new("cargo");
+        cmd.current_dir(&self.worktree)
+            .env("CARGO_TARGET_DIR", &self.target_dir);
+        if let Some(toolchain) = &self.toolchain {
+            cmd.env("RUSTUP_TOOLCHAIN", toolchain);
+        }
+        cmd
+    }
+
+    /// Returns a command running the `cargo-clippy` binary of this revision.
+    #[must_use]
+    pub fn cargo_clippy(&self) -> Command {
+        let mut cmd = Command::new(self.target_dir.join("debug").join(CARGO_CLIPPY_EXE));
+        if let Some(toolchain) = &self.toolchain {
+            cmd.env("RUSTUP_TOOLCHAIN", toolchain);
+        }
+        cmd
+    }
+
+    /// The directory containing the build artifacts of this revision.
+    #[must_use]
+    pub fn artifacts_dir(&self) -> PathBuf {
+        self.target_dir.join("debug")
+    }
+
+    /// A temporary directory which is removed together with this build.
+    #[must_use]
+    pub fn temp_dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+impl Drop for RevBuild {
+    fn drop(&mut self) {
+        if self.worktree.exists() {
+            let _ = Command::new("git")
+                .current_dir(clippy_project_root())
+                .args(["worktree", "remove", "--force"])
+                .arg(&self.worktree)
+                .status();
+        }
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Reads the toolchain channel from the `rust-toolchain` file in `dir`.
+fn read_toolchain(dir: &Path) -> Option<String> {
+    let contents = fs::read_to_string(dir.join("rust-toolchain")).ok()?;
+    contents.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        (key.trim() == "channel").then(|| value.trim().trim_matches('"').to_string())
+    })
+}
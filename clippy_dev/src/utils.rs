@@ -3,9 +3,9 @@ use std::process::{self, ExitStatus};
 use std::{fs, io};
 
 #[cfg(not(windows))]
-static CARGO_CLIPPY_EXE: &str = "cargo-clippy";
+pub(crate) static CARGO_CLIPPY_EXE: &str = "cargo-clippy";
 #[cfg(windows)]
-static CARGO_CLIPPY_EXE: &str = "cargo-clippy.exe";
+pub(crate) static CARGO_CLIPPY_EXE: &str = "cargo-clippy.exe";
 
 /// Returns the path to the `cargo-clippy` binary
 ///
@@ -137,13 +137,13 @@ impl_lint_pass!(ArbitrarySourceItemOrdering => [ARBITRARY_SOURCE_ITEM_ORDERING])
 #[derive(Debug)]
 #[allow(clippy::struct_excessive_bools)] // Bools are cached feature flags.
 pub struct ArbitrarySourceItemOrdering {
-    assoc_types_order: SourceItemOrderingTraitAssocItemKinds,
+    assoc_types_order: &'static SourceItemOrderingTraitAssocItemKinds,
     enable_ordering_for_enum: bool,
     enable_ordering_for_impl: bool,
     enable_ordering_for_module: bool,
     enable_ordering_for_struct: bool,
     enable_ordering_for_trait: bool,
-    module_item_order_groupings: SourceItemOrderingModuleItemGroupings,
+    module_item_order_groupings: &'static SourceItemOrderingModuleItemGroupings,
 }
 
 impl ArbitrarySourceItemOrdering {
@@ -151,13 +151,13 @@ impl ArbitrarySourceItemOrdering {
         #[allow(clippy::enum_glob_use)] // Very local glob use for legibility.
         use SourceItemOrderingCategory::*;
         Self {
-            assoc_types_order: conf.trait_assoc_item_kinds_order.clone(),
+            assoc_types_order: &conf.trait_assoc_item_kinds_order,
             enable_ordering_for_enum: conf.source_item_ordering.contains(&Enum),
             enable_ordering_for_impl: conf.source_item_ordering.contains(&Impl),
             enable_ordering_for_module: conf.source_item_ordering.contains(&Module),
             enable_ordering_for_struct: conf.source_item_ordering.contains(&Struct),
             enable_ordering_for_trait: conf.source_item_ordering.contains(&Trait),
-            module_item_order_groupings: conf.module_item_order_groupings.clone(),
+            module_item_order_groupings: &conf.module_item_order_groupings,
         }
     }
 
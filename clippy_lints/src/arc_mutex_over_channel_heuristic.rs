@@ -0,0 +1,343 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{fn_def_id, get_parent_expr, is_diag_trait_item, path_to_local, path_to_local_id};
+use core::ops::ControlFlow;
+use itertools::Itertools;
+use rustc_data_structures::fx::{FxIndexMap, FxIndexSet};
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{BindingMode, Expr, ExprKind, ItemKind, LetStmt, Node, PatKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::impl_lint_pass;
+use rustc_span::{Span, sym};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for struct fields of type `Arc<Mutex<Vec<T>>>`, `Arc<Mutex<VecDeque<T>>>` or
+    /// `Arc<Mutex<HashMap<K, V>>>` which are only ever added to in one set of functions and
+    /// only ever drained in another set of functions.
+    ///
+    /// ### Why is this bad?
+    /// Such a field is used as a hand-written queue between producers and consumers. A channel
+    /// expresses this directly, doesn't need to lock the whole collection for every item and
+    /// lets the consumers wait for new items instead of polling.
+    ///
+    /// ### Known problems
+    /// This is a heuristic. Only usages in the current crate are considered, so fields which are
+    /// reachable from other crates are not linted. The collection is only recognized when it is
+    /// locked with `.lock().unwrap()` or `.lock().expect(..)`, any other use of the field, its
+    /// clones or its guards, e.g. passing them to a function, prevents the lint.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::sync::{Arc, Mutex};
+    /// struct Jobs {
+    ///     queue: Arc<Mutex<Vec<u32>>>,
+    /// }
+    ///
+    /// impl Jobs {
+    ///     fn submit(&self, job: u32) {
+    ///         self.queue.lock().unwrap().push(job);
+    ///     }
+    ///
+    ///     fn run(&self) {
+    ///         for job in self.queue.lock().unwrap().drain(..) {
+    ///             println!("{job}");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::sync::mpsc::{Receiver, Sender};
+    /// struct Jobs {
+    ///     sender: Sender<u32>,
+    ///     receiver: Receiver<u32>,
+    /// }
+    ///
+    /// impl Jobs {
+    ///     fn submit(&self, job: u32) {
+    ///         self.sender.send(job).unwrap();
+    ///     }
+    ///
+    ///     fn run(&self) {
+    ///         for job in self.receiver.try_iter() {
+    ///             println!("{job}");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub ARC_MUTEX_OVER_CHANNEL_HEURISTIC,
+    pedantic,
+    "`Arc<Mutex<..>>` collections which are only filled and drained, like a channel"
+}
+
+impl_lint_pass!(ArcMutexOverChannelHeuristic => [ARC_MUTEX_OVER_CHANNEL_HEURISTIC]);
+
+/// Methods which add items to a `Vec`, `VecDeque` or `HashMap`.
+const PRODUCER_METHODS: &[&str] = &["push", "push_back", "push_front", "insert", "extend", "append", "entry"];
+
+/// Methods which remove items from a `Vec`, `VecDeque` or `HashMap`.
+const CONSUMER_METHODS: &[&str] = &["pop", "pop_back", "pop_front", "drain", "remove", "clear", "split_off"];
+
+struct FieldUsage {
+    span: Span,
+    producers: FxIndexSet<LocalDefId>,
+    consumers: FxIndexSet<LocalDefId>,
+    other_uses: bool,
+}
+
+#[derive(Default)]
+pub struct ArcMutexOverChannelHeuristic {
+    fields: FxIndexMap<LocalDefId, FieldUsage>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ArcMutexOverChannelHeuristic {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        // The fields are gathered before any body is checked, as they may be used before the
+        // struct is defined.
+        for id in cx.tcx.hir().items() {
+            if matches!(cx.tcx.def_kind(id.owner_id), DefKind::Struct)
+                && let item = cx.tcx.hir().item(id)
+                && let ItemKind::Struct(data, _) = &item.kind
+                && !in_external_macro(cx.sess(), item.span)
+            {
+                for field in data.fields() {
+                    if !cx.effective_visibilities.is_exported(field.def_id)
+                        && is_arc_mutex_collection(cx, cx.tcx.type_of(field.def_id).instantiate_identity())
+                    {
+                        self.fields.insert(field.def_id, FieldUsage {
+                            span: field.span,
+                            producers: FxIndexSet::default(),
+                            consumers: FxIndexSet::default(),
+                            other_uses: false,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Field(..) = expr.kind
+            && let Some(field) = accessed_field(cx, expr)
+            && let Some(usage) = self.fields.get_mut(&field)
+            && !is_only_locked(cx, expr)
+        {
+            usage.other_uses = true;
+        } else if let ExprKind::MethodCall(method, recv, ..) = expr.kind
+            && let Some(field) = locked_field(cx, guard_init(cx, recv))
+            && let Some(usage) = self.fields.get_mut(&field)
+        {
+            let owner = cx.tcx.hir().enclosing_body_owner(expr.hir_id);
+            // Attribute uses in closures to the function they are defined in.
+            let Some(owner) = cx.tcx.typeck_root_def_id(owner.to_def_id()).as_local() else {
+                return;
+            };
+            let method = method.ident.as_str();
+            if PRODUCER_METHODS.contains(&method) {
+                usage.producers.insert(owner);
+            } else if CONSUMER_METHODS.contains(&method) {
+                usage.consumers.insert(owner);
+            } else {
+                usage.other_uses = true;
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (&def_id, usage) in &self.fields {
+            if !usage.other_uses
+                && !usage.producers.is_empty()
+                && !usage.consumers.is_empty()
+                && usage.producers.is_disjoint(&usage.consumers)
+            {
+                let names = |fns: &FxIndexSet<LocalDefId>| {
+                    fns.iter()
+                        .map(|&def_id| format!("`{}`", cx.tcx.item_name(def_id.to_def_id())))
+                        .join(", ")
+                };
+                span_lint_hir_and_then(
+                    cx,
+                    ARC_MUTEX_OVER_CHANNEL_HEURISTIC,
+                    cx.tcx.local_def_id_to_hir_id(def_id),
+                    usage.span,
+                    "this collection is only filled and drained, like a channel",
+                    |diag| {
+                        diag.note(format!(
+                            "items are only added in {} and only removed in {}",
+                            names(&usage.producers),
+                            names(&usage.consumers),
+                        ));
+                        diag.help("consider sending the items through a channel, e.g. `std::sync::mpsc::channel`");
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn is_arc_mutex_collection<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    let inner = |ty: Ty<'tcx>, name| match ty.kind() {
+        ty::Adt(adt, args) if cx.tcx.is_diagnostic_item(name, adt.did()) => Some(args.type_at(0)),
+        _ => None,
+    };
+    inner(ty, sym::Arc)
+        .and_then(|ty| inner(ty, sym::Mutex))
+        .and_then(Ty::ty_adt_def)
+        .is_some_and(|adt| {
+            matches!(
+                cx.tcx.get_diagnostic_name(adt.did()),
+                Some(sym::Vec | sym::VecDeque | sym::HashMap)
+            )
+        })
+}
+
+/// If `expr` is a local bound to a lock guard, e.g. `let mut guard = x.lock().unwrap();`, returns
+/// the initializer of the local. Otherwise returns `expr`.
+fn guard_init<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    if let Some(id) = path_to_local(expr)
+        && let Node::LetStmt(local) = cx.tcx.parent_hir_node(id)
+        && let Some(init) = local.init
+    {
+        init
+    } else {
+        expr
+    }
+}
+
+/// If `expr` is a local bound to a clone of an `Arc`, e.g. `let x = Arc::clone(&self.x);` or
+/// `let x = self.x.clone();`, returns the cloned expression. Otherwise returns `expr`.
+fn arc_clone_source<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    if let Some(id) = path_to_local(expr)
+        && let Node::LetStmt(local) = cx.tcx.parent_hir_node(id)
+        && let Some(init) = local.init
+        && fn_def_id(cx, init).is_some_and(|id| is_diag_trait_item(cx, id, sym::Clone))
+    {
+        match init.kind {
+            ExprKind::MethodCall(_, recv, [], _) => return recv,
+            ExprKind::Call(_, [arg]) => return arg.peel_borrows(),
+            _ => {},
+        }
+    }
+    expr
+}
+
+/// Matches `x.field.lock().unwrap()` and `x.field.lock().expect(..)`, returning the field. The
+/// field may also be locked through a clone of the `Arc`.
+fn locked_field<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<LocalDefId> {
+    if let ExprKind::MethodCall(unwrap, lock_call, ..) = expr.kind
+        && matches!(unwrap.ident.as_str(), "unwrap" | "expect")
+        && let ExprKind::MethodCall(lock, lock_recv, [], _) = lock_call.kind
+        && lock.ident.as_str() == "lock"
+    {
+        accessed_field(cx, arc_clone_source(cx, lock_recv))
+    } else {
+        None
+    }
+}
+
+/// Returns the struct field accessed by `expr`, if any.
+fn accessed_field(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<LocalDefId> {
+    if let ExprKind::Field(base, _) = expr.kind
+        && let Some(adt) = cx.typeck_results().expr_ty_adjusted(base).peel_refs().ty_adt_def()
+        && adt.is_struct()
+    {
+        let index = cx.typeck_results().field_index(expr.hir_id);
+        adt.non_enum_variant().fields[index].did.as_local()
+    } else {
+        None
+    }
+}
+
+/// Checks if the field access `expr` is only used to lock the collection and call a method on
+/// the guard, either directly or through clones of the `Arc` and locals bound to the guard. Any
+/// other use, e.g. passing the `Arc` or the guard to a function, lets the collection escape.
+fn is_only_locked<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> bool {
+    match get_parent_expr(cx, expr).map(|parent| (parent, parent.kind)) {
+        Some((parent, ExprKind::MethodCall(lock, recv, [], _))) if recv.hir_id == expr.hir_id => {
+            if lock.ident.as_str() == "lock" {
+                is_guard_only_called(cx, parent)
+            } else {
+                fn_def_id(cx, parent).is_some_and(|id| is_diag_trait_item(cx, id, sym::Clone))
+                    && is_clone_only_locked(cx, parent)
+            }
+        },
+        Some((borrow, ExprKind::AddrOf(..))) => get_parent_expr(cx, borrow).is_some_and(|call| {
+            matches!(call.kind, ExprKind::Call(_, [_]))
+                && fn_def_id(cx, call).is_some_and(|id| is_diag_trait_item(cx, id, sym::Clone))
+                && is_clone_only_locked(cx, call)
+        }),
+        _ => false,
+    }
+}
+
+/// Checks if `lock_call` is unwrapped and the guard is only used as the receiver of method
+/// calls, either directly or through a local.
+fn is_guard_only_called<'tcx>(cx: &LateContext<'tcx>, lock_call: &'tcx Expr<'tcx>) -> bool {
+    let Some(unwrap_call) = get_parent_expr(cx, lock_call) else {
+        return false;
+    };
+    if let ExprKind::MethodCall(unwrap, recv, ..) = unwrap_call.kind
+        && recv.hir_id == lock_call.hir_id
+        && matches!(unwrap.ident.as_str(), "unwrap" | "expect")
+    {
+        if let Some(parent) = get_parent_expr(cx, unwrap_call) {
+            matches!(parent.kind, ExprKind::MethodCall(_, recv, ..) if recv.hir_id == unwrap_call.hir_id)
+        } else {
+            local_uses_all(cx, unwrap_call, |use_expr| {
+                get_parent_expr(cx, use_expr).is_some_and(
+                    |parent| matches!(parent.kind, ExprKind::MethodCall(_, recv, ..) if recv.hir_id == use_expr.hir_id),
+                )
+            })
+        }
+    } else {
+        false
+    }
+}
+
+/// Checks if the clone of the `Arc` is bound to a local which is only locked.
+fn is_clone_only_locked<'tcx>(cx: &LateContext<'tcx>, clone_call: &'tcx Expr<'tcx>) -> bool {
+    local_uses_all(cx, clone_call, |use_expr| {
+        get_parent_expr(cx, use_expr).is_some_and(|parent| {
+            matches!(parent.kind, ExprKind::MethodCall(lock, recv, [], _)
+                if recv.hir_id == use_expr.hir_id && lock.ident.as_str() == "lock")
+                && is_guard_only_called(cx, parent)
+        })
+    })
+}
+
+/// If `init` initializes a local, e.g. `let x = init;`, checks if every use of the local
+/// satisfies `f`.
+fn local_uses_all<'tcx>(
+    cx: &LateContext<'tcx>,
+    init: &'tcx Expr<'tcx>,
+    mut f: impl FnMut(&'tcx Expr<'tcx>) -> bool,
+) -> bool {
+    if let Node::LetStmt(LetStmt {
+        pat,
+        init: Some(local_init),
+        ..
+    }) = cx.tcx.parent_hir_node(init.hir_id)
+        && local_init.hir_id == init.hir_id
+        && let PatKind::Binding(BindingMode(..), local_id, _, None) = pat.kind
+        && let Some(body) = cx
+            .tcx
+            .hir()
+            .maybe_body_owned_by(cx.tcx.hir().enclosing_body_owner(local_id))
+    {
+        for_each_expr(cx, body.value, |e| {
+            if path_to_local_id(e, local_id) && !f(e) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_none()
+    } else {
+        false
+    }
+}
@@ -0,0 +1,316 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::{SpanRangeExt, position_before_rarrow, snippet_block, snippet_with_applicability};
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{def_path_def_ids, path_def_id, std_or_core};
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, DefIdSet, LocalDefId};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{
+    Body, Closure, ClosureKind, CoroutineDesugaring, CoroutineKind, CoroutineSource, Expr, ExprKind, FnDecl, FnRetTy,
+    GenericArg, LangItem, Node, Path, QPath, TyKind,
+};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, TyCtxt};
+use rustc_session::impl_lint_pass;
+use rustc_span::{Span, sym};
+use std::ops::ControlFlow;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions and trait methods returning a boxed future type alias like
+    /// `futures::future::BoxFuture` whose body is only `Box::pin(async move { .. })`.
+    ///
+    /// The type aliases can be configured with `boxed-future-aliases`.
+    ///
+    /// ### Why is this bad?
+    /// Boxing the future allocates. Since Rust 1.75, trait methods can be `async fn`, and other
+    /// functions can return `impl Future` instead.
+    ///
+    /// ### Known problems
+    /// Unlike a boxed future, `async fn` in traits makes the trait incompatible with `dyn`, and
+    /// doesn't require the returned future to be `Send`. Callers may also depend on the returned
+    /// future being boxed, e.g. to store futures returned by different functions together. Functions
+    /// which call themselves in the `async` block need the box and are not linted.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use futures::future::BoxFuture;
+    ///
+    /// trait Fetch {
+    ///     fn fetch(&self) -> BoxFuture<'_, u32> {
+    ///         Box::pin(async move { 42 })
+    ///     }
+    /// }
+    ///
+    /// fn fetch(x: &u32) -> BoxFuture<'_, u32> {
+    ///     Box::pin(async move { *x })
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// trait Fetch {
+    ///     async fn fetch(&self) -> u32 {
+    ///         42
+    ///     }
+    /// }
+    ///
+    /// fn fetch(x: &u32) -> impl std::future::Future<Output = u32> + Send + '_ {
+    ///     async move { *x }
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub ASYNC_FN_RETURNING_BOXED_FUTURE_ALIAS,
+    pedantic,
+    "returning a boxed future type alias from a single `async` block"
+}
+
+pub struct AsyncFnReturningBoxedFutureAlias {
+    msrv: Msrv,
+    aliases: DefIdSet,
+}
+
+impl AsyncFnReturningBoxedFutureAlias {
+    pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
+        Self {
+            msrv: conf.msrv.clone(),
+            aliases: conf
+                .boxed_future_aliases
+                .iter()
+                .flat_map(|path| def_path_def_ids(tcx, &path.split("::").collect::<Vec<_>>()))
+                .collect(),
+        }
+    }
+}
+
+impl_lint_pass!(AsyncFnReturningBoxedFutureAlias => [ASYNC_FN_RETURNING_BOXED_FUTURE_ALIAS]);
+
+/// The kind of function returning the boxed future.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FnContainer {
+    /// A free function or an inherent method, which can return `impl Future`.
+    Free,
+    /// A provided method of a trait.
+    Trait,
+    /// A method in an implementation of a local trait.
+    TraitImpl,
+}
+
+impl<'tcx> LateLintPass<'tcx> for AsyncFnReturningBoxedFutureAlias {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        fn_def_id: LocalDefId,
+    ) {
+        if let Some(header) = kind.header()
+            && !header.asyncness.is_async()
+            && let FnRetTy::Return(ret_ty) = decl.output
+            && let TyKind::Path(QPath::Resolved(None, path)) = ret_ty.kind
+            && let Res::Def(DefKind::TyAlias, alias_def_id) = path.res
+            && self.aliases.contains(&alias_def_id)
+            && !span.from_expansion()
+            && !in_external_macro(cx.sess(), span)
+            && let Some(container) = fn_container(cx, fn_def_id)
+            && (container == FnContainer::Free || self.msrv.meets(msrvs::ASYNC_FN_IN_TRAIT))
+            && let Some(alias) = BoxedFutureAlias::new(cx, alias_def_id, path)
+            && let ExprKind::Block(block, _) = body.value.kind
+            && block.stmts.is_empty()
+            && let Some(box_pin) = block.expr
+            && let Some((future, async_body)) = boxed_async_block(cx, box_pin)
+            && !calls_fn(cx, async_body.value, fn_def_id.to_def_id())
+        {
+            let header_span = span.with_hi(ret_ty.span.hi());
+            let alias_name = path.segments.last().map_or("", |segment| segment.ident.as_str());
+            span_lint_and_then(
+                cx,
+                ASYNC_FN_RETURNING_BOXED_FUTURE_ALIAS,
+                header_span,
+                format!("this function returns a `{alias_name}` built from a single `async` block"),
+                |diag| {
+                    let mut applicability = Applicability::MaybeIncorrect;
+                    let output = snippet_with_applicability(cx, alias.output.span, "..", &mut applicability);
+                    if container == FnContainer::Free {
+                        let future_trait = if cx.tcx.sess.edition().at_least_rust_2024() {
+                            String::from("Future")
+                        } else if let Some(std_or_core) = std_or_core(cx) {
+                            format!("{std_or_core}::future::Future")
+                        } else {
+                            return;
+                        };
+                        let future = snippet_with_applicability(cx, future.span, "..", &mut applicability);
+                        diag.multipart_suggestion(
+                            "return `impl Future` and the `async` block directly",
+                            vec![
+                                (
+                                    ret_ty.span,
+                                    format!("impl {future_trait}<Output = {output}>{}", alias.bounds),
+                                ),
+                                (box_pin.span, future.into_owned()),
+                            ],
+                            applicability,
+                        );
+                        return;
+                    }
+
+                    if let Some(header_snip) = header_span.get_source_text(cx)
+                        && let Some(ret_pos) = position_before_rarrow(&header_snip)
+                    {
+                        let ret = if let TyKind::Tup([]) = alias.output.kind {
+                            String::new()
+                        } else {
+                            format!(" -> {output}")
+                        };
+                        let body_snip = snippet_block(cx, async_body.value.span, "..", Some(block.span));
+                        diag.multipart_suggestion(
+                            "make the method `async` and return the output of the future directly",
+                            vec![
+                                (header_span, format!("async {}{ret}", &header_snip[..ret_pos])),
+                                (block.span, body_snip.into_owned()),
+                            ],
+                            applicability,
+                        );
+                    }
+                    if alias.is_send {
+                        diag.note(
+                            "`async fn` in traits can't be used with `dyn` and doesn't require the future to be `Send`",
+                        );
+                    } else {
+                        diag.note("`async fn` in traits can't be used with `dyn`");
+                    }
+                    if container == FnContainer::TraitImpl {
+                        diag.note("the trait definition and its other implementations need the same change");
+                    }
+                },
+            );
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+fn fn_container(cx: &LateContext<'_>, fn_def_id: LocalDefId) -> Option<FnContainer> {
+    match cx.tcx.hir_node_by_def_id(fn_def_id) {
+        Node::Item(_) => Some(FnContainer::Free),
+        Node::TraitItem(_) => Some(FnContainer::Trait),
+        Node::ImplItem(_) => match cx.tcx.associated_item(fn_def_id).trait_item_def_id {
+            // The signature of a foreign trait can't be changed
+            Some(trait_item_def_id) => trait_item_def_id.is_local().then_some(FnContainer::TraitImpl),
+            None => Some(FnContainer::Free),
+        },
+        _ => None,
+    }
+}
+
+/// A use of a boxed future type alias, e.g. `futures::future::BoxFuture<'a, T>`.
+struct BoxedFutureAlias<'tcx> {
+    /// The argument of the alias used as the output of the future.
+    output: &'tcx rustc_hir::Ty<'tcx>,
+    /// The auto traits and the lifetime of the boxed future, e.g. ` + Send + 'a`.
+    bounds: String,
+    is_send: bool,
+}
+
+impl<'tcx> BoxedFutureAlias<'tcx> {
+    fn new(cx: &LateContext<'tcx>, alias_def_id: DefId, path: &'tcx Path<'tcx>) -> Option<Self> {
+        let ty = cx.tcx.type_of(alias_def_id).instantiate_identity();
+        let ty::Adt(_, pin_args) = ty.kind() else {
+            return None;
+        };
+        if !is_type_lang_item(cx, ty, LangItem::Pin) {
+            return None;
+        }
+        let ty::Dynamic(preds, region, _) = pin_args.type_at(0).boxed_ty()?.kind() else {
+            return None;
+        };
+        let lang_items = cx.tcx.lang_items();
+        if preds.principal_def_id() != lang_items.future_trait() {
+            return None;
+        }
+        let args = path.segments.last()?.args?.args;
+        let ty::Param(output_param) = preds
+            .projection_bounds()
+            .find(|p| lang_items.future_output() == Some(p.item_def_id()))?
+            .skip_binder()
+            .term
+            .as_type()?
+            .kind()
+        else {
+            return None;
+        };
+        let GenericArg::Type(output) = args.get(output_param.index as usize)? else {
+            return None;
+        };
+
+        let send = cx.tcx.get_diagnostic_item(sym::Send);
+        let mut bounds = String::new();
+        for auto_trait in preds.auto_traits() {
+            bounds.push_str(" + ");
+            bounds.push_str(cx.tcx.item_name(auto_trait).as_str());
+        }
+        let lifetime = match region.kind() {
+            ty::ReStatic => "'static",
+            ty::ReEarlyParam(param) => match args.get(param.index as usize)? {
+                GenericArg::Lifetime(lifetime) if lifetime.is_anonymous() => "'_",
+                GenericArg::Lifetime(lifetime) => lifetime.ident.as_str(),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        bounds.push_str(" + ");
+        bounds.push_str(lifetime);
+
+        Some(Self {
+            output,
+            bounds,
+            is_send: preds.auto_traits().any(|auto_trait| Some(auto_trait) == send),
+        })
+    }
+}
+
+/// If `expr` is `Box::pin(async { .. })`, returns the `async` block and its body.
+fn boxed_async_block<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Body<'tcx>)> {
+    if let ExprKind::Call(box_pin, [future]) = expr.kind
+        && let ExprKind::Path(QPath::TypeRelative(ty, seg)) = box_pin.kind
+        && seg.ident.name.as_str() == "pin"
+        && path_def_id(cx, ty).is_some_and(|did| cx.tcx.lang_items().owned_box() == Some(did))
+        && let ExprKind::Closure(&Closure {
+            kind: ClosureKind::Coroutine(CoroutineKind::Desugared(CoroutineDesugaring::Async, CoroutineSource::Block)),
+            body,
+            ..
+        }) = future.kind
+        && !future.span.from_expansion()
+    {
+        Some((future, cx.tcx.hir().body(body)))
+    } else {
+        None
+    }
+}
+
+/// Whether the body of the `async` block calls `fn_def_id`, in which case the box is required to
+/// make the recursive future have a finite size.
+fn calls_fn<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'tcx>, fn_def_id: DefId) -> bool {
+    for_each_expr(cx, body, |e| {
+        let callee = match e.kind {
+            ExprKind::Call(callee, _) => path_def_id(cx, callee),
+            ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(e.hir_id),
+            _ => None,
+        };
+        if callee == Some(fn_def_id) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
@@ -9,6 +9,7 @@ mod mixed_attributes_style;
 mod non_minimal_cfg;
 mod repr_attributes;
 mod should_panic_without_expect;
+mod undocumented_clippy_allow;
 mod unnecessary_clippy_cfg;
 mod useless_attribute;
 mod utils;
@@ -16,6 +17,7 @@ mod utils;
 use clippy_config::Conf;
 use clippy_utils::msrvs::{self, Msrv};
 use rustc_ast::{self as ast, Attribute, MetaItemInner, MetaItemKind};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_hir::{ImplItem, Item, TraitItem};
 use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass};
 use rustc_session::impl_lint_pass;
@@ -203,6 +205,44 @@ declare_clippy_lint! {
     "ensures that all `allow` and `expect` attributes have a reason"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `#[allow(clippy::..)]` and `#[expect(clippy::..)]` attributes which neither have
+    /// a reason nor a comment after the attribute on the same line.
+    ///
+    /// Lints and lint groups can be exempted with the `undocumented-clippy-allow-exempt`
+    /// configuration.
+    ///
+    /// ### Why restrict this?
+    /// Documenting why a Clippy lint is allowed makes it easier to audit the suppressions and to
+    /// remove the ones which are not needed anymore.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[allow(clippy::needless_return)]
+    /// fn f() -> u32 {
+    ///     return 1;
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// #[allow(clippy::needless_return, reason = "generated by a macro in the real code")]
+    /// fn f() -> u32 {
+    ///     return 1;
+    /// }
+    ///
+    /// #[allow(clippy::needless_return)] // kept for symmetry with the other branches
+    /// fn g() -> u32 {
+    ///     return 2;
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub UNDOCUMENTED_CLIPPY_ALLOW,
+    restriction,
+    "`#[allow(clippy::..)]` without a reason or a comment"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for usage of the `#[allow]` attribute and suggests replacing it with
@@ -520,12 +560,25 @@ impl EarlyLintPass for EarlyAttributes {
 
 pub struct PostExpansionEarlyAttributes {
     msrv: Msrv,
+    /// The lints which are exempt from `undocumented_clippy_allow`, including the lints of exempt
+    /// groups and the group names themselves
+    undocumented_allow_exempt: FxHashSet<String>,
 }
 
 impl PostExpansionEarlyAttributes {
     pub fn new(conf: &'static Conf) -> Self {
+        let exempt: Vec<&str> = conf
+            .undocumented_clippy_allow_exempt
+            .iter()
+            .map(|name| name.strip_prefix("clippy::").unwrap_or(name))
+            .collect();
+        let exempt_lints = crate::declared_lints::LINTS
+            .iter()
+            .filter(|info| exempt.contains(&info.category_str()))
+            .map(|info| info.name_lower());
         Self {
             msrv: conf.msrv.clone(),
+            undocumented_allow_exempt: exempt.iter().map(ToString::to_string).chain(exempt_lints).collect(),
         }
     }
 }
@@ -539,6 +592,7 @@ impl_lint_pass!(PostExpansionEarlyAttributes => [
     SHOULD_PANIC_WITHOUT_EXPECT,
     MIXED_ATTRIBUTES_STYLE,
     DUPLICATED_ATTRIBUTES,
+    UNDOCUMENTED_CLIPPY_ALLOW,
 ]);
 
 impl EarlyLintPass for PostExpansionEarlyAttributes {
@@ -557,6 +611,9 @@ impl EarlyLintPass for PostExpansionEarlyAttributes {
                 {
                     allow_attributes_without_reason::check(cx, ident.name, items, attr);
                 }
+                if matches!(ident.name, sym::allow | sym::expect) {
+                    undocumented_clippy_allow::check(cx, ident.name, items, attr, &self.undocumented_allow_exempt);
+                }
                 if is_lint_level(ident.name, attr.id) {
                     blanket_clippy_restriction_lints::check(cx, ident.name, items);
                 }
@@ -0,0 +1,61 @@
+use super::UNDOCUMENTED_CLIPPY_ALLOW;
+use super::utils::extract_clippy_lint;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::is_from_proc_macro;
+use clippy_utils::source::SpanRangeExt;
+use rustc_ast::{Attribute, MetaItemInner, MetaItemKind};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_lint::{EarlyContext, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_span::{Symbol, sym};
+
+pub(super) fn check(
+    cx: &EarlyContext<'_>,
+    name: Symbol,
+    items: &[MetaItemInner],
+    attr: &Attribute,
+    exempt: &FxHashSet<String>,
+) {
+    if let Some(item) = items.last().and_then(MetaItemInner::meta_item)
+        && let MetaItemKind::NameValue(_) = &item.kind
+        && item.path == sym::reason
+    {
+        return;
+    }
+
+    let Some(lint) = items
+        .iter()
+        .filter_map(extract_clippy_lint)
+        .find(|lint| !exempt.contains(lint.as_str()))
+    else {
+        return;
+    };
+
+    if in_external_macro(cx.sess(), attr.span) || is_from_proc_macro(cx, attr) || has_trailing_comment(cx, attr) {
+        return;
+    }
+
+    span_lint_and_help(
+        cx,
+        UNDOCUMENTED_CLIPPY_ALLOW,
+        attr.span,
+        format!(
+            "`clippy::{lint}` is {} without a reason",
+            if name == sym::expect { "expected" } else { "allowed" }
+        ),
+        None,
+        "add a reason with `, reason = \"..\"` or explain it in a comment after the attribute",
+    );
+}
+
+/// Checks for a comment after the attribute on the same line, e.g. `#[allow(clippy::lint)] // why`
+fn has_trailing_comment(cx: &EarlyContext<'_>, attr: &Attribute) -> bool {
+    attr.span
+        .with_source_text_and_range(cx, |src, range| {
+            src[range.end..]
+                .lines()
+                .next()
+                .is_some_and(|rest| rest.trim_start().starts_with("//"))
+        })
+        .unwrap_or(false)
+}
@@ -2,13 +2,15 @@ mod common_metadata;
 mod feature_name;
 mod lint_groups_priority;
 mod multiple_crate_versions;
+mod redundant_feature_cfg_in_code;
 mod wildcard_dependencies;
 
 use cargo_metadata::MetadataCommand;
 use clippy_config::Conf;
 use clippy_utils::diagnostics::span_lint;
 use clippy_utils::is_lint_allowed;
-use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::fx::{FxHashSet, FxIndexSet};
+use rustc_hir::Attribute;
 use rustc_hir::hir_id::CRATE_HIR_ID;
 use rustc_lint::{LateContext, LateLintPass, Lint};
 use rustc_session::impl_lint_pass;
@@ -204,9 +206,48 @@ declare_clippy_lint! {
     "a lint group in `Cargo.toml` at the same priority as a lint"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `#[cfg(feature = "x")]` attributes where the feature `x` is always enabled in
+    /// the workspace: it's enabled by the `default` feature of the package, and every package of
+    /// the workspace depending on it either keeps the default features or enables `x` itself.
+    ///
+    /// ### Why is this bad?
+    /// The attribute has no effect, and suggests that the code can be disabled when it can't.
+    ///
+    /// ### Known problems
+    /// Packages outside of the workspace, e.g. users of a published crate, may still disable the
+    /// default features. The lint is meant for crates which are only used in their workspace.
+    ///
+    /// ### Example
+    /// ```toml
+    /// [features]
+    /// default = ["std"]
+    /// std = []
+    /// ```
+    /// ```rust,ignore
+    /// #[cfg(feature = "std")]
+    /// fn now() -> std::time::Instant {
+    ///     std::time::Instant::now()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// fn now() -> std::time::Instant {
+    ///     std::time::Instant::now()
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub REDUNDANT_FEATURE_CFG_IN_CODE,
+    cargo,
+    "a `cfg` on a feature which is always enabled in the workspace"
+}
+
 pub struct Cargo {
-    allowed_duplicate_crates: FxHashSet<String>,
+    allowed_duplicate_crates: FxHashSet<&'static str>,
     ignore_publish: bool,
+    /// The features of the crate which are enabled however it's built in the workspace.
+    always_enabled_features: FxIndexSet<String>,
 }
 
 impl_lint_pass!(Cargo => [
@@ -216,24 +257,27 @@ impl_lint_pass!(Cargo => [
     MULTIPLE_CRATE_VERSIONS,
     WILDCARD_DEPENDENCIES,
     LINT_GROUPS_PRIORITY,
+    REDUNDANT_FEATURE_CFG_IN_CODE,
 ]);
 
 impl Cargo {
     pub fn new(conf: &'static Conf) -> Self {
         Self {
-            allowed_duplicate_crates: conf.allowed_duplicate_crates.iter().cloned().collect(),
+            allowed_duplicate_crates: conf.allowed_duplicate_crates.iter().map(|s| &**s).collect(),
             ignore_publish: conf.cargo_ignore_publish,
+            always_enabled_features: FxIndexSet::default(),
         }
     }
 }
 
-impl LateLintPass<'_> for Cargo {
-    fn check_crate(&mut self, cx: &LateContext<'_>) {
+impl<'tcx> LateLintPass<'tcx> for Cargo {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
         static NO_DEPS_LINTS: &[&Lint] = &[
             CARGO_COMMON_METADATA,
             REDUNDANT_FEATURE_NAMES,
             NEGATIVE_FEATURE_NAMES,
             WILDCARD_DEPENDENCIES,
+            REDUNDANT_FEATURE_CFG_IN_CODE,
         ];
         static WITH_DEPS_LINTS: &[&Lint] = &[MULTIPLE_CRATE_VERSIONS];
 
@@ -248,6 +292,8 @@ impl LateLintPass<'_> for Cargo {
                     common_metadata::check(cx, &metadata, self.ignore_publish);
                     feature_name::check(cx, &metadata);
                     wildcard_dependencies::check(cx, &metadata);
+                    self.always_enabled_features =
+                        redundant_feature_cfg_in_code::always_enabled_features(cx, &metadata);
                 },
                 Err(e) => {
                     for lint in NO_DEPS_LINTS {
@@ -273,4 +319,10 @@ impl LateLintPass<'_> for Cargo {
             }
         }
     }
+
+    fn check_attribute(&mut self, cx: &LateContext<'tcx>, attr: &'tcx Attribute) {
+        if !self.always_enabled_features.is_empty() {
+            redundant_feature_cfg_in_code::check(cx, attr, &self.always_enabled_features);
+        }
+    }
 }
@@ -8,7 +8,7 @@ use rustc_span::DUMMY_SP;
 
 use super::MULTIPLE_CRATE_VERSIONS;
 
-pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata, allowed_duplicate_crates: &FxHashSet<String>) {
+pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata, allowed_duplicate_crates: &FxHashSet<&'static str>) {
     let local_name = cx.tcx.crate_name(LOCAL_CRATE);
     let mut packages = metadata.packages.clone();
     packages.sort_by(|a, b| a.name.cmp(&b.name));
@@ -32,7 +32,7 @@ pub(super) fn check(cx: &LateContext<'_>, metadata: &Metadata, allowed_duplicate
     {
         for (name, group) in &packages
             .iter()
-            .filter(|p| !allowed_duplicate_crates.contains(&p.name))
+            .filter(|p| !allowed_duplicate_crates.contains(p.name.as_str()))
             .group_by(|p| &p.name)
         {
             let group: Vec<&Package> = group.collect();
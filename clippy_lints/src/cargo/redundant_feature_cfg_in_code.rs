@@ -0,0 +1,80 @@
+use cargo_metadata::{Metadata, Package};
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_data_structures::fx::FxIndexSet;
+use rustc_errors::Applicability;
+use rustc_hir::Attribute;
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::REDUNDANT_FEATURE_CFG_IN_CODE;
+
+/// Returns the features of the local package which are enabled however it's built in the
+/// workspace: by itself with its default features, and as a dependency of the other packages.
+pub(super) fn always_enabled_features(cx: &LateContext<'_>, metadata: &Metadata) -> FxIndexSet<String> {
+    let local_name = cx.tcx.crate_name(LOCAL_CRATE);
+    let Some(local) = metadata
+        .packages
+        .iter()
+        .find(|p| p.name.replace('-', "_") == local_name.as_str())
+    else {
+        return FxIndexSet::default();
+    };
+
+    let mut enabled = enabled_features(local, ["default"]);
+    for package in &metadata.packages {
+        for dep in package.dependencies.iter().filter(|dep| dep.name == local.name) {
+            let dep_enabled = enabled_features(
+                local,
+                dep.features
+                    .iter()
+                    .map(String::as_str)
+                    .chain(dep.uses_default_features.then_some("default")),
+            );
+            enabled.retain(|feature| dep_enabled.contains(feature));
+        }
+    }
+    enabled
+}
+
+/// Returns the features of `package` enabled by enabling `features`, including the features they
+/// enable in turn.
+fn enabled_features<'a>(package: &Package, features: impl IntoIterator<Item = &'a str>) -> FxIndexSet<String> {
+    let mut enabled = FxIndexSet::default();
+    let mut stack: Vec<&str> = features.into_iter().collect();
+    while let Some(feature) = stack.pop() {
+        // Features of dependencies, e.g. `dep:serde` or `serde/std`, can't be tested with `cfg`
+        if let Some(implied) = package.features.get(feature)
+            && enabled.insert(feature.to_owned())
+        {
+            stack.extend(
+                implied
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|implied| !implied.starts_with("dep:") && !implied.contains('/')),
+            );
+        }
+    }
+    enabled
+}
+
+pub(super) fn check(cx: &LateContext<'_>, attr: &Attribute, always_enabled_features: &FxIndexSet<String>) {
+    if attr.has_name(sym::cfg)
+        && !attr.span.from_expansion()
+        && let Some(items) = attr.meta_item_list()
+        && let [item] = &*items
+        && item.has_name(sym::feature)
+        && let Some(feature) = item.value_str()
+        && always_enabled_features.contains(feature.as_str())
+    {
+        span_lint_and_then(
+            cx,
+            REDUNDANT_FEATURE_CFG_IN_CODE,
+            attr.span,
+            format!("the feature `{feature}` is always enabled in this workspace"),
+            |diag| {
+                diag.span_suggestion(attr.span, "remove the attribute", "", Applicability::MaybeIncorrect);
+            },
+        );
+    }
+}
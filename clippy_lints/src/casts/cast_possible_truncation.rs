@@ -1,6 +1,7 @@
 use clippy_utils::consts::{ConstEvalCtxt, Constant};
 use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
 use clippy_utils::expr_or_init;
+use clippy_utils::rinterval::{Interval, IntervalCtxt, type_range};
 use clippy_utils::source::snippet;
 use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::{get_discriminant_value, is_isize_or_usize};
@@ -8,7 +9,7 @@ use rustc_errors::{Applicability, Diag};
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::{BinOpKind, Expr, ExprKind};
 use rustc_lint::LateContext;
-use rustc_middle::ty::{self, FloatTy, Ty};
+use rustc_middle::ty::{self, FloatTy, IntTy, Ty, UintTy};
 use rustc_span::Span;
 use rustc_target::abi::IntegerType;
 
@@ -91,8 +92,23 @@ pub(super) fn check(
     cast_to: Ty<'_>,
     cast_to_span: Span,
 ) {
+    let mut range = None;
     let msg = match (cast_from.kind(), cast_to.is_integral()) {
         (ty::Int(_) | ty::Uint(_), true) => {
+            // Casts of constants are still linted, the constant can be written with the target type.
+            if ConstEvalCtxt::new(cx).eval(cast_expr).is_none() {
+                range = IntervalCtxt::new(cx).eval(cast_expr);
+            }
+            if let Some(range) = range
+                && fits_in(cx, range, cast_to)
+            {
+                return;
+            }
+            // Only mention the range if it says more than the type.
+            if range.is_some() && range == type_range(cx, cast_from) {
+                range = None;
+            }
+
             let from_nbits = apply_reductions(
                 cx,
                 utils::int_ty_to_nbits(cast_from, cx.tcx),
@@ -170,12 +186,30 @@ pub(super) fn check(
 
     span_lint_and_then(cx, CAST_POSSIBLE_TRUNCATION, expr.span, msg, |diag| {
         diag.help("if this is intentional allow the lint with `#[allow(clippy::cast_possible_truncation)]` ...");
+        if let Some(range) = range {
+            if range.lo == range.hi {
+                diag.note(format!("the value is always `{range}`"));
+            } else {
+                diag.note(format!("the value is in the range `{range}`"));
+            }
+        }
         if !cast_from.is_floating_point() {
             offer_suggestion(cx, expr, cast_expr, cast_to_span, diag);
         }
     });
 }
 
+/// Whether every value of `range` can be represented by `ty`, on targets with 32-bit or 64-bit wide
+/// pointers.
+fn fits_in(cx: &LateContext<'_>, range: Interval, ty: Ty<'_>) -> bool {
+    match *ty.kind() {
+        ty::Uint(UintTy::U128) => range.lo >= 0,
+        ty::Uint(UintTy::Usize) => range.is_subset_of(Interval::new(0, u32::MAX.into())),
+        ty::Int(IntTy::Isize) => range.is_subset_of(Interval::new(i32::MIN.into(), i32::MAX.into())),
+        _ => type_range(cx, ty).is_some_and(|to| range.is_subset_of(to)),
+    }
+}
+
 fn offer_suggestion(
     cx: &LateContext<'_>,
     expr: &Expr<'_>,
@@ -0,0 +1,283 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::rinterval::{Interval, IntervalCtxt};
+use clippy_utils::source::snippet;
+use clippy_utils::{higher, is_never_expr, path_to_local, path_to_local_id};
+use rustc_ast::{LitKind, RangeLimits};
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId, Node, PatKind, RangeEnd, StmtKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, UintTy};
+
+use super::CHAR_ARITHMETIC_AS_U8;
+
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    if expr.span.from_expansion() {
+        return;
+    }
+    match expr.kind {
+        ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::Sub => check_char_minus_byte(cx, expr, lhs, rhs),
+        ExprKind::Cast(inner, _) if cx.typeck_results().expr_ty(expr).is_char() => check_byte_plus_as_char(cx, inner),
+        _ => {},
+    }
+}
+
+/// Checks for `c as u8 - b'a'`.
+fn check_char_minus_byte(cx: &LateContext<'_>, expr: &Expr<'_>, lhs: &Expr<'_>, rhs: &Expr<'_>) {
+    if let ExprKind::Cast(c, _) = lhs.kind
+        && !matches!(c.kind, ExprKind::Lit(_))
+        && cx.typeck_results().expr_ty(c).is_char()
+        && ty::Uint(UintTy::U8) == *cx.typeck_results().expr_ty(lhs).kind()
+        && let Some((start, range, check)) = ascii_range(rhs)
+        && !path_to_local(c).is_some_and(|local| {
+            guarded_range(cx, expr.hir_id, local).is_some_and(|guarded| guarded.is_subset_of(range))
+        })
+    {
+        let c = snippet(cx, c.span, "c");
+        span_lint_and_then(
+            cx,
+            CHAR_ARITHMETIC_AS_U8,
+            expr.span,
+            format!("subtracting `b'{start}'` from a `char` cast to `u8` without checking its range"),
+            |diag| {
+                diag.note(format!(
+                    "characters outside of `'{start}'..='{}'` produce wrong results or overflow",
+                    display_char(range.hi)
+                ));
+                if start == '0' {
+                    diag.help(format!(
+                        "use `{c}.to_digit(10)` instead, which returns `None` for characters that aren't digits"
+                    ));
+                } else {
+                    diag.help(format!(
+                        "check `{c}.{check}()` first, or use `{c}.to_digit(36)`, which maps letters to `10..=35`"
+                    ));
+                }
+            },
+        );
+    }
+}
+
+/// Checks for `(n + b'a') as char`.
+fn check_byte_plus_as_char(cx: &LateContext<'_>, sum: &Expr<'_>) {
+    if let ExprKind::Binary(op, lhs, rhs) = sum.kind
+        && op.node == BinOpKind::Add
+        && ty::Uint(UintTy::U8) == *cx.typeck_results().expr_ty(sum).kind()
+        && let Some((n, (start, range, _))) = ascii_range(rhs)
+            .map(|range| (lhs, range))
+            .or_else(|| ascii_range(lhs).map(|range| (rhs, range)))
+        && !IntervalCtxt::new(cx)
+            .eval(n)
+            .is_some_and(|offset| offset.is_subset_of(Interval::new(0, range.hi - range.lo)))
+    {
+        let max_offset = range.hi - range.lo;
+        span_lint_and_then(
+            cx,
+            CHAR_ARITHMETIC_AS_U8,
+            sum.span,
+            format!("adding a value to `b'{start}'` which may produce a character outside of its range"),
+            |diag| {
+                if start == '0' {
+                    let digit = match n.kind {
+                        ExprKind::Cast(inner, _)
+                            if ty::Uint(UintTy::U32) == *cx.typeck_results().expr_ty(inner).kind() =>
+                        {
+                            snippet(cx, inner.span, "..").into_owned()
+                        },
+                        _ => format!("u32::from({})", snippet(cx, n.span, "..")),
+                    };
+                    diag.help(format!(
+                        "use `char::from_digit({digit}, 10)` instead, which returns `None` for values above 9"
+                    ));
+                } else {
+                    diag.help(format!(
+                        "check that `{}` is at most {max_offset} first",
+                        snippet(cx, n.span, "..")
+                    ));
+                }
+            },
+        );
+    }
+}
+
+/// If `expr` is `b'0'`, `b'a'` or `b'A'`, returns the character, the range of characters starting
+/// at it and the `char` method checking for that range.
+fn ascii_range(expr: &Expr<'_>) -> Option<(char, Interval, &'static str)> {
+    if let ExprKind::Lit(lit) = expr.kind
+        && let LitKind::Byte(byte) = lit.node
+    {
+        let (end, check) = match byte {
+            b'0' => (b'9', "is_ascii_digit"),
+            b'a' => (b'z', "is_ascii_lowercase"),
+            b'A' => (b'Z', "is_ascii_uppercase"),
+            _ => return None,
+        };
+        Some((byte.into(), Interval::new(byte.into(), end.into()), check))
+    } else {
+        None
+    }
+}
+
+fn display_char(value: i128) -> char {
+    u32::try_from(value).ok().and_then(char::from_u32).unwrap_or('?')
+}
+
+/// Computes the range the `char` local `local` is known to be in at `hir_id`, based on the
+/// conditions of enclosing `if` expressions, the patterns of enclosing `match` arms and preceding
+/// `if !cond { return; }` statements.
+fn guarded_range(cx: &LateContext<'_>, hir_id: HirId, local: HirId) -> Option<Interval> {
+    let mut range = None;
+    let mut child = hir_id;
+    for (parent, node) in cx.tcx.hir().parent_iter(hir_id) {
+        let guard = match node {
+            Node::Expr(e) => match e.kind {
+                ExprKind::If(cond, then, _) if then.hir_id == child => condition_range(cond, local),
+                ExprKind::Closure(_) => break,
+                _ => None,
+            },
+            Node::Arm(arm) if arm.body.hir_id == child => {
+                let pat_range = if let Node::Expr(Expr {
+                    kind: ExprKind::Match(scrutinee, ..),
+                    ..
+                }) = cx.tcx.parent_hir_node(parent)
+                    && path_to_local_id(scrutinee, local)
+                {
+                    pattern_range(arm.pat)
+                } else {
+                    None
+                };
+                intersect(pat_range, arm.guard.and_then(|guard| condition_range(guard, local)))
+            },
+            Node::Block(block) => block
+                .stmts
+                .iter()
+                .take_while(|stmt| stmt.hir_id != child)
+                .filter_map(|stmt| match stmt.kind {
+                    StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+                    _ => None,
+                })
+                .filter_map(|e| match e.kind {
+                    ExprKind::If(cond, then, None) if is_never_expr(cx, then).is_some() => {
+                        match cond.peel_drop_temps().kind {
+                            ExprKind::Unary(UnOp::Not, cond) => condition_range(cond, local),
+                            _ => None,
+                        }
+                    },
+                    _ => None,
+                })
+                .reduce(|a, b| intersect(Some(a), Some(b)).unwrap_or(a)),
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => break,
+            _ => None,
+        };
+        range = intersect(range, guard);
+        child = parent;
+    }
+    range
+}
+
+/// Computes the range `local` is known to be in if `cond` is true.
+fn condition_range(cond: &Expr<'_>, local: HirId) -> Option<Interval> {
+    match cond.peel_drop_temps().kind {
+        ExprKind::Binary(op, lhs, rhs) => match op.node {
+            BinOpKind::And => intersect(condition_range(lhs, local), condition_range(rhs, local)),
+            BinOpKind::Lt | BinOpKind::Le | BinOpKind::Gt | BinOpKind::Ge => {
+                let (op, value) = if path_to_local_id(lhs, local) {
+                    (op.node, char_lit(rhs)?)
+                } else if path_to_local_id(rhs, local) {
+                    let op = match op.node {
+                        BinOpKind::Lt => BinOpKind::Gt,
+                        BinOpKind::Le => BinOpKind::Ge,
+                        BinOpKind::Gt => BinOpKind::Lt,
+                        _ => BinOpKind::Le,
+                    };
+                    (op, char_lit(lhs)?)
+                } else {
+                    return None;
+                };
+                match op {
+                    BinOpKind::Lt => Some(Interval::new(0, value.checked_sub(1)?)),
+                    BinOpKind::Le => Some(Interval::new(0, value)),
+                    BinOpKind::Gt => Some(Interval::new(value + 1, u32::from(char::MAX).into())),
+                    _ => Some(Interval::new(value, u32::from(char::MAX).into())),
+                }
+            },
+            _ => None,
+        },
+        ExprKind::MethodCall(method, recv, [], _) if path_to_local_id(recv, local) => {
+            let (start, end) = match method.ident.name.as_str() {
+                "is_ascii_digit" => ('0', '9'),
+                "is_ascii_lowercase" => ('a', 'z'),
+                "is_ascii_uppercase" => ('A', 'Z'),
+                "is_ascii" => ('\0', '\x7f'),
+                _ => return None,
+            };
+            Some(Interval::new(u32::from(start).into(), u32::from(end).into()))
+        },
+        // `('a'..='z').contains(&c)`
+        ExprKind::MethodCall(method, recv, [arg], _)
+            if method.ident.name.as_str() == "contains"
+                && let ExprKind::AddrOf(_, _, arg) = arg.kind
+                && path_to_local_id(arg, local) =>
+        {
+            let range = higher::Range::hir(recv)?;
+            let start = char_lit(range.start?)?;
+            let end = char_lit(range.end?)?;
+            let end = match range.limits {
+                RangeLimits::Closed => end,
+                RangeLimits::HalfOpen => end.checked_sub(1)?,
+            };
+            (start <= end).then(|| Interval::new(start, end))
+        },
+        // `matches!(c, 'a'..='z')`
+        ExprKind::Match(scrutinee, [arm, _], _)
+            if path_to_local_id(scrutinee, local)
+                && arm.guard.is_none()
+                && let ExprKind::Lit(lit) = arm.body.kind
+                && let LitKind::Bool(true) = lit.node =>
+        {
+            pattern_range(arm.pat)
+        },
+        _ => None,
+    }
+}
+
+/// Computes the range of `char` values matched by a pattern made of literals and ranges.
+fn pattern_range(pat: &rustc_hir::Pat<'_>) -> Option<Interval> {
+    match pat.kind {
+        PatKind::Lit(lit) => char_lit(lit).map(Interval::single),
+        PatKind::Range(Some(start), Some(end), range_end) => {
+            let start = char_lit(start)?;
+            let end = match range_end {
+                RangeEnd::Included => char_lit(end)?,
+                RangeEnd::Excluded => char_lit(end)?.checked_sub(1)?,
+            };
+            (start <= end).then(|| Interval::new(start, end))
+        },
+        PatKind::Binding(.., Some(pat)) => pattern_range(pat),
+        PatKind::Or(pats) => pats.iter().map(pattern_range).reduce(|a, b| {
+            let (a, b) = (a?, b?);
+            Some(Interval::new(a.lo.min(b.lo), a.hi.max(b.hi)))
+        })?,
+        _ => None,
+    }
+}
+
+fn char_lit(expr: &Expr<'_>) -> Option<i128> {
+    if let ExprKind::Lit(lit) = expr.kind
+        && let LitKind::Char(c) = lit.node
+    {
+        Some(u32::from(c).into())
+    } else {
+        None
+    }
+}
+
+/// Intersects two optional ranges, where `None` means the range is unknown. An empty intersection
+/// can only happen in unreachable code, in which case any range is fine.
+fn intersect(a: Option<Interval>, b: Option<Interval>) -> Option<Interval> {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let lo = a.lo.max(b.lo);
+            Some(Interval::new(lo, a.hi.min(b.hi).max(lo)))
+        },
+        (a, b) => a.or(b),
+    }
+}
@@ -13,6 +13,7 @@ mod cast_ptr_alignment;
 mod cast_sign_loss;
 mod cast_slice_different_sizes;
 mod cast_slice_from_raw_parts;
+mod char_arithmetic_as_u8;
 mod char_lit_as_u8;
 mod fn_to_numeric_cast;
 mod fn_to_numeric_cast_any;
@@ -20,8 +21,10 @@ mod fn_to_numeric_cast_with_truncation;
 mod ptr_as_ptr;
 mod ptr_cast_constness;
 mod ref_as_ptr;
+mod timestamp_millis_truncation;
 mod unnecessary_cast;
 mod utils;
+mod wrong_transmute_ptr_to_integer_roundtrip;
 mod zero_ptr;
 
 use clippy_config::Conf;
@@ -87,6 +90,9 @@ declare_clippy_lint! {
     /// default. It suggests user either explicitly ignore the lint,
     /// or use `try_from()` and handle the truncation, default, or panic explicitly.
     ///
+    /// Casts of integers whose value provably fits in the target type, e.g. `(x % 100) as u8` or
+    /// `x.min(255) as u8`, are not linted.
+    ///
     /// ### Why is this bad?
     /// In some problem domains, it is good practice to avoid
     /// truncation. This lint can be activated to help assess where additional
@@ -754,6 +760,115 @@ declare_clippy_lint! {
     "detects `as *mut _` and `as *const _` conversion"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for arithmetic converting between characters and their position in an ASCII range,
+    /// such as `c as u8 - b'a'` or `(n + b'0') as char`, where nothing ensures the character or
+    /// the value is in the expected range.
+    ///
+    /// The range of a character is taken from enclosing `if` conditions such as
+    /// `c.is_ascii_lowercase()` or `('a'..='z').contains(&c)`, enclosing `match` arms, and
+    /// preceding `if !cond { return; }` checks. The range of a value added to a byte literal is
+    /// computed from the expression itself, e.g. `n % 10`.
+    ///
+    /// ### Why is this bad?
+    /// Casting a `char` to `u8` truncates it, and subtracting `b'a'` from a character below `'a'`
+    /// overflows. Adding a value which is too large to `b'0'` results in a character which isn't a
+    /// digit. Either way the result is silently wrong in release builds.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn index(c: char) -> u8 {
+    ///     c as u8 - b'a'
+    /// }
+    ///
+    /// fn digit(n: u32) -> char {
+    ///     (n as u8 + b'0') as char
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn index(c: char) -> Option<u8> {
+    ///     if c.is_ascii_lowercase() {
+    ///         Some(c as u8 - b'a')
+    ///     } else {
+    ///         None
+    ///     }
+    /// }
+    ///
+    /// fn digit(n: u32) -> Option<char> {
+    ///     char::from_digit(n, 10)
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub CHAR_ARITHMETIC_AS_U8,
+    pedantic,
+    "arithmetic between a `char` and an ASCII byte literal without checking the range"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Duration`s converted to milliseconds, microseconds or nanoseconds by hand, such
+    /// as `d.as_secs() * 1000 + d.subsec_millis() as u64`, and for Unix timestamps, i.e. durations
+    /// since `UNIX_EPOCH`, cast to an integer type smaller than 64 bits.
+    ///
+    /// ### Why is this bad?
+    /// The manual conversion is harder to read than `as_millis()` and overflows for large
+    /// durations. Casting a timestamp to a smaller integer silently truncates it: the number of
+    /// seconds since the Unix epoch no longer fits in an `i32` in 2038, and the number of
+    /// milliseconds already doesn't fit in a `u32`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::time::{SystemTime, UNIX_EPOCH};
+    /// let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    /// let millis = since_epoch.as_secs() * 1000 + since_epoch.subsec_millis() as u64;
+    /// let secs = since_epoch.as_secs() as i32;
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::time::{SystemTime, UNIX_EPOCH};
+    /// let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    /// let millis = since_epoch.as_millis();
+    /// let secs = since_epoch.as_secs() as i64;
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub TIMESTAMP_MILLIS_TRUNCATION,
+    pedantic,
+    "converting a `Duration` to milliseconds by hand or truncating a Unix timestamp"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for pointers cast to an integer and back, such as `ptr as usize as *mut T` or
+    /// `(ptr as usize + 8) as *mut T`, following `let` bindings of the address. Also checks for
+    /// integers cast to pointers in `unsafe` blocks.
+    ///
+    /// ### Why is this bad?
+    /// A pointer cast from an integer only gets a provenance, i.e. the permission to access some
+    /// memory, through the provenance exposed by earlier casts. This is hard to reason about, and
+    /// isn't supported by tools such as Miri or CHERI. The strict provenance API keeps track of the
+    /// provenance of the pointer: `map_addr` and `with_addr` change the address of a pointer while
+    /// keeping its provenance, and `with_exposed_provenance` makes the remaining casts explicit.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn align_down(ptr: *const u8) -> *const u8 {
+    ///     (ptr as usize & !7) as *const u8
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn align_down(ptr: *const u8) -> *const u8 {
+    ///     ptr.map_addr(|addr| addr & !7)
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub WRONG_TRANSMUTE_PTR_TO_INTEGER_ROUNDTRIP,
+    pedantic,
+    "casting a pointer to an integer and back instead of using the strict provenance API"
+}
+
 pub struct Casts {
     msrv: Msrv,
 }
@@ -792,6 +907,9 @@ impl_lint_pass!(Casts => [
     ZERO_PTR,
     REF_AS_PTR,
     AS_POINTER_UNDERSCORE,
+    CHAR_ARITHMETIC_AS_U8,
+    TIMESTAMP_MILLIS_TRUNCATION,
+    WRONG_TRANSMUTE_PTR_TO_INTEGER_ROUNDTRIP,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Casts {
@@ -819,9 +937,20 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
             fn_to_numeric_cast::check(cx, expr, cast_from_expr, cast_from, cast_to);
             fn_to_numeric_cast_with_truncation::check(cx, expr, cast_from_expr, cast_from, cast_to);
             zero_ptr::check(cx, expr, cast_from_expr, cast_to_hir);
+            wrong_transmute_ptr_to_integer_roundtrip::check(
+                cx,
+                expr,
+                cast_from_expr,
+                cast_from,
+                cast_to,
+                cast_to_hir,
+                &self.msrv,
+            );
 
             if cast_to.is_numeric() {
-                cast_possible_truncation::check(cx, expr, cast_from_expr, cast_from, cast_to, cast_to_hir.span);
+                if !timestamp_millis_truncation::check_cast(cx, expr, cast_from_expr, cast_to_hir) {
+                    cast_possible_truncation::check(cx, expr, cast_from_expr, cast_from, cast_to, cast_to_hir.span);
+                }
                 if cast_from.is_numeric() {
                     cast_possible_wrap::check(cx, expr, cast_from, cast_to);
                     cast_precision_loss::check(cx, expr, cast_from, cast_to);
@@ -845,6 +974,8 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
 
         cast_ptr_alignment::check(cx, expr);
         char_lit_as_u8::check(cx, expr);
+        char_arithmetic_as_u8::check(cx, expr);
+        timestamp_millis_truncation::check_manual_conversion(cx, expr);
         ptr_as_ptr::check(cx, expr, &self.msrv);
         cast_slice_different_sizes::check(cx, expr, &self.msrv);
         ptr_cast_constness::check_null_ptr_cast_method(cx, expr);
@@ -0,0 +1,178 @@
+use clippy_utils::consts::{ConstEvalCtxt, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::{is_type_diagnostic_item, match_type};
+use clippy_utils::{
+    SpanlessEq, expr_or_init, is_diag_trait_item, is_lint_allowed, last_path_segment, path_def_id, paths,
+};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, Ty};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::TIMESTAMP_MILLIS_TRUNCATION;
+
+/// Checks for `d.as_secs() * 1000 + d.subsec_millis() as u64`.
+pub(super) fn check_manual_conversion<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+    if let ExprKind::Binary(op, lhs, rhs) = expr.kind
+        && op.node == BinOpKind::Add
+        && !expr.span.from_expansion()
+        && let Some(((secs_recv, factor), (subsec_recv, subsec))) = scaled_secs(cx, lhs)
+            .zip(subsec_part(cx, rhs))
+            .or_else(|| scaled_secs(cx, rhs).zip(subsec_part(cx, lhs)))
+        && let Some(method) = match (factor, subsec) {
+            (1_000, "subsec_millis") => Some("as_millis"),
+            (1_000_000, "subsec_micros") => Some("as_micros"),
+            (1_000_000_000, "subsec_nanos") => Some("as_nanos"),
+            _ => None,
+        }
+        && SpanlessEq::new(cx).eq_expr(secs_recv, subsec_recv)
+    {
+        span_lint_and_then(
+            cx,
+            TIMESTAMP_MILLIS_TRUNCATION,
+            expr.span,
+            format!("manual conversion of a `Duration` using `{subsec}()`"),
+            |diag| {
+                let mut applicability = Applicability::MaybeIncorrect;
+                let recv = snippet_with_applicability(cx, secs_recv.span, "..", &mut applicability);
+                diag.span_suggestion(expr.span, "use", format!("{recv}.{method}()"), applicability);
+                diag.note(format!(
+                    "`{method}()` returns a `u128`, which can't overflow unlike the multiplication"
+                ));
+            },
+        );
+    }
+}
+
+/// Checks for `SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i32`. Returns
+/// whether the lint was emitted, in which case `cast_possible_truncation` isn't. The lint isn't
+/// emitted if it's allowed, so that `cast_possible_truncation` still is.
+pub(super) fn check_cast(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    cast_from_expr: &Expr<'_>,
+    cast_to_hir: &Ty<'_>,
+) -> bool {
+    let (signed, bits) = match *cx.typeck_results().expr_ty(expr).kind() {
+        ty::Int(int_ty) => (true, int_ty.bit_width()),
+        ty::Uint(uint_ty) => (false, uint_ty.bit_width()),
+        _ => return false,
+    };
+    if let Some(bits) = bits
+        && bits < 64
+        && let ExprKind::MethodCall(method, recv, [], _) = cast_from_expr.kind
+        && matches!(
+            method.ident.as_str(),
+            "as_secs" | "as_millis" | "as_micros" | "as_nanos"
+        )
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv).peel_refs(), sym::Duration)
+        && is_unix_timestamp(cx, recv)
+        && !cast_to_hir.span.from_expansion()
+        && !is_lint_allowed(cx, TIMESTAMP_MILLIS_TRUNCATION, expr.hir_id)
+    {
+        let wider = if signed { "i64" } else { "u64" };
+        span_lint_and_then(
+            cx,
+            TIMESTAMP_MILLIS_TRUNCATION,
+            expr.span,
+            format!("casting a Unix timestamp to a {bits}-bit integer can truncate it"),
+            |diag| {
+                if method.ident.as_str() == "as_secs" && bits == 32 {
+                    diag.note(if signed {
+                        "the number of seconds since the Unix epoch exceeds `i32::MAX` in 2038"
+                    } else {
+                        "the number of seconds since the Unix epoch exceeds `u32::MAX` in 2106"
+                    });
+                }
+                diag.span_suggestion(
+                    cast_to_hir.span,
+                    "use a wider integer type",
+                    wider,
+                    Applicability::MaybeIncorrect,
+                );
+            },
+        );
+        true
+    } else {
+        false
+    }
+}
+
+/// If `expr` is `d.as_secs() * factor`, returns `d` and the factor.
+fn scaled_secs<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, u128)> {
+    let ExprKind::Binary(op, lhs, rhs) = expr.kind else {
+        return None;
+    };
+    if op.node != BinOpKind::Mul {
+        return None;
+    }
+    let (secs, factor) = if let Some(Constant::Int(factor)) = ConstEvalCtxt::new(cx).eval(rhs) {
+        (lhs, factor)
+    } else if let Some(Constant::Int(factor)) = ConstEvalCtxt::new(cx).eval(lhs) {
+        (rhs, factor)
+    } else {
+        return None;
+    };
+    duration_method(cx, peel_widening(cx, secs)).and_then(|(name, recv)| (name == "as_secs").then_some((recv, factor)))
+}
+
+/// If `expr` is `d.subsec_millis()` or similar, possibly widened, returns `d` and the method.
+fn subsec_part<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx str)> {
+    duration_method(cx, peel_widening(cx, expr))
+        .filter(|(name, _)| name.starts_with("subsec_"))
+        .map(|(name, recv)| (recv, name))
+}
+
+/// If `expr` is a method call without arguments on a `Duration`, returns the name of the method
+/// and the `Duration`.
+fn duration_method<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(&'tcx str, &'tcx Expr<'tcx>)> {
+    if let ExprKind::MethodCall(method, recv, [], _) = expr.kind
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv).peel_refs(), sym::Duration)
+    {
+        Some((method.ident.name.as_str(), recv))
+    } else {
+        None
+    }
+}
+
+/// Removes `as u64` casts and `u64::from` calls around `expr`.
+fn peel_widening<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    match expr.kind {
+        ExprKind::Cast(inner, _) => inner,
+        ExprKind::Call(callee, [inner])
+            if path_def_id(cx, callee).is_some_and(|did| is_diag_trait_item(cx, did, sym::From)) =>
+        {
+            inner
+        },
+        _ => expr,
+    }
+}
+
+/// Whether `expr` is a `Duration` since the Unix epoch, i.e.
+/// `time.duration_since(UNIX_EPOCH).unwrap()` or `UNIX_EPOCH.elapsed().unwrap()`, or a local
+/// initialized with one.
+fn is_unix_timestamp(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let mut expr = expr_or_init(cx, expr);
+    while let ExprKind::MethodCall(method, recv, _, _) = expr.kind
+        && matches!(method.ident.as_str(), "unwrap" | "expect" | "unwrap_or_default")
+    {
+        expr = recv;
+    }
+    match expr.kind {
+        ExprKind::MethodCall(method, recv, [epoch], _) if method.ident.as_str() == "duration_since" => {
+            match_type(cx, cx.typeck_results().expr_ty(recv), &paths::SYSTEM_TIME) && is_unix_epoch(epoch)
+        },
+        ExprKind::MethodCall(method, recv, [], _) if method.ident.as_str() == "elapsed" => is_unix_epoch(recv),
+        _ => false,
+    }
+}
+
+fn is_unix_epoch(expr: &Expr<'_>) -> bool {
+    if let ExprKind::Path(ref qpath) = expr.kind {
+        last_path_segment(qpath).ident.as_str() == "UNIX_EPOCH"
+    } else {
+        false
+    }
+}
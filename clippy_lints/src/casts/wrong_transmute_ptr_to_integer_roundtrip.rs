@@ -0,0 +1,268 @@
+use clippy_utils::consts::ConstEvalCtxt;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::{SpanRangeExt, snippet_with_context};
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{contains_name, expr_or_init, is_in_const_context, path_to_local, std_or_core};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, BlockCheckMode, Expr, ExprKind, Node, TyKind, UnsafeSource};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty, UintTy};
+use rustc_span::{BytePos, Span};
+
+use super::WRONG_TRANSMUTE_PTR_TO_INTEGER_ROUNDTRIP;
+
+/// The `ptr as usize` cast an address was computed from.
+struct AddrSource<'tcx> {
+    cast: &'tcx Expr<'tcx>,
+    ptr: &'tcx Expr<'tcx>,
+    /// Whether a `let` binding was followed to find the cast.
+    through_binding: bool,
+}
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    cast_from_expr: &'tcx Expr<'tcx>,
+    cast_from: Ty<'tcx>,
+    cast_to: Ty<'tcx>,
+    cast_to_hir: &'tcx rustc_hir::Ty<'tcx>,
+    msrv: &Msrv,
+) {
+    if !is_usize(cast_from) || !cast_to.is_unsafe_ptr() || expr.span.from_expansion() || is_in_const_context(cx) {
+        return;
+    }
+
+    if let Some(source) = addr_source(cx, cast_from_expr) {
+        if source.cast.hir_id == expr_or_init(cx, cast_from_expr).hir_id {
+            lint_roundtrip(cx, expr, &source, cast_to, cast_to_hir);
+        } else if msrv.meets(msrvs::STRICT_PROVENANCE) {
+            lint_modified_roundtrip(cx, expr, cast_from_expr, &source, cast_to, cast_to_hir);
+        }
+    } else if msrv.meets(msrvs::STRICT_PROVENANCE)
+        && ConstEvalCtxt::new(cx).eval(cast_from_expr).is_none()
+        && is_in_unsafe_block(cx, expr)
+        && let ty::RawPtr(_, mutbl) = *cast_to.kind()
+        && let TyKind::Ptr(mut_ty) = cast_to_hir.kind
+        && let Some(std_or_core) = std_or_core(cx)
+    {
+        span_lint_and_then(
+            cx,
+            WRONG_TRANSMUTE_PTR_TO_INTEGER_ROUNDTRIP,
+            expr.span,
+            "casting an integer to a pointer in an unsafe block",
+            |diag| {
+                let mut app = Applicability::MachineApplicable;
+                let (addr, _) =
+                    snippet_with_context(cx, strip_parens(cast_from_expr), expr.span.ctxt(), "..", &mut app);
+                let func = if mutbl.is_mut() {
+                    "with_exposed_provenance_mut"
+                } else {
+                    "with_exposed_provenance"
+                };
+                let sugg = if let TyKind::Infer = mut_ty.ty.kind {
+                    format!("{std_or_core}::ptr::{func}({addr})")
+                } else if let Some(pointee) = mut_ty.ty.span.get_source_text(cx) {
+                    format!("{std_or_core}::ptr::{func}::<{pointee}>({addr})")
+                } else {
+                    return;
+                };
+                diag.span_suggestion(
+                    expr.span,
+                    "use the exposed provenance API to make this explicit",
+                    sugg,
+                    app,
+                );
+                diag.note("the address must come from a pointer which was exposed, e.g. with `expose_provenance`");
+            },
+        );
+    }
+}
+
+/// Lints `ptr as usize as *mut T`, where the address isn't modified.
+fn lint_roundtrip<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    source: &AddrSource<'tcx>,
+    cast_to: Ty<'tcx>,
+    cast_to_hir: &rustc_hir::Ty<'_>,
+) {
+    span_lint_and_then(
+        cx,
+        WRONG_TRANSMUTE_PTR_TO_INTEGER_ROUNDTRIP,
+        expr.span,
+        "casting a pointer to an integer and back",
+        |diag| {
+            let ptr_ty = cx.typeck_results().expr_ty(source.ptr);
+            if let Some(suffix) = retype_suffix(cx, ptr_ty, cast_to, cast_to_hir)
+                && (!source.through_binding || path_to_local(source.ptr).is_some())
+            {
+                let mut app = if source.through_binding {
+                    Applicability::MaybeIncorrect
+                } else {
+                    Applicability::MachineApplicable
+                };
+                let ptr = Sugg::hir_with_context(cx, source.ptr, source.cast.span.ctxt(), "..", &mut app);
+                let sugg = if suffix.is_empty() {
+                    ptr.to_string()
+                } else {
+                    format!("{}{suffix}", ptr.maybe_par())
+                };
+                diag.span_suggestion(expr.span, "use the pointer directly", sugg, app);
+            } else {
+                diag.help("cast the pointer directly instead");
+            }
+        },
+    );
+}
+
+/// Lints `(ptr as usize + offset) as *mut T`, where the address is modified on the way.
+fn lint_modified_roundtrip<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    addr: &'tcx Expr<'tcx>,
+    source: &AddrSource<'tcx>,
+    cast_to: Ty<'tcx>,
+    cast_to_hir: &rustc_hir::Ty<'_>,
+) {
+    span_lint_and_then(
+        cx,
+        WRONG_TRANSMUTE_PTR_TO_INTEGER_ROUNDTRIP,
+        expr.span,
+        "casting a pointer to an integer and back loses track of its provenance",
+        |diag| {
+            let ptr_ty = cx.typeck_results().expr_ty(source.ptr);
+            let Some(suffix) = retype_suffix(cx, ptr_ty, cast_to, cast_to_hir) else {
+                diag.help("use `map_addr` or `with_addr` to change the address of the pointer");
+                return;
+            };
+            let ctxt = expr.span.ctxt();
+            if source.through_binding {
+                if path_to_local(source.ptr).is_none() {
+                    diag.help("use `with_addr` to change the address of the pointer");
+                    return;
+                }
+                let mut app = Applicability::MaybeIncorrect;
+                let ptr = Sugg::hir_with_context(cx, source.ptr, source.cast.span.ctxt(), "..", &mut app);
+                let (addr, _) = snippet_with_context(cx, strip_parens(addr), ctxt, "..", &mut app);
+                diag.span_suggestion(
+                    expr.span,
+                    "use `with_addr` to keep the provenance of the pointer",
+                    format!("{}.with_addr({addr}){suffix}", ptr.maybe_par()),
+                    app,
+                );
+            } else {
+                let mut app = if contains_name(sym!(addr), addr, cx) {
+                    Applicability::MaybeIncorrect
+                } else {
+                    Applicability::MachineApplicable
+                };
+                let ptr = Sugg::hir_with_context(cx, source.ptr, ctxt, "..", &mut app);
+                let inner = strip_parens(addr);
+                diag.multipart_suggestion(
+                    "use `map_addr` to keep the provenance of the pointer",
+                    vec![
+                        (
+                            expr.span.with_hi(inner.lo()),
+                            format!("{}.map_addr(|addr| ", ptr.maybe_par()),
+                        ),
+                        (source.cast.span, "addr".into()),
+                        (expr.span.with_lo(inner.hi()), format!("){suffix}")),
+                    ],
+                    app,
+                );
+            }
+        },
+    );
+}
+
+/// Finds the `ptr as usize` cast an address is computed from, following `let` bindings and
+/// arithmetic on the address.
+fn addr_source<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<AddrSource<'tcx>> {
+    let init = expr_or_init(cx, expr);
+    let through_binding = init.hir_id != expr.hir_id;
+    let mut source = match init.kind {
+        ExprKind::Cast(ptr, _)
+            if cx.typeck_results().expr_ty(ptr).is_unsafe_ptr() && is_usize(cx.typeck_results().expr_ty(init)) =>
+        {
+            return Some(AddrSource {
+                cast: init,
+                ptr,
+                through_binding,
+            });
+        },
+        ExprKind::Binary(op, lhs, _)
+            if matches!(
+                op.node,
+                BinOpKind::Add | BinOpKind::Sub | BinOpKind::BitAnd | BinOpKind::BitOr | BinOpKind::BitXor
+            ) =>
+        {
+            addr_source(cx, lhs)?
+        },
+        ExprKind::MethodCall(path, recv, [_], _)
+            if matches!(path.ident.name.as_str(), "wrapping_add" | "wrapping_sub") =>
+        {
+            addr_source(cx, recv)?
+        },
+        _ => return None,
+    };
+    source.through_binding |= through_binding;
+    Some(source)
+}
+
+/// Returns the method call needed to convert a pointer of type `from` to `to`: an empty string if
+/// they are the same, or a call to `cast` if only the pointee type differs.
+fn retype_suffix<'tcx>(
+    cx: &LateContext<'tcx>,
+    from: Ty<'tcx>,
+    to: Ty<'tcx>,
+    to_hir: &rustc_hir::Ty<'_>,
+) -> Option<String> {
+    if from == to {
+        return Some(String::new());
+    }
+    if let ty::RawPtr(_, from_mutbl) = *from.kind()
+        && let ty::RawPtr(_, to_mutbl) = *to.kind()
+        && from_mutbl == to_mutbl
+        && let TyKind::Ptr(mut_ty) = to_hir.kind
+    {
+        if let TyKind::Infer = mut_ty.ty.kind {
+            Some(".cast()".into())
+        } else {
+            let pointee = mut_ty.ty.span.get_source_text(cx)?;
+            Some(format!(".cast::<{pointee}>()"))
+        }
+    } else {
+        None
+    }
+}
+
+/// Returns the span of the expression without the parentheses around it, if any.
+fn strip_parens(expr: &Expr<'_>) -> Span {
+    if let ExprKind::Binary(_, first, _) | ExprKind::MethodCall(_, first, ..) = expr.kind
+        && expr.span.lo() < first.span.lo()
+    {
+        expr.span
+            .with_lo(expr.span.lo() + BytePos(1))
+            .with_hi(expr.span.hi() - BytePos(1))
+    } else {
+        expr.span
+    }
+}
+
+fn is_in_unsafe_block(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Block(block) if block.rules == BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided) => {
+                return true;
+            },
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => break,
+            _ => {},
+        }
+    }
+    false
+}
+
+fn is_usize(ty: Ty<'_>) -> bool {
+    matches!(ty.kind(), ty::Uint(UintTy::Usize))
+}
@@ -1,17 +1,24 @@
-use clippy_utils::diagnostics::span_lint;
+use clippy_utils::diagnostics::{span_lint, span_lint_hir};
 use clippy_utils::ty::{get_type_diagnostic_name, is_type_lang_item};
 use clippy_utils::visitors::{Visitable, for_each_expr};
-use clippy_utils::{get_enclosing_block, path_to_local_id};
+use clippy_utils::{get_enclosing_block, path_to_local, path_to_local_id};
 use core::ops::ControlFlow;
-use rustc_hir::{Body, ExprKind, HirId, LangItem, LetStmt, Node, PatKind};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::{Body, Expr, ExprKind, HirId, Item, ItemKind, LangItem, LetStmt, Node, Pat, PatKind, StructTailExpr};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::declare_lint_pass;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::impl_lint_pass;
+use rustc_span::Span;
 use rustc_span::symbol::sym;
 
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for collections that are never queried.
     ///
+    /// This includes fields of collection types which are only written to, as long as the field
+    /// can't be accessed outside of the crate.
+    ///
     /// ### Why is this bad?
     /// Putting effort into constructing a collection but then never querying it might indicate that
     /// the author forgot to do whatever they intended to do with the collection. Example: Clone
@@ -41,23 +48,103 @@ declare_clippy_lint! {
     nursery,
     "a collection is never queried"
 }
-declare_lint_pass!(CollectionIsNeverRead => [COLLECTION_IS_NEVER_READ]);
+
+#[derive(Default)]
+pub struct CollectionIsNeverRead {
+    /// Fields of collection types which can't be accessed outside of the crate
+    fields: Vec<(LocalDefId, HirId, Span)>,
+    written_fields: FxHashSet<DefId>,
+    read_fields: FxHashSet<DefId>,
+}
+
+impl_lint_pass!(CollectionIsNeverRead => [COLLECTION_IS_NEVER_READ]);
 
 impl<'tcx> LateLintPass<'tcx> for CollectionIsNeverRead {
     fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx LetStmt<'tcx>) {
         // Look for local variables whose type is a container. Search surrounding block for read access.
         if let PatKind::Binding(_, local_id, _, _) = local.pat.kind
-            && match_acceptable_type(cx, local)
+            && match_acceptable_type(cx, cx.typeck_results().pat_ty(local.pat))
             && let Some(enclosing_block) = get_enclosing_block(cx, local.hir_id)
             && has_no_read_access(cx, local_id, enclosing_block)
         {
             span_lint(cx, COLLECTION_IS_NEVER_READ, local.span, "collection is never read");
         }
     }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Struct(data, _) = item.kind {
+            self.fields.extend(
+                data.fields()
+                    .iter()
+                    .filter(|field| {
+                        !cx.effective_visibilities.is_exported(field.def_id)
+                            && match_acceptable_type(cx, cx.tcx.type_of(field.def_id).instantiate_identity())
+                    })
+                    .map(|field| (field.def_id, field.hir_id, field.span)),
+            );
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Field(base, _) = expr.kind
+            && let ty::Adt(adt, _) = cx.typeck_results().expr_ty_adjusted(base).peel_refs().kind()
+            && adt.is_struct()
+            && let Some(field) = adt
+                .non_enum_variant()
+                .fields
+                .get(cx.typeck_results().field_index(expr.hir_id))
+        {
+            if is_read_access(cx, expr) {
+                self.read_fields.insert(field.did);
+            } else {
+                self.written_fields.insert(field.did);
+            }
+        } else if let ExprKind::Struct(_, _, StructTailExpr::Base(base)) = expr.kind
+            && let ty::Adt(adt, _) = cx.typeck_results().expr_ty(base).kind()
+            && adt.is_struct()
+        {
+            // The base of a functional update is read
+            self.read_fields
+                .extend(adt.non_enum_variant().fields.iter().map(|field| field.did));
+        }
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx Pat<'tcx>) {
+        // Destructuring a field is assumed to read it
+        if let PatKind::Struct(..) | PatKind::TupleStruct(..) = pat.kind
+            && let ty::Adt(adt, _) = cx.typeck_results().pat_ty(pat).kind()
+            && adt.is_struct()
+        {
+            let fields = &adt.non_enum_variant().fields;
+            match pat.kind {
+                PatKind::Struct(_, pat_fields, _) => self.read_fields.extend(
+                    pat_fields
+                        .iter()
+                        .filter_map(|pat_field| fields.iter().find(|field| field.name == pat_field.ident.name))
+                        .map(|field| field.did),
+                ),
+                _ => self.read_fields.extend(fields.iter().map(|field| field.did)),
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for &(def_id, hir_id, span) in &self.fields {
+            let def_id = def_id.to_def_id();
+            if self.written_fields.contains(&def_id) && !self.read_fields.contains(&def_id) {
+                span_lint_hir(
+                    cx,
+                    COLLECTION_IS_NEVER_READ,
+                    hir_id,
+                    span,
+                    "collection field is never read",
+                );
+            }
+        }
+    }
 }
 
-fn match_acceptable_type(cx: &LateContext<'_>, local: &LetStmt<'_>) -> bool {
-    let ty = cx.typeck_results().pat_ty(local.pat);
+fn match_acceptable_type<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
     matches!(
         get_type_diagnostic_name(cx, ty),
         Some(
@@ -87,71 +174,78 @@ fn has_no_read_access<'tcx, T: Visitable<'tcx>>(cx: &LateContext<'tcx>, id: HirI
 
         // `id` is being accessed. Investigate if it's a read access.
         has_access = true;
-
-        // `id` appearing in the left-hand side of an assignment is not a read access:
-        //
-        // id = ...; // Not reading `id`.
-        if let Node::Expr(parent) = cx.tcx.parent_hir_node(expr.hir_id)
-            && let ExprKind::Assign(lhs, ..) = parent.kind
-            && path_to_local_id(lhs, id)
-        {
-            return ControlFlow::Continue(());
+        if is_read_access(cx, expr) {
+            has_read_access = true;
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
         }
+    });
 
-        // Look for method call with receiver `id`. It might be a non-read access:
-        //
-        // id.foo(args)
-        //
-        // Only assuming this for "official" methods defined on the type. For methods defined in extension
-        // traits (identified as local, based on the orphan rule), pessimistically assume that they might
-        // have side effects, so consider them a read.
-        if let Node::Expr(parent) = cx.tcx.parent_hir_node(expr.hir_id)
-            && let ExprKind::MethodCall(_, receiver, args, _) = parent.kind
-            && path_to_local_id(receiver, id)
-            && let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(parent.hir_id)
-            && !method_def_id.is_local()
-        {
-            // If this "official" method takes closures,
-            // it has read access if one of the closures has read access.
-            //
-            // items.retain(|item| send_item(item).is_ok());
-            let is_read_in_closure_arg = args.iter().any(|arg| {
-                if let ExprKind::Closure(closure) = arg.kind
-                    // To keep things simple, we only check the first param to see if its read.
-                    && let Body { params: [param, ..], value } = cx.tcx.hir().body(closure.body)
-                {
-                    !has_no_read_access(cx, param.hir_id, *value)
-                } else {
-                    false
-                }
-            });
-            if is_read_in_closure_arg {
-                has_read_access = true;
-                return ControlFlow::Break(());
-            }
+    // Ignore collections that have no access at all. Other lints should catch them.
+    has_access && !has_read_access
+}
 
-            // The method call is a statement, so the return value is not used. That's not a read access:
-            //
-            // id.foo(args);
-            if let Node::Stmt(..) = cx.tcx.parent_hir_node(parent.hir_id) {
-                return ControlFlow::Continue(());
-            }
+/// Checks if `expr`, an access to a collection in a local or a field, reads the collection.
+fn is_read_access(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    // `id` appearing in the left-hand side of an assignment is not a read access:
+    //
+    // id = ...; // Not reading `id`.
+    if let Node::Expr(parent) = cx.tcx.parent_hir_node(expr.hir_id)
+        && let ExprKind::Assign(lhs, ..) = parent.kind
+        && (lhs.hir_id == expr.hir_id || path_to_local(expr).is_some_and(|id| path_to_local_id(lhs, id)))
+    {
+        return false;
+    }
 
-            // The method call is not a statement, so its return value is used somehow but its type is the
-            // unit type, so this is not a real read access. Examples:
-            //
-            // let y = x.clear();
-            // println!("{:?}", x.clear());
-            if cx.typeck_results().expr_ty(parent).is_unit() {
-                return ControlFlow::Continue(());
+    // Look for method call with receiver `id`. It might be a non-read access:
+    //
+    // id.foo(args)
+    //
+    // Only assuming this for "official" methods defined on the type. For methods defined in extension
+    // traits (identified as local, based on the orphan rule), pessimistically assume that they might
+    // have side effects, so consider them a read.
+    if let Node::Expr(parent) = cx.tcx.parent_hir_node(expr.hir_id)
+        && let ExprKind::MethodCall(_, receiver, args, _) = parent.kind
+        && receiver.hir_id == expr.hir_id
+        && let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(parent.hir_id)
+        && !method_def_id.is_local()
+    {
+        // If this "official" method takes closures,
+        // it has read access if one of the closures has read access.
+        //
+        // items.retain(|item| send_item(item).is_ok());
+        let is_read_in_closure_arg = args.iter().any(|arg| {
+            if let ExprKind::Closure(closure) = arg.kind
+                // To keep things simple, we only check the first param to see if its read.
+                && let Body { params: [param, ..], value } = cx.tcx.hir().body(closure.body)
+            {
+                !has_no_read_access(cx, param.hir_id, *value)
+            } else {
+                false
             }
+        });
+        if is_read_in_closure_arg {
+            return true;
         }
 
-        // Any other access to `id` is a read access. Stop searching.
-        has_read_access = true;
-        ControlFlow::Break(())
-    });
+        // The method call is a statement, so the return value is not used. That's not a read access:
+        //
+        // id.foo(args);
+        if let Node::Stmt(..) = cx.tcx.parent_hir_node(parent.hir_id) {
+            return false;
+        }
 
-    // Ignore collections that have no access at all. Other lints should catch them.
-    has_access && !has_read_access
+        // The method call is not a statement, so its return value is used somehow but its type is the
+        // unit type, so this is not a real read access. Examples:
+        //
+        // let y = x.clear();
+        // println!("{:?}", x.clear());
+        if cx.typeck_results().expr_ty(parent).is_unit() {
+            return false;
+        }
+    }
+
+    // Any other access to `id` is a read access.
+    true
 }
@@ -47,7 +47,7 @@ impl_lint_pass!(DbgMacro => [DBG_MACRO]);
 impl DbgMacro {
     pub fn new(conf: &'static Conf) -> Self {
         DbgMacro {
-            allow_dbg_in_tests: conf.allow_dbg_in_tests,
+            allow_dbg_in_tests: conf.is_allowed_in_tests(DBG_MACRO),
             checked_dbg_call_site: FxHashSet::default(),
             prev_ctxt: SyntaxContext::root(),
         }
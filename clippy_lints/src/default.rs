@@ -1,14 +1,14 @@
 use clippy_utils::diagnostics::{span_lint_and_note, span_lint_and_sugg};
 use clippy_utils::source::snippet_with_context;
 use clippy_utils::ty::{has_drop, is_copy};
-use clippy_utils::{contains_name, get_parent_expr, in_automatically_derived, is_from_proc_macro};
+use clippy_utils::{contains_name, get_parent_expr, in_automatically_derived, is_from_proc_macro, is_lint_allowed};
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
-use rustc_hir::def::Res;
+use rustc_hir::def::{DefKind, Res};
 use rustc_hir::{Block, Expr, ExprKind, PatKind, QPath, Stmt, StmtKind, StructTailExpr};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty;
 use rustc_middle::ty::print::with_forced_trimmed_paths;
+use rustc_middle::ty::{self, AdtDef};
 use rustc_session::impl_lint_pass;
 use rustc_span::symbol::{Ident, Symbol};
 use rustc_span::{Span, sym};
@@ -70,13 +70,53 @@ declare_clippy_lint! {
     "binding initialized with Default should have its fields set in the initializer"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for immediate reassignment of fields of a struct created with one of its
+    /// associated constructor functions, such as `Foo::new()`.
+    ///
+    /// ### Why is this bad?
+    /// The fields can be set in the initializer with the [functional update syntax](https://doc.rust-lang.org/reference/expressions/struct-expr.html#functional-update-syntax),
+    /// which avoids the mutable binding.
+    ///
+    /// ### Known problems
+    /// The constructor is evaluated after the new field values in the suggested struct literal,
+    /// which matters if either of them has side effects.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # struct A { i: i32, j: i32 }
+    /// # impl A { fn new() -> Self { Self { i: 0, j: 0 } } }
+    /// let mut a = A::new();
+    /// a.i = 42;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// # struct A { i: i32, j: i32 }
+    /// # impl A { fn new() -> Self { Self { i: 0, j: 0 } } }
+    /// let a = A {
+    ///     i: 42,
+    ///     ..A::new()
+    /// };
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub FIELD_REASSIGN_OUTSIDE_CONSTRUCTOR,
+    pedantic,
+    "binding initialized with a constructor function should have its fields set in the initializer"
+}
+
 #[derive(Default)]
 pub struct Default {
     // Spans linted by `field_reassign_with_default`.
     reassigned_linted: FxHashSet<Span>,
 }
 
-impl_lint_pass!(Default => [DEFAULT_TRAIT_ACCESS, FIELD_REASSIGN_WITH_DEFAULT]);
+impl_lint_pass!(Default => [
+    DEFAULT_TRAIT_ACCESS,
+    FIELD_REASSIGN_WITH_DEFAULT,
+    FIELD_REASSIGN_OUTSIDE_CONSTRUCTOR,
+]);
 
 impl<'tcx> LateLintPass<'tcx> for Default {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
@@ -119,20 +159,21 @@ impl<'tcx> LateLintPass<'tcx> for Default {
         };
         for (stmt_idx, stmt) in stmts_head.iter().enumerate() {
             // find all binding statements like `let mut _ = T::default()` where `T::default()` is the
-            // `default` method of the `Default` trait, and store statement index in current block being
-            // checked and the name of the bound variable
-            let (local, variant, binding_name, binding_type, span) = if let StmtKind::Let(local) = stmt.kind
+            // `default` method of the `Default` trait, or `let mut _ = T::new()` where `T::new()` is a
+            // constructor function of `T`, and store statement index in current block being checked and
+            // the name of the bound variable
+            let (local, variant, binding_name, binding_type, span, init) = if let StmtKind::Let(local) = stmt.kind
                 // only take `let ...` statements
                 && let Some(expr) = local.init
                 && !in_automatically_derived(cx.tcx, expr.hir_id)
                 && !expr.span.from_expansion()
                 // only take bindings to identifiers
                 && let PatKind::Binding(_, binding_id, ident, _) = local.pat.kind
-                // only when assigning `... = Default::default()`
-                && is_expr_default(expr, cx)
                 && let binding_type = cx.typeck_results().node_type(binding_id)
                 && let ty::Adt(adt, args) = *binding_type.kind()
                 && adt.is_struct()
+                // only when assigning `... = Default::default()` or `... = T::new()`
+                && let Some(init) = Init::of_expr(cx, expr, adt)
                 && let variant = adt.non_enum_variant()
                 && (adt.did().is_local() || !variant.is_field_list_non_exhaustive())
                 && let module_did = cx.tcx.parent_module(stmt.hir_id)
@@ -148,7 +189,7 @@ impl<'tcx> LateLintPass<'tcx> for Default {
                     })
                 && (!has_drop(cx, binding_type) || all_fields_are_copy)
             {
-                (local, variant, ident.name, binding_type, expr.span)
+                (local, variant, ident.name, binding_type, expr.span, init)
             } else {
                 continue;
             };
@@ -226,6 +267,20 @@ impl<'tcx> LateLintPass<'tcx> for Default {
                     binding_type.to_string()
                 };
 
+                if let Init::Constructor = init {
+                    let base = snippet_with_context(cx, span, init_ctxt, "..", &mut app).0;
+                    let sugg = format!("{binding_type} {{ {field_list}, ..{base} }}");
+                    span_lint_and_note(
+                        cx,
+                        FIELD_REASSIGN_OUTSIDE_CONSTRUCTOR,
+                        first_assign.unwrap().span,
+                        format!("field assignment outside of initializer for an instance created with `{base}`"),
+                        Some(local.span),
+                        format!("consider initializing the variable with `{sugg}` and removing relevant reassignments"),
+                    );
+                    continue;
+                }
+
                 let sugg = if ext_with_default {
                     if field_list.is_empty() {
                         format!("{binding_type}::default()")
@@ -251,6 +306,28 @@ impl<'tcx> LateLintPass<'tcx> for Default {
     }
 }
 
+/// How the binding whose fields are reassigned was initialized.
+enum Init {
+    /// `Default::default()`
+    Default,
+    /// An associated constructor function, e.g. `T::new()`
+    Constructor,
+}
+
+impl Init {
+    fn of_expr<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, adt: AdtDef<'_>) -> Option<Self> {
+        if is_expr_default(expr, cx) {
+            Some(Self::Default)
+        } else if is_expr_constructor(cx, expr, adt)
+            && !is_lint_allowed(cx, FIELD_REASSIGN_OUTSIDE_CONSTRUCTOR, expr.hir_id)
+        {
+            Some(Self::Constructor)
+        } else {
+            None
+        }
+    }
+}
+
 /// Checks if the given expression is the `default` method belonging to the `Default` trait.
 fn is_expr_default<'tcx>(expr: &'tcx Expr<'tcx>, cx: &LateContext<'tcx>) -> bool {
     if let ExprKind::Call(fn_expr, []) = &expr.kind
@@ -264,6 +341,23 @@ fn is_expr_default<'tcx>(expr: &'tcx Expr<'tcx>, cx: &LateContext<'tcx>) -> bool
     }
 }
 
+/// Checks if the given expression calls an inherent associated function of `adt` which returns
+/// `Self`, e.g. `Foo::new()`.
+fn is_expr_constructor(cx: &LateContext<'_>, expr: &Expr<'_>, adt: AdtDef<'_>) -> bool {
+    if let ExprKind::Call(fn_expr, _) = expr.kind
+        && let ExprKind::Path(ref qpath) = fn_expr.kind
+        && let Res::Def(DefKind::AssocFn, def_id) = cx.qpath_res(qpath, fn_expr.hir_id)
+        && let Some(impl_id) = cx.tcx.impl_of_method(def_id)
+        && cx.tcx.impl_trait_ref(impl_id).is_none()
+        && cx.tcx.type_of(impl_id).instantiate_identity().ty_adt_def() == Some(adt)
+    {
+        let output = cx.tcx.fn_sig(def_id).instantiate_identity().output().skip_binder();
+        output.ty_adt_def() == Some(adt)
+    } else {
+        false
+    }
+}
+
 /// Returns the reassigned field and the assigning expression (right-hand side of assign).
 fn field_reassigned_by_stmt<'tcx>(this: &Stmt<'tcx>, binding_name: Symbol) -> Option<(Ident, &'tcx Expr<'tcx>)> {
     if let StmtKind::Semi(later_expr) = this.kind
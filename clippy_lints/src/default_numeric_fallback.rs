@@ -1,10 +1,13 @@
 use clippy_utils::diagnostics::span_lint_hir_and_then;
-use clippy_utils::numeric_literal;
 use clippy_utils::source::snippet_opt;
+use clippy_utils::{numeric_literal, path_to_local};
 use rustc_ast::ast::{LitFloatType, LitIntType, LitKind};
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::{Visitor, walk_expr, walk_stmt};
-use rustc_hir::{Block, Body, ConstContext, Expr, ExprKind, FnRetTy, HirId, Lit, Stmt, StmtKind, StructTailExpr};
+use rustc_hir::{
+    Arm, BinOpKind, Block, Body, ConstContext, Expr, ExprKind, FnRetTy, HirId, LetExpr, Lit, Node, Pat, Stmt, StmtKind,
+    StructTailExpr, UnOp,
+};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{self, FloatTy, IntTy, PolyFnSig, Ty};
@@ -25,6 +28,9 @@ declare_clippy_lint! {
     /// ### Why restrict this?
     /// To ensure that every numeric type is chosen explicitly rather than implicitly.
     ///
+    /// Literals whose type is given by their context are not linted, e.g. the operand of a binary
+    /// operation whose other operand has a type, a pattern matched against a typed value, or an index.
+    ///
     /// ### Known problems
     /// This lint is implemented using a custom algorithm independent of rustc's inference,
     /// which results in many false positives and false negatives.
@@ -120,6 +126,85 @@ impl<'a, 'tcx> NumericFallbackVisitor<'a, 'tcx> {
     }
 }
 
+impl<'tcx> NumericFallbackVisitor<'_, 'tcx> {
+    /// Visits `expr`, whose type is the same as the type of `by` if `same_ty` is set. Literals in
+    /// it aren't linted if the type of `by` doesn't come from a literal as well.
+    fn visit_expr_constrained_by(&mut self, expr: &'tcx Expr<'_>, by: &Expr<'_>, same_ty: bool) {
+        if same_ty && !may_fall_back(by) {
+            self.ty_bounds.push(ExplicitTyBound(true));
+            self.visit_expr(expr);
+            self.ty_bounds.pop();
+        } else {
+            self.visit_expr(expr);
+        }
+    }
+
+    /// Visits `pat`, which is matched against `scrutinee`.
+    fn visit_pat_matched_against(&mut self, pat: &'tcx Pat<'_>, scrutinee: &Expr<'_>) {
+        if may_fall_back(scrutinee) {
+            self.visit_pat(pat);
+        } else {
+            self.ty_bounds.push(ExplicitTyBound(true));
+            self.visit_pat(pat);
+            self.ty_bounds.pop();
+        }
+    }
+
+    /// Visits the operands of `expr` if it gives some of them the type of another operand or of
+    /// the value they're matched against, returns `false` for any other expression.
+    fn visit_typed_by_context(&mut self, expr: &'tcx Expr<'_>) -> bool {
+        match &expr.kind {
+            ExprKind::Binary(op, lhs, rhs) if !is_shift(op.node) => {
+                let same_ty = self.cx.typeck_results().expr_ty(lhs) == self.cx.typeck_results().expr_ty(rhs);
+                self.visit_expr_constrained_by(lhs, rhs, same_ty);
+                self.visit_expr_constrained_by(rhs, lhs, same_ty);
+            },
+
+            ExprKind::AssignOp(op, lhs, rhs) if !is_shift(op.node) => {
+                let same_ty = self.cx.typeck_results().expr_ty(lhs) == self.cx.typeck_results().expr_ty(rhs);
+                self.visit_expr(lhs);
+                self.visit_expr_constrained_by(rhs, lhs, same_ty);
+            },
+
+            ExprKind::Assign(lhs, rhs, _) => {
+                self.visit_expr(lhs);
+                // The type of `x` in `let mut x; x = 1;` comes from the literal
+                self.ty_bounds.push(ExplicitTyBound(!place_may_fall_back(self.cx, lhs)));
+                self.visit_expr(rhs);
+                self.ty_bounds.pop();
+            },
+
+            // The type of an index is given by the `Index` implementation of the indexed value
+            ExprKind::Index(base, index, _) => {
+                self.visit_expr(base);
+                self.ty_bounds.push(ExplicitTyBound(true));
+                self.visit_expr(index);
+                self.ty_bounds.pop();
+            },
+
+            ExprKind::Match(scrutinee, arms, _) => {
+                self.visit_expr(scrutinee);
+                for arm in *arms {
+                    let Arm { pat, guard, body, .. } = arm;
+                    self.visit_pat_matched_against(pat, scrutinee);
+                    if let Some(guard) = guard {
+                        self.visit_expr(guard);
+                    }
+                    self.visit_expr(body);
+                }
+            },
+
+            ExprKind::Let(LetExpr { pat, init, .. }) => {
+                self.visit_expr(init);
+                self.visit_pat_matched_against(pat, init);
+            },
+
+            _ => return false,
+        }
+        true
+    }
+}
+
 impl<'tcx> Visitor<'tcx> for NumericFallbackVisitor<'_, 'tcx> {
     fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
         match &expr.kind {
@@ -166,8 +251,20 @@ impl<'tcx> Visitor<'tcx> for NumericFallbackVisitor<'_, 'tcx> {
             ExprKind::MethodCall(_, receiver, args, _) => {
                 if let Some(def_id) = self.cx.typeck_results().type_dependent_def_id(expr.hir_id) {
                     let fn_sig = self.cx.tcx.fn_sig(def_id).instantiate_identity().skip_binder();
+                    // Arguments of type `Self`, e.g. of `x.max(1)`, get the type of the receiver
+                    let self_bound = self
+                        .cx
+                        .typeck_results()
+                        .expr_ty_adjusted(receiver)
+                        .peel_refs()
+                        .is_numeric()
+                        && !may_fall_back(receiver);
                     for (expr, bound) in iter::zip(iter::once(*receiver).chain(args.iter()), fn_sig.inputs()) {
-                        self.ty_bounds.push((*bound).into());
+                        if bound.is_param(0) && self.cx.tcx.trait_of_item(def_id).is_some() {
+                            self.ty_bounds.push(ExplicitTyBound(self_bound));
+                        } else {
+                            self.ty_bounds.push((*bound).into());
+                        }
                         self.visit_expr(expr);
                         self.ty_bounds.pop();
                     }
@@ -213,6 +310,8 @@ impl<'tcx> Visitor<'tcx> for NumericFallbackVisitor<'_, 'tcx> {
                 return;
             },
 
+            _ if self.visit_typed_by_context(expr) => return,
+
             _ => {},
         }
 
@@ -242,6 +341,44 @@ fn fn_sig_opt<'tcx>(cx: &LateContext<'tcx>, hir_id: HirId) -> Option<PolyFnSig<'
     }
 }
 
+fn is_shift(op: BinOpKind) -> bool {
+    matches!(op, BinOpKind::Shl | BinOpKind::Shr)
+}
+
+/// Whether the type of `expr` may come from the default numeric fallback of a literal in it, e.g.
+/// `1`, `-1` or `1 + 2`.
+fn may_fall_back(expr: &Expr<'_>) -> bool {
+    match expr.kind {
+        ExprKind::Lit(lit) => matches!(
+            lit.node,
+            LitKind::Int(_, LitIntType::Unsuffixed) | LitKind::Float(_, LitFloatType::Unsuffixed)
+        ),
+        ExprKind::Unary(UnOp::Neg, inner) | ExprKind::DropTemps(inner) => may_fall_back(inner),
+        ExprKind::Binary(op, lhs, rhs) => !is_shift(op.node) && may_fall_back(lhs) && may_fall_back(rhs),
+        ExprKind::Block(
+            Block {
+                stmts: [],
+                expr: Some(inner),
+                ..
+            },
+            _,
+        ) => may_fall_back(inner),
+        _ => false,
+    }
+}
+
+/// Whether the type of the place `expr` may come from the default numeric fallback, i.e. it's a
+/// local declared without a type whose initializer, if any, may fall back as well.
+fn place_may_fall_back(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let Some(local) = path_to_local(expr)
+        && let Node::LetStmt(let_stmt) = cx.tcx.parent_hir_node(local)
+    {
+        let_stmt.ty.is_none() && let_stmt.init.is_none_or(may_fall_back)
+    } else {
+        false
+    }
+}
+
 /// Wrapper around a `bool` to make the meaning of the value clearer
 #[derive(Debug, Clone, Copy)]
 struct ExplicitTyBound(pub bool);
@@ -0,0 +1,191 @@
+use clippy_config::Conf;
+use clippy_config::types::{DisallowedPath, create_disallowed_map};
+use clippy_utils::diagnostics::{span_lint_and_then, span_lint_hir_and_then};
+use clippy_utils::macros::macro_backtrace;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Diag;
+use rustc_hir::def_id::DefIdMap;
+use rustc_hir::{
+    Attribute, Expr, ForeignItem, HirId, ImplItem, Item, ItemKind, OwnerId, Pat, Path, Stmt, TraitItem, Ty,
+};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::TyCtxt;
+use rustc_session::impl_lint_pass;
+use rustc_span::{ExpnId, MacroKind, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Denies the configured attributes in clippy.toml
+    ///
+    /// Builtin attributes like `inline` and tool attributes like `rustfmt::skip` are matched by
+    /// their path. Attribute macros like `tokio::main` and derive macros are resolved, so they are
+    /// found under any name they are imported as.
+    ///
+    /// Note: Even though this lint is warn-by-default, it will only trigger if
+    /// attributes are defined in the clippy.toml file.
+    ///
+    /// ### Why is this bad?
+    /// Some attributes are undesirable in certain contexts, and it's beneficial to
+    /// lint for them as needed.
+    ///
+    /// ### Example
+    /// An example clippy.toml configuration:
+    /// ```toml
+    /// # clippy.toml
+    /// disallowed-attributes = [
+    ///     # Can use a string as the path of the disallowed attribute.
+    ///     "inline",
+    ///     # Can also use an inline table with a `path` key.
+    ///     { path = "rustfmt::skip" },
+    ///     # When using an inline table, can add a `reason` for why the attribute
+    ///     # is disallowed.
+    ///     { path = "tokio::main", reason = "use the custom runtime builder" },
+    /// ]
+    /// ```
+    /// ```rust,ignore
+    /// #[inline]
+    /// fn f() {}
+    ///
+    /// // The diagnostic will contain the message "use the custom runtime builder"
+    /// #[tokio::main]
+    /// async fn main() {}
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub DISALLOWED_ATTRIBUTES,
+    style,
+    "use of a disallowed attribute"
+}
+
+pub struct DisallowedAttributes {
+    /// Builtin and tool attributes, which aren't resolved.
+    disallowed_paths: &'static [DisallowedPath],
+    /// Attribute and derive macros.
+    disallowed_macros: DefIdMap<(&'static str, Option<&'static str>)>,
+    seen: FxHashSet<ExpnId>,
+    // Track the most recently seen node that can have a `derive` attribute.
+    // Needed to use the correct lint level.
+    derive_src: Option<OwnerId>,
+}
+
+impl DisallowedAttributes {
+    pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
+        Self {
+            disallowed_paths: &conf.disallowed_attributes,
+            disallowed_macros: create_disallowed_map(tcx, &conf.disallowed_attributes),
+            seen: FxHashSet::default(),
+            derive_src: None,
+        }
+    }
+
+    fn check(&mut self, cx: &LateContext<'_>, span: Span, derive_src: Option<OwnerId>) {
+        if self.disallowed_macros.is_empty() {
+            return;
+        }
+
+        for mac in macro_backtrace(span) {
+            if !self.seen.insert(mac.expn) {
+                return;
+            }
+
+            if matches!(mac.kind, MacroKind::Attr | MacroKind::Derive)
+                && let Some(&(path, reason)) = self.disallowed_macros.get(&mac.def_id)
+            {
+                let msg = format!("use of a disallowed attribute `{path}`");
+                if matches!(mac.kind, MacroKind::Derive)
+                    && let Some(derive_src) = derive_src
+                {
+                    span_lint_hir_and_then(
+                        cx,
+                        DISALLOWED_ATTRIBUTES,
+                        cx.tcx.local_def_id_to_hir_id(derive_src.def_id),
+                        mac.span,
+                        msg,
+                        add_reason(reason),
+                    );
+                } else {
+                    span_lint_and_then(cx, DISALLOWED_ATTRIBUTES, mac.span, msg, add_reason(reason));
+                }
+            }
+        }
+    }
+}
+
+impl_lint_pass!(DisallowedAttributes => [DISALLOWED_ATTRIBUTES]);
+
+impl LateLintPass<'_> for DisallowedAttributes {
+    fn check_attribute(&mut self, cx: &LateContext<'_>, attr: &Attribute) {
+        if let Some(segments) = attr.ident_path()
+            && !in_external_macro(cx.sess(), attr.span)
+            && let Some(disallowed) = self.disallowed_paths.iter().find(|disallowed| {
+                disallowed
+                    .path()
+                    .split("::")
+                    .eq(segments.iter().map(|segment| segment.name.as_str()))
+            })
+        {
+            span_lint_and_then(
+                cx,
+                DISALLOWED_ATTRIBUTES,
+                attr.span,
+                format!("use of a disallowed attribute `{}`", disallowed.path()),
+                add_reason(disallowed.reason()),
+            );
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+        self.check(cx, expr.span, None);
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'_>, stmt: &Stmt<'_>) {
+        self.check(cx, stmt.span, None);
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'_>, ty: &Ty<'_>) {
+        self.check(cx, ty.span, None);
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'_>, pat: &Pat<'_>) {
+        self.check(cx, pat.span, None);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
+        self.check(cx, item.span, self.derive_src);
+        self.check(cx, item.vis_span, None);
+
+        if matches!(
+            item.kind,
+            ItemKind::Struct(..) | ItemKind::Enum(..) | ItemKind::Union(..)
+        ) && macro_backtrace(item.span).all(|m| !matches!(m.kind, MacroKind::Derive))
+        {
+            self.derive_src = Some(item.owner_id);
+        }
+    }
+
+    fn check_foreign_item(&mut self, cx: &LateContext<'_>, item: &ForeignItem<'_>) {
+        self.check(cx, item.span, None);
+        self.check(cx, item.vis_span, None);
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'_>, item: &ImplItem<'_>) {
+        self.check(cx, item.span, None);
+        self.check(cx, item.vis_span, None);
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'_>, item: &TraitItem<'_>) {
+        self.check(cx, item.span, None);
+    }
+
+    fn check_path(&mut self, cx: &LateContext<'_>, path: &Path<'_>, _: HirId) {
+        self.check(cx, path.span, None);
+    }
+}
+
+fn add_reason(reason: Option<&'static str>) -> impl FnOnce(&mut Diag<'_, ()>) {
+    move |diag| {
+        if let Some(reason) = reason {
+            diag.note(reason);
+        }
+    }
+}
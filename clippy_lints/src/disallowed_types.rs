@@ -3,9 +3,10 @@ use clippy_utils::diagnostics::span_lint_and_then;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_hir::def::Res;
 use rustc_hir::def_id::DefIdMap;
-use rustc_hir::{Item, ItemKind, PolyTraitRef, PrimTy, Ty, TyKind, UseKind};
+use rustc_hir::{Body, Item, ItemKind, PolyTraitRef, PrimTy, Ty, TyKind, UseKind};
+use rustc_hir_analysis::lower_ty;
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty::TyCtxt;
+use rustc_middle::ty::{self, Ty as MiddleTy, TyCtxt};
 use rustc_session::impl_lint_pass;
 use rustc_span::Span;
 
@@ -31,6 +32,9 @@ declare_clippy_lint! {
     ///     # When using an inline table, can add a `reason` for why the type
     ///     # is disallowed.
     ///     { path = "std::net::Ipv4Addr", reason = "no IPv4 allowed" },
+    ///     # Can give the generic arguments to only disallow some instantiations
+    ///     # of a type, with `*` matching any type.
+    ///     { path = "std::collections::HashMap<std::string::String, *>", reason = "use a newtype" },
     /// ]
     /// ```
     ///
@@ -50,18 +54,133 @@ declare_clippy_lint! {
     "use of disallowed types"
 }
 
+/// A type pattern from the configuration.
+#[derive(Clone)]
+enum TypePattern {
+    /// `*`, matching any type.
+    Any,
+    /// A type path with patterns for its generic type arguments. Arguments which aren't given
+    /// match any type.
+    Path(Vec<Res>, Vec<TypePattern>),
+}
+
+impl TypePattern {
+    fn matches(&self, ty: MiddleTy<'_>) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Path(res, args) => {
+                res.iter().any(|res| match *res {
+                    Res::Def(_, id) => matches!(ty.kind(), ty::Adt(adt, _) if adt.did() == id),
+                    Res::PrimTy(prim) => ty.primitive_symbol() == Some(prim.name()),
+                    _ => false,
+                }) && args_match(args, ty)
+            },
+        }
+    }
+}
+
+/// Checks the generic type arguments of `ty` against `args`. Lifetime and const arguments are
+/// skipped.
+fn args_match(args: &[TypePattern], ty: MiddleTy<'_>) -> bool {
+    let ty::Adt(_, generic_args) = ty.kind() else {
+        return args.iter().all(|arg| matches!(arg, TypePattern::Any));
+    };
+    let mut tys = generic_args.types();
+    args.iter()
+        .all(|arg| tys.next().map_or(matches!(arg, TypePattern::Any), |ty| arg.matches(ty)))
+}
+
+/// Splits a type pattern such as `std::collections::HashMap<*, std::vec::Vec<*>>` into its path
+/// and the text of its generic arguments, if it has any.
+fn split_generic_args(pattern: &str) -> Option<(&str, Option<Vec<&str>>)> {
+    let Some((path, rest)) = pattern.split_once('<') else {
+        return Some((pattern.trim(), None));
+    };
+    let rest = rest.trim_end().strip_suffix('>')?;
+    let mut args = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in rest.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 => {
+                args.push(rest[start..i].trim());
+                start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    if depth != 0 {
+        return None;
+    }
+    args.push(rest[start..].trim());
+    Some((path.trim(), Some(args)))
+}
+
+/// Resolves a type pattern. Returns `None` if it's malformed.
+fn resolve_pattern(tcx: TyCtxt<'_>, pattern: &str) -> Option<TypePattern> {
+    if pattern == "*" {
+        return Some(TypePattern::Any);
+    }
+    let (path, args) = split_generic_args(pattern)?;
+    if path.is_empty() {
+        return None;
+    }
+    let path: Vec<_> = path.split("::").collect();
+    let args = args
+        .unwrap_or_default()
+        .into_iter()
+        .map(|arg| resolve_pattern(tcx, arg))
+        .collect::<Option<_>>()?;
+    Some(TypePattern::Path(clippy_utils::def_path_res(tcx, &path), args))
+}
+
+/// A `disallowed-types` entry which only applies to some generic arguments.
+struct GenericEntry {
+    /// The patterns the generic arguments have to match.
+    args: Vec<TypePattern>,
+    path: &'static str,
+    reason: Option<&'static str>,
+}
+
 pub struct DisallowedTypes {
     def_ids: DefIdMap<(&'static str, Option<&'static str>)>,
     prim_tys: FxHashMap<PrimTy, (&'static str, Option<&'static str>)>,
+    /// The types which are only disallowed with some generic arguments, with the patterns for
+    /// these arguments.
+    generic_def_ids: DefIdMap<Vec<GenericEntry>>,
+    /// The number of bodies being visited, as `lower_ty` can't be used in them.
+    in_body: usize,
 }
 
 impl DisallowedTypes {
     pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
         let mut def_ids = DefIdMap::default();
         let mut prim_tys = FxHashMap::default();
+        let mut generic_def_ids: DefIdMap<Vec<_>> = DefIdMap::default();
         for x in &conf.disallowed_types {
-            let path: Vec<_> = x.path().split("::").collect::<Vec<_>>();
             let reason = x.reason();
+            if x.path().contains(['<', '>', '*']) {
+                if let Some(TypePattern::Path(res, args)) = resolve_pattern(tcx, x.path()) {
+                    for res in res {
+                        if let Res::Def(_, id) = res {
+                            generic_def_ids.entry(id).or_default().push(GenericEntry {
+                                args: args.clone(),
+                                path: x.path(),
+                                reason,
+                            });
+                        }
+                    }
+                } else {
+                    tcx.sess.dcx().warn(format!(
+                        "the `disallowed-types` entry `{}` isn't a valid type pattern and is ignored",
+                        x.path()
+                    ));
+                }
+                continue;
+            }
+            let path: Vec<_> = x.path().split("::").collect::<Vec<_>>();
             for res in clippy_utils::def_path_res(tcx, &path) {
                 match res {
                     Res::Def(_, id) => {
@@ -74,7 +193,12 @@ impl DisallowedTypes {
                 }
             }
         }
-        Self { def_ids, prim_tys }
+        Self {
+            def_ids,
+            prim_tys,
+            generic_def_ids,
+            in_body: 0,
+        }
     }
 
     fn check_res_emit(&self, cx: &LateContext<'_>, res: &Res, span: Span) {
@@ -83,18 +207,45 @@ impl DisallowedTypes {
             Res::PrimTy(prim) if let Some(&x) = self.prim_tys.get(prim) => x,
             _ => return,
         };
-        span_lint_and_then(
-            cx,
-            DISALLOWED_TYPES,
-            span,
-            format!("use of a disallowed type `{path}`"),
-            |diag| {
-                if let Some(reason) = reason {
-                    diag.note(reason);
-                }
-            },
-        );
+        emit(cx, span, path, reason);
+    }
+
+    fn check_generic_args_emit<'tcx>(&self, cx: &LateContext<'tcx>, res: &Res, hir_ty: &Ty<'tcx>) {
+        if let Res::Def(_, did) = res
+            && let Some(patterns) = self.generic_def_ids.get(did)
+            && let Some(ty) = self.middle_ty(cx, hir_ty)
+            && let Some(entry) = patterns.iter().find(|entry| args_match(&entry.args, ty))
+        {
+            emit(cx, hir_ty.span, entry.path, entry.reason);
+        }
     }
+
+    /// Gets the type written as `hir_ty`, with its default generic arguments and the types inferred
+    /// for its `_` placeholders.
+    fn middle_ty<'tcx>(&self, cx: &LateContext<'tcx>, hir_ty: &Ty<'tcx>) -> Option<MiddleTy<'tcx>> {
+        // `lower_ty` can't be used in bodies, but the types written there are recorded by typeck
+        if self.in_body > 0 {
+            cx.maybe_typeck_results()
+                .filter(|typeck| typeck.hir_owner == hir_ty.hir_id.owner)
+                .and_then(|typeck| typeck.node_type_opt(hir_ty.hir_id))
+        } else {
+            Some(lower_ty(cx.tcx, hir_ty))
+        }
+    }
+}
+
+fn emit(cx: &LateContext<'_>, span: Span, path: &str, reason: Option<&'static str>) {
+    span_lint_and_then(
+        cx,
+        DISALLOWED_TYPES,
+        span,
+        format!("use of a disallowed type `{path}`"),
+        |diag| {
+            if let Some(reason) = reason {
+                diag.note(reason);
+            }
+        },
+    );
 }
 
 impl_lint_pass!(DisallowedTypes => [DISALLOWED_TYPES]);
@@ -110,10 +261,20 @@ impl<'tcx> LateLintPass<'tcx> for DisallowedTypes {
 
     fn check_ty(&mut self, cx: &LateContext<'tcx>, ty: &'tcx Ty<'tcx>) {
         if let TyKind::Path(path) = &ty.kind {
-            self.check_res_emit(cx, &cx.qpath_res(path, ty.hir_id), ty.span);
+            let res = cx.qpath_res(path, ty.hir_id);
+            self.check_res_emit(cx, &res, ty.span);
+            self.check_generic_args_emit(cx, &res, ty);
         }
     }
 
+    fn check_body(&mut self, _: &LateContext<'tcx>, _: &Body<'tcx>) {
+        self.in_body += 1;
+    }
+
+    fn check_body_post(&mut self, _: &LateContext<'tcx>, _: &Body<'tcx>) {
+        self.in_body -= 1;
+    }
+
     fn check_poly_trait_ref(&mut self, cx: &LateContext<'tcx>, poly: &'tcx PolyTraitRef<'tcx>) {
         self.check_res_emit(cx, &poly.trait_ref.path.res, poly.trait_ref.path.span);
     }
@@ -1,3 +1,5 @@
+use clippy_config::Conf;
+use clippy_config::types::ConfRegex;
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::source::snippet_with_applicability;
 use rustc_data_structures::fx::FxHashSet;
@@ -8,13 +10,37 @@ use url::Url;
 
 use crate::doc::DOC_MARKDOWN;
 
+/// The words which don't need backticks, from the `doc-valid-idents` and `doc-valid-idents-regex`
+/// configurations.
+pub struct ValidIdents {
+    idents: FxHashSet<&'static str>,
+    regexes: &'static [ConfRegex],
+}
+
+impl ValidIdents {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            idents: conf.doc_valid_idents.iter().map(|s| &**s).collect(),
+            regexes: &conf.doc_valid_idents_regex,
+        }
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        self.idents.contains(word) || self.regexes.iter().any(|regex| regex.is_match(word))
+    }
+}
+
+/// Checks the words of `text` for missing backticks. `reference_label` is the span and replacement
+/// keeping the label of the shortcut or collapsed reference link `text` is part of, if any. It is
+/// taken by the first suggestion, so the label is only inserted once per link.
 pub fn check(
     cx: &LateContext<'_>,
-    valid_idents: &FxHashSet<String>,
+    valid_idents: &ValidIdents,
     text: &str,
     span: Span,
     code_level: isize,
     blockquote_level: isize,
+    reference_label: &mut Option<(Span, String)>,
 ) {
     for orig_word in text.split(|c: char| c.is_whitespace() || c == '\'') {
         // Trim punctuation as in `some comment (see foo::bar).`
@@ -73,11 +99,18 @@ pub fn check(
             span.parent(),
         );
 
-        check_word(cx, word, span, code_level, blockquote_level);
+        check_word(cx, word, span, code_level, blockquote_level, reference_label);
     }
 }
 
-fn check_word(cx: &LateContext<'_>, word: &str, span: Span, code_level: isize, blockquote_level: isize) {
+fn check_word(
+    cx: &LateContext<'_>,
+    word: &str,
+    span: Span,
+    code_level: isize,
+    blockquote_level: isize,
+    reference_label: &mut Option<(Span, String)>,
+) {
     /// Checks if a string is upper-camel-case, i.e., starts with an uppercase and
     /// contains at least two uppercase letters (`Clippy` is ok) and one lower-case
     /// letter (`NASA` is ok).
@@ -145,7 +178,15 @@ fn check_word(cx: &LateContext<'_>, word: &str, span: Span, code_level: isize, b
             |diag| {
                 let mut applicability = Applicability::MachineApplicable;
                 let snippet = snippet_with_applicability(cx, span, "..", &mut applicability);
-                diag.span_suggestion_verbose(span, "try", format!("`{snippet}`"), applicability);
+                if let Some((label_span, label)) = reference_label.take() {
+                    diag.multipart_suggestion_verbose(
+                        "try",
+                        vec![(span, format!("`{snippet}`")), (label_span, label)],
+                        applicability,
+                    );
+                } else {
+                    diag.span_suggestion_verbose(span, "try", format!("`{snippet}`"), applicability);
+                }
             },
         );
     }
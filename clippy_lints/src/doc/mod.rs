@@ -10,13 +10,13 @@ use clippy_utils::macros::{is_panic, root_macro_call_first_node};
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::visitors::Visitable;
 use clippy_utils::{is_entrypoint_fn, is_trait_impl_item, method_chain_args};
+use markdown::ValidIdents;
 use pulldown_cmark::Event::{
     Code, DisplayMath, End, FootnoteReference, HardBreak, Html, InlineHtml, InlineMath, Rule, SoftBreak, Start,
     TaskListMarker, Text,
 };
-use pulldown_cmark::Tag::{BlockQuote, CodeBlock, FootnoteDefinition, Heading, Item, Link, Paragraph};
-use pulldown_cmark::{BrokenLink, CodeBlockKind, CowStr, Options, TagEnd};
-use rustc_data_structures::fx::FxHashSet;
+use pulldown_cmark::Tag::{BlockQuote, CodeBlock, FootnoteDefinition, Heading, Item, Link, Paragraph, TableCell};
+use pulldown_cmark::{BrokenLink, CodeBlockKind, CowStr, LinkType, Options, TagEnd};
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::{self, Visitor};
 use rustc_hir::{AnonConst, Attribute, Expr, ImplItemKind, ItemKind, Node, Safety, TraitItemKind};
@@ -591,14 +591,14 @@ declare_clippy_lint! {
 }
 
 pub struct Documentation {
-    valid_idents: FxHashSet<String>,
+    valid_idents: ValidIdents,
     check_private_items: bool,
 }
 
 impl Documentation {
     pub fn new(conf: &'static Conf) -> Self {
         Self {
-            valid_idents: conf.doc_valid_idents.iter().cloned().collect(),
+            valid_idents: ValidIdents::new(conf),
             check_private_items: conf.check_private_items,
         }
     }
@@ -741,7 +741,7 @@ struct DocHeaders {
 /// Others are checked elsewhere, e.g. in `check_doc` if they need access to markdown, or
 /// back in the various late lint pass methods if they need the final doc headers, like "Safety" or
 /// "Panics" sections.
-fn check_attrs(cx: &LateContext<'_>, valid_idents: &FxHashSet<String>, attrs: &[Attribute]) -> Option<DocHeaders> {
+fn check_attrs(cx: &LateContext<'_>, valid_idents: &ValidIdents, attrs: &[Attribute]) -> Option<DocHeaders> {
     /// We don't want the parser to choke on intra doc links. Since we don't
     /// actually care about rendering them, just pretend that all broken links
     /// point to a fake address.
@@ -817,7 +817,7 @@ enum Container {
 #[allow(clippy::too_many_lines)] // Only a big match statement
 fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize>)>>(
     cx: &LateContext<'_>,
-    valid_idents: &FxHashSet<String>,
+    valid_idents: &ValidIdents,
     events: Events,
     doc: &str,
     fragments: Fragments<'_>,
@@ -826,6 +826,7 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
     let mut headers = DocHeaders::default();
     let mut in_code = false;
     let mut in_link = None;
+    let mut reference_label = None;
     let mut in_heading = false;
     let mut in_footnote_definition = false;
     let mut is_rust = false;
@@ -833,7 +834,7 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
     let mut ignore = false;
     let mut edition = None;
     let mut ticks_unbalanced = false;
-    let mut text_to_check: Vec<(CowStr<'_>, Range<usize>, isize)> = Vec::new();
+    let mut text_to_check: Vec<(CowStr<'_>, Range<usize>, isize, Option<ReferenceLabelFix>)> = Vec::new();
     let mut paragraph_range = 0..0;
     let mut code_level = 0;
     let mut blockquote_level = 0;
@@ -915,8 +916,18 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
                 is_rust = false;
                 ignore = false;
             },
-            Start(Link { dest_url, .. }) => in_link = Some(dest_url),
-            End(TagEnd::Link) => in_link = None,
+            Start(Link { link_type, dest_url, .. }) => {
+                reference_label = reference_label_fix(doc, link_type, range.clone());
+                in_link = Some(dest_url);
+            },
+            End(TagEnd::Link) => {
+                in_link = None;
+                reference_label = None;
+            },
+            Start(TableCell) => {
+                ticks_unbalanced = false;
+                paragraph_range = range;
+            },
             Start(Heading { .. } | Paragraph | Item) => {
                 if let Start(Heading { .. }) = event {
                     in_heading = true;
@@ -966,7 +977,7 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
                     is_first_paragraph = false;
                 }
             },
-            End(TagEnd::Heading(_) | TagEnd::Paragraph | TagEnd::Item) => {
+            End(TagEnd::Heading(_) | TagEnd::Paragraph | TagEnd::Item | TagEnd::TableCell) => {
                 if let End(TagEnd::Heading(_)) = event {
                     in_heading = false;
                 }
@@ -983,9 +994,27 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
                         "a backtick may be missing a pair",
                     );
                 } else {
-                    for (text, range, assoc_code_level) in text_to_check {
+                    // The label of a link is only inserted by the first suggestion, even if the
+                    // link text is split into several events
+                    let mut fixed_labels = Vec::new();
+                    for (text, range, assoc_code_level, reference_label) in text_to_check {
                         if let Some(span) = fragments.span(cx, range) {
-                            markdown::check(cx, valid_idents, &text, span, assoc_code_level, blockquote_level);
+                            let label_range = reference_label.as_ref().map(|(range, _)| range.clone());
+                            let mut reference_label = reference_label
+                                .filter(|(range, _)| !fixed_labels.contains(range))
+                                .and_then(|(range, label)| fragments.span(cx, range).map(|span| (span, label)));
+                            markdown::check(
+                                cx,
+                                valid_idents,
+                                &text,
+                                span,
+                                assoc_code_level,
+                                blockquote_level,
+                                &mut reference_label,
+                            );
+                            if reference_label.is_none() {
+                                fixed_labels.extend(label_range);
+                            }
                         }
                     }
                 }
@@ -1055,7 +1084,7 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
                         // Don't check the text associated with external URLs
                         continue;
                     }
-                    text_to_check.push((text, range, code_level));
+                    text_to_check.push((text, range, code_level, reference_label.clone()));
                 }
             }
             FootnoteReference(_) => {}
@@ -1064,6 +1093,29 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
     headers
 }
 
+/// The range of the doc string to replace to keep the label of a reference link, and its
+/// replacement.
+type ReferenceLabelFix = (Range<usize>, String);
+
+/// Backticks added to the text of a shortcut or collapsed reference link (`[foo_bar]` or
+/// `[foo_bar][]`) would also change its label, so the link would no longer match its reference
+/// definition. For such a link this returns the range which has to be replaced to keep the label
+/// explicitly, along with its replacement.
+fn reference_label_fix(doc: &str, link_type: LinkType, range: Range<usize>) -> Option<ReferenceLabelFix> {
+    let link = doc.get(range.clone())?.strip_prefix('[')?;
+    let (label, fix_range) = match link_type {
+        LinkType::Shortcut => (link.strip_suffix(']')?, range.end..range.end),
+        // The range of a collapsed link ends before its trailing `[]`.
+        LinkType::Collapsed if doc.get(range.end..)?.starts_with("[]") => {
+            (link.strip_suffix(']')?, range.end..range.end + 2)
+        },
+        _ => return None,
+    };
+    // Link labels are matched with any sequence of whitespace collapsed into a single space.
+    let label = label.split_whitespace().collect::<Vec<_>>().join(" ");
+    Some((fix_range, format!("[{label}]")))
+}
+
 struct FindPanicUnwrap<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     is_const: bool,
@@ -1,15 +1,27 @@
-use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
 use clippy_utils::source::{reindent_multiline, snippet_indent, snippet_with_applicability, snippet_with_context};
+use clippy_utils::sugg::import_path;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::usage::local_used_after_expr;
+use clippy_utils::visitors::{for_each_expr, is_local_used};
 use clippy_utils::{
-    SpanlessEq, can_move_expr_to_closure_no_visit, higher, is_expr_final_block_expr, is_expr_used_or_unified,
-    peel_hir_expr_while,
+    SpanlessEq, binop_traits, can_move_expr_to_closure_no_visit, get_parent_expr, higher, is_expr_final_block_expr,
+    is_expr_used_or_unified, is_res_lang_ctor, path_to_local, path_to_local_id, peel_blocks_with_stmt,
+    peel_hir_expr_while, span_contains_comment, unused_name,
 };
 use core::fmt::{self, Write};
+use core::ops::ControlFlow;
 use rustc_errors::Applicability;
+use rustc_hir::LangItem::OptionSome;
 use rustc_hir::hir_id::HirIdSet;
 use rustc_hir::intravisit::{Visitor, walk_expr};
-use rustc_hir::{Block, Expr, ExprKind, HirId, Pat, Stmt, StmtKind, UnOp};
+use rustc_hir::{
+    BinOpKind, BindingMode, Block, BorrowKind, Expr, ExprKind, HirId, LetStmt, Mutability, Node, Pat, PatKind, Stmt,
+    StmtKind, UnOp,
+};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::GenericArgKind;
 use rustc_session::declare_lint_pass;
 use rustc_span::{DUMMY_SP, Span, SyntaxContext, sym};
 
@@ -56,15 +68,68 @@ declare_clippy_lint! {
     "use of `contains_key` followed by `insert` on a `HashMap` or `BTreeMap`"
 }
 
-declare_lint_pass!(HashMapPass => [MAP_ENTRY]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for updates of a `HashMap` or `BTreeMap` value which are paired with a separate
+    /// `insert` for when the key is missing, where that `insert` is equivalent to applying the
+    /// update to the default value.
+    ///
+    /// ### Why is this bad?
+    /// The key is looked up twice. Using `entry(..).or_default()` only needs a single lookup and
+    /// is shorter.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # let mut map: HashMap<&str, u32> = HashMap::new();
+    /// # let k = "a";
+    /// if let Some(v) = map.get_mut(&k) {
+    ///     *v += 1;
+    /// } else {
+    ///     map.insert(k, 1);
+    /// }
+    ///
+    /// let count = map.get(&k).copied().unwrap_or_default();
+    /// map.insert(k, count + 1);
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # let mut map: HashMap<&str, u32> = HashMap::new();
+    /// # let k = "a";
+    /// *map.entry(k).or_default() += 1;
+    ///
+    /// *map.entry(k).or_default() += 1;
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub HASH_MAP_ENTRY_API_OR_DEFAULT,
+    perf,
+    "updating a map value with a separate fallback `insert` instead of using `entry(..).or_default()`"
+}
+
+declare_lint_pass!(HashMapPass => [MAP_ENTRY, HASH_MAP_ENTRY_API_OR_DEFAULT]);
 
 impl<'tcx> LateLintPass<'tcx> for HashMapPass {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if block.span.from_expansion() {
+            return;
+        }
+        for [first, second] in block.stmts.array_windows() {
+            // The value returned by `insert` has to be unused.
+            if let StmtKind::Semi(insert_expr) = second.kind {
+                check_get_then_insert(cx, first, second, insert_expr);
+            }
+        }
+    }
+
     #[expect(clippy::too_many_lines)]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if expr.span.from_expansion() {
             return;
         }
 
+        check_get_mut_else_insert(cx, expr);
+
         let Some(higher::If {
             cond: cond_expr,
             then: then_expr,
@@ -85,6 +150,8 @@ impl<'tcx> LateLintPass<'tcx> for HashMapPass {
         let mut app = Applicability::MachineApplicable;
         let map_str = snippet_with_context(cx, contains_expr.map.span, contains_expr.call_ctxt, "..", &mut app).0;
         let key_str = snippet_with_context(cx, contains_expr.key.span, contains_expr.call_ctxt, "..", &mut app).0;
+        let entry = import_path(cx, expr.hir_id, map_ty.entry_path());
+        let with_entry = |sugg| entry.with_import(vec![(expr.span, sugg)]);
         let sugg = if let Some(else_expr) = else_expr {
             let Some(else_search) = find_insert_calls(cx, &contains_expr, else_expr) else {
                 return;
@@ -113,10 +180,10 @@ impl<'tcx> LateLintPass<'tcx> for HashMapPass {
                         snippet_with_applicability(cx, then_expr.span, "{ .. }", &mut app),
                     ),
                 };
-                format!(
+                with_entry(format!(
                     "if let {}::{entry_kind} = {map_str}.entry({key_str}) {then_str} else {else_str}",
-                    map_ty.entry_path(),
-                )
+                    entry.path,
+                ))
             } else {
                 // if .. { insert } else { insert }
                 let ((then_str, then_entry), (else_str, else_entry)) = if contains_expr.negated {
@@ -132,13 +199,13 @@ impl<'tcx> LateLintPass<'tcx> for HashMapPass {
                 };
                 let indent_str = snippet_indent(cx, expr.span);
                 let indent_str = indent_str.as_deref().unwrap_or("");
-                format!(
+                with_entry(format!(
                     "match {map_str}.entry({key_str}) {{\n{indent_str}    {entry}::{then_entry} => {}\n\
                         {indent_str}    {entry}::{else_entry} => {}\n{indent_str}}}",
                     reindent_multiline(then_str.into(), true, Some(4 + indent_str.len())),
                     reindent_multiline(else_str.into(), true, Some(4 + indent_str.len())),
-                    entry = map_ty.entry_path(),
-                )
+                    entry = entry.path,
+                ))
             }
         } else {
             if then_search.edits.is_empty() {
@@ -153,18 +220,44 @@ impl<'tcx> LateLintPass<'tcx> for HashMapPass {
                 } else {
                     then_search.snippet_occupied(cx, then_expr.span, &mut app)
                 };
-                format!(
+                with_entry(format!(
                     "if let {}::{entry_kind} = {map_str}.entry({key_str}) {body_str}",
-                    map_ty.entry_path(),
-                )
+                    entry.path,
+                ))
             } else if let Some(insertion) = then_search.as_single_insertion() {
                 let value_str = snippet_with_context(cx, insertion.value.span, then_expr.span.ctxt(), "..", &mut app).0;
                 if contains_expr.negated {
-                    if insertion.value.can_have_side_effects() {
+                    let sugg = if value_borrows_map_owner(cx, insertion.value, contains_expr.map) {
+                        // The entry borrows the map for as long as it lives, so the value has to be
+                        // computed before looking it up.
+                        if !is_stmt_or_block_tail(cx, expr) {
+                            return;
+                        }
+                        // The new binding mustn't shadow a name used anywhere else in the body
+                        let Some(body_id) = cx.enclosing_body else {
+                            return;
+                        };
+                        let body = cx.tcx.hir().body(body_id).value;
+                        let value_name = unused_name("value", body, cx);
+                        let value_ty = cx.typeck_results().expr_ty(insertion.value);
+                        if !switch_to_eager_eval(cx, insertion.value)
+                            || value_ty
+                                .walk()
+                                .any(|arg| matches!(arg.unpack(), GenericArgKind::Lifetime(_)))
+                        {
+                            app = Applicability::MaybeIncorrect;
+                        }
+                        let indent_str = snippet_indent(cx, expr.span);
+                        format!(
+                            "let {value_name} = {value_str};\n{}{map_str}.entry({key_str}).or_insert({value_name});",
+                            indent_str.as_deref().unwrap_or(""),
+                        )
+                    } else if insertion.value.can_have_side_effects() {
                         format!("{map_str}.entry({key_str}).or_insert_with(|| {value_str});")
                     } else {
                         format!("{map_str}.entry({key_str}).or_insert({value_str});")
-                    }
+                    };
+                    vec![(expr.span, sugg)]
                 } else {
                     // TODO: suggest using `if let Some(v) = map.get_mut(k) { .. }` here.
                     // This would need to be a different lint.
@@ -173,7 +266,10 @@ impl<'tcx> LateLintPass<'tcx> for HashMapPass {
             } else {
                 let block_str = then_search.snippet_closure(cx, then_expr.span, &mut app);
                 if contains_expr.negated {
-                    format!("{map_str}.entry({key_str}).or_insert_with(|| {block_str});")
+                    vec![(
+                        expr.span,
+                        format!("{map_str}.entry({key_str}).or_insert_with(|| {block_str});"),
+                    )]
                 } else {
                     // TODO: suggest using `if let Some(v) = map.get_mut(k) { .. }` here.
                     // This would need to be a different lint.
@@ -182,18 +278,228 @@ impl<'tcx> LateLintPass<'tcx> for HashMapPass {
             }
         };
 
-        span_lint_and_sugg(
+        span_lint_and_then(
             cx,
             MAP_ENTRY,
             expr.span,
             format!("usage of `contains_key` followed by `insert` on a `{}`", map_ty.name()),
+            |diag| {
+                diag.multipart_suggestion("try", sugg, app);
+            },
+        );
+    }
+}
+
+/// Checks for
+/// ```ignore
+/// if let Some(v) = map.get_mut(&k) {
+///     *v += n;
+/// } else {
+///     map.insert(k, n);
+/// }
+/// ```
+/// where inserting `n` is the same as applying the update to the default value of an integer.
+fn check_get_mut_else_insert<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+    if let Some(higher::IfLet {
+        let_pat,
+        let_expr,
+        if_then,
+        if_else: Some(if_else),
+        ..
+    }) = higher::IfLet::hir(cx, expr)
+        && let PatKind::TupleStruct(ref qpath, [binding], ddpos) = let_pat.kind
+        && ddpos.as_opt_usize().is_none()
+        && is_res_lang_ctor(cx, cx.qpath_res(qpath, let_pat.hir_id), OptionSome)
+        && let PatKind::Binding(BindingMode::NONE, binding_id, _, None) = binding.kind
+        && let Some((map_ty, map, key)) = try_parse_get(cx, let_expr, "get_mut")
+        && let ExprKind::AssignOp(op, lhs, rhs) = peel_blocks_with_stmt(if_then).kind
+        && let ExprKind::Unary(UnOp::Deref, deref_target) = lhs.kind
+        && path_to_local_id(deref_target, binding_id)
+        // `0 + n`, `0 | n` and `0 ^ n` are all `n`
+        && matches!(op.node, BinOpKind::Add | BinOpKind::BitOr | BinOpKind::BitXor)
+        && cx.typeck_results().expr_ty(lhs).is_integral()
+        && let Some(insert) = try_parse_insert(cx, peel_blocks_with_stmt(if_else))
+        && let mut eq = SpanlessEq::new(cx)
+        && eq.eq_expr(map, insert.map)
+        && eq.eq_expr(key, insert.key)
+        && eq.eq_expr(rhs, insert.value)
+        && !rhs.can_have_side_effects()
+        && !span_contains_comment(cx.tcx.sess.source_map(), expr.span)
+    {
+        let mut app = Applicability::MachineApplicable;
+        let ctxt = expr.span.ctxt();
+        let map_str = snippet_with_context(cx, insert.map.span, ctxt, "..", &mut app).0;
+        let key_str = snippet_with_context(cx, insert.key.span, ctxt, "..", &mut app).0;
+        let rhs_str = snippet_with_context(cx, rhs.span, ctxt, "..", &mut app).0;
+        let semi = if let Node::Stmt(Stmt {
+            kind: StmtKind::Expr(_),
+            ..
+        }) = cx.tcx.parent_hir_node(expr.hir_id)
+        {
+            ";"
+        } else {
+            ""
+        };
+        span_lint_and_sugg(
+            cx,
+            HASH_MAP_ENTRY_API_OR_DEFAULT,
+            expr.span,
+            format!("usage of `get_mut` followed by `insert` on a `{}`", map_ty.name()),
             "try",
-            sugg,
+            format!(
+                "*{map_str}.entry({key_str}).or_default() {}= {rhs_str}{semi}",
+                op.node.as_str()
+            ),
             app,
         );
     }
 }
 
+/// Checks for
+/// ```ignore
+/// let x = map.get(&k).copied().unwrap_or_default();
+/// map.insert(k, x + n);
+/// ```
+/// where `x` isn't used afterwards.
+fn check_get_then_insert<'tcx>(
+    cx: &LateContext<'tcx>,
+    get_stmt: &'tcx Stmt<'tcx>,
+    insert_stmt: &'tcx Stmt<'tcx>,
+    insert_expr: &'tcx Expr<'tcx>,
+) {
+    if let StmtKind::Let(LetStmt {
+        pat,
+        init: Some(init),
+        els: None,
+        ..
+    }) = get_stmt.kind
+        && let PatKind::Binding(BindingMode::NONE, binding_id, _, None) = pat.kind
+        && let ExprKind::MethodCall(unwrap_path, copied_expr, [], _) = init.kind
+        && unwrap_path.ident.name.as_str() == "unwrap_or_default"
+        && let ExprKind::MethodCall(copied_path, get_expr, [], _) = copied_expr.kind
+        && matches!(copied_path.ident.name.as_str(), "cloned" | "copied")
+        && let Some((map_ty, map, key)) = try_parse_get(cx, get_expr, "get")
+        && let Some(insert) = try_parse_insert(cx, insert_expr)
+        && let ExprKind::Binary(op, lhs, rhs) = insert.value.kind
+        && binop_traits(op.node).is_some()
+        && path_to_local_id(lhs, binding_id)
+        && let ty = cx.typeck_results().expr_ty(lhs)
+        && (ty.is_integral() || ty.is_floating_point())
+        && let mut eq = SpanlessEq::new(cx)
+        && eq.eq_expr(map, insert.map)
+        && eq.eq_expr(key, insert.key)
+        // The map can't be borrowed while the entry is alive.
+        && !contains_expr(cx, rhs, map)
+        && !is_local_used(cx, rhs, binding_id)
+        && !is_local_used(cx, insert.key, binding_id)
+        && !local_used_after_expr(cx, binding_id, insert_expr)
+        && !get_stmt.span.from_expansion()
+        && !insert_stmt.span.from_expansion()
+        && let span = get_stmt.span.to(insert_stmt.span)
+        && !span_contains_comment(cx.tcx.sess.source_map(), span)
+    {
+        let mut app = Applicability::MachineApplicable;
+        let ctxt = span.ctxt();
+        let map_str = snippet_with_context(cx, insert.map.span, ctxt, "..", &mut app).0;
+        let key_str = snippet_with_context(cx, insert.key.span, ctxt, "..", &mut app).0;
+        let rhs_str = snippet_with_context(cx, rhs.span, ctxt, "..", &mut app).0;
+        span_lint_and_sugg(
+            cx,
+            HASH_MAP_ENTRY_API_OR_DEFAULT,
+            span,
+            format!("usage of `get` followed by `insert` on a `{}`", map_ty.name()),
+            "try",
+            format!(
+                "*{map_str}.entry({key_str}).or_default() {}= {rhs_str};",
+                op.node.as_str()
+            ),
+            app,
+        );
+    }
+}
+
+/// Checks whether computing `value` while the entry of `map` is alive would conflict with the
+/// borrow of the map, because it uses the local variable owning the map. Other fields of that
+/// variable are borrowed separately.
+fn value_borrows_map_owner<'tcx>(cx: &LateContext<'tcx>, value: &'tcx Expr<'tcx>, map: &Expr<'_>) -> bool {
+    let mut map_fields = Vec::new();
+    let owner = peel_hir_expr_while(map, |e| {
+        if let ExprKind::Field(base, field) = e.kind {
+            map_fields.push(field.name);
+            Some(base)
+        } else {
+            None
+        }
+    });
+    map_fields.reverse();
+    let Some(owner_id) = path_to_local(owner) else {
+        return false;
+    };
+
+    for_each_expr(cx, value, |e| {
+        if path_to_local_id(e, owner_id) {
+            let mut fields = Vec::new();
+            let mut used = e;
+            while let Some(parent) = get_parent_expr(cx, used)
+                && let ExprKind::Field(_, field) = parent.kind
+            {
+                fields.push(field.name);
+                used = parent;
+            }
+            if map_fields
+                .iter()
+                .zip(&fields)
+                .all(|(map_field, field)| map_field == field)
+            {
+                return ControlFlow::Break(());
+            }
+        }
+        ControlFlow::Continue(())
+    })
+    .is_some()
+}
+
+/// Checks whether the expression is a statement or the final expression of a block, so that
+/// statements can be put before it.
+fn is_stmt_or_block_tail(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    matches!(cx.tcx.parent_hir_node(expr.hir_id), Node::Stmt(_)) || is_expr_final_block_expr(cx.tcx, expr)
+}
+
+/// Parses `map.{method}(&key)` on a `BTreeMap` or a `HashMap`.
+fn try_parse_get<'tcx>(
+    cx: &LateContext<'_>,
+    expr: &'tcx Expr<'tcx>,
+    method: &str,
+) -> Option<(MapType, &'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let ExprKind::MethodCall(path, map, [arg], _) = expr.kind
+        && path.ident.name.as_str() == method
+        && let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, key) = arg.kind
+    {
+        let map_ty = cx.typeck_results().expr_ty(map).peel_refs();
+        if is_type_diagnostic_item(cx, map_ty, sym::HashMap) {
+            Some((MapType::Hash, map, key))
+        } else if is_type_diagnostic_item(cx, map_ty, sym::BTreeMap) {
+            Some((MapType::BTree, map, key))
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
+/// Checks whether `haystack` contains an expression equal to `needle`.
+fn contains_expr<'tcx>(cx: &LateContext<'tcx>, haystack: &'tcx Expr<'tcx>, needle: &Expr<'_>) -> bool {
+    for_each_expr(cx, haystack, |e| {
+        if SpanlessEq::new(cx).eq_expr(e, needle) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
 #[derive(Clone, Copy)]
 enum MapType {
     Hash,
@@ -206,10 +512,10 @@ impl MapType {
             Self::BTree => "BTreeMap",
         }
     }
-    fn entry_path(self) -> &'static str {
+    fn entry_path(self) -> &'static [&'static str] {
         match self {
-            Self::Hash => "std::collections::hash_map::Entry",
-            Self::BTree => "std::collections::btree_map::Entry",
+            Self::Hash => &["std", "collections", "hash_map", "Entry"],
+            Self::BTree => &["std", "collections", "btree_map", "Entry"],
         }
     }
 }
@@ -0,0 +1,152 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::peel_blocks;
+use rustc_ast::LitKind;
+use rustc_data_structures::fx::{FxHashMap, FxIndexMap};
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{Expr, ExprKind, Item, ItemKind, MatchSource, VariantData};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::impl_lint_pass;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for enums with exactly two fieldless variants named like a boolean switch
+    /// (e.g. `Enabled`/`Disabled`, `Yes`/`No` or `On`/`Off`) which are only ever matched
+    /// to produce a `bool`.
+    ///
+    /// ### Why is this bad?
+    /// If every use of the enum immediately converts it back into a `bool`, the enum adds
+    /// boilerplate without adding clarity. A newtype over `bool`, or deriving `PartialEq`
+    /// and comparing against a variant, expresses the same thing more directly.
+    ///
+    /// ### Known problems
+    /// Only usages in the current crate are considered, so exported enums are not linted.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// enum Mode {
+    ///     On,
+    ///     Off,
+    /// }
+    ///
+    /// fn is_on(mode: Mode) -> bool {
+    ///     match mode {
+    ///         Mode::On => true,
+    ///         Mode::Off => false,
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// struct Mode(bool);
+    ///
+    /// fn is_on(mode: Mode) -> bool {
+    ///     mode.0
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub ENUM_VARIANT_BOOL_PAIR,
+    pedantic,
+    "two-variant enums mirroring `bool` which are only matched to produce booleans"
+}
+
+impl_lint_pass!(EnumVariantBoolPair => [ENUM_VARIANT_BOOL_PAIR]);
+
+/// Variant name pairs which mirror `true` and `false`.
+const BOOL_PAIRS: &[(&str, &str)] = &[
+    ("Enabled", "Disabled"),
+    ("Enable", "Disable"),
+    ("Yes", "No"),
+    ("On", "Off"),
+    ("True", "False"),
+];
+
+#[derive(Default)]
+struct EnumUsage {
+    bool_matches: usize,
+    other_uses: bool,
+}
+
+#[derive(Default)]
+pub struct EnumVariantBoolPair {
+    /// The enums with a boolean pair of variants, and their spans.
+    enums: FxIndexMap<LocalDefId, Span>,
+    /// The uses of all local enums. An enum may be used before its definition is visited, so
+    /// the uses are only matched to the enums once the whole crate was checked.
+    usages: FxHashMap<LocalDefId, EnumUsage>,
+}
+
+impl EnumVariantBoolPair {
+    fn usage_of(&mut self, cx: &LateContext<'_>, scrutinee: &Expr<'_>) -> Option<&mut EnumUsage> {
+        let adt = cx.typeck_results().expr_ty(scrutinee).peel_refs().ty_adt_def()?;
+        if adt.is_enum() && adt.variants().len() == 2 {
+            Some(self.usages.entry(adt.did().as_local()?).or_default())
+        } else {
+            None
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for EnumVariantBoolPair {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Enum(def, _) = &item.kind
+            && let [first, second] = def.variants
+            && matches!(first.data, VariantData::Unit(..))
+            && matches!(second.data, VariantData::Unit(..))
+            && let (first, second) = (first.ident.as_str(), second.ident.as_str())
+            && BOOL_PAIRS
+                .iter()
+                .any(|&(t, f)| (t, f) == (first, second) || (f, t) == (first, second))
+            && !in_external_macro(cx.sess(), item.span)
+            && !cx.effective_visibilities.is_exported(item.owner_id.def_id)
+        {
+            self.enums.insert(item.owner_id.def_id, item.span);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        match expr.kind {
+            ExprKind::Match(scrutinee, arms, MatchSource::Normal) => {
+                if let Some(usage) = self.usage_of(cx, scrutinee) {
+                    if arms.iter().all(|arm| arm.guard.is_none() && is_bool_lit(arm.body)) {
+                        usage.bool_matches += 1;
+                    } else {
+                        usage.other_uses = true;
+                    }
+                }
+            },
+            // `if let` and `let .. else` use the enum for control flow, which is fine.
+            ExprKind::Let(let_expr) => {
+                if let Some(usage) = self.usage_of(cx, let_expr.init) {
+                    usage.other_uses = true;
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (&def_id, &span) in &self.enums {
+            if let Some(usage) = self.usages.get(&def_id)
+                && usage.bool_matches > 0
+                && !usage.other_uses
+            {
+                span_lint_hir_and_then(
+                    cx,
+                    ENUM_VARIANT_BOOL_PAIR,
+                    cx.tcx.local_def_id_to_hir_id(def_id),
+                    span,
+                    "this enum mirrors `bool` and is only matched to produce booleans",
+                    |diag| {
+                        diag.help("consider using a newtype over `bool`, or comparing against a variant with `==`");
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn is_bool_lit(expr: &Expr<'_>) -> bool {
+    matches!(peel_blocks(expr).kind, ExprKind::Lit(lit) if matches!(lit.node, LitKind::Bool(_)))
+}
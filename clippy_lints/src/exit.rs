@@ -1,7 +1,8 @@
-use clippy_utils::diagnostics::span_lint;
-use clippy_utils::is_entrypoint_fn;
+use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
+use clippy_utils::{is_entrypoint_fn, match_def_path, paths};
 use rustc_hir::{Expr, ExprKind, Item, ItemKind, OwnerNode};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::config::CrateType;
 use rustc_session::declare_lint_pass;
 use rustc_span::sym;
 
@@ -39,21 +40,75 @@ declare_clippy_lint! {
     "detects `std::process::exit` calls"
 }
 
-declare_lint_pass!(Exit => [EXIT]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Detects calls to `std::process::exit` and `std::process::abort` in library crates.
+    ///
+    /// ### Why is this bad?
+    /// A library cannot know how the program using it wants to handle a failure. Terminating
+    /// the process takes that decision away from the caller, skips destructors and makes the
+    /// library unusable in contexts such as servers or test harnesses.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// pub fn load(path: &str) -> String {
+    ///     match std::fs::read_to_string(path) {
+    ///         Ok(s) => s,
+    ///         Err(_) => std::process::exit(1),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// pub fn load(path: &str) -> std::io::Result<String> {
+    ///     std::fs::read_to_string(path)
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub EXIT_IN_LIBRARY_CODE,
+    suspicious,
+    "`std::process::exit` or `std::process::abort` calls in library crates"
+}
+
+declare_lint_pass!(Exit => [EXIT, EXIT_IN_LIBRARY_CODE]);
 
 impl<'tcx> LateLintPass<'tcx> for Exit {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
-        if let ExprKind::Call(path_expr, [_]) = e.kind
+        if let ExprKind::Call(path_expr, args) = e.kind
             && let ExprKind::Path(ref path) = path_expr.kind
             && let Some(def_id) = cx.qpath_res(path, path_expr.hir_id).opt_def_id()
-            && cx.tcx.is_diagnostic_item(sym::process_exit, def_id)
-            && let parent = cx.tcx.hir().get_parent_item(e.hir_id)
-            && let OwnerNode::Item(Item{kind: ItemKind::Fn{ .. }, ..}) = cx.tcx.hir_owner_node(parent)
-            // If the next item up is a function we check if it is an entry point
-            // and only then emit a linter warning
-            && !is_entrypoint_fn(cx, parent.to_def_id())
         {
-            span_lint(cx, EXIT, e.span, "usage of `process::exit`");
+            let is_exit = cx.tcx.is_diagnostic_item(sym::process_exit, def_id);
+            if !is_exit && !match_def_path(cx, def_id, &paths::ABORT) {
+                return;
+            }
+
+            if !cx.tcx.crate_types().contains(&CrateType::Executable) {
+                let name = if is_exit { "exit" } else { "abort" };
+                span_lint_and_help(
+                    cx,
+                    EXIT_IN_LIBRARY_CODE,
+                    e.span,
+                    format!("usage of `process::{name}` in library code"),
+                    None,
+                    "consider returning an error to the caller instead",
+                );
+            }
+            if is_exit && args.len() == 1 {
+                check_exit(cx, e);
+            }
         }
     }
 }
+
+fn check_exit(cx: &LateContext<'_>, e: &Expr<'_>) {
+    let parent = cx.tcx.hir().get_parent_item(e.hir_id);
+    if let OwnerNode::Item(Item{kind: ItemKind::Fn{ .. }, ..}) = cx.tcx.hir_owner_node(parent)
+        // If the next item up is a function we check if it is an entry point
+        // and only then emit a linter warning
+        && !is_entrypoint_fn(cx, parent.to_def_id())
+    {
+        span_lint(cx, EXIT, e.span, "usage of `process::exit`");
+    }
+}
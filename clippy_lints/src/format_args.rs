@@ -1,5 +1,6 @@
 use arrayvec::ArrayVec;
 use clippy_config::Conf;
+use clippy_utils::consts::{ConstEvalCtxt, Constant};
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::is_diag_trait_item;
 use clippy_utils::macros::{
@@ -12,8 +13,8 @@ use clippy_utils::source::SpanRangeExt;
 use clippy_utils::ty::{implements_trait, is_type_lang_item};
 use itertools::Itertools;
 use rustc_ast::{
-    FormatArgPosition, FormatArgPositionKind, FormatArgsPiece, FormatArgumentKind, FormatCount, FormatOptions,
-    FormatPlaceholder, FormatTrait,
+    FormatAlignment, FormatArgPosition, FormatArgPositionKind, FormatArgsPiece, FormatArgumentKind, FormatCount,
+    FormatDebugHex, FormatOptions, FormatPlaceholder, FormatSign, FormatTrait,
 };
 use rustc_errors::Applicability;
 use rustc_errors::SuggestionStyle::{CompletelyHidden, ShowCode};
@@ -136,6 +137,9 @@ declare_clippy_lint! {
     /// Detects [formatting parameters] that have no effect on the output of
     /// `format!()`, `println!()` or similar macros.
     ///
+    /// This includes format specifiers applied to `format_args!()`, a precision
+    /// applied to an integer and a width given by an argument which is zero.
+    ///
     /// ### Why is this bad?
     /// Shorter format specifiers are easier to read, it may also indicate that
     /// an expected formatting operation such as adding padding isn't happening.
@@ -145,11 +149,15 @@ declare_clippy_lint! {
     /// println!("{:.}", 1.0);
     ///
     /// println!("not padded: {:5}", format_args!("..."));
+    ///
+    /// println!("{:.2}", 10);
     /// ```
     /// Use instead:
     /// ```no_run
     /// println!("{}", 1.0);
     ///
+    /// println!("{}", 10);
+    ///
     /// println!("not padded: {}", format_args!("..."));
     /// // OR
     /// println!("padded: {:5}", format!("..."));
@@ -228,6 +236,7 @@ impl FormatArgsExpr<'_, '_> {
                 && let Some(arg_expr) = find_format_arg_expr(self.expr, arg)
             {
                 self.check_unused_format_specifier(placeholder, arg_expr);
+                self.check_no_op_format_counts(placeholder, arg_expr);
 
                 if placeholder.format_trait == FormatTrait::Display
                     && placeholder.format_options == FormatOptions::default()
@@ -291,6 +300,139 @@ impl FormatArgsExpr<'_, '_> {
         }
     }
 
+    /// Checks for a precision applied to an integer and for a width given by an argument which
+    /// evaluates to zero, neither of which has an effect.
+    fn check_no_op_format_counts(&self, placeholder: &FormatPlaceholder, arg: &Expr<'_>) {
+        let options = &placeholder.format_options;
+        let ty = self.cx.typeck_results().expr_ty(arg).peel_refs();
+        if is_type_lang_item(self.cx, ty, LangItem::FormatArguments) {
+            // Handled by `check_unused_format_specifier`
+            return;
+        }
+
+        let (msg, count, new_options) = if let Some(precision) = &options.precision
+            && ty.is_integral()
+            && !matches!(placeholder.format_trait, FormatTrait::LowerExp | FormatTrait::UpperExp)
+        {
+            let new_options = FormatOptions {
+                precision: None,
+                ..options.clone()
+            };
+            ("precision has no effect on integers", precision, new_options)
+        } else if let Some(width @ FormatCount::Argument(position)) = &options.width
+            && let Ok(index) = position.index
+            && let Some(width_arg) = self.format_args.arguments.all_args().get(index)
+            && let Some(width_expr) = find_format_arg_expr(self.expr, width_arg)
+            && let Some(Constant::Int(0)) = ConstEvalCtxt::new(self.cx).eval(width_expr)
+        {
+            let new_options = FormatOptions {
+                width: None,
+                ..options.clone()
+            };
+            ("a width of zero has no effect", width, new_options)
+        } else {
+            return;
+        };
+
+        let Some(placeholder_span) = placeholder.span else {
+            return;
+        };
+
+        // Removing the only reference to an explicit argument leaves it unused, and removing a `*`
+        // shifts the following implicit arguments
+        let removable = match count {
+            FormatCount::Literal(_) => true,
+            FormatCount::Argument(position) => {
+                position.kind != FormatArgPositionKind::Implicit
+                    && position.index.is_ok_and(|index| {
+                        self.is_aliased(index)
+                            || matches!(
+                                self.format_args.arguments.all_args()[index].kind,
+                                FormatArgumentKind::Captured(_)
+                            )
+                    })
+            },
+        };
+
+        span_lint_and_then(self.cx, UNUSED_FORMAT_SPECS, placeholder_span, msg, |diag| {
+            if removable
+                && let Some(format_span) = format_placeholder_format_span(placeholder)
+                && let Some(spec) = self.render_format_spec(placeholder.format_trait, &new_options)
+            {
+                diag.span_suggestion_verbose(format_span, "remove it", spec, Applicability::MachineApplicable);
+            } else {
+                diag.help("remove it together with the argument it refers to");
+            }
+        });
+    }
+
+    /// Renders the format spec `:...` for the given options, or an empty string if there is none.
+    fn render_format_spec(&self, format_trait: FormatTrait, options: &FormatOptions) -> Option<String> {
+        let mut spec = String::new();
+        if let Some(fill) = options.fill {
+            spec.push(fill);
+        }
+        if let Some(alignment) = options.alignment {
+            spec.push(match alignment {
+                FormatAlignment::Left => '<',
+                FormatAlignment::Right => '>',
+                FormatAlignment::Center => '^',
+            });
+        }
+        if let Some(sign) = options.sign {
+            spec.push(match sign {
+                FormatSign::Plus => '+',
+                FormatSign::Minus => '-',
+            });
+        }
+        if options.alternate {
+            spec.push('#');
+        }
+        if options.zero_pad {
+            spec.push('0');
+        }
+        if let Some(width) = &options.width {
+            spec.push_str(&self.render_format_count(width)?);
+        }
+        if let Some(precision) = &options.precision {
+            spec.push('.');
+            spec.push_str(&self.render_format_count(precision)?);
+        }
+        if let Some(debug_hex) = options.debug_hex {
+            spec.push(match debug_hex {
+                FormatDebugHex::Lower => 'x',
+                FormatDebugHex::Upper => 'X',
+            });
+        }
+        spec.push_str(match format_trait {
+            FormatTrait::Display => "",
+            FormatTrait::Debug => "?",
+            FormatTrait::LowerExp => "e",
+            FormatTrait::UpperExp => "E",
+            FormatTrait::Octal => "o",
+            FormatTrait::Pointer => "p",
+            FormatTrait::Binary => "b",
+            FormatTrait::LowerHex => "x",
+            FormatTrait::UpperHex => "X",
+        });
+
+        Some(if spec.is_empty() { spec } else { format!(":{spec}") })
+    }
+
+    fn render_format_count(&self, count: &FormatCount) -> Option<String> {
+        match count {
+            FormatCount::Literal(n) => Some(n.to_string()),
+            FormatCount::Argument(position) => match position.kind {
+                FormatArgPositionKind::Implicit => Some("*".to_string()),
+                FormatArgPositionKind::Number => Some(format!("{}$", position.index.ok()?)),
+                FormatArgPositionKind::Named => {
+                    let arg = self.format_args.arguments.all_args().get(position.index.ok()?)?;
+                    Some(format!("{}$", arg.kind.ident()?))
+                },
+            },
+        }
+    }
+
     fn check_uninlined_args(&self) {
         if self.format_args.span.from_expansion() {
             return;
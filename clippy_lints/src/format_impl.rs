@@ -1,19 +1,27 @@
-use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg};
+use std::ops::ControlFlow;
+
+use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::macros::{FormatArgsStorage, find_format_arg_expr, is_format_macro, root_macro_call_first_node};
+use clippy_utils::visitors::for_each_expr;
 use clippy_utils::{get_parent_as_impl, is_diag_trait_item, path_to_local, peel_ref_operators};
 use rustc_ast::{FormatArgsPiece, FormatTrait};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind, Impl, ImplItem, ImplItemKind, QPath};
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{Expr, ExprKind, Impl, ImplItem, ImplItemKind, QPath, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::TypeckResults;
 use rustc_session::impl_lint_pass;
 use rustc_span::symbol::kw;
-use rustc_span::{Symbol, sym};
+use rustc_span::{Span, Symbol, sym};
 
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for format trait implementations (e.g. `Display`) with a recursive call to itself
     /// which uses `self` as a parameter.
-    /// This is typically done indirectly with the `write!` macro or with `to_string()`.
+    /// This is typically done indirectly with the `write!` macro or with `to_string()`, or
+    /// through a method of the same type which does so, e.g. `write!(f, "{}", self.pretty())`
+    /// where `pretty` calls `self.to_string()`.
     ///
     /// ### Why is this bad?
     /// This will lead to infinite recursion and a stack overflow.
@@ -136,6 +144,7 @@ impl<'tcx> LateLintPass<'tcx> for FormatImpl {
             };
             linter.check_to_string_in_display();
             linter.check_self_in_format_args();
+            linter.check_self_method_call();
             linter.check_print_in_format_impl();
         }
     }
@@ -182,18 +191,7 @@ impl FormatImplExpr<'_, '_> {
         {
             for piece in &format_args.template {
                 if let FormatArgsPiece::Placeholder(placeholder) = piece
-                    && let trait_name = match placeholder.format_trait {
-                        FormatTrait::Display => sym::Display,
-                        FormatTrait::Debug => sym::Debug,
-                        FormatTrait::LowerExp => sym!(LowerExp),
-                        FormatTrait::UpperExp => sym!(UpperExp),
-                        FormatTrait::Octal => sym!(Octal),
-                        FormatTrait::Pointer => sym::Pointer,
-                        FormatTrait::Binary => sym!(Binary),
-                        FormatTrait::LowerHex => sym!(LowerHex),
-                        FormatTrait::UpperHex => sym!(UpperHex),
-                    }
-                    && trait_name == self.format_trait_impl.name
+                    && format_trait_name(placeholder.format_trait) == self.format_trait_impl.name
                     && let Ok(index) = placeholder.argument.index
                     && let Some(arg) = format_args.arguments.all_args().get(index)
                     && let Some(arg_expr) = find_format_arg_expr(self.expr, arg)
@@ -222,6 +220,36 @@ impl FormatImplExpr<'_, '_> {
         }
     }
 
+    /// Checks for methods called on `self` which format `self` using the same trait.
+    fn check_self_method_call(&self) {
+        if let ExprKind::MethodCall(path, self_arg, ..) = self.expr.kind
+            && is_self(self.cx.typeck_results(), self_arg)
+            && let Some(method_id) = self.cx.typeck_results().type_dependent_def_id(self.expr.hir_id)
+            && let Some(method_id) = method_id.as_local()
+            && let Some(span) = formats_self(
+                self.cx,
+                self.format_args,
+                self.format_trait_impl.name,
+                method_id,
+                &mut FxHashSet::default(),
+            )
+        {
+            let FormatTraitNames { name, .. } = self.format_trait_impl;
+            span_lint_and_then(
+                self.cx,
+                RECURSIVE_FORMAT_IMPL,
+                self.expr.span,
+                format!(
+                    "using `self.{}()` in `impl {name}` will cause infinite recursion",
+                    path.ident
+                ),
+                |diag| {
+                    diag.span_note(span, format!("`self` is formatted as `{name}` here"));
+                },
+            );
+        }
+    }
+
     fn check_print_in_format_impl(&self) {
         if let Some(macro_call) = root_macro_call_first_node(self.cx, self.expr)
             && let Some(name) = self.cx.tcx.get_diagnostic_name(macro_call.def_id)
@@ -251,6 +279,97 @@ impl FormatImplExpr<'_, '_> {
     }
 }
 
+fn format_trait_name(format_trait: FormatTrait) -> Symbol {
+    match format_trait {
+        FormatTrait::Display => sym::Display,
+        FormatTrait::Debug => sym::Debug,
+        FormatTrait::LowerExp => sym!(LowerExp),
+        FormatTrait::UpperExp => sym!(UpperExp),
+        FormatTrait::Octal => sym!(Octal),
+        FormatTrait::Pointer => sym::Pointer,
+        FormatTrait::Binary => sym!(Binary),
+        FormatTrait::LowerHex => sym!(LowerHex),
+        FormatTrait::UpperHex => sym!(UpperHex),
+    }
+}
+
+/// Like `peel_ref_operators`, but for an arbitrary body. Checks whether the result is `self`.
+fn is_self(typeck: &TypeckResults<'_>, mut expr: &Expr<'_>) -> bool {
+    loop {
+        match expr.kind {
+            ExprKind::AddrOf(_, _, e) => expr = e,
+            ExprKind::Unary(UnOp::Deref, e) if typeck.expr_ty(e).is_ref() => expr = e,
+            ExprKind::Path(QPath::Resolved(None, path)) => {
+                return matches!(path.segments, [segment] if segment.ident.name == kw::SelfLower);
+            },
+            _ => return false,
+        }
+    }
+}
+
+/// Maximum number of methods looked into when searching for indirect recursion.
+const MAX_VISITED_METHODS: usize = 8;
+
+/// Checks whether the inherent method `method_id` formats `self` using the trait `name`, either
+/// directly or through further inherent methods called on `self`. Returns the span where `self`
+/// is formatted.
+fn formats_self(
+    cx: &LateContext<'_>,
+    format_args: &FormatArgsStorage,
+    name: Symbol,
+    method_id: LocalDefId,
+    visited: &mut FxHashSet<LocalDefId>,
+) -> Option<Span> {
+    if visited.len() >= MAX_VISITED_METHODS
+        || !visited.insert(method_id)
+        || cx.tcx.impl_of_method(method_id.to_def_id()).is_none()
+    {
+        return None;
+    }
+    let body = cx.tcx.hir().maybe_body_owned_by(method_id)?;
+    let typeck = cx.tcx.typeck(method_id);
+
+    for_each_expr(cx, body.value, |e| {
+        if let ExprKind::MethodCall(path, self_arg, args, _) = e.kind
+            && is_self(typeck, self_arg)
+            && let Some(callee_id) = typeck.type_dependent_def_id(e.hir_id)
+        {
+            if name == sym::Display
+                && args.is_empty()
+                && path.ident.name == sym::to_string
+                && is_diag_trait_item(cx, callee_id, sym::ToString)
+            {
+                return ControlFlow::Break(e.span);
+            }
+            if let Some(callee_id) = callee_id.as_local()
+                && let Some(span) = formats_self(cx, format_args, name, callee_id, visited)
+            {
+                return ControlFlow::Break(span);
+            }
+        }
+
+        if let Some(macro_call) = root_macro_call_first_node(cx, e)
+            && is_format_macro(cx, macro_call.def_id)
+            && let Some(args) = format_args.get(cx, e, macro_call.expn)
+            && args.template.iter().any(|piece| {
+                if let FormatArgsPiece::Placeholder(placeholder) = piece
+                    && format_trait_name(placeholder.format_trait) == name
+                    && let Ok(index) = placeholder.argument.index
+                    && let Some(arg) = args.arguments.all_args().get(index)
+                    && let Some(arg_expr) = find_format_arg_expr(e, arg)
+                {
+                    is_self(typeck, arg_expr)
+                } else {
+                    false
+                }
+            })
+        {
+            return ControlFlow::Break(macro_call.span);
+        }
+        ControlFlow::Continue(())
+    })
+}
+
 fn is_format_trait_impl(cx: &LateContext<'_>, impl_item: &ImplItem<'_>) -> Option<FormatTraitNames> {
     if impl_item.ident.name == sym::fmt
         && let ImplItemKind::Fn(_, body_id) = impl_item.kind
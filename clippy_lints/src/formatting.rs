@@ -1,11 +1,11 @@
 use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_note};
 use clippy_utils::is_span_if;
-use clippy_utils::source::snippet_opt;
-use rustc_ast::ast::{BinOpKind, Block, Expr, ExprKind, StmtKind};
+use clippy_utils::source::{snippet_indent, snippet_opt};
+use rustc_ast::ast::{BinOpKind, Block, BorrowKind, Expr, ExprKind, LocalKind, Mutability, Stmt, StmtKind};
 use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_session::declare_lint_pass;
-use rustc_span::Span;
+use rustc_span::{BytePos, Span};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -113,11 +113,51 @@ declare_clippy_lint! {
     "possible missing comma in array"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for let-chains whose formatting hides their structure. It lints if an operand of
+    /// an `if` or `while` let-chain is continued on a line that is not indented past the `if`
+    /// or `while`, and if a statement starting with `&&` follows a statement ending with `;`.
+    ///
+    /// ### Why is this bad?
+    /// A continuation that is not indented looks like the start of a new statement. A `;`
+    /// at the end of what was meant to be the first part of a condition ends the statement,
+    /// and the rest is parsed as a new, unused `& &..` expression.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// if let Some(x) = a &&
+    /// let Some(y) = b { // looks like a new statement
+    /// }
+    ///
+    /// let ok = a.is_some()
+    ///     && b.is_some();
+    ///     && c.is_some(); // the `;` above ends the condition
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// if let Some(x) = a
+    ///     && let Some(y) = b
+    /// {
+    /// }
+    ///
+    /// let ok = a.is_some()
+    ///     && b.is_some()
+    ///     && c.is_some();
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub SUSPICIOUS_LET_CHAIN_FORMATTING,
+    suspicious,
+    "suspicious formatting of let-chains"
+}
+
 declare_lint_pass!(Formatting => [
     SUSPICIOUS_ASSIGNMENT_FORMATTING,
     SUSPICIOUS_UNARY_OP_FORMATTING,
     SUSPICIOUS_ELSE_FORMATTING,
-    POSSIBLE_MISSING_COMMA
+    POSSIBLE_MISSING_COMMA,
+    SUSPICIOUS_LET_CHAIN_FORMATTING
 ]);
 
 impl EarlyLintPass for Formatting {
@@ -126,6 +166,7 @@ impl EarlyLintPass for Formatting {
             if let (StmtKind::Expr(first), StmtKind::Expr(second) | StmtKind::Semi(second)) = (&w[0].kind, &w[1].kind) {
                 check_missing_else(cx, first, second);
             }
+            check_terminated_chain(cx, &w[0], &w[1]);
         }
     }
 
@@ -134,6 +175,7 @@ impl EarlyLintPass for Formatting {
         check_unop(cx, expr);
         check_else(cx, expr);
         check_array(cx, expr);
+        check_let_chain(cx, expr);
     }
 }
 
@@ -316,6 +358,87 @@ fn check_missing_else(cx: &EarlyContext<'_>, first: &Expr, second: &Expr) {
     }
 }
 
+/// Implementation of the `SUSPICIOUS_LET_CHAIN_FORMATTING` lint for operands of a let-chain that
+/// are continued on a line which is not indented past the `if` or `while`.
+fn check_let_chain(cx: &EarlyContext<'_>, expr: &Expr) {
+    let (cond, keyword) = match &expr.kind {
+        ExprKind::If(cond, ..) => (cond, "if"),
+        ExprKind::While(cond, ..) => (cond, "while"),
+        _ => return,
+    };
+    if expr.span.from_expansion() || in_external_macro(cx.sess(), expr.span) {
+        return;
+    }
+    let mut operands = Vec::new();
+    chain_operands(cond, &mut operands);
+    if !operands.iter().any(|e| matches!(e.kind, ExprKind::Let(..))) {
+        return;
+    }
+    let Some(expr_indent) = snippet_indent(cx, expr.span) else {
+        return;
+    };
+    for [prev, next] in operands.array_windows() {
+        if !next.span.from_expansion()
+            && let Some(gap) = snippet_opt(cx, prev.span.between(next.span))
+            && gap.contains('\n')
+            && let Some(next_indent) = snippet_indent(cx, next.span)
+            // Indentation mixing tabs and spaces differently can't be compared.
+            && expr_indent.starts_with(&next_indent)
+        {
+            span_lint_and_note(
+                cx,
+                SUSPICIOUS_LET_CHAIN_FORMATTING,
+                next.span,
+                format!("this continues the condition of the `{keyword}`, but it is not indented past it"),
+                None,
+                format!("to remove this lint, indent the condition further than the `{keyword}`"),
+            );
+        }
+    }
+}
+
+/// Collects the operands of a `&&` chain, from left to right.
+fn chain_operands<'a>(expr: &'a Expr, operands: &mut Vec<&'a Expr>) {
+    if let ExprKind::Binary(op, lhs, rhs) = &expr.kind
+        && op.node == BinOpKind::And
+    {
+        chain_operands(lhs, operands);
+        chain_operands(rhs, operands);
+    } else {
+        operands.push(expr);
+    }
+}
+
+/// Implementation of the `SUSPICIOUS_LET_CHAIN_FORMATTING` lint for a `;` ending what looks like
+/// the first part of a condition, turning the `&& ..` that follows into a new statement.
+fn check_terminated_chain(cx: &EarlyContext<'_>, first: &Stmt, second: &Stmt) {
+    let first_ends_with_semi = match &first.kind {
+        StmtKind::Let(local) => !matches!(local.kind, LocalKind::Decl),
+        StmtKind::Semi(_) => true,
+        _ => false,
+    };
+    if first_ends_with_semi
+        && let StmtKind::Expr(second_expr) | StmtKind::Semi(second_expr) = &second.kind
+        && let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, inner) = &second_expr.kind
+        && let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _) = inner.kind
+        && !first.span.from_expansion()
+        && !second_expr.span.from_expansion()
+        && let Some(gap) = snippet_opt(cx, first.span.between(second_expr.span))
+        && gap.contains('\n')
+        && let op_span = second_expr.span.with_hi(second_expr.span.lo() + BytePos(2))
+        && snippet_opt(cx, op_span).is_some_and(|op| op == "&&")
+    {
+        span_lint_and_note(
+            cx,
+            SUSPICIOUS_LET_CHAIN_FORMATTING,
+            op_span,
+            "this looks like a continuation of the previous condition, but the `;` ends the statement",
+            None,
+            "to remove this lint, remove the `;` or remove the `&&`",
+        );
+    }
+}
+
 fn is_block(expr: &Expr) -> bool {
     matches!(expr.kind, ExprKind::Block(..))
 }
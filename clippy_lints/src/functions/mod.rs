@@ -18,6 +18,7 @@ use rustc_middle::ty::TyCtxt;
 use rustc_session::impl_lint_pass;
 use rustc_span::Span;
 use rustc_span::def_id::{DefIdSet, LocalDefId};
+use too_many_arguments::TooManyArgumentsConf;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -297,6 +298,58 @@ declare_clippy_lint! {
     "function returning `Result` with large `Err` type"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `map_err` calls followed by `?` which wrap an error into a variant of a large
+    /// error type, in functions returning that type or a `Box` of it. The size threshold can be
+    /// set with the `large-error-threshold` configuration.
+    ///
+    /// ### Why is this bad?
+    /// The large error is moved around in a `Result` when it's propagated by `?`, and moved once
+    /// more into a `Box` if the function returns one. Implementing `From` for the `Box` allows `?`
+    /// to box the error as soon as it's created, and the function to return the smaller
+    /// `Result<_, Box<_>>`.
+    ///
+    /// ### Known problems
+    /// The suggestion changes the return type of the function, other code returning the error
+    /// might have to be updated.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// pub enum ParseError {
+    ///     Io(std::io::Error),
+    ///     UnparsedBytes([u8; 512]),
+    /// }
+    ///
+    /// pub fn parse(path: &str) -> Result<String, ParseError> {
+    ///     let s = std::fs::read_to_string(path).map_err(ParseError::Io)?;
+    ///     Ok(s)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// pub enum ParseError {
+    ///     Io(std::io::Error),
+    ///     UnparsedBytes([u8; 512]),
+    /// }
+    ///
+    /// impl From<std::io::Error> for Box<ParseError> {
+    ///     fn from(e: std::io::Error) -> Self {
+    ///         Box::new(ParseError::Io(e))
+    ///     }
+    /// }
+    ///
+    /// pub fn parse(path: &str) -> Result<String, Box<ParseError>> {
+    ///     let s = std::fs::read_to_string(path)?;
+    ///     Ok(s)
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub RESULT_LARGE_ERR_MAP_ERR_BOXING,
+    pedantic,
+    "wrapping an error into a large error type with `map_err` before propagating it"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for getter methods that return a field that doesn't correspond
@@ -449,7 +502,7 @@ declare_clippy_lint! {
 }
 
 pub struct Functions {
-    too_many_arguments_threshold: u64,
+    too_many_arguments: TooManyArgumentsConf,
     too_many_lines_threshold: u64,
     large_error_threshold: u64,
     avoid_breaking_exported_api: bool,
@@ -462,7 +515,7 @@ pub struct Functions {
 impl Functions {
     pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
         Self {
-            too_many_arguments_threshold: conf.too_many_arguments_threshold,
+            too_many_arguments: TooManyArgumentsConf::new(conf),
             too_many_lines_threshold: conf.too_many_lines_threshold,
             large_error_threshold: conf.large_error_threshold,
             avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
@@ -485,6 +538,7 @@ impl_lint_pass!(Functions => [
     MUST_USE_CANDIDATE,
     RESULT_UNIT_ERR,
     RESULT_LARGE_ERR,
+    RESULT_LARGE_ERR_MAP_ERR_BOXING,
     MISNAMED_GETTERS,
     IMPL_TRAIT_IN_PARAMS,
     RENAMED_FUNCTION_PARAMS,
@@ -502,11 +556,12 @@ impl<'tcx> LateLintPass<'tcx> for Functions {
         def_id: LocalDefId,
     ) {
         let hir_id = cx.tcx.local_def_id_to_hir_id(def_id);
-        too_many_arguments::check_fn(cx, kind, decl, span, hir_id, self.too_many_arguments_threshold);
+        too_many_arguments::check_fn(cx, kind, decl, body, span, hir_id, &self.too_many_arguments);
         too_many_lines::check_fn(cx, kind, span, body, self.too_many_lines_threshold);
         not_unsafe_ptr_arg_deref::check_fn(cx, kind, decl, body, def_id);
         misnamed_getters::check_fn(cx, kind, decl, body, span);
         impl_trait_in_params::check_fn(cx, &kind, body, hir_id);
+        result::check_fn(cx, kind, decl, body, def_id, self.large_error_threshold);
         ref_option::check_fn(
             cx,
             kind,
@@ -532,7 +587,7 @@ impl<'tcx> LateLintPass<'tcx> for Functions {
     }
 
     fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'_>) {
-        too_many_arguments::check_trait_item(cx, item, self.too_many_arguments_threshold);
+        too_many_arguments::check_trait_item(cx, item, &self.too_many_arguments);
         not_unsafe_ptr_arg_deref::check_trait_item(cx, item);
         must_use::check_trait_item(cx, item);
         result::check_trait_item(cx, item, self.large_error_threshold, &self.msrv);
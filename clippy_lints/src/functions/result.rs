@@ -1,16 +1,22 @@
 use clippy_utils::msrvs::{self, Msrv};
-use rustc_errors::Diag;
+use rustc_errors::{Applicability, Diag};
 use rustc_hir as hir;
+use rustc_hir::def::{CtorKind, CtorOf, DefKind, Res};
+use rustc_hir::intravisit::FnKind;
 use rustc_lint::{LateContext, LintContext};
 use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::{self, Ty};
 use rustc_span::{Span, sym};
+use std::ops::ControlFlow;
 
 use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
-use clippy_utils::ty::{AdtVariantInfo, approx_ty_size, is_type_diagnostic_item};
-use clippy_utils::{is_no_std_crate, trait_ref_of_method};
+use clippy_utils::source::{snippet_indent, snippet_opt};
+use clippy_utils::ty::{AdtVariantInfo, approx_ty_size, implements_trait, is_type_diagnostic_item};
+use clippy_utils::visitors::for_each_expr_without_closures;
+use clippy_utils::{is_no_std_crate, path_res, trait_ref_of_method};
 
-use super::{RESULT_LARGE_ERR, RESULT_UNIT_ERR};
+use super::{RESULT_LARGE_ERR, RESULT_LARGE_ERR_MAP_ERR_BOXING, RESULT_UNIT_ERR};
 
 /// The type of the `Err`-variant in a `std::result::Result` returned by the
 /// given `FnDecl`
@@ -154,3 +160,133 @@ fn check_result_large_err<'tcx>(cx: &LateContext<'tcx>, err_ty: Ty<'tcx>, hir_ty
         }
     }
 }
+
+pub(super) fn check_fn<'tcx>(
+    cx: &LateContext<'tcx>,
+    kind: FnKind<'tcx>,
+    decl: &'tcx hir::FnDecl<'tcx>,
+    body: &'tcx hir::Body<'tcx>,
+    def_id: hir::def_id::LocalDefId,
+    large_err_threshold: u64,
+) {
+    if matches!(kind, FnKind::Closure) {
+        return;
+    }
+    let ret_ty = cx
+        .tcx
+        .instantiate_bound_regions_with_erased(cx.tcx.fn_sig(def_id).instantiate_identity().output());
+    if is_type_diagnostic_item(cx, ret_ty, sym::Result)
+        && let ty::Adt(_, args) = ret_ty.kind()
+    {
+        let ret_err_ty = args.type_at(1);
+        for_each_expr_without_closures(body.value, |e| {
+            if let hir::ExprKind::Match(scrutinee, _, hir::MatchSource::TryDesugar(_)) = e.kind
+                && let hir::ExprKind::Call(_, [map_err]) = scrutinee.kind
+            {
+                check_map_err_boxing(cx, decl, map_err, ret_err_ty, large_err_threshold);
+            }
+            ControlFlow::<()>::Continue(())
+        });
+    }
+}
+
+/// Checks for `recv.map_err(LargeError::Variant)?` in a function returning `LargeError` or a
+/// `Box<LargeError>`.
+fn check_map_err_boxing<'tcx>(
+    cx: &LateContext<'tcx>,
+    decl: &'tcx hir::FnDecl<'tcx>,
+    map_err: &'tcx hir::Expr<'tcx>,
+    ret_err_ty: Ty<'tcx>,
+    large_err_threshold: u64,
+) {
+    if let hir::ExprKind::MethodCall(method, recv, [ctor], _) = map_err.kind
+        && method.ident.name == sym::map_err
+        && !map_err.span.from_expansion()
+        && !recv.span.from_expansion()
+        && let recv_ty = cx.typeck_results().expr_ty(recv)
+        && is_type_diagnostic_item(cx, recv_ty, sym::Result)
+        && let ty::Adt(_, recv_args) = recv_ty.kind()
+        && let src_ty = recv_args.type_at(1)
+        && let Res::Def(DefKind::Ctor(ctor_of, CtorKind::Fn), ctor_id) = path_res(cx, ctor)
+        && let adt_id = match ctor_of {
+            CtorOf::Struct => cx.tcx.parent(ctor_id),
+            CtorOf::Variant => cx.tcx.parent(cx.tcx.parent(ctor_id)),
+        }
+        // The `From` implementation can't be written for a generic error type
+        && cx.tcx.generics_of(adt_id).count() == 0
+        && let err_ty = cx.tcx.type_of(adt_id).instantiate_identity()
+        && let is_boxed = ret_err_ty.boxed_ty() == Some(err_ty)
+        && (is_boxed || ret_err_ty == err_ty)
+        && let Some(local_id) = adt_id.as_local()
+        && let hir::Node::Item(item) = cx.tcx.hir_node_by_def_id(local_id)
+        && let ty_size = approx_ty_size(cx, err_ty)
+        && ty_size >= large_err_threshold
+    {
+        let mut sugg = vec![(map_err.span.with_lo(recv.span.hi()), String::new())];
+        if !is_boxed {
+            // Return `Result<_, Box<LargeError>>` instead
+            let Some(ret_err_hir_ty) = result_err_hir_ty(decl) else {
+                return;
+            };
+            let Some(snip) = snippet_opt(cx, ret_err_hir_ty.span) else {
+                return;
+            };
+            sugg.push((ret_err_hir_ty.span, format!("Box<{snip}>")));
+        }
+
+        let name = item.ident;
+        let src = with_no_trimmed_paths!(src_ty.to_string());
+        let from_implemented = cx
+            .tcx
+            .get_diagnostic_item(sym::From)
+            .is_some_and(|from| implements_trait(cx, Ty::new_box(cx.tcx, err_ty), from, &[src_ty.into()]));
+        let help = if from_implemented {
+            format!("remove the `map_err`, `Box<{name}>` already implements `From<{src}>`")
+        } else {
+            let ctor = match ctor_of {
+                CtorOf::Struct => name.to_string(),
+                CtorOf::Variant => format!("{name}::{}", cx.tcx.item_name(cx.tcx.parent(ctor_id))),
+            };
+            let indent = snippet_indent(cx, item.span).unwrap_or_default();
+            sugg.push((
+                item.span.shrink_to_hi(),
+                format!(
+                    "\n\n{indent}impl From<{src}> for Box<{name}> {{\n\
+                    {indent}    fn from(e: {src}) -> Self {{\n\
+                    {indent}        Box::new({ctor}(e))\n\
+                    {indent}    }}\n\
+                    {indent}}}"
+                ),
+            ));
+            format!("implement `From<{src}>` for `Box<{name}>` to box the error as soon as it's created")
+        };
+
+        span_lint_and_then(
+            cx,
+            RESULT_LARGE_ERR_MAP_ERR_BOXING,
+            method.ident.span.with_hi(map_err.span.hi()),
+            "the error created by this `map_err` is very large",
+            |diag| {
+                diag.note(format!(
+                    "`{err_ty}` is at least {ty_size} bytes and is moved when the error is propagated"
+                ));
+                diag.multipart_suggestion_verbose(help, sugg, Applicability::MaybeIncorrect);
+            },
+        );
+    }
+}
+
+/// The HIR type of the `Err`-variant in the `Result<_, E>` written as the return type of `decl`.
+fn result_err_hir_ty<'tcx>(decl: &hir::FnDecl<'tcx>) -> Option<&'tcx hir::Ty<'tcx>> {
+    if let hir::FnRetTy::Return(hir_ty) = decl.output
+        && let hir::TyKind::Path(hir::QPath::Resolved(None, path)) = hir_ty.kind
+        && let [.., segment] = path.segments
+        && segment.ident.name == sym::Result
+        && let Some(args) = segment.args
+        && let [_, hir::GenericArg::Type(err_ty)] = args.args
+    {
+        Some(err_ty)
+    } else {
+        None
+    }
+}
@@ -1,6 +1,8 @@
+use clippy_config::Conf;
 use rustc_hir::{self as hir, intravisit};
 use rustc_lint::LateContext;
 use rustc_span::Span;
+use rustc_span::symbol::Ident;
 use rustc_target::spec::abi::Abi;
 
 use clippy_utils::diagnostics::span_lint;
@@ -8,58 +10,127 @@ use clippy_utils::is_trait_impl_item;
 
 use super::TOO_MANY_ARGUMENTS;
 
+pub(super) struct TooManyArgumentsConf {
+    threshold: u64,
+    count_self: bool,
+    ignored_attributes: &'static [String],
+    ignored_names: &'static [String],
+}
+
+impl TooManyArgumentsConf {
+    pub(super) fn new(conf: &'static Conf) -> Self {
+        Self {
+            threshold: conf.too_many_arguments_threshold,
+            count_self: conf.too_many_arguments_count_self,
+            ignored_attributes: &conf.too_many_arguments_ignored_attributes,
+            ignored_names: &conf.too_many_arguments_ignored_names,
+        }
+    }
+
+    fn is_ignored_name(&self, ident: Ident) -> bool {
+        self.ignored_names
+            .iter()
+            .any(|pattern| matches_wildcard(pattern, ident.as_str()))
+    }
+
+    fn has_ignored_attribute(&self, cx: &LateContext<'_>, hir_id: hir::HirId) -> bool {
+        !self.ignored_attributes.is_empty()
+            && cx.tcx.hir().attrs(hir_id).iter().any(|attr| {
+                attr.ident_path()
+                    .and_then(|path| path.last().copied())
+                    .is_some_and(|name| self.ignored_attributes.iter().any(|ignored| ignored == name.as_str()))
+            })
+    }
+}
+
 pub(super) fn check_fn(
     cx: &LateContext<'_>,
     kind: intravisit::FnKind<'_>,
     decl: &hir::FnDecl<'_>,
+    body: &hir::Body<'_>,
     span: Span,
     hir_id: hir::HirId,
-    too_many_arguments_threshold: u64,
+    conf: &TooManyArgumentsConf,
 ) {
     // don't warn for implementations, it's not their fault
     if !is_trait_impl_item(cx, hir_id) {
         // don't lint extern functions decls, it's not their fault either
         match kind {
             intravisit::FnKind::Method(
-                _,
+                ident,
                 &hir::FnSig {
                     header: hir::FnHeader { abi: Abi::Rust, .. },
                     ..
                 },
             )
-            | intravisit::FnKind::ItemFn(_, _, hir::FnHeader { abi: Abi::Rust, .. }) => check_arg_number(
-                cx,
-                decl,
-                span.with_hi(decl.output.span().hi()),
-                too_many_arguments_threshold,
-            ),
+            | intravisit::FnKind::ItemFn(ident, _, hir::FnHeader { abi: Abi::Rust, .. }) => {
+                if !conf.is_ignored_name(ident) && !conf.has_ignored_attribute(cx, hir_id) {
+                    check_arg_number(cx, decl, Some(body), span.with_hi(decl.output.span().hi()), conf);
+                }
+            },
             _ => {},
         }
     }
 }
 
-pub(super) fn check_trait_item(cx: &LateContext<'_>, item: &hir::TraitItem<'_>, too_many_arguments_threshold: u64) {
-    if let hir::TraitItemKind::Fn(ref sig, _) = item.kind {
+pub(super) fn check_trait_item(cx: &LateContext<'_>, item: &hir::TraitItem<'_>, conf: &TooManyArgumentsConf) {
+    if let hir::TraitItemKind::Fn(ref sig, ref trait_fn) = item.kind
         // don't lint extern functions decls, it's not their fault
-        if sig.header.abi == Abi::Rust {
-            check_arg_number(
-                cx,
-                sig.decl,
-                item.span.with_hi(sig.decl.output.span().hi()),
-                too_many_arguments_threshold,
-            );
-        }
+        && sig.header.abi == Abi::Rust
+        && !conf.is_ignored_name(item.ident)
+        && !conf.has_ignored_attribute(cx, item.hir_id())
+    {
+        let body = match trait_fn {
+            hir::TraitFn::Provided(body_id) => Some(cx.tcx.hir().body(*body_id)),
+            hir::TraitFn::Required(_) => None,
+        };
+        check_arg_number(cx, sig.decl, body, item.span.with_hi(sig.decl.output.span().hi()), conf);
     }
 }
 
-fn check_arg_number(cx: &LateContext<'_>, decl: &hir::FnDecl<'_>, fn_span: Span, too_many_arguments_threshold: u64) {
-    let args = decl.inputs.len() as u64;
-    if args > too_many_arguments_threshold {
+fn check_arg_number(
+    cx: &LateContext<'_>,
+    decl: &hir::FnDecl<'_>,
+    body: Option<&hir::Body<'_>>,
+    fn_span: Span,
+    conf: &TooManyArgumentsConf,
+) {
+    let skip_self = !conf.count_self && decl.implicit_self.has_implicit_self();
+    let ignored_params = body.map_or(0, |body| {
+        body.params
+            .iter()
+            .skip(usize::from(skip_self))
+            .filter(|param| conf.has_ignored_attribute(cx, param.hir_id))
+            .count()
+    });
+    let args = (decl.inputs.len() - usize::from(skip_self) - ignored_params) as u64;
+    let threshold = conf.threshold;
+    if args > threshold {
         span_lint(
             cx,
             TOO_MANY_ARGUMENTS,
             fn_span,
-            format!("this function has too many arguments ({args}/{too_many_arguments_threshold})"),
+            format!("this function has too many arguments ({args}/{threshold})"),
         );
     }
 }
+
+/// Matches `name` against `pattern`, where `*` in `pattern` matches any sequence of characters.
+fn matches_wildcard(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let Some(last) = parts.next_back() else {
+        // No `*` in the pattern
+        return rest.is_empty();
+    };
+    for part in parts {
+        let Some(index) = rest.find(part) else {
+            return false;
+        };
+        rest = &rest[index + part.len()..];
+    }
+    rest.ends_with(last)
+}
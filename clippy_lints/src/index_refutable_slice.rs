@@ -1,8 +1,9 @@
 use clippy_config::Conf;
 use clippy_utils::consts::{ConstEvalCtxt, Constant};
-use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::higher::IfLet;
 use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::ty::is_copy;
 use clippy_utils::{is_expn_of, is_lint_allowed, path_to_local};
 use rustc_data_structures::fx::{FxHashSet, FxIndexMap, FxIndexSet};
@@ -15,12 +16,14 @@ use rustc_middle::hir::nested_filter;
 use rustc_middle::ty;
 use rustc_session::impl_lint_pass;
 use rustc_span::Span;
-use rustc_span::symbol::Ident;
+use rustc_span::symbol::{Ident, sym};
 
 declare_clippy_lint! {
     /// ### What it does
     /// The lint checks for slice bindings in patterns that are only used to
-    /// access individual slice values.
+    /// access individual slice values. Sequences of `let` statements which
+    /// bind elements of the same slice with `get(..).unwrap()` are linted as
+    /// well, as they can be replaced by a single `let`-`else` with a slice pattern.
     ///
     /// ### Why is this bad?
     /// Accessing slice values using indices can lead to panics. Using refutable
@@ -29,7 +32,7 @@ declare_clippy_lint! {
     ///
     /// ### Limitations
     /// This lint currently only checks for immutable access inside `if let`
-    /// patterns.
+    /// patterns and consecutive `let x = slice.get(N).unwrap();` statements.
     ///
     /// ### Example
     /// ```no_run
@@ -47,6 +50,17 @@ declare_clippy_lint! {
     ///     println!("{}", first);
     /// }
     /// ```
+    ///
+    /// ```no_run
+    /// let v = vec![1, 2, 3];
+    /// let first = v.get(0).unwrap();
+    /// let second = v.get(1).unwrap();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let v = vec![1, 2, 3];
+    /// let [first, second, ..] = &v[..] else { panic!() };
+    /// ```
     #[clippy::version = "1.59.0"]
     pub INDEX_REFUTABLE_SLICE,
     pedantic,
@@ -86,6 +100,31 @@ impl<'tcx> LateLintPass<'tcx> for IndexRefutableSlice {
         }
     }
 
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx hir::Block<'_>) {
+        if !block.span.from_expansion()
+            && !is_lint_allowed(cx, INDEX_REFUTABLE_SLICE, block.hir_id)
+            && self.msrv.meets(msrvs::LET_ELSE)
+        {
+            let mut group: Vec<GetUnwrapBinding> = Vec::new();
+            for stmt in block.stmts {
+                match find_get_unwrap_binding(cx, stmt, self.max_suggested_slice) {
+                    Some(binding)
+                        if group.first().is_some_and(|first| first.slice == binding.slice)
+                            && group.iter().all(|other| other.index != binding.index) =>
+                    {
+                        group.push(binding);
+                    },
+                    binding => {
+                        lint_get_unwrap_bindings(cx, &group);
+                        group.clear();
+                        group.extend(binding);
+                    },
+                }
+            }
+            lint_get_unwrap_bindings(cx, &group);
+        }
+    }
+
     extract_msrv_attr!(LateContext);
 }
 
@@ -178,6 +217,82 @@ fn lint_slice(cx: &LateContext<'_>, slice: &SliceLintInformation) {
     }
 }
 
+/// A `let x = slice.get(N).unwrap();` statement.
+struct GetUnwrapBinding {
+    stmt_span: Span,
+    ident: Ident,
+    slice: HirId,
+    slice_span: Span,
+    index: u64,
+}
+
+fn find_get_unwrap_binding(
+    cx: &LateContext<'_>,
+    stmt: &hir::Stmt<'_>,
+    max_suggested_slice: u64,
+) -> Option<GetUnwrapBinding> {
+    if let hir::StmtKind::Let(local) = stmt.kind
+        && !stmt.span.from_expansion()
+        && local.ty.is_none()
+        && local.els.is_none()
+        // We'll just ignore mut and ref bindings here as well
+        && let hir::PatKind::Binding(hir::BindingMode::NONE, _, ident, None) = local.pat.kind
+        && let Some(init) = local.init
+        && let hir::ExprKind::MethodCall(unwrap, get_call, [], _) = init.kind
+        && unwrap.ident.name == sym::unwrap
+        && let hir::ExprKind::MethodCall(get, slice_expr, [index_expr], _) = get_call.kind
+        && get.ident.as_str() == "get"
+        && let Some(slice) = path_to_local(slice_expr)
+        && let Some(method_id) = cx.typeck_results().type_dependent_def_id(get_call.hir_id)
+        && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
+        && cx.tcx.type_of(impl_id).instantiate_identity().is_slice()
+        && let Some(Constant::Int(index)) = ConstEvalCtxt::new(cx).eval(index_expr)
+        && let Ok(index) = index.try_into()
+        && index < max_suggested_slice
+    {
+        Some(GetUnwrapBinding {
+            stmt_span: stmt.span,
+            ident,
+            slice,
+            slice_span: slice_expr.span,
+            index,
+        })
+    } else {
+        None
+    }
+}
+
+fn lint_get_unwrap_bindings(cx: &LateContext<'_>, bindings: &[GetUnwrapBinding]) {
+    let ([first, .., last], Some(max_index)) = (bindings, bindings.iter().map(|binding| binding.index).max()) else {
+        return;
+    };
+
+    let pat_sugg_idents = (0..=max_index)
+        .map(|index| {
+            bindings
+                .iter()
+                .find(|binding| binding.index == index)
+                .map_or_else(|| "_".to_string(), |binding| binding.ident.to_string())
+        })
+        .collect::<Vec<_>>();
+
+    let mut applicability = Applicability::MaybeIncorrect;
+    let slice_name = snippet_with_applicability(cx, first.slice_span, "..", &mut applicability);
+
+    span_lint_and_sugg(
+        cx,
+        INDEX_REFUTABLE_SLICE,
+        first.stmt_span.to(last.stmt_span),
+        "these bindings can be a slice pattern to avoid `get(..).unwrap()`",
+        "replace the bindings with a slice pattern",
+        format!(
+            "let [{}, ..] = &{slice_name}[..] else {{ panic!() }};",
+            pat_sugg_idents.join(", ")
+        ),
+        applicability,
+    );
+}
+
 #[derive(Debug)]
 struct SliceLintInformation {
     ident: Ident,
@@ -103,7 +103,7 @@ pub struct IndexingSlicing {
 impl IndexingSlicing {
     pub fn new(conf: &'static Conf) -> Self {
         Self {
-            allow_indexing_slicing_in_tests: conf.allow_indexing_slicing_in_tests,
+            allow_indexing_slicing_in_tests: conf.is_allowed_in_tests(INDEXING_SLICING),
             suppress_restriction_lint_in_const: conf.suppress_restriction_lint_in_const,
         }
     }
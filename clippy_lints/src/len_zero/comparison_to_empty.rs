@@ -0,0 +1,159 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::{is_path_diagnostic_item, is_res_lang_ctor, is_trait_method, path_res, peel_ref_operators};
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, LangItem, LetExpr, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::Span;
+use rustc_span::symbol::sym;
+
+use super::{COMPARISON_TO_EMPTY, has_is_empty};
+
+/// Checks `if let "" = s` and `if let [] = v`.
+pub(super) fn check_let(cx: &LateContext<'_>, expr: &Expr<'_>, lt: &LetExpr<'_>) {
+    if match lt.pat.kind {
+        PatKind::Slice([], None, []) => true,
+        PatKind::Lit(lit) if is_empty_string(lit) => true,
+        _ => false,
+    } && !expr.span.from_expansion()
+        && has_is_empty(cx, lt.init)
+    {
+        let mut applicability = Applicability::MachineApplicable;
+
+        let lit1 = peel_ref_operators(cx, lt.init);
+        let lit_str = Sugg::hir_with_context(cx, lit1, lt.span.ctxt(), "_", &mut applicability).maybe_par();
+
+        span_lint_and_sugg(
+            cx,
+            COMPARISON_TO_EMPTY,
+            lt.span,
+            "comparison to empty slice using `if let`",
+            "using `is_empty` is clearer and more explicit",
+            format!("{lit_str}.is_empty()"),
+            applicability,
+        );
+    }
+}
+
+/// Checks `s == ""`, `v == []`, `opt == Some("")` and `opt == Some(String::new())`.
+pub(super) fn check_empty_expr(
+    cx: &LateContext<'_>,
+    span: Span,
+    lit1: &Expr<'_>,
+    lit2: &Expr<'_>,
+    op: &str,
+    msrv: &Msrv,
+) {
+    if (is_empty_array(lit2) || is_empty_string(lit2)) && has_is_empty(cx, lit1) {
+        let mut applicability = Applicability::MachineApplicable;
+
+        let lit1 = peel_ref_operators(cx, lit1);
+        let lit_str = Sugg::hir_with_context(cx, lit1, span.ctxt(), "_", &mut applicability).maybe_par();
+
+        span_lint_and_sugg(
+            cx,
+            COMPARISON_TO_EMPTY,
+            span,
+            "comparison to empty slice",
+            format!("using `{op}is_empty` is clearer and more explicit"),
+            format!("{op}{lit_str}.is_empty()"),
+            applicability,
+        );
+    } else if let ExprKind::Call(some, [arg]) = lit2.kind
+        && is_res_lang_ctor(cx, path_res(cx, some), LangItem::OptionSome)
+        && (is_empty_string(arg) || is_string_new(cx, arg))
+        && let opt_ty = cx.typeck_results().expr_ty(lit1)
+        && is_type_diagnostic_item(cx, opt_ty, sym::Option)
+        && let ty::Adt(_, args) = opt_ty.kind()
+        && let Some((as_ref, is_empty)) = string_is_empty_path(cx, args.type_at(0))
+        && msrv.meets(msrvs::OPTION_RESULT_IS_VARIANT_AND)
+    {
+        let mut applicability = Applicability::MachineApplicable;
+        let opt = Sugg::hir_with_context(cx, lit1, span.ctxt(), "_", &mut applicability).maybe_par();
+
+        span_lint_and_sugg(
+            cx,
+            COMPARISON_TO_EMPTY,
+            span,
+            "comparison to `Some` of an empty string",
+            format!("using `{op}is_some_and({is_empty})` is clearer and more explicit"),
+            format!("{op}{opt}{as_ref}.is_some_and({is_empty})"),
+            applicability,
+        );
+    }
+}
+
+/// Checks `s.chars().count() == 0` and similar comparisons, see `check_cmp`.
+pub(super) fn check_chars_count(
+    cx: &LateContext<'_>,
+    span: Span,
+    count_call: &Expr<'_>,
+    lit: &LitKind,
+    op: &str,
+    compare_to: u32,
+) {
+    if let LitKind::Int(lit, _) = *lit
+        && lit == u128::from(compare_to)
+        && let ExprKind::MethodCall(count, receiver, [], _) = count_call.kind
+        && count.ident.as_str() == "count"
+        && is_trait_method(cx, count_call, sym::Iterator)
+        && let ExprKind::MethodCall(chars, s, [], _) = receiver.kind
+        && chars.ident.as_str() == "chars"
+        && cx.typeck_results().expr_ty_adjusted(s).peel_refs().is_str()
+    {
+        let mut applicability = Applicability::MachineApplicable;
+        let s = Sugg::hir_with_context(cx, s, span.ctxt(), "_", &mut applicability).maybe_par();
+
+        span_lint_and_sugg(
+            cx,
+            COMPARISON_TO_EMPTY,
+            span,
+            format!(
+                "comparison of the number of characters to {}",
+                if compare_to == 0 { "zero" } else { "one" }
+            ),
+            format!("using `{op}is_empty` is clearer and more explicit"),
+            format!("{op}{s}.is_empty()"),
+            applicability,
+        );
+    }
+}
+
+fn is_empty_string(expr: &Expr<'_>) -> bool {
+    if let ExprKind::Lit(lit) = expr.kind {
+        if let LitKind::Str(lit, _) = lit.node {
+            let lit = lit.as_str();
+            return lit.is_empty();
+        }
+    }
+    false
+}
+
+fn is_empty_array(expr: &Expr<'_>) -> bool {
+    if let ExprKind::Array(arr) = expr.kind {
+        return arr.is_empty();
+    }
+    false
+}
+
+/// For the inner type of an `Option`, returns the method to call on the option before
+/// `is_some_and` and the path of the `is_empty` method to pass to it.
+fn string_is_empty_path(cx: &LateContext<'_>, ty: Ty<'_>) -> Option<(&'static str, &'static str)> {
+    if is_type_lang_item(cx, ty, LangItem::String) {
+        Some((".as_ref()", "String::is_empty"))
+    } else if let ty::Ref(_, inner, _) = ty.kind()
+        && inner.is_str()
+    {
+        Some(("", "str::is_empty"))
+    } else {
+        None
+    }
+}
+
+fn is_string_new(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Call(func, []) if is_path_diagnostic_item(cx, func, sym::string_new))
+}
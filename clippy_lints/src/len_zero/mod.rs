@@ -1,6 +1,10 @@
+mod comparison_to_empty;
+
+use clippy_config::Conf;
 use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::msrvs::Msrv;
 use clippy_utils::source::{SpanRangeExt, snippet_with_context};
-use clippy_utils::sugg::{Sugg, has_enclosing_paren};
+use clippy_utils::sugg::has_enclosing_paren;
 use clippy_utils::ty::implements_trait;
 use clippy_utils::{get_item_name, get_parent_as_impl, is_lint_allowed, is_trait_method, peel_ref_operators};
 use rustc_ast::ast::LitKind;
@@ -9,12 +13,12 @@ use rustc_hir::def::Res;
 use rustc_hir::def_id::{DefId, DefIdSet};
 use rustc_hir::{
     AssocItemKind, BinOpKind, Expr, ExprKind, FnRetTy, GenericArg, GenericBound, ImplItem, ImplItemKind,
-    ImplicitSelfKind, Item, ItemKind, Mutability, Node, OpaqueTyOrigin, PatKind, PathSegment, PrimTy, QPath,
-    TraitItemRef, TyKind,
+    ImplicitSelfKind, Item, ItemKind, Mutability, Node, OpaqueTyOrigin, PathSegment, PrimTy, QPath, TraitItemRef,
+    TyKind,
 };
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, AssocKind, FnSig, Ty};
-use rustc_session::declare_lint_pass;
+use rustc_session::impl_lint_pass;
 use rustc_span::source_map::Spanned;
 use rustc_span::symbol::sym;
 use rustc_span::{Span, Symbol};
@@ -85,6 +89,9 @@ declare_clippy_lint! {
     /// Checks for comparing to an empty slice such as `""` or `[]`,
     /// and suggests using `.is_empty()` where applicable.
     ///
+    /// It also checks for comparing the number of characters of a string to zero, and for
+    /// comparing an `Option<String>` or `Option<&str>` to `Some` of an empty string.
+    ///
     /// ### Why is this bad?
     /// Some structures can answer `.is_empty()` much faster
     /// than checking for equality. So it is good to get into the habit of using
@@ -101,6 +108,10 @@ declare_clippy_lint! {
     /// if arr == [] {
     ///     ..
     /// }
+    ///
+    /// if s.chars().count() == 0 || opt == Some("") {
+    ///     ..
+    /// }
     /// ```
     /// Use instead:
     /// ```ignore
@@ -111,6 +122,10 @@ declare_clippy_lint! {
     /// if arr.is_empty() {
     ///     ..
     /// }
+    ///
+    /// if s.is_empty() || opt.is_some_and(str::is_empty) {
+    ///     ..
+    /// }
     /// ```
     #[clippy::version = "1.49.0"]
     pub COMPARISON_TO_EMPTY,
@@ -118,7 +133,19 @@ declare_clippy_lint! {
     "checking `x == \"\"` or `x == []` (or similar) when `.is_empty()` could be used instead"
 }
 
-declare_lint_pass!(LenZero => [LEN_ZERO, LEN_WITHOUT_IS_EMPTY, COMPARISON_TO_EMPTY]);
+pub struct LenZero {
+    msrv: Msrv,
+}
+
+impl LenZero {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            msrv: conf.msrv.clone(),
+        }
+    }
+}
+
+impl_lint_pass!(LenZero => [LEN_ZERO, LEN_WITHOUT_IS_EMPTY, COMPARISON_TO_EMPTY]);
 
 impl<'tcx> LateLintPass<'tcx> for LenZero {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
@@ -161,41 +188,21 @@ impl<'tcx> LateLintPass<'tcx> for LenZero {
     }
 
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        if let ExprKind::Let(lt) = expr.kind
-            && match lt.pat.kind {
-                PatKind::Slice([], None, []) => true,
-                PatKind::Lit(lit) if is_empty_string(lit) => true,
-                _ => false,
-            }
-            && !expr.span.from_expansion()
-            && has_is_empty(cx, lt.init)
-        {
-            let mut applicability = Applicability::MachineApplicable;
-
-            let lit1 = peel_ref_operators(cx, lt.init);
-            let lit_str = Sugg::hir_with_context(cx, lit1, lt.span.ctxt(), "_", &mut applicability).maybe_par();
-
-            span_lint_and_sugg(
-                cx,
-                COMPARISON_TO_EMPTY,
-                lt.span,
-                "comparison to empty slice using `if let`",
-                "using `is_empty` is clearer and more explicit",
-                format!("{lit_str}.is_empty()"),
-                applicability,
-            );
+        if let ExprKind::Let(lt) = expr.kind {
+            comparison_to_empty::check_let(cx, expr, lt);
         }
 
         if let ExprKind::MethodCall(method, lhs_expr, [rhs_expr], _) = expr.kind
             && is_trait_method(cx, expr, sym::PartialEq)
             && !expr.span.from_expansion()
         {
-            check_empty_expr(
+            comparison_to_empty::check_empty_expr(
                 cx,
                 expr.span,
                 lhs_expr,
                 peel_ref_operators(cx, rhs_expr),
                 (method.ident.name == sym::ne).then_some("!").unwrap_or_default(),
+                &self.msrv,
             );
         }
 
@@ -206,27 +213,29 @@ impl<'tcx> LateLintPass<'tcx> for LenZero {
             let actual_span = span_without_enclosing_paren(cx, expr.span);
             match cmp {
                 BinOpKind::Eq => {
-                    check_cmp(cx, actual_span, left, right, "", 0); // len == 0
-                    check_cmp(cx, actual_span, right, left, "", 0); // 0 == len
+                    check_cmp(cx, &self.msrv, actual_span, left, right, "", 0); // len == 0
+                    check_cmp(cx, &self.msrv, actual_span, right, left, "", 0); // 0 == len
                 },
                 BinOpKind::Ne => {
-                    check_cmp(cx, actual_span, left, right, "!", 0); // len != 0
-                    check_cmp(cx, actual_span, right, left, "!", 0); // 0 != len
+                    check_cmp(cx, &self.msrv, actual_span, left, right, "!", 0); // len != 0
+                    check_cmp(cx, &self.msrv, actual_span, right, left, "!", 0); // 0 != len
                 },
                 BinOpKind::Gt => {
-                    check_cmp(cx, actual_span, left, right, "!", 0); // len > 0
-                    check_cmp(cx, actual_span, right, left, "", 1); // 1 > len
+                    check_cmp(cx, &self.msrv, actual_span, left, right, "!", 0); // len > 0
+                    check_cmp(cx, &self.msrv, actual_span, right, left, "", 1); // 1 > len
                 },
                 BinOpKind::Lt => {
-                    check_cmp(cx, actual_span, left, right, "", 1); // len < 1
-                    check_cmp(cx, actual_span, right, left, "!", 0); // 0 < len
+                    check_cmp(cx, &self.msrv, actual_span, left, right, "", 1); // len < 1
+                    check_cmp(cx, &self.msrv, actual_span, right, left, "!", 0); // 0 < len
                 },
-                BinOpKind::Ge => check_cmp(cx, actual_span, left, right, "!", 1), // len >= 1
-                BinOpKind::Le => check_cmp(cx, actual_span, right, left, "!", 1), // 1 <= len
+                BinOpKind::Ge => check_cmp(cx, &self.msrv, actual_span, left, right, "!", 1), // len >= 1
+                BinOpKind::Le => check_cmp(cx, &self.msrv, actual_span, right, left, "!", 1), // 1 <= len
                 _ => (),
             }
         }
     }
+
+    extract_msrv_attr!(LateContext);
 }
 
 fn span_without_enclosing_paren(cx: &LateContext<'_>, span: Span) -> Span {
@@ -509,12 +518,23 @@ fn check_for_is_empty(
     });
 }
 
-fn check_cmp(cx: &LateContext<'_>, span: Span, method: &Expr<'_>, lit: &Expr<'_>, op: &str, compare_to: u32) {
+fn check_cmp(
+    cx: &LateContext<'_>,
+    msrv: &Msrv,
+    span: Span,
+    method: &Expr<'_>,
+    lit: &Expr<'_>,
+    op: &str,
+    compare_to: u32,
+) {
     if method.span.from_expansion() {
         return;
     }
 
-    if let (&ExprKind::MethodCall(method_path, receiver, [], _), ExprKind::Lit(lit)) = (&method.kind, &lit.kind) {
+    // `s.trim() == ""` is a comparison to an empty string, not of a length
+    if let (&ExprKind::MethodCall(method_path, receiver, [], _), ExprKind::Lit(lit)) = (&method.kind, &lit.kind)
+        && !matches!(lit.node, LitKind::Str(..))
+    {
         // check if we are in an is_empty() method
         if let Some(name) = get_item_name(cx, method) {
             if name.as_str() == "is_empty" {
@@ -523,8 +543,9 @@ fn check_cmp(cx: &LateContext<'_>, span: Span, method: &Expr<'_>, lit: &Expr<'_>
         }
 
         check_len(cx, span, method_path.ident.name, receiver, &lit.node, op, compare_to);
+        comparison_to_empty::check_chars_count(cx, span, method, &lit.node, op, compare_to);
     } else {
-        check_empty_expr(cx, span, method, lit, op);
+        comparison_to_empty::check_empty_expr(cx, span, method, lit, op, msrv);
     }
 }
 
@@ -561,42 +582,6 @@ fn check_len(
     }
 }
 
-fn check_empty_expr(cx: &LateContext<'_>, span: Span, lit1: &Expr<'_>, lit2: &Expr<'_>, op: &str) {
-    if (is_empty_array(lit2) || is_empty_string(lit2)) && has_is_empty(cx, lit1) {
-        let mut applicability = Applicability::MachineApplicable;
-
-        let lit1 = peel_ref_operators(cx, lit1);
-        let lit_str = Sugg::hir_with_context(cx, lit1, span.ctxt(), "_", &mut applicability).maybe_par();
-
-        span_lint_and_sugg(
-            cx,
-            COMPARISON_TO_EMPTY,
-            span,
-            "comparison to empty slice",
-            format!("using `{op}is_empty` is clearer and more explicit"),
-            format!("{op}{lit_str}.is_empty()"),
-            applicability,
-        );
-    }
-}
-
-fn is_empty_string(expr: &Expr<'_>) -> bool {
-    if let ExprKind::Lit(lit) = expr.kind {
-        if let LitKind::Str(lit, _) = lit.node {
-            let lit = lit.as_str();
-            return lit.is_empty();
-        }
-    }
-    false
-}
-
-fn is_empty_array(expr: &Expr<'_>) -> bool {
-    if let ExprKind::Array(arr) = expr.kind {
-        return arr.is_empty();
-    }
-    false
-}
-
 /// Checks if this type has an `is_empty` method.
 fn has_is_empty(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     /// Gets an `AssocItem` and return true if it matches `is_empty(self)`.
@@ -77,12 +77,14 @@ mod absolute_paths;
 mod almost_complete_range;
 mod approx_const;
 mod arbitrary_source_item_ordering;
+mod arc_mutex_over_channel_heuristic;
 mod arc_with_non_send_sync;
 mod as_conversions;
 mod asm_syntax;
 mod assertions_on_constants;
 mod assertions_on_result_states;
 mod assigning_clones;
+mod async_fn_returning_boxed_future_alias;
 mod async_yields_async;
 mod attrs;
 mod await_holding_invalid;
@@ -114,6 +116,7 @@ mod default_union_representation;
 mod dereference;
 mod derivable_impls;
 mod derive;
+mod disallowed_attributes;
 mod disallowed_macros;
 mod disallowed_methods;
 mod disallowed_names;
@@ -130,6 +133,7 @@ mod empty_with_brackets;
 mod endian_bytes;
 mod entry;
 mod enum_clike;
+mod enum_variant_bool_pair;
 mod equatable_if_let;
 mod error_impl_error;
 mod escape;
@@ -200,6 +204,7 @@ mod lifetimes;
 mod lines_filter_map_ok;
 mod literal_representation;
 mod literal_string_with_formatting_args;
+mod loop_select_starvation;
 mod loops;
 mod macro_metavars_in_unsafe;
 mod macro_use;
@@ -245,6 +250,7 @@ mod missing_fields_in_debug;
 mod missing_inline;
 mod missing_trait_methods;
 mod mixed_read_write_in_expression;
+mod module_inception_depth;
 mod module_style;
 mod multi_assignments;
 mod multiple_bound_locations;
@@ -286,6 +292,7 @@ mod only_used_in_recursion;
 mod operators;
 mod option_env_unwrap;
 mod option_if_let_else;
+mod overflow_prone_duration_arithmetic;
 mod panic_in_result_fn;
 mod panic_unimplemented;
 mod panicking_overflow_checks;
@@ -297,6 +304,7 @@ mod pathbuf_init_then_push;
 mod pattern_type_mismatch;
 mod permissions_set_readonly_false;
 mod pointers_in_nomem_asm_block;
+mod possible_deadlock_double_lock;
 mod precedence;
 mod ptr;
 mod ptr_offset_with_cast;
@@ -308,6 +316,7 @@ mod ranges;
 mod raw_strings;
 mod rc_clone_in_vec_init;
 mod read_zero_byte_vec;
+mod recursive_default_impl;
 mod redundant_async_block;
 mod redundant_clone;
 mod redundant_closure_call;
@@ -340,11 +349,14 @@ mod single_component_path_imports;
 mod single_range_in_vec_init;
 mod size_of_in_element_count;
 mod size_of_ref;
+mod slice_fill_with_copy_from_slice_of_repeat;
+mod slow_string_comparisons;
 mod slow_vector_initialization;
 mod std_instead_of_core;
 mod string_patterns;
 mod strings;
 mod strlen_on_c_strings;
+mod struct_update_syntax_resets_fields;
 mod suspicious_operation_groupings;
 mod suspicious_trait_impl;
 mod suspicious_xor_used_as_pow;
@@ -367,19 +379,24 @@ mod uninhabited_references;
 mod uninit_vec;
 mod unit_return_expecting_ord;
 mod unit_types;
+mod unjoined_thread_handle;
+mod unnecessary_box_pin;
 mod unnecessary_box_returns;
 mod unnecessary_literal_bound;
 mod unnecessary_map_on_constructor;
 mod unnecessary_owned_empty_strings;
+mod unnecessary_rc_refcell_for_immutable_data;
 mod unnecessary_self_imports;
 mod unnecessary_struct_initialization;
 mod unnecessary_wraps;
 mod unneeded_struct_pattern;
 mod unnested_or_patterns;
+mod unportable_path_literal;
 mod unsafe_removed_from_name;
 mod unused_async;
 mod unused_io_amount;
 mod unused_peekable;
+mod unused_pub_crate;
 mod unused_result_ok;
 mod unused_rounding;
 mod unused_self;
@@ -402,9 +419,12 @@ mod zombie_processes;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
 use clippy_config::{Conf, get_configuration_metadata, sanitize_explanation};
+use clippy_utils::diagnostics::FixFilter;
 use clippy_utils::macros::FormatArgsStorage;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_lint::{Lint, LintId};
+use rustc_session::Session;
+use rustc_span::edit_distance::edit_distance;
 use utils::attr_collector::{AttrCollector, AttrStorage};
 
 /// Register all pre expansion lints
@@ -529,9 +549,36 @@ impl LintInfo {
             Internal => "internal",
         }
     }
+
+    /// Returns the first sentence of the "Why is this bad?" section of the documentation, or of
+    /// the "Why restrict this?" section for restriction lints
+    pub fn why_summary(&self) -> Option<String> {
+        let paragraph = self
+            .explanation
+            .lines()
+            .map(str::trim)
+            .skip_while(|line| !line.starts_with("### Why"))
+            .skip(1)
+            .skip_while(|line| line.is_empty())
+            .take_while(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with("```"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        // A sentence ends with a period followed by the capital letter of the next one
+        let end = paragraph
+            .match_indices(". ")
+            .find(|&(i, _)| paragraph[i + 2..].starts_with(char::is_uppercase))
+            .map_or(paragraph.len(), |(i, _)| i + 1);
+        let summary = &paragraph[..end];
+        (!summary.is_empty()).then(|| summary.to_owned())
+    }
 }
 
+/// Prints the documentation of the lint `name`, or the lints of a group when `name` is of the
+/// form `group:perf`. Returns the exit code.
 pub fn explain(name: &str) -> i32 {
+    if let Some(group) = name.strip_prefix("group:") {
+        return explain_group(group);
+    }
     let target = format!("clippy::{}", name.to_ascii_uppercase());
 
     if let Some(info) = declared_lints::LINTS.iter().find(|info| info.lint.name == target) {
@@ -549,10 +596,182 @@ pub fn explain(name: &str) -> i32 {
         0
     } else {
         println!("unknown lint: {name}");
+        let similar = similar_lints(name);
+        if !similar.is_empty() {
+            println!("\ndid you mean:");
+            for lint in similar {
+                println!("    {lint}");
+            }
+        }
         1
     }
 }
 
+/// Lists the lints of a group with their descriptions
+fn explain_group(group: &str) -> i32 {
+    let mut lints: Vec<_> = declared_lints::LINTS
+        .iter()
+        .filter(|info| info.category_str() == group)
+        .map(|info| (info.name_lower(), info.lint.desc))
+        .collect();
+    if lints.is_empty() {
+        let mut groups: Vec<_> = declared_lints::LINTS.iter().map(|info| info.category_str()).collect();
+        groups.sort_unstable();
+        groups.dedup();
+        println!("unknown lint group: {group}");
+        println!("\nthe lint groups are: {}", groups.join(", "));
+        return 1;
+    }
+
+    lints.sort_unstable();
+    let width = lints.iter().map(|(name, _)| name.len()).max().unwrap_or_default();
+    println!("{} lints in `clippy::{group}`:\n", lints.len());
+    for (name, desc) in lints {
+        println!("    {name:width$}  {desc}");
+    }
+    0
+}
+
+/// Returns up to three lint names close to `name`, the closest first
+fn similar_lints(name: &str) -> Vec<String> {
+    let max_dist = (name.len() / 3).max(1);
+    let mut similar: Vec<_> = declared_lints::LINTS
+        .iter()
+        .filter_map(|info| {
+            let lint = info.name_lower();
+            Some((edit_distance(name, &lint, max_dist)?, lint))
+        })
+        .collect();
+    similar.sort_unstable();
+    similar.into_iter().take(3).map(|(_, lint)| lint).collect()
+}
+
+/// Normalizes a lint or lint group name passed to Clippy, e.g. `Clippy::Needless-Return` to
+/// `needless_return`
+fn normalize_lint_name(name: &str) -> String {
+    let name = name.to_ascii_lowercase().replace('-', "_");
+    match name.strip_prefix("clippy::") {
+        Some(name) => name.to_owned(),
+        None => name,
+    }
+}
+
+/// Expands a lint group name passed to Clippy, e.g. `clippy::pedantic`, to the names of its lints
+/// with the `clippy::` prefix. If `lints` is set, lint names are accepted as well and expand to
+/// themselves. Returns `None` for unknown names.
+fn expand_lint_name(name: &str, lints: bool) -> Option<Vec<String>> {
+    let name = normalize_lint_name(name);
+    let expanded: Vec<_> = declared_lints::LINTS
+        .iter()
+        .filter(|info| info.category_str() == name || (lints && info.name_lower() == name))
+        .map(|info| info.lint.name_lower())
+        .collect();
+    (!expanded.is_empty()).then_some(expanded)
+}
+
+/// Restricts the suggestions applied by `cargo clippy --fix`, see [`FixFilter`].
+///
+/// `only` contains lint names (`clippy::uninlined_format_args`) or lint groups
+/// (`clippy::pedantic`), groups are expanded to all of their lints. An empty `only` doesn't
+/// restrict the lints.
+///
+/// # Errors
+///
+/// Returns the first name which is neither a lint nor a lint group.
+pub fn set_fix_filter(apply_maybe_incorrect: bool, only: &[String]) -> Result<(), String> {
+    let only_lints = if only.is_empty() {
+        None
+    } else {
+        let mut lints = FxHashSet::default();
+        for lint in only {
+            lints.extend(expand_lint_name(lint, true).ok_or_else(|| lint.clone())?);
+        }
+        Some(lints)
+    };
+    clippy_utils::diagnostics::set_fix_filter(FixFilter {
+        apply_maybe_incorrect,
+        only_lints,
+    });
+    Ok(())
+}
+
+/// Hides the warnings of the lint groups passed to `cargo clippy --hide`, e.g. `pedantic` or
+/// `clippy::nursery`.
+///
+/// # Errors
+///
+/// Returns the first name which isn't a lint group.
+pub fn hide_groups(groups: &[String]) -> Result<(), String> {
+    let mut lints = FxHashSet::default();
+    for group in groups {
+        lints.extend(expand_lint_name(group, false).ok_or_else(|| group.clone())?);
+    }
+    clippy_utils::diagnostics::set_hidden_lints(lints);
+    Ok(())
+}
+
+/// Sets the lints which are not emitted in tests, from `lints-allowed-in-tests` and the
+/// `allow-*-in-tests` options, and warns about the names in `lints-allowed-in-tests` which are
+/// neither Clippy lints nor lint groups.
+pub fn set_lints_allowed_in_tests(sess: &Session, conf: &Conf) {
+    let mut allowed: FxHashSet<_> = declared_lints::LINTS
+        .iter()
+        .filter(|info| conf.is_allowed_in_tests(info.lint))
+        .map(|info| info.lint.name_lower())
+        .collect();
+    for lint in &conf.lints_allowed_in_tests {
+        if let Some(lints) = expand_lint_name(lint, true) {
+            allowed.extend(lints);
+            continue;
+        }
+
+        let mut diag = sess.dcx().struct_warn(format!(
+            "unknown lint `{lint}` in `lints-allowed-in-tests` of Clippy's configuration file"
+        ));
+        let similar = similar_lints(&normalize_lint_name(lint));
+        if !similar.is_empty() {
+            diag.help(format!("did you mean: `{}`", similar.join("`, `")));
+        }
+        diag.emit();
+    }
+    clippy_utils::diagnostics::set_allowed_in_tests(allowed);
+}
+
+/// Tags the alternatives of suggestions with their stable ID, see
+/// [`suggest_alternatives`](clippy_utils::diagnostics::suggest_alternatives).
+pub fn set_tag_suggestion_alternatives() {
+    clippy_utils::diagnostics::set_tag_suggestion_alternatives();
+}
+
+/// Suggests an `#[allow]` attribute for every emitted lint, see
+/// [`set_suggest_allows`](clippy_utils::diagnostics::set_suggest_allows).
+pub fn set_suggest_allows() {
+    clippy_utils::diagnostics::set_suggest_allows();
+}
+
+/// Counts the emitted lints for the summary of `cargo clippy --summary`, see
+/// [`record_emissions`](clippy_utils::diagnostics::record_emissions).
+pub fn record_emissions() {
+    clippy_utils::diagnostics::record_emissions();
+}
+
+/// Appends the lints emitted in this session to the file at `path`, one
+/// `lint<TAB>total<TAB>fixable` line per lint.
+///
+/// # Errors
+///
+/// Returns the error of writing to the file.
+pub fn write_emission_summary(path: &std::path::Path) -> std::io::Result<()> {
+    utils::append_tsv(
+        path,
+        clippy_utils::diagnostics::take_emissions()
+            .into_iter()
+            .map(|(lint, count)| [lint, count.total.to_string(), count.fixable.to_string()]),
+    )
+}
+
+pub use utils::pass_timings::{time_passes, write_pass_timings};
+
 fn register_categories(store: &mut rustc_lint::LintStore) {
     let mut groups = RegistrationGroups::default();
 
@@ -576,6 +795,14 @@ fn register_categories(store: &mut rustc_lint::LintStore) {
 #[expect(clippy::too_many_lines)]
 pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     register_categories(store);
+    clippy_utils::diagnostics::set_denied_in_ci(conf.denied_in_ci());
+    if conf.verbose_diagnostics {
+        clippy_utils::diagnostics::set_lint_summaries(
+            declared_lints::LINTS
+                .iter()
+                .map(|info| (info.lint.name_lower(), (info.category_str(), info.why_summary()))),
+        );
+    }
 
     for (old_name, new_name) in deprecated_lints::RENAMED {
         store.register_renamed(old_name, new_name);
@@ -584,6 +811,8 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         store.register_removed(name, reason);
     }
 
+    store.register_early_pass(|| Box::new(utils::node_span_collector::NodeSpanCollector));
+
     let format_args_storage = FormatArgsStorage::default();
     let format_args = format_args_storage.clone();
     store.register_early_pass(move || {
@@ -596,6 +825,8 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     let attrs = attr_storage.clone();
     store.register_early_pass(move || Box::new(AttrCollector::new(attrs.clone())));
 
+    store.register_late_pass(|_| Box::new(utils::late_passes_start::LatePassesStart));
+
     // all the internal lints
     #[cfg(feature = "internal")]
     {
@@ -631,7 +862,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |_| Box::new(booleans::NonminimalBool::new(conf)));
     store.register_late_pass(|_| Box::new(enum_clike::UnportableVariant));
     store.register_late_pass(|_| Box::new(float_literal::FloatLiteral));
-    store.register_late_pass(|_| Box::new(ptr::Ptr));
+    store.register_late_pass(|_| Box::<ptr::Ptr>::default());
     store.register_late_pass(|_| Box::new(needless_bool::NeedlessBool));
     store.register_late_pass(|_| Box::new(needless_bool::BoolComparison));
     store.register_late_pass(|_| Box::new(needless_for_each::NeedlessForEach));
@@ -640,7 +871,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(mut_mut::MutMut));
     store.register_late_pass(|_| Box::new(mut_reference::UnnecessaryMutPassed));
     store.register_late_pass(|_| Box::<significant_drop_tightening::SignificantDropTightening<'_>>::default());
-    store.register_late_pass(|_| Box::new(len_zero::LenZero));
+    store.register_late_pass(move |_| Box::new(len_zero::LenZero::new(conf)));
     store.register_late_pass(move |_| Box::new(attrs::Attributes::new(conf)));
     store.register_late_pass(|_| Box::new(blocks_in_conditions::BlocksInConditions));
     store.register_late_pass(|_| Box::new(unicode::Unicode));
@@ -677,9 +908,9 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(size_of_in_element_count::SizeOfInElementCount));
     store.register_late_pass(|_| Box::new(same_name_method::SameNameMethod));
     store.register_late_pass(move |_| Box::new(index_refutable_slice::IndexRefutableSlice::new(conf)));
-    store.register_late_pass(|_| Box::<shadow::Shadow>::default());
+    store.register_late_pass(move |_| Box::new(shadow::Shadow::new(conf)));
     store.register_late_pass(|_| Box::new(unit_types::UnitTypes));
-    store.register_late_pass(move |_| Box::new(loops::Loops::new(conf)));
+    store.register_late_pass(move |tcx| Box::new(loops::Loops::new(tcx, conf)));
     store.register_late_pass(|_| Box::<main_recursion::MainRecursion>::default());
     store.register_late_pass(move |_| Box::new(lifetimes::Lifetimes::new(conf)));
     store.register_late_pass(|_| Box::new(entry::HashMapPass));
@@ -689,7 +920,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(needless_update::NeedlessUpdate));
     store.register_late_pass(|_| Box::new(needless_borrowed_ref::NeedlessBorrowedRef));
     store.register_late_pass(|_| Box::new(borrow_deref_ref::BorrowDerefRef));
-    store.register_late_pass(|_| Box::<no_effect::NoEffect>::default());
+    store.register_late_pass(move |tcx| Box::new(no_effect::NoEffect::new(tcx, conf)));
     store.register_late_pass(|_| Box::new(temporary_assignment::TemporaryAssignment));
     store.register_late_pass(move |_| Box::new(transmute::Transmute::new(conf)));
     store.register_late_pass(move |_| Box::new(cognitive_complexity::CognitiveComplexity::new(conf)));
@@ -795,7 +1026,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |_| Box::new(wildcard_imports::WildcardImports::new(conf)));
     store.register_late_pass(|_| Box::<redundant_pub_crate::RedundantPubCrate>::default());
     store.register_late_pass(|_| Box::<dereference::Dereferencing<'_>>::default());
-    store.register_late_pass(|_| Box::new(option_if_let_else::OptionIfLetElse));
+    store.register_late_pass(move |_| Box::new(option_if_let_else::OptionIfLetElse::new(conf)));
     store.register_late_pass(|_| Box::new(future_not_send::FutureNotSend));
     store.register_late_pass(move |_| Box::new(large_futures::LargeFuture::new(conf)));
     store.register_late_pass(|_| Box::new(if_let_mutex::IfLetMutex));
@@ -890,7 +1121,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(multiple_unsafe_ops_per_block::MultipleUnsafeOpsPerBlock));
     store.register_late_pass(move |_| Box::new(extra_unused_type_parameters::ExtraUnusedTypeParameters::new(conf)));
     store.register_late_pass(|_| Box::new(no_mangle_with_rust_abi::NoMangleWithRustAbi));
-    store.register_late_pass(|_| Box::new(collection_is_never_read::CollectionIsNeverRead));
+    store.register_late_pass(|_| Box::<collection_is_never_read::CollectionIsNeverRead>::default());
     store.register_late_pass(|_| Box::new(missing_assert_message::MissingAssertMessage));
     store.register_late_pass(|_| Box::new(needless_maybe_sized::NeedlessMaybeSized));
     store.register_late_pass(|_| Box::new(redundant_async_block::RedundantAsyncBlock));
@@ -972,5 +1203,32 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(unnecessary_literal_bound::UnnecessaryLiteralBound));
     store.register_late_pass(move |_| Box::new(arbitrary_source_item_ordering::ArbitrarySourceItemOrdering::new(conf)));
     store.register_late_pass(|_| Box::new(unneeded_struct_pattern::UnneededStructPattern));
+    store.register_late_pass(|_| Box::<enum_variant_bool_pair::EnumVariantBoolPair>::default());
+    store.register_late_pass(move |tcx| Box::new(slow_string_comparisons::SlowStringComparisons::new(tcx, conf)));
+    store.register_late_pass(|_| Box::<arc_mutex_over_channel_heuristic::ArcMutexOverChannelHeuristic>::default());
+    store.register_late_pass(|_| Box::new(loop_select_starvation::LoopSelectStarvation));
+    store.register_late_pass(|_| Box::new(possible_deadlock_double_lock::PossibleDeadlockDoubleLock));
+    store.register_late_pass(move |_| Box::new(module_inception_depth::ModuleInceptionDepth::new(conf)));
+    store.register_late_pass(|_| Box::new(recursive_default_impl::RecursiveDefaultImpl));
+    store.register_late_pass(move |_| Box::new(unnecessary_box_pin::UnnecessaryBoxPin::new(conf)));
+    store.register_late_pass(move |_| {
+        Box::new(unnecessary_rc_refcell_for_immutable_data::UnnecessaryRcRefcellForImmutableData::new(conf))
+    });
+    store.register_late_pass(move |_| Box::new(unportable_path_literal::UnportablePathLiteral::new(conf)));
+    store.register_late_pass(move |_| {
+        Box::new(slice_fill_with_copy_from_slice_of_repeat::SliceFillWithCopyFromSliceOfRepeat::new(conf))
+    });
+    store.register_late_pass(move |tcx| {
+        Box::new(async_fn_returning_boxed_future_alias::AsyncFnReturningBoxedFutureAlias::new(tcx, conf))
+    });
+    store.register_late_pass(move |tcx| Box::new(disallowed_attributes::DisallowedAttributes::new(tcx, conf)));
+    store.register_late_pass(move |_| Box::new(unjoined_thread_handle::UnjoinedThreadHandle::new(conf)));
+    store.register_late_pass(|_| Box::<unused_pub_crate::UnusedPubCrate>::default());
+    store.register_late_pass(|_| Box::new(overflow_prone_duration_arithmetic::OverflowProneDurationArithmetic));
+    store.register_late_pass(move |_| {
+        Box::new(struct_update_syntax_resets_fields::StructUpdateSyntaxResetsFields::new(
+            conf,
+        ))
+    });
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
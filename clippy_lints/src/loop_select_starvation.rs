@@ -0,0 +1,171 @@
+use std::ops::ControlFlow;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::root_macro_call;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::visitors::{for_each_expr, for_each_expr_without_closures};
+use clippy_utils::{match_def_path, paths};
+use rustc_hir::{
+    Closure, ClosureKind, CoroutineDesugaring, CoroutineKind, CoroutineSource, Expr, ExprKind, LoopSource, StmtKind,
+    YieldSource,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::declare_lint_pass;
+use rustc_span::SyntaxContext;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `tokio::select!` in a `loop` where one of the branches is always ready, e.g.
+    /// `std::future::ready(..)` or an `async` block which never awaits.
+    ///
+    /// ### Why is this bad?
+    /// An always ready branch completes on every iteration of the loop. Without `biased;`,
+    /// `select!` picks one of the ready branches at random, so the loop never waits and keeps
+    /// spinning. With `biased;`, the always ready branch starves all the branches after it.
+    ///
+    /// ### Example
+    /// ```ignore
+    /// loop {
+    ///     tokio::select! {
+    ///         Some(msg) = rx.recv() => handle(msg),
+    ///         _ = async { check_state() } => {},
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// loop {
+    ///     tokio::select! {
+    ///         biased;
+    ///         Some(msg) = rx.recv() => handle(msg),
+    ///         _ = async { check_state() } => {},
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub LOOP_SELECT_STARVATION,
+    nursery,
+    "`tokio::select!` in a loop with an always ready branch"
+}
+
+declare_lint_pass!(LoopSelectStarvation => [LOOP_SELECT_STARVATION]);
+
+impl<'tcx> LateLintPass<'tcx> for LoopSelectStarvation {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Loop(block, _, LoopSource::Loop, _) = expr.kind
+            && !in_external_macro(cx.tcx.sess, expr.span)
+        {
+            let ctxt = expr.span.ctxt();
+            let stmts = block.stmts.iter().filter_map(|stmt| match stmt.kind {
+                StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+                StmtKind::Let(_) | StmtKind::Item(_) => None,
+            });
+            for select in stmts.chain(block.expr) {
+                check_select(cx, select, ctxt);
+            }
+        }
+    }
+}
+
+fn check_select<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, ctxt: SyntaxContext) {
+    let Some(macro_call) = root_macro_call(expr.span) else {
+        return;
+    };
+    if macro_call.span.ctxt() != ctxt || !match_def_path(cx, macro_call.def_id, &paths::TOKIO_SELECT) {
+        return;
+    }
+    let Some(futures) = select_futures(cx, expr, ctxt) else {
+        return;
+    };
+    let biased = snippet_opt(cx, macro_call.span).is_some_and(|snippet| is_biased(&snippet));
+
+    for (i, future) in futures.iter().enumerate() {
+        let is_last = i + 1 == futures.len();
+        if (biased && is_last) || !is_always_ready(cx, future) {
+            continue;
+        }
+        span_lint_and_then(
+            cx,
+            LOOP_SELECT_STARVATION,
+            future.span,
+            "this `select!` branch is always ready",
+            |diag| {
+                if biased {
+                    diag.note("the branches after it are never polled");
+                    diag.help("move this branch after the other branches");
+                } else {
+                    diag.note("the loop never waits for the other branches");
+                    diag.help("add `biased;` to the `select!` and move this branch last, or restructure the loop");
+                }
+            },
+        );
+    }
+}
+
+/// Finds the futures of the branches of a `select!` expansion. They are put into a tuple which
+/// comes from the expansion, while the futures themselves come from the caller.
+fn select_futures<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    ctxt: SyntaxContext,
+) -> Option<Vec<&'tcx Expr<'tcx>>> {
+    for_each_expr(cx, expr, |e| {
+        if let ExprKind::Tup(elems) = e.kind
+            && e.span.ctxt() != ctxt
+            && !elems.is_empty()
+            && let futures = elems
+                .iter()
+                .map(|elem| peel_into_future(elem, ctxt))
+                .collect::<Vec<_>>()
+            && futures.iter().all(|future| future.span.ctxt() == ctxt)
+        {
+            ControlFlow::Break(futures)
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+}
+
+/// Newer versions of `tokio` wrap every future in a call to `IntoFuture::into_future`.
+fn peel_into_future<'tcx>(expr: &'tcx Expr<'tcx>, ctxt: SyntaxContext) -> &'tcx Expr<'tcx> {
+    if let ExprKind::Call(_, [arg]) | ExprKind::MethodCall(_, arg, [], _) = expr.kind
+        && expr.span.ctxt() != ctxt
+        && arg.span.ctxt() == ctxt
+    {
+        arg
+    } else {
+        expr
+    }
+}
+
+fn is_biased(snippet: &str) -> bool {
+    snippet
+        .split_once(['{', '(', '['])
+        .and_then(|(_, args)| args.trim_start().strip_prefix("biased"))
+        .is_some_and(|rest| rest.trim_start().starts_with(';'))
+}
+
+fn is_always_ready<'tcx>(cx: &LateContext<'tcx>, future: &'tcx Expr<'tcx>) -> bool {
+    if let ExprKind::Closure(Closure {
+        kind: ClosureKind::Coroutine(CoroutineKind::Desugared(CoroutineDesugaring::Async, CoroutineSource::Block)),
+        body,
+        ..
+    }) = future.kind
+    {
+        // An `async` block without any `.await` completes when it is first polled.
+        for_each_expr_without_closures(cx.tcx.hir().body(*body).value, |e| {
+            if let ExprKind::Yield(_, YieldSource::Await { .. }) = e.kind {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_none()
+    } else {
+        // `std::future::ready` and `futures::future::ready`
+        cx.typeck_results().expr_ty(future).ty_adt_def().is_some_and(|adt| {
+            match_def_path(cx, adt.did(), &paths::FUTURE_READY) || match_def_path(cx, adt.did(), &paths::FUTURES_READY)
+        })
+    }
+}
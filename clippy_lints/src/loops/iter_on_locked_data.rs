@@ -0,0 +1,86 @@
+use super::ITER_ON_LOCKED_DATA;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::path_def_id;
+use clippy_utils::visitors::{for_each_expr, for_each_expr_without_closures};
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{DefId, DefIdSet};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::{Span, sym};
+use std::ops::ControlFlow;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'_>, body: &'tcx Expr<'_>, slow_calls: &DefIdSet) {
+    if let Some(guard) = guard_temporary(cx, arg)
+        && let Some(slow_call) = slow_call(cx, body, slow_calls)
+    {
+        span_lint_and_then(
+            cx,
+            ITER_ON_LOCKED_DATA,
+            arg.span,
+            format!("this loop holds a `{guard}` until it ends"),
+            |diag| {
+                diag.span_note(slow_call, "the lock is still held during this call, which may block");
+                diag.help("clone or collect the data before the loop to release the lock earlier");
+            },
+        );
+    }
+}
+
+/// Returns the name of the lock guard type if the iterated expression creates one as a temporary,
+/// e.g. in `data.lock().unwrap().iter()`. Temporaries of the iterated expression are only dropped
+/// at the end of the loop.
+fn guard_temporary(cx: &LateContext<'_>, arg: &Expr<'_>) -> Option<&'static str> {
+    for_each_expr_without_closures(arg, |e| {
+        if !matches!(e.kind, ExprKind::Call(..) | ExprKind::MethodCall(..)) {
+            return ControlFlow::Continue(());
+        }
+        if let ty::Adt(adt, _) = cx.typeck_results().expr_ty(e).kind() {
+            match cx.tcx.get_diagnostic_name(adt.did()) {
+                Some(sym::MutexGuard) => return ControlFlow::Break("MutexGuard"),
+                Some(sym::RwLockReadGuard) => return ControlFlow::Break("RwLockReadGuard"),
+                Some(sym::RwLockWriteGuard) => return ControlFlow::Break("RwLockWriteGuard"),
+                _ => {},
+            }
+        }
+        ControlFlow::Continue(())
+    })
+}
+
+/// Returns the span of the first call in the loop body to one of the configured slow functions.
+fn slow_call<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'tcx>, slow_calls: &DefIdSet) -> Option<Span> {
+    for_each_expr(cx, body, |e| {
+        let def_id = match e.kind {
+            ExprKind::Call(callee, _) => path_def_id(cx, callee),
+            ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(e.hir_id),
+            _ => None,
+        };
+        if let Some(def_id) = def_id
+            && is_slow(cx, def_id, slow_calls)
+        {
+            ControlFlow::Break(e.span.source_callsite())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+}
+
+/// Whether the function or one of its parents, e.g. its trait, the type of its `impl` block or its
+/// module, is configured as slow.
+fn is_slow(cx: &LateContext<'_>, mut def_id: DefId, slow_calls: &DefIdSet) -> bool {
+    loop {
+        if slow_calls.contains(&def_id) {
+            return true;
+        }
+        if let DefKind::Impl { of_trait: false } = cx.tcx.def_kind(def_id)
+            && let Some(adt) = cx.tcx.type_of(def_id).instantiate_identity().ty_adt_def()
+            && slow_calls.contains(&adt.did())
+        {
+            return true;
+        }
+        match cx.tcx.opt_parent(def_id) {
+            Some(parent) => def_id = parent,
+            None => return false,
+        }
+    }
+}
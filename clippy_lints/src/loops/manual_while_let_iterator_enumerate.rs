@@ -0,0 +1,36 @@
+use super::{IncrementVisitor, InitializeVisitor, MANUAL_WHILE_LET_ITERATOR_ENUMERATE};
+use clippy_utils::{get_enclosing_block, is_integer_const, is_lint_allowed};
+use rustc_hir::Expr;
+use rustc_hir::intravisit::{walk_block, walk_expr};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty, UintTy};
+use rustc_span::Symbol;
+
+/// Returns the name of a `usize` variable which is initialized to zero before the `while let` loop
+/// and incremented exactly once per iteration of its body, i.e. a counter which can be replaced by
+/// `Iterator::enumerate`.
+pub(super) fn counter<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, body: &'tcx Expr<'_>) -> Option<Symbol> {
+    if is_lint_allowed(cx, MANUAL_WHILE_LET_ITERATOR_ENUMERATE, expr.hir_id) {
+        return None;
+    }
+
+    let mut increment_visitor = IncrementVisitor::new(cx);
+    walk_expr(&mut increment_visitor, body);
+
+    let block = get_enclosing_block(cx, expr.hir_id)?;
+    increment_visitor.into_results().find_map(|id| {
+        let mut initialize_visitor = InitializeVisitor::new(cx, expr, id);
+        walk_block(&mut initialize_visitor, block);
+
+        match initialize_visitor.get_result() {
+            // usize or inferred
+            Some((name, ty, initializer))
+                if is_integer_const(cx, initializer, 0)
+                    && matches!(ty.map(Ty::kind), Some(ty::Uint(UintTy::Usize)) | None) =>
+            {
+                Some(name)
+            },
+            _ => None,
+        }
+    })
+}
@@ -5,29 +5,35 @@ mod explicit_iter_loop;
 mod for_kv_map;
 mod infinite_loop;
 mod iter_next_loop;
+mod iter_on_locked_data;
 mod manual_find;
 mod manual_flatten;
 mod manual_memcpy;
+mod manual_while_let_iterator_enumerate;
 mod manual_while_let_some;
 mod missing_spin_loop;
 mod mut_range_bound;
 mod needless_range_loop;
 mod never_loop;
+mod repeated_deref_in_loop;
 mod same_item_push;
 mod single_element_loop;
 mod unused_enumerate_index;
 mod utils;
+mod vec_of_boxed_trait_objects_iteration_dyn_dispatch;
 mod while_float;
 mod while_immutable_condition;
 mod while_let_loop;
 mod while_let_on_iterator;
 
 use clippy_config::Conf;
-use clippy_utils::higher;
 use clippy_utils::msrvs::Msrv;
+use clippy_utils::{def_path_def_ids, higher};
 use rustc_ast::Label;
+use rustc_hir::def_id::DefIdSet;
 use rustc_hir::{Expr, ExprKind, LoopSource, Pat};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::TyCtxt;
 use rustc_session::impl_lint_pass;
 use rustc_span::Span;
 use utils::{IncrementVisitor, InitializeVisitor, make_iterator_snippet};
@@ -64,7 +70,8 @@ declare_clippy_lint! {
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for looping over the range of `0..len` of some
-    /// collection just to get the values by index.
+    /// collection just to get the values by index. Ranges reversed
+    /// with `rev` or stepped with `step_by` are checked as well.
     ///
     /// ### Why is this bad?
     /// Just iterating the collection itself makes the intent
@@ -299,6 +306,25 @@ declare_clippy_lint! {
     ///     ..
     /// }
     /// ```
+    ///
+    /// Also checks for `while let` loops awaiting the next item of a stream which is created
+    /// anew in the loop condition, e.g. `while let Some(val) = stream.clone().next().await`. Such a
+    /// loop only ever sees the first item of each new stream. The traits providing `next` can be
+    /// configured with `stream-traits`.
+    ///
+    /// ```ignore
+    /// while let Some(val) = make_stream().next().await {
+    ///     ..
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```ignore
+    /// let mut stream = make_stream();
+    /// while let Some(val) = stream.next().await {
+    ///     ..
+    /// }
+    /// ```
     #[clippy::version = "pre 1.29.0"]
     pub WHILE_LET_ON_ITERATOR,
     style,
@@ -714,15 +740,217 @@ declare_clippy_lint! {
     "possibly unintended infinite loop"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `for` loops over data behind a `Mutex` or `RwLock` where the lock guard is a
+    /// temporary of the iterated expression, e.g. `data.lock().unwrap().iter()`, and the loop body
+    /// calls a function which may block, e.g. because it does I/O.
+    ///
+    /// The functions which are considered to block can be configured with
+    /// `iter-on-locked-data-slow-calls`.
+    ///
+    /// ### Why is this bad?
+    /// Temporaries of the iterated expression are only dropped at the end of the loop, so the lock
+    /// is held for the whole loop. Other threads waiting for the lock are blocked while the loop
+    /// does slow work.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// # use std::io::Write;
+    /// fn save(names: &Mutex<Vec<String>>, file: &mut std::fs::File) {
+    ///     for name in names.lock().unwrap().iter() {
+    ///         writeln!(file, "{name}").unwrap();
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// # use std::io::Write;
+    /// fn save(names: &Mutex<Vec<String>>, file: &mut std::fs::File) {
+    ///     let names = names.lock().unwrap().clone();
+    ///     for name in &names {
+    ///         writeln!(file, "{name}").unwrap();
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub ITER_ON_LOCKED_DATA,
+    pedantic,
+    "iterating over locked data while calling functions which may block"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks `while let` loops over `Iterator::next` which use an explicit variable as a counter
+    /// of the iterations.
+    ///
+    /// ### Why is this bad?
+    /// The loop can be written as a `for` loop using `.enumerate()`, which makes the intent clearer.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let v = vec![1];
+    /// # fn bar(bar: usize, baz: usize) {}
+    /// let mut iter = v.into_iter();
+    /// let mut i = 0;
+    /// while let Some(item) = iter.next() {
+    ///     bar(i, item);
+    ///     i += 1;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let v = vec![1];
+    /// # fn bar(bar: usize, baz: usize) {}
+    /// let iter = v.into_iter();
+    /// for (i, item) in iter.enumerate() {
+    ///     bar(i, item);
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub MANUAL_WHILE_LET_ITERATOR_ENUMERATE,
+    complexity,
+    "`while let` loop over an iterator with an explicit counter which could use `.enumerate()`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for loops which lock the same `Mutex` with `lock().unwrap()`, or borrow the same
+    /// `RefCell`, on every iteration, while the `Mutex` or `RefCell` isn't used in any other way in
+    /// the loop.
+    ///
+    /// Accesses deciding when the loop is exited aren't linted, e.g. a loop polling a flag with
+    /// `if *flag.lock().unwrap() { break }`, as the value is expected to change in the meantime.
+    ///
+    /// ### Why is this bad?
+    /// Locking a `Mutex` or borrowing a `RefCell` isn't free. Doing it once before the loop avoids
+    /// repeating the work, and the poisoning or borrow checks, on every iteration.
+    ///
+    /// ### Known problems
+    /// The lock or borrow is then held for the whole loop. Other threads can't lock the `Mutex`
+    /// in the meantime, and functions called from the loop which access the `RefCell` through
+    /// another path will panic if they borrow it mutably.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// fn sum(values: &[u32], total: &Mutex<u32>) {
+    ///     for value in values {
+    ///         *total.lock().unwrap() += value;
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// fn sum(values: &[u32], total: &Mutex<u32>) {
+    ///     let mut total = total.lock().unwrap();
+    ///     for value in values {
+    ///         *total += value;
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub REPEATED_DEREF_IN_LOOP,
+    pedantic,
+    "locking the same `Mutex` or borrowing the same `RefCell` on every iteration of a loop"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for hot loops calling a trait method on every element of a `Vec`, a slice or an
+    /// array of `Box<dyn Trait>`, when the trait is local and only has a few implementors. A loop
+    /// is considered hot if it's nested in another loop or in a function marked `#[inline]`.
+    ///
+    /// ### Why is this bad?
+    /// Each call is dispatched dynamically through the vtable, which prevents inlining, and each
+    /// element is a separate allocation. With only a few implementors, an enum with a variant for
+    /// each of them can be stored directly, and its methods can be inlined. If all the elements
+    /// have the same type, a generic `Vec<T>` avoids the dispatch altogether.
+    ///
+    /// ### Known problems
+    /// Enums can't be extended by other crates, and are as large as their largest variant.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// trait Shape {
+    ///     fn area(&self) -> f64;
+    /// }
+    /// struct Square(f64);
+    /// impl Shape for Square {
+    ///     fn area(&self) -> f64 { self.0 * self.0 }
+    /// }
+    /// struct Circle(f64);
+    /// impl Shape for Circle {
+    ///     fn area(&self) -> f64 { 3.14 * self.0 * self.0 }
+    /// }
+    ///
+    /// fn total_area(scenes: &[Vec<Box<dyn Shape>>]) -> f64 {
+    ///     let mut total = 0.0;
+    ///     for scene in scenes {
+    ///         for shape in scene {
+    ///             total += shape.area();
+    ///         }
+    ///     }
+    ///     total
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// enum Shape {
+    ///     Square(f64),
+    ///     Circle(f64),
+    /// }
+    /// impl Shape {
+    ///     fn area(&self) -> f64 {
+    ///         match self {
+    ///             Self::Square(side) => side * side,
+    ///             Self::Circle(radius) => 3.14 * radius * radius,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn total_area(scenes: &[Vec<Shape>]) -> f64 {
+    ///     let mut total = 0.0;
+    ///     for scene in scenes {
+    ///         for shape in scene {
+    ///             total += shape.area();
+    ///         }
+    ///     }
+    ///     total
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub VEC_OF_BOXED_TRAIT_OBJECTS_ITERATION_DYN_DISPATCH,
+    nursery,
+    "dynamic dispatch on boxed trait objects with few implementors in a hot loop"
+}
+
 pub struct Loops {
     msrv: Msrv,
     enforce_iter_loop_reborrow: bool,
+    avoid_breaking_exported_api: bool,
+    slow_calls: DefIdSet,
+    stream_traits: DefIdSet,
 }
 impl Loops {
-    pub fn new(conf: &'static Conf) -> Self {
+    pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
         Self {
             msrv: conf.msrv.clone(),
             enforce_iter_loop_reborrow: conf.enforce_iter_loop_reborrow,
+            avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+            slow_calls: conf
+                .iter_on_locked_data_slow_calls
+                .iter()
+                .flat_map(|path| def_path_def_ids(tcx, &path.split("::").collect::<Vec<_>>()))
+                .collect(),
+            stream_traits: conf
+                .stream_traits
+                .iter()
+                .flat_map(|path| def_path_def_ids(tcx, &path.split("::").collect::<Vec<_>>()))
+                .collect(),
         }
     }
 }
@@ -750,6 +978,10 @@ impl_lint_pass!(Loops => [
     MANUAL_WHILE_LET_SOME,
     UNUSED_ENUMERATE_INDEX,
     INFINITE_LOOP,
+    ITER_ON_LOCKED_DATA,
+    MANUAL_WHILE_LET_ITERATOR_ENUMERATE,
+    REPEATED_DEREF_IN_LOOP,
+    VEC_OF_BOXED_TRAIT_OBJECTS_ITERATION_DYN_DISPATCH,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Loops {
@@ -796,13 +1028,14 @@ impl<'tcx> LateLintPass<'tcx> for Loops {
             infinite_loop::check(cx, expr, block, label);
         }
 
-        while_let_on_iterator::check(cx, expr);
+        while_let_on_iterator::check(cx, expr, &self.stream_traits);
 
         if let Some(higher::While { condition, body, span }) = higher::While::hir(expr) {
             while_immutable_condition::check(cx, condition, body);
             while_float::check(cx, condition);
             missing_spin_loop::check(cx, condition, body);
             manual_while_let_some::check(cx, condition, body, span);
+            repeated_deref_in_loop::check(cx, expr, body);
         }
     }
 
@@ -834,6 +1067,16 @@ impl Loops {
         manual_flatten::check(cx, pat, arg, body, span);
         manual_find::check(cx, pat, arg, body, span, expr);
         unused_enumerate_index::check(cx, pat, arg, body);
+        iter_on_locked_data::check(cx, arg, body, &self.slow_calls);
+        repeated_deref_in_loop::check(cx, expr, body);
+        vec_of_boxed_trait_objects_iteration_dyn_dispatch::check(
+            cx,
+            pat,
+            arg,
+            body,
+            expr,
+            self.avoid_breaking_exported_api,
+        );
     }
 
     fn check_for_loop_arg(&self, cx: &LateContext<'_>, _: &Pat<'_>, arg: &Expr<'_>) {
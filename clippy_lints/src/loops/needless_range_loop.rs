@@ -3,7 +3,7 @@ use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::source::snippet;
 use clippy_utils::ty::has_iter_method;
 use clippy_utils::visitors::is_local_used;
-use clippy_utils::{SpanlessEq, contains_name, higher, is_integer_const, sugg};
+use clippy_utils::{SpanlessEq, contains_name, higher, is_integer_const, is_trait_method, sugg};
 use rustc_ast::ast;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap};
 use rustc_errors::Applicability;
@@ -16,8 +16,16 @@ use rustc_middle::ty::{self, Ty};
 use rustc_span::symbol::{Symbol, sym};
 use std::{iter, mem};
 
+/// An adaptor called on the range being looped over.
+enum RangeAdaptor<'tcx> {
+    /// `(start..end).rev()`
+    Rev,
+    /// `(start..end).step_by(step)`
+    StepBy(&'tcx Expr<'tcx>),
+}
+
 /// Checks for looping over a range and then indexing a sequence with it.
-/// The iteratee must be a range literal.
+/// The iteratee must be a range literal, possibly reversed or stepped.
 #[expect(clippy::too_many_lines)]
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
@@ -26,11 +34,25 @@ pub(super) fn check<'tcx>(
     body: &'tcx Expr<'_>,
     expr: &'tcx Expr<'_>,
 ) {
+    let (range, adaptor) = match arg.kind {
+        ExprKind::MethodCall(method, recv, [], _)
+            if method.ident.name.as_str() == "rev" && is_trait_method(cx, arg, sym::Iterator) =>
+        {
+            (recv, Some(RangeAdaptor::Rev))
+        },
+        ExprKind::MethodCall(method, recv, [step], _)
+            if method.ident.name.as_str() == "step_by" && is_trait_method(cx, arg, sym::Iterator) =>
+        {
+            (recv, Some(RangeAdaptor::StepBy(step)))
+        },
+        _ => (arg, None),
+    };
+
     if let Some(higher::Range {
         start: Some(start),
         ref end,
         limits,
-    }) = higher::Range::hir(arg)
+    }) = higher::Range::hir(range)
     {
         // the var must be a single name
         if let PatKind::Binding(_, canonical_id, ident, _) = pat.kind {
@@ -125,6 +147,17 @@ pub(super) fn check<'tcx>(
                     String::new()
                 };
 
+                let adaptor = match adaptor {
+                    None => String::new(),
+                    Some(RangeAdaptor::Rev) => ".rev()".to_string(),
+                    Some(RangeAdaptor::StepBy(step)) => {
+                        if visitor.indexed_mut.contains(&indexed) && contains_name(indexed, step, cx) {
+                            return;
+                        }
+                        format!(".step_by({})", snippet(cx, step.span, ".."))
+                    },
+                };
+
                 let (ref_mut, method) = if visitor.indexed_mut.contains(&indexed) {
                     ("mut ", "iter_mut")
                 } else {
@@ -152,7 +185,7 @@ pub(super) fn check<'tcx>(
                                     (pat.span, format!("({}, <item>)", ident.name)),
                                     (
                                         arg.span,
-                                        format!("{indexed}.{method}().enumerate(){method_1}{method_2}"),
+                                        format!("{indexed}.{method}().enumerate(){method_1}{method_2}{adaptor}"),
                                     ),
                                 ],
                                 Applicability::HasPlaceholders,
@@ -160,10 +193,10 @@ pub(super) fn check<'tcx>(
                         },
                     );
                 } else {
-                    let repl = if starts_at_zero && take_is_empty {
+                    let repl = if starts_at_zero && take_is_empty && adaptor.is_empty() {
                         format!("&{ref_mut}{indexed}")
                     } else {
-                        format!("{indexed}.{method}(){method_1}{method_2}")
+                        format!("{indexed}.{method}(){method_1}{method_2}{adaptor}")
                     };
 
                     span_lint_and_then(
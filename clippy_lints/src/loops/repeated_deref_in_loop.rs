@@ -0,0 +1,246 @@
+use super::REPEATED_DEREF_IN_LOOP;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::{Descend, for_each_expr, for_each_expr_without_closures, is_local_used};
+use clippy_utils::{get_parent_expr, higher, path_to_local, path_to_local_id};
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId, Node, YieldSource};
+use rustc_lint::LateContext;
+use rustc_span::{Symbol, sym};
+use std::ops::ControlFlow;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AccessKind {
+    /// `mutex.lock().unwrap()`
+    Lock,
+    /// `cell.borrow()`
+    Borrow,
+    /// `cell.borrow_mut()`
+    BorrowMut,
+}
+
+struct Access<'tcx> {
+    kind: AccessKind,
+    /// The whole access, e.g. `mutex.lock().unwrap()`
+    expr: &'tcx Expr<'tcx>,
+    /// The `Mutex` or `RefCell`
+    recv: &'tcx Expr<'tcx>,
+    /// The local and the fields the receiver is made of, e.g. `self` and `[cache]` for `self.cache`
+    place: (HirId, Vec<Symbol>),
+}
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, body: &'tcx Expr<'tcx>) {
+    let accesses = accesses(cx, body);
+    if accesses.is_empty() {
+        return;
+    }
+    let has_await = for_each_expr(cx, expr, |e| {
+        if let ExprKind::Yield(_, YieldSource::Await { .. }) = e.kind {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some();
+    let condition = higher::While::hir(expr).map(|while_loop| while_loop.condition);
+
+    let mut checked: Vec<&(HirId, Vec<Symbol>)> = Vec::new();
+    for access in &accesses {
+        if checked.contains(&&access.place) {
+            continue;
+        }
+        checked.push(&access.place);
+
+        let group: Vec<_> = accesses.iter().filter(|a| a.place == access.place).collect();
+        let (local, _) = access.place;
+        if group.iter().any(|a| a.kind != access.kind)
+            || (access.kind == AccessKind::Lock && has_await)
+            || expr.span.contains(cx.tcx.hir().span(local))
+            || !group.iter().any(|a| is_unconditional(cx, a.expr, body))
+            || group.iter().any(|a| feeds_exit_condition(cx, a.expr, body, condition))
+            || !only_used_in(cx, expr, &access.place, &group)
+        {
+            continue;
+        }
+
+        let recv = snippet(cx, access.recv.span, "..");
+        let (msg, help) = match access.kind {
+            AccessKind::Lock => (
+                format!("`{recv}` is locked on every iteration of this loop"),
+                "lock it once before the loop and reuse the guard",
+            ),
+            AccessKind::Borrow => (
+                format!("`{recv}` is borrowed on every iteration of this loop"),
+                "borrow it once before the loop and reuse the `Ref`",
+            ),
+            AccessKind::BorrowMut => (
+                format!("`{recv}` is mutably borrowed on every iteration of this loop"),
+                "borrow it once before the loop and reuse the `RefMut`",
+            ),
+        };
+        span_lint_and_then(cx, REPEATED_DEREF_IN_LOOP, access.expr.span, msg, |diag| {
+            diag.help(help);
+        });
+    }
+}
+
+/// Collects the accesses to a `Mutex` or `RefCell` in the loop body, not counting those in closures
+/// and nested loops.
+fn accesses<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'tcx>) -> Vec<Access<'tcx>> {
+    let mut accesses = Vec::new();
+    for_each_expr_without_closures(body, |e| {
+        if matches!(e.kind, ExprKind::Loop(..)) {
+            return ControlFlow::<(), _>::Continue(Descend::No);
+        }
+        if let Some((kind, recv)) = access(cx, e)
+            && !e.span.from_expansion()
+            && let Some(place) = place(recv)
+        {
+            accesses.push(Access {
+                kind,
+                expr: e,
+                recv,
+                place,
+            });
+        }
+        ControlFlow::Continue(Descend::Yes)
+    });
+    accesses
+}
+
+fn access<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> Option<(AccessKind, &'tcx Expr<'tcx>)> {
+    let (kind, recv, ty) = match e.kind {
+        ExprKind::MethodCall(unwrap, lock, [], _)
+            if unwrap.ident.name == sym::unwrap
+                && let ExprKind::MethodCall(method, recv, [], _) = lock.kind
+                && method.ident.name.as_str() == "lock" =>
+        {
+            (AccessKind::Lock, recv, sym::Mutex)
+        },
+        ExprKind::MethodCall(method, recv, [], _) => match method.ident.name.as_str() {
+            "borrow" => (AccessKind::Borrow, recv, sym::RefCell),
+            "borrow_mut" => (AccessKind::BorrowMut, recv, sym::RefCell),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    is_type_diagnostic_item(cx, cx.typeck_results().expr_ty_adjusted(recv).peel_refs(), ty).then_some((kind, recv))
+}
+
+/// If `expr` is a local or a field of one, returns the local and the accessed fields.
+fn place(mut expr: &Expr<'_>) -> Option<(HirId, Vec<Symbol>)> {
+    let mut fields = Vec::new();
+    while let ExprKind::Field(base, field) = expr.kind {
+        fields.push(field.name);
+        expr = base;
+    }
+    fields.reverse();
+    path_to_local(expr).map(|local| (local, fields))
+}
+
+/// Whether `expr` is evaluated on every iteration, i.e. isn't inside of a condition or a nested
+/// loop.
+fn is_unconditional<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, body: &Expr<'_>) -> bool {
+    let mut child = expr.hir_id;
+    // Statements and blocks are walked through as well, unlike with `get_parent_expr`
+    for (parent_id, parent) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        if child == body.hir_id {
+            break;
+        }
+        if let Node::Expr(parent) = parent {
+            match parent.kind {
+                ExprKind::If(cond, ..) if cond.hir_id != child => return false,
+                ExprKind::Match(scrutinee, ..) if scrutinee.hir_id != child => return false,
+                ExprKind::Binary(op, _, rhs)
+                    if matches!(op.node, BinOpKind::And | BinOpKind::Or) && rhs.hir_id == child =>
+                {
+                    return false;
+                },
+                ExprKind::Loop(..) | ExprKind::Closure(_) => return false,
+                _ => {},
+            }
+        }
+        child = parent_id;
+    }
+    true
+}
+
+/// Whether the value of `expr` decides whether the loop is exited, e.g. in
+/// `if *flag.lock().unwrap() { break }`, or is assigned to a local read by the condition of the
+/// `while` loop. The value is then expected to change while the loop runs, which moving the
+/// access out of the loop would prevent.
+fn feeds_exit_condition<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    body: &Expr<'_>,
+    condition: Option<&'tcx Expr<'tcx>>,
+) -> bool {
+    let mut child = expr;
+    while child.hir_id != body.hir_id
+        && let Some(parent) = get_parent_expr(cx, child)
+    {
+        let exits = match parent.kind {
+            ExprKind::If(cond, then, els) if cond.hir_id == child.hir_id => {
+                exits_loop(then) || els.is_some_and(exits_loop)
+            },
+            ExprKind::Match(scrutinee, arms, _) if scrutinee.hir_id == child.hir_id => {
+                arms.iter().any(|arm| exits_loop(arm.body))
+            },
+            ExprKind::Assign(lhs, rhs, _) | ExprKind::AssignOp(_, lhs, rhs) if rhs.hir_id == child.hir_id => {
+                path_to_local(lhs).is_some_and(|local| condition.is_some_and(|cond| is_local_used(cx, cond, local)))
+            },
+            _ => false,
+        };
+        if exits {
+            return true;
+        }
+        child = parent;
+    }
+    false
+}
+
+/// Whether `expr` contains a `break` or a `return`, not counting those in closures and nested
+/// loops.
+fn exits_loop(expr: &Expr<'_>) -> bool {
+    for_each_expr_without_closures(expr, |e| match e.kind {
+        ExprKind::Break(..) | ExprKind::Ret(_) => ControlFlow::Break(()),
+        ExprKind::Loop(..) => ControlFlow::Continue(Descend::No),
+        _ => ControlFlow::Continue(Descend::Yes),
+    })
+    .is_some()
+}
+
+/// Whether the place is only used through the accesses of `group` in the loop. Any other use
+/// of the place or of a place containing it may assign to it, or lock or borrow it again, e.g. by
+/// calling a method on `self` when the accessed place is `self.cache`, which would deadlock or
+/// panic once the access is moved out of the loop.
+fn only_used_in<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    (local, fields): &(HirId, Vec<Symbol>),
+    group: &[&Access<'tcx>],
+) -> bool {
+    for_each_expr(cx, expr, |e| {
+        if !path_to_local_id(e, *local) {
+            return ControlFlow::Continue(());
+        }
+        let mut place = e;
+        for field in fields {
+            let Some(parent) = get_parent_expr(cx, place) else {
+                return ControlFlow::Break(());
+            };
+            match parent.kind {
+                ExprKind::Field(_, name) if name.name == *field => place = parent,
+                // A disjoint field
+                ExprKind::Field(..) => return ControlFlow::Continue(()),
+                _ => return ControlFlow::Break(()),
+            }
+        }
+        if group.iter().any(|access| access.recv.hir_id == place.hir_id) {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    })
+    .is_none()
+}
@@ -0,0 +1,130 @@
+use super::VEC_OF_BOXED_TRAIT_OBJECTS_ITERATION_DYN_DISPATCH;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::path_to_local_id;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_expr;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, Node, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::sym;
+use std::ops::ControlFlow;
+
+/// The maximum number of implementors of the trait for which an enum is suggested.
+const MAX_IMPLEMENTORS: usize = 4;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &Expr<'_>,
+    avoid_breaking_exported_api: bool,
+) {
+    if let PatKind::Binding(_, binding, _, None) = pat.kind
+        && let Some(trait_id) = boxed_trait_object_elements(cx, arg)
+        && let Some(trait_id) = trait_id.as_local()
+        && !(avoid_breaking_exported_api && cx.effective_visibilities.is_exported(trait_id))
+        && let Some(call_span) = for_each_expr(cx, body, |e| {
+            if let ExprKind::MethodCall(_, recv, ..) = e.kind
+                && path_to_local_id(recv, binding)
+                && let Some(method_id) = cx.typeck_results().type_dependent_def_id(e.hir_id)
+                && cx.tcx.trait_of_item(method_id) == Some(trait_id.to_def_id())
+            {
+                ControlFlow::Break(e.span)
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        && is_hot(cx, expr)
+        && let Some(implementors) = implementors(cx, trait_id.to_def_id())
+    {
+        let trait_name = cx.tcx.item_name(trait_id.to_def_id());
+        span_lint_and_then(
+            cx,
+            VEC_OF_BOXED_TRAIT_OBJECTS_ITERATION_DYN_DISPATCH,
+            call_span,
+            format!("dynamic dispatch on every element of a collection of `Box<dyn {trait_name}>` in a hot loop"),
+            |diag| {
+                if let [implementor] = &*implementors {
+                    diag.help(format!(
+                        "`{trait_name}` is only implemented by `{implementor}`, consider storing it directly \
+                        instead of boxing it"
+                    ));
+                } else {
+                    diag.help(format!(
+                        "`{trait_name}` is only implemented by {} in this crate, consider storing an enum with a \
+                        variant for each of them instead, or using generics if all elements have the same type",
+                        join_names(&implementors)
+                    ));
+                }
+            },
+        );
+    }
+}
+
+/// If `arg` iterates over a `Vec`, a slice or an array of `Box<dyn Trait>`, returns the trait.
+fn boxed_trait_object_elements(cx: &LateContext<'_>, arg: &Expr<'_>) -> Option<DefId> {
+    let collection = match arg.kind {
+        ExprKind::MethodCall(method, recv, [], _) if matches!(method.ident.as_str(), "iter" | "iter_mut") => recv,
+        _ => arg,
+    };
+    let ty = cx.typeck_results().expr_ty_adjusted(collection).peel_refs();
+    let elem_ty = match ty.kind() {
+        ty::Slice(elem_ty) | ty::Array(elem_ty, _) => *elem_ty,
+        ty::Adt(_, args) if is_type_diagnostic_item(cx, ty, sym::Vec) => args.type_at(0),
+        _ => return None,
+    };
+    match elem_ty.boxed_ty()?.kind() {
+        ty::Dynamic(preds, ..) => preds.principal_def_id(),
+        _ => None,
+    }
+}
+
+/// Whether the loop is in a function marked `#[inline]` or nested in another loop.
+fn is_hot(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    for (hir_id, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(Expr {
+                kind: ExprKind::Loop(..),
+                ..
+            }) => return true,
+            Node::Expr(Expr {
+                kind: ExprKind::Closure(..),
+                ..
+            }) => return false,
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => {
+                return cx.tcx.hir().attrs(hir_id).iter().any(|attr| attr.has_name(sym::inline));
+            },
+            _ => {},
+        }
+    }
+    false
+}
+
+/// Returns the types implementing the trait if there are only a few of them and the trait has no
+/// blanket implementations.
+fn implementors<'tcx>(cx: &LateContext<'tcx>, trait_id: DefId) -> Option<Vec<Ty<'tcx>>> {
+    let impls = cx.tcx.trait_impls_of(trait_id);
+    if !impls.blanket_impls().is_empty() {
+        return None;
+    }
+    let mut implementors: Vec<Ty<'tcx>> = Vec::new();
+    for &impl_id in impls.non_blanket_impls().values().flatten() {
+        let self_ty = cx.tcx.type_of(impl_id).instantiate_identity();
+        if !implementors.contains(&self_ty) {
+            implementors.push(self_ty);
+        }
+    }
+    (!implementors.is_empty() && implementors.len() <= MAX_IMPLEMENTORS).then_some(implementors)
+}
+
+/// Formats the types like "`A`, `B` and `C`".
+fn join_names(tys: &[Ty<'_>]) -> String {
+    let names: Vec<_> = tys.iter().map(|ty| format!("`{ty}`")).collect();
+    match names.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} and {last}", rest.join(", ")),
+        None => String::new(),
+    }
+}
@@ -1,21 +1,29 @@
 use std::ops::ControlFlow;
 
-use super::WHILE_LET_ON_ITERATOR;
+use super::manual_while_let_iterator_enumerate::counter;
+use super::{MANUAL_WHILE_LET_ITERATOR_ENUMERATE, WHILE_LET_ON_ITERATOR};
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::source::snippet_with_applicability;
-use clippy_utils::visitors::is_res_used;
-use clippy_utils::{get_enclosing_loop_or_multi_call_closure, higher, is_refutable, is_res_lang_ctor, is_trait_method};
+use clippy_utils::source::{snippet_indent, snippet_with_applicability};
+use clippy_utils::visitors::{for_each_expr, is_local_used, is_res_used};
+use clippy_utils::{
+    get_enclosing_loop_or_multi_call_closure, higher, is_refutable, is_res_lang_ctor, is_trait_method, path_to_local,
+    unused_name,
+};
 use rustc_errors::Applicability;
 use rustc_hir::def::Res;
+use rustc_hir::def_id::DefIdSet;
 use rustc_hir::intravisit::{Visitor, walk_expr};
-use rustc_hir::{Closure, Expr, ExprKind, HirId, LangItem, LetStmt, Mutability, PatKind, UnOp};
+use rustc_hir::{Closure, Expr, ExprKind, HirId, LangItem, LetStmt, MatchSource, Mutability, Node, PatKind, UnOp};
 use rustc_lint::LateContext;
 use rustc_middle::hir::nested_filter::OnlyBodies;
+use rustc_middle::ty::GenericArgKind;
 use rustc_middle::ty::adjustment::Adjust;
 use rustc_span::Symbol;
 use rustc_span::symbol::sym;
 
-pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, stream_traits: &DefIdSet) {
+    check_stream(cx, expr, stream_traits);
+
     if let Some(higher::WhileLet { if_then, let_pat, let_expr, label, .. }) = higher::WhileLet::hir(expr)
         // check for `Some(..)` pattern
         && let PatKind::TupleStruct(ref pat_path, some_pat, _) = let_pat.kind
@@ -56,6 +64,18 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         };
 
         let iterator = snippet_with_applicability(cx, iter_expr.span, "_", &mut applicability);
+        if let Some(name) = counter(cx, expr, if_then) {
+            span_lint_and_sugg(
+                cx,
+                MANUAL_WHILE_LET_ITERATOR_ENUMERATE,
+                expr.span.with_hi(let_expr.span.hi()),
+                format!("the variable `{name}` is used as a loop counter"),
+                "try",
+                format!("{loop_label}for ({name}, {loop_var}) in {iterator}{by_ref}.enumerate()"),
+                Applicability::MaybeIncorrect,
+            );
+            return;
+        }
         span_lint_and_sugg(
             cx,
             WHILE_LET_ON_ITERATOR,
@@ -68,6 +88,73 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
     }
 }
 
+/// Checks for `while let Some(x) = make_stream().next().await`, which awaits the first item of a
+/// new stream on each iteration. Only streams returned by a function or cloned from a local are
+/// checked.
+fn check_stream<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, stream_traits: &DefIdSet) {
+    if let Some(higher::WhileLet {
+        if_then,
+        let_pat,
+        let_expr,
+        label,
+        ..
+    }) = higher::WhileLet::hir(expr)
+        && let PatKind::TupleStruct(ref pat_path, _, _) = let_pat.kind
+        && is_res_lang_ctor(cx, cx.qpath_res(pat_path, let_pat.hir_id), LangItem::OptionSome)
+        // check for `.await` on a call to `StreamExt::next`
+        && let ExprKind::Match(into_future, _, MatchSource::AwaitDesugar) = let_expr.kind
+        && let ExprKind::Call(_, [next_call]) = into_future.kind
+        && let ExprKind::MethodCall(method_name, stream_expr, [], _) = next_call.kind
+        && method_name.ident.name == sym::next
+        && let Some(method_id) = cx.typeck_results().type_dependent_def_id(next_call.hir_id)
+        && cx.tcx.trait_of_item(method_id).is_some_and(|id| stream_traits.contains(&id))
+        // A place is the same stream on each iteration, and so is a reborrow like `stream.as_mut()`
+        && try_parse_iter_expr(cx, stream_expr).is_none()
+        // Other methods may return a new handle to a shared stream, e.g. `rx.stream()` on a channel
+        && match stream_expr.kind {
+            ExprKind::Call(..) => true,
+            ExprKind::MethodCall(_, receiver, [], _) => {
+                is_trait_method(cx, stream_expr, sym::Clone) && path_to_local(receiver).is_some()
+            },
+            _ => false,
+        }
+        && !cx.typeck_results().expr_ty(stream_expr).walk().any(|arg| {
+            matches!(arg.unpack(), GenericArgKind::Type(ty) if ty.ref_mutability() == Some(Mutability::Mut))
+        })
+        // The stream may be meant to be recreated from the state updated by the loop, and once it's
+        // created before the loop it may keep borrowing the locals it's created from
+        && for_each_expr(cx, stream_expr, |e| {
+            if path_to_local(e).is_some_and(|id| is_local_used(cx, if_then, id)) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_none()
+        // The stream is created in a `let` statement before the loop
+        && matches!(cx.tcx.parent_hir_node(expr.hir_id), Node::Stmt(_) | Node::Block(_))
+        && let Some(body_id) = cx.enclosing_body
+    {
+        // The new binding mustn't shadow a name used anywhere else in the body
+        let body = cx.tcx.hir().body(body_id).value;
+        let name = unused_name("stream", body, cx);
+        let mut applicability = Applicability::MaybeIncorrect;
+        let stream = snippet_with_applicability(cx, stream_expr.span, "..", &mut applicability);
+        let pat = snippet_with_applicability(cx, let_pat.span, "..", &mut applicability);
+        let indent = snippet_indent(cx, expr.span).unwrap_or_default();
+        let loop_label = label.map_or(String::new(), |l| format!("{}: ", l.ident.name));
+        span_lint_and_sugg(
+            cx,
+            WHILE_LET_ON_ITERATOR,
+            expr.span.with_hi(let_expr.span.hi()),
+            "this loop creates a new stream on each iteration",
+            "create the stream once, before the loop",
+            format!("let mut {name} = {stream};\n{indent}{loop_label}while let {pat} = {name}.next().await"),
+            applicability,
+        );
+    }
+}
+
 #[derive(Debug)]
 struct IterExpr {
     /// The fields used, in order of child to parent.
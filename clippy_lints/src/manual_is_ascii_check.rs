@@ -3,26 +3,27 @@ use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::macros::matching_root_macro_call;
 use clippy_utils::msrvs::{self, Msrv};
 use clippy_utils::sugg::Sugg;
-use clippy_utils::{higher, is_in_const_context, path_to_local, peel_ref_operators};
+use clippy_utils::{eq_expr_value, higher, is_in_const_context, path_to_local, peel_ref_operators};
 use rustc_ast::LitKind::{Byte, Char};
 use rustc_ast::ast::RangeLimits;
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind, Node, Param, PatKind, RangeEnd};
+use rustc_hir::{BinOpKind, Expr, ExprKind, Node, Param, PatKind, RangeEnd};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty::{self, Ty};
+use rustc_middle::ty::{self, Ty, UintTy};
 use rustc_session::impl_lint_pass;
 use rustc_span::{Span, sym};
 
 declare_clippy_lint! {
     /// ### What it does
     /// Suggests to use dedicated built-in methods,
-    /// `is_ascii_(lowercase|uppercase|digit|hexdigit)` for checking on corresponding
-    /// ascii range
+    /// `is_ascii_(lowercase|uppercase|alphabetic|alphanumeric|digit|hexdigit)` for checking on
+    /// corresponding ascii range, written either as `matches!`, `contains` or as a chain of
+    /// comparisons
     ///
     /// ### Why is this bad?
     /// Using the built-in functions is more readable and makes it
     /// clear that it's not a specific subset of characters, but all
-    /// ASCII (lowercase|uppercase|digit|hexdigit) characters.
+    /// ASCII (lowercase|uppercase|alphabetic|alphanumeric|digit|hexdigit) characters.
     /// ### Example
     /// ```no_run
     /// fn main() {
@@ -35,6 +36,9 @@ declare_clippy_lint! {
     ///     ('0'..='9').contains(&'0');
     ///     ('a'..='z').contains(&'a');
     ///     ('A'..='Z').contains(&'A');
+    ///
+    ///     let c = 'x';
+    ///     let _ = (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || (c >= '0' && c <= '9');
     /// }
     /// ```
     /// Use instead:
@@ -49,6 +53,9 @@ declare_clippy_lint! {
     ///     '0'.is_ascii_digit();
     ///     'a'.is_ascii_lowercase();
     ///     'A'.is_ascii_uppercase();
+    ///
+    ///     let c = 'x';
+    ///     let _ = c.is_ascii_alphanumeric();
     /// }
     /// ```
     #[clippy::version = "1.67.0"]
@@ -86,9 +93,55 @@ enum CharRange {
     UpperHexLetter,
     /// '0..=9' | 'a..=f' | 'A..=F'
     HexDigit,
+    /// `FullChar` | `Digit`
+    Alphanumeric,
     Otherwise,
 }
 
+impl CharRange {
+    // The disjoint parts the ranges are made of
+    const LOWER_HEX: u8 = 1 << 0;
+    const LOWER_REST: u8 = 1 << 1;
+    const UPPER_HEX: u8 = 1 << 2;
+    const UPPER_REST: u8 = 1 << 3;
+    const DIGIT: u8 = 1 << 4;
+
+    fn bits(&self) -> Option<u8> {
+        match self {
+            Self::LowerChar => Some(Self::LOWER_HEX | Self::LOWER_REST),
+            Self::UpperChar => Some(Self::UPPER_HEX | Self::UPPER_REST),
+            Self::FullChar => Some(Self::LOWER_HEX | Self::LOWER_REST | Self::UPPER_HEX | Self::UPPER_REST),
+            Self::Digit => Some(Self::DIGIT),
+            Self::LowerHexLetter => Some(Self::LOWER_HEX),
+            Self::UpperHexLetter => Some(Self::UPPER_HEX),
+            Self::HexDigit => Some(Self::DIGIT | Self::LOWER_HEX | Self::UPPER_HEX),
+            Self::Alphanumeric => {
+                Some(Self::DIGIT | Self::LOWER_HEX | Self::LOWER_REST | Self::UPPER_HEX | Self::UPPER_REST)
+            },
+            Self::Otherwise => None,
+        }
+    }
+
+    /// Merges the ranges, e.g. `'a'..='z' | 'A'..='Z'` into `FullChar`. Returns `Otherwise` if the
+    /// merged range isn't one of the known ranges.
+    fn union(ranges: impl IntoIterator<Item = Self>) -> Self {
+        let bits = ranges.into_iter().try_fold(0, |bits, range| Some(bits | range.bits()?));
+        [
+            Self::LowerChar,
+            Self::UpperChar,
+            Self::FullChar,
+            Self::Digit,
+            Self::LowerHexLetter,
+            Self::UpperHexLetter,
+            Self::HexDigit,
+            Self::Alphanumeric,
+        ]
+        .into_iter()
+        .find(|range| bits.is_some() && range.bits() == bits)
+        .unwrap_or(Self::Otherwise)
+    }
+}
+
 impl<'tcx> LateLintPass<'tcx> for ManualIsAsciiCheck {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if !self.msrv.meets(msrvs::IS_ASCII_DIGIT) {
@@ -117,6 +170,21 @@ impl<'tcx> LateLintPass<'tcx> for ManualIsAsciiCheck {
             let ty_sugg = get_ty_sugg(cx, arg);
             let range = check_range(start, end);
             check_is_ascii(cx, expr.span, arg, &range, ty_sugg);
+        } else if let ExprKind::Binary(op, ..) = expr.kind
+            && matches!(op.node, BinOpKind::And | BinOpKind::Or)
+            && !expr.span.from_expansion()
+            // Only lint the outermost chain of comparisons
+            && !matches!(
+                cx.tcx.parent_hir_node(expr.hir_id),
+                Node::Expr(parent) if check_comparisons(cx, parent).is_some()
+            )
+            && let Some((recv, range)) = check_comparisons(cx, expr)
+            && matches!(
+                cx.typeck_results().expr_ty(recv).kind(),
+                ty::Char | ty::Uint(UintTy::U8)
+            )
+        {
+            check_is_ascii(cx, expr.span, recv, &range, None);
         }
     }
 
@@ -148,6 +216,7 @@ fn check_is_ascii(
         CharRange::FullChar => "is_ascii_alphabetic",
         CharRange::Digit => "is_ascii_digit",
         CharRange::HexDigit => "is_ascii_hexdigit",
+        CharRange::Alphanumeric => "is_ascii_alphanumeric",
         CharRange::Otherwise | CharRange::LowerHexLetter | CharRange::UpperHexLetter => return,
     };
     let default_snip = "..";
@@ -171,21 +240,7 @@ fn check_is_ascii(
 
 fn check_pat(pat_kind: &PatKind<'_>) -> CharRange {
     match pat_kind {
-        PatKind::Or(pats) => {
-            let ranges = pats.iter().map(|p| check_pat(&p.kind)).collect::<Vec<_>>();
-
-            if ranges.len() == 2 && ranges.contains(&CharRange::UpperChar) && ranges.contains(&CharRange::LowerChar) {
-                CharRange::FullChar
-            } else if ranges.len() == 3
-                && ranges.contains(&CharRange::Digit)
-                && ranges.contains(&CharRange::LowerHexLetter)
-                && ranges.contains(&CharRange::UpperHexLetter)
-            {
-                CharRange::HexDigit
-            } else {
-                CharRange::Otherwise
-            }
-        },
+        PatKind::Or(pats) => CharRange::union(pats.iter().map(|p| check_pat(&p.kind))),
         PatKind::Range(Some(start), Some(end), kind) if *kind == RangeEnd::Included => check_range(start, end),
         _ => CharRange::Otherwise,
     }
@@ -207,3 +262,61 @@ fn check_range(start: &Expr<'_>, end: &Expr<'_>) -> CharRange {
         CharRange::Otherwise
     }
 }
+
+/// Checks for `x >= lo && x <= hi`, or a `||` chain of such checks on the same expression.
+fn check_comparisons<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, CharRange)> {
+    let mut ranges = Vec::new();
+    let recv = collect_comparisons(cx, expr, &mut ranges)?;
+    let range = CharRange::union(ranges);
+    (range != CharRange::Otherwise).then_some((recv, range))
+}
+
+/// Collects the ranges of a `||` chain of `x >= lo && x <= hi` checks, and returns the checked
+/// expression if it's the same in all of them.
+fn collect_comparisons<'tcx>(
+    cx: &LateContext<'_>,
+    expr: &'tcx Expr<'tcx>,
+    ranges: &mut Vec<CharRange>,
+) -> Option<&'tcx Expr<'tcx>> {
+    let ExprKind::Binary(op, lhs, rhs) = expr.kind else {
+        return None;
+    };
+    match op.node {
+        BinOpKind::Or => {
+            let lhs_recv = collect_comparisons(cx, lhs, ranges)?;
+            let rhs_recv = collect_comparisons(cx, rhs, ranges)?;
+            eq_expr_value(cx, lhs_recv, rhs_recv).then_some(lhs_recv)
+        },
+        BinOpKind::And => {
+            let (lhs_recv, lhs_bound) = check_bound(lhs)?;
+            let (rhs_recv, rhs_bound) = check_bound(rhs)?;
+            let ((Bound::Lower(start), Bound::Upper(end)) | (Bound::Upper(end), Bound::Lower(start))) =
+                (lhs_bound, rhs_bound)
+            else {
+                return None;
+            };
+            ranges.push(check_range(start, end));
+            eq_expr_value(cx, lhs_recv, rhs_recv).then_some(lhs_recv)
+        },
+        _ => None,
+    }
+}
+
+enum Bound<'tcx> {
+    Lower(&'tcx Expr<'tcx>),
+    Upper(&'tcx Expr<'tcx>),
+}
+
+/// Checks for `x >= lit`, `x <= lit` and their mirrored forms.
+fn check_bound<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, Bound<'tcx>)> {
+    let ExprKind::Binary(op, lhs, rhs) = expr.kind else {
+        return None;
+    };
+    match (op.node, &lhs.kind, &rhs.kind) {
+        (BinOpKind::Ge, _, ExprKind::Lit(_)) => Some((lhs, Bound::Lower(rhs))),
+        (BinOpKind::Le, _, ExprKind::Lit(_)) => Some((lhs, Bound::Upper(rhs))),
+        (BinOpKind::Le, ExprKind::Lit(_), _) => Some((rhs, Bound::Lower(lhs))),
+        (BinOpKind::Ge, ExprKind::Lit(_), _) => Some((rhs, Bound::Upper(lhs))),
+        _ => None,
+    }
+}
@@ -48,6 +48,36 @@ declare_clippy_lint! {
     "suggests using `strip_{prefix,suffix}` over `str::{starts,ends}_with` and slicing"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for tests of both `str::starts_with` and `str::ends_with`, followed by slicing off
+    /// the prefix and the suffix using their lengths.
+    ///
+    /// ### Why is this bad?
+    /// Chaining `strip_prefix` and `strip_suffix` doesn't need to repeat the patterns and their
+    /// lengths. The slicing also panics if the prefix and the suffix overlap, e.g. when stripping
+    /// `"ab"` and `"ba"` from `"aba"`, in which case `strip_suffix` returns `None` instead.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let s = "(hello)";
+    /// if s.starts_with('(') && s.ends_with(')') {
+    ///     assert_eq!(&s[1..s.len() - 1], "hello");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let s = "(hello)";
+    /// if let Some(inner) = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+    ///     assert_eq!(inner, "hello");
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub MANUAL_STRIP_PREFIX_SUFFIX_CHAIN,
+    complexity,
+    "suggests chaining `strip_prefix` and `strip_suffix` over `str::starts_with`, `str::ends_with` and slicing"
+}
+
 pub struct ManualStrip {
     msrv: Msrv,
 }
@@ -60,7 +90,7 @@ impl ManualStrip {
     }
 }
 
-impl_lint_pass!(ManualStrip => [MANUAL_STRIP]);
+impl_lint_pass!(ManualStrip => [MANUAL_STRIP, MANUAL_STRIP_PREFIX_SUFFIX_CHAIN]);
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum StripKind {
@@ -70,32 +100,27 @@ enum StripKind {
 
 impl<'tcx> LateLintPass<'tcx> for ManualStrip {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        if let Some(higher::If { cond, then, .. }) = higher::If::hir(expr)
-            && let ExprKind::MethodCall(_, target_arg, [pattern], _) = cond.kind
-            && let ExprKind::Path(target_path) = &target_arg.kind
+        let Some(higher::If { cond, then, .. }) = higher::If::hir(expr) else {
+            return;
+        };
+        if let ExprKind::Binary(
+            Spanned {
+                node: BinOpKind::And, ..
+            },
+            left,
+            right,
+        ) = cond.kind
+        {
+            self.check_prefix_suffix_chain(cx, expr, left, right, then);
+        } else if let Some((strip_kind, target_arg, target_res, pattern)) = strip_test(cx, cond)
             && self.msrv.meets(msrvs::STR_STRIP_PREFIX)
-            && let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(cond.hir_id)
+            && !is_mutated(cx, target_res, then)
         {
-            let strip_kind = if cx.tcx.is_diagnostic_item(sym::str_starts_with, method_def_id) {
-                StripKind::Prefix
-            } else if cx.tcx.is_diagnostic_item(sym::str_ends_with, method_def_id) {
-                StripKind::Suffix
-            } else {
-                return;
-            };
-            let target_res = cx.qpath_res(target_path, target_arg.hir_id);
-            if target_res == Res::Err {
-                return;
+            let (prefix, suffix) = match strip_kind {
+                StripKind::Prefix => (Some(pattern), None),
+                StripKind::Suffix => (None, Some(pattern)),
             };
-
-            if let Res::Local(hir_id) = target_res
-                && let Some(used_mutably) = mutated_variables(then, cx)
-                && used_mutably.contains(&hir_id)
-            {
-                return;
-            }
-
-            let strippings = find_stripping(cx, strip_kind, target_res, pattern, then);
+            let strippings = find_stripping(cx, target_res, prefix, suffix, then);
             if !strippings.is_empty() {
                 let kind_word = match strip_kind {
                     StripKind::Prefix => "prefix",
@@ -133,6 +158,94 @@ impl<'tcx> LateLintPass<'tcx> for ManualStrip {
     extract_msrv_attr!(LateContext);
 }
 
+impl ManualStrip {
+    /// Checks `if s.starts_with(prefix) && s.ends_with(suffix)`, in either order.
+    fn check_prefix_suffix_chain<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        expr: &'tcx Expr<'_>,
+        left: &'tcx Expr<'_>,
+        right: &'tcx Expr<'_>,
+        then: &'tcx Expr<'_>,
+    ) {
+        if let Some((left_kind, target_arg, target_res, left_pattern)) = strip_test(cx, left)
+            && let Some((right_kind, _, right_res, right_pattern)) = strip_test(cx, right)
+            && left_kind != right_kind
+            && target_res == right_res
+            && self.msrv.meets(msrvs::STR_STRIP_PREFIX)
+            && !is_mutated(cx, target_res, then)
+        {
+            let (prefix, suffix) = match left_kind {
+                StripKind::Prefix => (left_pattern, right_pattern),
+                StripKind::Suffix => (right_pattern, left_pattern),
+            };
+            let strippings = find_stripping(cx, target_res, Some(prefix), Some(suffix), then);
+            if !strippings.is_empty() {
+                let test_span = expr.span.until(then.span);
+                span_lint_and_then(
+                    cx,
+                    MANUAL_STRIP_PREFIX_SUFFIX_CHAIN,
+                    strippings[0],
+                    "stripping a prefix and a suffix manually",
+                    |diag| {
+                        diag.span_note(test_span, "the prefix and the suffix were tested here");
+                        diag.multipart_suggestion(
+                            "try chaining the `strip_prefix` and `strip_suffix` methods",
+                            iter::once((
+                                test_span,
+                                format!(
+                                    "if let Some(<stripped>) = {}.strip_prefix({}).and_then(|s| s.strip_suffix({})) ",
+                                    snippet(cx, target_arg.span, ".."),
+                                    snippet(cx, prefix.span, ".."),
+                                    snippet(cx, suffix.span, ".."),
+                                ),
+                            ))
+                            .chain(strippings.into_iter().map(|span| (span, "<stripped>".into())))
+                            .collect(),
+                            Applicability::HasPlaceholders,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// If `cond` is `target.starts_with(pattern)` or `target.ends_with(pattern)`, where `target` is a
+/// path, returns the kind of the test, `target`, what it resolves to and `pattern`.
+fn strip_test<'tcx>(
+    cx: &LateContext<'tcx>,
+    cond: &'tcx Expr<'_>,
+) -> Option<(StripKind, &'tcx Expr<'tcx>, Res, &'tcx Expr<'tcx>)> {
+    if let ExprKind::MethodCall(_, target_arg, [pattern], _) = cond.kind
+        && let ExprKind::Path(target_path) = &target_arg.kind
+        && let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(cond.hir_id)
+    {
+        let strip_kind = if cx.tcx.is_diagnostic_item(sym::str_starts_with, method_def_id) {
+            StripKind::Prefix
+        } else if cx.tcx.is_diagnostic_item(sym::str_ends_with, method_def_id) {
+            StripKind::Suffix
+        } else {
+            return None;
+        };
+        let target_res = cx.qpath_res(target_path, target_arg.hir_id);
+        (target_res != Res::Err).then_some((strip_kind, target_arg, target_res, pattern))
+    } else {
+        None
+    }
+}
+
+/// Whether `target` is a local variable which is mutated in `then`.
+fn is_mutated<'tcx>(cx: &LateContext<'tcx>, target: Res, then: &'tcx Expr<'tcx>) -> bool {
+    if let Res::Local(hir_id) = target
+        && let Some(used_mutably) = mutated_variables(then, cx)
+    {
+        used_mutably.contains(&hir_id)
+    } else {
+        false
+    }
+}
+
 // Returns `Some(arg)` if `expr` matches `arg.len()` and `None` otherwise.
 fn len_arg<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
     if let ExprKind::MethodCall(_, arg, [], _) = expr.kind
@@ -145,6 +258,19 @@ fn len_arg<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<&'tcx E
     }
 }
 
+// Returns `Some(arg)` if `expr` matches `arg.len_utf8()`, the length in bytes of a `char`, and
+// `None` otherwise.
+fn len_utf8_arg<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::MethodCall(method, arg, [], _) = expr.kind
+        && method.ident.name.as_str() == "len_utf8"
+        && cx.typeck_results().expr_ty_adjusted(arg).peel_refs().is_char()
+    {
+        Some(arg)
+    } else {
+        None
+    }
+}
+
 // Returns the length of the `expr` if it's a constant string or char.
 fn constant_length(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<u128> {
     let value = ConstEvalCtxt::new(cx).eval(expr)?;
@@ -164,7 +290,9 @@ fn eq_pattern_length<'tcx>(cx: &LateContext<'tcx>, pattern: &Expr<'_>, expr: &'t
     {
         constant_length(cx, pattern).is_some_and(|length| *n == length)
     } else {
-        len_arg(cx, expr).is_some_and(|arg| eq_expr_value(cx, pattern, arg))
+        len_arg(cx, expr)
+            .or_else(|| len_utf8_arg(cx, expr))
+            .is_some_and(|arg| eq_expr_value(cx, pattern, arg))
     }
 }
 
@@ -185,24 +313,58 @@ fn peel_ref<'a>(expr: &'a Expr<'_>) -> &'a Expr<'a> {
     }
 }
 
-/// Find expressions where `target` is stripped using the length of `pattern`.
-/// We'll suggest replacing these expressions with the result of the `strip_{prefix,suffix}`
-/// method.
+/// Find expressions where `target` is stripped using the length of `prefix`, of `suffix`, or of
+/// both. We'll suggest replacing these expressions with the result of the `strip_{prefix,suffix}`
+/// methods.
 fn find_stripping<'tcx>(
     cx: &LateContext<'tcx>,
-    strip_kind: StripKind,
     target: Res,
-    pattern: &'tcx Expr<'_>,
+    prefix: Option<&'tcx Expr<'_>>,
+    suffix: Option<&'tcx Expr<'_>>,
     expr: &'tcx Expr<'_>,
 ) -> Vec<Span> {
     struct StrippingFinder<'a, 'tcx> {
         cx: &'a LateContext<'tcx>,
-        strip_kind: StripKind,
         target: Res,
-        pattern: &'tcx Expr<'tcx>,
+        prefix: Option<&'tcx Expr<'tcx>>,
+        suffix: Option<&'tcx Expr<'tcx>>,
         results: Vec<Span>,
     }
 
+    impl<'tcx> StrippingFinder<'_, 'tcx> {
+        /// Whether the range `start..end` starts after the prefix, if any, and ends before the
+        /// suffix, if any.
+        fn is_stripping_range(&self, start: Option<&'tcx Expr<'tcx>>, end: Option<&'tcx Expr<'tcx>>) -> bool {
+            let start_matches = match (self.prefix, start) {
+                (Some(prefix), Some(start)) => eq_pattern_length(self.cx, prefix, start),
+                (None, None) => true,
+                _ => false,
+            };
+            let end_matches = match (self.suffix, end) {
+                (Some(suffix), Some(end)) => {
+                    if let ExprKind::Binary(
+                        Spanned {
+                            node: BinOpKind::Sub, ..
+                        },
+                        left,
+                        right,
+                    ) = end.kind
+                        && let Some(left_arg) = len_arg(self.cx, left)
+                        && let ExprKind::Path(left_path) = &left_arg.kind
+                        && self.cx.qpath_res(left_path, left_arg.hir_id) == self.target
+                    {
+                        eq_pattern_length(self.cx, suffix, right)
+                    } else {
+                        false
+                    }
+                },
+                (None, None) => true,
+                _ => false,
+            };
+            start_matches && end_matches
+        }
+    }
+
     impl<'tcx> Visitor<'tcx> for StrippingFinder<'_, 'tcx> {
         fn visit_expr(&mut self, ex: &'tcx Expr<'_>) {
             if is_ref_str(self.cx, ex)
@@ -211,33 +373,10 @@ fn find_stripping<'tcx>(
                 && let Some(higher::Range { start, end, .. }) = higher::Range::hir(index)
                 && let ExprKind::Path(path) = &indexed.kind
                 && self.cx.qpath_res(path, ex.hir_id) == self.target
+                && self.is_stripping_range(start, end)
             {
-                match (self.strip_kind, start, end) {
-                    (StripKind::Prefix, Some(start), None) => {
-                        if eq_pattern_length(self.cx, self.pattern, start) {
-                            self.results.push(ex.span);
-                            return;
-                        }
-                    },
-                    (StripKind::Suffix, None, Some(end)) => {
-                        if let ExprKind::Binary(
-                            Spanned {
-                                node: BinOpKind::Sub, ..
-                            },
-                            left,
-                            right,
-                        ) = end.kind
-                            && let Some(left_arg) = len_arg(self.cx, left)
-                            && let ExprKind::Path(left_path) = &left_arg.kind
-                            && self.cx.qpath_res(left_path, left_arg.hir_id) == self.target
-                            && eq_pattern_length(self.cx, self.pattern, right)
-                        {
-                            self.results.push(ex.span);
-                            return;
-                        }
-                    },
-                    _ => {},
-                }
+                self.results.push(ex.span);
+                return;
             }
 
             walk_expr(self, ex);
@@ -246,9 +385,9 @@ fn find_stripping<'tcx>(
 
     let mut finder = StrippingFinder {
         cx,
-        strip_kind,
         target,
-        pattern,
+        prefix,
+        suffix,
         results: vec![],
     };
     walk_expr(&mut finder, expr);
@@ -1,7 +1,9 @@
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::source::{snippet, snippet_with_applicability, snippet_with_context};
+use clippy_utils::source::{
+    snippet, snippet_block_with_applicability, snippet_with_applicability, snippet_with_context,
+};
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::{iter_input_pats, method_chain_args};
+use clippy_utils::{contains_return, iter_input_pats, method_chain_args};
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass};
@@ -102,9 +104,8 @@ fn is_unit_function(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
     let ty = cx.typeck_results().expr_ty(expr);
 
     if let ty::FnDef(id, _) = *ty.kind() {
-        if let Some(fn_type) = cx.tcx.fn_sig(id).instantiate_identity().no_bound_vars() {
-            return is_unit_type(fn_type.output());
-        }
+        // Late bound lifetimes of the arguments, e.g. of `&mut self`, can't appear in `()`
+        return is_unit_type(cx.tcx.fn_sig(id).instantiate_identity().output().skip_binder());
     }
     false
 }
@@ -238,6 +239,26 @@ fn lint_map_unit_fn(
                     snippet_with_context(cx, reduced_expr_span, var_arg.span.ctxt(), "_", &mut applicability).0,
                 );
                 diag.span_suggestion(stmt.span, "try", suggestion, applicability);
+            } else if let hir::ExprKind::Block(block, _) = closure_expr.kind
+                && !contains_return(block)
+            {
+                // A block with several statements can be used as the body of the `if let` as is,
+                // unless a `return` in it would now return from the enclosing function.
+                let mut applicability = Applicability::MachineApplicable;
+                let suggestion = format!(
+                    "if let {0}({1}) = {2} {3}",
+                    variant,
+                    snippet_with_applicability(cx, binding.pat.span, "_", &mut applicability),
+                    snippet_with_applicability(cx, var_arg.span, "_", &mut applicability),
+                    snippet_block_with_applicability(
+                        cx,
+                        closure_expr.span,
+                        "{ .. }",
+                        Some(stmt.span),
+                        &mut applicability
+                    ),
+                );
+                diag.span_suggestion(stmt.span, "try", suggestion, applicability);
             } else {
                 let suggestion = format!(
                     "if let {0}({1}) = {2} {{ ... }}",
@@ -253,8 +274,22 @@ fn lint_map_unit_fn(
 
 impl LateLintPass<'_> for MapUnit {
     fn check_stmt(&mut self, cx: &LateContext<'_>, stmt: &hir::Stmt<'_>) {
-        if let hir::StmtKind::Semi(expr) = stmt.kind
-            && !stmt.span.from_expansion()
+        let expr = match stmt.kind {
+            hir::StmtKind::Semi(expr) => expr,
+            // `let _ = x.map(f);`
+            hir::StmtKind::Let(&hir::LetStmt {
+                pat: hir::Pat {
+                    kind: hir::PatKind::Wild,
+                    ..
+                },
+                ty: None,
+                init: Some(init),
+                els: None,
+                ..
+            }) => init,
+            _ => return,
+        };
+        if !stmt.span.from_expansion()
             && let Some(arglists) = method_chain_args(expr, &["map"])
         {
             lint_map_unit_fn(cx, stmt, expr, arglists[0]);
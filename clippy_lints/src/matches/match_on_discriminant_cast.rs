@@ -0,0 +1,114 @@
+use clippy_utils::consts::{ConstEvalCtxt, FullInt};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::rinterval::type_range;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::{EnumValue, get_discriminant_value};
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, Expr, ExprKind, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, AdtDef, Ty};
+use rustc_span::Span;
+use rustc_target::abi::VariantIdx;
+use std::slice;
+
+use super::MATCH_ON_DISCRIMINANT_CAST;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, scrutinee: &'tcx Expr<'_>, arms: &'tcx [Arm<'_>]) {
+    if let ExprKind::Cast(inner, _) = scrutinee.kind
+        && let ty::Adt(adt, _) = *cx.typeck_results().expr_ty(inner).kind()
+        && adt.is_enum()
+        && let Some(discriminants) = discriminants(cx, adt, cx.typeck_results().expr_ty(scrutinee))
+    {
+        let mut replacements = Vec::new();
+        // The variants matched by arms without a guard
+        let mut covered = Vec::new();
+        let mut wildcard = None;
+        for arm in arms {
+            let pats = match arm.pat.kind {
+                PatKind::Wild => {
+                    wildcard = Some(arm.pat);
+                    continue;
+                },
+                PatKind::Or(pats) => pats,
+                _ => slice::from_ref(arm.pat),
+            };
+            for pat in pats {
+                let Some((span, variant)) = variant_of_literal(cx, pat, &discriminants) else {
+                    return;
+                };
+                replacements.push((span, variant));
+                if arm.guard.is_none() {
+                    covered.push(variant);
+                }
+            }
+        }
+        if replacements.is_empty() {
+            return;
+        }
+
+        span_lint_and_then(
+            cx,
+            MATCH_ON_DISCRIMINANT_CAST,
+            scrutinee.span,
+            "matching on the discriminant of an enum instead of its variants",
+            |diag| {
+                let mut app = Applicability::MaybeIncorrect;
+                let (inner_snip, _) = snippet_with_context(cx, inner.span, scrutinee.span.ctxt(), "..", &mut app);
+                let enum_path = cx.tcx.def_path_str(adt.did());
+                let mut sugg = vec![(scrutinee.span, inner_snip.into_owned())];
+                sugg.extend(
+                    replacements
+                        .iter()
+                        .map(|&(span, variant)| (span, format!("{enum_path}::{}", adt.variant(variant).name))),
+                );
+                diag.multipart_suggestion("match on the enum directly", sugg, app);
+
+                covered.sort_unstable();
+                covered.dedup();
+                // Variants may be added to a `#[non_exhaustive]` enum of another crate
+                if let Some(wildcard) = wildcard
+                    && covered.len() == adt.variants().len()
+                    && (!adt.is_variant_list_non_exhaustive() || adt.did().is_local())
+                {
+                    diag.span_note(wildcard.span, "this wildcard pattern then becomes unreachable");
+                }
+            },
+        );
+    }
+}
+
+/// Returns the discriminant of every variant of the enum, if they are all preserved by the cast to
+/// `cast_ty`.
+fn discriminants(cx: &LateContext<'_>, adt: AdtDef<'_>, cast_ty: Ty<'_>) -> Option<Vec<i128>> {
+    let range = type_range(cx, cast_ty)?;
+    if adt.variants().is_empty() || adt.variants().iter().any(|variant| !variant.fields.is_empty()) {
+        return None;
+    }
+    adt.variants()
+        .indices()
+        .map(|i| {
+            let value = match get_discriminant_value(cx.tcx, adt, i) {
+                EnumValue::Signed(value) => value,
+                EnumValue::Unsigned(value) => i128::try_from(value).ok()?,
+            };
+            (range.lo..=range.hi).contains(&value).then_some(value)
+        })
+        .collect()
+}
+
+/// If the pattern is an integer literal which is the discriminant of a variant, returns its span
+/// and the variant.
+fn variant_of_literal(cx: &LateContext<'_>, pat: &Pat<'_>, discriminants: &[i128]) -> Option<(Span, VariantIdx)> {
+    if let PatKind::Lit(lit) = pat.kind
+        && !pat.span.from_expansion()
+    {
+        let value = match ConstEvalCtxt::new(cx).eval_full_int(lit)? {
+            FullInt::S(value) => value,
+            FullInt::U(value) => i128::try_from(value).ok()?,
+        };
+        let index = discriminants.iter().position(|&discr| discr == value)?;
+        Some((pat.span, index.into()))
+    } else {
+        None
+    }
+}
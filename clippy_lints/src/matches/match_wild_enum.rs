@@ -1,6 +1,7 @@
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::macros::{is_panic, root_macro_call};
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::{is_refutable, peel_hir_pat_refs, recurse_or_patterns};
+use clippy_utils::{is_refutable, peel_blocks_with_stmt, peel_hir_pat_refs, recurse_or_patterns};
 use rustc_errors::Applicability;
 use rustc_hir::def::{CtorKind, DefKind, Res};
 use rustc_hir::{Arm, Expr, PatKind, PathSegment, QPath, Ty, TyKind};
@@ -8,7 +9,7 @@ use rustc_lint::LateContext;
 use rustc_middle::ty::{self, VariantDef};
 use rustc_span::sym;
 
-use super::{MATCH_WILDCARD_FOR_SINGLE_VARIANTS, WILDCARD_ENUM_MATCH_ARM};
+use super::{MATCH_WILDCARD_FOR_SINGLE_VARIANTS, NON_EXHAUSTIVE_OMITTED_PATTERNS_HELPER, WILDCARD_ENUM_MATCH_ARM};
 
 #[expect(clippy::too_many_lines)]
 pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
@@ -23,6 +24,10 @@ pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
         _ => return,
     };
 
+    if adt_def.is_variant_list_non_exhaustive() && adt_def.did().as_local().is_none() {
+        check_panicking_wildcard(cx, arms);
+    }
+
     // First pass - check for violation, but don't do much book-keeping because this is hopefully
     // the uncommon case, and the book-keeping is slightly expensive.
     let mut wildcard_span = None;
@@ -173,6 +178,36 @@ pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
     };
 }
 
+/// Lints a wildcard arm which panics in a match on a `#[non_exhaustive]` enum from another crate.
+/// Any variant added to the enum in the future will reach the wildcard.
+fn check_panicking_wildcard(cx: &LateContext<'_>, arms: &[Arm<'_>]) {
+    if arms.len() < 2 {
+        return;
+    }
+    for arm in arms {
+        if arm.guard.is_none()
+            && matches!(
+                peel_hir_pat_refs(arm.pat).0.kind,
+                PatKind::Wild | PatKind::Binding(_, _, _, None)
+            )
+            && let Some(macro_call) = root_macro_call(peel_blocks_with_stmt(arm.body).span)
+            && (is_panic(cx, macro_call.def_id) || cx.tcx.item_name(macro_call.def_id) == sym::unreachable)
+        {
+            let name = cx.tcx.item_name(macro_call.def_id);
+            span_lint_and_then(
+                cx,
+                NON_EXHAUSTIVE_OMITTED_PATTERNS_HELPER,
+                arm.span,
+                format!("wildcard arm of a `#[non_exhaustive]` enum calls `{name}!`"),
+                |diag| {
+                    diag.note("variants added to the enum in a future version will panic at runtime");
+                    diag.help("handle unknown variants with a fallback or return an error instead");
+                },
+            );
+        }
+    }
+}
+
 enum CommonPrefixSearcher<'a> {
     None,
     Path(&'a [PathSegment<'a>]),
@@ -8,6 +8,7 @@ mod manual_utils;
 mod match_as_ref;
 mod match_bool;
 mod match_like_matches;
+mod match_on_discriminant_cast;
 mod match_on_vec_items;
 mod match_ref_pats;
 mod match_same_arms;
@@ -956,6 +957,7 @@ declare_clippy_lint! {
     /// match x {
     ///     Some(x) if matches!(x, Some(1)) => ..,
     ///     Some(x) if x == Some(2) => ..,
+    ///     Some(x) if x.is_none() && cond => ..,
     ///     _ => todo!(),
     /// }
     /// ```
@@ -964,6 +966,7 @@ declare_clippy_lint! {
     /// match x {
     ///     Some(Some(1)) => ..,
     ///     Some(Some(2)) => ..,
+    ///     Some(None) if cond => ..,
     ///     _ => todo!(),
     /// }
     /// ```
@@ -1007,6 +1010,98 @@ declare_clippy_lint! {
     "find manual implementations of `.ok()` or `.err()` on `Result`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard arms which call `unreachable!()` or `panic!()` in matches on
+    /// `#[non_exhaustive]` enums from other crates.
+    ///
+    /// ### Why is this bad?
+    /// The wildcard arm is required because the crate defining the enum may add variants in a
+    /// future version. When this happens the code compiles without a warning, but panics at
+    /// runtime once one of the new variants is matched.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// fn name(ordering: Ordering) -> &'static str {
+    ///     match ordering {
+    ///         Ordering::Relaxed => "relaxed",
+    ///         Ordering::Release => "release",
+    ///         Ordering::Acquire => "acquire",
+    ///         Ordering::AcqRel => "acq_rel",
+    ///         Ordering::SeqCst => "seq_cst",
+    ///         _ => unreachable!(),
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// fn name(ordering: Ordering) -> &'static str {
+    ///     match ordering {
+    ///         Ordering::Relaxed => "relaxed",
+    ///         Ordering::Release => "release",
+    ///         Ordering::Acquire => "acquire",
+    ///         Ordering::AcqRel => "acq_rel",
+    ///         Ordering::SeqCst => "seq_cst",
+    ///         _ => "unknown",
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub NON_EXHAUSTIVE_OMITTED_PATTERNS_HELPER,
+    pedantic,
+    "a wildcard arm which panics in a match on a `#[non_exhaustive]` enum from another crate"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match` expressions on an enum cast to an integer, e.g. `match kind as u8`, whose
+    /// arms are the discriminants of its variants.
+    ///
+    /// ### Why is this bad?
+    /// The arms have to be kept in sync with the discriminants by hand, and the compiler can't
+    /// check that every variant is handled. Matching on the variants is clearer and exhaustive.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[derive(Clone, Copy)]
+    /// enum Kind {
+    ///     File,
+    ///     Dir,
+    /// }
+    ///
+    /// fn describe(kind: Kind) -> &'static str {
+    ///     match kind as u8 {
+    ///         0 => "file",
+    ///         1 => "directory",
+    ///         _ => unreachable!(),
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[derive(Clone, Copy)]
+    /// enum Kind {
+    ///     File,
+    ///     Dir,
+    /// }
+    ///
+    /// fn describe(kind: Kind) -> &'static str {
+    ///     match kind {
+    ///         Kind::File => "file",
+    ///         Kind::Dir => "directory",
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub MATCH_ON_DISCRIMINANT_CAST,
+    pedantic,
+    "matching on the discriminant of an enum cast to an integer instead of on its variants"
+}
+
 pub struct Matches {
     msrv: Msrv,
     infallible_destructuring_match_linted: bool,
@@ -1049,6 +1144,8 @@ impl_lint_pass!(Matches => [
     MANUAL_FILTER,
     REDUNDANT_GUARDS,
     MANUAL_OK_ERR,
+    NON_EXHAUSTIVE_OMITTED_PATTERNS_HELPER,
+    MATCH_ON_DISCRIMINANT_CAST,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Matches {
@@ -1120,6 +1217,7 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
                     match_as_ref::check(cx, ex, arms, expr);
                     needless_match::check_match(cx, ex, arms, expr);
                     match_on_vec_items::check(cx, ex);
+                    match_on_discriminant_cast::check(cx, ex, arms);
                     match_str_case_mismatch::check(cx, ex, arms);
                     redundant_guards::check(cx, arms, &self.msrv);
 
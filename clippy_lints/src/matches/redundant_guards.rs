@@ -2,6 +2,7 @@ use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::macros::matching_root_macro_call;
 use clippy_utils::msrvs::Msrv;
 use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::visitors::{for_each_expr_without_closures, is_local_used};
 use clippy_utils::{is_in_const_context, path_to_local};
 use rustc_ast::{BorrowKind, LitKind};
@@ -22,97 +23,143 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'tcx>], msrv:
             continue;
         };
 
-        // `Some(x) if matches!(x, y)`
-        if let ExprKind::Match(scrutinee, [arm, _], MatchSource::Normal) = guard.kind
-            && matching_root_macro_call(cx, guard.span, sym::matches_macro).is_some()
-            && let Some(binding) = get_pat_binding(cx, scrutinee, outer_arm)
-            && !pat_contains_disallowed_or(arm.pat, msrv)
+        // `Some(x) if x == Some(2) && cond`
+        // `Some(x) if cond && x == Some(2)`
+        if let ExprKind::Binary(bin_op, lhs, rhs) = guard.kind
+            && bin_op.node == BinOpKind::And
+            && !guard.span.from_expansion()
         {
-            let pat_span = match (arm.pat.kind, binding.byref_ident) {
-                (PatKind::Ref(pat, _), Some(_)) => pat.span,
-                (PatKind::Ref(..), None) | (_, Some(_)) => continue,
-                _ => arm.pat.span,
-            };
-            emit_redundant_guards(
-                cx,
-                outer_arm,
-                guard.span,
-                snippet(cx, pat_span, "<binding>"),
-                &binding,
-                arm.guard,
-            );
-        }
-        // `Some(x) if let Some(2) = x`
-        else if let ExprKind::Let(let_expr) = guard.kind
-            && let Some(binding) = get_pat_binding(cx, let_expr.init, outer_arm)
-            && !pat_contains_disallowed_or(let_expr.pat, msrv)
-        {
-            let pat_span = match (let_expr.pat.kind, binding.byref_ident) {
-                (PatKind::Ref(pat, _), Some(_)) => pat.span,
-                (PatKind::Ref(..), None) | (_, Some(_)) => continue,
-                _ => let_expr.pat.span,
-            };
-            emit_redundant_guards(
-                cx,
-                outer_arm,
-                let_expr.span,
-                snippet(cx, pat_span, "<binding>"),
-                &binding,
-                None,
-            );
-        }
-        // `Some(x) if x == Some(2)`
-        // `Some(x) if Some(2) == x`
-        else if let ExprKind::Binary(bin_op, local, pat) = guard.kind
-            && matches!(bin_op.node, BinOpKind::Eq)
-            // Ensure they have the same type. If they don't, we'd need deref coercion which isn't
-            // possible (currently) in a pattern. In some cases, you can use something like
-            // `as_deref` or similar but in general, we shouldn't lint this as it'd create an
-            // extraordinary amount of FPs.
-            //
-            // This isn't necessary in the other two checks, as they must be a pattern already.
-            && cx.typeck_results().expr_ty(local) == cx.typeck_results().expr_ty(pat)
-            // Since we want to lint on both `x == Some(2)` and `Some(2) == x`, we might have to "swap"
-            // `local` and `pat`, depending on which side they are.
-            && let Some((binding, pat)) = get_pat_binding(cx, local, outer_arm)
-                .map(|binding| (binding, pat))
-                .or_else(|| get_pat_binding(cx, pat, outer_arm).map(|binding| (binding, local)))
-            && expr_can_be_pat(cx, pat)
-        {
-            let pat_span = match (pat.kind, binding.byref_ident) {
-                (ExprKind::AddrOf(BorrowKind::Ref, _, expr), Some(_)) => expr.span,
-                (ExprKind::AddrOf(..), None) | (_, Some(_)) => continue,
-                _ => pat.span,
-            };
-            emit_redundant_guards(
-                cx,
-                outer_arm,
-                guard.span,
-                snippet(cx, pat_span, "<binding>"),
-                &binding,
-                None,
-            );
-        } else if let ExprKind::MethodCall(path, recv, args, ..) = guard.kind
-            && let Some(binding) = get_pat_binding(cx, recv, outer_arm)
-        {
-            check_method_calls(cx, outer_arm, path.ident.name.as_str(), recv, args, guard, &binding);
+            // Moving `rhs` into the pattern would make `lhs` only be evaluated once the pattern matched
+            if !check_guard(cx, outer_arm, lhs, Some(rhs), msrv) && !lhs.can_have_side_effects() {
+                check_guard(cx, outer_arm, rhs, Some(lhs), msrv);
+            }
+        } else {
+            check_guard(cx, outer_arm, guard, None, msrv);
         }
     }
 }
 
-fn check_method_calls<'tcx>(
+/// Checks if `guard` can be moved into the pattern of `outer_arm`. `rest` is the remaining part of
+/// the guard if `guard` is one of the operands of `&&`, it is kept as the guard of the arm.
+///
+/// Returns `true` if a lint was emitted.
+fn check_guard<'tcx>(
     cx: &LateContext<'tcx>,
-    arm: &Arm<'tcx>,
+    outer_arm: &Arm<'tcx>,
+    guard: &'tcx Expr<'tcx>,
+    rest: Option<&'tcx Expr<'tcx>>,
+    msrv: &Msrv,
+) -> bool {
+    // `Some(x) if matches!(x, y)`
+    if let ExprKind::Match(scrutinee, [arm, _], MatchSource::Normal) = guard.kind
+        && matching_root_macro_call(cx, guard.span, sym::matches_macro).is_some()
+        && let Some(binding) = get_pat_binding(cx, scrutinee, outer_arm, rest)
+        && !pat_contains_disallowed_or(arm.pat, msrv)
+    {
+        let pat_span = match (arm.pat.kind, binding.byref_ident) {
+            (PatKind::Ref(pat, _), Some(_)) => pat.span,
+            (PatKind::Ref(..), None) | (_, Some(_)) => return false,
+            _ => arm.pat.span,
+        };
+        // Both the inner guard and the rest of the outer guard would have to be kept
+        if arm.guard.is_some() && rest.is_some() {
+            return false;
+        }
+        emit_redundant_guards(
+            cx,
+            outer_arm,
+            guard.span,
+            snippet(cx, pat_span, "<binding>"),
+            &binding,
+            arm.guard.or(rest),
+        );
+    }
+    // `Some(x) if let Some(2) = x`
+    else if let ExprKind::Let(let_expr) = guard.kind
+        && let Some(binding) = get_pat_binding(cx, let_expr.init, outer_arm, rest)
+        && !pat_contains_disallowed_or(let_expr.pat, msrv)
+    {
+        let pat_span = match (let_expr.pat.kind, binding.byref_ident) {
+            (PatKind::Ref(pat, _), Some(_)) => pat.span,
+            (PatKind::Ref(..), None) | (_, Some(_)) => return false,
+            _ => let_expr.pat.span,
+        };
+        emit_redundant_guards(
+            cx,
+            outer_arm,
+            let_expr.span,
+            snippet(cx, pat_span, "<binding>"),
+            &binding,
+            rest,
+        );
+    }
+    // `Some(x) if x == Some(2)`
+    // `Some(x) if Some(2) == x`
+    else if let ExprKind::Binary(bin_op, local, pat) = guard.kind
+        && matches!(bin_op.node, BinOpKind::Eq)
+        // Ensure they have the same type. If they don't, we'd need deref coercion which isn't
+        // possible (currently) in a pattern. In some cases, you can use something like
+        // `as_deref` or similar but in general, we shouldn't lint this as it'd create an
+        // extraordinary amount of FPs.
+        //
+        // This isn't necessary in the other two checks, as they must be a pattern already.
+        && cx.typeck_results().expr_ty(local) == cx.typeck_results().expr_ty(pat)
+        // Since we want to lint on both `x == Some(2)` and `Some(2) == x`, we might have to "swap"
+        // `local` and `pat`, depending on which side they are.
+        && let Some((binding, pat)) = get_pat_binding(cx, local, outer_arm, rest)
+            .map(|binding| (binding, pat))
+            .or_else(|| get_pat_binding(cx, pat, outer_arm, rest).map(|binding| (binding, local)))
+        && expr_can_be_pat(cx, pat)
+    {
+        let pat_span = match (pat.kind, binding.byref_ident) {
+            (ExprKind::AddrOf(BorrowKind::Ref, _, expr), Some(_)) => expr.span,
+            (ExprKind::AddrOf(..), None) | (_, Some(_)) => return false,
+            _ => pat.span,
+        };
+        emit_redundant_guards(
+            cx,
+            outer_arm,
+            guard.span,
+            snippet(cx, pat_span, "<binding>"),
+            &binding,
+            rest,
+        );
+    } else if let ExprKind::MethodCall(path, recv, args, ..) = guard.kind
+        && let Some(binding) = get_pat_binding(cx, recv, outer_arm, rest)
+        && let Some(sugg) = method_call_pat(cx, path.ident.name.as_str(), recv, args)
+    {
+        emit_redundant_guards(cx, outer_arm, guard.span, sugg, &binding, rest);
+    } else {
+        return false;
+    }
+
+    true
+}
+
+/// Returns the pattern which is equivalent to calling `method` on the binding in the guard.
+fn method_call_pat(
+    cx: &LateContext<'_>,
     method: &str,
     recv: &Expr<'_>,
     args: &[Expr<'_>],
-    if_expr: &Expr<'_>,
-    binding: &PatBindingInfo,
-) {
+) -> Option<Cow<'static, str>> {
     let ty = cx.typeck_results().expr_ty(recv).peel_refs();
     let slice_like = ty.is_slice() || ty.is_array();
 
-    let sugg = if method == "is_empty" {
+    let sugg = if (is_type_diagnostic_item(cx, ty, sym::Option) || is_type_diagnostic_item(cx, ty, sym::Result))
+        && let Some(variant) = match method {
+            // `x if x.is_some()` becomes Some(_)
+            // `x if x.is_none()` becomes None
+            "is_some" => Some("Some(_)"),
+            "is_none" => Some("None"),
+            // `x if x.is_ok()` becomes Ok(_)
+            // `x if x.is_err()` becomes Err(_)
+            "is_ok" => Some("Ok(_)"),
+            "is_err" => Some("Err(_)"),
+            _ => None,
+        } {
+        variant.into()
+    } else if method == "is_empty" {
         // `s if s.is_empty()` becomes ""
         // `arr if arr.is_empty()` becomes []
 
@@ -121,7 +168,7 @@ fn check_method_calls<'tcx>(
         } else if slice_like {
             "[]".into()
         } else {
-            return;
+            return None;
         }
     } else if slice_like
         && let Some(needle) = args.first()
@@ -142,15 +189,15 @@ fn check_method_calls<'tcx>(
         } else if method == "ends_with" {
             sugg.insert_str(1, ".., ");
         } else {
-            return;
+            return None;
         }
 
         sugg.into()
     } else {
-        return;
+        return None;
     };
 
-    emit_redundant_guards(cx, arm, if_expr.span, sugg, binding, None);
+    Some(sugg)
 }
 
 struct PatBindingInfo {
@@ -163,9 +210,11 @@ fn get_pat_binding<'tcx>(
     cx: &LateContext<'tcx>,
     guard_expr: &Expr<'_>,
     outer_arm: &Arm<'tcx>,
+    rest: Option<&'tcx Expr<'tcx>>,
 ) -> Option<PatBindingInfo> {
     if let Some(local) = path_to_local(guard_expr)
         && !is_local_used(cx, outer_arm.body, local)
+        && rest.is_none_or(|rest| !is_local_used(cx, rest, local))
     {
         let mut span = None;
         let mut byref_ident = None;
@@ -230,7 +279,11 @@ fn emit_redundant_guards<'tcx>(
                 vec![
                     suggestion_span,
                     (
-                        guard_span.source_callsite().with_lo(outer_arm.pat.span.hi()),
+                        outer_arm
+                            .guard
+                            .map_or(guard_span, |guard| guard.span)
+                            .source_callsite()
+                            .with_lo(outer_arm.pat.span.hi()),
                         inner_guard.map_or_else(String::new, |guard| {
                             format!(" if {}", snippet(cx, guard.span, "<guard>"))
                         }),
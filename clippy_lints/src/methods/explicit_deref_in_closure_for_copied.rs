@@ -0,0 +1,192 @@
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_copy;
+use clippy_utils::{is_lint_allowed, is_res_lang_ctor, is_trait_method, path_res, path_to_local_id, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::OptionSome;
+use rustc_hir::{BindingMode, Closure, Expr, ExprKind, HirId, Mutability, Pat, PatKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_span::sym;
+
+use super::{EXPLICIT_DEREF_IN_CLOSURE_FOR_COPIED, method_call};
+
+/// Checks `iter.map(..)`, `iter.filter_map(..)` and `iter.flat_map(..)` with a closure which only
+/// copies or clones the elements. Returns whether the lint was emitted, in which case the other
+/// lints on these closures aren't. The lint isn't emitted if it's allowed, so that they still are.
+pub(super) fn check_map(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    recv: &Expr<'_>,
+    arg: &Expr<'_>,
+    name: &str,
+    msrv: &Msrv,
+) -> bool {
+    if is_trait_method(cx, expr, sym::Iterator)
+        && !is_lint_allowed(cx, EXPLICIT_DEREF_IN_CLOSURE_FOR_COPIED, expr.hir_id)
+        && let ExprKind::Closure(&Closure { body, .. }) = arg.kind
+        && let [param] = cx.tcx.hir().body(body).params
+        && let value = peel_blocks(cx.tcx.hir().body(body).value)
+        && let Some(value) = if name == "map" {
+            // `map(|x| *x)` and `map(|&x| x)` are linted by `map_clone`
+            is_tuple_pat(param.pat).then_some(value)
+        } else {
+            some_arg(cx, value)
+        }
+        && let ty::Ref(_, elem_ty, Mutability::Not) = param_ty(cx, param.pat).kind()
+        && cx.typeck_results().expr_ty(value) == *elem_ty
+        && copies_elements(cx, param.pat, value)
+    {
+        let mut applicability = Applicability::MachineApplicable;
+        let (message, method) = if is_copy(cx, *elem_ty) && msrv.meets(msrvs::ITERATOR_COPIED) {
+            (format!("this `{name}` closure only copies the elements"), "copied")
+        } else {
+            (format!("this `{name}` closure only clones the elements"), "cloned")
+        };
+        span_lint_and_sugg(
+            cx,
+            EXPLICIT_DEREF_IN_CLOSURE_FOR_COPIED,
+            expr.span,
+            message,
+            format!("consider calling the dedicated `{method}` method"),
+            format!(
+                "{}.{method}()",
+                snippet_with_applicability(cx, recv.span, "..", &mut applicability)
+            ),
+            applicability,
+        );
+        true
+    } else {
+        false
+    }
+}
+
+/// Checks `iter.filter(|&&x| ..).copied()`, where the elements can be copied before filtering
+/// them.
+pub(super) fn check_filter(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, name: &str) {
+    if is_trait_method(cx, expr, sym::Iterator)
+        && is_trait_method(cx, recv, sym::Iterator)
+        && let Some(("filter", _, [arg], filter_span, _)) = method_call(recv)
+        && let ExprKind::Closure(&Closure { body, .. }) = arg.kind
+        && let [param] = cx.tcx.hir().body(body).params
+        && let PatKind::Ref(inner, Mutability::Not) = param.pat.kind
+        && let PatKind::Ref(binding, Mutability::Not) = inner.kind
+        && let PatKind::Binding(BindingMode::NONE, .., None) = binding.kind
+        && !param.pat.span.from_expansion()
+    {
+        span_lint_and_then(
+            cx,
+            EXPLICIT_DEREF_IN_CLOSURE_FOR_COPIED,
+            filter_span.with_hi(expr.span.hi()),
+            format!("dereferencing the elements twice in the `filter` closure before calling `{name}`"),
+            |diag| {
+                let mut applicability = Applicability::MachineApplicable;
+                let inner = snippet_with_applicability(cx, inner.span, "..", &mut applicability);
+                diag.multipart_suggestion(
+                    format!("call `{name}` before `filter`"),
+                    vec![
+                        (filter_span.shrink_to_lo(), format!("{name}().")),
+                        (param.pat.span, inner.into_owned()),
+                        (expr.span.with_lo(recv.span.hi()), String::new()),
+                    ],
+                    applicability,
+                );
+            },
+        );
+    }
+}
+
+/// Returns the type of the closure parameter matched by `pat`, before the reference is implicitly
+/// dereferenced by a tuple pattern.
+fn param_ty<'tcx>(cx: &LateContext<'tcx>, pat: &Pat<'_>) -> Ty<'tcx> {
+    let typeck = cx.typeck_results();
+    typeck
+        .pat_adjustments()
+        .get(pat.hir_id)
+        .and_then(|adjustments| adjustments.first().copied())
+        .unwrap_or_else(|| typeck.pat_ty(pat))
+}
+
+fn is_tuple_pat(pat: &Pat<'_>) -> bool {
+    match pat.kind {
+        PatKind::Tuple(..) => true,
+        PatKind::Ref(inner, _) => matches!(inner.kind, PatKind::Tuple(..)),
+        _ => false,
+    }
+}
+
+/// If `expr` is `Some(arg)`, returns `arg`.
+fn some_arg<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::Call(callee, [arg]) = expr.kind
+        && is_res_lang_ctor(cx, path_res(cx, callee), OptionSome)
+    {
+        Some(arg)
+    } else {
+        None
+    }
+}
+
+/// Whether `value` is a copy of the reference matched by `pat`, e.g. `|x| *x`, `|&x| x`,
+/// `|(a, b)| (*a, b.clone())` or `|&(a, b)| (a, b)`.
+fn copies_elements(cx: &LateContext<'_>, pat: &Pat<'_>, value: &Expr<'_>) -> bool {
+    match pat.kind {
+        PatKind::Ref(inner, Mutability::Not) => moves_bindings(inner, value),
+        PatKind::Binding(BindingMode::NONE, id, _, None) => derefs_binding(cx, id, value),
+        PatKind::Tuple(pats, dotdot) if dotdot.as_opt_usize().is_none() => {
+            if let ExprKind::Tup(exprs) = value.kind
+                && pats.len() == exprs.len()
+            {
+                pats.iter()
+                    .zip(exprs)
+                    .all(|(pat, value)| copies_elements(cx, pat, value))
+            } else {
+                false
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Whether `value` rebuilds the value matched by `pat` from its bindings.
+fn moves_bindings(pat: &Pat<'_>, value: &Expr<'_>) -> bool {
+    match pat.kind {
+        PatKind::Binding(BindingMode::NONE, id, _, None) => path_to_local_id(value, id),
+        PatKind::Tuple(pats, dotdot) if dotdot.as_opt_usize().is_none() => {
+            if let ExprKind::Tup(exprs) = value.kind
+                && pats.len() == exprs.len()
+            {
+                pats.iter().zip(exprs).all(|(pat, value)| moves_bindings(pat, value))
+            } else {
+                false
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Whether `value` is `*x` or `x.clone()`, where `x` is the binding `id` of type `&T`.
+fn derefs_binding(cx: &LateContext<'_>, id: HirId, value: &Expr<'_>) -> bool {
+    let binding = match value.kind {
+        ExprKind::Unary(UnOp::Deref, binding) => binding,
+        ExprKind::MethodCall(method, binding, [], _)
+            if method.ident.name == sym::clone
+                && let Some(fn_id) = cx.typeck_results().type_dependent_def_id(value.hir_id)
+                && cx.tcx.trait_of_item(fn_id) == cx.tcx.lang_items().clone_trait()
+                && !cx
+                    .typeck_results()
+                    .expr_adjustments(binding)
+                    .iter()
+                    .any(|adjustment| matches!(adjustment.kind, Adjust::Deref(Some(..)))) =>
+        {
+            binding
+        },
+        _ => return false,
+    };
+    path_to_local_id(binding, id)
+        && matches!(
+            cx.typeck_results().expr_ty(binding).kind(),
+            ty::Ref(_, _, Mutability::Not)
+        )
+}
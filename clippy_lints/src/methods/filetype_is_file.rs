@@ -7,6 +7,8 @@ use rustc_span::{Span, sym};
 
 use super::FILETYPE_IS_FILE;
 
+pub(super) const TARGETS: &[&str] = &["is_file"];
+
 pub(super) fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>) {
     let ty = cx.typeck_results().expr_ty(recv);
 
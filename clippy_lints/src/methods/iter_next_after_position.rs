@@ -0,0 +1,135 @@
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::local_used_once;
+use clippy_utils::{
+    eq_expr_value, find_binding_init, get_enclosing_block, is_trait_method, path_to_local, path_to_local_id,
+    peel_blocks,
+};
+use rustc_errors::Applicability;
+use rustc_hir::{
+    BindingMode, Closure, Expr, ExprKind, HirId, LangItem, MatchSource, Mutability, PatKind, QPath, TyKind,
+};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::{Span, sym};
+
+use super::ITER_NEXT_AFTER_POSITION;
+
+/// Checks for `v.iter().position(p).map(|i| &v[i])`.
+pub(super) fn check_map(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    position: &Expr<'_>,
+    position_recv: &Expr<'_>,
+    position_arg: &Expr<'_>,
+    position_span: Span,
+    map_arg: &Expr<'_>,
+) {
+    if is_trait_method(cx, position, sym::Iterator)
+        && let Some(collection) = iterated_collection(cx, position_recv)
+        && let ExprKind::Closure(&Closure { body, .. }) = map_arg.kind
+        && let body = cx.tcx.hir().body(body)
+        && let [param] = body.params
+        && let PatKind::Binding(_, idx_id, ..) = param.pat.kind
+        && let Some(base) = indexed_by(peel_blocks(body.value), idx_id)
+        && eq_expr_value(cx, base, collection)
+    {
+        let mut applicability = Applicability::MachineApplicable;
+        let predicate = find_predicate(cx, position_arg, &mut applicability);
+        span_lint_and_sugg(
+            cx,
+            ITER_NEXT_AFTER_POSITION,
+            expr.span.with_lo(position_span.lo()),
+            "using the index from `position` only to get the element",
+            "use `find` instead",
+            format!("find({predicate})"),
+            applicability,
+        );
+    }
+}
+
+/// Returns the predicate of `position` adapted to `find`, which passes the items by reference. A
+/// closure's parameter pattern is prefixed with `&`, other predicates are kept as they are and
+/// the applicability is lowered.
+fn find_predicate(cx: &LateContext<'_>, predicate: &Expr<'_>, applicability: &mut Applicability) -> String {
+    if let ExprKind::Closure(&Closure { body, fn_decl, .. }) = predicate.kind
+        && let [param] = cx.tcx.hir().body(body).params
+        && let [param_ty] = fn_decl.inputs
+        && let TyKind::Infer = param_ty.kind
+        && !predicate.span.from_expansion()
+    {
+        let before = snippet_with_applicability(cx, predicate.span.until(param.pat.span), "..", applicability);
+        let pat = snippet_with_applicability(cx, param.pat.span, "..", applicability);
+        let after = snippet_with_applicability(
+            cx,
+            param.pat.span.between(predicate.span.shrink_to_hi()),
+            "..",
+            applicability,
+        );
+        if let PatKind::Binding(BindingMode(_, Mutability::Mut), ..) = param.pat.kind {
+            format!("{before}&({pat}){after}")
+        } else {
+            format!("{before}&{pat}{after}")
+        }
+    } else {
+        *applicability = Applicability::MaybeIncorrect;
+        snippet_with_applicability(cx, predicate.span, "..", applicability).into_owned()
+    }
+}
+
+/// Checks for `&v[idx]` where `idx` comes from `let idx = v.iter().position(p)?;` and is not used
+/// anywhere else.
+pub(super) fn check_index(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    if let ExprKind::AddrOf(_, _, inner) = expr.kind
+        && let ExprKind::Index(base, idx, _) = inner.kind
+        && let Some(idx_id) = path_to_local(idx)
+        && let Some(init) = find_binding_init(cx, idx_id)
+        && let ExprKind::Match(scrutinee, _, MatchSource::TryDesugar(_)) = init.kind
+        && let ExprKind::Call(branch, [position]) = scrutinee.kind
+        && let ExprKind::Path(QPath::LangItem(LangItem::TryTraitBranch, ..)) = branch.kind
+        && let ExprKind::MethodCall(method, position_recv, [_], _) = position.kind
+        && method.ident.name.as_str() == "position"
+        && is_trait_method(cx, position, sym::Iterator)
+        && let Some(collection) = iterated_collection(cx, position_recv)
+        && eq_expr_value(cx, base, collection)
+        && let Some(block) = get_enclosing_block(cx, idx_id)
+        && local_used_once(cx, block, idx_id).is_some_and(|e| e.hir_id == idx.hir_id)
+    {
+        span_lint_and_then(
+            cx,
+            ITER_NEXT_AFTER_POSITION,
+            expr.span,
+            "using the index from `position` only to get the element",
+            |diag| {
+                diag.span_note(position.span, "the index is computed here");
+                diag.help("use `find` to get the element directly");
+            },
+        );
+    }
+}
+
+/// Returns `v` if `recv` is `v.iter()` on a slice, array or `Vec`.
+fn iterated_collection<'a>(cx: &LateContext<'_>, recv: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
+    if let ExprKind::MethodCall(method, collection, [], _) = recv.kind
+        && method.ident.name == sym::iter
+        && let ty = cx.typeck_results().expr_ty(collection).peel_refs()
+        && (matches!(ty.kind(), ty::Slice(_) | ty::Array(..)) || is_type_diagnostic_item(cx, ty, sym::Vec))
+    {
+        Some(collection)
+    } else {
+        None
+    }
+}
+
+/// Returns `v` if `expr` is `&v[idx]`.
+fn indexed_by<'a>(expr: &'a Expr<'a>, idx_id: HirId) -> Option<&'a Expr<'a>> {
+    if let ExprKind::AddrOf(_, _, inner) = expr.kind
+        && let ExprKind::Index(base, idx, _) = inner.kind
+        && path_to_local_id(idx, idx_id)
+    {
+        Some(base)
+    } else {
+        None
+    }
+}
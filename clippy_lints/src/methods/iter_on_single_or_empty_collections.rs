@@ -13,6 +13,8 @@ use rustc_lint::LateContext;
 
 use super::{ITER_ON_EMPTY_COLLECTIONS, ITER_ON_SINGLE_ITEMS};
 
+pub(super) const TARGETS: &[&str] = &["iter", "iter_mut", "into_iter"];
+
 enum IterType {
     Iter,
     IterMut,
@@ -0,0 +1,64 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_errors::Applicability;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::{ITERATOR_COLLECT_INTO_UNIT, method_call};
+
+/// Checks `iter.collect::<()>()` and `iter.collect::<Result<(), _>>()`. The latter is left to
+/// `map_collect_result_unit` when `iter` is a call to `map`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, recv: &'tcx Expr<'tcx>, allow_result: bool) {
+    if expr.span.from_expansion() {
+        return;
+    }
+    let collect_ty = cx.typeck_results().expr_ty(expr);
+    let mut applicability = Applicability::MachineApplicable;
+
+    if collect_ty.is_unit() {
+        let sugg = if let Some(("map", iter, [map_fn], _, _)) = method_call(recv) {
+            format!(
+                "{}.for_each({})",
+                snippet_with_applicability(cx, iter.span, "..", &mut applicability),
+                snippet_with_applicability(cx, map_fn.span, "..", &mut applicability),
+            )
+        } else {
+            // `drop` may not resolve to `std::mem::drop` where the suggestion is applied
+            applicability = Applicability::MaybeIncorrect;
+            format!(
+                "{}.for_each(drop)",
+                snippet_with_applicability(cx, recv.span, "..", &mut applicability)
+            )
+        };
+        span_lint_and_sugg(
+            cx,
+            ITERATOR_COLLECT_INTO_UNIT,
+            expr.span,
+            "collecting into `()` only consumes the iterator",
+            "use `for_each` instead",
+            sugg,
+            applicability,
+        );
+    } else if !allow_result
+        && is_type_diagnostic_item(cx, collect_ty, sym::Result)
+        && let ty::Adt(_, args) = collect_ty.kind()
+        && args.type_at(0).is_unit()
+        && !matches!(method_call(recv), Some(("map", ..)))
+    {
+        span_lint_and_sugg(
+            cx,
+            ITERATOR_COLLECT_INTO_UNIT,
+            expr.span,
+            "collecting into `Result<(), _>` only consumes the iterator",
+            "use `try_for_each` instead",
+            format!(
+                "{}.try_for_each(std::convert::identity)",
+                snippet_with_applicability(cx, recv.span, "..", &mut applicability)
+            ),
+            applicability,
+        );
+    }
+}
@@ -11,6 +11,8 @@ use rustc_span::{Span, Symbol, sym};
 
 use super::MANUAL_C_STR_LITERALS;
 
+pub(super) const TARGETS: &[&str] = &["as_ptr"];
+
 /// Checks:
 /// - `b"...".as_ptr()`
 /// - `b"...".as_ptr().cast()`
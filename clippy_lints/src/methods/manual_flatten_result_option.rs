@@ -0,0 +1,109 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::{get_iterator_item_ty, implements_trait, is_type_diagnostic_item};
+use clippy_utils::{is_diag_item_method, is_trait_method, path_to_local_id, return_ty};
+use rustc_errors::Applicability;
+use rustc_hir::def::DefKind;
+use rustc_hir::{Body, Closure, Expr, ExprKind, OwnerId};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::sym;
+
+use super::MANUAL_FLATTEN_RESULT_OPTION;
+
+/// Checks for `iter.collect::<Result<_, _>>().ok().unwrap()` in a function returning a `Result`.
+/// `ok_recv` is the receiver of `ok()`.
+pub(super) fn check_unwrap(cx: &LateContext<'_>, expr: &Expr<'_>, ok_recv: &Expr<'_>) {
+    if let ExprKind::MethodCall(method, _, [], _) = ok_recv.kind
+        && method.ident.as_str() == "collect"
+        && is_trait_method(cx, ok_recv, sym::Iterator)
+        && let Some(err_ty) = result_err_ty(cx, cx.typeck_results().expr_ty(ok_recv))
+        && let Some(ret_err_ty) = enclosing_fn_err_ty(cx)
+        && let Some(from) = cx.tcx.get_diagnostic_item(sym::From)
+        && implements_trait(cx, ret_err_ty, from, &[err_ty.into()])
+    {
+        let mut applicability = Applicability::MaybeIncorrect;
+        let collect = snippet_with_applicability(cx, ok_recv.span, "..", &mut applicability);
+        span_lint_and_sugg(
+            cx,
+            MANUAL_FLATTEN_RESULT_OPTION,
+            expr.span,
+            "the error of the collected `Result` is discarded before unwrapping it",
+            "use `?` to return the error instead of panicking",
+            format!("{collect}?"),
+            applicability,
+        );
+    }
+}
+
+/// Checks for `iter.filter_map(|r| r.ok()).collect()` and `iter.flatten().collect()` over
+/// `Result`s in a function returning a `Result`. `recv` is the receiver of `collect()`.
+pub(super) fn check_collect(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>) {
+    let discards_errors = match recv.kind {
+        ExprKind::MethodCall(method, _, [arg], _) if method.ident.as_str() == "filter_map" => is_result_ok(cx, arg),
+        ExprKind::MethodCall(method, flatten_recv, [], _) if method.ident.as_str() == "flatten" => {
+            is_trait_method(cx, recv, sym::Iterator)
+                && get_iterator_item_ty(cx, cx.typeck_results().expr_ty(flatten_recv))
+                    .is_some_and(|item| is_type_diagnostic_item(cx, item, sym::Result))
+        },
+        _ => false,
+    };
+    if discards_errors && enclosing_fn_err_ty(cx).is_some() {
+        span_lint_and_help(
+            cx,
+            MANUAL_FLATTEN_RESULT_OPTION,
+            expr.span,
+            "the errors are silently discarded before collecting",
+            None,
+            "to return the first error instead, collect into a `Result` and use `?`, e.g. \
+            `.collect::<Result<Vec<_>, _>>()?`",
+        );
+    }
+}
+
+/// Whether `arg` is `Result::ok`, `|r| r.ok()` or `|r| r.as_ref().ok()`.
+fn is_result_ok(cx: &LateContext<'_>, arg: &Expr<'_>) -> bool {
+    match arg.kind {
+        ExprKind::Path(ref qpath) => cx
+            .qpath_res(qpath, arg.hir_id)
+            .opt_def_id()
+            .is_some_and(|did| cx.tcx.is_diagnostic_item(sym::result_ok_method, did)),
+        ExprKind::Closure(&Closure { body, .. }) => {
+            if let Body {
+                params: [param], value, ..
+            } = cx.tcx.hir().body(body)
+                && let ExprKind::MethodCall(method, mut receiver, [], _) = value.kind
+                && method.ident.as_str() == "ok"
+                && let Some(method_did) = cx.typeck_results().type_dependent_def_id(value.hir_id)
+                && is_diag_item_method(cx, method_did, sym::Result)
+            {
+                if let ExprKind::MethodCall(as_ref, as_ref_recv, [], _) = receiver.kind
+                    && as_ref.ident.as_str() == "as_ref"
+                {
+                    receiver = as_ref_recv;
+                }
+                path_to_local_id(receiver, param.pat.hir_id)
+            } else {
+                false
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Returns the error type of the function containing the current body if it returns a
+/// `Result`. Closures are not considered, `?` would return from the closure.
+fn enclosing_fn_err_ty<'tcx>(cx: &LateContext<'tcx>) -> Option<Ty<'tcx>> {
+    let def_id = cx.tcx.hir().body_owner_def_id(cx.enclosing_body?);
+    if !matches!(cx.tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn) {
+        return None;
+    }
+    result_err_ty(cx, return_ty(cx, OwnerId { def_id }))
+}
+
+fn result_err_ty<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+    match ty.kind() {
+        ty::Adt(_, args) if is_type_diagnostic_item(cx, ty, sym::Result) => Some(args.type_at(1)),
+        _ => None,
+    }
+}
@@ -18,6 +18,7 @@ mod double_ended_iterator_last;
 mod drain_collect;
 mod err_expect;
 mod expect_fun_call;
+mod explicit_deref_in_closure_for_copied;
 mod extend_with_drain;
 mod filetype_is_file;
 mod filter_map;
@@ -42,6 +43,7 @@ mod iter_cloned_collect;
 mod iter_count;
 mod iter_filter;
 mod iter_kv_map;
+mod iter_next_after_position;
 mod iter_next_slice;
 mod iter_nth;
 mod iter_nth_zero;
@@ -51,9 +53,11 @@ mod iter_overeager_cloned;
 mod iter_skip_next;
 mod iter_skip_zero;
 mod iter_with_drain;
+mod iterator_collect_into_unit;
 mod iterator_step_by_zero;
 mod join_absolute_paths;
 mod manual_c_str_literals;
+mod manual_flatten_result_option;
 mod manual_inspect;
 mod manual_is_variant_and;
 mod manual_next_back;
@@ -70,6 +74,7 @@ mod map_identity;
 mod map_unwrap_or;
 mod map_with_unused_argument_over_ranges;
 mod mut_mutex_lock;
+mod mutex_in_struct_without_poison_handling;
 mod needless_as_bytes;
 mod needless_character_iteration;
 mod needless_collect;
@@ -104,6 +109,7 @@ mod skip_while_next;
 mod stable_sort_primitive;
 mod str_split;
 mod str_splitn;
+mod str_to_lowercase_cmp;
 mod string_extend_chars;
 mod string_lit_chars_any;
 mod suspicious_command_arg_space;
@@ -146,14 +152,18 @@ use clippy_utils::msrvs::{self, Msrv};
 use clippy_utils::ty::{contains_ty_adt_constructor_opaque, implements_trait, is_copy, is_type_diagnostic_item};
 use clippy_utils::{contains_return, is_bool, is_trait_method, iter_input_pats, peel_blocks, return_ty};
 pub use path_ends_with_ext::DEFAULT_ALLOWED_DOTFILES;
-use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_hir as hir;
 use rustc_hir::{Expr, ExprKind, Node, Stmt, StmtKind, TraitItem, TraitItemKind};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{self, TraitRef, Ty};
 use rustc_session::impl_lint_pass;
-use rustc_span::{Span, sym};
+use rustc_span::{Span, Symbol, sym};
+use std::cell::OnceCell;
+use utils::ReceiverKind;
+
+use crate::utils::pass_timings::time_part;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -4339,13 +4349,306 @@ declare_clippy_lint! {
     "using `NonZero::new_unchecked()` in a `const` context"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for iterators which are collected into `()` or `Result<(), E>`.
+    ///
+    /// ### Why is this bad?
+    /// Collecting into `()` only consumes the iterator for the side effects of its items.
+    /// `for_each` and `try_for_each` state this intent directly.
+    ///
+    /// `.map(..).collect::<Result<(), _>>()` is linted by `map_collect_result_unit`
+    /// instead. Collecting into `Result<(), E>` can be allowed with the
+    /// `allow-collect-into-result-unit` configuration, which only affects this lint.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let v = vec![1, 2, 3];
+    /// v.iter().map(|x| println!("{x}")).collect::<()>();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let v = vec![1, 2, 3];
+    /// v.iter().for_each(|x| println!("{x}"));
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub ITERATOR_COLLECT_INTO_UNIT,
+    style,
+    "collecting an iterator into `()` or `Result<(), E>` for its side effects"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for case-insensitive comparisons and searches of strings which change the case of
+    /// both strings with `to_lowercase` or `to_uppercase`, e.g. `a.to_lowercase() == b.to_lowercase()`
+    /// or `a.to_uppercase().contains(&b.to_uppercase())`.
+    ///
+    /// ### Why is this bad?
+    /// Both `to_lowercase` and `to_uppercase` allocate a new string and apply the Unicode case
+    /// mapping to every character. When both strings are ASCII, `eq_ignore_ascii_case` compares
+    /// them without allocating. Otherwise the `unicase` crate offers case-insensitive
+    /// comparisons which don't allocate.
+    ///
+    /// ### Known problems
+    /// The suggestion only behaves the same if both strings are ASCII. It's only applied
+    /// automatically if both are ASCII string literals or constants. Strings known to contain
+    /// other characters aren't linted.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// const EXPECTED: &str = "Content-Type";
+    /// const HEADER: &str = "content-type";
+    /// let _ = EXPECTED.to_lowercase() == HEADER.to_lowercase();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// const EXPECTED: &str = "Content-Type";
+    /// const HEADER: &str = "content-type";
+    /// let _ = EXPECTED.eq_ignore_ascii_case(HEADER);
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub STR_TO_LOWERCASE_CMP,
+    perf,
+    "case-insensitive comparison of strings using `to_lowercase` or `to_uppercase`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for indices found with `Iterator::position` on the iterator of a slice, array or
+    /// `Vec` which are only used to index the same collection, e.g.
+    /// `v.iter().position(p).map(|i| &v[i])` or `let i = v.iter().position(p)?;` followed by `&v[i]`.
+    ///
+    /// ### Why is this bad?
+    /// `Iterator::find` returns the element directly, which is shorter and doesn't need a
+    /// bounds check.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn first_even(v: &[u32]) -> Option<&u32> {
+    ///     let idx = v.iter().position(|x| x % 2 == 0)?;
+    ///     Some(&v[idx])
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn first_even(v: &[u32]) -> Option<&u32> {
+    ///     v.iter().find(|x| *x % 2 == 0)
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub ITER_NEXT_AFTER_POSITION,
+    complexity,
+    "using `position` to index the same collection instead of `find`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the pieces of a split string being collected into a `Vec` only to index into it
+    /// with a constant index.
+    ///
+    /// ### Why is this bad?
+    /// The `Vec` allocation is unnecessary, the piece can be taken from the iterator directly.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let s = "key:value";
+    /// let key = s.split(':').collect::<Vec<_>>()[0];
+    /// let value = s.splitn(2, ':').collect::<Vec<_>>()[1];
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let s = "key:value";
+    /// let key = s.split(':').next().unwrap();
+    /// let value = s.split_once(':').unwrap().1;
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub SPLIT_THEN_INDEX,
+    perf,
+    "collecting the pieces of a split string into a `Vec` to index into it"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `.lock().unwrap()` on a `Mutex` stored in a struct field in library crates.
+    /// Binary crates are only checked if `allow-lock-unwrap-in-binaries` is set to `false`.
+    ///
+    /// ### Why restrict this?
+    /// A mutex is poisoned when a thread panics while holding its lock. A library calling
+    /// `unwrap` on the result of `lock` turns this into a panic in every caller, which can't
+    /// recover from it. Handling the `PoisonError`, or using a mutex which doesn't poison, leaves
+    /// that decision to the library's users.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// pub struct Counter {
+    ///     count: Mutex<u32>,
+    /// }
+    ///
+    /// impl Counter {
+    ///     pub fn increment(&self) {
+    ///         *self.count.lock().unwrap() += 1;
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::sync::{Mutex, PoisonError};
+    /// pub struct Counter {
+    ///     count: Mutex<u32>,
+    /// }
+    ///
+    /// impl Counter {
+    ///     pub fn increment(&self) {
+    ///         *self.count.lock().unwrap_or_else(PoisonError::into_inner) += 1;
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub MUTEX_IN_STRUCT_WITHOUT_POISON_HANDLING,
+    restriction,
+    "unwrapping the result of locking a mutex stored in a struct field in a library"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for errors which are discarded while collecting an iterator of `Result`s in a
+    /// function that returns a `Result` itself:
+    /// - `iter.filter_map(Result::ok).collect()` or `iter.flatten().collect()`, which skip the
+    ///   errors
+    /// - `iter.collect::<Result<_, _>>().ok().unwrap()`, which panics on an error
+    ///
+    /// ### Why is this bad?
+    /// The error is lost. Since the function can return an error, collecting into a `Result`
+    /// and using `?` is usually what was intended.
+    ///
+    /// ### Known problems
+    /// Skipping the invalid items may be deliberate.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::num::ParseIntError;
+    /// fn parse(input: &[&str]) -> Result<Vec<u32>, ParseIntError> {
+    ///     Ok(input.iter().map(|s| s.parse()).filter_map(Result::ok).collect())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::num::ParseIntError;
+    /// fn parse(input: &[&str]) -> Result<Vec<u32>, ParseIntError> {
+    ///     Ok(input.iter().map(|s| s.parse()).collect::<Result<Vec<_>, _>>()?)
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub MANUAL_FLATTEN_RESULT_OPTION,
+    pedantic,
+    "discarding the errors of an iterator of `Result`s in a function returning a `Result`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for closures which only copy or clone the elements of an iterator over references
+    /// where `map_clone` doesn't apply:
+    /// - `filter_map(|x| Some(*x))` and `flat_map(|x| Some(*x))`
+    /// - tuple patterns, like `map(|(a, b)| (*a, *b))` or `map(|&(a, b)| (a, b))`
+    /// - `filter(|&&x| ..)` followed by `copied()` or `cloned()`
+    ///
+    /// ### Why is this bad?
+    /// The `copied` and `cloned` adapters are shorter and say what the code does. Calling them
+    /// before `filter` avoids matching the elements through two references.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let pairs = [(1, 2), (3, 4)];
+    /// let v: Vec<(i32, i32)> = pairs.iter().map(|(a, b)| (*a, *b)).collect();
+    /// let odd: Vec<i32> = [1, 2, 3].iter().filter(|&&x| x % 2 == 1).copied().collect();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let pairs = [(1, 2), (3, 4)];
+    /// let v: Vec<(i32, i32)> = pairs.iter().copied().collect();
+    /// let odd: Vec<i32> = [1, 2, 3].iter().copied().filter(|&x| x % 2 == 1).collect();
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub EXPLICIT_DEREF_IN_CLOSURE_FOR_COPIED,
+    pedantic,
+    "closures which only copy or clone the elements of an iterator"
+}
+
+/// A method call handled by [`Methods::check_methods`].
+#[derive(Clone, Copy)]
+struct MethodCall<'a, 'tcx> {
+    /// The lint pass, for its configuration
+    pass: &'a Methods,
+    expr: &'tcx Expr<'tcx>,
+    name: &'tcx str,
+    recv: &'tcx Expr<'tcx>,
+    args: &'tcx [Expr<'tcx>],
+    span: Span,
+    call_span: Span,
+    /// Computed on first use by [`MethodCall::recv_kind`], and shared by all handlers of the call
+    recv_kind: &'a OnceCell<ReceiverKind>,
+}
+
+impl<'tcx> MethodCall<'_, 'tcx> {
+    /// Returns the kind of the receiver of the call.
+    fn recv_kind(&self, cx: &LateContext<'tcx>) -> ReceiverKind {
+        *self.recv_kind.get_or_init(|| ReceiverKind::of(cx, self.recv))
+    }
+}
+
+/// Defines the handlers of the method calls checked by [`Methods::check_methods`], each preceded
+/// by the names of the methods it's called for, and generates `METHOD_HANDLERS` from them. A
+/// handler which only runs the checks of one lint module takes the names from the `TARGETS` of the
+/// module.
+///
+/// A handler runs once per call and checks the number of arguments itself, so the lints on a given
+/// call are emitted in the order they appear in the handler. Handlers of the same method run in
+/// the order they're defined in.
+macro_rules! method_handlers {
+    ($(
+        $names:expr;
+        fn $handler:ident($cx:ident, $call:ident) $body:block
+    )*) => {
+        $(
+            fn $handler<'tcx>($cx: &LateContext<'tcx>, $call: MethodCall<'_, 'tcx>) $body
+        )*
+
+        /// The method names and handlers, along with the names the handlers are timed as
+        const METHOD_HANDLERS: &[(&[&str], MethodHandler)] = &[$(($names, MethodHandler {
+            name: concat!("Methods::", stringify!($handler)),
+            check: $handler,
+        })),*];
+    };
+}
+
+#[derive(Clone, Copy)]
+struct MethodHandler {
+    name: &'static str,
+    check: for<'a, 'tcx> fn(&LateContext<'tcx>, MethodCall<'a, 'tcx>),
+}
+
+fn method_handlers() -> FxHashMap<Symbol, Vec<MethodHandler>> {
+    let mut handlers: FxHashMap<Symbol, Vec<MethodHandler>> = FxHashMap::default();
+    for &(names, handler) in METHOD_HANDLERS {
+        for name in names {
+            handlers.entry(Symbol::intern(name)).or_default().push(handler);
+        }
+    }
+    handlers
+}
+
+#[expect(clippy::struct_excessive_bools)] // Bools are configuration values.
 pub struct Methods {
     avoid_breaking_exported_api: bool,
     msrv: Msrv,
     allow_expect_in_tests: bool,
     allow_unwrap_in_tests: bool,
     allowed_dotfiles: FxHashSet<&'static str>,
+    allow_collect_into_result_unit: bool,
+    allow_lock_unwrap_in_binaries: bool,
     format_args: FormatArgsStorage,
+    method_handlers: FxHashMap<Symbol, Vec<MethodHandler>>,
 }
 
 impl Methods {
@@ -4356,10 +4659,13 @@ impl Methods {
         Self {
             avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
             msrv: conf.msrv.clone(),
-            allow_expect_in_tests: conf.allow_expect_in_tests,
-            allow_unwrap_in_tests: conf.allow_unwrap_in_tests,
+            allow_expect_in_tests: conf.is_allowed_in_tests(EXPECT_USED),
+            allow_unwrap_in_tests: conf.is_allowed_in_tests(UNWRAP_USED),
             allowed_dotfiles,
+            allow_collect_into_result_unit: conf.allow_collect_into_result_unit,
+            allow_lock_unwrap_in_binaries: conf.allow_lock_unwrap_in_binaries,
             format_args,
+            method_handlers: method_handlers(),
         }
     }
 }
@@ -4506,6 +4812,13 @@ impl_lint_pass!(Methods => [
     UNNECESSARY_MAP_OR,
     DOUBLE_ENDED_ITERATOR_LAST,
     USELESS_NONZERO_NEW_UNCHECKED,
+    ITERATOR_COLLECT_INTO_UNIT,
+    STR_TO_LOWERCASE_CMP,
+    ITER_NEXT_AFTER_POSITION,
+    SPLIT_THEN_INDEX,
+    MUTEX_IN_STRUCT_WITHOUT_POISON_HANDLING,
+    MANUAL_FLATTEN_RESULT_OPTION,
+    EXPLICIT_DEREF_IN_CLOSURE_FOR_COPIED,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -4563,7 +4876,10 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
                     eq: op.node == hir::BinOpKind::Eq,
                 };
                 lint_binary_expr_with_method_call(cx, &mut info);
+                str_to_lowercase_cmp::check_cmp(cx, expr, lhs, rhs, op.node == hir::BinOpKind::Eq);
             },
+            ExprKind::AddrOf(..) => iter_next_after_position::check_index(cx, expr),
+            ExprKind::Index(base, index, _) => str_splitn::check_collect_index(cx, expr, base, index, &self.msrv),
             _ => (),
         }
     }
@@ -4704,593 +5020,1187 @@ impl<'tcx> LateLintPass<'tcx> for Methods {
 }
 
 impl Methods {
-    #[allow(clippy::too_many_lines)]
     fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        if let Some((name, recv, args, span, call_span)) = method_call(expr) {
-            match (name, args) {
-                ("add" | "offset" | "sub" | "wrapping_offset" | "wrapping_add" | "wrapping_sub", [_arg]) => {
-                    zst_offset::check(cx, expr, recv);
-                },
-                ("all", [arg]) => {
-                    unused_enumerate_index::check(cx, expr, recv, arg);
-                    needless_character_iteration::check(cx, expr, recv, arg, true);
-                    match method_call(recv) {
-                        Some(("cloned", recv2, [], _, _)) => {
-                            iter_overeager_cloned::check(
-                                cx,
-                                expr,
-                                recv,
-                                recv2,
-                                iter_overeager_cloned::Op::NeedlessMove(arg),
-                                false,
-                            );
-                        },
-                        Some(("map", _, [map_arg], _, map_call_span)) => {
-                            map_all_any_identity::check(cx, expr, recv, map_call_span, map_arg, call_span, arg, "all");
-                        },
-                        _ => {},
-                    }
-                },
-                ("and_then", [arg]) => {
-                    let biom_option_linted = bind_instead_of_map::check_and_then_some(cx, expr, recv, arg);
-                    let biom_result_linted = bind_instead_of_map::check_and_then_ok(cx, expr, recv, arg);
-                    if !biom_option_linted && !biom_result_linted {
-                        unnecessary_lazy_eval::check(cx, expr, recv, arg, "and");
-                    }
-                },
-                ("any", [arg]) => {
-                    unused_enumerate_index::check(cx, expr, recv, arg);
-                    needless_character_iteration::check(cx, expr, recv, arg, false);
-                    match method_call(recv) {
-                        Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(
-                            cx,
-                            expr,
-                            recv,
-                            recv2,
-                            iter_overeager_cloned::Op::NeedlessMove(arg),
-                            false,
-                        ),
-                        Some(("chars", recv, _, _, _))
-                            if let ExprKind::Closure(arg) = arg.kind
-                                && let body = cx.tcx.hir().body(arg.body)
-                                && let [param] = body.params =>
-                        {
-                            string_lit_chars_any::check(cx, expr, recv, param, peel_blocks(body.value), &self.msrv);
-                        },
-                        Some(("map", _, [map_arg], _, map_call_span)) => {
-                            map_all_any_identity::check(cx, expr, recv, map_call_span, map_arg, call_span, arg, "any");
-                        },
-                        _ => {},
-                    }
-                },
-                ("arg", [arg]) => {
-                    suspicious_command_arg_space::check(cx, recv, arg, span);
-                },
-                ("as_deref" | "as_deref_mut", []) => {
-                    needless_option_as_deref::check(cx, expr, recv, name);
-                },
-                ("as_bytes", []) => {
-                    if let Some(("as_str", recv, [], as_str_span, _)) = method_call(recv) {
-                        redundant_as_str::check(cx, expr, recv, as_str_span, span);
-                    }
-                },
-                ("as_mut", []) => useless_asref::check(cx, expr, "as_mut", recv),
-                ("as_ptr", []) => manual_c_str_literals::check_as_ptr(cx, expr, recv, &self.msrv),
-                ("as_ref", []) => useless_asref::check(cx, expr, "as_ref", recv),
-                ("assume_init", []) => uninit_assumed_init::check(cx, expr, recv),
-                ("cloned", []) => {
-                    cloned_instead_of_copied::check(cx, expr, recv, span, &self.msrv);
-                    option_as_ref_cloned::check(cx, recv, span);
-                },
-                ("collect", []) if is_trait_method(cx, expr, sym::Iterator) => {
-                    needless_collect::check(cx, span, expr, recv, call_span);
-                    match method_call(recv) {
-                        Some((name @ ("cloned" | "copied"), recv2, [], _, _)) => {
-                            iter_cloned_collect::check(cx, name, expr, recv2);
-                        },
-                        Some(("map", m_recv, [m_arg], m_ident_span, _)) => {
-                            map_collect_result_unit::check(cx, expr, m_recv, m_arg);
-                            format_collect::check(cx, expr, m_arg, m_ident_span);
-                        },
-                        Some(("take", take_self_arg, [take_arg], _, _)) => {
-                            if self.msrv.meets(msrvs::STR_REPEAT) {
-                                manual_str_repeat::check(cx, expr, recv, take_self_arg, take_arg);
-                            }
-                        },
-                        Some(("drain", recv, args, ..)) => {
-                            drain_collect::check(cx, args, expr, recv);
-                        },
-                        _ => {},
-                    }
-                },
-                ("count", []) if is_trait_method(cx, expr, sym::Iterator) => match method_call(recv) {
-                    Some(("cloned", recv2, [], _, _)) => {
-                        iter_overeager_cloned::check(cx, expr, recv, recv2, iter_overeager_cloned::Op::RmCloned, false);
-                    },
-                    Some((name2 @ ("into_iter" | "iter" | "iter_mut"), recv2, [], _, _)) => {
-                        iter_count::check(cx, expr, recv2, name2);
-                    },
-                    Some(("map", _, [arg], _, _)) => suspicious_map::check(cx, expr, recv, arg),
-                    Some(("filter", recv2, [arg], _, _)) => bytecount::check(cx, expr, recv2, arg),
-                    Some(("bytes", recv2, [], _, _)) => bytes_count_to_len::check(cx, expr, recv, recv2),
-                    _ => {},
-                },
-                ("min" | "max", [arg]) => {
-                    unnecessary_min_or_max::check(cx, expr, name, recv, arg);
-                },
-                ("drain", ..) => {
-                    if let Node::Stmt(Stmt { hir_id: _, kind, .. }) = cx.tcx.parent_hir_node(expr.hir_id)
-                        && matches!(kind, StmtKind::Semi(_))
-                        && args.len() <= 1
-                    {
-                        clear_with_drain::check(cx, expr, recv, span, args.first());
-                    } else if let [arg] = args {
-                        iter_with_drain::check(cx, expr, recv, span, arg);
-                    }
-                },
-                ("ends_with", [arg]) => {
-                    if let ExprKind::MethodCall(.., span) = expr.kind {
-                        case_sensitive_file_extension_comparisons::check(cx, expr, span, recv, arg);
-                    }
-                    path_ends_with_ext::check(cx, recv, arg, expr, &self.msrv, &self.allowed_dotfiles);
-                },
-                ("expect", [_]) => {
-                    match method_call(recv) {
-                        Some(("ok", recv, [], _, _)) => ok_expect::check(cx, expr, recv),
-                        Some(("err", recv, [], err_span, _)) => {
-                            err_expect::check(cx, expr, recv, span, err_span, &self.msrv);
-                        },
-                        _ => unwrap_expect_used::check(
-                            cx,
-                            expr,
-                            recv,
-                            false,
-                            self.allow_expect_in_tests,
-                            unwrap_expect_used::Variant::Expect,
-                        ),
-                    }
-                    unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
-                },
-                ("expect_err", [_]) => {
-                    unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
-                    unwrap_expect_used::check(
+        if let ExprKind::MethodCall(path, ..) = expr.kind
+            && let Some(handlers) = self.method_handlers.get(&path.ident.name)
+            && let Some((name, recv, args, span, call_span)) = method_call(expr)
+        {
+            let call = MethodCall {
+                pass: self,
+                expr,
+                name,
+                recv,
+                args,
+                span,
+                call_span,
+                recv_kind: &OnceCell::new(),
+            };
+            for handler in handlers {
+                time_part(handler.name, || (handler.check)(cx, call));
+            }
+        }
+    }
+}
+
+method_handlers! {
+    zst_offset::TARGETS;
+    fn check_ptr_offset(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [_] = args {
+            zst_offset::check(cx, expr, recv);
+        }
+    }
+
+    &["all"];
+    fn check_all(cx, call) {
+        let MethodCall {
+            expr,
+            recv,
+            args,
+            call_span,
+            ..
+        } = call;
+        if let [arg] = args {
+            unused_enumerate_index::check(cx, expr, recv, arg);
+            needless_character_iteration::check(cx, expr, recv, arg, true);
+            match method_call(recv) {
+                Some(("cloned", recv2, [], _, _)) => {
+                    iter_overeager_cloned::check(
                         cx,
                         expr,
                         recv,
-                        true,
-                        self.allow_expect_in_tests,
-                        unwrap_expect_used::Variant::Expect,
+                        recv2,
+                        iter_overeager_cloned::Op::NeedlessMove(arg),
+                        false,
                     );
                 },
-                ("extend", [arg]) => {
-                    string_extend_chars::check(cx, expr, recv, arg);
-                    extend_with_drain::check(cx, expr, recv, arg);
-                },
-                ("filter", [arg]) => {
-                    if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
-                        // if `arg` has side-effect, the semantic will change
-                        iter_overeager_cloned::check(
-                            cx,
-                            expr,
-                            recv,
-                            recv2,
-                            iter_overeager_cloned::Op::FixClosure(name, arg),
-                            false,
-                        );
-                    }
-                    if self.msrv.meets(msrvs::ITER_FLATTEN) {
-                        // use the sourcemap to get the span of the closure
-                        iter_filter::check(cx, expr, arg, span);
-                    }
-                },
-                ("find", [arg]) => {
-                    if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
-                        // if `arg` has side-effect, the semantic will change
-                        iter_overeager_cloned::check(
-                            cx,
-                            expr,
-                            recv,
-                            recv2,
-                            iter_overeager_cloned::Op::FixClosure(name, arg),
-                            false,
-                        );
-                    }
-                },
-                ("filter_map", [arg]) => {
-                    unused_enumerate_index::check(cx, expr, recv, arg);
-                    unnecessary_filter_map::check(cx, expr, arg, name);
-                    filter_map_bool_then::check(cx, expr, arg, call_span);
-                    filter_map_identity::check(cx, expr, arg, span);
+                Some(("map", _, [map_arg], _, map_call_span)) => {
+                    map_all_any_identity::check(cx, expr, recv, map_call_span, map_arg, call_span, arg, "all");
                 },
-                ("find_map", [arg]) => {
-                    unused_enumerate_index::check(cx, expr, recv, arg);
-                    unnecessary_filter_map::check(cx, expr, arg, name);
+                _ => {},
+            }
+        }
+    }
+
+    &["and_then"];
+    fn check_and_then(cx, call) {
+        let MethodCall {
+            expr,
+            recv,
+            args,
+            ..
+        } = call;
+        if let [arg] = args {
+            let biom_option_linted = bind_instead_of_map::check_and_then_some(cx, expr, recv, arg);
+            let biom_result_linted = bind_instead_of_map::check_and_then_ok(cx, expr, recv, arg);
+            if !biom_option_linted && !biom_result_linted {
+                unnecessary_lazy_eval::check(cx, expr, arg, "and", call.recv_kind(cx));
+            }
+        }
+    }
+
+    &["any"];
+    fn check_any(cx, call) {
+        let MethodCall {
+            expr,
+            recv,
+            args,
+            call_span,
+            ..
+        } = call;
+        if let [arg] = args {
+            unused_enumerate_index::check(cx, expr, recv, arg);
+            needless_character_iteration::check(cx, expr, recv, arg, false);
+            match method_call(recv) {
+                Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(
+                    cx,
+                    expr,
+                    recv,
+                    recv2,
+                    iter_overeager_cloned::Op::NeedlessMove(arg),
+                    false,
+                ),
+                Some(("chars", recv, _, _, _))
+                    if let ExprKind::Closure(arg) = arg.kind
+                        && let body = cx.tcx.hir().body(arg.body)
+                        && let [param] = body.params =>
+                {
+                    string_lit_chars_any::check(cx, expr, recv, param, peel_blocks(body.value), &call.pass.msrv);
                 },
-                ("flat_map", [arg]) => {
-                    unused_enumerate_index::check(cx, expr, recv, arg);
-                    flat_map_identity::check(cx, expr, arg, span);
-                    flat_map_option::check(cx, expr, arg, span);
+                Some(("map", _, [map_arg], _, map_call_span)) => {
+                    map_all_any_identity::check(cx, expr, recv, map_call_span, map_arg, call_span, arg, "any");
                 },
-                ("flatten", []) => match method_call(recv) {
-                    Some(("map", recv, [map_arg], map_span, _)) => {
-                        map_flatten::check(cx, expr, recv, map_arg, map_span);
-                    },
-                    Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(
-                        cx,
-                        expr,
-                        recv,
-                        recv2,
-                        iter_overeager_cloned::Op::LaterCloned,
-                        true,
-                    ),
-                    _ => {},
+                _ => {},
+            }
+        }
+    }
+
+    suspicious_command_arg_space::TARGETS;
+    fn check_arg(cx, call) {
+        let MethodCall { recv, args, span, .. } = call;
+        if let [arg] = args {
+            suspicious_command_arg_space::check(cx, recv, arg, span);
+        }
+    }
+
+    needless_option_as_deref::TARGETS;
+    fn check_as_deref(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if args.is_empty() {
+            needless_option_as_deref::check(cx, expr, recv, name);
+        }
+    }
+
+    &["as_bytes"];
+    fn check_as_bytes(cx, call) {
+        let MethodCall {
+            expr, recv, args, span, ..
+        } = call;
+        if args.is_empty() {
+            if let Some(("as_str", recv, [], as_str_span, _)) = method_call(recv) {
+                redundant_as_str::check(cx, expr, recv, as_str_span, span);
+            }
+        }
+    }
+
+    useless_asref::TARGETS;
+    fn check_as_ref(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if args.is_empty() {
+            useless_asref::check(cx, expr, name, recv);
+        }
+    }
+
+    manual_c_str_literals::TARGETS;
+    fn check_as_ptr(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if args.is_empty() {
+            manual_c_str_literals::check_as_ptr(cx, expr, recv, &call.pass.msrv);
+        }
+    }
+
+
+    uninit_assumed_init::TARGETS;
+    fn check_assume_init(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if args.is_empty() {
+            uninit_assumed_init::check(cx, expr, recv);
+        }
+    }
+
+    &["cloned"];
+    fn check_cloned(cx, call) {
+        let MethodCall {
+            expr, recv, args, span, ..
+        } = call;
+        if args.is_empty() {
+            cloned_instead_of_copied::check(cx, expr, recv, span, &call.pass.msrv);
+            option_as_ref_cloned::check(cx, recv, span);
+        }
+    }
+
+    &["cloned", "copied"];
+    fn check_copied(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if args.is_empty() {
+            explicit_deref_in_closure_for_copied::check_filter(cx, expr, recv, name);
+        }
+    }
+
+    &["collect"];
+    fn check_collect(cx, call) {
+        let MethodCall {
+            expr,
+            recv,
+            args,
+            span,
+            call_span,
+            ..
+        } = call;
+        if args.is_empty() && is_trait_method(cx, expr, sym::Iterator) {
+            needless_collect::check(cx, span, expr, recv, call_span);
+            iterator_collect_into_unit::check(cx, expr, recv, call.pass.allow_collect_into_result_unit);
+            manual_flatten_result_option::check_collect(cx, expr, recv);
+            match method_call(recv) {
+                Some((name @ ("cloned" | "copied"), recv2, [], _, _)) => {
+                    iter_cloned_collect::check(cx, name, expr, recv2);
                 },
-                ("fold", [init, acc]) => {
-                    manual_try_fold::check(cx, expr, init, acc, call_span, &self.msrv);
-                    unnecessary_fold::check(cx, expr, init, acc, span);
+                Some(("map", m_recv, [m_arg], m_ident_span, _)) => {
+                    map_collect_result_unit::check(cx, expr, m_recv, m_arg);
+                    format_collect::check(cx, expr, m_arg, m_ident_span);
                 },
-                ("for_each", [arg]) => {
-                    unused_enumerate_index::check(cx, expr, recv, arg);
-                    match method_call(recv) {
-                        Some(("inspect", _, [_], span2, _)) => inspect_for_each::check(cx, expr, span2),
-                        Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(
-                            cx,
-                            expr,
-                            recv,
-                            recv2,
-                            iter_overeager_cloned::Op::NeedlessMove(arg),
-                            false,
-                        ),
-                        _ => {},
+                Some(("take", take_self_arg, [take_arg], _, _)) => {
+                    if call.pass.msrv.meets(msrvs::STR_REPEAT) {
+                        manual_str_repeat::check(cx, expr, recv, take_self_arg, take_arg);
                     }
                 },
-                ("get", [arg]) => {
-                    get_first::check(cx, expr, recv, arg);
-                    get_last_with_len::check(cx, expr, recv, arg);
-                },
-                ("get_or_insert_with", [arg]) => unnecessary_lazy_eval::check(cx, expr, recv, arg, "get_or_insert"),
-                ("hash", [arg]) => {
-                    unit_hash::check(cx, expr, recv, arg);
+                Some(("drain", recv, args, ..)) => {
+                    drain_collect::check(cx, args, expr, recv);
                 },
-                ("is_empty", []) => {
-                    match method_call(recv) {
-                        Some((prev_method @ ("as_bytes" | "bytes"), prev_recv, [], _, _)) => {
-                            needless_as_bytes::check(cx, prev_method, "is_empty", prev_recv, expr.span);
-                        },
-                        Some(("as_str", recv, [], as_str_span, _)) => {
-                            redundant_as_str::check(cx, expr, recv, as_str_span, span);
-                        },
-                        _ => {},
-                    }
-                    is_empty::check(cx, expr, recv);
-                },
-                ("is_file", []) => filetype_is_file::check(cx, expr, recv),
-                ("is_digit", [radix]) => is_digit_ascii_radix::check(cx, expr, recv, radix, &self.msrv),
-                ("is_none", []) => check_is_some_is_none(cx, expr, recv, call_span, false),
-                ("is_some", []) => check_is_some_is_none(cx, expr, recv, call_span, true),
-                ("iter" | "iter_mut" | "into_iter", []) => {
-                    iter_on_single_or_empty_collections::check(cx, expr, name, recv);
+                _ => {},
+            }
+        }
+    }
+
+    str_to_lowercase_cmp::TARGETS;
+    fn check_contains(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [arg] = args {
+            str_to_lowercase_cmp::check_contains(cx, expr, recv, arg);
+        }
+    }
+
+    &["count"];
+    fn check_count(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if args.is_empty() && is_trait_method(cx, expr, sym::Iterator) {
+            match method_call(recv) {
+                Some(("cloned", recv2, [], _, _)) => {
+                    iter_overeager_cloned::check(cx, expr, recv, recv2, iter_overeager_cloned::Op::RmCloned, false);
                 },
-                ("join", [join_arg]) => {
-                    if let Some(("collect", _, _, span, _)) = method_call(recv) {
-                        unnecessary_join::check(cx, expr, recv, join_arg, span);
-                    } else {
-                        join_absolute_paths::check(cx, recv, join_arg, expr.span);
-                    }
+                Some((name2 @ ("into_iter" | "iter" | "iter_mut"), recv2, [], _, _)) => {
+                    iter_count::check(cx, expr, recv2, name2);
                 },
-                ("last", []) => {
-                    if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
-                        iter_overeager_cloned::check(
-                            cx,
-                            expr,
-                            recv,
-                            recv2,
-                            iter_overeager_cloned::Op::LaterCloned,
-                            false,
-                        );
-                    }
-                    double_ended_iterator_last::check(cx, expr, recv, call_span);
-                },
-                ("len", []) => {
-                    if let Some((prev_method @ ("as_bytes" | "bytes"), prev_recv, [], _, _)) = method_call(recv) {
-                        needless_as_bytes::check(cx, prev_method, "len", prev_recv, expr.span);
-                    }
-                },
-                ("lock", []) => {
-                    mut_mutex_lock::check(cx, expr, recv, span);
-                },
-                (name @ ("map" | "map_err"), [m_arg]) => {
-                    if name == "map" {
-                        unused_enumerate_index::check(cx, expr, recv, m_arg);
-                        map_clone::check(cx, expr, recv, m_arg, &self.msrv);
-                        map_with_unused_argument_over_ranges::check(cx, expr, recv, m_arg, &self.msrv, span);
-                        match method_call(recv) {
-                            Some((map_name @ ("iter" | "into_iter"), recv2, _, _, _)) => {
-                                iter_kv_map::check(cx, map_name, expr, recv2, m_arg, &self.msrv);
-                            },
-                            Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(
-                                cx,
-                                expr,
-                                recv,
-                                recv2,
-                                iter_overeager_cloned::Op::NeedlessMove(m_arg),
-                                false,
-                            ),
-                            _ => {},
-                        }
-                    } else {
-                        map_err_ignore::check(cx, expr, m_arg);
-                    }
-                    if let Some((name, recv2, args, span2, _)) = method_call(recv) {
-                        match (name, args) {
-                            ("as_mut", []) => option_as_ref_deref::check(cx, expr, recv2, m_arg, true, &self.msrv),
-                            ("as_ref", []) => option_as_ref_deref::check(cx, expr, recv2, m_arg, false, &self.msrv),
-                            ("filter", [f_arg]) => {
-                                filter_map::check(cx, expr, recv2, f_arg, span2, recv, m_arg, span, false);
-                            },
-                            ("find", [f_arg]) => {
-                                filter_map::check(cx, expr, recv2, f_arg, span2, recv, m_arg, span, true);
-                            },
-                            _ => {},
-                        }
-                    }
-                    map_identity::check(cx, expr, recv, m_arg, name, span);
-                    manual_inspect::check(cx, expr, m_arg, name, span, &self.msrv);
-                    crate::useless_conversion::check_function_application(cx, expr, recv, m_arg);
+                Some(("map", _, [arg], _, _)) => suspicious_map::check(cx, expr, recv, arg),
+                Some(("filter", recv2, [arg], _, _)) => bytecount::check(cx, expr, recv2, arg),
+                Some(("bytes", recv2, [], _, _)) => bytes_count_to_len::check(cx, expr, recv, recv2),
+                _ => {},
+            }
+        }
+    }
+
+    unnecessary_min_or_max::TARGETS;
+    fn check_min_max(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if let [arg] = args {
+            unnecessary_min_or_max::check(cx, expr, name, recv, arg);
+        }
+    }
+
+    &["drain"];
+    fn check_drain(cx, call) {
+        let MethodCall {
+            expr, recv, args, span, ..
+        } = call;
+        if let Node::Stmt(Stmt { hir_id: _, kind, .. }) = cx.tcx.parent_hir_node(expr.hir_id)
+            && matches!(kind, StmtKind::Semi(_))
+            && args.len() <= 1
+        {
+            clear_with_drain::check(cx, expr, recv, span, args.first());
+        } else if let [arg] = args {
+            iter_with_drain::check(cx, expr, recv, span, arg);
+        }
+    }
+
+    &["ends_with"];
+    fn check_ends_with(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [arg] = args {
+            if let ExprKind::MethodCall(.., span) = expr.kind {
+                case_sensitive_file_extension_comparisons::check(cx, expr, span, recv, arg);
+            }
+            path_ends_with_ext::check(cx, recv, arg, expr, &call.pass.msrv, &call.pass.allowed_dotfiles);
+        }
+    }
+
+    &["expect"];
+    fn check_expect(cx, call) {
+        let MethodCall {
+            expr,
+            name,
+            recv,
+            args,
+            span,
+            ..
+        } = call;
+        if let [_] = args {
+            match method_call(recv) {
+                Some(("ok", recv, [], _, _)) => ok_expect::check(cx, expr, recv),
+                Some(("err", recv, [], err_span, _)) => {
+                    err_expect::check(cx, expr, recv, span, err_span, &call.pass.msrv);
                 },
-                ("map_break" | "map_continue", [m_arg]) => {
-                    crate::useless_conversion::check_function_application(cx, expr, recv, m_arg);
+                _ => unwrap_expect_used::check(
+                    cx,
+                    expr,
+                    recv,
+                    false,
+                    call.pass.allow_expect_in_tests,
+                    unwrap_expect_used::Variant::Expect,
+                ),
+            }
+            unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
+        }
+    }
+
+    &["expect_err"];
+    fn check_expect_err(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if let [_] = args {
+            unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
+            unwrap_expect_used::check(
+                cx,
+                expr,
+                recv,
+                true,
+                call.pass.allow_expect_in_tests,
+                unwrap_expect_used::Variant::Expect,
+            );
+        }
+    }
+
+    &["extend"];
+    fn check_extend(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [arg] = args {
+            string_extend_chars::check(cx, expr, recv, arg);
+            extend_with_drain::check(cx, expr, recv, arg);
+        }
+    }
+
+    &["filter"];
+    fn check_filter(cx, call) {
+        let MethodCall {
+            expr,
+            name,
+            recv,
+            args,
+            span,
+            ..
+        } = call;
+        if let [arg] = args {
+            if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
+                // if `arg` has side-effect, the semantic will change
+                iter_overeager_cloned::check(
+                    cx,
+                    expr,
+                    recv,
+                    recv2,
+                    iter_overeager_cloned::Op::FixClosure(name, arg),
+                    false,
+                );
+            }
+            if call.pass.msrv.meets(msrvs::ITER_FLATTEN) {
+                // use the sourcemap to get the span of the closure
+                iter_filter::check(cx, expr, arg, span);
+            }
+        }
+    }
+
+    &["find"];
+    fn check_find(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if let [arg] = args {
+            if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
+                // if `arg` has side-effect, the semantic will change
+                iter_overeager_cloned::check(
+                    cx,
+                    expr,
+                    recv,
+                    recv2,
+                    iter_overeager_cloned::Op::FixClosure(name, arg),
+                    false,
+                );
+            }
+        }
+    }
+
+    &["filter_map"];
+    fn check_filter_map(cx, call) {
+        let MethodCall {
+            expr,
+            name,
+            recv,
+            args,
+            span,
+            call_span,
+            ..
+        } = call;
+        if let [arg] = args {
+            unused_enumerate_index::check(cx, expr, recv, arg);
+            if !explicit_deref_in_closure_for_copied::check_map(cx, expr, recv, arg, name, &call.pass.msrv) {
+                unnecessary_filter_map::check(cx, expr, arg, name);
+            }
+            filter_map_bool_then::check(cx, expr, arg, call_span);
+            filter_map_identity::check(cx, expr, arg, span);
+        }
+    }
+
+    &["find_map"];
+    fn check_find_map(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if let [arg] = args {
+            unused_enumerate_index::check(cx, expr, recv, arg);
+            unnecessary_filter_map::check(cx, expr, arg, name);
+        }
+    }
+
+    &["flat_map"];
+    fn check_flat_map(cx, call) {
+        let MethodCall {
+            expr, recv, args, span, ..
+        } = call;
+        if let [arg] = args {
+            unused_enumerate_index::check(cx, expr, recv, arg);
+            flat_map_identity::check(cx, expr, arg, span);
+            if !explicit_deref_in_closure_for_copied::check_map(cx, expr, recv, arg, "flat_map", &call.pass.msrv) {
+                flat_map_option::check(cx, expr, arg, span);
+            }
+        }
+    }
+
+    &["flatten"];
+    fn check_flatten(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if args.is_empty() {
+            match method_call(recv) {
+                Some(("map", recv, [map_arg], map_span, _)) => {
+                    map_flatten::check(cx, expr, recv, map_arg, map_span);
                 },
-                ("map_or", [def, map]) => {
-                    option_map_or_none::check(cx, expr, recv, def, map);
-                    manual_ok_or::check(cx, expr, recv, def, map);
-                    option_map_or_err_ok::check(cx, expr, recv, def, map);
-                    unnecessary_map_or::check(cx, expr, recv, def, map, &self.msrv);
+                Some(("cloned", recv2, [], _, _)) => {
+                    iter_overeager_cloned::check(cx, expr, recv, recv2, iter_overeager_cloned::Op::LaterCloned, true);
                 },
-                ("map_or_else", [def, map]) => {
-                    result_map_or_else_none::check(cx, expr, recv, def, map);
-                    unnecessary_result_map_or_else::check(cx, expr, recv, def, map);
+                _ => {},
+            }
+        }
+    }
+
+    &["fold"];
+    fn check_fold(cx, call) {
+        let MethodCall {
+            expr,
+            args,
+            span,
+            call_span,
+            ..
+        } = call;
+        if let [init, acc] = args {
+            manual_try_fold::check(cx, expr, init, acc, call_span, &call.pass.msrv);
+            unnecessary_fold::check(cx, expr, init, acc, span);
+        }
+    }
+
+    &["for_each"];
+    fn check_for_each(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [arg] = args {
+            unused_enumerate_index::check(cx, expr, recv, arg);
+            match method_call(recv) {
+                Some(("inspect", _, [_], span2, _)) => inspect_for_each::check(cx, expr, span2),
+                Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(
+                    cx,
+                    expr,
+                    recv,
+                    recv2,
+                    iter_overeager_cloned::Op::NeedlessMove(arg),
+                    false,
+                ),
+                _ => {},
+            }
+        }
+    }
+
+    &["get"];
+    fn check_get(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [arg] = args {
+            get_first::check(cx, expr, recv, arg);
+            get_last_with_len::check(cx, expr, recv, arg);
+        }
+    }
+
+    &["get_or_insert_with"];
+    fn check_get_or_insert_with(cx, call) {
+        let MethodCall { expr, args, .. } = call;
+        if let [arg] = args {
+            unnecessary_lazy_eval::check(cx, expr, arg, "get_or_insert", call.recv_kind(cx));
+        }
+    }
+
+    unit_hash::TARGETS;
+    fn check_hash(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [arg] = args {
+            unit_hash::check(cx, expr, recv, arg);
+        }
+    }
+
+    &["is_empty"];
+    fn check_is_empty(cx, call) {
+        let MethodCall {
+            expr, recv, args, span, ..
+        } = call;
+        if args.is_empty() {
+            match method_call(recv) {
+                Some((prev_method @ ("as_bytes" | "bytes"), prev_recv, [], _, _)) => {
+                    needless_as_bytes::check(cx, prev_method, "is_empty", prev_recv, expr.span);
                 },
-                ("next", []) => {
-                    if let Some((name2, recv2, args2, _, _)) = method_call(recv) {
-                        match (name2, args2) {
-                            ("cloned", []) => iter_overeager_cloned::check(
-                                cx,
-                                expr,
-                                recv,
-                                recv2,
-                                iter_overeager_cloned::Op::LaterCloned,
-                                false,
-                            ),
-                            ("filter", [arg]) => filter_next::check(cx, expr, recv2, arg),
-                            ("filter_map", [arg]) => filter_map_next::check(cx, expr, recv2, arg, &self.msrv),
-                            ("iter", []) => iter_next_slice::check(cx, expr, recv2),
-                            ("skip", [arg]) => iter_skip_next::check(cx, expr, recv2, arg),
-                            ("skip_while", [_]) => skip_while_next::check(cx, expr),
-                            ("rev", []) => manual_next_back::check(cx, expr, recv, recv2),
-                            _ => {},
-                        }
-                    }
+                Some(("as_str", recv, [], as_str_span, _)) => {
+                    redundant_as_str::check(cx, expr, recv, as_str_span, span);
                 },
-                ("nth", [n_arg]) => match method_call(recv) {
-                    Some(("bytes", recv2, [], _, _)) => bytes_nth::check(cx, expr, recv2, n_arg),
+                _ => {},
+            }
+            is_empty::check(cx, expr, recv);
+        }
+    }
+
+    filetype_is_file::TARGETS;
+    fn check_is_file(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if args.is_empty() {
+            filetype_is_file::check(cx, expr, recv);
+        }
+    }
+
+    is_digit_ascii_radix::TARGETS;
+    fn check_is_digit(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [radix] = args {
+            is_digit_ascii_radix::check(cx, expr, recv, radix, &call.pass.msrv);
+        }
+    }
+
+    &["is_none"];
+    fn check_is_none(cx, call) {
+        let MethodCall {
+            expr,
+            recv,
+            args,
+            call_span,
+            ..
+        } = call;
+        if args.is_empty() {
+            check_is_some_is_none(cx, expr, recv, call_span, false);
+        }
+    }
+
+    &["is_some"];
+    fn check_is_some(cx, call) {
+        let MethodCall {
+            expr,
+            recv,
+            args,
+            call_span,
+            ..
+        } = call;
+        if args.is_empty() {
+            check_is_some_is_none(cx, expr, recv, call_span, true);
+        }
+    }
+
+    iter_on_single_or_empty_collections::TARGETS;
+    fn check_iter(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if args.is_empty() {
+            iter_on_single_or_empty_collections::check(cx, expr, name, recv);
+        }
+    }
+
+    &["join"];
+    fn check_join(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [join_arg] = args {
+            if let Some(("collect", _, _, span, _)) = method_call(recv) {
+                unnecessary_join::check(cx, expr, recv, join_arg, span);
+            } else {
+                join_absolute_paths::check(cx, recv, join_arg, expr.span);
+            }
+        }
+    }
+
+    &["last"];
+    fn check_last(cx, call) {
+        let MethodCall {
+            expr,
+            recv,
+            args,
+            call_span,
+            ..
+        } = call;
+        if args.is_empty() {
+            if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
+                iter_overeager_cloned::check(cx, expr, recv, recv2, iter_overeager_cloned::Op::LaterCloned, false);
+            }
+            double_ended_iterator_last::check(cx, expr, recv, call_span);
+        }
+    }
+
+    &["len"];
+    fn check_len(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if args.is_empty() {
+            if let Some((prev_method @ ("as_bytes" | "bytes"), prev_recv, [], _, _)) = method_call(recv) {
+                needless_as_bytes::check(cx, prev_method, "len", prev_recv, expr.span);
+            }
+        }
+    }
+
+    mut_mutex_lock::TARGETS;
+    fn check_lock(cx, call) {
+        let MethodCall {
+            expr, recv, args, span, ..
+        } = call;
+        if args.is_empty() {
+            mut_mutex_lock::check(cx, expr, recv, span);
+        }
+    }
+
+    &["map", "map_err"];
+    fn check_map(cx, call) {
+        let MethodCall {
+            expr,
+            name,
+            recv,
+            args,
+            span,
+            ..
+        } = call;
+        if let [m_arg] = args {
+            if name == "map" {
+                unused_enumerate_index::check(cx, expr, recv, m_arg);
+                map_clone::check(cx, expr, recv, m_arg, &call.pass.msrv);
+                explicit_deref_in_closure_for_copied::check_map(cx, expr, recv, m_arg, name, &call.pass.msrv);
+                map_with_unused_argument_over_ranges::check(cx, expr, recv, m_arg, &call.pass.msrv, span);
+                match method_call(recv) {
+                    Some((map_name @ ("iter" | "into_iter"), recv2, _, _, _)) => {
+                        iter_kv_map::check(cx, map_name, expr, recv2, m_arg, &call.pass.msrv);
+                    },
+                    Some(("position", recv2, [p_arg], span2, _)) => {
+                        iter_next_after_position::check_map(cx, expr, recv, recv2, p_arg, span2, m_arg);
+                    },
                     Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(
                         cx,
                         expr,
                         recv,
                         recv2,
-                        iter_overeager_cloned::Op::LaterCloned,
+                        iter_overeager_cloned::Op::NeedlessMove(m_arg),
                         false,
                     ),
-                    Some((iter_method @ ("iter" | "iter_mut"), iter_recv, [], iter_span, _)) => {
-                        if !iter_nth::check(cx, expr, iter_recv, iter_method, iter_span, span) {
-                            iter_nth_zero::check(cx, expr, recv, n_arg);
-                        }
+                    _ => {},
+                }
+            } else {
+                map_err_ignore::check(cx, expr, m_arg);
+            }
+            if let Some((name, recv2, args, span2, _)) = method_call(recv) {
+                match (name, args) {
+                    ("as_mut", []) => option_as_ref_deref::check(cx, expr, recv2, m_arg, true, &call.pass.msrv),
+                    ("as_ref", []) => option_as_ref_deref::check(cx, expr, recv2, m_arg, false, &call.pass.msrv),
+                    ("filter", [f_arg]) => {
+                        filter_map::check(cx, expr, recv2, f_arg, span2, recv, m_arg, span, false);
                     },
-                    _ => iter_nth_zero::check(cx, expr, recv, n_arg),
-                },
-                ("ok_or_else", [arg]) => unnecessary_lazy_eval::check(cx, expr, recv, arg, "ok_or"),
-                ("open", [_]) => {
-                    open_options::check(cx, expr, recv);
-                },
-                ("or_else", [arg]) => {
-                    if !bind_instead_of_map::check_or_else_err(cx, expr, recv, arg) {
-                        unnecessary_lazy_eval::check(cx, expr, recv, arg, "or");
-                    }
-                },
-                ("push", [arg]) => {
-                    path_buf_push_overwrite::check(cx, expr, arg);
-                },
-                ("read_to_end", [_]) => {
-                    verbose_file_reads::check(cx, expr, recv, verbose_file_reads::READ_TO_END_MSG);
-                },
-                ("read_to_string", [_]) => {
-                    verbose_file_reads::check(cx, expr, recv, verbose_file_reads::READ_TO_STRING_MSG);
-                },
-                ("read_line", [arg]) => {
-                    read_line_without_trim::check(cx, expr, recv, arg);
-                },
-                ("repeat", [arg]) => {
-                    repeat_once::check(cx, expr, recv, arg);
-                },
-                (name @ ("replace" | "replacen"), [arg1, arg2] | [arg1, arg2, _]) => {
-                    no_effect_replace::check(cx, expr, arg1, arg2);
+                    ("find", [f_arg]) => {
+                        filter_map::check(cx, expr, recv2, f_arg, span2, recv, m_arg, span, true);
+                    },
+                    _ => {},
+                }
+            }
+            map_identity::check(cx, expr, recv, m_arg, name, span);
+            manual_inspect::check(cx, expr, m_arg, name, span, &call.pass.msrv);
+            crate::useless_conversion::check_function_application(cx, expr, recv, m_arg);
+        }
+    }
 
-                    // Check for repeated `str::replace` calls to perform `collapsible_str_replace` lint
-                    if self.msrv.meets(msrvs::PATTERN_TRAIT_CHAR_ARRAY)
-                        && name == "replace"
-                        && let Some(("replace", ..)) = method_call(recv)
-                    {
-                        collapsible_str_replace::check(cx, expr, arg1, arg2);
-                    }
-                },
-                ("resize", [count_arg, default_arg]) => {
-                    vec_resize_to_zero::check(cx, expr, count_arg, default_arg, span);
-                },
-                ("seek", [arg]) => {
-                    if self.msrv.meets(msrvs::SEEK_FROM_CURRENT) {
-                        seek_from_current::check(cx, expr, recv, arg);
-                    }
-                    if self.msrv.meets(msrvs::SEEK_REWIND) {
-                        seek_to_start_instead_of_rewind::check(cx, expr, recv, arg, span);
-                    }
-                },
-                ("skip", [arg]) => {
-                    iter_skip_zero::check(cx, expr, arg);
-                    iter_out_of_bounds::check_skip(cx, expr, recv, arg);
+    &["map_break", "map_continue"];
+    fn check_map_break(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [m_arg] = args {
+            crate::useless_conversion::check_function_application(cx, expr, recv, m_arg);
+        }
+    }
 
-                    if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
-                        iter_overeager_cloned::check(
-                            cx,
-                            expr,
-                            recv,
-                            recv2,
-                            iter_overeager_cloned::Op::LaterCloned,
-                            false,
-                        );
-                    }
-                },
-                ("sort", []) => {
-                    stable_sort_primitive::check(cx, expr, recv);
-                },
-                ("sort_by", [arg]) => {
-                    unnecessary_sort_by::check(cx, expr, recv, arg, false);
-                },
-                ("sort_unstable_by", [arg]) => {
-                    unnecessary_sort_by::check(cx, expr, recv, arg, true);
-                },
-                ("split", [arg]) => {
-                    str_split::check(cx, expr, recv, arg);
-                },
-                ("splitn" | "rsplitn", [count_arg, pat_arg]) => {
-                    if let Some(Constant::Int(count)) = ConstEvalCtxt::new(cx).eval(count_arg) {
-                        suspicious_splitn::check(cx, name, expr, recv, count);
-                        str_splitn::check(cx, name, expr, recv, pat_arg, count, &self.msrv);
-                    }
-                },
-                ("splitn_mut" | "rsplitn_mut", [count_arg, _]) => {
-                    if let Some(Constant::Int(count)) = ConstEvalCtxt::new(cx).eval(count_arg) {
-                        suspicious_splitn::check(cx, name, expr, recv, count);
-                    }
-                },
-                ("step_by", [arg]) => iterator_step_by_zero::check(cx, expr, arg),
-                ("take", [arg]) => {
-                    iter_out_of_bounds::check_take(cx, expr, recv, arg);
-                    if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
-                        iter_overeager_cloned::check(
-                            cx,
-                            expr,
-                            recv,
-                            recv2,
-                            iter_overeager_cloned::Op::LaterCloned,
-                            false,
-                        );
-                    }
+    &["map_or"];
+    fn check_map_or(cx, call) {
+        let MethodCall {
+            expr,
+            recv,
+            args,
+            ..
+        } = call;
+        if let [def, map] = args {
+            option_map_or_none::check(cx, expr, recv, def, map, call.recv_kind(cx));
+            manual_ok_or::check(cx, expr, recv, def, map);
+            option_map_or_err_ok::check(cx, expr, recv, def, map, call.recv_kind(cx));
+            unnecessary_map_or::check(cx, expr, recv, def, map, &call.pass.msrv);
+        }
+    }
+
+    &["map_or_else"];
+    fn check_map_or_else(cx, call) {
+        let MethodCall {
+            expr,
+            recv,
+            args,
+            ..
+        } = call;
+        if let [def, map] = args {
+            result_map_or_else_none::check(cx, expr, recv, def, map, call.recv_kind(cx));
+            unnecessary_result_map_or_else::check(cx, expr, recv, def, map, call.recv_kind(cx));
+        }
+    }
+
+    &["next"];
+    fn check_next(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if args.is_empty() {
+            if let Some((name2, recv2, args2, _, _)) = method_call(recv) {
+                match (name2, args2) {
+                    ("cloned", []) => iter_overeager_cloned::check(
+                        cx,
+                        expr,
+                        recv,
+                        recv2,
+                        iter_overeager_cloned::Op::LaterCloned,
+                        false,
+                    ),
+                    ("filter", [arg]) => filter_next::check(cx, expr, recv2, arg),
+                    ("filter_map", [arg]) => filter_map_next::check(cx, expr, recv2, arg, &call.pass.msrv),
+                    ("iter", []) => iter_next_slice::check(cx, expr, recv2),
+                    ("skip", [arg]) => iter_skip_next::check(cx, expr, recv2, arg),
+                    ("skip_while", [_]) => skip_while_next::check(cx, expr),
+                    ("rev", []) => manual_next_back::check(cx, expr, recv, recv2),
+                    _ => {},
+                }
+            }
+        }
+    }
+
+    &["nth"];
+    fn check_nth(cx, call) {
+        let MethodCall {
+            expr, recv, args, span, ..
+        } = call;
+        if let [n_arg] = args {
+            match method_call(recv) {
+                Some(("bytes", recv2, [], _, _)) => bytes_nth::check(cx, expr, recv2, n_arg),
+                Some(("cloned", recv2, [], _, _)) => {
+                    iter_overeager_cloned::check(cx, expr, recv, recv2, iter_overeager_cloned::Op::LaterCloned, false);
                 },
-                ("take", []) => needless_option_take::check(cx, expr, recv),
-                ("then", [arg]) => {
-                    if !self.msrv.meets(msrvs::BOOL_THEN_SOME) {
-                        return;
+                Some((iter_method @ ("iter" | "iter_mut"), iter_recv, [], iter_span, _)) => {
+                    if !iter_nth::check(cx, expr, iter_recv, iter_method, iter_span, span) {
+                        iter_nth_zero::check(cx, expr, recv, n_arg);
                     }
-                    unnecessary_lazy_eval::check(cx, expr, recv, arg, "then_some");
-                },
-                ("try_into", []) if is_trait_method(cx, expr, sym::TryInto) => {
-                    unnecessary_fallible_conversions::check_method(cx, expr);
                 },
-                ("to_owned", []) => {
-                    if !suspicious_to_owned::check(cx, expr, recv) {
-                        implicit_clone::check(cx, name, expr, recv);
-                    }
+                _ => iter_nth_zero::check(cx, expr, recv, n_arg),
+            }
+        }
+    }
+
+    &["ok_or_else"];
+    fn check_ok_or_else(cx, call) {
+        let MethodCall { expr, args, .. } = call;
+        if let [arg] = args {
+            unnecessary_lazy_eval::check(cx, expr, arg, "ok_or", call.recv_kind(cx));
+        }
+    }
+
+    open_options::TARGETS;
+    fn check_open(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [_] = args {
+            open_options::check(cx, expr, recv);
+        }
+    }
+
+    &["or_else"];
+    fn check_or_else(cx, call) {
+        let MethodCall {
+            expr,
+            recv,
+            args,
+            ..
+        } = call;
+        if let [arg] = args {
+            if !bind_instead_of_map::check_or_else_err(cx, expr, recv, arg) {
+                unnecessary_lazy_eval::check(cx, expr, arg, "or", call.recv_kind(cx));
+            }
+        }
+    }
+
+    path_buf_push_overwrite::TARGETS;
+    fn check_push(cx, call) {
+        let MethodCall { expr, args, .. } = call;
+        if let [arg] = args {
+            path_buf_push_overwrite::check(cx, expr, arg);
+        }
+    }
+
+    verbose_file_reads::TARGETS;
+    fn check_read_to_end(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if let [_] = args {
+            let msg = if name == "read_to_end" {
+                verbose_file_reads::READ_TO_END_MSG
+            } else {
+                verbose_file_reads::READ_TO_STRING_MSG
+            };
+            verbose_file_reads::check(cx, expr, recv, msg);
+        }
+    }
+
+
+    read_line_without_trim::TARGETS;
+    fn check_read_line(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [arg] = args {
+            read_line_without_trim::check(cx, expr, recv, arg);
+        }
+    }
+
+    repeat_once::TARGETS;
+    fn check_repeat(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [arg] = args {
+            repeat_once::check(cx, expr, recv, arg);
+        }
+    }
+
+    &["replace", "replacen"];
+    fn check_replace(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if let [arg1, arg2] | [arg1, arg2, _] = args {
+            no_effect_replace::check(cx, expr, arg1, arg2);
+
+            // Check for repeated `str::replace` calls to perform `collapsible_str_replace` lint
+            if call.pass.msrv.meets(msrvs::PATTERN_TRAIT_CHAR_ARRAY)
+                && name == "replace"
+                && let Some(("replace", ..)) = method_call(recv)
+            {
+                collapsible_str_replace::check(cx, expr, arg1, arg2);
+            }
+        }
+    }
+
+    vec_resize_to_zero::TARGETS;
+    fn check_resize(cx, call) {
+        let MethodCall { expr, args, span, .. } = call;
+        if let [count_arg, default_arg] = args {
+            vec_resize_to_zero::check(cx, expr, count_arg, default_arg, span);
+        }
+    }
+
+    &["seek"];
+    fn check_seek(cx, call) {
+        let MethodCall {
+            expr, recv, args, span, ..
+        } = call;
+        if let [arg] = args {
+            if call.pass.msrv.meets(msrvs::SEEK_FROM_CURRENT) {
+                seek_from_current::check(cx, expr, recv, arg);
+            }
+            if call.pass.msrv.meets(msrvs::SEEK_REWIND) {
+                seek_to_start_instead_of_rewind::check(cx, expr, recv, arg, span);
+            }
+        }
+    }
+
+    &["skip"];
+    fn check_skip(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [arg] = args {
+            iter_skip_zero::check(cx, expr, arg);
+            iter_out_of_bounds::check_skip(cx, expr, recv, arg);
+
+            if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
+                iter_overeager_cloned::check(cx, expr, recv, recv2, iter_overeager_cloned::Op::LaterCloned, false);
+            }
+        }
+    }
+
+    stable_sort_primitive::TARGETS;
+    fn check_sort(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if args.is_empty() {
+            stable_sort_primitive::check(cx, expr, recv);
+        }
+    }
+
+    unnecessary_sort_by::TARGETS;
+    fn check_sort_by(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if let [arg] = args {
+            unnecessary_sort_by::check(cx, expr, recv, arg, name == "sort_unstable_by");
+        }
+    }
+
+
+    str_split::TARGETS;
+    fn check_split(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [arg] = args {
+            str_split::check(cx, expr, recv, arg);
+        }
+    }
+
+    &["splitn", "rsplitn"];
+    fn check_splitn(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if let [count_arg, pat_arg] = args {
+            if let Some(Constant::Int(count)) = ConstEvalCtxt::new(cx).eval(count_arg) {
+                suspicious_splitn::check(cx, name, expr, recv, count);
+                str_splitn::check(cx, name, expr, recv, pat_arg, count, &call.pass.msrv);
+            }
+        }
+    }
+
+    &["splitn_mut", "rsplitn_mut"];
+    fn check_splitn_mut(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if let [count_arg, _] = args {
+            if let Some(Constant::Int(count)) = ConstEvalCtxt::new(cx).eval(count_arg) {
+                suspicious_splitn::check(cx, name, expr, recv, count);
+            }
+        }
+    }
+
+    iterator_step_by_zero::TARGETS;
+    fn check_step_by(cx, call) {
+        let MethodCall { expr, args, .. } = call;
+        if let [arg] = args {
+            iterator_step_by_zero::check(cx, expr, arg);
+        }
+    }
+
+    &["take"];
+    fn check_take(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        match args {
+            [arg] => {
+                iter_out_of_bounds::check_take(cx, expr, recv, arg);
+                if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
+                    iter_overeager_cloned::check(cx, expr, recv, recv2, iter_overeager_cloned::Op::LaterCloned, false);
+                }
+            },
+            [] => {
+                needless_option_take::check(cx, expr, recv);
+            },
+            _ => {},
+        }
+    }
+
+    &["then"];
+    fn check_then(cx, call) {
+        let MethodCall { expr, args, .. } = call;
+        if let [arg] = args {
+            if !call.pass.msrv.meets(msrvs::BOOL_THEN_SOME) {
+                return;
+            }
+            unnecessary_lazy_eval::check(cx, expr, arg, "then_some", call.recv_kind(cx));
+        }
+    }
+
+    unnecessary_fallible_conversions::TARGETS;
+    fn check_try_into(cx, call) {
+        let MethodCall { expr, args, .. } = call;
+        if args.is_empty() && is_trait_method(cx, expr, sym::TryInto) {
+            unnecessary_fallible_conversions::check_method(cx, expr);
+        }
+    }
+
+    &["to_owned"];
+    fn check_to_owned(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if args.is_empty() && !suspicious_to_owned::check(cx, expr, recv) {
+            implicit_clone::check(cx, name, expr, recv);
+        }
+    }
+
+    &["to_os_string", "to_path_buf", "to_vec"];
+    fn check_to_os_string(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if args.is_empty() {
+            implicit_clone::check(cx, name, expr, recv);
+        }
+    }
+
+    type_id_on_box::TARGETS;
+    fn check_type_id(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if args.is_empty() {
+            type_id_on_box::check(cx, recv, expr.span);
+        }
+    }
+
+    &["unwrap"];
+    fn check_unwrap(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if args.is_empty() {
+            match method_call(recv) {
+                Some(("get", recv, [get_arg], _, _)) => {
+                    get_unwrap::check(cx, expr, recv, get_arg, false);
                 },
-                ("to_os_string" | "to_path_buf" | "to_vec", []) => {
-                    implicit_clone::check(cx, name, expr, recv);
+                Some(("get_mut", recv, [get_arg], _, _)) => {
+                    get_unwrap::check(cx, expr, recv, get_arg, true);
                 },
-                ("type_id", []) => {
-                    type_id_on_box::check(cx, recv, expr.span);
+                Some(("or", recv, [or_arg], or_span, _)) => {
+                    or_then_unwrap::check(cx, expr, recv, or_arg, or_span);
                 },
-                ("unwrap", []) => {
-                    match method_call(recv) {
-                        Some(("get", recv, [get_arg], _, _)) => {
-                            get_unwrap::check(cx, expr, recv, get_arg, false);
-                        },
-                        Some(("get_mut", recv, [get_arg], _, _)) => {
-                            get_unwrap::check(cx, expr, recv, get_arg, true);
-                        },
-                        Some(("or", recv, [or_arg], or_span, _)) => {
-                            or_then_unwrap::check(cx, expr, recv, or_arg, or_span);
-                        },
-                        _ => {},
-                    }
-                    unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
-                    unwrap_expect_used::check(
-                        cx,
-                        expr,
-                        recv,
-                        false,
-                        self.allow_unwrap_in_tests,
-                        unwrap_expect_used::Variant::Unwrap,
-                    );
+                Some(("ok", ok_recv, [], _, _)) => {
+                    manual_flatten_result_option::check_unwrap(cx, expr, ok_recv);
                 },
-                ("unwrap_err", []) => {
-                    unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
-                    unwrap_expect_used::check(
+                Some(("lock", lock_recv, [], lock_span, _)) => {
+                    mutex_in_struct_without_poison_handling::check(
                         cx,
                         expr,
                         recv,
-                        true,
-                        self.allow_unwrap_in_tests,
-                        unwrap_expect_used::Variant::Unwrap,
+                        lock_recv,
+                        lock_span,
+                        call.pass.allow_lock_unwrap_in_binaries,
                     );
                 },
-                ("unwrap_or", [u_arg]) => {
-                    match method_call(recv) {
-                        Some((arith @ ("checked_add" | "checked_sub" | "checked_mul"), lhs, [rhs], _, _)) => {
-                            manual_saturating_arithmetic::check(cx, expr, lhs, rhs, u_arg, &arith["checked_".len()..]);
-                        },
-                        Some(("map", m_recv, [m_arg], span, _)) => {
-                            option_map_unwrap_or::check(cx, expr, m_recv, m_arg, recv, u_arg, span, &self.msrv);
-                        },
-                        Some(("then_some", t_recv, [t_arg], _, _)) => {
-                            obfuscated_if_else::check(cx, expr, t_recv, t_arg, u_arg);
-                        },
-                        _ => {},
-                    }
-                    unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
-                },
-                ("unwrap_or_default", []) => {
-                    if let Some(("map", m_recv, [arg], span, _)) = method_call(recv) {
-                        manual_is_variant_and::check(cx, expr, m_recv, arg, span, &self.msrv);
-                    }
-                    unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
-                },
-                ("unwrap_unchecked" | "unwrap_err_unchecked", []) => {
-                    unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
-                },
-                ("unwrap_or_else", [u_arg]) => {
-                    match method_call(recv) {
-                        Some(("map", recv, [map_arg], _, _))
-                            if map_unwrap_or::check(cx, expr, recv, map_arg, u_arg, &self.msrv) => {},
-                        _ => {
-                            unnecessary_lazy_eval::check(cx, expr, recv, u_arg, "unwrap_or");
-                        },
-                    }
-                    unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
-                },
-                ("wake", []) => {
-                    waker_clone_wake::check(cx, expr, recv);
+                _ => {},
+            }
+            unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
+            unwrap_expect_used::check(
+                cx,
+                expr,
+                recv,
+                false,
+                call.pass.allow_unwrap_in_tests,
+                unwrap_expect_used::Variant::Unwrap,
+            );
+        }
+    }
+
+    &["unwrap_err"];
+    fn check_unwrap_err(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if args.is_empty() {
+            unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
+            unwrap_expect_used::check(
+                cx,
+                expr,
+                recv,
+                true,
+                call.pass.allow_unwrap_in_tests,
+                unwrap_expect_used::Variant::Unwrap,
+            );
+        }
+    }
+
+    &["unwrap_or"];
+    fn check_unwrap_or(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if let [u_arg] = args {
+            match method_call(recv) {
+                Some((arith @ ("checked_add" | "checked_sub" | "checked_mul"), lhs, [rhs], _, _)) => {
+                    manual_saturating_arithmetic::check(cx, expr, lhs, rhs, u_arg, &arith["checked_".len()..]);
                 },
-                ("write", []) => {
-                    readonly_write_lock::check(cx, expr, recv);
+                Some(("map", m_recv, [m_arg], span, _)) => {
+                    option_map_unwrap_or::check(cx, expr, m_recv, m_arg, recv, u_arg, span, &call.pass.msrv);
                 },
-                ("zip", [arg]) => {
-                    if let ExprKind::MethodCall(name, iter_recv, [], _) = recv.kind
-                        && name.ident.name == sym::iter
-                    {
-                        range_zip_with_len::check(cx, expr, iter_recv, arg);
-                    }
+                Some(("then_some", t_recv, [t_arg], _, _)) => {
+                    obfuscated_if_else::check(cx, expr, t_recv, t_arg, u_arg);
                 },
                 _ => {},
             }
+            unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
+        }
+    }
+
+    &["unwrap_or_default"];
+    fn check_unwrap_or_default(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if args.is_empty() {
+            if let Some(("map", m_recv, [arg], span, _)) = method_call(recv) {
+                manual_is_variant_and::check(cx, expr, m_recv, arg, span, &call.pass.msrv);
+            }
+            unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
+        }
+    }
+
+    &["unwrap_unchecked", "unwrap_err_unchecked"];
+    fn check_unwrap_unchecked(cx, call) {
+        let MethodCall {
+            expr, name, recv, args, ..
+        } = call;
+        if args.is_empty() {
+            unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
+        }
+    }
+
+    &["unwrap_or_else"];
+    fn check_unwrap_or_else(cx, call) {
+        let MethodCall {
+            expr,
+            name,
+            recv,
+            args,
+            ..
+        } = call;
+        if let [u_arg] = args {
+            match method_call(recv) {
+                Some(("map", recv, [map_arg], _, _))
+                    if map_unwrap_or::check(cx, expr, recv, map_arg, u_arg, &call.pass.msrv) => {},
+                _ => {
+                    unnecessary_lazy_eval::check(cx, expr, u_arg, "unwrap_or", call.recv_kind(cx));
+                },
+            }
+            unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
+        }
+    }
+
+    waker_clone_wake::TARGETS;
+    fn check_wake(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if args.is_empty() {
+            waker_clone_wake::check(cx, expr, recv);
+        }
+    }
+
+    readonly_write_lock::TARGETS;
+    fn check_write(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if args.is_empty() {
+            readonly_write_lock::check(cx, expr, recv);
+        }
+    }
+
+    &["zip"];
+    fn check_zip(cx, call) {
+        let MethodCall { expr, recv, args, .. } = call;
+        if let [arg] = args {
+            if let ExprKind::MethodCall(name, iter_recv, [], _) = recv.kind
+                && name.ident.name == sym::iter
+            {
+                range_zip_with_len::check(cx, expr, iter_recv, arg);
+            }
         }
     }
 }
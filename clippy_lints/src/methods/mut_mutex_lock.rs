@@ -8,6 +8,8 @@ use rustc_span::{Span, sym};
 
 use super::MUT_MUTEX_LOCK;
 
+pub(super) const TARGETS: &[&str] = &["lock"];
+
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, ex: &'tcx Expr<'tcx>, recv: &'tcx Expr<'tcx>, name_span: Span) {
     if matches!(expr_custom_deref_adjustment(cx, recv), None | Some(Mutability::Mut))
         && let (_, ref_depth, Mutability::Mut) = peel_mid_ty_refs_is_mutable(cx.typeck_results().expr_ty(recv))
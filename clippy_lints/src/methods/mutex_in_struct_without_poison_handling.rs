@@ -0,0 +1,37 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_session::config::CrateType;
+use rustc_span::{Span, sym};
+
+use super::MUTEX_IN_STRUCT_WITHOUT_POISON_HANDLING;
+
+/// Checks for `self.field.lock().unwrap()`, where `lock_recv` is `self.field` and `lock_call` is
+/// `self.field.lock()`.
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    lock_call: &Expr<'_>,
+    lock_recv: &Expr<'_>,
+    lock_span: Span,
+    allow_in_binaries: bool,
+) {
+    if let ExprKind::Field(_, field) = lock_recv.peel_borrows().kind
+        && let Some(method_id) = cx.typeck_results().type_dependent_def_id(lock_call.hir_id)
+        && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
+        && is_type_diagnostic_item(cx, cx.tcx.type_of(impl_id).instantiate_identity(), sym::Mutex)
+        && !(allow_in_binaries && cx.tcx.crate_types().contains(&CrateType::Executable))
+        && !expr.span.from_expansion()
+    {
+        span_lint_and_help(
+            cx,
+            MUTEX_IN_STRUCT_WITHOUT_POISON_HANDLING,
+            lock_span.with_hi(expr.span.hi()),
+            format!("calling `unwrap` on the lock of the mutex in field `{field}` panics if it is poisoned"),
+            None,
+            "handle the `PoisonError`, e.g. with `.unwrap_or_else(PoisonError::into_inner)`, \
+            or use a mutex which isn't poisoned, such as `parking_lot::Mutex`",
+        );
+    }
+}
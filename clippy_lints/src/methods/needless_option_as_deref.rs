@@ -11,6 +11,8 @@ use rustc_span::sym;
 
 use super::NEEDLESS_OPTION_AS_DEREF;
 
+pub(super) const TARGETS: &[&str] = &["as_deref", "as_deref_mut"];
+
 pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, name: &str) {
     let typeck = cx.typeck_results();
     let outer_ty = typeck.expr_ty(expr);
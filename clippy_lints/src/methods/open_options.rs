@@ -12,6 +12,8 @@ use rustc_span::{Span, sym};
 
 use super::{NONSENSICAL_OPEN_OPTIONS, SUSPICIOUS_OPEN_OPTIONS};
 
+pub(super) const TARGETS: &[&str] = &["open"];
+
 fn is_open_options(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
     is_type_diagnostic_item(cx, ty, sym::FsOpenOptions) || match_type(cx, ty, &paths::TOKIO_IO_OPEN_OPTIONS)
 }
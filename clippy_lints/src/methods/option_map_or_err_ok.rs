@@ -1,14 +1,13 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet;
-use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::{is_res_lang_ctor, path_res};
 use rustc_errors::Applicability;
 use rustc_hir::LangItem::{ResultErr, ResultOk};
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::LateContext;
-use rustc_span::symbol::sym;
 
 use super::OPTION_MAP_OR_ERR_OK;
+use super::utils::ReceiverKind;
 
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
@@ -16,9 +15,10 @@ pub(super) fn check<'tcx>(
     recv: &'tcx Expr<'_>,
     or_expr: &'tcx Expr<'_>,
     map_expr: &'tcx Expr<'_>,
+    recv_kind: ReceiverKind,
 ) {
     // We check that it's called on an `Option` type.
-    if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Option)
+    if recv_kind == ReceiverKind::Option
         // We check that first we pass an `Err`.
         && let ExprKind::Call(call, &[arg]) = or_expr.kind
         && is_res_lang_ctor(cx, path_res(cx, call), ResultErr)
@@ -9,6 +9,8 @@ use std::path::{Component, Path};
 
 use super::PATH_BUF_PUSH_OVERWRITE;
 
+pub(super) const TARGETS: &[&str] = &["push"];
+
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, arg: &'tcx Expr<'_>) {
     if let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
         && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
@@ -16,6 +16,8 @@ use rustc_span::sym;
 
 use super::READ_LINE_WITHOUT_TRIM;
 
+pub(super) const TARGETS: &[&str] = &["read_line"];
+
 fn expr_is_string_literal_without_trailing_newline(expr: &Expr<'_>) -> bool {
     if let ExprKind::Lit(lit) = expr.kind
         && let LitKind::Str(sym, _) = lit.node
@@ -1,5 +1,6 @@
-use clippy_utils::consts::{ConstEvalCtxt, Constant};
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::expr_or_init;
+use clippy_utils::rinterval::{Interval, IntervalCtxt};
 use clippy_utils::source::snippet;
 use clippy_utils::ty::is_type_lang_item;
 use rustc_errors::Applicability;
@@ -8,13 +9,15 @@ use rustc_lint::LateContext;
 
 use super::REPEAT_ONCE;
 
+pub(super) const TARGETS: &[&str] = &["repeat"];
+
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx Expr<'_>,
     recv: &'tcx Expr<'_>,
     repeat_arg: &'tcx Expr<'_>,
 ) {
-    if ConstEvalCtxt::new(cx).eval(repeat_arg) == Some(Constant::Int(1)) {
+    if IntervalCtxt::new(cx).eval(expr_or_init(cx, repeat_arg)) == Some(Interval::single(1)) {
         let ty = cx.typeck_results().expr_ty(recv).peel_refs();
         if ty.is_str() {
             span_lint_and_sugg(
@@ -1,14 +1,13 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet;
-use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::{is_res_lang_ctor, path_res, peel_blocks};
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_hir::LangItem::{OptionNone, OptionSome};
 use rustc_lint::LateContext;
-use rustc_span::symbol::sym;
 
 use super::RESULT_MAP_OR_INTO_OPTION;
+use super::utils::ReceiverKind;
 
 /// lint use of `_.map_or_else(|_| None, Some)` for `Result`s
 pub(super) fn check<'tcx>(
@@ -17,9 +16,10 @@ pub(super) fn check<'tcx>(
     recv: &'tcx hir::Expr<'_>,
     def_arg: &'tcx hir::Expr<'_>,
     map_arg: &'tcx hir::Expr<'_>,
+    recv_kind: ReceiverKind,
 ) {
     // lint if the caller of `map_or_else()` is a `Result`
-    if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Result)
+    if recv_kind == ReceiverKind::Result
         // We check that it is mapped as `Some`.
         && is_res_lang_ctor(cx, path_res(cx, map_arg), OptionSome)
         && let hir::ExprKind::Closure(&hir::Closure { body, .. }) = def_arg.kind
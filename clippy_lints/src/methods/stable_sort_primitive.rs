@@ -7,6 +7,8 @@ use rustc_lint::LateContext;
 
 use super::STABLE_SORT_PRIMITIVE;
 
+pub(super) const TARGETS: &[&str] = &["sort"];
+
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>, recv: &'tcx Expr<'_>) {
     if let Some(method_id) = cx.typeck_results().type_dependent_def_id(e.hir_id)
         && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
@@ -8,6 +8,8 @@ use rustc_lint::LateContext;
 
 use super::STR_SPLIT_AT_NEWLINE;
 
+pub(super) const TARGETS: &[&str] = &["split"];
+
 pub(super) fn check<'a>(cx: &LateContext<'a>, expr: &'_ Expr<'_>, split_recv: &'a Expr<'_>, split_arg: &'_ Expr<'_>) {
     // We're looking for `A.trim().split(B)`, where the adjusted type of `A` is `&str` (e.g. an
     // expression returning `String`), and `B` is a `Pattern` that hard-codes a newline (either `"\n"`
@@ -2,6 +2,7 @@ use clippy_utils::consts::{ConstEvalCtxt, Constant};
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::msrvs::{self, Msrv};
 use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::usage::local_used_after_expr;
 use clippy_utils::visitors::{Descend, for_each_expr};
 use clippy_utils::{is_diag_item_method, match_def_path, path_to_local_id, paths};
@@ -14,7 +15,7 @@ use rustc_lint::LateContext;
 use rustc_middle::ty;
 use rustc_span::{Span, Symbol, SyntaxContext, sym};
 
-use super::{MANUAL_SPLIT_ONCE, NEEDLESS_SPLITN};
+use super::{MANUAL_SPLIT_ONCE, NEEDLESS_SPLITN, SPLIT_THEN_INDEX};
 
 pub(super) fn check(
     cx: &LateContext<'_>,
@@ -45,6 +46,68 @@ pub(super) fn check(
     }
 }
 
+/// Checks for `s.split(pat).collect::<Vec<_>>()[n]`.
+pub(super) fn check_collect_index(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    base: &Expr<'_>,
+    index: &Expr<'_>,
+    msrv: &Msrv,
+) {
+    if let ExprKind::MethodCall(collect, split, [], _) = base.kind
+        && collect.ident.as_str() == "collect"
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(base), sym::Vec)
+        && let ExprKind::MethodCall(split_method, split_recv, split_args, _) = split.kind
+        && matches!(
+            split_method.ident.as_str(),
+            "split"
+                | "rsplit"
+                | "splitn"
+                | "rsplitn"
+                | "split_terminator"
+                | "split_whitespace"
+                | "split_ascii_whitespace"
+        )
+        && cx.typeck_results().expr_ty_adjusted(split_recv).peel_refs().is_str()
+        && !expr.span.from_expansion()
+        && let Some(Constant::Int(n)) = ConstEvalCtxt::new(cx).eval(index)
+    {
+        let mut app = Applicability::MachineApplicable;
+        let ctxt = expr.span.ctxt();
+        let method_name = split_method.ident.as_str();
+        let sugg = match split_args {
+            // The second of at most two pieces is what `split_once` returns.
+            [count, pat]
+                if n == 1
+                    && msrv.meets(msrvs::STR_SPLIT_ONCE)
+                    && ConstEvalCtxt::new(cx).eval(count) == Some(Constant::Int(2)) =>
+            {
+                let (r, field) = if method_name == "splitn" { ("", 1) } else { ("r", 0) };
+                let recv = snippet_with_context(cx, split_recv.span, ctxt, "..", &mut app).0;
+                let pat = snippet_with_context(cx, pat.span, ctxt, "..", &mut app).0;
+                format!("{recv}.{r}split_once({pat}).unwrap().{field}")
+            },
+            _ => {
+                let split = snippet_with_context(cx, split.span, ctxt, "..", &mut app).0;
+                if n == 0 {
+                    format!("{split}.next().unwrap()")
+                } else {
+                    format!("{split}.nth({n}).unwrap()")
+                }
+            },
+        };
+        span_lint_and_sugg(
+            cx,
+            SPLIT_THEN_INDEX,
+            expr.span,
+            "collecting the split pieces into a `Vec` only to index into it",
+            "take the piece from the iterator instead",
+            sugg,
+            app,
+        );
+    }
+}
+
 fn lint_needless(cx: &LateContext<'_>, method_name: &str, expr: &Expr<'_>, self_arg: &Expr<'_>, pat_arg: &Expr<'_>) {
     let mut app = Applicability::MachineApplicable;
     let r = if method_name == "splitn" { "" } else { "r" };
@@ -0,0 +1,153 @@
+use clippy_utils::consts::{ConstEvalCtxt, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::sugg::Sugg;
+use rustc_errors::Applicability;
+use rustc_hir::{BorrowKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+
+use super::{STR_TO_LOWERCASE_CMP, method_call};
+
+pub(super) const TARGETS: &[&str] = &["contains"];
+
+/// Checks `a.to_lowercase() == b.to_lowercase()` and the same with `to_uppercase` and `!=`.
+pub(super) fn check_cmp<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    lhs: &'tcx Expr<'tcx>,
+    rhs: &'tcx Expr<'tcx>,
+    eq: bool,
+) {
+    if let Some((left, method, _)) = case_conversion(cx, lhs)
+        && let Some((right, right_method, _)) = case_conversion(cx, rhs)
+        && method == right_method
+        && let ascii = are_ascii(cx, left, right)
+        && ascii != Some(false)
+    {
+        span_lint_and_then(
+            cx,
+            STR_TO_LOWERCASE_CMP,
+            expr.span,
+            format!("case-insensitive comparison using `{method}`"),
+            |diag| {
+                let mut applicability = if ascii == Some(true) {
+                    Applicability::MachineApplicable
+                } else {
+                    diag.note(format!(
+                        "`{method}` allocates a new string for both sides of the comparison"
+                    ));
+                    diag.help("if the strings may contain other characters, consider the `unicase` crate");
+                    Applicability::MaybeIncorrect
+                };
+                let ctxt = expr.span.ctxt();
+                let left = Sugg::hir_with_context(cx, left, ctxt, "..", &mut applicability).maybe_par();
+                let right_sugg = Sugg::hir_with_context(cx, right, ctxt, "..", &mut applicability);
+                // `eq_ignore_ascii_case` takes a `&str`
+                let right = if cx.typeck_results().expr_ty(right).is_ref() {
+                    right_sugg
+                } else {
+                    right_sugg.addr()
+                };
+                diag.span_suggestion(
+                    expr.span,
+                    if ascii == Some(true) {
+                        "both strings are ASCII, use `eq_ignore_ascii_case`"
+                    } else {
+                        "if both strings are ASCII, use `eq_ignore_ascii_case`"
+                    },
+                    format!("{}{left}.eq_ignore_ascii_case({right})", if eq { "" } else { "!" }),
+                    applicability,
+                );
+            },
+        );
+    }
+}
+
+/// Checks `a.to_lowercase().contains(&b.to_lowercase())` and the same with `to_uppercase`.
+pub(super) fn check_contains<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    recv: &'tcx Expr<'tcx>,
+    arg: &'tcx Expr<'tcx>,
+) {
+    if let Some((haystack, method, haystack_method_span)) = case_conversion(cx, recv)
+        && let Some((needle, needle_method, needle_method_span)) = case_conversion(cx, peel_str_ref(arg))
+        && method == needle_method
+        && let ascii = are_ascii(cx, haystack, needle)
+        && ascii != Some(false)
+    {
+        span_lint_and_then(
+            cx,
+            STR_TO_LOWERCASE_CMP,
+            expr.span,
+            format!("case-insensitive search using `{method}`"),
+            |diag| {
+                let ascii_method = if method == "to_lowercase" {
+                    "to_ascii_lowercase"
+                } else {
+                    "to_ascii_uppercase"
+                };
+                let (msg, applicability) = if ascii == Some(true) {
+                    (
+                        format!("both strings are ASCII, use `{ascii_method}`"),
+                        Applicability::MachineApplicable,
+                    )
+                } else {
+                    (
+                        format!("if both strings are ASCII, use `{ascii_method}`"),
+                        Applicability::MaybeIncorrect,
+                    )
+                };
+                diag.multipart_suggestion(
+                    msg,
+                    vec![
+                        (haystack_method_span, ascii_method.to_string()),
+                        (needle_method_span, ascii_method.to_string()),
+                    ],
+                    applicability,
+                );
+            },
+        );
+    }
+}
+
+/// Returns the receiver of `s.to_lowercase()` or `s.to_uppercase()` on a string, the name of the
+/// method and the span of the method name.
+fn case_conversion<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx str, Span)> {
+    if let Some((method @ ("to_lowercase" | "to_uppercase"), recv, [], method_span, _)) = method_call(expr)
+        && cx.typeck_results().expr_ty_adjusted(recv).peel_refs().is_str()
+    {
+        Some((recv, method, method_span))
+    } else {
+        None
+    }
+}
+
+/// Peels `&s` and `s.as_str()`.
+fn peel_str_ref<'tcx>(expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    if let ExprKind::AddrOf(BorrowKind::Ref, _, inner) = expr.kind {
+        inner
+    } else if let Some(("as_str", recv, [], _, _)) = method_call(expr) {
+        recv
+    } else {
+        expr
+    }
+}
+
+/// Whether both expressions are strings which only contain ASCII characters: `Some(true)` if
+/// both are string literals or constants which do, `Some(false)` if one of them is a string
+/// literal or constant which doesn't, and `None` if it isn't known.
+fn are_ascii(cx: &LateContext<'_>, a: &Expr<'_>, b: &Expr<'_>) -> Option<bool> {
+    let is_ascii = |expr: &Expr<'_>| match ConstEvalCtxt::new(cx).eval(expr) {
+        Some(Constant::Str(s)) => Some(s.is_ascii()),
+        _ => None,
+    };
+    match (is_ascii(a), is_ascii(b)) {
+        (Some(false), _) | (_, Some(false)) => Some(false),
+        (Some(true), Some(true)) => Some(true),
+        _ => None,
+    }
+}
@@ -9,6 +9,8 @@ use rustc_middle::ty::print::with_forced_trimmed_paths;
 use rustc_middle::ty::{self, ExistentialPredicate, Ty};
 use rustc_span::{Span, sym};
 
+pub(super) const TARGETS: &[&str] = &["type_id"];
+
 /// Checks if the given type is `dyn Any`, or a trait object that has `Any` as a supertrait.
 /// Only in those cases will its vtable have a `type_id` method that returns the implementor's
 /// `TypeId`, and only in those cases can we give a proper suggestion to dereference the box.
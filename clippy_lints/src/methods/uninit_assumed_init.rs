@@ -7,6 +7,8 @@ use rustc_span::sym;
 
 use super::UNINIT_ASSUMED_INIT;
 
+pub(super) const TARGETS: &[&str] = &["assume_init"];
+
 /// lint for `MaybeUninit::uninit().assume_init()` (we already have the latter)
 pub(super) fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>) {
     if let hir::ExprKind::Call(callee, []) = recv.kind
@@ -8,6 +8,8 @@ use rustc_span::sym;
 
 use super::UNIT_HASH;
 
+pub(super) const TARGETS: &[&str] = &["hash"];
+
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>, arg: &'tcx Expr<'_>) {
     if is_trait_method(cx, expr, sym::Hash) && cx.typeck_results().expr_ty(recv).is_unit() {
         span_lint_and_then(
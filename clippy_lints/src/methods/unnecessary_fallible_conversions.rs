@@ -10,6 +10,8 @@ use rustc_span::{Span, sym};
 
 use super::UNNECESSARY_FALLIBLE_CONVERSIONS;
 
+pub(super) const TARGETS: &[&str] = &["try_into"];
+
 #[derive(Copy, Clone)]
 enum SpansKind {
     TraitFn { trait_span: Span, fn_span: Span },
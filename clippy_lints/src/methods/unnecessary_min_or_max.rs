@@ -11,6 +11,8 @@ use rustc_lint::LateContext;
 use rustc_middle::ty;
 use rustc_span::{Span, sym};
 
+pub(super) const TARGETS: &[&str] = &["min", "max"];
+
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx Expr<'_>,
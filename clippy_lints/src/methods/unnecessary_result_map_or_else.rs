@@ -1,15 +1,13 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::peel_blocks;
 use clippy_utils::source::snippet;
-use clippy_utils::ty::is_type_diagnostic_item;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_hir::{Closure, Expr, ExprKind, HirId, QPath};
 use rustc_lint::LateContext;
-use rustc_span::symbol::sym;
 
 use super::UNNECESSARY_RESULT_MAP_OR_ELSE;
-use super::utils::get_last_chain_binding_hir_id;
+use super::utils::{ReceiverKind, get_last_chain_binding_hir_id};
 
 fn emit_lint(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, def_arg: &Expr<'_>) {
     let msg = "unused \"map closure\" when calling `Result::map_or_else` value";
@@ -49,9 +47,10 @@ pub(super) fn check<'tcx>(
     recv: &'tcx Expr<'_>,
     def_arg: &'tcx Expr<'_>,
     map_arg: &'tcx Expr<'_>,
+    recv_kind: ReceiverKind,
 ) {
     // lint if the caller of `map_or_else()` is a `Result`
-    if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Result)
+    if recv_kind == ReceiverKind::Result
         && let ExprKind::Closure(&Closure { body, .. }) = map_arg.kind
         && let body = cx.tcx.hir().body(body)
         && let Some(first_param) = body.params.first()
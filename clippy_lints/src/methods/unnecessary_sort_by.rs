@@ -13,6 +13,8 @@ use std::iter;
 
 use super::UNNECESSARY_SORT_BY;
 
+pub(super) const TARGETS: &[&str] = &["sort_by", "sort_unstable_by"];
+
 enum LintTrigger {
     Sort(SortDetection),
     SortByKey(SortByKeyDetection),
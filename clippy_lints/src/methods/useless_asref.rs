@@ -15,6 +15,8 @@ use core::ops::ControlFlow;
 
 use super::USELESS_ASREF;
 
+pub(super) const TARGETS: &[&str] = &["as_mut", "as_ref"];
+
 /// Returns the first type inside the `Option`/`Result` type passed as argument.
 fn get_enum_ty(enum_ty: Ty<'_>) -> Option<Ty<'_>> {
     struct ContainsTyVisitor {
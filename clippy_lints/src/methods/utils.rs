@@ -8,6 +8,37 @@ use rustc_middle::ty::{self, Ty};
 use rustc_span::Span;
 use rustc_span::symbol::sym;
 
+/// A coarse classification of the type of a method call's receiver.
+///
+/// It's computed at most once per method call handled by `check_methods`, and only if one of the
+/// checks of the call needs it. This covers the receivers of the checks which are dispatched on
+/// method names shared by `Option`, `Result` and `bool`, such as `map_or` or `unwrap_or`; checks
+/// which look at the receiver of an earlier call of the method chain classify it themselves. Note
+/// that the receiver type is taken as is, i.e. before any autoderef.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum ReceiverKind {
+    Option,
+    Result,
+    Bool,
+    Other,
+}
+
+impl ReceiverKind {
+    /// Classifies the receiver `recv` of a method call.
+    pub(super) fn of(cx: &LateContext<'_>, recv: &Expr<'_>) -> Self {
+        let ty = cx.typeck_results().expr_ty(recv);
+        match ty.kind() {
+            ty::Bool => Self::Bool,
+            ty::Adt(adt, _) => match cx.tcx.get_diagnostic_name(adt.did()) {
+                Some(sym::Option) => Self::Option,
+                Some(sym::Result) => Self::Result,
+                _ => Self::Other,
+            },
+            _ => Self::Other,
+        }
+    }
+}
+
 pub(super) fn derefs_to_slice<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx Expr<'tcx>,
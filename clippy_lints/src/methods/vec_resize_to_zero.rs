@@ -10,6 +10,8 @@ use rustc_span::{Span, sym};
 
 use super::VEC_RESIZE_TO_ZERO;
 
+pub(super) const TARGETS: &[&str] = &["resize"];
+
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx Expr<'_>,
@@ -7,6 +7,8 @@ use rustc_span::sym;
 
 use super::VERBOSE_FILE_READS;
 
+pub(super) const TARGETS: &[&str] = &["read_to_end", "read_to_string"];
+
 pub(super) const READ_TO_END_MSG: (&str, &str) = ("use of `File::read_to_end`", "consider using `fs::read` instead");
 pub(super) const READ_TO_STRING_MSG: (&str, &str) = (
     "use of `File::read_to_string`",
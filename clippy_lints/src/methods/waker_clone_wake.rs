@@ -8,6 +8,8 @@ use rustc_span::sym;
 
 use super::WAKER_CLONE_WAKE;
 
+pub(super) const TARGETS: &[&str] = &["wake"];
+
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>) {
     let ty = cx.typeck_results().expr_ty(recv);
 
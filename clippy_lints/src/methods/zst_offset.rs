@@ -5,6 +5,15 @@ use rustc_middle::ty;
 
 use super::ZST_OFFSET;
 
+pub(super) const TARGETS: &[&str] = &[
+    "add",
+    "offset",
+    "sub",
+    "wrapping_offset",
+    "wrapping_add",
+    "wrapping_sub",
+];
+
 pub(super) fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>) {
     if let ty::RawPtr(ty, _) = cx.typeck_results().expr_ty(recv).kind()
         && let Ok(layout) = cx.tcx.layout_of(cx.typing_env().as_query_input(*ty))
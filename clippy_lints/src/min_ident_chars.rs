@@ -41,14 +41,14 @@ declare_clippy_lint! {
 impl_lint_pass!(MinIdentChars => [MIN_IDENT_CHARS]);
 
 pub struct MinIdentChars {
-    allowed_idents_below_min_chars: FxHashSet<String>,
+    allowed_idents_below_min_chars: FxHashSet<&'static str>,
     min_ident_chars_threshold: u64,
 }
 
 impl MinIdentChars {
     pub fn new(conf: &'static Conf) -> Self {
         Self {
-            allowed_idents_below_min_chars: conf.allowed_idents_below_min_chars.iter().cloned().collect(),
+            allowed_idents_below_min_chars: conf.allowed_idents_below_min_chars.iter().map(|s| &**s).collect(),
             min_ident_chars_threshold: conf.min_ident_chars_threshold,
         }
     }
@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::diagnostics::{SuggestionAlternative, span_lint_and_then, suggest_alternatives};
 use rustc_errors::Applicability;
 use rustc_lint::EarlyContext;
 use rustc_span::Span;
@@ -13,21 +13,26 @@ pub(super) fn check(cx: &EarlyContext<'_>, lit_span: Span, lit_snip: &str) {
         lit_span,
         "this is a decimal constant",
         |diag| {
-            diag.span_suggestion(
-                lit_span,
+            let decimal = SuggestionAlternative::new(
+                "decimal",
                 "if you mean to use a decimal constant, remove the `0` to avoid confusion",
-                trimmed_lit_snip.to_string(),
-                Applicability::MaybeIncorrect,
+                trimmed_lit_snip,
             );
             // do not advise to use octal form if the literal cannot be expressed in base 8.
-            if !lit_snip.contains(['8', '9']) {
-                diag.span_suggestion(
-                    lit_span,
+            let octal = (!lit_snip.contains(['8', '9'])).then(|| {
+                SuggestionAlternative::new(
+                    "octal",
                     "if you mean to use an octal constant, use `0o`",
                     format!("0o{trimmed_lit_snip}"),
-                    Applicability::MaybeIncorrect,
-                );
-            }
+                )
+            });
+            suggest_alternatives(
+                diag,
+                ZERO_PREFIXED_LITERAL,
+                lit_span,
+                [decimal].into_iter().chain(octal),
+                Applicability::MaybeIncorrect,
+            );
         },
     );
 }
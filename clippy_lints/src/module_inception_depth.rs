@@ -0,0 +1,105 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_help;
+use rustc_hir::{Item, ItemKind, OwnerNode};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::impl_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for modules which are nested in more modules of the same name than configured by
+    /// `max-module-inception-depth`, e.g. `a::a::a`, and for modules which only contain a single
+    /// `pub use` re-export.
+    ///
+    /// ### Why is this bad?
+    /// Repeating the module name makes paths longer without adding any information. A module
+    /// which only re-exports a single item adds a level to the path of that item, it can be
+    /// re-exported from the parent module instead.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// mod a {
+    ///     mod a {
+    ///         mod a {
+    ///             pub struct S;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// mod wrapper {
+    ///     pub use std::collections::HashMap;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// mod a {
+    ///     pub struct S;
+    /// }
+    ///
+    /// pub use std::collections::HashMap;
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub MODULE_INCEPTION_DEPTH,
+    pedantic,
+    "modules nested in modules of the same name, or which only contain a single re-export"
+}
+
+impl_lint_pass!(ModuleInceptionDepth => [MODULE_INCEPTION_DEPTH]);
+
+pub struct ModuleInceptionDepth {
+    max_depth: u64,
+    avoid_breaking_exported_api: bool,
+}
+
+impl ModuleInceptionDepth {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            max_depth: conf.max_module_inception_depth,
+            avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+        }
+    }
+}
+
+impl LateLintPass<'_> for ModuleInceptionDepth {
+    fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
+        if let ItemKind::Mod(module) = item.kind
+            && !item.span.from_expansion()
+            && !(self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(item.owner_id.def_id))
+        {
+            let name = item.ident.name;
+            let depth = cx
+                .tcx
+                .hir()
+                .parent_owner_iter(item.hir_id())
+                .take_while(|(_, node)| {
+                    matches!(node, OwnerNode::Item(Item { ident, kind: ItemKind::Mod(_), span, .. })
+                        if ident.name == name && !span.from_expansion())
+                })
+                .count() as u64
+                + 1;
+            if depth > self.max_depth {
+                span_lint_and_help(
+                    cx,
+                    MODULE_INCEPTION_DEPTH,
+                    item.span,
+                    format!("this module is the last of {depth} nested modules with the same name"),
+                    None,
+                    "flatten the nested modules",
+                );
+            } else if let [reexport_id] = module.item_ids
+                && let reexport = cx.tcx.hir().item(*reexport_id)
+                && let ItemKind::Use(..) = reexport.kind
+                && cx.tcx.visibility(reexport.owner_id.def_id).is_public()
+                && !reexport.span.from_expansion()
+            {
+                span_lint_and_help(
+                    cx,
+                    MODULE_INCEPTION_DEPTH,
+                    item.span,
+                    "this module only contains a single re-export",
+                    Some(reexport.span),
+                    "consider re-exporting the item from the parent module instead",
+                );
+            }
+        }
+    }
+}
@@ -1,11 +1,14 @@
+use clippy_config::Conf;
 use clippy_utils::diagnostics::{span_lint_hir, span_lint_hir_and_then};
 use clippy_utils::source::SpanRangeExt;
-use clippy_utils::ty::has_drop;
+use clippy_utils::ty::{deref_chain, has_drop, is_must_use_ty};
 use clippy_utils::{
-    in_automatically_derived, is_inside_always_const_context, is_lint_allowed, path_to_local, peel_blocks,
+    def_path_def_ids, in_automatically_derived, is_inside_always_const_context, is_lint_allowed, path_to_local,
+    peel_blocks,
 };
 use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{CrateNum, DefIdSet};
 use rustc_hir::{
     BinOpKind, BlockCheckMode, Expr, ExprKind, HirId, HirIdMap, ItemKind, LocalSource, Node, PatKind, Stmt, StmtKind,
     StructTailExpr, UnsafeSource, is_range_literal,
@@ -13,9 +16,12 @@ use rustc_hir::{
 use rustc_infer::infer::TyCtxtInferExt as _;
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_middle::ty::{Instance, Ty, TyCtxt};
 use rustc_session::impl_lint_pass;
-use rustc_span::Span;
+use rustc_span::{Span, sym};
 use rustc_trait_selection::error_reporting::InferCtxtErrorExt;
+use std::mem;
 use std::ops::Deref;
 
 declare_clippy_lint! {
@@ -27,6 +33,12 @@ declare_clippy_lint! {
     /// executed. However, as they have no effect, all they do is make the code less
     /// readable.
     ///
+    /// Overloaded operators and `Deref` implementations may have side effects, so statements
+    /// using them are only linted if the implementation is a `const fn`, comes from the standard
+    /// library or is for one of the types of the `pure-operator-types` configuration. Statements
+    /// whose value is `#[must_use]`, or dereferences to a `#[must_use]` type, aren't linted either
+    /// as the value was most likely meant to be used.
+    ///
     /// ### Example
     /// ```no_run
     /// 0;
@@ -75,10 +87,10 @@ declare_clippy_lint! {
     "outer expressions with no effect"
 }
 
-#[derive(Default)]
 pub struct NoEffect {
     underscore_bindings: HirIdMap<Span>,
     local_bindings: Vec<Vec<HirId>>,
+    pure_operator_types: DefIdSet,
 }
 
 impl_lint_pass!(NoEffect => [NO_EFFECT, UNNECESSARY_OPERATION, NO_EFFECT_UNDERSCORE_BINDING]);
@@ -88,7 +100,7 @@ impl<'tcx> LateLintPass<'tcx> for NoEffect {
         if self.check_no_effect(cx, stmt) {
             return;
         }
-        check_unnecessary_operation(cx, stmt);
+        check_unnecessary_operation(cx, stmt, &self.pure_operator_types);
     }
 
     fn check_block(&mut self, _: &LateContext<'tcx>, _: &'tcx rustc_hir::Block<'tcx>) {
@@ -117,6 +129,18 @@ impl<'tcx> LateLintPass<'tcx> for NoEffect {
 }
 
 impl NoEffect {
+    pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
+        Self {
+            underscore_bindings: HirIdMap::default(),
+            local_bindings: Vec::new(),
+            pure_operator_types: conf
+                .pure_operator_types
+                .iter()
+                .flat_map(|path| def_path_def_ids(tcx, &path.split("::").collect::<Vec<_>>()))
+                .collect(),
+        }
+    }
+
     fn check_no_effect(&mut self, cx: &LateContext<'_>, stmt: &Stmt<'_>) -> bool {
         if let StmtKind::Semi(expr) = stmt.kind {
             // Covered by rustc `path_statements` lint
@@ -129,12 +153,14 @@ impl NoEffect {
             }
             let expr = peel_blocks(expr);
 
-            if is_operator_overridden(cx, expr) {
+            if is_impure_operator(cx, expr, &self.pure_operator_types)
+                || is_must_use_through_deref(cx, cx.typeck_results().expr_ty(expr))
+            {
                 // Return `true`, to prevent `check_unnecessary_operation` from
                 // linting on this statement as well.
                 return true;
             }
-            if has_no_effect(cx, expr) {
+            if has_no_effect(cx, expr, &self.pure_operator_types) {
                 span_lint_hir_and_then(
                     cx,
                     NO_EFFECT,
@@ -189,7 +215,7 @@ impl NoEffect {
                 && let Some(init) = local.init
                 && local.els.is_none()
                 && !local.pat.span.from_expansion()
-                && has_no_effect(cx, init)
+                && has_no_effect(cx, init, &self.pure_operator_types)
                 && let PatKind::Binding(_, hir_id, ident, _) = local.pat.kind
                 && ident.name.to_ident_string().starts_with('_')
                 && !in_automatically_derived(cx.tcx, local.hir_id)
@@ -205,42 +231,84 @@ impl NoEffect {
     }
 }
 
-fn is_operator_overridden(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
-    // It's very hard or impossible to check whether overridden operator have side-effect this lint.
-    // So, this function assume user-defined operator is overridden with an side-effect.
-    // The definition of user-defined structure here is ADT-type,
-    // Althrough this will weaken the ability of this lint, less error lint-fix happen.
+/// Whether `expr` is an overloaded operator whose implementation isn't known to be free of side
+/// effects, i.e. it's not a `const fn`, doesn't come from the standard library and isn't for one
+/// of the `pure_types`.
+fn is_impure_operator(cx: &LateContext<'_>, expr: &Expr<'_>, pure_types: &DefIdSet) -> bool {
+    // reference: rust/compiler/rustc_middle/src/ty/typeck_results.rs: `is_method_call`.
+    // use this function to check whether operator is overridden in `ExprKind::{Binary, Unary, Index}`.
     match expr.kind {
-        ExprKind::Binary(..) | ExprKind::Unary(..) => {
-            // No need to check type of `lhs` and `rhs`
-            // because if the operator is overridden, at least one operand is ADT type
-
-            // reference: rust/compiler/rustc_middle/src/ty/typeck_results.rs: `is_method_call`.
-            // use this function to check whether operator is overridden in `ExprKind::{Binary, Unary}`.
-            cx.typeck_results().is_method_call(expr)
-        },
-        _ => false,
+        ExprKind::Binary(..) | ExprKind::Unary(..) | ExprKind::Index(..)
+            if cx.typeck_results().is_method_call(expr) => {},
+        _ => return false,
+    }
+    if let Some(method) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+        && let args = cx.typeck_results().node_args(expr.hir_id)
+        && let Ok(Some(instance)) = Instance::try_resolve(cx.tcx, cx.typing_env(), method, args)
+        && let Some(impl_id) = cx.tcx.impl_of_method(instance.def_id())
+    {
+        !(cx.tcx.is_const_fn(instance.def_id())
+            || is_std_crate(cx, impl_id.krate)
+            || is_pure_type(cx.tcx.type_of(impl_id).instantiate_identity(), pure_types))
+    } else {
+        true
     }
 }
 
-fn has_no_effect(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+/// Whether `expr` is implicitly dereferenced through a `Deref` implementation which isn't known
+/// to be free of side effects.
+fn has_impure_deref(cx: &LateContext<'_>, expr: &Expr<'_>, pure_types: &DefIdSet) -> bool {
+    let mut ty = cx.typeck_results().expr_ty(expr);
+    cx.typeck_results().expr_adjustments(expr).iter().any(|adjustment| {
+        let source = mem::replace(&mut ty, adjustment.target);
+        matches!(adjustment.kind, Adjust::Deref(Some(_)))
+            && !is_pure_type(source, pure_types)
+            && !source
+                .peel_refs()
+                .ty_adt_def()
+                .is_some_and(|adt| is_std_crate(cx, adt.did().krate))
+    })
+}
+
+fn is_pure_type(ty: Ty<'_>, pure_types: &DefIdSet) -> bool {
+    ty.peel_refs()
+        .ty_adt_def()
+        .is_some_and(|adt| pure_types.contains(&adt.did()))
+}
+
+fn is_std_crate(cx: &LateContext<'_>, krate: CrateNum) -> bool {
+    matches!(cx.tcx.crate_name(krate), sym::core | sym::alloc | sym::std)
+}
+
+/// Whether `ty` or any type it dereferences to is `#[must_use]`, in which case the value was most
+/// likely meant to be used rather than the statement to be removed.
+fn is_must_use_through_deref<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    deref_chain(cx, ty).any(|ty| is_must_use_ty(cx, ty))
+}
+
+fn has_no_effect(cx: &LateContext<'_>, expr: &Expr<'_>, pure_types: &DefIdSet) -> bool {
+    if is_impure_operator(cx, expr, pure_types) || has_impure_deref(cx, expr, pure_types) {
+        return false;
+    }
     match expr.kind {
         ExprKind::Lit(..) | ExprKind::Closure { .. } => true,
         ExprKind::Path(..) => !has_drop(cx, cx.typeck_results().expr_ty(expr)),
-        ExprKind::Index(a, b, _) | ExprKind::Binary(_, a, b) => has_no_effect(cx, a) && has_no_effect(cx, b),
-        ExprKind::Array(v) | ExprKind::Tup(v) => v.iter().all(|val| has_no_effect(cx, val)),
+        ExprKind::Index(a, b, _) | ExprKind::Binary(_, a, b) => {
+            has_no_effect(cx, a, pure_types) && has_no_effect(cx, b, pure_types)
+        },
+        ExprKind::Array(v) | ExprKind::Tup(v) => v.iter().all(|val| has_no_effect(cx, val, pure_types)),
         ExprKind::Repeat(inner, _)
         | ExprKind::Cast(inner, _)
         | ExprKind::Type(inner, _)
         | ExprKind::Unary(_, inner)
         | ExprKind::Field(inner, _)
-        | ExprKind::AddrOf(_, _, inner) => has_no_effect(cx, inner),
+        | ExprKind::AddrOf(_, _, inner) => has_no_effect(cx, inner, pure_types),
         ExprKind::Struct(_, fields, ref base) => {
             !has_drop(cx, cx.typeck_results().expr_ty(expr))
-                && fields.iter().all(|field| has_no_effect(cx, field.expr))
+                && fields.iter().all(|field| has_no_effect(cx, field.expr, pure_types))
                 && match &base {
                     StructTailExpr::None | StructTailExpr::DefaultFields(_) => true,
-                    StructTailExpr::Base(base) => has_no_effect(cx, base),
+                    StructTailExpr::Base(base) => has_no_effect(cx, base, pure_types),
                 }
         },
         ExprKind::Call(callee, args) => {
@@ -254,7 +322,8 @@ fn has_no_effect(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
                     Res::Def(DefKind::Struct | DefKind::Variant | DefKind::Ctor(..), ..)
                 );
                 if def_matched || is_range_literal(expr) {
-                    !has_drop(cx, cx.typeck_results().expr_ty(expr)) && args.iter().all(|arg| has_no_effect(cx, arg))
+                    !has_drop(cx, cx.typeck_results().expr_ty(expr))
+                        && args.iter().all(|arg| has_no_effect(cx, arg, pure_types))
                 } else {
                     false
                 }
@@ -266,12 +335,13 @@ fn has_no_effect(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
     }
 }
 
-fn check_unnecessary_operation(cx: &LateContext<'_>, stmt: &Stmt<'_>) {
+fn check_unnecessary_operation(cx: &LateContext<'_>, stmt: &Stmt<'_>, pure_types: &DefIdSet) {
     if let StmtKind::Semi(expr) = stmt.kind
         && !in_external_macro(cx.sess(), stmt.span)
         && let ctxt = stmt.span.ctxt()
         && expr.span.ctxt() == ctxt
-        && let Some(reduced) = reduce_expression(cx, expr)
+        && !is_must_use_through_deref(cx, cx.typeck_results().expr_ty(expr))
+        && let Some(reduced) = reduce_expression(cx, expr, pure_types)
         && reduced.iter().all(|e| e.span.ctxt() == ctxt)
     {
         if let ExprKind::Index(..) = &expr.kind {
@@ -325,11 +395,12 @@ fn check_unnecessary_operation(cx: &LateContext<'_>, stmt: &Stmt<'_>) {
     }
 }
 
-fn reduce_expression<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<Vec<&'a Expr<'a>>> {
-    if expr.span.from_expansion() {
+fn reduce_expression<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>, pure_types: &DefIdSet) -> Option<Vec<&'a Expr<'a>>> {
+    if expr.span.from_expansion() || is_impure_operator(cx, expr, pure_types) {
         return None;
     }
     match expr.kind {
+        ExprKind::Index(a, _, _) | ExprKind::Field(a, _) if has_impure_deref(cx, a, pure_types) => None,
         ExprKind::Index(a, b, _) => Some(vec![a, b]),
         ExprKind::Binary(ref binop, a, b) if binop.node != BinOpKind::And && binop.node != BinOpKind::Or => {
             Some(vec![a, b])
@@ -340,7 +411,7 @@ fn reduce_expression<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<Vec
         | ExprKind::Type(inner, _)
         | ExprKind::Unary(_, inner)
         | ExprKind::Field(inner, _)
-        | ExprKind::AddrOf(_, _, inner) => reduce_expression(cx, inner).or_else(|| Some(vec![inner])),
+        | ExprKind::AddrOf(_, _, inner) => reduce_expression(cx, inner, pure_types).or_else(|| Some(vec![inner])),
         ExprKind::Struct(_, fields, ref base) => {
             if has_drop(cx, cx.typeck_results().expr_ty(expr)) {
                 None
@@ -378,7 +449,7 @@ fn reduce_expression<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<Vec
                         BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided) => None,
                         BlockCheckMode::DefaultBlock => Some(vec![&**e]),
                         // in case of compiler-inserted signaling blocks
-                        BlockCheckMode::UnsafeBlock(_) => reduce_expression(cx, e),
+                        BlockCheckMode::UnsafeBlock(_) => reduce_expression(cx, e, pure_types),
                     }
                 })
             } else {
@@ -1,12 +1,14 @@
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
-use clippy_utils::ty::implements_trait;
-use clippy_utils::{is_from_proc_macro, is_res_lang_ctor, last_path_segment, path_res, std_or_core};
+use clippy_utils::ty::{get_adt_inherent_method, implements_trait};
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{is_from_proc_macro, is_res_lang_ctor, last_path_segment, path_res, path_to_local_id, std_or_core};
+use core::ops::ControlFlow;
 use rustc_errors::Applicability;
 use rustc_hir::def_id::LocalDefId;
-use rustc_hir::{Expr, ExprKind, ImplItem, ImplItemKind, LangItem, Node, UnOp};
+use rustc_hir::{Expr, ExprKind, ImplItem, ImplItemKind, LangItem, Node, Param, UnOp};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
-use rustc_middle::ty::EarlyBinder;
+use rustc_middle::ty::{EarlyBinder, Ty};
 use rustc_session::declare_lint_pass;
 use rustc_span::sym;
 use rustc_span::symbol::kw;
@@ -58,7 +60,11 @@ declare_clippy_lint! {
     /// ### Why is this bad?
     /// If both `PartialOrd` and `Ord` are implemented, they must agree. This is commonly done by
     /// wrapping the result of `cmp` in `Some` for `partial_cmp`. Not doing this may silently
-    /// introduce an error upon refactoring.
+    /// introduce an error upon refactoring, and any other implementation is likely to disagree
+    /// with `cmp` already, e.g. in how it orders the fields.
+    ///
+    /// The suggested replacement is `Some(self.cmp(other))`. It must not be applied if `cmp` itself
+    /// calls `partial_cmp`, as they would then call each other forever.
     ///
     /// ### Known issues
     /// Code that calls the `.into()` method instead will be flagged, despite `.into()` wrapping it
@@ -186,7 +192,7 @@ impl LateLintPass<'_> for NonCanonicalImpls {
 
             if block.stmts.is_empty()
                 && let Some(expr) = block.expr
-                && expr_is_cmp(cx, &expr.kind, impl_item, &mut needs_fully_qualified)
+                && expr_is_cmp(cx, &expr.kind, impl_item, body.params, &mut needs_fully_qualified)
             {
             }
             // Fix #12683, allow [`needless_return`] here
@@ -196,7 +202,7 @@ impl LateLintPass<'_> for NonCanonicalImpls {
                     kind: ExprKind::Ret(Some(Expr { kind: ret_kind, .. })),
                     ..
                 }) = stmt.kind
-                && expr_is_cmp(cx, ret_kind, impl_item, &mut needs_fully_qualified)
+                && expr_is_cmp(cx, ret_kind, impl_item, body.params, &mut needs_fully_qualified)
             {
             } else {
                 // If `Self` and `Rhs` are not the same type, bail. This makes creating a valid
@@ -219,6 +225,12 @@ impl LateLintPass<'_> for NonCanonicalImpls {
                         let Some(std_or_core) = std_or_core(cx) else {
                             return;
                         };
+                        // `self.cmp(other)` would call the inherent method
+                        let needs_fully_qualified = needs_fully_qualified
+                            || get_adt_inherent_method(cx, trait_impl.self_ty(), sym::cmp).is_some();
+                        if ord_cmp_calls_partial_cmp(cx, trait_impl.self_ty()) {
+                            diag.note("`cmp` calls `partial_cmp`, it needs to be implemented without it first");
+                        }
 
                         let suggs = match (other.pat.simple_ident(), needs_fully_qualified) {
                             (Some(other_ident), true) => vec![(
@@ -241,7 +253,7 @@ impl LateLintPass<'_> for NonCanonicalImpls {
                             ],
                         };
 
-                        diag.multipart_suggestion("change this to", suggs, Applicability::Unspecified);
+                        diag.multipart_suggestion("change this to", suggs, Applicability::MaybeIncorrect);
                     },
                 );
             }
@@ -249,11 +261,13 @@ impl LateLintPass<'_> for NonCanonicalImpls {
     }
 }
 
-/// Return true if `expr_kind` is a `cmp` call.
+/// Return true if `expr_kind` is `Some(self.cmp(other))`, with `self` and `other` being the
+/// parameters of `partial_cmp`.
 fn expr_is_cmp<'tcx>(
     cx: &LateContext<'tcx>,
     expr_kind: &'tcx ExprKind<'tcx>,
     impl_item: &ImplItem<'_>,
+    params: &[Param<'_>],
     needs_fully_qualified: &mut bool,
 ) -> bool {
     if let ExprKind::Call(
@@ -267,24 +281,35 @@ fn expr_is_cmp<'tcx>(
     {
         is_res_lang_ctor(cx, cx.qpath_res(some_path, *some_hir_id), LangItem::OptionSome)
             // Fix #11178, allow `Self::cmp(self, ..)` too
-            && self_cmp_call(cx, cmp_expr, impl_item.owner_id.def_id, needs_fully_qualified)
+            && self_cmp_call(cx, cmp_expr, impl_item.owner_id.def_id, params, needs_fully_qualified)
     } else {
         false
     }
 }
 
-/// Returns whether this is any of `self.cmp(..)`, `Self::cmp(self, ..)` or `Ord::cmp(self, ..)`.
+/// Returns whether this is any of `self.cmp(other)`, `Self::cmp(self, other)` or
+/// `Ord::cmp(self, other)`.
 fn self_cmp_call<'tcx>(
     cx: &LateContext<'tcx>,
     cmp_expr: &'tcx Expr<'tcx>,
     def_id: LocalDefId,
+    params: &[Param<'_>],
     needs_fully_qualified: &mut bool,
 ) -> bool {
+    let [self_param, other_param] = params else {
+        return false;
+    };
     match cmp_expr.kind {
-        ExprKind::Call(path, [_, _]) => path_res(cx, path)
-            .opt_def_id()
-            .is_some_and(|def_id| cx.tcx.is_diagnostic_item(sym::ord_cmp_method, def_id)),
-        ExprKind::MethodCall(_, _, [_other], ..) => {
+        ExprKind::Call(path, [this, other]) => {
+            path_to_local_id(this, self_param.pat.hir_id)
+                && path_to_local_id(other, other_param.pat.hir_id)
+                && path_res(cx, path)
+                    .opt_def_id()
+                    .is_some_and(|def_id| cx.tcx.is_diagnostic_item(sym::ord_cmp_method, def_id))
+        },
+        ExprKind::MethodCall(_, this, [other], ..)
+            if path_to_local_id(this, self_param.pat.hir_id) && path_to_local_id(other, other_param.pat.hir_id) =>
+        {
             // We can set this to true here no matter what as if it's a `MethodCall` and goes to the
             // `else` branch, it must be a method named `cmp` that isn't `Ord::cmp`
             *needs_fully_qualified = true;
@@ -300,3 +325,58 @@ fn self_cmp_call<'tcx>(
         _ => false,
     }
 }
+
+/// Whether the local implementation of `Ord::cmp` for `ty` calls `partial_cmp`. Replacing
+/// `partial_cmp` by a call to `cmp` would then result in infinite recursion.
+fn ord_cmp_calls_partial_cmp<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    let (Some(ord_def_id), Some(partial_ord_def_id), Some(adt)) = (
+        cx.tcx.get_diagnostic_item(sym::Ord),
+        cx.tcx.get_diagnostic_item(sym::PartialOrd),
+        ty.ty_adt_def(),
+    ) else {
+        return false;
+    };
+    let Some(impls) = cx.tcx.all_local_trait_impls(()).get(&ord_def_id) else {
+        return false;
+    };
+    impls
+        .iter()
+        .filter(|&&impl_id| {
+            cx.tcx
+                .type_of(impl_id)
+                .instantiate_identity()
+                .ty_adt_def()
+                .is_some_and(|impl_adt| impl_adt.did() == adt.did())
+        })
+        .flat_map(|&impl_id| cx.tcx.associated_items(impl_id).filter_by_name_unhygienic(sym::cmp))
+        .filter_map(|item| item.def_id.as_local())
+        .any(|cmp_id| {
+            let Some(body) = cx.tcx.hir().maybe_body_owned_by(cmp_id) else {
+                return false;
+            };
+            let typeck = cx.tcx.typeck(cmp_id);
+            for_each_expr(cx, body.value, |e| {
+                let callee = match e.kind {
+                    ExprKind::MethodCall(..) => typeck.type_dependent_def_id(e.hir_id),
+                    ExprKind::Path(ref qpath) => typeck.qpath_res(qpath, e.hir_id).opt_def_id(),
+                    _ => None,
+                };
+                // Only calls on the same type, comparing fields with `partial_cmp` is fine
+                if callee.is_some_and(|did| {
+                    cx.tcx.trait_of_item(did) == Some(partial_ord_def_id)
+                        && cx.tcx.item_name(did) == sym::partial_cmp
+                        && typeck
+                            .node_args(e.hir_id)
+                            .type_at(0)
+                            .peel_refs()
+                            .ty_adt_def()
+                            .is_some_and(|self_adt| self_adt.did() == adt.did())
+                }) {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .is_some()
+        })
+}
@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::diagnostics::{SuggestionAlternative, span_lint_and_then, suggest_alternatives};
 use clippy_utils::source::SpanRangeExt;
 use rustc_ast::token::LitKind;
 use rustc_ast::{Expr, ExprKind};
@@ -93,19 +93,25 @@ impl EarlyLintPass for OctalEscapes {
                         _ => false,
                     }) {
                         span_lint_and_then(cx, OCTAL_ESCAPES, span, "octal-looking escape in a literal", |diag| {
-                            diag.help_once("octal escapes are not supported, `\\0` is always null")
-                                .span_suggestion(
-                                    span,
-                                    "if an octal escape is intended, use a hex escape instead",
-                                    format!("\\x{:02x}", (((c_hi - b'0') << 3) | (c_lo - b'0'))),
-                                    Applicability::MaybeIncorrect,
-                                )
-                                .span_suggestion(
-                                    span,
-                                    "if a null escape is intended, disambiguate using",
-                                    format!("\\x00{}{}", c_hi as char, c_lo as char),
-                                    Applicability::MaybeIncorrect,
-                                );
+                            diag.help_once("octal escapes are not supported, `\\0` is always null");
+                            suggest_alternatives(
+                                diag,
+                                OCTAL_ESCAPES,
+                                span,
+                                [
+                                    SuggestionAlternative::new(
+                                        "hex",
+                                        "if an octal escape is intended, use a hex escape instead",
+                                        format!("\\x{:02x}", (((c_hi - b'0') << 3) | (c_lo - b'0'))),
+                                    ),
+                                    SuggestionAlternative::new(
+                                        "null",
+                                        "if a null escape is intended, disambiguate using",
+                                        format!("\\x00{}{}", c_hi as char, c_lo as char),
+                                    ),
+                                ],
+                                Applicability::MaybeIncorrect,
+                            );
                         });
                     } else {
                         break;
@@ -0,0 +1,123 @@
+use clippy_config::types::FloatAssertionStyle;
+use clippy_utils::consts::ConstEvalCtxt;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::is_in_test_function;
+use clippy_utils::macros::{PanicExpn, find_assert_eq_args, root_macro_call_first_node};
+use clippy_utils::source::indent_of;
+use clippy_utils::sugg::Sugg;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::{BodyId, Expr, ExprKind};
+use rustc_lint::{LateContext, LintContext};
+use rustc_middle::ty::{self, FloatTy};
+
+use super::IMPRECISE_FLOAT_EQUALITY_IN_TESTS;
+use super::float_cmp::is_allowed;
+
+pub(crate) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx Expr<'_>,
+    style: FloatAssertionStyle,
+    epsilons: &mut FxHashSet<(BodyId, FloatTy)>,
+) {
+    let Some(macro_call) = root_macro_call_first_node(cx, e) else {
+        return;
+    };
+    let macro_name = cx.tcx.item_name(macro_call.def_id);
+    let macro_name = macro_name.as_str();
+    let eq_macro = match macro_name {
+        "assert_eq" | "debug_assert_eq" => true,
+        "assert_ne" | "debug_assert_ne" => false,
+        _ => return,
+    };
+    let Some((left, right, panic_expn)) = find_assert_eq_args(cx, e, macro_call.expn) else {
+        return;
+    };
+    let ty::Float(float_ty) = *cx.typeck_results().expr_ty(left).kind() else {
+        return;
+    };
+    // Comparing with zero or infinity is exact, see `float_cmp`
+    let ecx = ConstEvalCtxt::new(cx);
+    if ecx.eval(left).is_some_and(|c| is_allowed(&c))
+        || ecx.eval(right).is_some_and(|c| is_allowed(&c))
+        || !is_in_test_function(cx.tcx, e.hir_id)
+    {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        IMPRECISE_FLOAT_EQUALITY_IN_TESTS,
+        macro_call.span,
+        format!(
+            "strict comparison of `{}` values in `{macro_name}!`",
+            float_ty.name_str()
+        ),
+        |diag| {
+            // assert_eq!(...)
+            // ^^^^^^^^^
+            let name_span = cx.sess().source_map().span_until_char(macro_call.span, '!');
+            let mut app = Applicability::MaybeIncorrect;
+            match style {
+                FloatAssertionStyle::Epsilon => {
+                    let ctxt = macro_call.span.ctxt();
+                    let left_sugg = Sugg::hir_with_context(cx, left, ctxt, "..", &mut app);
+                    let right_sugg = Sugg::hir_with_context(cx, right, ctxt, "..", &mut app);
+                    let (name, margin) = match float_ty {
+                        FloatTy::F16 => ("EPSILON_F16", "1e-3"),
+                        FloatTy::F32 => ("EPSILON_F32", "1e-6"),
+                        FloatTy::F64 => ("EPSILON", "1e-9"),
+                        FloatTy::F128 => ("EPSILON_F128", "1e-9"),
+                    };
+                    let assert_name = if macro_name.starts_with("debug_") {
+                        "debug_assert"
+                    } else {
+                        "assert"
+                    };
+                    let mut sugg = vec![
+                        (name_span, assert_name.to_owned()),
+                        (
+                            left.span.source_callsite().to(right.span.source_callsite()),
+                            format!(
+                                "({}).abs() {} {name}",
+                                left_sugg - right_sugg,
+                                if eq_macro { '<' } else { '>' }
+                            ),
+                        ),
+                    ];
+                    // Only the first comparison of a function defines the constant
+                    if let Some(body_id) = cx.enclosing_body
+                        && epsilons.insert((body_id, float_ty))
+                        && let ExprKind::Block(block, _) = cx.tcx.hir().body(body_id).value.kind
+                        && !block.span.from_expansion()
+                        && let Some(first) = block.stmts.first().map(|stmt| stmt.span).or(block.expr.map(|e| e.span))
+                    {
+                        let first = first.source_callsite();
+                        let indent = " ".repeat(indent_of(cx, first).unwrap_or(0));
+                        let constant = format!("const {name}: {} = {margin};\n{indent}", float_ty.name_str());
+                        if first.lo() == name_span.lo() {
+                            sugg[0].1.insert_str(0, &constant);
+                        } else {
+                            sugg.push((first.shrink_to_lo(), constant));
+                        }
+                    }
+                    diag.multipart_suggestion("consider comparing them within some margin of error", sugg, app);
+                },
+                // `approx` has no debug assertions and doesn't take a custom message
+                FloatAssertionStyle::Approx
+                    if !macro_name.starts_with("debug_") && matches!(panic_expn, PanicExpn::Empty) =>
+                {
+                    let approx_name = if eq_macro {
+                        "approx::assert_ulps_eq"
+                    } else {
+                        "approx::assert_ulps_ne"
+                    };
+                    diag.span_suggestion(name_span, "consider using the `approx` crate", approx_name, app);
+                },
+                FloatAssertionStyle::Approx => {
+                    diag.help("consider comparing them with the `approx` crate");
+                },
+            }
+        },
+    );
+}
@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::diagnostics::{SuggestionAlternative, span_lint_and_then, suggest_alternatives};
 use clippy_utils::source::SpanRangeExt;
 use clippy_utils::{eq_expr_value, sugg};
 use rustc_errors::Applicability;
@@ -48,19 +48,21 @@ fn lint_misrefactored_assign_op(
                 let a = &sugg::Sugg::hir(cx, assignee, "..");
                 let r = &sugg::Sugg::hir(cx, rhs, "..");
                 let long = format!("{snip_a} = {}", sugg::make_binop(op, a, r));
-                diag.span_suggestion(
+                suggest_alternatives(
+                    diag,
+                    MISREFACTORED_ASSIGN_OP,
                     expr.span,
-                    format!(
-                        "did you mean `{snip_a} = {snip_a} {} {snip_r}` or `{long}`? Consider replacing it with",
-                        op.as_str()
-                    ),
-                    format!("{snip_a} {}= {snip_r}", op.as_str()),
-                    Applicability::MaybeIncorrect,
-                );
-                diag.span_suggestion(
-                    expr.span,
-                    "or",
-                    long,
+                    [
+                        SuggestionAlternative::new(
+                            "compound",
+                            format!(
+                                "did you mean `{snip_a} = {snip_a} {} {snip_r}` or `{long}`? Consider replacing it with",
+                                op.as_str()
+                            ),
+                            format!("{snip_a} {}= {snip_r}", op.as_str()),
+                        ),
+                        SuggestionAlternative::new("expanded", "or", long),
+                    ],
                     Applicability::MaybeIncorrect, // snippet
                 );
             }
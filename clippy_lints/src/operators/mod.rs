@@ -10,6 +10,7 @@ mod erasing_op;
 mod float_cmp;
 mod float_equality_without_abs;
 mod identity_op;
+mod imprecise_float_equality_in_tests;
 mod integer_division;
 mod misrefactored_assign_op;
 mod modulo_arithmetic;
@@ -24,8 +25,11 @@ mod verbose_bit_mask;
 pub(crate) mod arithmetic_side_effects;
 
 use clippy_config::Conf;
-use rustc_hir::{Body, Expr, ExprKind, UnOp};
+use clippy_config::types::FloatAssertionStyle;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::{Body, BodyId, Expr, ExprKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::FloatTy;
 use rustc_session::impl_lint_pass;
 
 declare_clippy_lint! {
@@ -694,6 +698,46 @@ declare_clippy_lint! {
     "using `==` or `!=` on float constants instead of comparing difference with an allowed error"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `assert_eq!` and `assert_ne!` on floats in `#[test]` functions.
+    ///
+    /// ### Why is this bad?
+    /// This is `float_cmp` in tests: the expected value of a test is usually written as a literal,
+    /// while the actual value is computed, so they may differ by a rounding error and the test
+    /// fails even though the code is correct.
+    ///
+    /// Comparisons with zero or infinity are not linted, as they are exact.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[test]
+    /// fn sum() {
+    ///     assert_eq!(0.1 + 0.2, 0.3);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[test]
+    /// fn sum() {
+    ///     const EPSILON: f64 = 1e-9;
+    ///     assert!((0.1 + 0.2 - 0.3).abs() < EPSILON);
+    /// }
+    /// ```
+    /// With `float-assertion-style = "Approx"`, the comparison is done with the `approx` crate
+    /// instead:
+    /// ```ignore
+    /// #[test]
+    /// fn sum() {
+    ///     approx::assert_ulps_eq!(0.1 + 0.2, 0.3);
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub IMPRECISE_FLOAT_EQUALITY_IN_TESTS,
+    pedantic,
+    "using `assert_eq!` or `assert_ne!` on float values in tests"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for getting the remainder of integer division by one or minus
@@ -841,6 +885,10 @@ pub struct Operators {
     arithmetic_context: numeric_arithmetic::Context,
     verbose_bit_mask_threshold: u64,
     modulo_arithmetic_allow_comparison_to_zero: bool,
+    float_assertion_style: FloatAssertionStyle,
+    /// The bodies where `imprecise_float_equality_in_tests` already suggested an error margin
+    /// constant of the type.
+    float_epsilons: FxHashSet<(BodyId, FloatTy)>,
 }
 impl Operators {
     pub fn new(conf: &'static Conf) -> Self {
@@ -848,6 +896,8 @@ impl Operators {
             arithmetic_context: numeric_arithmetic::Context::default(),
             verbose_bit_mask_threshold: conf.verbose_bit_mask_threshold,
             modulo_arithmetic_allow_comparison_to_zero: conf.allow_comparison_to_zero,
+            float_assertion_style: conf.float_assertion_style,
+            float_epsilons: FxHashSet::default(),
         }
     }
 }
@@ -874,6 +924,7 @@ impl_lint_pass!(Operators => [
     CMP_OWNED,
     FLOAT_CMP,
     FLOAT_CMP_CONST,
+    IMPRECISE_FLOAT_EQUALITY_IN_TESTS,
     MODULO_ONE,
     MODULO_ARITHMETIC,
     NEEDLESS_BITWISE_BOOL,
@@ -884,6 +935,7 @@ impl_lint_pass!(Operators => [
 impl<'tcx> LateLintPass<'tcx> for Operators {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         eq_op::check_assert(cx, e);
+        imprecise_float_equality_in_tests::check(cx, e, self.float_assertion_style, &mut self.float_epsilons);
         match e.kind {
             ExprKind::Binary(op, lhs, rhs) => {
                 if !e.span.from_expansion() {
@@ -1,4 +1,6 @@
+use clippy_config::Conf;
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::effects::expr_effects;
 use clippy_utils::sugg::Sugg;
 use clippy_utils::{
     CaptureKind, can_move_expr_to_closure, eager_or_lazy, higher, is_else_clause, is_in_const_context,
@@ -9,7 +11,7 @@ use rustc_hir::LangItem::{OptionNone, OptionSome, ResultErr, ResultOk};
 use rustc_hir::def::Res;
 use rustc_hir::{Arm, BindingMode, Expr, ExprKind, MatchSource, Mutability, Pat, PatKind, Path, QPath, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::declare_lint_pass;
+use rustc_session::impl_lint_pass;
 use rustc_span::SyntaxContext;
 
 declare_clippy_lint! {
@@ -30,6 +32,10 @@ declare_clippy_lint! {
     /// `continue` (which cannot be used within closures). If these are found,
     /// this lint will not be raised.
     ///
+    /// The lint is also not raised if either branch has side effects: allocations, IO, `.await` or
+    /// early returns such as `?`. These are harder to follow once moved into a closure. The
+    /// `option-if-let-else-allow-effects` configuration lints these as well.
+    ///
     /// ### Example
     /// ```no_run
     /// # let optional: Option<u32> = Some(0);
@@ -71,7 +77,19 @@ declare_clippy_lint! {
     "reimplementation of Option::map_or"
 }
 
-declare_lint_pass!(OptionIfLetElse => [OPTION_IF_LET_ELSE]);
+pub struct OptionIfLetElse {
+    allow_effects: bool,
+}
+
+impl OptionIfLetElse {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            allow_effects: conf.option_if_let_else_allow_effects,
+        }
+    }
+}
+
+impl_lint_pass!(OptionIfLetElse => [OPTION_IF_LET_ELSE]);
 
 /// A struct containing information about occurrences of construct that this lint detects
 ///
@@ -115,6 +133,7 @@ fn try_get_option_occurrence<'tcx>(
     expr: &Expr<'_>,
     if_then: &'tcx Expr<'_>,
     if_else: &'tcx Expr<'_>,
+    allow_effects: bool,
 ) -> Option<OptionOccurrence> {
     let cond_expr = match expr.kind {
         ExprKind::Unary(UnOp::Deref, inner_expr) | ExprKind::AddrOf(_, _, inner_expr) => inner_expr,
@@ -128,6 +147,7 @@ fn try_get_option_occurrence<'tcx>(
             .iter()
             .filter_map(|(id, &c)| none_captures.get(id).map(|&c2| (c, c2)))
             .all(|(x, y)| x.is_imm_ref() && y.is_imm_ref())
+        && (allow_effects || (expr_effects(cx, if_then).is_empty() && expr_effects(cx, if_else).is_empty()))
     {
         let capture_mut = if bind_annotation == BindingMode::MUT {
             "mut "
@@ -232,7 +252,11 @@ fn try_get_inner_pat_and_is_result<'tcx>(cx: &LateContext<'tcx>, pat: &Pat<'tcx>
 /// If this expression is the option if let/else construct we're detecting, then
 /// this function returns an `OptionOccurrence` struct with details if
 /// this construct is found, or None if this construct is not found.
-fn detect_option_if_let_else<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'tcx>) -> Option<OptionOccurrence> {
+fn detect_option_if_let_else<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'tcx>,
+    allow_effects: bool,
+) -> Option<OptionOccurrence> {
     if let Some(higher::IfLet {
         let_pat,
         let_expr,
@@ -243,18 +267,22 @@ fn detect_option_if_let_else<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'tcx>) ->
         && !cx.typeck_results().expr_ty(expr).is_unit()
         && !is_else_clause(cx.tcx, expr)
     {
-        try_get_option_occurrence(cx, expr.span.ctxt(), let_pat, let_expr, if_then, if_else)
+        try_get_option_occurrence(cx, expr.span.ctxt(), let_pat, let_expr, if_then, if_else, allow_effects)
     } else {
         None
     }
 }
 
-fn detect_option_match<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'tcx>) -> Option<OptionOccurrence> {
+fn detect_option_match<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'tcx>,
+    allow_effects: bool,
+) -> Option<OptionOccurrence> {
     if let ExprKind::Match(ex, arms, MatchSource::Normal) = expr.kind
         && !cx.typeck_results().expr_ty(expr).is_unit()
         && let Some((let_pat, if_then, if_else)) = try_convert_match(cx, arms)
     {
-        try_get_option_occurrence(cx, expr.span.ctxt(), let_pat, ex, if_then, if_else)
+        try_get_option_occurrence(cx, expr.span.ctxt(), let_pat, ex, if_then, if_else, allow_effects)
     } else {
         None
     }
@@ -298,7 +326,8 @@ impl<'tcx> LateLintPass<'tcx> for OptionIfLetElse {
             return;
         }
 
-        let detection = detect_option_if_let_else(cx, expr).or_else(|| detect_option_match(cx, expr));
+        let detection = detect_option_if_let_else(cx, expr, self.allow_effects)
+            .or_else(|| detect_option_match(cx, expr, self.allow_effects));
         if let Some(det) = detection {
             span_lint_and_sugg(
                 cx,
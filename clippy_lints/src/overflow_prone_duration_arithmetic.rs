@@ -0,0 +1,174 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::rinterval::{Interval, IntervalCtxt, type_range};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{fn_def_id, is_trait_method};
+use rustc_ast::{LitIntType, LitKind};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty, UintTy};
+use rustc_session::declare_lint_pass;
+use rustc_span::{SyntaxContext, sym};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Duration` constructors taking a `u64`, such as `Duration::from_secs`, called
+    /// with a product computed in a narrower unsigned integer type and then widened, e.g.
+    /// `Duration::from_secs((hours * 3600) as u64)` where `hours` is a `u32`. The lint doesn't
+    /// trigger if the range of the operands shows that the product can't overflow.
+    ///
+    /// ### Why is this bad?
+    /// The multiplication overflows on large inputs even though the result would fit in a `u64`.
+    /// This panics in debug builds and silently produces a wrong duration in release builds.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// fn timeout(hours: u32) -> Duration {
+    ///     Duration::from_secs((hours * 3600).into())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// fn timeout(hours: u32) -> Duration {
+    ///     Duration::from_secs(u64::from(hours) * 3600)
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub OVERFLOW_PRONE_DURATION_ARITHMETIC,
+    suspicious,
+    "multiplying in a narrow integer type before widening the product to construct a `Duration`"
+}
+
+declare_lint_pass!(OverflowProneDurationArithmetic => [OVERFLOW_PRONE_DURATION_ARITHMETIC]);
+
+impl<'tcx> LateLintPass<'tcx> for OverflowProneDurationArithmetic {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Call(func, [arg]) = expr.kind
+            && let ExprKind::Path(QPath::TypeRelative(_, segment)) = func.kind
+            && matches!(
+                segment.ident.name.as_str(),
+                "from_secs" | "from_millis" | "from_micros" | "from_nanos"
+            )
+            && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::Duration)
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some((product, conversion)) = widened_expr(cx, arg)
+            && let ExprKind::Binary(op, ..) = product.kind
+            && op.node == BinOpKind::Mul
+            && let product_ty = cx.typeck_results().expr_ty(product)
+            // Widening the operands of a signed product changes the result for negative values
+            && let ty::Uint(_) = product_ty.kind()
+            && let Some(narrow_range) = type_range(cx, product_ty)
+            && narrow_range.hi < i128::from(u64::MAX)
+        {
+            let mut factors = Vec::new();
+            collect_factors(cx, product, product_ty, &mut factors);
+            // Factors behind references can't be converted as they are
+            if factors
+                .iter()
+                .any(|factor| cx.typeck_results().expr_ty(factor) != product_ty)
+            {
+                return;
+            }
+            let ictx = IntervalCtxt::new(cx);
+            let product_range = factors
+                .iter()
+                .map(|factor| ictx.eval(factor))
+                .try_fold(Interval::single(1), |acc, range| acc.checked_mul(range?));
+            if product_range.is_some_and(|range| range.is_subset_of(narrow_range)) {
+                return;
+            }
+
+            span_lint_and_then(
+                cx,
+                OVERFLOW_PRONE_DURATION_ARITHMETIC,
+                arg.span,
+                format!("this multiplication is done in `{product_ty}` before being widened to `u64`"),
+                |diag| {
+                    let mut app = Applicability::MaybeIncorrect;
+                    let ctxt = arg.span.ctxt();
+                    let sugg = factors
+                        .iter()
+                        .map(|factor| widen_factor(cx, factor, conversion, ctxt, &mut app))
+                        .collect::<Vec<_>>()
+                        .join(" * ");
+                    diag.note("it may overflow even though the product fits in a `u64`");
+                    diag.span_suggestion(arg.span, "widen the operands before multiplying", sugg, app);
+                },
+            );
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Conversion {
+    /// `x as u64`
+    Cast,
+    /// `u64::from(x)` or `x.into()`
+    From,
+}
+
+/// If `expr` converts a narrower integer to `u64`, returns the converted expression.
+fn widened_expr<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, Conversion)> {
+    let (inner, conversion) = match expr.kind {
+        ExprKind::Cast(inner, _) => (inner, Conversion::Cast),
+        ExprKind::Call(_, [inner])
+            if fn_def_id(cx, expr).is_some_and(|id| cx.tcx.is_diagnostic_item(sym::from_fn, id)) =>
+        {
+            (inner, Conversion::From)
+        },
+        ExprKind::MethodCall(_, inner, [], _) if is_trait_method(cx, expr, sym::Into) => (inner, Conversion::From),
+        _ => return None,
+    };
+    (*cx.typeck_results().expr_ty(expr).kind() == ty::Uint(UintTy::U64)
+        && cx.typeck_results().expr_ty(inner).is_integral()
+        && !inner.span.from_expansion())
+    .then_some((inner, conversion))
+}
+
+/// Collects the factors of a chain of multiplications in `ty`.
+fn collect_factors<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    ty: Ty<'tcx>,
+    factors: &mut Vec<&'tcx Expr<'tcx>>,
+) {
+    if let ExprKind::Binary(op, lhs, rhs) = expr.kind
+        && op.node == BinOpKind::Mul
+        && cx.typeck_results().expr_ty(expr) == ty
+    {
+        collect_factors(cx, lhs, ty, factors);
+        collect_factors(cx, rhs, ty, factors);
+    } else {
+        factors.push(expr);
+    }
+}
+
+/// Converts the factor to `u64` the same way the product was, unless it's an unsuffixed literal
+/// whose type will be inferred.
+fn widen_factor(
+    cx: &LateContext<'_>,
+    factor: &Expr<'_>,
+    conversion: Conversion,
+    ctxt: SyntaxContext,
+    app: &mut Applicability,
+) -> String {
+    if let ExprKind::Lit(lit) = factor.kind
+        && let LitKind::Int(_, LitIntType::Unsuffixed) = lit.node
+    {
+        return snippet_with_context(cx, factor.span, ctxt, "..", app).0.into_owned();
+    }
+    match conversion {
+        Conversion::Cast => Sugg::hir_with_context(cx, factor, ctxt, "..", app)
+            .as_ty("u64")
+            .to_string(),
+        Conversion::From => format!(
+            "u64::from({})",
+            snippet_with_context(cx, factor.span, ctxt, "..", app).0
+        ),
+    }
+}
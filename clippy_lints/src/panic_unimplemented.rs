@@ -14,7 +14,7 @@ pub struct PanicUnimplemented {
 impl PanicUnimplemented {
     pub fn new(conf: &'static Conf) -> Self {
         Self {
-            allow_panic_in_tests: conf.allow_panic_in_tests,
+            allow_panic_in_tests: conf.is_allowed_in_tests(PANIC),
         }
     }
 }
@@ -0,0 +1,362 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::fn_has_unsatisfiable_preds;
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, FnDecl};
+use rustc_index::IndexVec;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::mir::{
+    self, BasicBlock, Local, Operand, Place, ProjectionElem, Rvalue, START_BLOCK, StatementKind, TerminatorKind,
+};
+use rustc_middle::ty::{self, GenericArgKind, Ty};
+use rustc_session::declare_lint_pass;
+use rustc_span::def_id::LocalDefId;
+use rustc_span::source_map::Spanned;
+use rustc_span::{Span, sym};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for locking a `Mutex` or `RwLock` while a guard of an earlier lock of the
+    /// same `Mutex` or `RwLock` may still be alive.
+    ///
+    /// ### Why is this bad?
+    /// Neither `Mutex` nor `RwLock` are reentrant. Locking them again from the thread which
+    /// already holds the lock deadlocks or panics.
+    ///
+    /// ### Known problems
+    /// The analysis only follows guards through locals of the current function. Two locks
+    /// are only considered the same when they are reached through the same path, so locks
+    /// accessed through different references are not linted. Taking two read locks of the
+    /// same `RwLock` is not linted.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// fn push_len(m: &Mutex<Vec<usize>>) {
+    ///     if let Ok(mut v) = m.lock() {
+    ///         let len = m.lock().unwrap().len();
+    ///         v.push(len);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::sync::Mutex;
+    /// fn push_len(m: &Mutex<Vec<usize>>) {
+    ///     if let Ok(mut v) = m.lock() {
+    ///         let len = v.len();
+    ///         v.push(len);
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub POSSIBLE_DEADLOCK_DOUBLE_LOCK,
+    suspicious,
+    "locking a `Mutex` or `RwLock` while a guard of it may still be alive"
+}
+
+declare_lint_pass!(PossibleDeadlockDoubleLock => [POSSIBLE_DEADLOCK_DOUBLE_LOCK]);
+
+impl<'tcx> LateLintPass<'tcx> for PossibleDeadlockDoubleLock {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        _: &'tcx Body<'_>,
+        _: Span,
+        def_id: LocalDefId,
+    ) {
+        // Building MIR for `fn`s with unsatisfiable preds results in ICE.
+        if matches!(kind, FnKind::Closure) || fn_has_unsatisfiable_preds(cx, def_id.to_def_id()) {
+            return;
+        }
+
+        let mir = cx.tcx.optimized_mir(def_id.to_def_id());
+        let entry_states = guard_states(cx, mir);
+
+        for (bb, state) in entry_states.iter_enumerated() {
+            let Some(state) = state else {
+                continue;
+            };
+            let mut state = state.clone();
+            apply_block(cx, mir, bb, &mut state, |lock, held| {
+                let terminator = mir[bb].terminator();
+                let span = terminator.source_info.span;
+                if span.from_expansion() {
+                    return;
+                }
+                let node = mir.source_scopes[terminator.source_info.scope]
+                    .local_data
+                    .as_ref()
+                    .assert_crate_local()
+                    .lint_root;
+                span_lint_hir_and_then(
+                    cx,
+                    POSSIBLE_DEADLOCK_DOUBLE_LOCK,
+                    node,
+                    span,
+                    format!("this locks a `{}` which may already be locked", lock.kind.type_name()),
+                    |diag| {
+                        diag.span_note(held.span, "the guard of this earlier lock may still be alive");
+                        diag.help("reuse the earlier guard, or drop it before locking again");
+                    },
+                );
+            });
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LockKind {
+    Mutex,
+    RwLockRead,
+    RwLockWrite,
+}
+
+impl LockKind {
+    fn type_name(self) -> &'static str {
+        match self {
+            Self::Mutex => "Mutex",
+            Self::RwLockRead | Self::RwLockWrite => "RwLock",
+        }
+    }
+}
+
+/// A lock guard, or a value containing one, stored in a local.
+#[derive(Clone, Copy)]
+struct HeldLock<'tcx> {
+    /// The place of the `Mutex` or `RwLock` which was locked.
+    place: Place<'tcx>,
+    kind: LockKind,
+    span: Span,
+}
+
+impl HeldLock<'_> {
+    fn conflicts_with(&self, other: &Self) -> bool {
+        self.place == other.place && !(self.kind == LockKind::RwLockRead && other.kind == LockKind::RwLockRead)
+    }
+}
+
+/// The guards which may be alive at a point of the function.
+type State<'tcx> = FxIndexMap<Local, HeldLock<'tcx>>;
+
+/// Computes the guards which may be alive at the start of every reachable block.
+fn guard_states<'tcx>(cx: &LateContext<'tcx>, mir: &mir::Body<'tcx>) -> IndexVec<BasicBlock, Option<State<'tcx>>> {
+    let mut entry_states: IndexVec<BasicBlock, Option<State<'tcx>>> =
+        IndexVec::from_elem_n(None, mir.basic_blocks.len());
+    entry_states[START_BLOCK] = Some(State::default());
+    let mut worklist = vec![START_BLOCK];
+
+    while let Some(bb) = worklist.pop() {
+        let Some(mut state) = entry_states[bb].clone() else {
+            continue;
+        };
+        apply_block(cx, mir, bb, &mut state, |_, _| {});
+
+        for succ in mir[bb].terminator().successors() {
+            let changed = match &mut entry_states[succ] {
+                Some(succ_state) => {
+                    let len = succ_state.len();
+                    for (&local, &held) in &state {
+                        succ_state.entry(local).or_insert(held);
+                    }
+                    succ_state.len() != len
+                },
+                slot @ None => {
+                    *slot = Some(state.clone());
+                    true
+                },
+            };
+            if changed {
+                worklist.push(succ);
+            }
+        }
+    }
+
+    entry_states
+}
+
+/// Applies the effects of a block to `state`. `on_conflict` is called when the terminator of
+/// the block locks something which is already locked.
+fn apply_block<'tcx>(
+    cx: &LateContext<'tcx>,
+    mir: &mir::Body<'tcx>,
+    bb: BasicBlock,
+    state: &mut State<'tcx>,
+    mut on_conflict: impl FnMut(&HeldLock<'tcx>, &HeldLock<'tcx>),
+) {
+    let data = &mir[bb];
+    for stmt in &data.statements {
+        match &stmt.kind {
+            StatementKind::Assign(box (dest, rvalue)) => {
+                let moved = match rvalue {
+                    Rvalue::Use(Operand::Move(src)) => take_held(state, *src),
+                    Rvalue::Aggregate(_, operands) => operands.iter().find_map(|op| match op {
+                        Operand::Move(src) => take_held(state, *src),
+                        _ => None,
+                    }),
+                    _ => None,
+                };
+                if let Some(held) = moved
+                    && let Some(dest) = dest.as_local()
+                    && contains_guard(cx, mir.local_decls[dest].ty)
+                {
+                    state.insert(dest, held);
+                }
+            },
+            StatementKind::StorageDead(local) => {
+                state.swap_remove(local);
+            },
+            _ => {},
+        }
+    }
+
+    match &data.terminator().kind {
+        TerminatorKind::Drop { place, .. } => {
+            if let Some(local) = place.as_local() {
+                state.swap_remove(&local);
+            }
+        },
+        TerminatorKind::Call {
+            func,
+            args,
+            destination,
+            ..
+        } => {
+            let lock = lock_call(cx, mir, func, args, data.terminator().source_info.span);
+            if let Some(lock) = &lock
+                && let Some(held) = state.values().find(|held| held.conflicts_with(lock))
+            {
+                on_conflict(lock, held);
+            }
+
+            // A guard passed by value either ends up in the returned value, or is dropped.
+            let moved = args.iter().fold(None, |moved, arg| match arg.node {
+                Operand::Move(src) => take_held(state, src).or(moved),
+                _ => moved,
+            });
+            if let Some(dest) = destination.as_local()
+                && let Some(held) = lock.or(moved)
+                && contains_guard(cx, mir.local_decls[dest].ty)
+            {
+                state.insert(dest, held);
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Removes and returns the guard stored in the local of `place`, if any.
+fn take_held<'tcx>(state: &mut State<'tcx>, place: Place<'tcx>) -> Option<HeldLock<'tcx>> {
+    state.swap_remove(&place.local)
+}
+
+fn contains_guard<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    ty.walk().any(|arg| {
+        if let GenericArgKind::Type(ty) = arg.unpack()
+            && let ty::Adt(adt, _) = ty.kind()
+        {
+            matches!(
+                cx.tcx.get_diagnostic_name(adt.did()),
+                Some(sym::MutexGuard | sym::RwLockReadGuard | sym::RwLockWriteGuard)
+            )
+        } else {
+            false
+        }
+    })
+}
+
+/// Matches calls to `Mutex::lock`, `RwLock::read` and `RwLock::write`.
+fn lock_call<'tcx>(
+    cx: &LateContext<'tcx>,
+    mir: &mir::Body<'tcx>,
+    func: &Operand<'tcx>,
+    args: &[Spanned<Operand<'tcx>>],
+    span: Span,
+) -> Option<HeldLock<'tcx>> {
+    let ty::FnDef(def_id, _) = *func.ty(mir, cx.tcx).kind() else {
+        return None;
+    };
+    let impl_id = cx.tcx.impl_of_method(def_id)?;
+    let adt = cx.tcx.type_of(impl_id).instantiate_identity().ty_adt_def()?;
+    let kind = match (
+        cx.tcx.get_diagnostic_name(adt.did())?,
+        cx.tcx.item_name(def_id).as_str(),
+    ) {
+        (sym::Mutex, "lock") => LockKind::Mutex,
+        (sym::RwLock, "read") => LockKind::RwLockRead,
+        (sym::RwLock, "write") => LockKind::RwLockWrite,
+        _ => return None,
+    };
+    let [recv] = args else {
+        return None;
+    };
+    let recv = recv.node.place()?;
+    let place = resolve_place(cx, mir, recv.project_deeper(&[ProjectionElem::Deref], cx.tcx))?;
+    Some(HeldLock { place, kind, span })
+}
+
+/// Follows references through locals which are assigned once, so that e.g. both `m.lock()`
+/// and `(&m).lock()` resolve to the place of `m`. Returns `None` if the place is indexed or
+/// can't otherwise be identified.
+fn resolve_place<'tcx>(cx: &LateContext<'tcx>, mir: &mir::Body<'tcx>, mut place: Place<'tcx>) -> Option<Place<'tcx>> {
+    // Enough to get through the temporaries of auto-(de)ref
+    for _ in 0..8 {
+        if !place
+            .projection
+            .iter()
+            .all(|elem| matches!(elem, ProjectionElem::Deref | ProjectionElem::Field(..)))
+        {
+            return None;
+        }
+        let [ProjectionElem::Deref, rest @ ..] = &place.projection[..] else {
+            return Some(place);
+        };
+        place = match single_assignment(mir, place.local) {
+            Some(Assignment::Rvalue(Rvalue::Ref(_, _, src))) => src.project_deeper(rest, cx.tcx),
+            Some(Assignment::Rvalue(Rvalue::Use(Operand::Copy(src) | Operand::Move(src)))) => {
+                src.project_deeper(&place.projection[..], cx.tcx)
+            },
+            // `Deref::deref(&x)` returns a reference to `*x`, e.g. for an `Arc<Mutex<_>>`
+            Some(Assignment::Call(func, [arg])) if is_deref_call(cx, mir, func) => arg
+                .node
+                .place()?
+                .project_deeper(&[ProjectionElem::Deref, ProjectionElem::Deref], cx.tcx)
+                .project_deeper(rest, cx.tcx),
+            _ => return Some(place),
+        };
+    }
+    None
+}
+
+fn is_deref_call<'tcx>(cx: &LateContext<'tcx>, mir: &mir::Body<'tcx>, func: &Operand<'tcx>) -> bool {
+    matches!(*func.ty(mir, cx.tcx).kind(), ty::FnDef(def_id, _) if cx.tcx.is_diagnostic_item(sym::deref_method, def_id))
+}
+
+enum Assignment<'a, 'tcx> {
+    Rvalue(&'a Rvalue<'tcx>),
+    Call(&'a Operand<'tcx>, &'a [Spanned<Operand<'tcx>>]),
+}
+
+/// Returns the value assigned to `local`, if it is assigned exactly once.
+fn single_assignment<'a, 'tcx>(mir: &'a mir::Body<'tcx>, local: Local) -> Option<Assignment<'a, 'tcx>> {
+    let mut assignments = mir.basic_blocks.iter().flat_map(|data| {
+        let stmts = data.statements.iter().filter_map(move |stmt| match &stmt.kind {
+            StatementKind::Assign(box (dest, rvalue)) if dest.local == local => Some(Assignment::Rvalue(rvalue)),
+            _ => None,
+        });
+        let call = match &data.terminator().kind {
+            TerminatorKind::Call {
+                func,
+                args,
+                destination,
+                ..
+            } if destination.local == local => Some(Assignment::Call(func, args)),
+            _ => None,
+        };
+        stmts.chain(call)
+    });
+    let assignment = assignments.next()?;
+    assignments.next().is_none().then_some(assignment)
+}
@@ -1,26 +1,34 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg, span_lint_and_then, span_lint_hir_and_then};
 use clippy_utils::source::SpanRangeExt;
-use clippy_utils::visitors::contains_unsafe_block;
-use clippy_utils::{get_expr_use_or_unification_node, is_lint_allowed, path_def_id, path_to_local, std_or_core};
+use clippy_utils::visitors::{contains_unsafe_block, for_each_expr};
+use clippy_utils::{
+    get_expr_use_or_unification_node, get_parent_expr, is_lint_allowed, path_def_id, path_to_local, path_to_local_id,
+    std_or_core,
+};
 use hir::LifetimeName;
+use rustc_data_structures::fx::{FxHashMap, FxIndexMap};
 use rustc_errors::{Applicability, MultiSpan};
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_hir::hir_id::{HirId, HirIdMap};
 use rustc_hir::intravisit::{Visitor, walk_expr};
 use rustc_hir::{
-    self as hir, AnonConst, BinOpKind, BindingMode, Body, Expr, ExprKind, FnRetTy, FnSig, GenericArg, ImplItemKind,
-    ItemKind, Lifetime, Mutability, Node, Param, PatKind, QPath, TraitFn, TraitItem, TraitItemKind, TyKind,
+    self as hir, AnonConst, BinOpKind, BindingMode, Body, Closure, Expr, ExprKind, FnRetTy, FnSig, GenericArg,
+    GenericBound, ImplItemKind, ItemKind, Lifetime, Mutability, Node, Param, PatKind, QPath, Safety, TraitFn,
+    TraitItem, TraitItemKind, TyKind, WherePredicateKind,
 };
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_infer::traits::{Obligation, ObligationCause};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::nested_filter;
 use rustc_middle::ty::{self, Binder, ClauseKind, ExistentialPredicate, List, PredicateKind, Ty};
-use rustc_session::declare_lint_pass;
+use rustc_session::impl_lint_pass;
 use rustc_span::symbol::Symbol;
 use rustc_span::{Span, sym};
 use rustc_target::spec::abi::Abi;
 use rustc_trait_selection::infer::InferCtxtExt as _;
 use rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt as _;
+use std::ops::ControlFlow;
 use std::{fmt, iter};
 
 use crate::vec::is_allowed_vec_method;
@@ -31,6 +39,10 @@ declare_clippy_lint! {
     /// `&PathBuf`, and `Cow<_>`. It will also suggest you replace `.clone()` calls
     /// with the appropriate `.to_owned()`/`to_string()` calls.
     ///
+    /// The arguments of methods of traits which aren't exported are linted if the trait and all
+    /// implementations of the method can be changed. The parameters of closures are linted if
+    /// their type comes from an `Fn` bound of a function in the same crate, which is then changed.
+    ///
     /// ### Why is this bad?
     /// Requiring the argument to be of the specific type
     /// makes the function less useful for no benefit; slices in the form of `&[T]`
@@ -40,7 +52,8 @@ declare_clippy_lint! {
     /// There may be `fn(&Vec)`-typed references pointing to your function.
     /// If you have them, you will get a compiler error after applying this lint's
     /// suggestions. You then have the choice to undo your changes or change the
-    /// type of the reference.
+    /// type of the reference. The same goes for other closures passed to a function
+    /// whose `Fn` bound is changed.
     ///
     /// Note that if the function is part of your public interface, there may be
     /// other crates referencing it, of which you may not be aware. Carefully
@@ -147,7 +160,16 @@ declare_clippy_lint! {
     "invalid usage of a null pointer, suggesting `NonNull::dangling()` instead"
 }
 
-declare_lint_pass!(Ptr => [PTR_ARG, CMP_NULL, MUT_FROM_REF, INVALID_NULL_PTR_USAGE]);
+#[derive(Default)]
+pub struct Ptr {
+    /// The arguments of the methods of traits which aren't exported. They are linted at the end of
+    /// the crate, if all implementations of the method can be changed as well.
+    private_trait_fn_args: FxIndexMap<LocalDefId, Vec<TraitFnArg>>,
+    /// The arguments of the implementations of those methods.
+    private_trait_impl_fn_args: FxHashMap<LocalDefId, Vec<TraitFnArg>>,
+}
+
+impl_lint_pass!(Ptr => [PTR_ARG, CMP_NULL, MUT_FROM_REF, INVALID_NULL_PTR_USAGE]);
 
 impl<'tcx> LateLintPass<'tcx> for Ptr {
     fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>) {
@@ -164,13 +186,17 @@ impl<'tcx> LateLintPass<'tcx> for Ptr {
                 return;
             }
 
-            for arg in check_fn_args(
-                cx,
-                cx.tcx.fn_sig(item.owner_id).instantiate_identity().skip_binder(),
-                sig.decl.inputs,
-                &[],
-            )
-            .filter(|arg| arg.mutability() == Mutability::Not)
+            let fn_sig = cx.tcx.fn_sig(item.owner_id).instantiate_identity().skip_binder();
+            if is_private_trait_fn(cx, item.owner_id.to_def_id()) {
+                let args = check_fn_args(cx, fn_sig, sig.decl.inputs, &[], true)
+                    .map(|arg| TraitFnArg::new(cx, &arg, &PtrArgResult::default()))
+                    .collect();
+                self.private_trait_fn_args.insert(item.owner_id.def_id, args);
+                return;
+            }
+
+            for arg in
+                check_fn_args(cx, fn_sig, sig.decl.inputs, &[], true).filter(|arg| arg.mutability() == Mutability::Not)
             {
                 span_lint_hir_and_then(cx, PTR_ARG, arg.emission_id, arg.span, arg.build_msg(), |diag| {
                     diag.span_suggestion(
@@ -187,37 +213,50 @@ impl<'tcx> LateLintPass<'tcx> for Ptr {
     fn check_body(&mut self, cx: &LateContext<'tcx>, body: &Body<'tcx>) {
         let hir = cx.tcx.hir();
         let mut parents = hir.parent_iter(body.value.hir_id);
-        let (item_id, sig, is_trait_item) = match parents.next() {
+        let (item_id, sig, kind) = match parents.next() {
             Some((_, Node::Item(i))) => {
                 if let ItemKind::Fn { sig, .. } = &i.kind {
-                    (i.owner_id, sig, false)
+                    (i.owner_id, sig, FnKind::Fn)
                 } else {
                     return;
                 }
             },
             Some((_, Node::ImplItem(i))) => {
-                if !matches!(parents.next(),
-                    Some((_, Node::Item(i))) if matches!(&i.kind, ItemKind::Impl(i) if i.of_trait.is_none())
-                ) {
-                    return;
-                }
-                if let ImplItemKind::Fn(sig, _) = &i.kind {
-                    (i.owner_id, sig, false)
-                } else {
+                let ImplItemKind::Fn(sig, _) = &i.kind else {
                     return;
+                };
+                match cx.tcx.associated_item(i.owner_id).trait_item_def_id {
+                    None => (i.owner_id, sig, FnKind::Fn),
+                    Some(trait_fn_id) if is_private_trait_fn(cx, trait_fn_id) => {
+                        (i.owner_id, sig, FnKind::PrivateTraitImplFn)
+                    },
+                    Some(_) => return,
                 }
             },
             Some((_, Node::TraitItem(i))) => {
                 if let TraitItemKind::Fn(sig, _) = &i.kind {
-                    (i.owner_id, sig, true)
+                    if is_private_trait_fn(cx, i.owner_id.to_def_id()) {
+                        (i.owner_id, sig, FnKind::PrivateTraitFn)
+                    } else {
+                        (i.owner_id, sig, FnKind::TraitFn)
+                    }
                 } else {
                     return;
                 }
             },
+            Some((_, Node::Expr(closure_expr))) => {
+                if let ExprKind::Closure(closure) = closure_expr.kind {
+                    check_closure(cx, body, closure_expr, closure);
+                }
+                return;
+            },
             _ => return,
         };
 
-        check_mut_from_ref(cx, sig, Some(body));
+        // The signature of a trait implementation is checked on the trait
+        if kind != FnKind::PrivateTraitImplFn {
+            check_mut_from_ref(cx, sig, Some(body));
+        }
 
         if !matches!(sig.header.abi, Abi::Rust) {
             // Ignore `extern` functions with non-Rust calling conventions
@@ -226,26 +265,70 @@ impl<'tcx> LateLintPass<'tcx> for Ptr {
 
         let decl = sig.decl;
         let sig = cx.tcx.fn_sig(item_id).instantiate_identity().skip_binder();
-        let lint_args: Vec<_> = check_fn_args(cx, sig, decl.inputs, body.params)
-            .filter(|arg| !is_trait_item || arg.mutability() == Mutability::Not)
+        let lint_args: Vec<_> = check_fn_args(cx, sig, decl.inputs, body.params, kind != FnKind::PrivateTraitImplFn)
+            .filter(|arg| kind != FnKind::TraitFn || arg.mutability() == Mutability::Not)
             .collect();
         let results = check_ptr_arg_usage(cx, body, &lint_args);
 
-        for (result, args) in results.iter().zip(lint_args.iter()).filter(|(r, _)| !r.skip) {
-            span_lint_hir_and_then(cx, PTR_ARG, args.emission_id, args.span, args.build_msg(), |diag| {
-                diag.multipart_suggestion(
-                    "change this to",
-                    iter::once((args.span, format!("{}{}", args.ref_prefix, args.deref_ty.display(cx))))
-                        .chain(result.replacements.iter().map(|r| {
-                            (
-                                r.expr_span,
-                                format!("{}{}", r.self_span.get_source_text(cx).unwrap(), r.replacement),
-                            )
-                        }))
-                        .collect(),
-                    Applicability::Unspecified,
-                );
-            });
+        match kind {
+            FnKind::Fn | FnKind::TraitFn => {
+                for (result, args) in results.iter().zip(lint_args.iter()).filter(|(r, _)| !r.skip) {
+                    span_lint_hir_and_then(cx, PTR_ARG, args.emission_id, args.span, args.build_msg(), |diag| {
+                        diag.multipart_suggestion("change this to", args.sugg(cx, result), Applicability::Unspecified);
+                    });
+                }
+            },
+            FnKind::PrivateTraitFn => {
+                let args = TraitFnArg::from_results(cx, &lint_args, &results);
+                self.private_trait_fn_args.insert(item_id.def_id, args);
+            },
+            FnKind::PrivateTraitImplFn => {
+                let args = TraitFnArg::from_results(cx, &lint_args, &results);
+                self.private_trait_impl_fn_args.insert(item_id.def_id, args);
+            },
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        'trait_fns: for (&trait_fn_id, trait_args) in &self.private_trait_fn_args {
+            let mut impls_args = Vec::new();
+            for impl_id in cx.tcx.all_impls(cx.tcx.parent(trait_fn_id.to_def_id())) {
+                // Implementations using the provided method don't need to be changed
+                if let Some(impl_fn_id) = cx.tcx.impl_item_implementor_ids(impl_id).get(&trait_fn_id.to_def_id()) {
+                    match impl_fn_id
+                        .as_local()
+                        .and_then(|impl_fn_id| self.private_trait_impl_fn_args.get(&impl_fn_id))
+                    {
+                        Some(impl_args) => impls_args.push(impl_args),
+                        None => continue 'trait_fns,
+                    }
+                }
+            }
+
+            for arg in trait_args.iter().filter(|arg| !arg.skip) {
+                let Some(impl_args) = impls_args
+                    .iter()
+                    .map(|impl_args| {
+                        impl_args
+                            .iter()
+                            .find(|impl_arg| impl_arg.idx == arg.idx && !impl_arg.skip)
+                    })
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    continue;
+                };
+                span_lint_hir_and_then(cx, PTR_ARG, arg.emission_id, arg.span, arg.msg.clone(), |diag| {
+                    diag.multipart_suggestion(
+                        "change this to",
+                        arg.sugg
+                            .iter()
+                            .chain(impl_args.iter().flat_map(|impl_arg| &impl_arg.sugg))
+                            .cloned()
+                            .collect(),
+                        Applicability::Unspecified,
+                    );
+                });
+            }
         }
     }
 
@@ -332,7 +415,7 @@ struct PtrArg<'tcx> {
     ref_prefix: RefPrefix,
     deref_ty: DerefTy<'tcx>,
 }
-impl PtrArg<'_> {
+impl<'tcx> PtrArg<'tcx> {
     fn build_msg(&self) -> String {
         format!(
             "writing `&{}{}` instead of `&{}{}` involves a new object where a slice will do",
@@ -346,6 +429,57 @@ impl PtrArg<'_> {
     fn mutability(&self) -> Mutability {
         self.ref_prefix.mutability
     }
+
+    /// The suggestion to change the type of the argument and its uses in the body.
+    fn sugg(&self, cx: &LateContext<'tcx>, result: &PtrArgResult) -> Vec<(Span, String)> {
+        iter::once((self.span, format!("{}{}", self.ref_prefix, self.deref_ty.display(cx))))
+            .chain(result.replacements.iter().map(|r| {
+                (
+                    r.expr_span,
+                    format!("{}{}", r.self_span.get_source_text(cx).unwrap(), r.replacement),
+                )
+            }))
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FnKind {
+    Fn,
+    TraitFn,
+    /// A method of a trait which isn't exported.
+    PrivateTraitFn,
+    /// An implementation of a method of a trait which isn't exported.
+    PrivateTraitImplFn,
+}
+
+/// An argument of a method of a trait which isn't exported, or of an implementation of it.
+struct TraitFnArg {
+    idx: usize,
+    emission_id: HirId,
+    span: Span,
+    msg: String,
+    skip: bool,
+    sugg: Vec<(Span, String)>,
+}
+impl TraitFnArg {
+    fn new<'tcx>(cx: &LateContext<'tcx>, arg: &PtrArg<'tcx>, result: &PtrArgResult) -> Self {
+        Self {
+            idx: arg.idx,
+            emission_id: arg.emission_id,
+            span: arg.span,
+            msg: arg.build_msg(),
+            skip: result.skip,
+            sugg: arg.sugg(cx, result),
+        }
+    }
+
+    fn from_results<'tcx>(cx: &LateContext<'tcx>, args: &[PtrArg<'tcx>], results: &[PtrArgResult]) -> Vec<Self> {
+        args.iter()
+            .zip(results)
+            .map(|(arg, result)| Self::new(cx, arg, result))
+            .collect()
+    }
 }
 
 struct RefPrefix {
@@ -414,11 +548,14 @@ impl<'tcx> DerefTy<'tcx> {
     }
 }
 
+/// Finds the arguments which could be slices. `lint_cow` is `false` if the types are dictated by
+/// a trait or a bound, which is linted instead.
 fn check_fn_args<'cx, 'tcx: 'cx>(
     cx: &'cx LateContext<'tcx>,
     fn_sig: ty::FnSig<'tcx>,
     hir_tys: &'tcx [hir::Ty<'tcx>],
     params: &'tcx [Param<'tcx>],
+    lint_cow: bool,
 ) -> impl Iterator<Item = PtrArg<'tcx>> + 'cx {
     fn_sig
         .inputs()
@@ -455,35 +592,17 @@ fn check_fn_args<'cx, 'tcx: 'cx>(
                     },
                     Some(sym::PathBuf) => ([("clone", ".to_path_buf()"), ("as_path", "")].as_slice(), DerefTy::Path),
                     Some(sym::Cow) if mutability == Mutability::Not => {
-                        if let Some((lifetime, ty)) = name.args.and_then(|args| {
-                            if let [GenericArg::Lifetime(lifetime), ty] = args.args {
-                                return Some((lifetime, ty));
-                            }
-                            None
-                        }) {
+                        if lint_cow
+                            && let Some((lifetime, ty)) = name.args.and_then(|args| {
+                                if let [GenericArg::Lifetime(lifetime), ty] = args.args {
+                                    return Some((lifetime, ty));
+                                }
+                                None
+                            })
+                        {
                             if let LifetimeName::Param(param_def_id) = lifetime.res
                                 && !lifetime.is_anonymous()
-                                && fn_sig
-                                    .output()
-                                    .walk()
-                                    .filter_map(|arg| {
-                                        arg.as_region().and_then(|lifetime| match lifetime.kind() {
-                                            ty::ReEarlyParam(r) => Some(
-                                                cx.tcx
-                                                    .generics_of(cx.tcx.parent(param_def_id.to_def_id()))
-                                                    .region_param(r, cx.tcx)
-                                                    .def_id,
-                                            ),
-                                            ty::ReBound(_, r) => r.kind.get_id(),
-                                            ty::ReLateParam(r) => r.kind.get_id(),
-                                            ty::ReStatic
-                                            | ty::ReVar(_)
-                                            | ty::RePlaceholder(_)
-                                            | ty::ReErased
-                                            | ty::ReError(_) => None,
-                                        })
-                                    })
-                                    .any(|def_id| def_id.as_local().is_some_and(|def_id| def_id == param_def_id))
+                                && output_uses_lifetime(cx, fn_sig, param_def_id)
                             {
                                 // `&Cow<'a, T>` when the return type uses 'a is okay
                                 return None;
@@ -526,6 +645,177 @@ fn check_fn_args<'cx, 'tcx: 'cx>(
         })
 }
 
+/// Whether the return type of `fn_sig` uses the lifetime parameter `param_def_id`.
+fn output_uses_lifetime<'tcx>(cx: &LateContext<'tcx>, fn_sig: ty::FnSig<'tcx>, param_def_id: LocalDefId) -> bool {
+    fn_sig
+        .output()
+        .walk()
+        .filter_map(|arg| {
+            arg.as_region().and_then(|lifetime| match lifetime.kind() {
+                ty::ReEarlyParam(r) => Some(
+                    cx.tcx
+                        .generics_of(cx.tcx.parent(param_def_id.to_def_id()))
+                        .region_param(r, cx.tcx)
+                        .def_id,
+                ),
+                ty::ReBound(_, r) => r.kind.get_id(),
+                ty::ReLateParam(r) => r.kind.get_id(),
+                ty::ReStatic | ty::ReVar(_) | ty::RePlaceholder(_) | ty::ReErased | ty::ReError(_) => None,
+            })
+        })
+        .any(|def_id| def_id.as_local().is_some_and(|def_id| def_id == param_def_id))
+}
+
+/// Whether `fn_id` is a method of a trait which isn't exported, so that all of its
+/// implementations are in this crate.
+fn is_private_trait_fn(cx: &LateContext<'_>, fn_id: DefId) -> bool {
+    fn_id
+        .as_local()
+        .is_some_and(|fn_id| !cx.effective_visibilities.is_exported(cx.tcx.local_parent(fn_id)))
+}
+
+/// Checks the parameters of a closure passed to a function of this crate, whose types come from
+/// a bound like `F: Fn(&Vec<u32>)` of the function. The bound is changed along with the closure.
+fn check_closure<'tcx>(
+    cx: &LateContext<'tcx>,
+    body: &Body<'tcx>,
+    closure_expr: &'tcx Expr<'tcx>,
+    closure: &Closure<'tcx>,
+) {
+    let Some(call) = get_parent_expr(cx, closure_expr) else {
+        return;
+    };
+    let (callee_id, arg_idx) = match call.kind {
+        ExprKind::Call(callee, args) => (
+            path_def_id(cx, callee),
+            args.iter().position(|arg| arg.hir_id == closure_expr.hir_id),
+        ),
+        ExprKind::MethodCall(_, _, args, _) => (
+            cx.typeck_results().type_dependent_def_id(call.hir_id),
+            // The receiver is the first input of the method
+            args.iter()
+                .position(|arg| arg.hir_id == closure_expr.hir_id)
+                .map(|idx| idx + 1),
+        ),
+        _ => return,
+    };
+    if let Some(callee_id) = callee_id.and_then(DefId::as_local)
+        && let Some(arg_idx) = arg_idx
+        && matches!(cx.tcx.def_kind(callee_id), DefKind::Fn | DefKind::AssocFn)
+        && let callee_sig = cx.tcx.fn_sig(callee_id).instantiate_identity().skip_binder()
+        && let Some(&param_ty) = callee_sig.inputs().get(arg_idx)
+        && let ty::Param(param) = *param_ty.kind()
+        // The bound can only be changed if it's only used by the closure
+        && callee_sig
+            .inputs_and_output
+            .iter()
+            .enumerate()
+            .all(|(idx, ty)| idx == arg_idx || !ty.walk().any(|arg| arg == param_ty.into()))
+        && let Some(bound_inputs) = fn_bound_inputs(cx, callee_id, param)
+        && is_only_called(cx, callee_id, arg_idx)
+        && let ty::Closure(_, closure_args) = *cx.typeck_results().expr_ty(closure_expr).kind()
+    {
+        let closure_sig = cx
+            .tcx
+            .signature_unclosure(closure_args.as_closure().sig(), Safety::Safe)
+            .skip_binder();
+        let lint_args: Vec<_> = check_fn_args(cx, closure_sig, bound_inputs, body.params, false)
+            // The type of an annotated parameter may not be nameable in the function
+            .filter(|arg| {
+                matches!(
+                    closure.fn_decl.inputs.get(arg.idx),
+                    Some(hir::Ty {
+                        kind: TyKind::Infer,
+                        ..
+                    })
+                )
+            })
+            .collect();
+        let results = check_ptr_arg_usage(cx, body, &lint_args);
+
+        for (result, arg) in results.iter().zip(&lint_args).filter(|(r, _)| !r.skip) {
+            span_lint_hir_and_then(
+                cx,
+                PTR_ARG,
+                arg.emission_id,
+                body.params[arg.idx].span,
+                arg.build_msg(),
+                |diag| {
+                    diag.span_note(arg.span, "the type of the parameter comes from this bound");
+                    diag.multipart_suggestion_verbose(
+                        "change this to",
+                        arg.sugg(cx, result),
+                        Applicability::Unspecified,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Finds the parameter types of the `Fn` bound of the type parameter `param` of `fn_id`.
+fn fn_bound_inputs<'tcx>(
+    cx: &LateContext<'tcx>,
+    fn_id: LocalDefId,
+    param: ty::ParamTy,
+) -> Option<&'tcx [hir::Ty<'tcx>]> {
+    let param_id = cx.tcx.generics_of(fn_id).type_param(param, cx.tcx).def_id;
+    cx.tcx
+        .hir()
+        .get_generics(fn_id)?
+        .predicates
+        .iter()
+        .find_map(|predicate| {
+            if let WherePredicateKind::BoundPredicate(predicate) = predicate.kind
+                && predicate
+                    .bounded_ty
+                    .as_generic_param()
+                    .is_some_and(|(id, _)| id == param_id)
+            {
+                predicate.bounds.iter().find_map(|bound| {
+                    if let GenericBound::Trait(trait_bound) = bound
+                        && let Some(trait_id) = trait_bound.trait_ref.trait_def_id()
+                        && cx.tcx.fn_trait_kind_from_def_id(trait_id).is_some()
+                        && let [.., segment] = trait_bound.trait_ref.path.segments
+                        && let Some((inputs, _)) = segment.args().paren_sugar_inputs_output()
+                        && !trait_bound.span.from_expansion()
+                    {
+                        Some(inputs)
+                    } else {
+                        None
+                    }
+                })
+            } else {
+                None
+            }
+        })
+}
+
+/// Whether the parameter `idx` of `fn_id` is only ever called, so it can be passed arguments
+/// coerced to another type.
+fn is_only_called(cx: &LateContext<'_>, fn_id: LocalDefId, idx: usize) -> bool {
+    let Some(body) = cx.tcx.hir().maybe_body_owned_by(fn_id) else {
+        return false;
+    };
+    let Some(PatKind::Binding(BindingMode::NONE, id, _, None)) = body.params.get(idx).map(|param| param.pat.kind)
+    else {
+        return false;
+    };
+    for_each_expr(cx, body.value, |e| {
+        if path_to_local_id(e, id)
+            && !matches!(
+                get_parent_expr(cx, e),
+                Some(Expr { kind: ExprKind::Call(callee, _), .. }) if callee.hir_id == e.hir_id
+            )
+        {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_none()
+}
+
 fn check_mut_from_ref<'tcx>(cx: &LateContext<'tcx>, sig: &FnSig<'_>, body: Option<&Body<'tcx>>) {
     if let FnRetTy::Return(ty) = sig.decl.output
         && let Some((out, Mutability::Mut, _)) = get_ref_lm(ty)
@@ -7,9 +7,9 @@ use clippy_utils::msrvs::Msrv;
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
 use clippy_utils::{
-    eq_expr_value, higher, is_else_clause, is_in_const_context, is_lint_allowed, is_path_lang_item, is_res_lang_ctor,
-    pat_and_expr_can_be_question_mark, path_res, path_to_local, path_to_local_id, peel_blocks, peel_blocks_with_stmt,
-    span_contains_cfg, span_contains_comment,
+    eq_expr_value, higher, is_else_clause, is_in_const_context, is_lint_allowed, is_path_lang_item, is_refutable,
+    is_res_lang_ctor, is_trait_method, pat_and_expr_can_be_question_mark, path_res, path_to_local, path_to_local_id,
+    peel_blocks, peel_blocks_with_stmt, span_contains_cfg, span_contains_comment,
 };
 use rustc_errors::Applicability;
 use rustc_hir::LangItem::{self, OptionNone, OptionSome, ResultErr, ResultOk};
@@ -113,52 +113,128 @@ fn find_let_else_ret_expression<'hir>(block: &'hir Block<'hir>) -> Option<&'hir
     }
 }
 
+/// Make sure the init expr implements try trait so a valid suggestion could be given.
+///
+/// Because the init expr could have the type of `&Option<T>` which does not implements `Try`.
+///
+/// NB: This conveniently prevents the cause of
+/// issue [#12412](https://github.com/rust-lang/rust-clippy/issues/12412),
+/// since accessing an `Option` field from a borrowed struct requires borrow, such as
+/// `&some_struct.opt`, which is type of `&Option`. And we can't suggest `&some_struct.opt?`
+/// or `(&some_struct.opt)?` since the first one has different semantics and the later does
+/// not implements `Try`.
+fn init_expr_can_use_question_mark(cx: &LateContext<'_>, init_expr: &Expr<'_>) -> bool {
+    let init_ty = cx.typeck_results().expr_ty_adjusted(init_expr);
+    cx.tcx
+        .lang_items()
+        .try_trait()
+        .is_some_and(|did| implements_trait(cx, init_ty, did, &[]))
+}
+
 fn check_let_some_else_return_none(cx: &LateContext<'_>, stmt: &Stmt<'_>) {
-    /// Make sure the init expr implements try trait so a valid suggestion could be given.
-    ///
-    /// Because the init expr could have the type of `&Option<T>` which does not implements `Try`.
-    ///
-    /// NB: This conveniently prevents the cause of
-    /// issue [#12412](https://github.com/rust-lang/rust-clippy/issues/12412),
-    /// since accessing an `Option` field from a borrowed struct requires borrow, such as
-    /// `&some_struct.opt`, which is type of `&Option`. And we can't suggest `&some_struct.opt?`
-    /// or `(&some_struct.opt)?` since the first one has different semantics and the later does
-    /// not implements `Try`.
-    fn init_expr_can_use_question_mark(cx: &LateContext<'_>, init_expr: &Expr<'_>) -> bool {
-        let init_ty = cx.typeck_results().expr_ty_adjusted(init_expr);
-        cx.tcx
-            .lang_items()
-            .try_trait()
-            .is_some_and(|did| implements_trait(cx, init_ty, did, &[]))
+    if let StmtKind::Let(LetStmt {
+        pat,
+        init: Some(init_expr),
+        els: Some(els),
+        ..
+    }) = stmt.kind
+        && init_expr_can_use_question_mark(cx, init_expr)
+        && let Some(ret) = find_let_else_ret_expression(els)
+        && let Some(inner_pat) = pat_and_expr_can_be_question_mark(cx, pat, ret)
+        && !span_contains_comment(cx.tcx.sess.source_map(), els.span)
+    {
+        emit_let_else_question_mark(cx, stmt, init_expr, inner_pat);
     }
+}
 
+/// Checks for `let Ok(x) = expr else { return Err(e) };`, where `e` (optionally passed through
+/// `.into()` or `From::from`) is the only possible value of `expr`'s error type.
+///
+/// Since the `else` branch has no access to the original error, this is only equivalent to `?`
+/// when the error type carries no information, i.e. `()` or a fieldless struct. The conversion,
+/// if any, also has to be the `From` conversion that `?` would apply.
+fn check_let_ok_else_return_err(cx: &LateContext<'_>, stmt: &Stmt<'_>) {
     if let StmtKind::Let(LetStmt {
         pat,
         init: Some(init_expr),
         els: Some(els),
         ..
     }) = stmt.kind
+        && let PatKind::TupleStruct(ref pat_path, [inner_pat], _) = pat.kind
+        && is_res_lang_ctor(cx, cx.qpath_res(pat_path, pat.hir_id), ResultOk)
+        && !is_refutable(cx, inner_pat)
         && init_expr_can_use_question_mark(cx, init_expr)
+        && let ty::Adt(init_adt, init_args) = cx.typeck_results().expr_ty_adjusted(init_expr).kind()
+        && cx.tcx.is_diagnostic_item(sym::Result, init_adt.did())
+        && let err_ty = init_args.type_at(1)
+        && is_unit_like(err_ty)
         && let Some(ret) = find_let_else_ret_expression(els)
-        && let Some(inner_pat) = pat_and_expr_can_be_question_mark(cx, pat, ret)
+        && let ExprKind::Ret(Some(ret_val)) = peel_blocks(ret).kind
+        && let ExprKind::Call(err_ctor, [err_arg]) = ret_val.kind
+        && is_res_lang_ctor(cx, path_res(cx, err_ctor), ResultErr)
+        && let ty::Adt(_, ret_args) = cx.typeck_results().expr_ty(ret_val).kind()
+        && let Some(err_val) = peel_err_conversion(cx, err_arg, ret_args.type_at(1))
+        && cx.typeck_results().expr_ty(err_val) == err_ty
+        && matches!(err_val.kind, ExprKind::Tup([]) | ExprKind::Path(_))
+        && !ret_val.span.from_expansion()
         && !span_contains_comment(cx.tcx.sess.source_map(), els.span)
     {
-        let mut applicability = Applicability::MaybeIncorrect;
-        let init_expr_str = snippet_with_applicability(cx, init_expr.span, "..", &mut applicability);
-        let receiver_str = snippet_with_applicability(cx, inner_pat.span, "..", &mut applicability);
-        let sugg = format!("let {receiver_str} = {init_expr_str}?;",);
-        span_lint_and_sugg(
-            cx,
-            QUESTION_MARK,
-            stmt.span,
-            "this `let...else` may be rewritten with the `?` operator",
-            "replace it with",
-            sugg,
-            applicability,
-        );
+        emit_let_else_question_mark(cx, stmt, init_expr, inner_pat);
+    }
+}
+
+/// Returns `true` for types with exactly one value, whose construction can't be distinguished
+/// from the error that `?` would propagate.
+fn is_unit_like(ty: Ty<'_>) -> bool {
+    match ty.kind() {
+        ty::Tuple(tys) => tys.is_empty(),
+        ty::Adt(adt, _) => adt.is_struct() && adt.all_fields().next().is_none(),
+        _ => false,
     }
 }
 
+/// Peels `e.into()` and `From::from(e)` off of the returned error, as long as the returned error
+/// type implements `From` for the type of `e`. Without a conversion the value is returned as is.
+fn peel_err_conversion<'a, 'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'a Expr<'a>,
+    ret_err_ty: Ty<'tcx>,
+) -> Option<&'a Expr<'a>> {
+    let inner = match expr.kind {
+        ExprKind::MethodCall(_, recv, [], _) if is_trait_method(cx, expr, sym::Into) => recv,
+        ExprKind::Call(callee, [arg])
+            if let ExprKind::Path(ref qpath) = callee.kind
+                && let Some(def_id) = cx.qpath_res(qpath, callee.hir_id).opt_def_id()
+                && let Some(trait_id) = cx.tcx.trait_of_item(def_id)
+                && cx.tcx.is_diagnostic_item(sym::From, trait_id) =>
+        {
+            arg
+        },
+        _ => return Some(expr),
+    };
+    let inner_ty = cx.typeck_results().expr_ty(inner);
+    cx.tcx
+        .get_diagnostic_item(sym::From)
+        .is_some_and(|from| implements_trait(cx, ret_err_ty, from, &[inner_ty.into()]))
+        .then_some(inner)
+}
+
+fn emit_let_else_question_mark(cx: &LateContext<'_>, stmt: &Stmt<'_>, init_expr: &Expr<'_>, inner_pat: &Pat<'_>) {
+    let mut applicability = Applicability::MaybeIncorrect;
+    let init_expr_str = snippet_with_applicability(cx, init_expr.span, "..", &mut applicability);
+    let receiver_str = snippet_with_applicability(cx, inner_pat.span, "..", &mut applicability);
+    let sugg = format!("let {receiver_str} = {init_expr_str}?;",);
+    span_lint_and_sugg(
+        cx,
+        QUESTION_MARK,
+        stmt.span,
+        "this `let...else` may be rewritten with the `?` operator",
+        "replace it with",
+        sugg,
+        applicability,
+    );
+}
+
 fn is_early_return(smbl: Symbol, cx: &LateContext<'_>, if_block: &IfBlockType<'_>) -> bool {
     match *if_block {
         IfBlockType::IfIs(caller, caller_ty, call_sym, if_then) => {
@@ -491,6 +567,10 @@ impl<'tcx> LateLintPass<'tcx> for QuestionMark {
 
         if !self.inside_try_block() && !is_in_const_context(cx) {
             check_let_some_else_return_none(cx, stmt);
+            // The `else` branch may be the only thing pinning down the return type of the closure.
+            if self.inferred_ret_closure_stack == 0 {
+                check_let_ok_else_return_err(cx, stmt);
+            }
         }
         self.check_manual_let_else(cx, stmt);
     }
@@ -0,0 +1,262 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::peel_blocks;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Body, Expr, ExprKind, Impl, ImplItemKind, Item, ItemKind, Node, StructTailExpr};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::fast_reject::SimplifiedType;
+use rustc_middle::ty::{self, Ty, TypeckResults};
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for manual `Default` implementations which call `Default::default()` for a field
+    /// whose derived `Default` implementation constructs the implementing type, or which call a
+    /// helper function doing so.
+    ///
+    /// The direct recursion through `..Default::default()` is already reported by rustc's
+    /// `unconditional_recursion` lint.
+    ///
+    /// ### Why is this bad?
+    /// The implementation calls itself unconditionally, which overflows the stack.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[derive(Default)]
+    /// struct Inner {
+    ///     outer: Box<Outer>,
+    /// }
+    ///
+    /// struct Outer {
+    ///     inner: Option<Inner>,
+    ///     a: u32,
+    /// }
+    ///
+    /// impl Default for Outer {
+    ///     fn default() -> Self {
+    ///         Outer {
+    ///             inner: Some(Default::default()),
+    ///             a: 1,
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[derive(Default)]
+    /// struct Inner {
+    ///     outer: Box<Outer>,
+    /// }
+    ///
+    /// struct Outer {
+    ///     inner: Option<Inner>,
+    ///     a: u32,
+    /// }
+    ///
+    /// impl Default for Outer {
+    ///     fn default() -> Self {
+    ///         Outer { inner: None, a: 1 }
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub RECURSIVE_DEFAULT_IMPL,
+    correctness,
+    "`Default` implementation which calls itself through a field or a helper function"
+}
+
+declare_lint_pass!(RecursiveDefaultImpl => [RECURSIVE_DEFAULT_IMPL]);
+
+impl<'tcx> LateLintPass<'tcx> for RecursiveDefaultImpl {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Impl(Impl {
+            of_trait: Some(trait_ref),
+            items: [child],
+            ..
+        }) = item.kind
+            && !cx.tcx.has_attr(item.owner_id, sym::automatically_derived)
+            && !item.span.from_expansion()
+            && let Some(default_trait_id) = trait_ref.trait_def_id()
+            && cx.tcx.is_diagnostic_item(sym::Default, default_trait_id)
+            && let Node::ImplItem(impl_item) = cx.tcx.hir_node(child.id.hir_id())
+            && let ImplItemKind::Fn(_, body_id) = impl_item.kind
+        {
+            let checker = Checker {
+                cx,
+                self_ty: cx.tcx.type_of(item.owner_id).instantiate_identity(),
+                default_trait_id,
+            };
+            let Body { value, .. } = cx.tcx.hir().body(body_id);
+            let mut recursions = Vec::new();
+            checker.collect_recursions(
+                cx.tcx.typeck_body(body_id),
+                value,
+                &mut FxHashSet::from_iter([impl_item.owner_id.to_def_id()]),
+                &mut recursions,
+            );
+            for (call, recursion) in recursions {
+                checker.emit(call, recursion);
+            }
+        }
+    }
+}
+
+/// A call which recurses into the checked `Default` implementation.
+#[derive(Clone, Copy)]
+enum Recursion<'tcx> {
+    /// `Default::default()` for a value of this type
+    Default(Ty<'tcx>),
+    /// A call to this local function
+    Helper(DefId),
+}
+
+struct Checker<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    self_ty: Ty<'tcx>,
+    default_trait_id: DefId,
+}
+
+impl<'tcx> Checker<'_, 'tcx> {
+    /// Collects the calls in the value returned by a function which recurse into the `Default`
+    /// implementation of `self_ty`. Only the values which are always evaluated are checked, i.e.
+    /// the ones in struct expressions, constructor calls and tuples.
+    fn collect_recursions(
+        &self,
+        typeck: &'tcx TypeckResults<'tcx>,
+        expr: &'tcx Expr<'tcx>,
+        visited_fns: &mut FxHashSet<DefId>,
+        recursions: &mut Vec<(&'tcx Expr<'tcx>, Recursion<'tcx>)>,
+    ) {
+        let expr = peel_blocks(expr);
+        match expr.kind {
+            ExprKind::Struct(_, fields, tail) => {
+                for field in fields {
+                    self.collect_recursions(typeck, field.expr, visited_fns, recursions);
+                }
+                if let StructTailExpr::Base(base) = tail {
+                    self.collect_recursions(typeck, base, visited_fns, recursions);
+                }
+            },
+            ExprKind::Call(callee, args) if !expr.span.from_expansion() => {
+                if let ExprKind::Path(ref qpath) = callee.kind
+                    && let Res::Def(kind, def_id) = typeck.qpath_res(qpath, callee.hir_id)
+                {
+                    match kind {
+                        DefKind::Ctor(..) => {
+                            for arg in args {
+                                self.collect_recursions(typeck, arg, visited_fns, recursions);
+                            }
+                        },
+                        _ if args.is_empty() && self.cx.tcx.is_diagnostic_item(sym::default_fn, def_id) => {
+                            let ty = typeck.expr_ty(expr);
+                            if self.default_reaches_self(ty, &mut FxHashSet::default()) {
+                                recursions.push((expr, Recursion::Default(ty)));
+                            }
+                        },
+                        DefKind::Fn | DefKind::AssocFn if self.helper_recurses(def_id, visited_fns) => {
+                            recursions.push((expr, Recursion::Helper(def_id)));
+                        },
+                        _ => {},
+                    }
+                }
+            },
+            ExprKind::Tup(elems) => {
+                for elem in elems {
+                    self.collect_recursions(typeck, elem, visited_fns, recursions);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    /// Whether the local function `fn_id` returns a value whose construction recurses into the
+    /// `Default` implementation of `self_ty`.
+    fn helper_recurses(&self, fn_id: DefId, visited_fns: &mut FxHashSet<DefId>) -> bool {
+        if let Some(fn_id) = fn_id.as_local()
+            && visited_fns.insert(fn_id.to_def_id())
+            && let Some(body) = self.cx.tcx.hir().maybe_body_owned_by(fn_id)
+        {
+            let mut recursions = Vec::new();
+            self.collect_recursions(self.cx.tcx.typeck(fn_id), body.value, visited_fns, &mut recursions);
+            !recursions.is_empty()
+        } else {
+            false
+        }
+    }
+
+    fn emit(&self, call: &Expr<'_>, recursion: Recursion<'tcx>) {
+        // The direct recursion is reported by `unconditional_recursion`
+        if let Recursion::Default(ty) = recursion
+            && ty == self.self_ty
+        {
+            return;
+        }
+        span_lint_and_then(
+            self.cx,
+            RECURSIVE_DEFAULT_IMPL,
+            call.span,
+            "this call recurses into the `Default` implementation it is part of",
+            |diag| match recursion {
+                Recursion::Default(ty) => {
+                    diag.note(format!(
+                        "`{ty}::default()` calls `{}::default()` through derived `Default` implementations",
+                        self.self_ty
+                    ));
+                    diag.help("initialize the value without calling `Default::default()`");
+                },
+                Recursion::Helper(fn_id) => {
+                    diag.note(format!(
+                        "`{}` calls `{}::default()`",
+                        self.cx.tcx.def_path_str(fn_id),
+                        self.self_ty
+                    ));
+                },
+            },
+        );
+    }
+
+    /// Whether `ty`'s `Default` implementation calls the one of `self_ty`, following the fields of
+    /// derived implementations and smart pointers.
+    fn default_reaches_self(&self, ty: Ty<'tcx>, visited: &mut FxHashSet<Ty<'tcx>>) -> bool {
+        if ty == self.self_ty {
+            return true;
+        }
+        if !visited.insert(ty) {
+            return false;
+        }
+        match *ty.kind() {
+            ty::Tuple(tys) => tys.iter().any(|ty| self.default_reaches_self(ty, visited)),
+            ty::Array(elem, len) => {
+                len.try_to_target_usize(self.cx.tcx).is_some_and(|len| len > 0)
+                    && self.default_reaches_self(elem, visited)
+            },
+            ty::Adt(adt, args)
+                if adt.is_box()
+                    || is_type_diagnostic_item(self.cx, ty, sym::Rc)
+                    || is_type_diagnostic_item(self.cx, ty, sym::Arc) =>
+            {
+                self.default_reaches_self(args.type_at(0), visited)
+            },
+            ty::Adt(adt, args) if adt.is_struct() && self.has_derived_default(adt.did()) => adt
+                .all_fields()
+                .any(|field| self.default_reaches_self(field.ty(self.cx.tcx, args), visited)),
+            _ => false,
+        }
+    }
+
+    fn has_derived_default(&self, adt_id: DefId) -> bool {
+        self.cx
+            .tcx
+            .trait_impls_of(self.default_trait_id)
+            .non_blanket_impls()
+            .get(&SimplifiedType::Adt(adt_id))
+            .is_some_and(|impls| {
+                impls
+                    .iter()
+                    .any(|&impl_id| self.cx.tcx.has_attr(impl_id, sym::automatically_derived))
+            })
+    }
+}
@@ -1,7 +1,9 @@
-use clippy_utils::diagnostics::span_lint_and_help;
-use clippy_utils::is_from_proc_macro;
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::source::{IntoSpan, SpanRangeExt};
 use clippy_utils::ty::needs_ordered_drop;
+use clippy_utils::{is_from_proc_macro, peel_blocks};
 use rustc_ast::Mutability;
+use rustc_errors::Applicability;
 use rustc_hir::def::Res;
 use rustc_hir::{BindingMode, ByRef, ExprKind, HirId, LetStmt, Node, Pat, PatKind, QPath};
 use rustc_hir_typeck::expr_use_visitor::PlaceBase;
@@ -9,8 +11,8 @@ use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::UpvarCapture;
 use rustc_session::declare_lint_pass;
-use rustc_span::DesugaringKind;
 use rustc_span::symbol::Ident;
+use rustc_span::{DesugaringKind, Span};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -44,7 +46,38 @@ declare_clippy_lint! {
     suspicious,
     "redundant redefinition of a local binding"
 }
-declare_lint_pass!(RedundantLocals => [REDUNDANT_LOCALS]);
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for redefinitions of local bindings which `redundant_locals` doesn't catch: the
+    /// binding is wrapped in a block, as in `let x = { x };`, or a function parameter is redefined
+    /// as mutable by the first statement of the function.
+    ///
+    /// ### Why is this bad?
+    /// The block doesn't change what the redefinition does, which is nothing but shortening the
+    /// lifetime of the variable. A parameter can be declared mutable directly.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn foo(a: i32, b: Vec<i32>) {
+    ///     let a = { a };
+    ///     let mut b = b;
+    ///     b.push(a);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn foo(a: i32, mut b: Vec<i32>) {
+    ///     b.push(a);
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub REDUNDANT_LOCALS_THROUGH_BLOCKS,
+    pedantic,
+    "redundant redefinition of a local binding through a block, or of a parameter as mutable"
+}
+
+declare_lint_pass!(RedundantLocals => [REDUNDANT_LOCALS, REDUNDANT_LOCALS_THROUGH_BLOCKS]);
 
 impl<'tcx> LateLintPass<'tcx> for RedundantLocals {
     fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx LetStmt<'tcx>) {
@@ -53,8 +86,9 @@ impl<'tcx> LateLintPass<'tcx> for RedundantLocals {
             && let PatKind::Binding(BindingMode(ByRef::No, mutability), _, ident, None) = local.pat.kind
             // the binding is not type-ascribed
             && local.ty.is_none()
-            // the expression is a resolved path
-            && let Some(expr) = local.init
+            // the expression is a resolved path, possibly wrapped in blocks written by the user
+            && let Some(init) = local.init
+            && let expr = if init.span.from_expansion() { init } else { peel_blocks(init) }
             && let ExprKind::Path(qpath @ QPath::Resolved(None, path)) = expr.kind
             // the path is a single segment equal to the local's name
             && let [last_segment] = path.segments
@@ -62,29 +96,79 @@ impl<'tcx> LateLintPass<'tcx> for RedundantLocals {
             // resolve the path to its defining binding pattern
             && let Res::Local(binding_id) = cx.qpath_res(&qpath, expr.hir_id)
             && let Node::Pat(binding_pat) = cx.tcx.hir_node(binding_id)
-            // the previous binding has the same mutability
-            && find_binding(binding_pat, ident).is_some_and(|bind| bind.1 == mutability)
-            // the local does not change the effect of assignments to the binding. see #11290
-            && !affects_assignments(cx, mutability, binding_id, local.hir_id)
+            && let Some(BindingMode(_, binding_mutability)) = find_binding(binding_pat, ident)
             // the local does not affect the code's drop behavior
             && !needs_ordered_drop(cx, cx.typeck_results().expr_ty(expr))
             // the local is user-controlled
             && !in_external_macro(cx.sess(), local.span)
-            && !is_from_proc_macro(cx, expr)
+            && !is_from_proc_macro(cx, init)
             && !is_by_value_closure_capture(cx, local.hir_id, binding_id)
         {
-            span_lint_and_help(
-                cx,
-                REDUNDANT_LOCALS,
-                local.span,
-                format!("redundant redefinition of a binding `{ident}`"),
-                Some(binding_pat.span),
-                format!("`{ident}` is initially defined here"),
-            );
+            if binding_mutability == mutability
+                // the local does not change the effect of assignments to the binding. see #11290
+                && !affects_assignments(cx, mutability, binding_id, local.hir_id)
+            {
+                let lint = if init.hir_id == expr.hir_id {
+                    REDUNDANT_LOCALS
+                } else {
+                    REDUNDANT_LOCALS_THROUGH_BLOCKS
+                };
+                span_lint_and_help(
+                    cx,
+                    lint,
+                    local.span,
+                    format!("redundant redefinition of a binding `{ident}`"),
+                    Some(binding_pat.span),
+                    format!("`{ident}` is initially defined here"),
+                );
+            } else if mutability == Mutability::Mut
+                && let PatKind::Binding(BindingMode::NONE, ..) = binding_pat.kind
+                && !binding_pat.span.from_expansion()
+                && let Some(stmt_span) = first_stmt_of_body(cx, local)
+                && let Node::Param(_) = cx.tcx.parent_hir_node(binding_id)
+            {
+                span_lint_and_then(
+                    cx,
+                    REDUNDANT_LOCALS_THROUGH_BLOCKS,
+                    local.span,
+                    format!("redundant redefinition of the parameter `{ident}` as mutable"),
+                    |diag| {
+                        diag.multipart_suggestion(
+                            "declare the parameter as mutable instead",
+                            vec![
+                                (binding_pat.span.shrink_to_lo(), "mut ".into()),
+                                (
+                                    stmt_span.with_leading_whitespace(cx).with_ctxt(stmt_span.ctxt()),
+                                    String::new(),
+                                ),
+                            ],
+                            Applicability::MachineApplicable,
+                        );
+                    },
+                );
+            }
         }
     }
 }
 
+/// Checks if the local is the first statement of the body of the enclosing function or closure,
+/// and returns the span of the statement.
+fn first_stmt_of_body(cx: &LateContext<'_>, local: &LetStmt<'_>) -> Option<Span> {
+    let Node::Stmt(stmt) = cx.tcx.parent_hir_node(local.hir_id) else {
+        return None;
+    };
+    let Node::Block(block) = cx.tcx.parent_hir_node(stmt.hir_id) else {
+        return None;
+    };
+    let body = cx.tcx.hir().body(cx.enclosing_body?);
+    if !matches!(body.value.kind, ExprKind::Block(body_block, _) if body_block.hir_id == block.hir_id)
+        || block.stmts.first()?.hir_id != stmt.hir_id
+    {
+        return None;
+    }
+    Some(stmt.span)
+}
+
 /// Checks if the enclosing body is a closure and if the given local is captured by value.
 ///
 /// In those cases, the redefinition may be necessary to force a move:
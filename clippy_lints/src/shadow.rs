@@ -1,17 +1,21 @@
 use std::ops::ControlFlow;
 
+use clippy_config::Conf;
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::path_to_local_id;
-use clippy_utils::source::snippet;
+use clippy_utils::source::{snippet, snippet_opt};
 use clippy_utils::visitors::{Descend, Visitable, for_each_expr};
 use rustc_data_structures::fx::FxHashMap;
+use rustc_errors::Applicability;
 use rustc_hir::def::Res;
 use rustc_hir::def_id::LocalDefId;
 use rustc_hir::hir_id::ItemLocalId;
-use rustc_hir::{Block, Body, BodyOwnerKind, Expr, ExprKind, HirId, LetExpr, Node, Pat, PatKind, QPath, UnOp};
+use rustc_hir::{
+    Block, Body, BodyOwnerKind, Expr, ExprField, ExprKind, HirId, LetExpr, Node, Pat, PatField, PatKind, QPath, UnOp,
+};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::impl_lint_pass;
-use rustc_span::{Span, Symbol};
+use rustc_span::{Ident, Span, Symbol};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -23,6 +27,9 @@ declare_clippy_lint! {
     ///
     /// See also `shadow_reuse` and `shadow_unrelated` for other restrictions on shadowing.
     ///
+    /// Shadowing with a value of a different type, like `let x = &x;`, can be allowed with the
+    /// `allow-type-changing-shadows` configuration.
+    ///
     /// ### Example
     /// ```no_run
     /// # let x = 1;
@@ -52,6 +59,9 @@ declare_clippy_lint! {
     ///
     /// See also `shadow_same` and `shadow_unrelated` for other restrictions on shadowing.
     ///
+    /// Converting a value while keeping its name, like `let x = x.parse()?;`, can be allowed with
+    /// the `allow-type-changing-shadows` configuration.
+    ///
     /// ### Example
     /// ```no_run
     /// let x = 2;
@@ -104,11 +114,20 @@ declare_clippy_lint! {
     "rebinding a name without even using the original value"
 }
 
-#[derive(Default)]
 pub(crate) struct Shadow {
+    allow_type_changing_shadows: bool,
     bindings: Vec<(FxHashMap<Symbol, Vec<ItemLocalId>>, LocalDefId)>,
 }
 
+impl Shadow {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            allow_type_changing_shadows: conf.allow_type_changing_shadows,
+            bindings: Vec::new(),
+        }
+    }
+}
+
 impl_lint_pass!(Shadow => [SHADOW_SAME, SHADOW_REUSE, SHADOW_UNRELATED]);
 
 impl<'tcx> LateLintPass<'tcx> for Shadow {
@@ -126,6 +145,7 @@ impl<'tcx> LateLintPass<'tcx> for Shadow {
         }
 
         let HirId { owner, local_id } = id;
+        let allow_type_changing_shadows = self.allow_type_changing_shadows;
         // get (or insert) the list of items for this owner and symbol
         let (ref mut data, scope_owner) = *self.bindings.last_mut().unwrap();
         let items_with_name = data.entry(ident.name).or_default();
@@ -139,7 +159,15 @@ impl<'tcx> LateLintPass<'tcx> for Shadow {
 
             if is_shadow(cx, scope_owner, prev, local_id) {
                 let prev_hir_id = HirId { owner, local_id: prev };
-                lint_shadow(cx, pat, prev_hir_id, ident.span);
+                lint_shadow(
+                    cx,
+                    pat,
+                    prev_hir_id,
+                    ident,
+                    scope_owner,
+                    items_with_name.len() + 1,
+                    allow_type_changing_shadows,
+                );
                 // only lint against the "nearest" shadowed binding
                 break;
             }
@@ -200,9 +228,49 @@ pub fn is_local_used_except<'tcx>(
     .is_some()
 }
 
-fn lint_shadow(cx: &LateContext<'_>, pat: &Pat<'_>, shadowed: HirId, span: Span) {
-    let (lint, msg) = match find_init(cx, pat.hir_id) {
-        Some((expr, _)) if is_self_shadow(cx, pat, expr, shadowed) => {
+/// How a binding relates to the binding it shadows.
+enum ShadowKind<'tcx> {
+    /// `let x = &x;`, with the initializer
+    Same(&'tcx Expr<'tcx>),
+    /// `let x = x + 1;`
+    Reuse,
+    /// `let x = y;`
+    Unrelated,
+}
+
+fn shadow_kind<'tcx>(cx: &LateContext<'tcx>, pat: &Pat<'_>, shadowed: HirId) -> ShadowKind<'tcx> {
+    match find_init(cx, pat.hir_id) {
+        Some((expr, _)) if is_self_shadow(cx, pat, expr, shadowed) => ShadowKind::Same(expr),
+        Some((expr, except)) if is_local_used_except(cx, expr, shadowed, except) => ShadowKind::Reuse,
+        _ => ShadowKind::Unrelated,
+    }
+}
+
+/// `nth` is the number of bindings with the same name in `scope_owner`, including this one. It's
+/// used to derive a new name for the binding.
+fn lint_shadow(
+    cx: &LateContext<'_>,
+    pat: &Pat<'_>,
+    shadowed: HirId,
+    ident: Ident,
+    scope_owner: LocalDefId,
+    nth: usize,
+    allow_type_changing_shadows: bool,
+) {
+    let kind = shadow_kind(cx, pat, shadowed);
+    // `let x = x.parse()?;`
+    if allow_type_changing_shadows
+        && matches!(kind, ShadowKind::Same(_) | ShadowKind::Reuse)
+        && let typeck = cx.typeck_results()
+        && typeck
+            .node_type_opt(shadowed)
+            .is_some_and(|shadowed_ty| shadowed_ty != typeck.node_type(pat.hir_id))
+    {
+        return;
+    }
+
+    let (lint, msg) = match kind {
+        ShadowKind::Same(expr) => {
             let msg = format!(
                 "`{}` is shadowed by itself in `{}`",
                 snippet(cx, pat.span, "_"),
@@ -210,20 +278,94 @@ fn lint_shadow(cx: &LateContext<'_>, pat: &Pat<'_>, shadowed: HirId, span: Span)
             );
             (SHADOW_SAME, msg)
         },
-        Some((expr, except)) if is_local_used_except(cx, expr, shadowed, except) => {
+        ShadowKind::Reuse => {
             let msg = format!("`{}` is shadowed", snippet(cx, pat.span, "_"));
             (SHADOW_REUSE, msg)
         },
-        _ => {
+        ShadowKind::Unrelated => {
             let msg = format!("`{}` shadows a previous, unrelated binding", snippet(cx, pat.span, "_"));
             (SHADOW_UNRELATED, msg)
         },
     };
-    span_lint_and_then(cx, lint, span, msg, |diag| {
+    span_lint_and_then(cx, lint, ident.span, msg, |diag| {
         diag.span_note(cx.tcx.hir().span(shadowed), "previous binding is here");
+        // Reusing the shadowed value is usually deliberate, a new name doesn't help
+        if !matches!(kind, ShadowKind::Reuse)
+            && let Some(sugg) = rename_suggestion(cx, pat, ident, scope_owner, nth)
+        {
+            diag.multipart_suggestion(
+                "use a different name for the new binding",
+                sugg,
+                Applicability::MachineApplicable,
+            );
+        }
     });
 }
 
+/// Renames the binding and all of its uses to `<name>_<n>`, the first such name starting at
+/// `nth` which doesn't occur in the body yet.
+fn rename_suggestion(
+    cx: &LateContext<'_>,
+    pat: &Pat<'_>,
+    ident: Ident,
+    scope_owner: LocalDefId,
+    nth: usize,
+) -> Option<Vec<(Span, String)>> {
+    let hir = cx.tcx.hir();
+    // The other alternatives of an or-pattern bind the same name
+    let in_or_pat = hir
+        .parent_iter(pat.hir_id)
+        .map_while(|(_, node)| match node {
+            Node::Pat(pat) => Some(pat),
+            _ => None,
+        })
+        .any(|pat| matches!(pat.kind, PatKind::Or(_)));
+    if in_or_pat {
+        return None;
+    }
+
+    let body_src = snippet_opt(cx, hir.span_with_body(cx.tcx.local_def_id_to_hir_id(scope_owner)))?;
+    let mut n = nth;
+    let mut name = format!("{}_{n}", ident.name);
+    while contains_ident(&body_src, &name) {
+        n += 1;
+        name = format!("{}_{n}", ident.name);
+    }
+    let rename = |hir_id| match cx.tcx.parent_hir_node(hir_id) {
+        Node::ExprField(ExprField { is_shorthand: true, .. }) => format!("{}: {name}", ident.name),
+        _ => name.clone(),
+    };
+
+    let mut sugg = vec![
+        if let Node::PatField(PatField { is_shorthand: true, .. }) = cx.tcx.parent_hir_node(pat.hir_id) {
+            // `Foo { ref mut x }` becomes `Foo { x: ref mut x_2 }`
+            let binding_mode = snippet_opt(cx, pat.span.until(ident.span))?;
+            (pat.span, format!("{}: {binding_mode}{name}", ident.name))
+        } else {
+            (ident.span, name.clone())
+        },
+    ];
+    for_each_expr(cx, hir.body_owned_by(scope_owner).value, |e| {
+        if !path_to_local_id(e, pat.hir_id) {
+            return ControlFlow::Continue(());
+        }
+        if e.span.from_expansion() || snippet_opt(cx, e.span).as_deref() != Some(ident.as_str()) {
+            return ControlFlow::Break(());
+        }
+        sugg.push((e.span, rename(e.hir_id)));
+        ControlFlow::Continue(())
+    })
+    .is_none()
+    .then_some(sugg)
+}
+
+/// Whether `src` contains `ident` as a whole identifier.
+fn contains_ident(src: &str, ident: &str) -> bool {
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+    src.match_indices(ident)
+        .any(|(i, _)| !src[..i].ends_with(is_ident_char) && !src[i + ident.len()..].starts_with(is_ident_char))
+}
+
 /// Returns true if the expression is a simple transformation of a local binding such as `&x`
 fn is_self_shadow(cx: &LateContext<'_>, pat: &Pat<'_>, mut expr: &Expr<'_>, hir_id: HirId) -> bool {
     let hir = cx.tcx.hir();
@@ -0,0 +1,221 @@
+use clippy_config::Conf;
+use clippy_utils::consts::ConstEvalCtxt;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use clippy_utils::{SpanlessEq, higher, is_in_const_context, path_def_id, path_to_local_id};
+use rustc_errors::Applicability;
+use rustc_hir::def::DefKind;
+use rustc_hir::{BindingMode, Block, Expr, ExprKind, Mutability, PatKind, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for slices being overwritten with a single value by copying from a repeated value,
+    /// e.g. `dst.copy_from_slice(&vec![value; dst.len()])` or `dst.clone_from_slice(&[value; N])`,
+    /// and for loops assigning a constant to every element of a slice, including when the
+    /// constant is returned by a helper function.
+    ///
+    /// ### Why is this bad?
+    /// `dst.fill(value)` is clearer and doesn't need a temporary buffer. Building the buffer with
+    /// `vec!` allocates.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn reset(buf: &mut [u8]) {
+    ///     buf.copy_from_slice(&vec![0; buf.len()]);
+    ///     for x in buf.iter_mut() {
+    ///         *x = 0;
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn reset(buf: &mut [u8]) {
+    ///     buf.fill(0);
+    ///     buf.fill(0);
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub SLICE_FILL_WITH_COPY_FROM_SLICE_OF_REPEAT,
+    complexity,
+    "overwriting a slice with copies of a single value without using `fill`"
+}
+
+pub struct SliceFillWithCopyFromSliceOfRepeat {
+    msrv: Msrv,
+}
+
+impl SliceFillWithCopyFromSliceOfRepeat {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            msrv: conf.msrv.clone(),
+        }
+    }
+}
+
+impl_lint_pass!(SliceFillWithCopyFromSliceOfRepeat => [SLICE_FILL_WITH_COPY_FROM_SLICE_OF_REPEAT]);
+
+impl<'tcx> LateLintPass<'tcx> for SliceFillWithCopyFromSliceOfRepeat {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !self.msrv.meets(msrvs::SLICE_FILL) || is_in_const_context(cx) {
+            return;
+        }
+        if let Some(for_loop) = higher::ForLoop::hir(expr) {
+            if !for_loop.body.span.from_expansion() && !in_external_macro(cx.sess(), for_loop.span) {
+                check_loop(cx, &for_loop);
+            }
+        } else if let ExprKind::MethodCall(method, dst, [arg], _) = expr.kind
+            && let name = method.ident.as_str()
+            && matches!(name, "copy_from_slice" | "clone_from_slice")
+            && !expr.span.from_expansion()
+            && cx.typeck_results().expr_ty_adjusted(dst).peel_refs().is_slice()
+            && let ExprKind::AddrOf(_, Mutability::Not, src) = arg.kind
+        {
+            check_copy_from_slice(cx, expr, name, dst, src);
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Checks for `dst.copy_from_slice(&vec![value; dst.len()])` and
+/// `dst.copy_from_slice(&[value; N])`.
+fn check_copy_from_slice(cx: &LateContext<'_>, expr: &Expr<'_>, name: &str, dst: &Expr<'_>, src: &Expr<'_>) {
+    let (value, same_len) = if let Some(higher::VecArgs::Repeat(value, len)) = higher::VecArgs::hir(cx, src) {
+        // Any other length would panic
+        if let ExprKind::MethodCall(len_method, len_recv, [], _) = len.kind
+            && len_method.ident.as_str() == "len"
+            && SpanlessEq::new(cx).eq_expr(len_recv, dst)
+        {
+            (value, true)
+        } else {
+            return;
+        }
+    } else if let ExprKind::Repeat(value, _) = src.kind
+        && let ty::Array(_, src_len) = cx.typeck_results().expr_ty(src).kind()
+    {
+        match cx.typeck_results().expr_ty(dst).peel_refs().kind() {
+            ty::Array(_, dst_len) => {
+                if src_len.try_to_target_usize(cx.tcx) != dst_len.try_to_target_usize(cx.tcx) {
+                    return;
+                }
+                (value, true)
+            },
+            _ => (value, false),
+        }
+    } else {
+        return;
+    };
+
+    span_lint_and_then(
+        cx,
+        SLICE_FILL_WITH_COPY_FROM_SLICE_OF_REPEAT,
+        expr.span,
+        format!("`{name}` with a repeated value"),
+        |diag| {
+            let mut applicability = if same_len {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
+            let ctxt = expr.span.ctxt();
+            let dst = snippet_with_context(cx, dst.span, ctxt, "..", &mut applicability).0;
+            let value = snippet_with_context(cx, value.span, ctxt, "..", &mut applicability).0;
+            if !same_len {
+                diag.note(format!("`{name}` panics if the lengths differ, `fill` doesn't"));
+            }
+            diag.span_suggestion(expr.span, "use `fill`", format!("{dst}.fill({value})"), applicability);
+        },
+    );
+}
+
+/// Checks for `for x in dst.iter_mut() { *x = value; }` where `value` is a constant.
+fn check_loop<'tcx>(cx: &LateContext<'tcx>, for_loop: &higher::ForLoop<'tcx>) {
+    if let PatKind::Binding(BindingMode::NONE, binding, _, None) = for_loop.pat.kind
+        && let Some(dst) = iterated_slice(cx, for_loop.arg)
+        && let ExprKind::Block(block, None) = for_loop.body.kind
+        && let Some(assign) = single_expr(block)
+        && let ExprKind::Assign(lhs, value, _) = assign.kind
+        && let ExprKind::Unary(UnOp::Deref, place) = lhs.kind
+        && path_to_local_id(place, binding)
+        && !value.span.from_expansion()
+        && (ConstEvalCtxt::new(cx).eval(value).is_some() || is_const_helper_call(cx, value))
+        && let Some(clone_trait) = cx.tcx.lang_items().clone_trait()
+        && implements_trait(cx, cx.typeck_results().expr_ty(value), clone_trait, &[])
+    {
+        span_lint_and_then(
+            cx,
+            SLICE_FILL_WITH_COPY_FROM_SLICE_OF_REPEAT,
+            for_loop.span,
+            "assigning the same value to every element of a slice",
+            |diag| {
+                let mut applicability = Applicability::MachineApplicable;
+                let ctxt = for_loop.span.ctxt();
+                let dst = snippet_with_context(cx, dst.span, ctxt, "..", &mut applicability).0;
+                let value = snippet_with_context(cx, value.span, ctxt, "..", &mut applicability).0;
+                diag.span_suggestion(
+                    for_loop.span,
+                    "use `fill`",
+                    format!("{dst}.fill({value});"),
+                    applicability,
+                );
+            },
+        );
+    }
+}
+
+/// If `arg` is `dst.iter_mut()` or `&mut dst` over a slice, an array or a `Vec`, returns `dst`.
+fn iterated_slice<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match arg.kind {
+        ExprKind::MethodCall(method, recv, [], _)
+            if method.ident.as_str() == "iter_mut"
+                && cx.typeck_results().expr_ty_adjusted(recv).peel_refs().is_slice() =>
+        {
+            Some(recv)
+        },
+        ExprKind::AddrOf(_, Mutability::Mut, dst) => {
+            let ty = cx.typeck_results().expr_ty(dst).peel_refs();
+            (ty.is_slice() || ty.is_array() || is_type_diagnostic_item(cx, ty, sym::Vec)).then_some(dst)
+        },
+        _ => None,
+    }
+}
+
+fn single_expr<'tcx>(block: &Block<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match (block.stmts, block.expr) {
+        ([], Some(expr)) => Some(expr),
+        ([stmt], None) => match stmt.kind {
+            StmtKind::Expr(expr) | StmtKind::Semi(expr) => Some(expr),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether `expr` calls a local function without arguments or generic parameters whose body
+/// evaluates to a constant, e.g. `fn default_value() -> u8 { 42 }`.
+fn is_const_helper_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let ExprKind::Call(callee, []) = expr.kind
+        && let Some(def_id) = path_def_id(cx, callee)
+        && cx.tcx.def_kind(def_id) == DefKind::Fn
+        && let Some(local_def_id) = def_id.as_local()
+        && cx.tcx.generics_of(def_id).count() == 0
+        && let Some(body) = cx.tcx.hir().maybe_body_owned_by(local_def_id)
+    {
+        ConstEvalCtxt::with_env(
+            cx.tcx,
+            ty::TypingEnv::non_body_analysis(cx.tcx, def_id),
+            cx.tcx.typeck(local_def_id),
+        )
+        .eval(body.value)
+        .is_some()
+    } else {
+        false
+    }
+}
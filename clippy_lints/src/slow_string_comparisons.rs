@@ -0,0 +1,150 @@
+use clippy_config::Conf;
+use clippy_utils::def_path_def_ids;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::peel_mid_ty_refs_is_mutable;
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefIdMap;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, TyCtxt};
+use rustc_session::impl_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for values which are converted to a `String` only to be compared with a string
+    /// literal, such as `path.to_string_lossy() == "foo"` or `os_str.to_string() == "foo"`.
+    ///
+    /// The checked types and how to compare them can be configured with
+    /// `string-comparison-types`. By default this covers `OsStr`, `OsString`, `Path` and `PathBuf`.
+    ///
+    /// ### Why is this bad?
+    /// The conversion allocates, and for types which aren't necessarily valid UTF-8 it
+    /// also has to validate or replace the contents. Comparing without the conversion avoids both.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::path::Path;
+    /// fn is_config(path: &Path) -> bool {
+    ///     path.to_string_lossy() == "config.toml"
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::path::Path;
+    /// fn is_config(path: &Path) -> bool {
+    ///     path.as_os_str() == "config.toml"
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub SLOW_STRING_COMPARISONS,
+    perf,
+    "converting a value to a `String` only to compare it with a string literal"
+}
+
+impl_lint_pass!(SlowStringComparisons => [SLOW_STRING_COMPARISONS]);
+
+pub struct SlowStringComparisons {
+    /// The configured types, mapped to the method to call before comparing, if any
+    types: DefIdMap<Option<&'static str>>,
+}
+
+impl SlowStringComparisons {
+    pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
+        Self {
+            types: conf
+                .string_comparison_types
+                .iter()
+                .flat_map(|ty| {
+                    let path = ty.path.split("::").collect::<Vec<_>>();
+                    def_path_def_ids(tcx, &path).map(|id| (id, ty.method.as_deref()))
+                })
+                .collect(),
+        }
+    }
+
+    /// If `expr` converts a value of one of the configured types to a string, returns that value
+    /// and the method to call on it instead.
+    fn check_conversion<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        expr: &'tcx Expr<'tcx>,
+    ) -> Option<(&'tcx Expr<'tcx>, Option<&'static str>)> {
+        let ExprKind::MethodCall(segment, recv, [], _) = expr.kind else {
+            return None;
+        };
+        match segment.ident.name.as_str() {
+            // `x.to_string_lossy().into_owned()`, `x.display().to_string()`
+            "to_string" | "to_owned" | "into_owned" if let Some(res) = self.check_conversion(cx, recv) => Some(res),
+            "to_string" | "to_string_lossy" | "display" => {
+                let (ty, _, _) = peel_mid_ty_refs_is_mutable(cx.typeck_results().expr_ty(recv));
+                if let ty::Adt(adt, _) = ty.kind()
+                    && let Some(&method) = self.types.get(&adt.did())
+                {
+                    Some((recv, method))
+                } else {
+                    None
+                }
+            },
+            _ => None,
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for SlowStringComparisons {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Binary(op, left, right) = expr.kind
+            && matches!(op.node, BinOpKind::Eq | BinOpKind::Ne)
+            && !expr.span.from_expansion()
+        {
+            let (converted, lit) = if is_str_lit(right) {
+                (left, right)
+            } else if is_str_lit(left) {
+                (right, left)
+            } else {
+                return;
+            };
+            let Some((recv, method)) = self.check_conversion(cx, converted) else {
+                return;
+            };
+
+            let mut app = Applicability::MachineApplicable;
+            let recv_sugg = Sugg::hir_with_context(cx, recv, expr.span.ctxt(), "..", &mut app);
+            let sugg = if let Some(method) = method {
+                format!("{}.{method}()", recv_sugg.maybe_par())
+            } else {
+                // The configured type is compared to `&str`, so remove any additional references
+                let (_, refs, _) = peel_mid_ty_refs_is_mutable(cx.typeck_results().expr_ty(recv));
+                (1..refs).fold(recv_sugg, |sugg, _| sugg.deref()).to_string()
+            };
+            let lit = Sugg::hir_with_context(cx, lit, expr.span.ctxt(), "..", &mut app);
+            let sugg = if converted.hir_id == left.hir_id {
+                format!("{sugg} {} {lit}", op.node.as_str())
+            } else {
+                format!("{lit} {} {sugg}", op.node.as_str())
+            };
+
+            span_lint_and_sugg(
+                cx,
+                SLOW_STRING_COMPARISONS,
+                expr.span,
+                "converting to a `String` to compare it with a string literal",
+                "compare without converting",
+                sugg,
+                app,
+            );
+        }
+    }
+}
+
+/// Checks for a string literal which can't be produced by a lossy conversion.
+fn is_str_lit(expr: &Expr<'_>) -> bool {
+    if let ExprKind::Lit(lit) = expr.kind
+        && let LitKind::Str(s, _) = lit.node
+    {
+        !s.as_str().contains(char::REPLACEMENT_CHARACTER)
+    } else {
+        false
+    }
+}
@@ -1,17 +1,17 @@
 use clippy_config::Conf;
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::is_from_proc_macro;
 use clippy_utils::msrvs::Msrv;
+use clippy_utils::{def_path_res_with_base, find_crates, is_from_proc_macro, is_lint_allowed};
 use rustc_attr_parsing::{StabilityLevel, StableSince};
 use rustc_errors::Applicability;
 use rustc_hir::def::Res;
 use rustc_hir::def_id::DefId;
-use rustc_hir::{HirId, Path, PathSegment};
+use rustc_hir::{HirId, ItemKind, Path, PathSegment};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_session::impl_lint_pass;
 use rustc_span::symbol::kw;
-use rustc_span::{Span, sym};
+use rustc_span::{Span, Symbol, sym};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -40,6 +40,9 @@ declare_clippy_lint! {
     /// ### What it does
     /// Finds items imported through `std` when available through `alloc`.
     ///
+    /// The lint only triggers if the `alloc` crate is in the extern prelude, i.e. the crate root
+    /// contains `extern crate alloc;`.
+    ///
     /// ### Why restrict this?
     /// Crates which have `no_std` compatibility and require alloc may wish to ensure types are imported from
     /// alloc to ensure disabling `std` does not cause the crate to fail to compile. This lint is also useful
@@ -94,6 +97,8 @@ pub struct StdReexports {
     // when the path could be also be used to access the module.
     prev_span: Span,
     msrv: Msrv,
+    /// Whether the `alloc` crate can be named, i.e. there is an `extern crate alloc;`.
+    alloc_in_prelude: bool,
 }
 
 impl StdReexports {
@@ -101,6 +106,7 @@ impl StdReexports {
         Self {
             prev_span: Span::default(),
             msrv: conf.msrv.clone(),
+            alloc_in_prelude: false,
         }
     }
 }
@@ -108,9 +114,17 @@ impl StdReexports {
 impl_lint_pass!(StdReexports => [STD_INSTEAD_OF_CORE, STD_INSTEAD_OF_ALLOC, ALLOC_INSTEAD_OF_CORE]);
 
 impl<'tcx> LateLintPass<'tcx> for StdReexports {
-    fn check_path(&mut self, cx: &LateContext<'tcx>, path: &Path<'tcx>, _: HirId) {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        self.alloc_in_prelude = cx.tcx.hir().root_module().item_ids.iter().any(|&item_id| {
+            let item = cx.tcx.hir().item(item_id);
+            // `extern crate alloc;`, not renamed in either direction
+            matches!(item.kind, ItemKind::ExternCrate(None | Some(sym::alloc))) && item.ident.name == sym::alloc
+        });
+    }
+
+    fn check_path(&mut self, cx: &LateContext<'tcx>, path: &Path<'tcx>, hir_id: HirId) {
         if let Res::Def(_, def_id) = path.res
-            && let Some(first_segment) = get_first_segment(path)
+            && let Some((first_segment, rest)) = split_first_segment(path)
             && is_stable(cx, def_id, &self.msrv)
             && !in_external_macro(cx.sess(), path.span)
             && !is_from_proc_macro(cx, &first_segment.ident)
@@ -118,7 +132,7 @@ impl<'tcx> LateLintPass<'tcx> for StdReexports {
             let (lint, used_mod, replace_with) = match first_segment.ident.name {
                 sym::std => match cx.tcx.crate_name(def_id.krate) {
                     sym::core => (STD_INSTEAD_OF_CORE, "std", "core"),
-                    sym::alloc => (STD_INSTEAD_OF_ALLOC, "std", "alloc"),
+                    sym::alloc if self.alloc_in_prelude => (STD_INSTEAD_OF_ALLOC, "std", "alloc"),
                     _ => {
                         self.prev_span = first_segment.ident.span;
                         return;
@@ -134,7 +148,11 @@ impl<'tcx> LateLintPass<'tcx> for StdReexports {
                 },
                 _ => return,
             };
-            if first_segment.ident.span != self.prev_span {
+            // Resolving the path in the other crate is expensive, these lints are allowed by default
+            if first_segment.ident.span != self.prev_span
+                && !is_lint_allowed(cx, lint, hir_id)
+                && is_exported_from(cx, Symbol::intern(replace_with), rest, def_id)
+            {
                 #[expect(clippy::collapsible_span_lint_calls, reason = "rust-clippy#7797")]
                 span_lint_and_then(
                     cx,
@@ -158,19 +176,28 @@ impl<'tcx> LateLintPass<'tcx> for StdReexports {
     extract_msrv_attr!(LateContext);
 }
 
-/// Returns the first named segment of a [`Path`].
+/// Returns the first named segment of a [`Path`] and the segments following it.
 ///
 /// If this is a global path (such as `::std::fmt::Debug`), then the segment after [`kw::PathRoot`]
 /// is returned.
-fn get_first_segment<'tcx>(path: &Path<'tcx>) -> Option<&'tcx PathSegment<'tcx>> {
+fn split_first_segment<'tcx>(path: &Path<'tcx>) -> Option<(&'tcx PathSegment<'tcx>, &'tcx [PathSegment<'tcx>])> {
     match path.segments {
         // A global path will have PathRoot as the first segment. In this case, return the segment after.
-        [x, y, ..] if x.ident.name == kw::PathRoot => Some(y),
-        [x, ..] => Some(x),
-        _ => None,
+        [x, rest @ ..] if x.ident.name == kw::PathRoot => rest.split_first(),
+        segments => segments.split_first(),
     }
 }
 
+/// Checks that the item can be named by replacing the first segment of the path with `krate`.
+/// This isn't always the case, e.g. `std::os::raw::c_int` is a re-export of `core::ffi::c_int`,
+/// but there is no `core::os`.
+fn is_exported_from(cx: &LateContext<'_>, krate: Symbol, segments: &[PathSegment<'_>], def_id: DefId) -> bool {
+    let path: Vec<&str> = segments.iter().map(|segment| segment.ident.as_str()).collect();
+    def_path_res_with_base(cx.tcx, find_crates(cx.tcx, krate), &path)
+        .iter()
+        .any(|res| res.opt_def_id() == Some(def_id))
+}
+
 /// Checks if all ancestors of `def_id` meet `msrv` to avoid linting [unstable moves](https://github.com/rust-lang/rust/pull/95956)
 /// or now stable moves that were once unstable.
 ///
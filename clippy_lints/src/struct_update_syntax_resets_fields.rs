@@ -0,0 +1,160 @@
+use clippy_config::Conf;
+use clippy_config::types::StructUpdateDefaultBehavior;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::{is_copy, match_type};
+use clippy_utils::{is_default_equivalent_call, is_from_proc_macro, is_in_test, paths};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, StructTailExpr};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::impl_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for struct expressions using `..Default::default()` to fill in the fields which
+    /// aren't listed, when one of those is a private field which isn't `Copy`.
+    ///
+    /// With the `struct-update-default-behavior` configuration set to `"AllLocalStructs"`, it
+    /// also checks such expressions for every struct defined in the crate.
+    ///
+    /// ### Why is this bad?
+    /// Private fields often have to be consistent with the other fields, e.g. a cache or an
+    /// index of the data in a public field. The default value of such a field is likely wrong
+    /// once the other fields are set.
+    ///
+    /// Fields added to the struct later are also silently set to their default value, even
+    /// where they should have been set explicitly.
+    ///
+    /// Private fields which are zero-sized or lazily initialized caches (`OnceCell` and
+    /// `OnceLock`) are fine to start out with their default value, as are all fields in tests.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// mod table {
+    ///     #[derive(Default)]
+    ///     pub struct Table {
+    ///         pub rows: Vec<String>,
+    ///         index: Vec<usize>,
+    ///     }
+    ///
+    ///     pub fn with_rows(rows: Vec<String>) -> Table {
+    ///         Table { rows, ..Default::default() }
+    ///     }
+    ///     # fn build_index(rows: &[String]) -> Vec<usize> { Vec::new() }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// mod table {
+    ///     #[derive(Default)]
+    ///     pub struct Table {
+    ///         pub rows: Vec<String>,
+    ///         index: Vec<usize>,
+    ///     }
+    ///
+    ///     pub fn with_rows(rows: Vec<String>) -> Table {
+    ///         let index = build_index(&rows);
+    ///         Table { rows, index }
+    ///     }
+    ///     # fn build_index(rows: &[String]) -> Vec<usize> { Vec::new() }
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub STRUCT_UPDATE_SYNTAX_RESETS_FIELDS,
+    suspicious,
+    "struct update syntax leaving private fields to their default value"
+}
+
+pub struct StructUpdateSyntaxResetsFields {
+    behavior: StructUpdateDefaultBehavior,
+}
+
+impl StructUpdateSyntaxResetsFields {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            behavior: conf.struct_update_default_behavior,
+        }
+    }
+}
+
+impl_lint_pass!(StructUpdateSyntaxResetsFields => [STRUCT_UPDATE_SYNTAX_RESETS_FIELDS]);
+
+impl<'tcx> LateLintPass<'tcx> for StructUpdateSyntaxResetsFields {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Struct(_, fields, StructTailExpr::Base(base)) = expr.kind
+            && let ExprKind::Call(func, []) = base.kind
+            && is_default_equivalent_call(cx, func)
+            && let ty::Adt(adt, args) = *cx.typeck_results().expr_ty(expr).kind()
+            && adt.is_struct()
+            && !expr.span.from_expansion()
+            && !in_external_macro(cx.sess(), expr.span)
+            && !is_in_own_impl(cx, expr, adt.did())
+            && !is_from_proc_macro(cx, expr)
+            && !is_in_test(cx.tcx, expr.hir_id)
+        {
+            let listed: FxHashSet<_> = fields.iter().map(|field| field.ident.name).collect();
+            let remaining = adt
+                .non_enum_variant()
+                .fields
+                .iter()
+                .filter(|field| !listed.contains(&field.name))
+                .collect::<Vec<_>>();
+            let private = remaining
+                .iter()
+                .filter(|field| !field.vis.is_public() && may_break_invariants(cx, field.ty(cx.tcx, args)))
+                .map(|field| format!("`{}`", field.name))
+                .collect::<Vec<_>>();
+            let name = cx.tcx.item_name(adt.did());
+            let check_local = self.behavior == StructUpdateDefaultBehavior::AllLocalStructs && adt.did().is_local();
+            if remaining.is_empty() || (private.is_empty() && !check_local) {
+                return;
+            }
+
+            span_lint_and_then(
+                cx,
+                STRUCT_UPDATE_SYNTAX_RESETS_FIELDS,
+                base.span,
+                format!("the fields of `{name}` which aren't listed are set to their default value"),
+                |diag| {
+                    if !private.is_empty() {
+                        let plural = if private.len() == 1 { "" } else { "s" };
+                        diag.note(format!(
+                            "this includes the private field{plural} {}, which may need to be consistent with the other fields",
+                            private.join(", ")
+                        ));
+                    }
+                    if check_local {
+                        diag.note(format!(
+                            "fields added to `{name}` later will also be set to their default value"
+                        ));
+                    }
+                    diag.help("set the fields explicitly");
+                },
+            );
+        }
+    }
+}
+
+/// Checks whether the default value of a private field of type `ty` may be inconsistent with the
+/// other fields.
+fn may_break_invariants<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    !is_copy(cx, ty)
+        && !cx.layout_of(ty).is_ok_and(|layout| layout.is_zst())
+        && !match_type(cx, ty, &paths::ONCE_CELL)
+        && !match_type(cx, ty, &paths::ONCE_LOCK)
+}
+
+/// Checks whether the expression is in a method of the struct itself, which is expected to
+/// uphold the invariants of its fields.
+fn is_in_own_impl(cx: &LateContext<'_>, expr: &Expr<'_>, adt_did: DefId) -> bool {
+    let owner = cx
+        .tcx
+        .typeck_root_def_id(cx.tcx.hir().enclosing_body_owner(expr.hir_id).to_def_id());
+    cx.tcx
+        .impl_of_method(owner)
+        .and_then(|impl_id| cx.tcx.type_of(impl_id).instantiate_identity().ty_adt_def())
+        .is_some_and(|adt| adt.did() == adt_did)
+}
@@ -1,19 +1,22 @@
 use clippy_config::Conf;
-use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg, span_lint_hir_and_then};
 use clippy_utils::msrvs::{self, Msrv};
 use clippy_utils::source::{SpanRangeExt, snippet, snippet_with_applicability};
 use clippy_utils::{SpanlessEq, SpanlessHash, is_from_proc_macro};
 use core::hash::{Hash, Hasher};
 use itertools::Itertools;
-use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap, IndexEntry};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap, FxIndexSet, IndexEntry};
 use rustc_data_structures::unhash::UnhashMap;
 use rustc_errors::Applicability;
 use rustc_hir::def::Res;
+use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_hir::{
-    BoundPolarity, GenericBound, Generics, Item, ItemKind, LangItem, Node, Path, PathSegment, PredicateOrigin, QPath,
-    TraitBoundModifiers, TraitItem, TraitRef, Ty, TyKind, WherePredicateKind,
+    BoundPolarity, GenericBound, Generics, Impl, Item, ItemKind, LangItem, Node, Path, PathSegment, PredicateOrigin,
+    QPath, TraitBoundModifiers, TraitItem, TraitRef, Ty, TyKind, WherePredicateKind,
 };
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::print::{PrintTraitRefExt, with_forced_trimmed_paths};
+use rustc_middle::ty::{self, EarlyBinder, GenericArg, GenericArgKind};
 use rustc_session::impl_lint_pass;
 use rustc_span::{BytePos, Span};
 
@@ -86,6 +89,43 @@ declare_clippy_lint! {
     "check if the same trait bounds are specified more than once during a generic declaration"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for local traits where every impl in the crate repeats the same bounds on the
+    /// generic parameters passed to the trait, e.g. `T: Clone + Debug + Default`.
+    ///
+    /// ### Why is this bad?
+    /// Long where clauses which have to be kept in sync across many impls are hard to read and
+    /// to maintain. They can be moved to the trait definition, or, if the trait definition
+    /// already has them, be replaced by a single bound on a helper trait with a blanket impl.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::fmt::Debug;
+    /// trait Store<T> {}
+    /// struct Memory;
+    /// struct Disk;
+    /// impl<T> Store<T> for Memory where T: Clone + Debug + Default {}
+    /// impl<T> Store<T> for Disk where T: Clone + Debug + Default {}
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::fmt::Debug;
+    /// trait Storable: Clone + Debug + Default {}
+    /// impl<T: Clone + Debug + Default> Storable for T {}
+    ///
+    /// trait Store<T: Storable> {}
+    /// struct Memory;
+    /// struct Disk;
+    /// impl<T: Storable> Store<T> for Memory {}
+    /// impl<T: Storable> Store<T> for Disk {}
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub DUPLICATE_BOUNDS_ACROSS_IMPLS,
+    pedantic,
+    "every impl of a trait repeats the same bounds"
+}
+
 pub struct TraitBounds {
     max_trait_bounds: u64,
     msrv: Msrv,
@@ -100,7 +140,11 @@ impl TraitBounds {
     }
 }
 
-impl_lint_pass!(TraitBounds => [TYPE_REPETITION_IN_BOUNDS, TRAIT_DUPLICATION_IN_BOUNDS]);
+impl_lint_pass!(TraitBounds => [
+    TYPE_REPETITION_IN_BOUNDS,
+    TRAIT_DUPLICATION_IN_BOUNDS,
+    DUPLICATE_BOUNDS_ACROSS_IMPLS,
+]);
 
 impl<'tcx> LateLintPass<'tcx> for TraitBounds {
     fn check_generics(&mut self, cx: &LateContext<'tcx>, generics: &'tcx Generics<'_>) {
@@ -224,6 +268,10 @@ impl<'tcx> LateLintPass<'tcx> for TraitBounds {
         }
     }
 
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        check_bounds_across_impls(cx);
+    }
+
     extract_msrv_attr!(LateContext);
 }
 
@@ -371,6 +419,160 @@ fn check_trait_bound_duplication<'tcx>(cx: &LateContext<'tcx>, generics: &'_ Gen
     }
 }
 
+/// The minimum number of bounds every impl has to repeat for `DUPLICATE_BOUNDS_ACROSS_IMPLS`.
+const MIN_REPEATED_BOUNDS: usize = 3;
+
+fn check_bounds_across_impls(cx: &LateContext<'_>) {
+    for (&trait_id, impls) in cx.tcx.all_local_trait_impls(()) {
+        let Some(trait_id) = trait_id.as_local() else {
+            continue;
+        };
+        if impls.len() < 2 || cx.tcx.def_span(trait_id).from_expansion() {
+            continue;
+        }
+        // The bounds shared by every impl, in the order of the first one
+        let mut common: Option<FxIndexSet<ty::TraitRef<'_>>> = None;
+        for &impl_id in impls {
+            let Some(bounds) = impl_bounds(cx, impl_id, trait_id.to_def_id()) else {
+                common = None;
+                break;
+            };
+            match &mut common {
+                Some(common) => common.retain(|bound| bounds.contains(bound)),
+                None => common = Some(bounds),
+            }
+        }
+        let Some(common) = common.filter(|common| common.len() >= MIN_REPEATED_BOUNDS) else {
+            continue;
+        };
+
+        let trait_bounds = trait_bounds(cx, trait_id.to_def_id());
+        let mut impl_spans: Vec<_> = impls
+            .iter()
+            .filter_map(|&impl_id| match cx.tcx.hir().expect_item(impl_id).kind {
+                ItemKind::Impl(Impl {
+                    of_trait: Some(trait_ref),
+                    ..
+                }) => Some(trait_ref.path.span),
+                _ => None,
+            })
+            .collect();
+        impl_spans.sort_by_key(|span| span.lo());
+        span_lint_hir_and_then(
+            cx,
+            DUPLICATE_BOUNDS_ACROSS_IMPLS,
+            cx.tcx.local_def_id_to_hir_id(trait_id),
+            cx.tcx.hir().expect_item(trait_id).ident.span,
+            format!(
+                "every impl of `{}` repeats the same bounds",
+                cx.tcx.item_name(trait_id.to_def_id())
+            ),
+            |diag| {
+                for span in impl_spans {
+                    diag.span_note(span, "the bounds are repeated here");
+                }
+                // Group the bounds by the bounded parameter, e.g. `T: Clone + Debug`
+                let mut grouped: FxIndexMap<_, Vec<String>> = FxIndexMap::default();
+                for bound in &common {
+                    grouped
+                        .entry(bound.self_ty())
+                        .or_default()
+                        .push(with_forced_trimmed_paths!(bound.print_only_trait_path().to_string()));
+                }
+                let bounds = grouped
+                    .iter()
+                    .map(|(ty, traits)| format!("{ty}: {}", traits.join(" + ")))
+                    .join(", ");
+                if common.iter().all(|bound| trait_bounds.contains(bound)) {
+                    diag.help(format!(
+                        "the trait definition has the same bounds, consider adding a trait with them as \
+                        supertraits and a blanket impl, and using it as a single bound instead of `{bounds}`"
+                    ));
+                } else {
+                    diag.help(format!(
+                        "consider moving the bounds to the trait definition: `where {bounds}`"
+                    ));
+                }
+            },
+        );
+    }
+}
+
+/// Returns the bounds of the impl on the generic parameters passed to the trait, expressed in
+/// terms of the parameters of the trait, e.g. `T: Clone` for `impl<U: Clone> Trait<U> for S`.
+/// Bounds involving any other parameter of the impl are ignored. Returns `None` for impls from
+/// an expansion.
+fn impl_bounds<'tcx>(
+    cx: &LateContext<'tcx>,
+    impl_id: LocalDefId,
+    trait_id: DefId,
+) -> Option<FxIndexSet<ty::TraitRef<'tcx>>> {
+    if cx.tcx.def_span(impl_id).from_expansion() {
+        return None;
+    }
+    let impl_trait_ref = cx.tcx.impl_trait_ref(impl_id)?.instantiate_identity();
+    let trait_args = ty::GenericArgs::identity_for_item(cx.tcx, trait_id);
+    let impl_args = ty::GenericArgs::identity_for_item(cx.tcx, impl_id);
+    // The position of each parameter of the impl in the arguments of the trait
+    let positions: Vec<_> = impl_args
+        .iter()
+        .map(|arg| impl_trait_ref.args.iter().position(|trait_arg| trait_arg == arg))
+        .collect();
+    let args = cx.tcx.mk_args_from_iter(
+        impl_args
+            .iter()
+            .zip(&positions)
+            .map(|(arg, pos)| pos.map_or(arg, |pos| trait_args[pos])),
+    );
+    let is_mapped = |arg: GenericArg<'_>| match arg.unpack() {
+        GenericArgKind::Type(ty) => match *ty.kind() {
+            ty::Param(param) => positions[param.index as usize].is_some(),
+            _ => true,
+        },
+        GenericArgKind::Lifetime(region) => match region.kind() {
+            ty::ReEarlyParam(param) => positions[param.index as usize].is_some(),
+            _ => true,
+        },
+        GenericArgKind::Const(ct) => match ct.kind() {
+            ty::ConstKind::Param(param) => positions[param.index as usize].is_some(),
+            _ => true,
+        },
+    };
+
+    Some(
+        cx.tcx
+            .explicit_predicates_of(impl_id)
+            .predicates
+            .iter()
+            .filter_map(|(pred, _)| match pred.kind().no_bound_vars()? {
+                ty::ClauseKind::Trait(tr)
+                    if tr.polarity == ty::PredicatePolarity::Positive
+                        && matches!(tr.self_ty().kind(), ty::Param(_))
+                        && cx.tcx.lang_items().sized_trait() != Some(tr.def_id())
+                        && tr.trait_ref.args.iter().flat_map(GenericArg::walk).all(is_mapped) =>
+                {
+                    Some(EarlyBinder::bind(tr.trait_ref).instantiate(cx.tcx, args))
+                },
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// Returns the bounds of the trait on its own parameters, including its supertraits.
+fn trait_bounds<'tcx>(cx: &LateContext<'tcx>, trait_id: DefId) -> FxIndexSet<ty::TraitRef<'tcx>> {
+    cx.tcx
+        .explicit_predicates_of(trait_id)
+        .predicates
+        .iter()
+        .chain(cx.tcx.explicit_super_predicates_of(trait_id).skip_binder())
+        .filter_map(|(pred, _)| match pred.kind().no_bound_vars()? {
+            ty::ClauseKind::Trait(tr) if tr.polarity == ty::PredicatePolarity::Positive => Some(tr.trait_ref),
+            _ => None,
+        })
+        .collect()
+}
+
 struct ComparableTraitRef<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     trait_ref: &'tcx TraitRef<'tcx>,
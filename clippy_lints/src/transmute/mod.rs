@@ -15,6 +15,7 @@ mod transmute_undefined_repr;
 mod transmutes_expressible_as_ptr_casts;
 mod transmuting_null;
 mod unsound_collection_transmute;
+mod unsound_from_raw_cast;
 mod useless_transmute;
 mod utils;
 mod wrong_transmute;
@@ -387,6 +388,37 @@ declare_clippy_lint! {
     "transmute between collections of layout-incompatible types"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Vec::from_raw_parts` and `Box::from_raw` called with a pointer obtained from a
+    /// `Vec` or `Box` and cast to a pointer to a type with a different size or alignment. The
+    /// elements of a `Vec` may be smaller if their size divides the size of the original ones, as
+    /// its length and capacity can then be scaled accordingly.
+    ///
+    /// ### Why is this bad?
+    /// This is undefined behavior, like transmuting the collection itself (see
+    /// [`unsound_collection_transmute`](#unsound_collection_transmute)). The allocation is later
+    /// deallocated with the layout of the new element type, which must be the same as the one it
+    /// was allocated with.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let mut v = std::mem::ManuallyDrop::new(vec![1_u16, 2, 3]);
+    /// let v = unsafe { Vec::from_raw_parts(v.as_mut_ptr() as *mut u32, v.len(), v.capacity()) };
+    /// ```
+    ///
+    /// You must always iterate, map and collect the values:
+    ///
+    /// ```no_run
+    /// let v = vec![1_u16, 2, 3];
+    /// let v = v.into_iter().map(u32::from).collect::<Vec<_>>();
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub UNSOUND_FROM_RAW_CAST,
+    correctness,
+    "`Vec::from_raw_parts` or `Box::from_raw` with a pointer cast to a layout-incompatible type"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for transmutes between types which do not have a representation defined relative to
@@ -590,6 +622,7 @@ impl_lint_pass!(Transmute => [
     TRANSMUTE_FLOAT_TO_INT,
     TRANSMUTE_NUM_TO_BYTES,
     UNSOUND_COLLECTION_TRANSMUTE,
+    UNSOUND_FROM_RAW_CAST,
     TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS,
     TRANSMUTE_UNDEFINED_REPR,
     TRANSMUTING_NULL,
@@ -606,6 +639,8 @@ impl Transmute {
 }
 impl<'tcx> LateLintPass<'tcx> for Transmute {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
+        unsound_from_raw_cast::check(cx, e);
+
         if let ExprKind::Call(path_expr, [arg]) = e.kind
             && let ExprKind::Path(QPath::Resolved(None, path)) = path_expr.kind
             && let Some(def_id) = path.res.opt_def_id()
@@ -0,0 +1,111 @@
+use super::UNSOUND_FROM_RAW_CAST;
+use super::utils::is_layout_incompatible;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{expr_or_init, path_def_id};
+use rustc_hir::{Expr, ExprKind, QPath};
+use rustc_lint::LateContext;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::symbol::sym;
+
+/// Checks `Vec::from_raw_parts(v.as_mut_ptr() as *mut U, len, cap)` and
+/// `Box::from_raw(Box::into_raw(b) as *mut U)`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
+    if let ExprKind::Call(func, [ptr, rest @ ..]) = e.kind
+        && !e.span.from_expansion()
+        && let ExprKind::Path(QPath::TypeRelative(ty, seg)) = func.kind
+        && let Some(ty_did) = path_def_id(cx, ty)
+        && let Some(collection) = match (seg.ident.name.as_str(), rest.len()) {
+            ("from_raw_parts", 2) if cx.tcx.is_diagnostic_item(sym::Vec, ty_did) => Some("Vec"),
+            ("from_raw", 0) if cx.tcx.lang_items().owned_box() == Some(ty_did) => Some("Box"),
+            _ => None,
+        }
+        && let source = peel_ptr_casts(expr_or_init(cx, peel_ptr_casts(ptr)))
+        && is_allocation_of(cx, source, collection)
+        && let ty::RawPtr(from_ty, _) = *cx.typeck_results().expr_ty(source).kind()
+        && let ty::RawPtr(to_ty, _) = *cx.typeck_results().expr_ty(ptr).kind()
+        && if collection == "Vec" {
+            is_vec_layout_incompatible(cx, from_ty, to_ty)
+        } else {
+            is_layout_incompatible(cx, from_ty, to_ty)
+        }
+    {
+        span_lint_and_then(
+            cx,
+            UNSOUND_FROM_RAW_CAST,
+            e.span,
+            format!("creating a `{collection}<{to_ty}>` from the allocation of a `{collection}<{from_ty}>` is unsound"),
+            |diag| {
+                for ty in [from_ty, to_ty] {
+                    if let Ok(layout) = cx.layout_of(ty) {
+                        diag.note(format!(
+                            "`{ty}` has size {} and alignment {}",
+                            layout.size.bytes(),
+                            layout.align.abi.bytes()
+                        ));
+                    }
+                }
+                diag.help("the allocation must be deallocated with the layout it was allocated with");
+            },
+        );
+    }
+}
+
+/// Whether the allocation of a `Vec<from>` can't be reused by a `Vec<to>`. Its capacity can be
+/// scaled, e.g. a `Vec<[u8; 4]>` can become a `Vec<u8>` with 4 times the length and capacity, so
+/// only the alignments have to match and the size of `from` has to be a multiple of the size of
+/// `to`.
+fn is_vec_layout_incompatible<'tcx>(cx: &LateContext<'tcx>, from: Ty<'tcx>, to: Ty<'tcx>) -> bool {
+    if let Ok(from_layout) = cx.layout_of(from)
+        && let Ok(to_layout) = cx.layout_of(to)
+    {
+        let (from_size, to_size) = (from_layout.size.bytes(), to_layout.size.bytes());
+        from_layout.align.abi != to_layout.align.abi
+            || if to_size == 0 {
+                from_size != 0
+            } else {
+                from_size % to_size != 0
+            }
+    } else {
+        // no idea about layout, so don't lint
+        false
+    }
+}
+
+/// Peels `p as *mut T` and `p.cast::<T>()`.
+fn peel_ptr_casts<'a, 'tcx>(mut expr: &'a Expr<'tcx>) -> &'a Expr<'tcx> {
+    loop {
+        match expr.kind {
+            ExprKind::Cast(inner, _) => expr = inner,
+            ExprKind::MethodCall(method, recv, [], _)
+                if matches!(method.ident.name.as_str(), "cast" | "cast_mut" | "cast_const") =>
+            {
+                expr = recv;
+            },
+            _ => return expr,
+        }
+    }
+}
+
+/// Whether the pointer is obtained from `vec.as_mut_ptr()` for a `Vec`, or from `Box::into_raw(b)`
+/// for a `Box`.
+fn is_allocation_of(cx: &LateContext<'_>, source: &Expr<'_>, collection: &str) -> bool {
+    match (collection, source.kind) {
+        ("Vec", ExprKind::MethodCall(method, recv, [], _)) => {
+            matches!(method.ident.name.as_str(), "as_mut_ptr" | "as_ptr")
+                && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty_adjusted(recv).peel_refs(), sym::Vec)
+        },
+        ("Box", ExprKind::Call(func, [_])) => {
+            if let ExprKind::Path(QPath::TypeRelative(ty, seg)) = func.kind
+                && seg.ident.name.as_str() == "into_raw"
+                && let Some(ty_did) = path_def_id(cx, ty)
+            {
+                cx.tcx.lang_items().owned_box() == Some(ty_did)
+            } else {
+                false
+            }
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,155 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{
+    get_enclosing_block, get_parent_expr, is_entrypoint_fn, match_def_path, path_def_id, path_to_local_id, paths,
+};
+use rustc_hir::{Block, Expr, ExprKind, HirId, LetStmt, PatKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::adjustment::{Adjust, Adjustment};
+use rustc_session::impl_lint_pass;
+use std::ops::ControlFlow;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the `JoinHandle` returned by `std::thread::spawn` being dropped without calling
+    /// `join` on it, either right away by assigning it to `_` or discarding it, or at the end of
+    /// the scope of the variable holding it. The `main` function is not linted.
+    ///
+    /// Modules in which threads may be detached can be listed in the
+    /// `unjoined-thread-handle-allowed-modules` configuration.
+    ///
+    /// ### Why is this bad?
+    /// Dropping a `JoinHandle` detaches the thread. Nothing waits for it to finish, a panic in it
+    /// goes unnoticed, and it is killed without unwinding when the process exits. This is rarely
+    /// intended outside of `main`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # fn work() {}
+    /// fn run() {
+    ///     let _ = std::thread::spawn(|| work());
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # fn work() {}
+    /// fn run() {
+    ///     let handle = std::thread::spawn(|| work());
+    ///     handle.join().unwrap();
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub UNJOINED_THREAD_HANDLE,
+    restriction,
+    "dropping the handle of a spawned thread without joining it"
+}
+
+pub struct UnjoinedThreadHandle {
+    allowed_modules: &'static [String],
+}
+
+impl UnjoinedThreadHandle {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            allowed_modules: &conf.unjoined_thread_handle_allowed_modules,
+        }
+    }
+
+    /// Whether the statement is in one of the configured modules, or in a submodule of one.
+    fn is_allowed_module(&self, cx: &LateContext<'_>, hir_id: HirId) -> bool {
+        if self.allowed_modules.is_empty() {
+            return false;
+        }
+        let module = cx.tcx.def_path_str(cx.tcx.parent_module(hir_id).to_def_id());
+        self.allowed_modules.iter().any(|allowed| {
+            let allowed = allowed.strip_prefix("crate::").unwrap_or(allowed);
+            module
+                .strip_prefix(allowed)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+        })
+    }
+}
+
+impl_lint_pass!(UnjoinedThreadHandle => [UNJOINED_THREAD_HANDLE]);
+
+impl<'tcx> LateLintPass<'tcx> for UnjoinedThreadHandle {
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
+        let (init, binding) = match stmt.kind {
+            StmtKind::Let(LetStmt {
+                pat, init: Some(init), ..
+            }) => match pat.kind {
+                PatKind::Wild => (*init, None),
+                PatKind::Binding(_, id, _, None) => (*init, Some(id)),
+                _ => return,
+            },
+            StmtKind::Semi(expr) => (expr, None),
+            _ => return,
+        };
+        if let ExprKind::Call(callee, _) = init.kind
+            && !in_external_macro(cx.sess(), stmt.span)
+            && path_def_id(cx, callee).is_some_and(|id| match_def_path(cx, id, &paths::THREAD_SPAWN))
+            && !is_entrypoint_fn(cx, cx.tcx.hir().get_parent_item(stmt.hir_id).to_def_id())
+            && !self.is_allowed_module(cx, stmt.hir_id)
+        {
+            let msg = match binding {
+                None => "the handle of the spawned thread is dropped immediately",
+                Some(binding) => {
+                    if let Some(block) = get_enclosing_block(cx, stmt.hir_id)
+                        && is_dropped_in_scope(cx, block, binding)
+                    {
+                        "the handle of the spawned thread is dropped at the end of the scope"
+                    } else {
+                        return;
+                    }
+                },
+            };
+            span_lint_and_help(
+                cx,
+                UNJOINED_THREAD_HANDLE,
+                stmt.span,
+                msg,
+                None,
+                "this detaches the thread, call `join` on the handle to wait for it to finish",
+            );
+        }
+    }
+}
+
+/// Whether the variable `binding` is only used by reference in the block, so that it is dropped at
+/// the end of it.
+fn is_dropped_in_scope<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>, binding: HirId) -> bool {
+    for_each_expr(cx, block, |e| {
+        if path_to_local_id(e, binding) && !is_used_by_ref(cx, e) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_none()
+}
+
+/// Whether the use `e` of a variable only borrows it, e.g. `handle.thread()` or `&handle`.
+fn is_used_by_ref(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    match get_parent_expr(cx, e) {
+        Some(Expr {
+            kind: ExprKind::AddrOf(..),
+            ..
+        }) => true,
+        Some(Expr {
+            kind: ExprKind::MethodCall(_, recv, ..),
+            ..
+        }) if recv.hir_id == e.hir_id => matches!(
+            cx.typeck_results().expr_adjustments(e),
+            [
+                Adjustment {
+                    kind: Adjust::Borrow(_),
+                    ..
+                },
+                ..
+            ]
+        ),
+        _ => false,
+    }
+}
@@ -0,0 +1,281 @@
+use std::ops::ControlFlow;
+
+use clippy_config::Conf;
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_hir_and_then};
+use clippy_utils::path_def_id;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::visitors::for_each_expr;
+use rustc_data_structures::fx::{FxHashSet, FxIndexMap};
+use rustc_errors::Applicability;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::{
+    Closure, ClosureKind, CoroutineDesugaring, CoroutineKind, CoroutineSource, Expr, ExprKind, LangItem, MatchSource,
+    Mutability, Node, QPath,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::impl_lint_pass;
+use rustc_target::abi::Size;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `async` blocks which are pinned in a `Box` only to be awaited immediately, and for
+    /// struct fields of type `Pin<Box<dyn Future>>` which only ever hold the future of the same
+    /// `async fn`.
+    ///
+    /// ### Why is this bad?
+    /// Awaiting a future does not require it to be boxed, the allocation is unnecessary. A struct
+    /// storing the future of a single `async fn` can be generic over the type of the future
+    /// instead.
+    ///
+    /// Futures which are larger than the `future-size-threshold` configuration (see
+    /// `large_futures`) and futures which call the enclosing function, directly or through other
+    /// functions, where the box is required for recursion, are not linted. Neither are the fields
+    /// of exported structs.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// async fn fetch() -> u32 {
+    ///     Box::pin(async { 42 }).await
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// async fn fetch() -> u32 {
+    ///     async { 42 }.await
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub UNNECESSARY_BOX_PIN,
+    pedantic,
+    "futures which are pinned in a `Box` without need"
+}
+
+pub struct UnnecessaryBoxPin {
+    future_size_threshold: u64,
+    /// The `async fn` whose futures are stored in each field of type `Pin<Box<dyn Future>>`, or
+    /// `None` if the field also holds other futures.
+    boxed_fields: FxIndexMap<LocalDefId, Option<DefId>>,
+}
+
+impl UnnecessaryBoxPin {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            future_size_threshold: conf.future_size_threshold,
+            boxed_fields: FxIndexMap::default(),
+        }
+    }
+
+    fn is_small<'tcx>(&self, cx: &LateContext<'tcx>, typing_env: ty::TypingEnv<'tcx>, ty: Ty<'tcx>) -> bool {
+        cx.tcx
+            .layout_of(typing_env.as_query_input(ty))
+            .is_ok_and(|layout| layout.layout.size() < Size::from_bytes(self.future_size_threshold))
+    }
+
+    /// Records the future stored in the field by the struct expression or the assignment `expr`.
+    fn check_field_init<'tcx>(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            ExprKind::Struct(_, fields, _)
+                if let ty::Adt(adt, _) = cx.typeck_results().expr_ty(expr).kind()
+                    && adt.is_struct() =>
+            {
+                for field in fields {
+                    if let Some(idx) = cx.typeck_results().opt_field_index(field.hir_id)
+                        && let Some(field_did) = adt.non_enum_variant().fields[idx].did.as_local()
+                        && boxed_future_output(cx, field_did).is_some()
+                    {
+                        self.record(field_did, boxed_async_fn(cx, field.expr));
+                    }
+                }
+            },
+            // The future can be replaced by other ones
+            ExprKind::Assign(place, ..)
+            | ExprKind::AssignOp(_, place, _)
+            | ExprKind::AddrOf(_, Mutability::Mut, place)
+                if let Some(field_did) = field_def_id(cx, place)
+                    && boxed_future_output(cx, field_did).is_some() =>
+            {
+                self.record(field_did, None);
+            },
+            _ => {},
+        }
+    }
+
+    fn record(&mut self, field: LocalDefId, async_fn: Option<DefId>) {
+        let stored = self.boxed_fields.entry(field).or_insert(async_fn);
+        if *stored != async_fn {
+            *stored = None;
+        }
+    }
+}
+
+impl_lint_pass!(UnnecessaryBoxPin => [UNNECESSARY_BOX_PIN]);
+
+impl<'tcx> LateLintPass<'tcx> for UnnecessaryBoxPin {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        self.check_field_init(cx, expr);
+
+        if let ExprKind::Match(scrutinee, _, MatchSource::AwaitDesugar) = expr.kind
+            && let ExprKind::Call(func, [boxed]) = scrutinee.kind
+            && let ExprKind::Path(QPath::LangItem(LangItem::IntoFutureIntoFuture, ..)) = func.kind
+            && !in_external_macro(cx.tcx.sess, expr.span)
+            && let ExprKind::Call(box_pin, [future]) = boxed.kind
+            && is_box_pin(cx, box_pin)
+            && let ExprKind::Closure(Closure { kind, body, .. }) = future.kind
+            && matches!(
+                kind,
+                ClosureKind::Coroutine(CoroutineKind::Desugared(
+                    CoroutineDesugaring::Async,
+                    CoroutineSource::Block
+                ))
+            )
+            && self.is_small(cx, cx.typing_env(), cx.typeck_results().expr_ty(future))
+            && let body_owner = cx.tcx.hir().enclosing_body_owner(expr.hir_id)
+            && let fn_def_id = cx.tcx.typeck_root_def_id(body_owner.to_def_id())
+            && !calls_fn(cx, cx.tcx.hir().body(*body).value, fn_def_id)
+        {
+            let mut applicability = Applicability::MachineApplicable;
+            let (snippet, _) = snippet_with_context(cx, future.span, boxed.span.ctxt(), "..", &mut applicability);
+            span_lint_and_sugg(
+                cx,
+                UNNECESSARY_BOX_PIN,
+                boxed.span,
+                "this future is boxed only to be awaited immediately",
+                "await the future directly",
+                snippet.into_owned(),
+                applicability,
+            );
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (&field_did, &async_fn) in &self.boxed_fields {
+            // Making the struct generic would change its API
+            if let Some(async_fn) = async_fn
+                && !cx.effective_visibilities.is_exported(cx.tcx.local_parent(field_did))
+                && let Node::Field(field) = cx.tcx.hir_node_by_def_id(field_did)
+                && !field.ty.span.from_expansion()
+                && let Some(output) = boxed_future_output(cx, field_did)
+                && let future = cx.tcx.fn_sig(async_fn).instantiate_identity().skip_binder().output()
+                && self.is_small(cx, ty::TypingEnv::post_analysis(cx.tcx, async_fn), future)
+            {
+                span_lint_hir_and_then(
+                    cx,
+                    UNNECESSARY_BOX_PIN,
+                    field.hir_id,
+                    field.ty.span,
+                    format!(
+                        "this field only holds the future of `{}`, which doesn't need to be boxed",
+                        cx.tcx.item_name(async_fn)
+                    ),
+                    |diag| {
+                        diag.help(format!(
+                            "make the struct generic over the type of the future, e.g. `F: Future<Output = {output}>`"
+                        ));
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn is_box_pin(cx: &LateContext<'_>, func: &Expr<'_>) -> bool {
+    if let ExprKind::Path(QPath::TypeRelative(ty, seg)) = func.kind {
+        seg.ident.name.as_str() == "pin"
+            && path_def_id(cx, ty).is_some_and(|did| cx.tcx.lang_items().owned_box() == Some(did))
+    } else {
+        false
+    }
+}
+
+/// Returns the `Output` type if the type of the field is `Pin<Box<dyn Future<Output = ..>>>`.
+fn boxed_future_output(cx: &LateContext<'_>, field: LocalDefId) -> Option<String> {
+    let ty = cx.tcx.type_of(field).instantiate_identity();
+    let ty::Adt(_, pin_args) = ty.kind() else {
+        return None;
+    };
+    if !is_type_lang_item(cx, ty, LangItem::Pin) {
+        return None;
+    }
+    let ty::Dynamic(preds, ..) = pin_args.type_at(0).boxed_ty()?.kind() else {
+        return None;
+    };
+    let lang_items = cx.tcx.lang_items();
+    if preds.principal_def_id() != lang_items.future_trait() {
+        return None;
+    }
+    let output = preds
+        .projection_bounds()
+        .find(|p| lang_items.future_output() == Some(p.item_def_id()))?;
+    Some(output.skip_binder().term.to_string())
+}
+
+/// Returns the `async fn` if `expr` is `Box::pin(f(..))`, where `f` is an `async fn`.
+fn boxed_async_fn(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<DefId> {
+    let ExprKind::Call(box_pin, [future]) = expr.kind else {
+        return None;
+    };
+    if !is_box_pin(cx, box_pin) {
+        return None;
+    }
+    let callee = match future.kind {
+        ExprKind::Call(callee, _) => path_def_id(cx, callee),
+        ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(future.hir_id),
+        _ => None,
+    }?;
+    (matches!(cx.tcx.def_kind(callee), DefKind::Fn | DefKind::AssocFn) && cx.tcx.asyncness(callee).is_async())
+        .then_some(callee)
+}
+
+/// Returns the field accessed by `expr`, if it's a field of a local struct.
+fn field_def_id(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<LocalDefId> {
+    let ExprKind::Field(base, _) = expr.kind else {
+        return None;
+    };
+    let ty::Adt(adt, _) = cx.typeck_results().expr_ty_adjusted(base).peel_refs().kind() else {
+        return None;
+    };
+    let idx = cx.typeck_results().opt_field_index(expr.hir_id)?;
+    if adt.is_struct() {
+        adt.non_enum_variant().fields[idx].did.as_local()
+    } else {
+        None
+    }
+}
+
+/// Whether the body of the `async` block calls `fn_def_id`, in which case the box is required to
+/// make the recursive future have a finite size. This includes calls through other functions of
+/// the crate, e.g. if the block calls `g` which calls `fn_def_id`.
+fn calls_fn<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'tcx>, fn_def_id: DefId) -> bool {
+    let mut bodies = vec![(cx.typeck_results(), body)];
+    let mut seen = FxHashSet::default();
+    while let Some((typeck, body)) = bodies.pop() {
+        let found = for_each_expr(cx, body, |e| {
+            let callee = match e.kind {
+                ExprKind::Call(callee, _) if let ExprKind::Path(ref qpath) = callee.kind => {
+                    typeck.qpath_res(qpath, callee.hir_id).opt_def_id()
+                },
+                ExprKind::MethodCall(..) => typeck.type_dependent_def_id(e.hir_id),
+                _ => None,
+            };
+            if callee == Some(fn_def_id) {
+                return ControlFlow::Break(());
+            }
+            if let Some(callee) = callee.and_then(DefId::as_local)
+                && seen.insert(callee)
+                && let Some(callee_body) = cx.tcx.hir().maybe_body_owned_by(callee)
+            {
+                bodies.push((cx.tcx.typeck(callee), callee_body.value));
+            }
+            ControlFlow::Continue(())
+        })
+        .is_some();
+        if found {
+            return true;
+        }
+    }
+    false
+}
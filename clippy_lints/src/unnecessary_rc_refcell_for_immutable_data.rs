@@ -0,0 +1,198 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::ty::get_type_diagnostic_name;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Expr, ExprKind, HirId, Item, ItemKind, LetStmt, PatKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::print::with_forced_trimmed_paths;
+use rustc_middle::ty::{self, Ty, TypeVisitableExt};
+use rustc_session::impl_lint_pass;
+use rustc_span::def_id::DefId;
+use rustc_span::{Span, Symbol, sym};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for fields and local variables of type `Rc<RefCell<T>>` or `Arc<RwLock<T>>` where
+    /// the contained value is never mutated anywhere in the crate, i.e. `borrow_mut()`, `write()`
+    /// or any other method giving mutable access is never called on a `RefCell<T>` or
+    /// `RwLock<T>`.
+    ///
+    /// ### Why is this bad?
+    /// The `RefCell` or `RwLock` adds runtime checks and, for `RwLock`, locking, which are not
+    /// needed for data which is only ever read. `Rc<T>` and `Arc<T>` already allow shared
+    /// read-only access.
+    ///
+    /// ### Known problems
+    /// The value may still be mutated by code outside of the crate, e.g. if the `Rc<RefCell<T>>`
+    /// is passed to a function of a dependency.
+    ///
+    /// Fields of exported structs are not linted unless the `avoid-breaking-exported-api`
+    /// configuration is set to `false`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// struct Settings {
+    ///     names: Rc<RefCell<Vec<String>>>,
+    /// }
+    ///
+    /// impl Settings {
+    ///     fn count(&self) -> usize {
+    ///         self.names.borrow().len()
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::rc::Rc;
+    /// struct Settings {
+    ///     names: Rc<Vec<String>>,
+    /// }
+    ///
+    /// impl Settings {
+    ///     fn count(&self) -> usize {
+    ///         self.names.len()
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub UNNECESSARY_RC_REFCELL_FOR_IMMUTABLE_DATA,
+    nursery,
+    "`Rc<RefCell<T>>` or `Arc<RwLock<T>>` whose contents are never mutated"
+}
+
+pub struct UnnecessaryRcRefcellForImmutableData<'tcx> {
+    avoid_breaking_exported_api: bool,
+    /// Fields and locals of type `Rc<RefCell<T>>` or `Arc<RwLock<T>>`, with the pointer type and
+    /// `T`
+    candidates: Vec<(HirId, Span, Symbol, Ty<'tcx>)>,
+    /// The types `T` for which `RefCell<T>` or `RwLock<T>` is mutated somewhere in the crate
+    mutated: FxHashSet<Ty<'tcx>>,
+    /// Whether a `RefCell<T>` or `RwLock<T>` with a generic `T` is mutated, which may be any of the
+    /// candidates
+    mutated_generic: bool,
+}
+
+impl<'tcx> UnnecessaryRcRefcellForImmutableData<'tcx> {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+            candidates: Vec::new(),
+            mutated: FxHashSet::default(),
+            mutated_generic: false,
+        }
+    }
+
+    fn add_candidate(&mut self, cx: &LateContext<'tcx>, hir_id: HirId, span: Span, ty: Ty<'tcx>) {
+        if let Some((ptr, inner)) = shared_cell_contents(cx, ty)
+            && !inner.has_param()
+        {
+            self.candidates.push((hir_id, span, ptr, cx.tcx.erase_regions(inner)));
+        }
+    }
+}
+
+impl_lint_pass!(UnnecessaryRcRefcellForImmutableData<'_> => [UNNECESSARY_RC_REFCELL_FOR_IMMUTABLE_DATA]);
+
+impl<'tcx> LateLintPass<'tcx> for UnnecessaryRcRefcellForImmutableData<'tcx> {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Struct(data, _) = item.kind
+            && !in_external_macro(cx.sess(), item.span)
+        {
+            for field in data.fields() {
+                if !(self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(field.def_id)) {
+                    let ty = cx.tcx.type_of(field.def_id).instantiate_identity();
+                    self.add_candidate(cx, field.hir_id, field.ty.span, ty);
+                }
+            }
+        }
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx LetStmt<'tcx>) {
+        if let PatKind::Binding(..) = local.pat.kind
+            && !local.span.from_expansion()
+        {
+            let span = local.ty.map_or(local.pat.span, |ty| ty.span);
+            self.add_candidate(cx, local.hir_id, span, cx.typeck_results().pat_ty(local.pat));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let (def_id, args_id) = match expr.kind {
+            ExprKind::MethodCall(..) => (cx.typeck_results().type_dependent_def_id(expr.hir_id), expr.hir_id),
+            ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, expr.hir_id) {
+                Res::Def(DefKind::AssocFn, def_id) => (Some(def_id), expr.hir_id),
+                _ => return,
+            },
+            _ => return,
+        };
+        if let Some(def_id) = def_id
+            && gives_mutable_access(cx, def_id)
+            && let Some(inner) = cx.typeck_results().node_args(args_id).types().next()
+        {
+            if inner.has_param() {
+                self.mutated_generic = true;
+            } else {
+                self.mutated.insert(cx.tcx.erase_regions(inner));
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        if self.mutated_generic {
+            return;
+        }
+        for &(hir_id, span, ptr, inner) in &self.candidates {
+            if !self.mutated.contains(&inner) {
+                let cell = if ptr == sym::Rc { "RefCell" } else { "RwLock" };
+                span_lint_hir_and_then(
+                    cx,
+                    UNNECESSARY_RC_REFCELL_FOR_IMMUTABLE_DATA,
+                    hir_id,
+                    span,
+                    format!("the contents of this `{ptr}<{cell}<_>>` are never mutated"),
+                    |diag| {
+                        diag.help(with_forced_trimmed_paths!(format!(
+                            "consider using `{ptr}<{inner}>` instead"
+                        )));
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// If `ty` is `Rc<RefCell<T>>` or `Arc<RwLock<T>>`, returns the name of the pointer type and `T`.
+fn shared_cell_contents<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<(Symbol, Ty<'tcx>)> {
+    if let ty::Adt(_, args) = ty.kind()
+        && let Some(ptr @ (sym::Rc | sym::Arc)) = get_type_diagnostic_name(cx, ty)
+        && let Some(cell_ty) = args.types().next()
+        && let ty::Adt(_, cell_args) = cell_ty.kind()
+        && let Some(cell) = get_type_diagnostic_name(cx, cell_ty)
+        && matches!((ptr, cell), (sym::Rc, sym::RefCell) | (sym::Arc, sym::RwLock))
+    {
+        Some((ptr, cell_args.types().next()?))
+    } else {
+        None
+    }
+}
+
+/// Whether `def_id` is an inherent method of `RefCell` or `RwLock` which doesn't only give shared
+/// access to the contained value.
+fn gives_mutable_access(cx: &LateContext<'_>, def_id: DefId) -> bool {
+    if let Some(impl_id) = cx.tcx.impl_of_method(def_id)
+        && cx.tcx.impl_trait_ref(impl_id).is_none()
+    {
+        let shared_only: &[&str] = match get_type_diagnostic_name(cx, cx.tcx.type_of(impl_id).instantiate_identity()) {
+            Some(sym::RefCell) => &["borrow", "try_borrow", "try_borrow_unguarded", "new"],
+            Some(sym::RwLock) => &["read", "try_read", "is_poisoned", "clear_poison", "new"],
+            _ => return false,
+        };
+        !shared_only.contains(&cx.tcx.item_name(def_id).as_str())
+    } else {
+        false
+    }
+}
@@ -1,12 +1,14 @@
 use clippy_config::Conf;
-use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
 use clippy_utils::source::snippet;
 use clippy_utils::visitors::find_all_ret_expressions;
-use clippy_utils::{contains_return, is_res_lang_ctor, path_res, return_ty};
+use clippy_utils::{contains_return, fn_def_id, get_parent_expr, is_res_lang_ctor, path_res, return_ty};
+use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::Applicability;
 use rustc_hir::LangItem::{OptionSome, ResultOk};
+use rustc_hir::def::{DefKind, Res};
 use rustc_hir::intravisit::FnKind;
-use rustc_hir::{Body, ExprKind, FnDecl, Impl, ItemKind, Node};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, Impl, ItemKind, MatchSource, Node, StmtKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
 use rustc_session::impl_lint_pass;
@@ -21,6 +23,9 @@ declare_clippy_lint! {
     /// ### Why is this bad?
     /// It is not meaningful to wrap values when no `None` or `Err` is returned.
     ///
+    /// The suggestion also updates the calls to the function, by removing the `?`, `unwrap()`
+    /// or `expect(..)` applied to the result, or by wrapping the call otherwise.
+    ///
     /// ### Known problems
     /// There can be false positives if the function signature is designed to
     /// fit some external requirement.
@@ -59,6 +64,31 @@ declare_clippy_lint! {
 
 pub struct UnnecessaryWraps {
     avoid_breaking_exported_api: bool,
+    wrapping_fns: Vec<WrappingFn>,
+    fn_uses: FxHashMap<LocalDefId, Vec<FnUse>>,
+}
+
+/// A function which only returns `Some` or `Ok`. It is linted in `check_crate_post`, once all of
+/// its uses are known.
+struct WrappingFn {
+    def_id: LocalDefId,
+    span: Span,
+    /// `Some` or `Ok`
+    wrapper: &'static str,
+    lint_msg: String,
+    return_type_sugg: (Span, String, String),
+    body_sugg: (&'static str, Vec<(Span, String)>),
+}
+
+enum FnUse {
+    /// The result is discarded, e.g. `f();`
+    Discarded,
+    /// The result is unwrapped by `?`, `unwrap()` or `expect(..)`, which the span covers.
+    Unwrapped(Span),
+    /// The result is used as is and has to be wrapped after the change.
+    Wrapped(Span),
+    /// Any other use, e.g. as a function pointer or from a macro expansion.
+    Other,
 }
 
 impl_lint_pass!(UnnecessaryWraps => [UNNECESSARY_WRAPS]);
@@ -67,6 +97,8 @@ impl UnnecessaryWraps {
     pub fn new(conf: &'static Conf) -> Self {
         Self {
             avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
+            wrapping_fns: Vec::new(),
+            fn_uses: FxHashMap::default(),
         }
     }
 }
@@ -103,12 +135,12 @@ impl<'tcx> LateLintPass<'tcx> for UnnecessaryWraps {
         }
 
         // Get the wrapper and inner types, if can't, abort.
-        let (return_type_label, lang_item, inner_type) =
+        let (return_type_label, wrapper, lang_item, inner_type) =
             if let ty::Adt(adt_def, subst) = return_ty(cx, hir_id.expect_owner()).kind() {
                 if cx.tcx.is_diagnostic_item(sym::Option, adt_def.did()) {
-                    ("Option", OptionSome, subst.type_at(0))
+                    ("Option", "Some", OptionSome, subst.type_at(0))
                 } else if cx.tcx.is_diagnostic_item(sym::Result, adt_def.did()) {
-                    ("Result", ResultOk, subst.type_at(0))
+                    ("Result", "Ok", ResultOk, subst.type_at(0))
                 } else {
                     return;
                 }
@@ -159,15 +191,106 @@ impl<'tcx> LateLintPass<'tcx> for UnnecessaryWraps {
                 )
             };
 
-            span_lint_and_then(cx, UNNECESSARY_WRAPS, span, lint_msg, |diag| {
-                diag.span_suggestion(
-                    fn_decl.output.span(),
-                    return_type_sugg_msg,
-                    return_type_sugg,
-                    Applicability::MaybeIncorrect,
-                );
-                diag.multipart_suggestion(body_sugg_msg, suggs, Applicability::MaybeIncorrect);
+            // Don't lint just yet, the suggestion needs all the uses of the function.
+            self.wrapping_fns.push(WrappingFn {
+                def_id,
+                span,
+                wrapper,
+                lint_msg,
+                return_type_sugg: (fn_decl.output.span(), return_type_sugg_msg, return_type_sugg),
+                body_sugg: (body_sugg_msg, suggs),
             });
         }
     }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some(def_id) = fn_def_id(cx, expr)
+            && let Some(def_id) = def_id.as_local()
+        {
+            self.fn_uses.entry(def_id).or_default().push(call_use(cx, expr));
+        } else if let ExprKind::Path(ref qpath) = expr.kind
+            && let Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) = cx.qpath_res(qpath, expr.hir_id)
+            && let Some(def_id) = def_id.as_local()
+            && !get_parent_expr(cx, expr)
+                .is_some_and(|parent| matches!(parent.kind, ExprKind::Call(callee, _) if callee.hir_id == expr.hir_id))
+        {
+            self.fn_uses.entry(def_id).or_default().push(FnUse::Other);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for fun in &self.wrapping_fns {
+            let mut call_suggs = Vec::new();
+            let mut can_update_calls = true;
+            for fn_use in self.fn_uses.get(&fun.def_id).map_or(&[][..], Vec::as_slice) {
+                match *fn_use {
+                    FnUse::Discarded => {},
+                    // Calls within the function itself would overlap with the body suggestion.
+                    FnUse::Unwrapped(span) | FnUse::Wrapped(span) if fun.span.contains(span) => {
+                        can_update_calls = false;
+                    },
+                    FnUse::Unwrapped(span) => call_suggs.push((span, String::new())),
+                    FnUse::Wrapped(span) => {
+                        call_suggs.push((span.shrink_to_lo(), format!("{}(", fun.wrapper)));
+                        call_suggs.push((span.shrink_to_hi(), ")".to_string()));
+                    },
+                    FnUse::Other => can_update_calls = false,
+                }
+            }
+
+            span_lint_hir_and_then(
+                cx,
+                UNNECESSARY_WRAPS,
+                cx.tcx.local_def_id_to_hir_id(fun.def_id),
+                fun.span,
+                fun.lint_msg.clone(),
+                |diag| {
+                    let (span, msg, sugg) = &fun.return_type_sugg;
+                    diag.span_suggestion(*span, msg.clone(), sugg.clone(), Applicability::MaybeIncorrect);
+                    let (msg, suggs) = &fun.body_sugg;
+                    diag.multipart_suggestion(*msg, suggs.clone(), Applicability::MaybeIncorrect);
+                    if !can_update_calls {
+                        diag.note("some uses of this function have to be updated manually");
+                    } else if !call_suggs.is_empty() {
+                        diag.multipart_suggestion(
+                            "...and then update the calls",
+                            call_suggs,
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// Classifies how the result of a call to a local function is used.
+fn call_use(cx: &LateContext<'_>, call: &Expr<'_>) -> FnUse {
+    if call.span.from_expansion() {
+        return FnUse::Other;
+    }
+    if let Node::Stmt(stmt) = cx.tcx.parent_hir_node(call.hir_id)
+        && let StmtKind::Semi(_) = stmt.kind
+    {
+        return FnUse::Discarded;
+    }
+    let Some(parent) = get_parent_expr(cx, call) else {
+        return FnUse::Wrapped(call.span);
+    };
+    // `f()?` is desugared to `match Try::branch(f()) { .. }`
+    if let ExprKind::Call(_, [arg]) = parent.kind
+        && arg.hir_id == call.hir_id
+        && let Some(try_expr) = get_parent_expr(cx, parent)
+        && let ExprKind::Match(scrutinee, _, MatchSource::TryDesugar(_)) = try_expr.kind
+        && scrutinee.hir_id == parent.hir_id
+    {
+        FnUse::Unwrapped(try_expr.span.with_lo(call.span.hi()))
+    } else if let ExprKind::MethodCall(method, recv, args, _) = parent.kind
+        && recv.hir_id == call.hir_id
+        && matches!((method.ident.name, args), (sym::unwrap, []) | (sym::expect, [_]))
+    {
+        FnUse::Unwrapped(parent.span.with_lo(call.span.hi()))
+    } else {
+        FnUse::Wrapped(call.span)
+    }
 }
@@ -0,0 +1,178 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{is_diag_item_method, is_diag_trait_item};
+use rustc_ast::{LitKind, StrStyle};
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, ClauseKind, Ty};
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for string literals using `\` as a path separator or starting with a drive prefix
+    /// such as `C:\`, which are passed to functions taking a path, e.g. `Path::new`,
+    /// `PathBuf::from`, `File::open` or any other function taking an `AsRef<Path>`.
+    ///
+    /// ### Why is this bad?
+    /// `\` is only a path separator on Windows. On other platforms it's a regular character, so
+    /// `"data\\input.txt"` is the name of a single file in the current directory. Drive prefixes
+    /// don't exist outside of Windows either.
+    ///
+    /// `/` is a path separator on every platform, including Windows. Alternatively, the path can be
+    /// built with `Path::join`.
+    ///
+    /// Crates which only target Windows can disable the lint with the
+    /// `allow-windows-path-literals` configuration.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::fs::File;
+    /// let file = File::open("data\\input.txt");
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use std::path::Path;
+    /// let file = File::open("data/input.txt");
+    /// // or
+    /// let file = File::open(Path::new("data").join("input.txt"));
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub UNPORTABLE_PATH_LITERAL,
+    pedantic,
+    "path literals which only work on Windows"
+}
+
+pub struct UnportablePathLiteral {
+    allow_windows_path_literals: bool,
+}
+
+impl UnportablePathLiteral {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            allow_windows_path_literals: conf.allow_windows_path_literals,
+        }
+    }
+}
+
+impl_lint_pass!(UnportablePathLiteral => [UNPORTABLE_PATH_LITERAL]);
+
+impl<'tcx> LateLintPass<'tcx> for UnportablePathLiteral {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if self.allow_windows_path_literals || in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        let (def_id, args) = match expr.kind {
+            ExprKind::Call(callee, args) => match *cx.typeck_results().expr_ty(callee).kind() {
+                ty::FnDef(def_id, _) => (def_id, args),
+                _ => return,
+            },
+            ExprKind::MethodCall(_, _, args, _) => match cx.typeck_results().type_dependent_def_id(expr.hir_id) {
+                // The receiver can't be a literal
+                Some(def_id) => (def_id, args),
+                None => return,
+            },
+            _ => return,
+        };
+        let inputs = cx.tcx.fn_sig(def_id).instantiate_identity().skip_binder().inputs();
+        // Skip the receiver of method calls
+        let inputs = &inputs[inputs.len().saturating_sub(args.len())..];
+        for (arg, &input) in args.iter().zip(inputs) {
+            if let ExprKind::Lit(lit) = arg.kind
+                && let LitKind::Str(value, style) = lit.node
+                && !arg.span.from_expansion()
+                && let Some(kind) = unportable_kind(value.as_str())
+                && takes_path(cx, expr, def_id, input)
+            {
+                lint(cx, arg, kind, style);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum UnportableKind {
+    /// The path starts with a drive prefix like `C:` or is a UNC path like `\\server\share`.
+    Absolute,
+    /// The path uses `\` as a separator.
+    Separator,
+}
+
+fn unportable_kind(path: &str) -> Option<UnportableKind> {
+    let bytes = path.as_bytes();
+    if matches!(bytes, [drive, b':', ..] if drive.is_ascii_alphabetic()) || path.starts_with("\\\\") {
+        Some(UnportableKind::Absolute)
+    } else if path.contains('\\') {
+        Some(UnportableKind::Separator)
+    } else {
+        None
+    }
+}
+
+/// Whether the parameter of type `input` of the called function `def_id` takes a path. That's
+/// the case for `Path::new`, `PathBuf::from` and parameters of a type implementing `AsRef<Path>`.
+fn takes_path<'tcx>(cx: &LateContext<'tcx>, call: &Expr<'_>, def_id: DefId, input: Ty<'tcx>) -> bool {
+    if (is_diag_item_method(cx, def_id, sym::Path) && cx.tcx.item_name(def_id) == sym::new)
+        || (is_diag_trait_item(cx, def_id, sym::From)
+            && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(call), sym::PathBuf))
+    {
+        return true;
+    }
+    let ty::Param(_) = input.kind() else {
+        return false;
+    };
+    let Some(as_ref) = cx.tcx.get_diagnostic_item(sym::AsRef) else {
+        return false;
+    };
+    cx.tcx
+        .param_env(def_id)
+        .caller_bounds()
+        .iter()
+        .any(|clause| match clause.kind().skip_binder() {
+            ClauseKind::Trait(pred) => {
+                pred.def_id() == as_ref
+                    && pred.self_ty() == input
+                    && is_type_diagnostic_item(cx, pred.trait_ref.args.type_at(1), sym::Path)
+            },
+            _ => false,
+        })
+}
+
+fn lint(cx: &LateContext<'_>, lit: &Expr<'_>, kind: UnportableKind, style: StrStyle) {
+    match kind {
+        UnportableKind::Absolute => span_lint_and_then(
+            cx,
+            UNPORTABLE_PATH_LITERAL,
+            lit.span,
+            "this path literal only exists on Windows",
+            |diag| {
+                diag.help("consider reading the path from the environment or a configuration instead");
+            },
+        ),
+        UnportableKind::Separator => span_lint_and_then(
+            cx,
+            UNPORTABLE_PATH_LITERAL,
+            lit.span,
+            "this path literal uses `\\` as a separator, which only works on Windows",
+            |diag| {
+                let snippet = snippet(cx, lit.span, "..");
+                let portable = match style {
+                    StrStyle::Cooked => snippet.replace("\\\\", "/"),
+                    StrStyle::Raw(_) => snippet.replace('\\', "/"),
+                };
+                diag.span_suggestion(
+                    lit.span,
+                    "use `/`, which is a separator on all platforms",
+                    portable,
+                    Applicability::MaybeIncorrect,
+                );
+            },
+        ),
+    }
+}
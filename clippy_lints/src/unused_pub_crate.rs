@@ -0,0 +1,209 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::is_from_proc_macro;
+use clippy_utils::source::SpanRangeExt;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{CRATE_DEF_ID, DefId, LocalDefId};
+use rustc_hir::{Expr, ExprKind, HirId, ImplItem, ImplItemKind, Item, ItemKind, Node, Path, QPath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Visibility};
+use rustc_session::impl_lint_pass;
+use rustc_span::{BytePos, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for items declared `pub(crate)` which are only used in the module defining them, or
+    /// in its submodules.
+    ///
+    /// ### Why is this bad?
+    /// Private items are already visible in their module and its submodules. Declaring such an
+    /// item `pub(crate)` suggests it's used elsewhere in the crate, and lets it be used from
+    /// anywhere by accident.
+    ///
+    /// ### Known problems
+    /// Uses in code disabled by `#[cfg]`, e.g. in tests, are not seen.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// mod parser {
+    ///     pub(crate) fn parse_digit(c: char) -> Option<u32> {
+    ///         c.to_digit(10)
+    ///     }
+    ///
+    ///     pub fn parse(s: &str) -> Vec<u32> {
+    ///         s.chars().filter_map(parse_digit).collect()
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// mod parser {
+    ///     fn parse_digit(c: char) -> Option<u32> {
+    ///         c.to_digit(10)
+    ///     }
+    ///
+    ///     pub fn parse(s: &str) -> Vec<u32> {
+    ///         s.chars().filter_map(parse_digit).collect()
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.86.0"]
+    pub UNUSED_PUB_CRATE,
+    nursery,
+    "`pub(crate)` items which are only used in their module"
+}
+
+#[derive(Default)]
+pub struct UnusedPubCrate {
+    /// The `pub(crate)` items, with the span of their visibility.
+    candidates: Vec<(LocalDefId, Span)>,
+    /// The local items used outside of their module.
+    used_outside: FxHashSet<LocalDefId>,
+}
+
+impl_lint_pass!(UnusedPubCrate => [UNUSED_PUB_CRATE]);
+
+impl UnusedPubCrate {
+    fn check_candidate(&mut self, cx: &LateContext<'_>, def_id: LocalDefId, vis_span: Span, span: Span) {
+        if cx.tcx.visibility(def_id) == Visibility::Restricted(CRATE_DEF_ID.to_def_id())
+            // Private items of the crate root are visible in the whole crate already
+            && cx.tcx.parent_module_from_def_id(def_id).to_local_def_id() != CRATE_DEF_ID
+            && !span.from_expansion()
+            && vis_span.check_source_text(cx, |src| src == "pub(crate)")
+        {
+            self.candidates.push((def_id, vis_span));
+        }
+    }
+
+    /// Records a use of `def_id` at `hir_id`. Uses through a re-export, or in the signature of an
+    /// item which isn't private, also need the item to be visible outside of its module.
+    fn record_use(&mut self, cx: &LateContext<'_>, def_id: DefId, hir_id: HirId, needs_visibility: bool) {
+        // Constructors are used through their struct or variant
+        let def_id = if let DefKind::Ctor(..) = cx.tcx.def_kind(def_id) {
+            cx.tcx.parent(def_id)
+        } else {
+            def_id
+        };
+        if let Some(def_id) = def_id.as_local() {
+            let def_module = cx.tcx.parent_module_from_def_id(def_id);
+            let use_module = cx.tcx.parent_module(hir_id);
+            if needs_visibility || !cx.tcx.is_descendant_of(use_module.to_def_id(), def_module.to_def_id()) {
+                self.used_outside.insert(def_id);
+            }
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for UnusedPubCrate {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if matches!(
+            item.kind,
+            ItemKind::Fn { .. }
+                | ItemKind::Const(..)
+                | ItemKind::Static(..)
+                | ItemKind::Struct(..)
+                | ItemKind::Enum(..)
+                | ItemKind::Union(..)
+                | ItemKind::TyAlias(..)
+                | ItemKind::Trait(..)
+                | ItemKind::Mod(..)
+        ) && !in_external_macro(cx.sess(), item.span)
+            && !is_from_proc_macro(cx, item)
+        {
+            self.check_candidate(cx, item.owner_id.def_id, item.vis_span, item.span);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        // Items of trait implementations have the visibility of the trait
+        if let ImplItemKind::Fn(..) | ImplItemKind::Const(..) = item.kind
+            && let Node::Item(impl_item) = cx.tcx.parent_hir_node(item.hir_id())
+            && let ItemKind::Impl(impl_) = impl_item.kind
+            && impl_.of_trait.is_none()
+            && !in_external_macro(cx.sess(), item.span)
+            && !is_from_proc_macro(cx, item)
+        {
+            self.check_candidate(cx, item.owner_id.def_id, item.vis_span, item.span);
+        }
+    }
+
+    fn check_path(&mut self, cx: &LateContext<'tcx>, path: &Path<'tcx>, hir_id: HirId) {
+        let needs_visibility = match cx.tcx.hir_node(hir_id) {
+            Node::Item(item) if let ItemKind::Use(..) = item.kind => is_exposed(cx, item.owner_id.def_id),
+            // A path outside of a body is part of the signature of an item
+            _ if cx.enclosing_body.is_none() => is_exposed(cx, hir_id.owner.def_id),
+            _ => false,
+        };
+        for segment in path.segments {
+            if let Res::Def(_, def_id) = segment.res {
+                self.record_use(cx, def_id, hir_id, needs_visibility);
+            }
+        }
+        if let Res::Def(_, def_id) = path.res {
+            self.record_use(cx, def_id, hir_id, needs_visibility);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            ExprKind::MethodCall(..) => {
+                if let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) {
+                    self.record_use(cx, def_id, expr.hir_id, false);
+                }
+            },
+            ExprKind::Path(ref qpath @ QPath::TypeRelative(..)) => {
+                if let Some(def_id) = cx.qpath_res(qpath, expr.hir_id).opt_def_id() {
+                    self.record_use(cx, def_id, expr.hir_id, false);
+                }
+            },
+            _ => {},
+        }
+        // Values of a type can be used outside of its module without naming it
+        if let ty::Adt(adt, _) = cx.typeck_results().expr_ty(expr).kind() {
+            self.record_use(cx, adt.did(), expr.hir_id, false);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for &(def_id, vis_span) in &self.candidates {
+            if self.used_outside.contains(&def_id) {
+                continue;
+            }
+            // Also remove the space following `pub(crate)`
+            let removal_span = vis_span.with_hi(vis_span.hi() + BytePos(1));
+            let removal_span = if removal_span.check_source_text(cx, |src| src == "pub(crate) ") {
+                removal_span
+            } else {
+                vis_span
+            };
+            let descr = cx.tcx.def_descr(def_id.to_def_id());
+            span_lint_hir_and_then(
+                cx,
+                UNUSED_PUB_CRATE,
+                cx.tcx.local_def_id_to_hir_id(def_id),
+                removal_span,
+                format!("this `pub(crate)` {descr} is only used in its module"),
+                |diag| {
+                    diag.span_suggestion(removal_span, "make it private", "", Applicability::MaybeIncorrect);
+                },
+            );
+        }
+    }
+}
+
+/// Whether the item is visible outside of its module, so that the items named in its signature
+/// must be as well.
+fn is_exposed(cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
+    match cx.tcx.def_kind(def_id) {
+        DefKind::Impl { .. } => false,
+        // Items of trait implementations are as visible as the trait
+        DefKind::AssocFn | DefKind::AssocConst | DefKind::AssocTy
+            if cx.tcx.associated_item(def_id).trait_item_def_id.is_some() =>
+        {
+            false
+        },
+        _ => cx.tcx.visibility(def_id) != Visibility::Restricted(cx.tcx.parent_module_from_def_id(def_id).to_def_id()),
+    }
+}
@@ -1,13 +1,13 @@
 use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::source::{snippet, snippet_with_applicability, snippet_with_context};
 use clippy_utils::sugg::{DiagExt as _, Sugg};
-use clippy_utils::ty::{is_copy, is_type_diagnostic_item, same_type_and_consts};
+use clippy_utils::ty::{implements_trait, is_copy, is_type_diagnostic_item, same_type_and_consts};
 use clippy_utils::{
     get_parent_expr, is_inherent_method_call, is_trait_item, is_trait_method, is_ty_alias, path_to_local,
 };
 use rustc_errors::Applicability;
 use rustc_hir::def_id::DefId;
-use rustc_hir::{BindingMode, Expr, ExprKind, HirId, MatchSource, Node, PatKind};
+use rustc_hir::{BindingMode, Expr, ExprKind, HirId, LangItem, MatchSource, Node, PatKind, QPath};
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_infer::traits::Obligation;
 use rustc_lint::{LateContext, LateLintPass};
@@ -22,6 +22,10 @@ declare_clippy_lint! {
     /// Checks for `Into`, `TryInto`, `From`, `TryFrom`, or `IntoIter` calls
     /// which uselessly convert to the same type.
     ///
+    /// Also checks for `.map_err(From::from)` on the operand of the `?` operator when the `?`
+    /// operator already performs the same conversion, and for lossless numeric conversions going
+    /// through an unneeded intermediate type, such as `i64::from(i32::from(x))` where `x: u8`.
+    ///
     /// ### Why is this bad?
     /// Redundant code.
     ///
@@ -354,6 +358,26 @@ impl<'tcx> LateLintPass<'tcx> for UselessConversion {
                         );
                     }
 
+                    if cx.tcx.is_diagnostic_item(sym::from_fn, def_id)
+                        && !same_type_and_consts(a, b)
+                        && a.is_numeric()
+                        && let Some((inner, inner_ty)) = lossless_numeric_conversion(cx, arg)
+                        && let Some(from_trait) = cx.tcx.get_diagnostic_item(sym::From)
+                        && implements_trait(cx, a, from_trait, &[inner_ty.into()])
+                    {
+                        let mut app = Applicability::MachineApplicable;
+                        let inner = snippet_with_context(cx, inner.span, e.span.ctxt(), "<expr>", &mut app).0;
+                        span_lint_and_sugg(
+                            cx,
+                            USELESS_CONVERSION,
+                            e.span,
+                            format!("useless intermediate conversion to `{b}`"),
+                            format!("convert from `{inner_ty}` directly"),
+                            format!("{}({inner})", snippet(cx, path.span, "From::from")),
+                            app,
+                        );
+                    }
+
                     if cx.tcx.is_diagnostic_item(sym::from_fn, def_id) && same_type_and_consts(a, b) {
                         let mut app = Applicability::MachineApplicable;
                         let sugg = Sugg::hir_with_context(cx, arg, e.span.ctxt(), "<expr>", &mut app).maybe_par();
@@ -389,25 +413,90 @@ impl<'tcx> LateLintPass<'tcx> for UselessConversion {
 /// higher-order mapping function.
 pub fn check_function_application(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &Expr<'_>) {
     if has_eligible_receiver(cx, recv, expr)
-        && (is_trait_item(cx, arg, sym::Into) || is_trait_item(cx, arg, sym::From))
+        && let is_from = is_trait_item(cx, arg, sym::From)
+        && (is_from || is_trait_item(cx, arg, sym::Into))
         && let ty::FnDef(_, args) = cx.typeck_results().expr_ty(arg).kind()
-        && let &[from_ty, to_ty] = args.into_type_list(cx.tcx).as_slice()
-        && same_type_and_consts(from_ty, to_ty)
+        && let &[self_ty, param_ty] = args.into_type_list(cx.tcx).as_slice()
     {
-        span_lint_and_then(
-            cx,
-            USELESS_CONVERSION,
-            expr.span.with_lo(recv.span.hi()),
-            format!("useless conversion to the same type: `{from_ty}`"),
-            |diag| {
-                diag.suggest_remove_item(
-                    cx,
-                    expr.span.with_lo(recv.span.hi()),
-                    "consider removing",
-                    Applicability::MachineApplicable,
-                );
-            },
-        );
+        // `Into::into` is generic over `Self: Into<T>`, `From::from` over `Self: From<T>`
+        let (from_ty, to_ty) = if is_from {
+            (param_ty, self_ty)
+        } else {
+            (self_ty, param_ty)
+        };
+        let (msg, app) = if same_type_and_consts(from_ty, to_ty) {
+            (
+                format!("useless conversion to the same type: `{from_ty}`"),
+                Applicability::MachineApplicable,
+            )
+        } else if let ExprKind::MethodCall(name, ..) = expr.kind
+            && name.ident.as_str() == "map_err"
+            && let Some(try_target) = try_error_target(cx, expr)
+            && same_type_and_consts(to_ty, try_target)
+            && let Some(from_trait) = cx.tcx.get_diagnostic_item(sym::From)
+            && implements_trait(cx, try_target, from_trait, &[from_ty.into()])
+        {
+            // Without the explicit conversion the error type of the `Result` may no longer be inferred
+            (
+                format!("useless conversion to `{to_ty}`, the `?` operator already converts the error"),
+                Applicability::MaybeIncorrect,
+            )
+        } else {
+            return;
+        };
+        span_lint_and_then(cx, USELESS_CONVERSION, expr.span.with_lo(recv.span.hi()), msg, |diag| {
+            diag.suggest_remove_item(cx, expr.span.with_lo(recv.span.hi()), "consider removing", app);
+        });
+    }
+}
+
+/// If `expr` is the operand of the `?` operator, returns the error type the `?` operator converts
+/// the error of a `Result` into, i.e. the error type of the enclosing function or closure.
+fn try_error_target<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<Ty<'tcx>> {
+    if let Some(branch) = get_parent_expr(cx, expr)
+        && let ExprKind::Call(branch_fn, [_]) = branch.kind
+        && let ExprKind::Path(QPath::LangItem(LangItem::TryTraitBranch, ..)) = branch_fn.kind
+        && let Some(try_match) = get_parent_expr(cx, branch)
+        && let ExprKind::Match(_, [residual_arm, ..], MatchSource::TryDesugar(_)) = try_match.kind
+        && let ExprKind::Ret(Some(ret)) | ExprKind::Break(_, Some(ret)) = residual_arm.body.kind
+        && let ret_ty = cx.typeck_results().expr_ty(ret)
+        && is_type_diagnostic_item(cx, ret_ty, sym::Result)
+        && let ty::Adt(_, args) = ret_ty.kind()
+    {
+        args.types().nth(1)
+    } else {
+        None
+    }
+}
+
+/// Returns the converted expression and its type if `expr` converts a number losslessly, i.e.
+/// `T::from(x)` or `x as T` where `T: From<X>`.
+fn lossless_numeric_conversion<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, Ty<'tcx>)> {
+    let inner = match expr.kind {
+        ExprKind::Call(path, [arg])
+            if let ExprKind::Path(ref qpath) = path.kind
+                && let Some(def_id) = cx.qpath_res(qpath, path.hir_id).opt_def_id()
+                && cx.tcx.is_diagnostic_item(sym::from_fn, def_id) =>
+        {
+            arg
+        },
+        ExprKind::Cast(inner, _) => inner,
+        _ => return None,
+    };
+    let inner_ty = cx.typeck_results().expr_ty(inner);
+    let ty = cx.typeck_results().expr_ty(expr);
+    if inner_ty.is_numeric()
+        && ty.is_numeric()
+        && !expr.span.from_expansion()
+        && let Some(from_trait) = cx.tcx.get_diagnostic_item(sym::From)
+        && implements_trait(cx, ty, from_trait, &[inner_ty.into()])
+    {
+        Some((inner, inner_ty))
+    } else {
+        None
     }
 }
 
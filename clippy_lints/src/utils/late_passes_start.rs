@@ -0,0 +1,15 @@
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+
+declare_lint_pass!(
+    /// Marks the start of the late lint passes for the diagnostics of `clippy_utils`, see
+    /// [`enter_late_passes`](clippy_utils::diagnostics::enter_late_passes). It has to be
+    /// registered before the other late passes, so that it runs first.
+    LatePassesStart => []
+);
+
+impl LateLintPass<'_> for LatePassesStart {
+    fn check_crate(&mut self, _: &LateContext<'_>) {
+        clippy_utils::diagnostics::enter_late_passes();
+    }
+}
@@ -1,7 +1,30 @@
+use std::fs::OpenOptions;
+use std::io::{self, Write as _};
+use std::path::Path;
+
 pub mod attr_collector;
 pub mod author;
 pub mod dump_hir;
 pub mod format_args_collector;
+pub mod late_passes_start;
+pub mod node_span_collector;
+pub mod pass_timings;
 
 #[cfg(feature = "internal")]
 pub mod internal_lints;
+
+/// Appends one line per row to the file at `path`, with the fields of a row separated by tabs.
+/// The file is appended to as a single crate may be compiled by several `clippy-driver`
+/// invocations.
+pub(crate) fn append_tsv<const N: usize>(path: &Path, rows: impl IntoIterator<Item = [String; N]>) -> io::Result<()> {
+    let mut out = String::new();
+    for row in rows {
+        out.push_str(&row.join("\t"));
+        out.push('\n');
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(out.as_bytes())
+}
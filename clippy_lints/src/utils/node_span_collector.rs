@@ -0,0 +1,16 @@
+use rustc_ast::Crate;
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::declare_lint_pass;
+
+declare_lint_pass!(
+    /// Collects the nodes the lints emitted by the generic `span_lint*` functions are looked up
+    /// in, see [`collect_node_spans`](clippy_utils::diagnostics::collect_node_spans). It has to be
+    /// registered before the other early passes, so that it runs first.
+    NodeSpanCollector => []
+);
+
+impl EarlyLintPass for NodeSpanCollector {
+    fn check_crate(&mut self, _: &EarlyContext<'_>, krate: &Crate) {
+        clippy_utils::diagnostics::collect_node_spans(krate);
+    }
+}
@@ -0,0 +1,154 @@
+//! Measures the time spent in each lint pass, used by `cargo lintcheck --perf`.
+//!
+//! The passes registered by Clippy are wrapped into [`TimedEarlyPass`] and [`TimedLatePass`],
+//! which forward every method to the inner pass and add up the time it took. The durations are
+//! collected when the passes are dropped and written out by [`write_pass_timings`].
+//!
+//! Passes can time parts of their work with [`time_part`], e.g. `Methods` times its handler of
+//! each method. The parts are written out along with the passes, as `Pass::part`.
+
+use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintPass, LintStore, LintVec};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+static PASS_TIMINGS: Mutex<Option<BTreeMap<&'static str, Duration>>> = Mutex::new(None);
+
+static TIME_PARTS: AtomicBool = AtomicBool::new(false);
+
+fn record(pass: &'static str, elapsed: Duration) {
+    let mut timings = PASS_TIMINGS.lock().unwrap();
+    *timings.get_or_insert_default().entry(pass).or_default() += elapsed;
+}
+
+/// Runs `f`, adding the time it took to `part` if the passes are timed. `part` is named
+/// `Pass::part`, its time is also included in the time of the pass.
+pub(crate) fn time_part<R>(part: &'static str, f: impl FnOnce() -> R) -> R {
+    if !TIME_PARTS.load(Ordering::Relaxed) {
+        return f();
+    }
+    let start = Instant::now();
+    let res = f();
+    record(part, start.elapsed());
+    res
+}
+
+/// Wraps the early and late passes registered after the given indices of the lint store.
+pub fn time_passes(store: &mut LintStore, early_start: usize, late_start: usize) {
+    TIME_PARTS.store(true, Ordering::Relaxed);
+    for factory in &mut store.early_passes[early_start..] {
+        let inner = std::mem::replace(factory, Box::new(|| unreachable!()));
+        *factory = Box::new(move || {
+            Box::new(TimedEarlyPass {
+                pass: inner(),
+                elapsed: Duration::ZERO,
+            })
+        });
+    }
+    for factory in &mut store.late_passes[late_start..] {
+        let inner = std::mem::replace(factory, Box::new(|_| unreachable!()));
+        *factory = Box::new(move |tcx| {
+            Box::new(TimedLatePass {
+                pass: inner(tcx),
+                elapsed: Duration::ZERO,
+            })
+        });
+    }
+}
+
+/// Appends the collected timings to the file at `path`, one `pass<TAB>nanoseconds` line per pass.
+///
+/// # Errors
+///
+/// Returns the error of writing to the file.
+///
+/// # Panics
+///
+/// Panics if a pass panicked while its timing was recorded.
+pub fn write_pass_timings(path: &Path) -> io::Result<()> {
+    let Some(timings) = PASS_TIMINGS.lock().unwrap().take() else {
+        return Ok(());
+    };
+    super::append_tsv(
+        path,
+        timings
+            .into_iter()
+            .map(|(pass, elapsed)| [pass.to_string(), elapsed.as_nanos().to_string()]),
+    )
+}
+
+struct TimedEarlyPass {
+    pass: Box<dyn EarlyLintPass>,
+    elapsed: Duration,
+}
+
+struct TimedLatePass<'tcx> {
+    pass: Box<dyn LateLintPass<'tcx> + 'tcx>,
+    elapsed: Duration,
+}
+
+impl Drop for TimedEarlyPass {
+    fn drop(&mut self) {
+        record(self.pass.name(), self.elapsed);
+    }
+}
+
+impl Drop for TimedLatePass<'_> {
+    fn drop(&mut self) {
+        record(self.pass.name(), self.elapsed);
+    }
+}
+
+// The name and lints are those of the wrapped pass, which the macros can't forward
+#[expect(rustc::lint_pass_impl_without_macro)]
+impl LintPass for TimedEarlyPass {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+
+    fn get_lints(&self) -> LintVec {
+        self.pass.get_lints()
+    }
+}
+
+// The name and lints are those of the wrapped pass, which the macros can't forward
+#[expect(rustc::lint_pass_impl_without_macro)]
+impl LintPass for TimedLatePass<'_> {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+
+    fn get_lints(&self) -> LintVec {
+        self.pass.get_lints()
+    }
+}
+
+macro_rules! impl_timed_early_pass {
+    ([], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => {
+        impl EarlyLintPass for TimedEarlyPass {
+            $(fn $name(&mut self, cx: &EarlyContext<'_>, $($param: $arg),*) {
+                let start = Instant::now();
+                self.pass.$name(cx, $($param),*);
+                self.elapsed += start.elapsed();
+            })*
+        }
+    };
+}
+
+macro_rules! impl_timed_late_pass {
+    ([], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => {
+        impl<'tcx> LateLintPass<'tcx> for TimedLatePass<'tcx> {
+            $(fn $name(&mut self, cx: &LateContext<'tcx>, $($param: $arg),*) {
+                let start = Instant::now();
+                self.pass.$name(cx, $($param),*);
+                self.elapsed += start.elapsed();
+            })*
+        }
+    };
+}
+
+rustc_lint::early_lint_methods!(impl_timed_early_pass, []);
+rustc_lint::late_lint_methods!(impl_timed_late_pass, []);
@@ -29,7 +29,7 @@ impl UselessVec {
             too_large_for_stack: conf.too_large_for_stack,
             msrv: conf.msrv.clone(),
             span_to_lint_map: BTreeMap::new(),
-            allow_in_test: conf.allow_useless_vec_in_tests,
+            allow_in_test: conf.is_allowed_in_tests(USELESS_VEC),
         }
     }
 }
@@ -100,14 +100,14 @@ declare_clippy_lint! {
 
 pub struct WildcardImports {
     warn_on_all: bool,
-    allowed_segments: FxHashSet<String>,
+    allowed_segments: FxHashSet<&'static str>,
 }
 
 impl WildcardImports {
     pub fn new(conf: &'static Conf) -> Self {
         Self {
             warn_on_all: conf.warn_on_all_wildcard_imports,
-            allowed_segments: conf.allowed_wildcard_imports.iter().cloned().collect(),
+            allowed_segments: conf.allowed_wildcard_imports.iter().map(|s| &**s).collect(),
         }
     }
 }
@@ -201,7 +201,7 @@ fn is_super_only_import(segments: &[PathSegment<'_>]) -> bool {
 
 // Allow skipping imports containing user configured segments,
 // i.e. "...::utils::...::*" if user put `allowed-wildcard-imports = ["utils"]` in `Clippy.toml`
-fn is_allowed_via_config(segments: &[PathSegment<'_>], allowed_segments: &FxHashSet<String>) -> bool {
+fn is_allowed_via_config(segments: &[PathSegment<'_>], allowed_segments: &FxHashSet<&'static str>) -> bool {
     // segment matching need to be exact instead of using 'contains', in case user unintentionally put
     // a single character in the config thus skipping most of the warnings.
     segments.iter().any(|seg| allowed_segments.contains(seg.ident.as_str()))
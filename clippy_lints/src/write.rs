@@ -241,7 +241,8 @@ declare_clippy_lint! {
 pub struct Write {
     format_args: FormatArgsStorage,
     in_debug_impl: bool,
-    allow_print_in_tests: bool,
+    allow_print_stdout_in_tests: bool,
+    allow_print_stderr_in_tests: bool,
 }
 
 impl Write {
@@ -249,7 +250,8 @@ impl Write {
         Self {
             format_args,
             in_debug_impl: false,
-            allow_print_in_tests: conf.allow_print_in_tests,
+            allow_print_stdout_in_tests: conf.is_allowed_in_tests(PRINT_STDOUT),
+            allow_print_stderr_in_tests: conf.is_allowed_in_tests(PRINT_STDERR),
         }
     }
 }
@@ -297,14 +299,14 @@ impl<'tcx> LateLintPass<'tcx> for Write {
             .as_ref()
             .is_some_and(|crate_name| crate_name == "build_script_build");
 
-        let allowed_in_tests = self.allow_print_in_tests && is_in_test(cx.tcx, expr.hir_id);
+        let allowed_in_tests = |allow: bool| allow && is_in_test(cx.tcx, expr.hir_id);
         match diag_name {
-            sym::print_macro | sym::println_macro if !allowed_in_tests => {
+            sym::print_macro | sym::println_macro if !allowed_in_tests(self.allow_print_stdout_in_tests) => {
                 if !is_build_script {
                     span_lint(cx, PRINT_STDOUT, macro_call.span, format!("use of `{name}!`"));
                 }
             },
-            sym::eprint_macro | sym::eprintln_macro if !allowed_in_tests => {
+            sym::eprint_macro | sym::eprintln_macro if !allowed_in_tests(self.allow_print_stderr_in_tests) => {
                 span_lint(cx, PRINT_STDERR, macro_call.span, format!("use of `{name}!`"));
             },
             sym::write_macro | sym::writeln_macro => {},
@@ -1,17 +1,28 @@
-use clippy_utils::diagnostics::span_lint_and_help;
+use std::ops::ControlFlow;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::path_to_local_id;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::sugg::import_path;
 use clippy_utils::ty::{is_normalizable, is_type_diagnostic_item};
-use rustc_hir::{self as hir, HirId, ItemKind, Node};
+use clippy_utils::visitors::for_each_expr;
+use rustc_errors::Applicability;
+use rustc_hir::{self as hir, ExprKind, GenericArg, HirId, ItemKind, LetStmt, Node, PatKind, QPath, StmtKind};
 use rustc_hir_analysis::lower_ty;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::layout::LayoutOf as _;
 use rustc_middle::ty::{self, Ty, TypeVisitableExt};
 use rustc_session::declare_lint_pass;
-use rustc_span::sym;
+use rustc_span::{Span, sym};
 
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for maps with zero-sized value types anywhere in the code.
     ///
+    /// For local variables, the suggestion also rewrites the uses of the map, e.g.
+    /// `map.insert(k, ())` to `set.insert(k)` and `map.contains_key(&k)` to `set.contains(&k)`.
+    /// If the map is used in any other way, only the type is flagged.
+    ///
     /// ### Why is this bad?
     /// Since there is only a single value for a zero-sized type, a map
     /// containing zero sized values is effectively a set. Using a set in that case improves
@@ -59,13 +70,32 @@ impl LateLintPass<'_> for ZeroSizedMapValues {
             && let Ok(layout) = cx.layout_of(ty)
             && layout.is_zst()
         {
-            span_lint_and_help(
+            // The constructor is rewritten together with the type annotation of the `let`.
+            if let Some(local) = let_of_constructor(cx, hir_ty)
+                && let Some(annotation) = local.ty
+                && set_rewrite(cx, local, annotation).is_some()
+            {
+                return;
+            }
+            let rewrite = if let Node::LetStmt(local) = cx.tcx.parent_hir_node(hir_ty.hir_id)
+                && local.ty.is_some_and(|ty| ty.hir_id == hir_ty.hir_id)
+            {
+                set_rewrite(cx, local, hir_ty)
+            } else {
+                None
+            };
+            span_lint_and_then(
                 cx,
                 ZERO_SIZED_MAP_VALUES,
                 hir_ty.span,
                 "map with zero-sized value type",
-                None,
-                "consider using a set instead",
+                |diag| {
+                    if let Some(edits) = rewrite {
+                        diag.multipart_suggestion("consider using a set instead", edits, Applicability::MaybeIncorrect);
+                    } else {
+                        diag.help("consider using a set instead");
+                    }
+                },
             );
         }
     }
@@ -93,3 +123,110 @@ fn ty_from_hir_ty<'tcx>(cx: &LateContext<'tcx>, hir_ty: &hir::Ty<'tcx>) -> Ty<'t
         })
         .unwrap_or_else(|| lower_ty(cx.tcx, hir_ty))
 }
+
+/// Returns the `let` statement if `hir_ty` is the map type of a constructor call initializing it,
+/// e.g. `HashMap` in `let map: HashMap<K, ()> = HashMap::new();`.
+fn let_of_constructor<'tcx>(cx: &LateContext<'tcx>, hir_ty: &hir::Ty<'_>) -> Option<&'tcx LetStmt<'tcx>> {
+    if let Node::Expr(path) = cx.tcx.parent_hir_node(hir_ty.hir_id)
+        && let ExprKind::Path(QPath::TypeRelative(ty, _)) = path.kind
+        && ty.hir_id == hir_ty.hir_id
+        && let Node::Expr(call) = cx.tcx.parent_hir_node(path.hir_id)
+        && let ExprKind::Call(func, _) = call.kind
+        && func.hir_id == path.hir_id
+        && let Node::LetStmt(local) = cx.tcx.parent_hir_node(call.hir_id)
+        && local.init.is_some_and(|init| init.hir_id == call.hir_id)
+    {
+        Some(local)
+    } else {
+        None
+    }
+}
+
+/// Computes the edits to turn the map bound by `local` into a set, including all of its uses and
+/// the import of the set. Returns `None` if the map is used in a way which can't be rewritten.
+fn set_rewrite(cx: &LateContext<'_>, local: &LetStmt<'_>, annotation: &hir::Ty<'_>) -> Option<Vec<(Span, String)>> {
+    let PatKind::Binding(_, binding, ..) = local.pat.kind else {
+        return None;
+    };
+    let hir::TyKind::Path(QPath::Resolved(None, path)) = annotation.kind else {
+        return None;
+    };
+    let [.., map] = path.segments else {
+        return None;
+    };
+    let set = match map.ident.name {
+        sym::HashMap => "HashSet",
+        sym::BTreeMap => "BTreeSet",
+        _ => return None,
+    };
+    let set_path = import_path(cx, local.hir_id, &["std", "collections", set]);
+    let set = set_path.path.as_str();
+    let [GenericArg::Type(key), _] = map.args?.args else {
+        return None;
+    };
+    let mut edits = vec![(annotation.span, format!("{set}<{}>", snippet_opt(cx, key.span)?))];
+
+    // Only constructors which exist with the same signature on the set are rewritten.
+    let init = local.init?;
+    if let ExprKind::Call(func, _) = init.kind
+        && let ExprKind::Path(QPath::TypeRelative(ty, constructor)) = func.kind
+        && matches!(constructor.ident.as_str(), "new" | "with_capacity" | "default")
+        && let hir::TyKind::Path(QPath::Resolved(None, ty_path)) = ty.kind
+        && let [.., ty_segment] = ty_path.segments
+        && ty_segment.ident.name == map.ident.name
+        && ty_segment.args.is_none()
+        && !init.span.from_expansion()
+    {
+        edits.push((ty.span, set.to_string()));
+    } else {
+        return None;
+    }
+
+    let body = cx
+        .tcx
+        .hir()
+        .body_owned_by(cx.tcx.hir().enclosing_body_owner(local.hir_id));
+    for_each_expr(cx, body.value, |e| {
+        if path_to_local_id(e, binding) && !rewrite_use(cx, e, &mut edits) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_none()
+    .then(|| set_path.with_import(edits))
+}
+
+/// Adds the edits needed for the use `map` of the map to `edits`. Returns `false` if the use
+/// can't be rewritten.
+fn rewrite_use(cx: &LateContext<'_>, map: &hir::Expr<'_>, edits: &mut Vec<(Span, String)>) -> bool {
+    let Node::Expr(call) = cx.tcx.parent_hir_node(map.hir_id) else {
+        return false;
+    };
+    let ExprKind::MethodCall(method, recv, args, _) = call.kind else {
+        return false;
+    };
+    if recv.hir_id != map.hir_id || call.span.from_expansion() {
+        return false;
+    }
+    // `insert` and `remove` return `bool` for sets, instead of `Option<()>`.
+    let result_unused = matches!(
+        cx.tcx.parent_hir_node(call.hir_id),
+        Node::Stmt(hir::Stmt {
+            kind: StmtKind::Semi(_),
+            ..
+        })
+    );
+    match (method.ident.as_str(), args) {
+        ("insert", [key, value]) if result_unused && matches!(value.kind, ExprKind::Tup([])) => {
+            edits.push((key.span.shrink_to_hi().with_hi(value.span.hi()), String::new()));
+        },
+        ("remove", [_]) if result_unused => {},
+        ("contains_key", [_]) => edits.push((method.ident.span, "contains".to_string())),
+        ("keys", []) => edits.push((method.ident.span, "iter".to_string())),
+        ("into_keys", []) => edits.push((method.ident.span, "into_iter".to_string())),
+        ("len" | "is_empty" | "clear", []) => {},
+        _ => return false,
+    }
+    true
+}
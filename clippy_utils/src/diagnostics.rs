@@ -8,13 +8,23 @@
 //! Thank you!
 //! ~The `INTERNAL_METADATA_COLLECTOR` lint
 
-use rustc_errors::{Applicability, Diag, DiagMessage, MultiSpan, SubdiagMessage};
+use crate::is_in_test;
+use crate::source::{line_span, snippet_opt};
+use rustc_ast::ast;
+use rustc_ast::visit::{
+    AssocCtxt, Visitor, walk_arm, walk_assoc_item, walk_crate, walk_field_def, walk_item, walk_stmt, walk_variant,
+};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_errors::{Applicability, Diag, DiagMessage, Level, MultiSpan, SubdiagMessage, Suggestions};
 #[cfg(debug_assertions)]
-use rustc_errors::{EmissionGuarantee, SubstitutionPart, Suggestions};
-use rustc_hir::HirId;
+use rustc_errors::{EmissionGuarantee, SubstitutionPart};
+use rustc_hir::{HirId, ItemKind, Node};
 use rustc_lint::{LateContext, Lint, LintContext};
-use rustc_span::Span;
+use rustc_middle::ty::{TyCtxt, tls};
+use rustc_span::{Span, Symbol, sym};
+use std::collections::BTreeMap;
 use std::env;
+use std::sync::{Mutex, OnceLock};
 
 fn docs_link(diag: &mut Diag<'_, ()>, lint: &'static Lint) {
     if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
@@ -30,6 +40,512 @@ fn docs_link(diag: &mut Diag<'_, ()>, lint: &'static Lint) {
     }
 }
 
+/// Restricts which suggestions `cargo clippy --fix` is allowed to apply.
+///
+/// `cargo fix` only applies suggestions marked as [`Applicability::MachineApplicable`], so this
+/// works by adjusting the applicability of the emitted suggestions. It is set once by the driver
+/// from the `--fix-applicability` and `--fix-only` options.
+#[derive(Debug, Default)]
+pub struct FixFilter {
+    /// Also apply suggestions marked as [`Applicability::MaybeIncorrect`], unless the lint offers
+    /// several of them as alternatives.
+    pub apply_maybe_incorrect: bool,
+    /// If set, only suggestions of these lints are applied. The names are lowercase and include
+    /// the `clippy::` prefix.
+    pub only_lints: Option<FxHashSet<String>>,
+}
+
+static FIX_FILTER: OnceLock<FixFilter> = OnceLock::new();
+
+/// Sets the [`FixFilter`] for this session. Only the first call has an effect.
+pub fn set_fix_filter(filter: FixFilter) {
+    let _ = FIX_FILTER.set(filter);
+}
+
+fn apply_fix_filter(diag: &mut Diag<'_, ()>, lint: &'static Lint) {
+    let Some(filter) = FIX_FILTER.get() else {
+        return;
+    };
+    let Suggestions::Enabled(suggestions) = &mut diag.suggestions else {
+        return;
+    };
+
+    let fixable = filter
+        .only_lints
+        .as_ref()
+        .is_none_or(|lints| lints.contains(&lint.name_lower()));
+    // Several `MaybeIncorrect` suggestions are alternatives to each other, e.g. those added by
+    // `suggest_alternatives`, and `cargo fix` would apply all of them
+    let promote = fixable
+        && filter.apply_maybe_incorrect
+        && suggestions
+            .iter()
+            .filter(|sugg| sugg.applicability == Applicability::MaybeIncorrect)
+            .count()
+            == 1;
+    for suggestion in suggestions {
+        suggestion.applicability = match suggestion.applicability {
+            Applicability::MachineApplicable if !fixable => Applicability::MaybeIncorrect,
+            Applicability::MaybeIncorrect if promote => Applicability::MachineApplicable,
+            applicability => applicability,
+        };
+    }
+}
+
+static DENIED_IN_CI: OnceLock<FxHashSet<String>> = OnceLock::new();
+
+/// Sets the lints which were promoted to `deny` by `deny-in-ci` in `clippy.toml`. The names are
+/// lowercase and include the `clippy::` prefix. Only the first call has an effect.
+pub fn set_denied_in_ci(lints: impl IntoIterator<Item = String>) {
+    let _ = DENIED_IN_CI.set(lints.into_iter().collect());
+}
+
+fn deny_in_ci_note(diag: &mut Diag<'_, ()>, lint: &'static Lint) {
+    if diag.is_error()
+        && DENIED_IN_CI
+            .get()
+            .is_some_and(|lints| lints.contains(&lint.name_lower()))
+    {
+        diag.note("denied by `deny-in-ci` in `clippy.toml`");
+    }
+}
+
+static LINT_SUMMARIES: OnceLock<FxHashMap<String, (&'static str, Option<String>)>> = OnceLock::new();
+
+/// Sets the category of every lint and the first sentence of its "Why is this bad?" documentation,
+/// which are added as a note to the emitted lints if `verbose-diagnostics` is enabled in
+/// `clippy.toml`. The names are lowercase and include the `clippy::` prefix. Only the first call
+/// has an effect.
+pub fn set_lint_summaries(summaries: impl IntoIterator<Item = (String, (&'static str, Option<String>))>) {
+    let _ = LINT_SUMMARIES.set(summaries.into_iter().collect());
+}
+
+fn lint_summary_note(diag: &mut Diag<'_, ()>, lint: &'static Lint) {
+    let name = lint.name_lower();
+    if let Some(summaries) = LINT_SUMMARIES.get()
+        && let Some((category, summary)) = summaries.get(&name)
+    {
+        let mut note = format!("`{name}` is in the `{category}` group");
+        if let Some(summary) = summary {
+            note = format!("{note}: {summary}");
+        }
+        diag.note(note);
+    }
+}
+
+static HIDDEN_LINTS: OnceLock<FxHashSet<String>> = OnceLock::new();
+
+/// Sets the lints whose warnings are not shown, from the groups passed to `--hide`. The names are
+/// lowercase and include the `clippy::` prefix. Only the first call has an effect.
+///
+/// The lints are only hidden at the `warn` level, they are still emitted if they are denied.
+pub fn set_hidden_lints(lints: impl IntoIterator<Item = String>) {
+    let _ = HIDDEN_LINTS.set(lints.into_iter().collect());
+}
+
+/// Whether the lint is hidden and its level is `warn`, in which case it isn't emitted. Under
+/// `-D warnings` the level is `deny`, so hidden lints are emitted as errors like any other denied
+/// or force-warned lint. Hiding a group thus can't make a build pass.
+fn is_hidden(lint: &'static Lint, level: impl FnOnce() -> rustc_lint::Level) -> bool {
+    HIDDEN_LINTS
+        .get()
+        .is_some_and(|lints| lints.contains(&lint.name_lower()))
+        && level() == rustc_lint::Level::Warn
+}
+
+static ALLOWED_IN_TESTS: OnceLock<FxHashSet<String>> = OnceLock::new();
+
+/// Sets the lints which are not emitted in test functions or `#[cfg(test)]`, from
+/// `lints-allowed-in-tests` and the `allow-*-in-tests` options in `clippy.toml`. The names are
+/// lowercase and include the `clippy::` prefix. Only the first call has an effect.
+pub fn set_allowed_in_tests(lints: impl IntoIterator<Item = String>) {
+    let _ = ALLOWED_IN_TESTS.set(lints.into_iter().collect());
+}
+
+fn is_allowed_in_tests(lint: &'static Lint) -> bool {
+    ALLOWED_IN_TESTS
+        .get()
+        .is_some_and(|lints| lints.contains(&lint.name_lower()))
+}
+
+/// Whether the lint is allowed in tests and emitted at a node in a test.
+fn is_allowed_in_test_at(tcx: TyCtxt<'_>, lint: &'static Lint, hir_id: HirId) -> bool {
+    is_allowed_in_tests(lint) && is_in_test(tcx, hir_id)
+}
+
+/// Whether the lint is allowed in tests and emitted at a span in a test.
+///
+/// The generic `span_lint*` functions don't have the node the lint is emitted at, so the span of
+/// the lint is looked up in the [`NodeSpan`]s instead. Lints emitted before they are collected,
+/// i.e. by pre-expansion passes, are never allowed.
+fn is_allowed_in_test_containing(lint: &'static Lint, sp: &MultiSpan) -> bool {
+    is_allowed_in_tests(lint)
+        && sp
+            .primary_span()
+            .and_then(|span| node_containing(span.source_callsite()))
+            .is_some_and(|node| node.in_test)
+}
+
+/// The number of times a lint was emitted, and how many of them have a suggestion that
+/// `cargo clippy --fix` applies.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EmissionCount {
+    pub total: usize,
+    pub fixable: usize,
+}
+
+static EMISSIONS: OnceLock<Mutex<BTreeMap<String, EmissionCount>>> = OnceLock::new();
+
+/// Starts counting the emitted lints for the summary of `cargo clippy --summary`. It is set by the
+/// driver, the counts are retrieved with [`take_emissions`].
+pub fn record_emissions() {
+    let _ = EMISSIONS.set(Mutex::default());
+}
+
+/// Returns the lints emitted since the last call, keyed by their lowercase name including the
+/// `clippy::` prefix. Empty unless [`record_emissions`] was called.
+pub fn take_emissions() -> BTreeMap<String, EmissionCount> {
+    EMISSIONS
+        .get()
+        .map(|emissions| std::mem::take(&mut *emissions.lock().unwrap()))
+        .unwrap_or_default()
+}
+
+fn record_emission(diag: &Diag<'_, ()>, lint: &'static Lint) {
+    if let Some(emissions) = EMISSIONS.get()
+        // Fulfilled expectations are not shown to the user
+        && !matches!(diag.level(), Level::Allow | Level::Expect(_))
+    {
+        let mut emissions = emissions.lock().unwrap();
+        let count = emissions.entry(lint.name_lower()).or_default();
+        count.total += 1;
+        count.fixable += usize::from(is_fixable(diag));
+    }
+}
+
+/// Whether `diag` has a suggestion that `cargo clippy --fix` applies.
+fn is_fixable(diag: &Diag<'_, ()>) -> bool {
+    if let Suggestions::Enabled(suggestions) = &diag.suggestions {
+        suggestions
+            .iter()
+            .any(|sugg| sugg.applicability == Applicability::MachineApplicable)
+    } else {
+        false
+    }
+}
+
+static TAG_ALTERNATIVES: OnceLock<()> = OnceLock::new();
+
+/// Tags every alternative added by [`suggest_alternatives`] with its stable ID for this session.
+/// It is set by the driver from the `--suggestion-alternatives` option.
+pub fn set_tag_suggestion_alternatives() {
+    let _ = TAG_ALTERNATIVES.set(());
+}
+
+/// A candidate rewrite, one of several that are offered for the same span.
+#[derive(Debug)]
+pub struct SuggestionAlternative {
+    /// Identifies the alternative among the alternatives of a lint. Together with the lint name
+    /// it forms the stable ID `clippy::<lint>::<id>` IDEs can use to tell the alternatives apart.
+    pub id: &'static str,
+    pub help: String,
+    pub sugg: String,
+}
+
+impl SuggestionAlternative {
+    #[must_use]
+    pub fn new(id: &'static str, help: impl Into<String>, sugg: impl Into<String>) -> Self {
+        Self {
+            id,
+            help: help.into(),
+            sugg: sugg.into(),
+        }
+    }
+}
+
+/// Adds one suggestion for each of the given alternatives, in order of preference.
+///
+/// Every alternative becomes its own child of the diagnostic, so IDEs can present a choice. If
+/// `--suggestion-alternatives` is passed to the driver, the help message of each alternative ends
+/// with its stable ID in brackets, e.g. `[clippy::octal_escapes::hex]`.
+///
+/// Only one of the alternatives can be right, so `applicability` must not be
+/// [`Applicability::MachineApplicable`]. `--fix-applicability=maybe-incorrect` doesn't apply them
+/// either.
+pub fn suggest_alternatives(
+    diag: &mut Diag<'_, ()>,
+    lint: &'static Lint,
+    sp: Span,
+    alternatives: impl IntoIterator<Item = SuggestionAlternative>,
+    applicability: Applicability,
+) {
+    debug_assert_ne!(applicability, Applicability::MachineApplicable);
+
+    let tag = TAG_ALTERNATIVES.get().is_some();
+    for SuggestionAlternative { id, help, sugg } in alternatives {
+        let help = if tag {
+            format!("{help} [{}::{id}]", lint.name_lower())
+        } else {
+            help
+        };
+        diag.span_suggestion(sp, help, sugg, applicability);
+    }
+}
+
+static SUGGEST_ALLOWS: OnceLock<()> = OnceLock::new();
+
+/// Makes every emitted lint suggest an `#[allow]` attribute for itself, so that the warnings of a
+/// crate can be silenced in bulk. It is set by the driver if the `CLIPPY_SUGGEST_ALLOWS`
+/// environment variable is `1`.
+pub fn set_suggest_allows() {
+    let _ = SUGGEST_ALLOWS.set(());
+}
+
+static LATE_PASSES: OnceLock<()> = OnceLock::new();
+
+/// Marks the start of the late lint passes. The early passes run while the HIR is lowered, so the
+/// lints emitted before can't be looked up in the HIR, e.g. to suggest an `#[allow]` attribute.
+pub fn enter_late_passes() {
+    let _ = LATE_PASSES.set(());
+}
+
+/// Suggests an `#[allow]` attribute for the lint on the innermost item, statement, field, variant
+/// or match arm which isn't in a macro expansion. It contains `node` if the lint is emitted at a
+/// given node, otherwise the primary span of the lint.
+///
+/// Lints with a fix that `cargo clippy --fix` applies get no suggestion, as it would apply both.
+/// Lints emitted by early passes get no suggestion either.
+fn suggest_allow(diag: &mut Diag<'_, ()>, lint: &'static Lint, node: Option<(TyCtxt<'_>, HirId)>) {
+    if SUGGEST_ALLOWS.get().is_none() || matches!(diag.level(), Level::Allow | Level::Expect(_)) || is_fixable(diag) {
+        return;
+    }
+
+    let sugg = if let Some((tcx, hir_id)) = node {
+        allow_target(tcx, hir_id).map(|span| allow_attr(tcx, lint, span))
+    } else if LATE_PASSES.get().is_some()
+        && let Some(span) = diag.span.primary_span()
+        && let Some(node) = node_containing(span.source_callsite())
+    {
+        // The generic `span_lint*` functions only have a `LintContext`
+        tls::with_opt(|tcx| tcx.map(|tcx| allow_attr(tcx, lint, node.span)))
+    } else {
+        None
+    };
+    if let Some((span, sugg)) = sugg {
+        diag.span_suggestion_verbose(span, "allow the lint here", sugg, Applicability::MachineApplicable);
+    }
+}
+
+/// Returns the span of the node an `#[allow]` attribute is put on, for a lint emitted at `hir_id`.
+fn allow_target(tcx: TyCtxt<'_>, hir_id: HirId) -> Option<Span> {
+    let nodes = std::iter::once(tcx.hir_node(hir_id)).chain(tcx.hir().parent_iter(hir_id).map(|(_, node)| node));
+    for node in nodes {
+        let span = match node {
+            // The span of an out-of-line module is the contents of its file
+            Node::Item(item) if matches!(item.kind, ItemKind::Mod(..)) => return None,
+            Node::Item(item) => item.span,
+            Node::TraitItem(item) => item.span,
+            Node::ImplItem(item) => item.span,
+            Node::ForeignItem(item) => item.span,
+            Node::Stmt(stmt) => stmt.span,
+            Node::Field(field) => field.span,
+            Node::Variant(variant) => variant.span,
+            Node::Arm(arm) => arm.span,
+            Node::Crate(_) => return None,
+            _ => continue,
+        };
+        // The attribute has to be added outside of the macro
+        if !span.from_expansion() {
+            return Some(span);
+        }
+    }
+    None
+}
+
+/// A node an `#[allow]` attribute can be put on, i.e. an item, statement, field, variant or match
+/// arm which isn't in a macro expansion.
+#[derive(Debug)]
+struct NodeSpan {
+    span: Span,
+    /// Whether the node is in a `#[test]` function or in an item marked `#[cfg(test)]`.
+    in_test: bool,
+}
+
+static NODE_SPANS: OnceLock<Vec<NodeSpan>> = OnceLock::new();
+
+/// Collects the nodes which are looked up by the generic `span_lint*` functions, as they don't
+/// have the node the lint is emitted at. It is called by the first early lint pass, so that the
+/// early passes can look up their lints as well. Only the first call has an effect, and only if
+/// lints are allowed in tests or `#[allow]` attributes are suggested.
+pub fn collect_node_spans(krate: &ast::Crate) {
+    if SUGGEST_ALLOWS.get().is_none() && ALLOWED_IN_TESTS.get().is_none_or(FxHashSet::is_empty) {
+        return;
+    }
+
+    let mut v = NodeSpanCollector {
+        nodes: Vec::new(),
+        in_test: false,
+        test_fns: Vec::new(),
+    };
+    v.visit_crate(krate);
+    let _ = NODE_SPANS.set(v.nodes);
+}
+
+/// Returns the innermost node containing `span`.
+fn node_containing(span: Span) -> Option<&'static NodeSpan> {
+    NODE_SPANS
+        .get()?
+        .iter()
+        .filter(|node| node.span.contains(span))
+        .min_by_key(|node| node.span.hi() - node.span.lo())
+}
+
+struct NodeSpanCollector {
+    nodes: Vec<NodeSpan>,
+    in_test: bool,
+    /// The names of the `#[test]` functions among the items which are visited.
+    test_fns: Vec<Symbol>,
+}
+
+impl NodeSpanCollector {
+    /// Adds the node at `span` unless it's in a macro expansion or not given, and visits its
+    /// contents with `walk`. The node and its contents are in a test if `is_test` is set.
+    fn add(&mut self, span: Option<Span>, is_test: bool, walk: impl FnOnce(&mut Self)) {
+        let outer = self.in_test;
+        self.in_test |= is_test;
+        if let Some(span) = span
+            && !span.from_expansion()
+        {
+            self.nodes.push(NodeSpan {
+                span,
+                in_test: self.in_test,
+            });
+        }
+        walk(self);
+        self.in_test = outer;
+    }
+
+    /// Visits the contents of a module or block with the names of its `#[test]` functions.
+    fn with_items<'a>(&mut self, items: impl Iterator<Item = &'a ast::Item>, walk: impl FnOnce(&mut Self)) {
+        // `#[test]` expands to a constant with the name of the function next to it
+        let test_fns = items
+            .filter(|item| {
+                matches!(item.kind, ast::ItemKind::Const(..))
+                    && item.attrs.iter().any(|attr| attr.has_name(sym::rustc_test_marker))
+            })
+            .map(|item| item.ident.name)
+            .collect();
+        let test_fns = std::mem::replace(&mut self.test_fns, test_fns);
+        walk(self);
+        self.test_fns = test_fns;
+    }
+}
+
+/// Checks if the attributes contain `#[cfg(test)]`.
+fn has_cfg_test(attrs: &[ast::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.has_name(sym::cfg)
+            && attr
+                .meta_item_list()
+                .is_some_and(|items| matches!(&*items, [item] if item.has_name(sym::test)))
+    })
+}
+
+impl<'a> Visitor<'a> for NodeSpanCollector {
+    fn visit_crate(&mut self, krate: &'a ast::Crate) {
+        self.with_items(krate.items.iter().map(|item| &**item), |v| walk_crate(v, krate));
+    }
+
+    fn visit_item(&mut self, item: &'a ast::Item) {
+        let is_test = has_cfg_test(&item.attrs)
+            || matches!(item.kind, ast::ItemKind::Fn(..)) && self.test_fns.contains(&item.ident.name);
+        // The span of an out-of-line module is the contents of its file
+        let span = (!matches!(item.kind, ast::ItemKind::Mod(..))).then_some(item.span);
+        self.add(span, is_test, |v| {
+            if let ast::ItemKind::Mod(_, ast::ModKind::Loaded(items, ..)) = &item.kind {
+                v.with_items(items.iter().map(|item| &**item), |v| walk_item(v, item));
+            } else {
+                walk_item(v, item);
+            }
+        });
+    }
+
+    fn visit_assoc_item(&mut self, item: &'a ast::AssocItem, ctxt: AssocCtxt) {
+        self.add(Some(item.span), has_cfg_test(&item.attrs), |v| {
+            walk_assoc_item(v, item, ctxt);
+        });
+    }
+
+    fn visit_foreign_item(&mut self, item: &'a ast::ForeignItem) {
+        self.add(Some(item.span), has_cfg_test(&item.attrs), |v| walk_item(v, item));
+    }
+
+    fn visit_block(&mut self, block: &'a ast::Block) {
+        let items = block.stmts.iter().filter_map(|stmt| match &stmt.kind {
+            ast::StmtKind::Item(item) => Some(&**item),
+            _ => None,
+        });
+        self.with_items(items, |v| {
+            for (i, stmt) in block.stmts.iter().enumerate() {
+                // The trailing expression of a block isn't a statement, and an attribute on it
+                // would be one on an expression
+                if i + 1 == block.stmts.len() && matches!(stmt.kind, ast::StmtKind::Expr(..)) {
+                    walk_stmt(v, stmt);
+                } else {
+                    v.visit_stmt(stmt);
+                }
+            }
+        });
+    }
+
+    fn visit_stmt(&mut self, stmt: &'a ast::Stmt) {
+        self.add(Some(stmt.span), false, |v| walk_stmt(v, stmt));
+    }
+
+    fn visit_field_def(&mut self, field: &'a ast::FieldDef) {
+        self.add(Some(field.span), has_cfg_test(&field.attrs), |v| {
+            walk_field_def(v, field);
+        });
+    }
+
+    fn visit_variant(&mut self, variant: &'a ast::Variant) {
+        self.add(Some(variant.span), has_cfg_test(&variant.attrs), |v| {
+            walk_variant(v, variant);
+        });
+    }
+
+    fn visit_arm(&mut self, arm: &'a ast::Arm) {
+        self.add(Some(arm.span), has_cfg_test(&arm.attrs), |v| walk_arm(v, arm));
+    }
+}
+
+/// Creates the insertion of an `#[allow]` attribute for the lint before the node at `span`.
+fn allow_attr(tcx: TyCtxt<'_>, lint: &'static Lint, span: Span) -> (Span, String) {
+    // Put the attribute on its own line if nothing precedes the node on its line
+    let line_start = line_span(&tcx, span.shrink_to_lo());
+    match snippet_opt(&tcx, line_start) {
+        Some(indent) if indent.trim().is_empty() => (
+            line_start.shrink_to_lo(),
+            format!("{indent}#[allow({})]\n", lint.name_lower()),
+        ),
+        _ => (span.shrink_to_lo(), format!("#[allow({})] ", lint.name_lower())),
+    }
+}
+
+/// Adds the parts shared by all lints to `diag`, once the lint added its own. `node` is the node
+/// the lint is emitted at, if it's given.
+fn finish_diag(diag: &mut Diag<'_, ()>, lint: &'static Lint, node: Option<(TyCtxt<'_>, HirId)>) {
+    docs_link(diag, lint);
+    deny_in_ci_note(diag, lint);
+    lint_summary_note(diag, lint);
+    suggest_allow(diag, lint, node);
+    apply_fix_filter(diag, lint);
+    record_emission(diag, lint);
+
+    #[cfg(debug_assertions)]
+    validate_diag(diag);
+}
+
 /// Makes sure that a diagnostic is well formed.
 ///
 /// rustc debug asserts a few properties about spans,
@@ -99,13 +615,14 @@ fn validate_diag(diag: &Diag<'_, impl EmissionGuarantee>) {
 ///    |     ^^^^^^^^^^^^^^^^^^^^^^^
 /// ```
 pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<MultiSpan>, msg: impl Into<DiagMessage>) {
+    let sp = sp.into();
+    if is_hidden(lint, || cx.get_lint_level(lint)) || is_allowed_in_test_containing(lint, &sp) {
+        return;
+    }
     #[expect(clippy::disallowed_methods)]
     cx.span_lint(lint, sp, |diag| {
         diag.primary_message(msg);
-        docs_link(diag, lint);
-
-        #[cfg(debug_assertions)]
-        validate_diag(diag);
+        finish_diag(diag, lint, None);
     });
 }
 
@@ -151,6 +668,10 @@ pub fn span_lint_and_help<T: LintContext>(
     help_span: Option<Span>,
     help: impl Into<SubdiagMessage>,
 ) {
+    let span = span.into();
+    if is_hidden(lint, || cx.get_lint_level(lint)) || is_allowed_in_test_containing(lint, &span) {
+        return;
+    }
     #[expect(clippy::disallowed_methods)]
     cx.span_lint(lint, span, |diag| {
         diag.primary_message(msg);
@@ -159,10 +680,7 @@ pub fn span_lint_and_help<T: LintContext>(
         } else {
             diag.help(help.into());
         }
-        docs_link(diag, lint);
-
-        #[cfg(debug_assertions)]
-        validate_diag(diag);
+        finish_diag(diag, lint, None);
     });
 }
 
@@ -211,6 +729,10 @@ pub fn span_lint_and_note<T: LintContext>(
     note_span: Option<Span>,
     note: impl Into<SubdiagMessage>,
 ) {
+    let span = span.into();
+    if is_hidden(lint, || cx.get_lint_level(lint)) || is_allowed_in_test_containing(lint, &span) {
+        return;
+    }
     #[expect(clippy::disallowed_methods)]
     cx.span_lint(lint, span, |diag| {
         diag.primary_message(msg);
@@ -219,10 +741,7 @@ pub fn span_lint_and_note<T: LintContext>(
         } else {
             diag.note(note.into());
         }
-        docs_link(diag, lint);
-
-        #[cfg(debug_assertions)]
-        validate_diag(diag);
+        finish_diag(diag, lint, None);
     });
 }
 
@@ -251,14 +770,15 @@ where
     M: Into<DiagMessage>,
     F: FnOnce(&mut Diag<'_, ()>),
 {
+    let sp = sp.into();
+    if is_hidden(lint, || cx.get_lint_level(lint)) || is_allowed_in_test_containing(lint, &sp) {
+        return;
+    }
     #[expect(clippy::disallowed_methods)]
     cx.span_lint(lint, sp, |diag| {
         diag.primary_message(msg);
         f(diag);
-        docs_link(diag, lint);
-
-        #[cfg(debug_assertions)]
-        validate_diag(diag);
+        finish_diag(diag, lint, None);
     });
 }
 
@@ -287,13 +807,13 @@ where
 /// the compiler check lint level attributes at the place of the expression and
 /// the `#[allow]` will work.
 pub fn span_lint_hir(cx: &LateContext<'_>, lint: &'static Lint, hir_id: HirId, sp: Span, msg: impl Into<DiagMessage>) {
+    if is_hidden(lint, || cx.tcx.lint_level_at_node(lint, hir_id).0) || is_allowed_in_test_at(cx.tcx, lint, hir_id) {
+        return;
+    }
     #[expect(clippy::disallowed_methods)]
     cx.tcx.node_span_lint(lint, hir_id, sp, |diag| {
         diag.primary_message(msg);
-        docs_link(diag, lint);
-
-        #[cfg(debug_assertions)]
-        validate_diag(diag);
+        finish_diag(diag, lint, Some((cx.tcx, hir_id)));
     });
 }
 
@@ -329,14 +849,14 @@ pub fn span_lint_hir_and_then(
     msg: impl Into<DiagMessage>,
     f: impl FnOnce(&mut Diag<'_, ()>),
 ) {
+    if is_hidden(lint, || cx.tcx.lint_level_at_node(lint, hir_id).0) || is_allowed_in_test_at(cx.tcx, lint, hir_id) {
+        return;
+    }
     #[expect(clippy::disallowed_methods)]
     cx.tcx.node_span_lint(lint, hir_id, sp, |diag| {
         diag.primary_message(msg);
         f(diag);
-        docs_link(diag, lint);
-
-        #[cfg(debug_assertions)]
-        validate_diag(diag);
+        finish_diag(diag, lint, Some((cx.tcx, hir_id)));
     });
 }
 
@@ -390,3 +910,18 @@ pub fn span_lint_and_sugg<T: LintContext>(
         validate_diag(diag);
     });
 }
+
+/// Like [`span_lint_and_sugg`], but offers several alternative rewrites of `sp`, see
+/// [`suggest_alternatives`].
+pub fn span_lint_and_sugg_alternatives<T: LintContext>(
+    cx: &T,
+    lint: &'static Lint,
+    sp: Span,
+    msg: impl Into<DiagMessage>,
+    alternatives: impl IntoIterator<Item = SuggestionAlternative>,
+    applicability: Applicability,
+) {
+    span_lint_and_then(cx, lint, sp, msg.into(), |diag| {
+        suggest_alternatives(diag, lint, sp, alternatives, applicability);
+    });
+}
@@ -0,0 +1,93 @@
+//! Classifies the side effects of an expression, for lints which move code into a closure or change
+//! when it's evaluated.
+//!
+//! See lints:
+//!  - option-if-let-else
+
+use crate::is_diag_trait_item;
+use crate::visitors::for_each_expr_without_closures;
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, MatchSource};
+use rustc_lint::LateContext;
+use rustc_span::{Symbol, sym};
+
+/// The side effects an expression may have.
+#[expect(clippy::struct_excessive_bools)] // The effects are independent of each other.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Effects {
+    /// Calls a non-`const` function of `alloc` returning a value which owns memory, such as
+    /// `Box::new`, `vec!` or `to_string`, or clones such a value.
+    pub allocation: bool,
+    /// Calls a function of `std::io`, `std::fs`, `std::net`, `std::process` or `std::env`, e.g.
+    /// through `println!`.
+    pub io: bool,
+    /// Contains an `.await` or a `yield`.
+    pub await_point: bool,
+    /// Leaves the expression through `return`, `?`, or a `break` or `continue` to an outer loop.
+    pub early_return: bool,
+}
+
+impl Effects {
+    /// Whether the expression has none of the side effects.
+    pub fn is_empty(self) -> bool {
+        self == Self::default()
+    }
+}
+
+/// Classifies the side effects of the expression. The bodies of closures and async blocks aren't
+/// taken into account, as they aren't evaluated along with the expression.
+pub fn expr_effects<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Effects {
+    let mut effects = Effects::default();
+    // The expressions a `break` or `continue` can target without leaving `expr`.
+    let mut inner = FxHashSet::default();
+    for_each_expr_without_closures(expr, |e| {
+        inner.insert(e.hir_id);
+        match e.kind {
+            ExprKind::Call(callee, _) => {
+                if let ExprKind::Path(ref qpath) = callee.kind
+                    && let Res::Def(DefKind::Fn | DefKind::AssocFn, id) = cx.qpath_res(qpath, callee.hir_id)
+                {
+                    call_effects(cx, e, id, &mut effects);
+                }
+            },
+            ExprKind::MethodCall(..) => {
+                if let Some(id) = cx.typeck_results().type_dependent_def_id(e.hir_id) {
+                    call_effects(cx, e, id, &mut effects);
+                }
+            },
+            ExprKind::Match(_, _, MatchSource::AwaitDesugar) | ExprKind::Yield(..) => effects.await_point = true,
+            ExprKind::Match(_, _, MatchSource::TryDesugar(_)) | ExprKind::Ret(_) | ExprKind::Become(_) => {
+                effects.early_return = true;
+            },
+            ExprKind::Break(dest, _) | ExprKind::Continue(dest) => {
+                if !dest.target_id.is_ok_and(|id| inner.contains(&id)) {
+                    effects.early_return = true;
+                }
+            },
+            _ => {},
+        }
+        ControlFlow::<!, ()>::Continue(())
+    });
+    effects
+}
+
+fn call_effects(cx: &LateContext<'_>, call: &Expr<'_>, callee: DefId, effects: &mut Effects) {
+    let path = cx.get_def_path(callee);
+    if path.first() == Some(&sym::std)
+        && matches!(
+            path.get(1).map(Symbol::as_str),
+            Some("io" | "fs" | "net" | "process" | "env")
+        )
+    {
+        effects.io = true;
+    }
+    if ((cx.tcx.crate_name(callee.krate) == sym::alloc && !cx.tcx.is_const_fn(callee))
+        || is_diag_trait_item(cx, callee, sym::Clone))
+        && cx.typeck_results().expr_ty(call).needs_drop(cx.tcx, cx.typing_env())
+    {
+        effects.allocation = true;
+    }
+}
@@ -62,6 +62,7 @@ pub mod comparisons;
 pub mod consts;
 pub mod diagnostics;
 pub mod eager_or_lazy;
+pub mod effects;
 pub mod higher;
 mod hir_utils;
 pub mod macros;
@@ -71,6 +72,7 @@ pub mod numeric_literal;
 pub mod paths;
 pub mod ptr;
 pub mod qualify_min_const_fn;
+pub mod rinterval;
 pub mod source;
 pub mod str_utils;
 pub mod sugg;
@@ -1362,6 +1364,18 @@ pub fn contains_name<'tcx>(name: Symbol, expr: &'tcx Expr<'_>, cx: &LateContext<
     cn.visit_expr(expr).is_break()
 }
 
+/// Returns `base`, or the first of `base_2`, `base_3`, ... if it's already used in `expr`. A
+/// binding of the returned name inserted into `expr` doesn't shadow any name used there.
+pub fn unused_name<'tcx>(base: &str, expr: &'tcx Expr<'_>, cx: &LateContext<'tcx>) -> Symbol {
+    let mut name = Symbol::intern(base);
+    let mut n = 1;
+    while contains_name(name, expr, cx) {
+        n += 1;
+        name = Symbol::intern(&format!("{base}_{n}"));
+    }
+    name
+}
+
 /// Returns `true` if `expr` contains a return expression
 pub fn contains_return<'tcx>(expr: impl Visitable<'tcx>) -> bool {
     for_each_expr_without_closures(expr, |e| {
@@ -34,7 +34,12 @@ pub const CHILD_ID: [&str; 4] = ["std", "process", "Child", "id"];
 pub const CHILD_KILL: [&str; 4] = ["std", "process", "Child", "kill"];
 pub const PANIC_ANY: [&str; 3] = ["std", "panic", "panic_any"];
 pub const CHAR_IS_ASCII: [&str; 5] = ["core", "char", "methods", "<impl char>", "is_ascii"];
+pub const FUTURE_READY: [&str; 4] = ["core", "future", "ready", "Ready"];
 pub const STDIN: [&str; 4] = ["std", "io", "stdio", "Stdin"];
+pub const SYSTEM_TIME: [&str; 3] = ["std", "time", "SystemTime"];
+pub const THREAD_SPAWN: [&str; 3] = ["std", "thread", "spawn"];
+pub const ONCE_CELL: [&str; 4] = ["core", "cell", "once", "OnceCell"];
+pub const ONCE_LOCK: [&str; 4] = ["std", "sync", "once_lock", "OnceLock"];
 
 // Paths in clippy itself
 pub const MSRV: [&str; 3] = ["clippy_utils", "msrvs", "Msrv"];
@@ -44,6 +49,8 @@ pub const MSRV: [&str; 3] = ["clippy_utils", "msrvs", "Msrv"];
 pub const FUTURES_IO_ASYNCREADEXT: [&str; 3] = ["futures_util", "io", "AsyncReadExt"];
 #[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
 pub const FUTURES_IO_ASYNCWRITEEXT: [&str; 3] = ["futures_util", "io", "AsyncWriteExt"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const FUTURES_READY: [&str; 4] = ["futures_util", "future", "ready", "Ready"];
 pub const ITERTOOLS_NEXT_TUPLE: [&str; 3] = ["itertools", "Itertools", "next_tuple"];
 pub const PARKING_LOT_MUTEX_GUARD: [&str; 3] = ["lock_api", "mutex", "MutexGuard"];
 pub const PARKING_LOT_RWLOCK_READ_GUARD: [&str; 3] = ["lock_api", "rwlock", "RwLockReadGuard"];
@@ -66,3 +73,5 @@ pub const TOKIO_IO_ASYNCWRITEEXT: [&str; 5] = ["tokio", "io", "util", "async_wri
 pub const TOKIO_IO_OPEN_OPTIONS: [&str; 4] = ["tokio", "fs", "open_options", "OpenOptions"];
 #[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
 pub const TOKIO_IO_OPEN_OPTIONS_NEW: [&str; 5] = ["tokio", "fs", "open_options", "OpenOptions", "new"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const TOKIO_SELECT: [&str; 4] = ["tokio", "macros", "select", "select"];
@@ -29,13 +29,14 @@ pub fn is_min_const_fn<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>, msrv: &Msrv)
     let def_id = body.source.def_id();
 
     for local in &body.local_decls {
-        check_ty(tcx, local.ty, local.source_info.span)?;
+        check_ty(tcx, local.ty, local.source_info.span, msrv)?;
     }
     // impl trait is gone in MIR, so check the return type manually
     check_ty(
         tcx,
         tcx.fn_sig(def_id).instantiate_identity().output().skip_binder(),
         body.local_decls.iter().next().unwrap().source_info.span,
+        msrv,
     )?;
 
     for bb in &*body.basic_blocks {
@@ -51,7 +52,7 @@ pub fn is_min_const_fn<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>, msrv: &Msrv)
     Ok(())
 }
 
-fn check_ty<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>, span: Span) -> McfResult {
+fn check_ty<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>, span: Span, msrv: &Msrv) -> McfResult {
     for arg in ty.walk() {
         let ty = match arg.unpack() {
             GenericArgKind::Type(ty) => ty,
@@ -62,14 +63,18 @@ fn check_ty<'tcx>(tcx: TyCtxt<'tcx>, ty: Ty<'tcx>, span: Span) -> McfResult {
         };
 
         match ty.kind() {
-            ty::Ref(_, _, hir::Mutability::Mut) => {
+            ty::Ref(_, _, hir::Mutability::Mut) if !msrv.meets(msrvs::CONST_MUT_REFS) => {
                 return Err((span, "mutable references in const fn are unstable".into()));
             },
-            ty::Alias(ty::Opaque, ..) => return Err((span, "`impl Trait` in const fn is unstable".into())),
-            ty::FnPtr(..) => {
+            ty::Alias(ty::Opaque, ..) if !msrv.meets(msrvs::CONST_FN_TRAIT_BOUND) => {
+                return Err((span, "`impl Trait` in const fn is unstable".into()));
+            },
+            // `async` blocks and the bodies of `async fn`s
+            ty::Coroutine(..) => return Err((span, "coroutines are unstable in const fn".into())),
+            ty::FnPtr(..) if !msrv.meets(msrvs::CONST_FN_FN_PTR_BASICS) => {
                 return Err((span, "function pointers in const fn are unstable".into()));
             },
-            ty::Dynamic(preds, _, _) => {
+            ty::Dynamic(preds, _, _) if !msrv.meets(msrvs::CONST_FN_TRAIT_BOUND) => {
                 for pred in *preds {
                     match pred.skip_binder() {
                         ty::ExistentialPredicate::AutoTrait(_) | ty::ExistentialPredicate::Projection(_) => {
@@ -134,9 +139,15 @@ fn check_rvalue<'tcx>(
                 | PointerCoercion::ReifyFnPointer,
                 _,
             ),
+            operand,
             _,
-            _,
-        ) => Err((span, "function pointer casts are not allowed in const fn".into())),
+        ) => {
+            if msrv.meets(msrvs::CONST_FN_FN_PTR_BASICS) {
+                check_operand(tcx, operand, span, body, msrv)
+            } else {
+                Err((span, "function pointer casts are not allowed in const fn".into()))
+            }
+        },
         Rvalue::Cast(CastKind::PointerCoercion(PointerCoercion::Unsize, _), op, cast_ty) => {
             let Some(pointee_ty) = cast_ty.builtin_deref(true) else {
                 // We cannot allow this for now.
@@ -147,6 +158,10 @@ fn check_rvalue<'tcx>(
                 check_operand(tcx, op, span, body, msrv)?;
                 // Casting/coercing things to slices is fine.
                 Ok(())
+            } else if let ty::Dynamic(..) = unsized_ty.kind()
+                && msrv.meets(msrvs::CONST_FN_TRAIT_BOUND)
+            {
+                check_operand(tcx, op, span, body, msrv)
             } else {
                 // We just can't allow trait objects until we have figured out trait method calls.
                 Err((span, "unsizing casts are not allowed in const fn".into()))
@@ -168,7 +183,11 @@ fn check_rvalue<'tcx>(
             check_operand(tcx, lhs, span, body, msrv)?;
             check_operand(tcx, rhs, span, body, msrv)?;
             let ty = lhs.ty(body, tcx);
-            if ty.is_integral() || ty.is_bool() || ty.is_char() {
+            if ty.is_integral()
+                || ty.is_bool()
+                || ty.is_char()
+                || (ty.is_floating_point() && msrv.meets(msrvs::CONST_FN_FLOATING_POINT_ARITHMETIC))
+            {
                 Ok(())
             } else {
                 Err((
@@ -181,7 +200,10 @@ fn check_rvalue<'tcx>(
         | Rvalue::ShallowInitBox(_, _) => Ok(()),
         Rvalue::UnaryOp(_, operand) => {
             let ty = operand.ty(body, tcx);
-            if ty.is_integral() || ty.is_bool() {
+            if ty.is_integral()
+                || ty.is_bool()
+                || (ty.is_floating_point() && msrv.meets(msrvs::CONST_FN_FLOATING_POINT_ARITHMETIC))
+            {
                 check_operand(tcx, operand, span, body, msrv)
             } else {
                 Err((span, "only int and `bool` operations are stable in const fn".into()))
@@ -275,7 +297,7 @@ fn check_place<'tcx>(tcx: TyCtxt<'tcx>, place: Place<'tcx>, span: Span, body: &B
                 }
             },
             ProjectionElem::Deref => match base.ty(body, tcx).ty.kind() {
-                ty::RawPtr(_, hir::Mutability::Mut) => {
+                ty::RawPtr(_, hir::Mutability::Mut) if !msrv.meets(msrvs::CONST_MUT_REFS) => {
                     return Err((span, "dereferencing raw mut pointer in const fn is unstable".into()));
                 },
                 ty::RawPtr(_, hir::Mutability::Not) if !msrv.meets(msrvs::CONST_RAW_PTR_DEREF) => {
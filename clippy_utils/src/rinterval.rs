@@ -0,0 +1,195 @@
+//! Range analysis for integer expressions.
+//!
+//! This computes a conservative interval containing every value an integer expression can take,
+//! based on constants, casts and operations which restrict the range of their result (e.g.
+//! `x % 100` or `x.min(255)`). Anything which isn't understood is assumed to take any value of its
+//! type.
+
+use crate::consts::{ConstEvalCtxt, Constant};
+use crate::{expr_or_init, int_bits, sext};
+use rustc_hir::{BinOpKind, Expr, ExprKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty::layout::IntegerExt;
+use rustc_middle::ty::{self, Ty, UintTy};
+use rustc_target::abi::Integer;
+use std::fmt;
+
+/// An inclusive range of integer values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Interval {
+    pub lo: i128,
+    pub hi: i128,
+}
+
+impl Interval {
+    pub fn new(lo: i128, hi: i128) -> Self {
+        debug_assert!(lo <= hi);
+        Self { lo, hi }
+    }
+
+    pub fn single(value: i128) -> Self {
+        Self { lo: value, hi: value }
+    }
+
+    /// Whether every value in `self` is also in `other`.
+    pub fn is_subset_of(self, other: Self) -> bool {
+        other.lo <= self.lo && self.hi <= other.hi
+    }
+
+    /// Computes the range of the products of values in `self` and `other`. Returns `None` if it
+    /// can't be represented.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let products = [
+            self.lo.checked_mul(other.lo)?,
+            self.lo.checked_mul(other.hi)?,
+            self.hi.checked_mul(other.lo)?,
+            self.hi.checked_mul(other.hi)?,
+        ];
+        Some(Self::new(*products.iter().min()?, *products.iter().max()?))
+    }
+
+    fn non_negative(self) -> bool {
+        self.lo >= 0
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.lo == self.hi {
+            write!(f, "{}", self.lo)
+        } else {
+            write!(f, "{}..={}", self.lo, self.hi)
+        }
+    }
+}
+
+/// Returns the range of all values of the integer type `ty`. `u128` is not supported as its upper
+/// bound can't be represented.
+pub fn type_range(cx: &LateContext<'_>, ty: Ty<'_>) -> Option<Interval> {
+    match *ty.kind() {
+        ty::Int(ity) => {
+            let shift = 128 - int_bits(cx.tcx, ity);
+            Some(Interval::new(i128::MIN >> shift, i128::MAX >> shift))
+        },
+        ty::Uint(UintTy::U128) => None,
+        ty::Uint(uty) => {
+            let bits = Integer::from_uint_ty(&cx.tcx, uty).size().bits();
+            Some(Interval::new(0, (1 << bits) - 1))
+        },
+        _ => None,
+    }
+}
+
+/// The context required to compute the range of an integer expression.
+pub struct IntervalCtxt<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    ecx: ConstEvalCtxt<'tcx>,
+}
+
+impl<'a, 'tcx> IntervalCtxt<'a, 'tcx> {
+    /// Creates the context from the lint context. This requires the lint context to be in a body.
+    pub fn new(cx: &'a LateContext<'tcx>) -> Self {
+        Self {
+            cx,
+            ecx: ConstEvalCtxt::new(cx),
+        }
+    }
+
+    /// Computes a range containing every value `expr` can evaluate to. Returns `None` if `expr` is
+    /// not of an integer type, or its type is `u128`.
+    pub fn eval(&self, expr: &Expr<'_>) -> Option<Interval> {
+        let ty = self.cx.typeck_results().expr_ty(expr);
+        let full = type_range(self.cx, ty)?;
+        let range = self.eval_kind(expr, ty).unwrap_or(full);
+        // Operations which may overflow are assumed to be able to take any value.
+        Some(if range.is_subset_of(full) { range } else { full })
+    }
+
+    fn eval_kind(&self, expr: &Expr<'_>, ty: Ty<'_>) -> Option<Interval> {
+        if let Some(Constant::Int(value)) = self.ecx.eval(expr) {
+            return match *ty.kind() {
+                ty::Int(ity) => Some(Interval::single(sext(self.cx.tcx, value, ity))),
+                _ => i128::try_from(value).ok().map(Interval::single),
+            };
+        }
+
+        match expr_or_init(self.cx, expr).kind {
+            ExprKind::Cast(inner, _) | ExprKind::DropTemps(inner) => self.eval(inner),
+            ExprKind::Block(block, _) => block.expr.and_then(|e| self.eval(e)),
+            ExprKind::Unary(UnOp::Neg, inner) => {
+                let inner = self.eval(inner)?;
+                Some(Interval::new(inner.hi.checked_neg()?, inner.lo.checked_neg()?))
+            },
+            ExprKind::Binary(op, lhs, rhs) => self.eval_binary(op.node, lhs, rhs),
+            ExprKind::MethodCall(method, recv, args, _) if self.cx.typeck_results().expr_ty(recv).is_integral() => {
+                self.eval_method(method.ident.name.as_str(), recv, args)
+            },
+            _ => None,
+        }
+    }
+
+    fn eval_binary(&self, op: BinOpKind, lhs: &Expr<'_>, rhs: &Expr<'_>) -> Option<Interval> {
+        let l = self.eval(lhs)?;
+        let r = self.eval(rhs)?;
+        match op {
+            BinOpKind::Add => Some(Interval::new(l.lo.checked_add(r.lo)?, l.hi.checked_add(r.hi)?)),
+            BinOpKind::Sub => Some(Interval::new(l.lo.checked_sub(r.hi)?, l.hi.checked_sub(r.lo)?)),
+            BinOpKind::Mul => l.checked_mul(r),
+            // Truncating division by a positive divisor is monotonic in both operands.
+            BinOpKind::Div if r.lo > 0 => {
+                let quotients = [l.lo / r.lo, l.lo / r.hi, l.hi / r.lo, l.hi / r.hi];
+                Some(Interval::new(*quotients.iter().min()?, *quotients.iter().max()?))
+            },
+            // The remainder has the sign of the dividend and is smaller than the divisor.
+            BinOpKind::Rem if r.lo > 0 || r.hi < 0 => {
+                let max = r.lo.unsigned_abs().max(r.hi.unsigned_abs()) - 1;
+                let max = i128::try_from(max).ok()?;
+                Some(Interval::new(l.lo.clamp(-max, 0), l.hi.clamp(0, max)))
+            },
+            BinOpKind::BitAnd => match (l.non_negative(), r.non_negative()) {
+                (true, true) => Some(Interval::new(0, l.hi.min(r.hi))),
+                (true, false) => Some(Interval::new(0, l.hi)),
+                (false, true) => Some(Interval::new(0, r.hi)),
+                (false, false) => None,
+            },
+            BinOpKind::Shr if r.lo >= 0 && r.hi < 128 => {
+                let (min_shift, max_shift) = (u32::try_from(r.lo).ok()?, u32::try_from(r.hi).ok()?);
+                Some(Interval::new(
+                    (l.lo >> min_shift).min(l.lo >> max_shift),
+                    (l.hi >> min_shift).max(l.hi >> max_shift),
+                ))
+            },
+            _ => None,
+        }
+    }
+
+    fn eval_method(&self, name: &str, recv: &Expr<'_>, args: &[Expr<'_>]) -> Option<Interval> {
+        match (name, args) {
+            ("min", [other]) => {
+                let (a, b) = (self.eval(recv)?, self.eval(other)?);
+                Some(Interval::new(a.lo.min(b.lo), a.hi.min(b.hi)))
+            },
+            ("max", [other]) => {
+                let (a, b) = (self.eval(recv)?, self.eval(other)?);
+                Some(Interval::new(a.lo.max(b.lo), a.hi.max(b.hi)))
+            },
+            ("clamp", [min, max]) => {
+                let (min, max) = (self.eval(min)?, self.eval(max)?);
+                (min.lo <= max.hi).then(|| Interval::new(min.lo, max.hi))
+            },
+            ("signum", []) => Some(Interval::new(-1, 1)),
+            (
+                "count_ones" | "count_zeros" | "leading_zeros" | "trailing_zeros" | "leading_ones" | "trailing_ones",
+                [],
+            ) => {
+                let bits = match *self.cx.typeck_results().expr_ty(recv).kind() {
+                    ty::Int(ity) => int_bits(self.cx.tcx, ity),
+                    ty::Uint(uty) => Integer::from_uint_ty(&self.cx.tcx, uty).size().bits(),
+                    _ => return None,
+                };
+                Some(Interval::new(0, i128::from(bits)))
+            },
+            _ => None,
+        }
+    }
+}
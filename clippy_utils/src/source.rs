@@ -348,7 +348,7 @@ fn first_char_in_first_line(sess: &impl HasSession, span: Span) -> Option<BytePo
 ///        let x = ();
 /// // ^^^^^^^^^^^^^^
 /// ```
-fn line_span(sess: &impl HasSession, span: Span) -> Span {
+pub(crate) fn line_span(sess: &impl HasSession, span: Span) -> Span {
     let span = original_sp(span, DUMMY_SP);
     let SourceFileAndLine { sf, line } = sess.sess().source_map().lookup_line(span.lo()).unwrap();
     let line_start = sf.lines()[line];
@@ -403,8 +403,7 @@ pub fn is_present_in_source(sess: &impl HasSession, span: Span) -> bool {
 ///               ^
 /// ```
 pub fn position_before_rarrow(s: &str) -> Option<usize> {
-    s.rfind("->").map(|rpos| {
-        let mut rpos = rpos;
+    s.rfind("->").map(|mut rpos| {
         let chars: Vec<char> = s.chars().collect();
         while rpos > 1 {
             if let Some(c) = chars.get(rpos - 1) {
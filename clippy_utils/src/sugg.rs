@@ -3,13 +3,14 @@
 
 use crate::source::{snippet, snippet_opt, snippet_with_applicability, snippet_with_context};
 use crate::ty::expr_sig;
-use crate::{get_parent_expr_for_hir, higher};
+use crate::{def_path_def_ids, get_parent_expr_for_hir, higher};
 use rustc_ast::util::parser::AssocOp;
 use rustc_ast::{ast, token};
 use rustc_ast_pretty::pprust::token_kind_to_string;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
-use rustc_hir::{Closure, ExprKind, HirId, MutTy, TyKind};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Closure, ExprKind, HirId, Item, ItemKind, MutTy, Node, StmtKind, TyKind, UseKind};
 use rustc_hir_typeck::expr_use_visitor::{Delegate, ExprUseVisitor, PlaceBase, PlaceWithHirId};
 use rustc_lint::{EarlyContext, LateContext, LintContext};
 use rustc_middle::hir::place::ProjectionKind;
@@ -790,6 +791,138 @@ impl<T: LintContext> DiagExt<T> for rustc_errors::Diag<'_, ()> {
     }
 }
 
+/// A path to an item to write in a suggestion, see [`import_path`].
+pub struct ImportedPath {
+    /// Either the name of the item, or its full path if it can't be imported.
+    pub path: String,
+    /// The `use` item to insert for the name to resolve, if it isn't already imported.
+    pub import: Option<(Span, String)>,
+}
+
+impl ImportedPath {
+    /// Returns `parts` along with the insertion of the `use` item, for a multipart suggestion
+    /// using the path.
+    #[must_use]
+    pub fn with_import(&self, mut parts: Vec<(Span, String)>) -> Vec<(Span, String)> {
+        parts.extend(self.import.clone());
+        parts
+    }
+}
+
+/// Prepares a path like `std::collections::hash_map::Entry` to be written in a suggestion at
+/// `hir_id`, so suggestions don't have to spell out the full path of the items they need.
+///
+/// The name of the item is used if it's already imported, either by the enclosing module or by a
+/// block around `hir_id`. Otherwise a `use` item is inserted before the first import of the
+/// module, or its first item if it has no imports. The full path is used if the name is taken by
+/// another item or a generic parameter in scope, or if a glob import could bring another item of
+/// the same name into scope.
+pub fn import_path(cx: &LateContext<'_>, hir_id: HirId, path: &[&str]) -> ImportedPath {
+    let full_path = || ImportedPath {
+        path: path.join("::"),
+        import: None,
+    };
+    let Some(&name) = path.last() else {
+        return full_path();
+    };
+    let def_ids: Vec<_> = def_path_def_ids(cx.tcx, path).collect();
+    let resolved = |imported| {
+        if imported {
+            ImportedPath {
+                path: name.to_owned(),
+                import: None,
+            }
+        } else {
+            full_path()
+        }
+    };
+
+    // Items and generic parameters of the enclosing scopes shadow the items of the module
+    for (_, node) in cx.tcx.hir().parent_iter(hir_id) {
+        if let Node::Item(Item {
+            kind: ItemKind::Mod(..),
+            ..
+        }) = node
+        {
+            break;
+        }
+        if let Some(generics) = node.generics()
+            && generics
+                .params
+                .iter()
+                .any(|param| param.name.ident().name.as_str() == name)
+        {
+            return full_path();
+        }
+        if let Node::Block(block) = node
+            && let Some(imported) = block
+                .stmts
+                .iter()
+                .filter_map(|stmt| match stmt.kind {
+                    StmtKind::Item(id) => Some(cx.tcx.hir().item(id)),
+                    _ => None,
+                })
+                .find_map(|item| declares_name(item, name, &def_ids))
+        {
+            return resolved(imported);
+        }
+    }
+
+    let (module, ..) = cx.tcx.hir().get_module(cx.tcx.parent_module(hir_id));
+    let items = module.item_ids.iter().map(|&id| cx.tcx.hir().item(id));
+
+    let mut first_use = None::<Span>;
+    let mut first_item = None::<Span>;
+    for item in items {
+        if let Some(imported) = declares_name(item, name, &def_ids) {
+            return resolved(imported);
+        }
+        if item.span.from_expansion() {
+            continue;
+        }
+        // Insert before the outer attributes and doc comments of the item
+        let span = cx
+            .tcx
+            .hir()
+            .attrs(item.hir_id())
+            .iter()
+            .fold(item.span, |span, attr| span.to(attr.span));
+        let first = if matches!(item.kind, ItemKind::Use(..)) {
+            &mut first_use
+        } else {
+            &mut first_item
+        };
+        if first.is_none_or(|first| span.lo() < first.lo()) {
+            *first = Some(span);
+        }
+    }
+
+    match first_use.or(first_item) {
+        Some(span) if let Some(indent) = indentation(cx, span) => ImportedPath {
+            path: name.to_owned(),
+            import: Some((span.shrink_to_lo(), format!("use {};\n{indent}", path.join("::")))),
+        },
+        _ => full_path(),
+    }
+}
+
+/// Checks whether `item` brings `name` into scope. Returns `Some(true)` if it imports one of
+/// `def_ids` under that name, `Some(false)` if the name may refer to something else.
+fn declares_name(item: &Item<'_>, name: &str, def_ids: &[DefId]) -> Option<bool> {
+    match item.kind {
+        ItemKind::Use(use_path, UseKind::Single) if item.ident.name.as_str() == name => Some(
+            use_path
+                .res
+                .iter()
+                .any(|res| res.opt_def_id().is_some_and(|id| def_ids.contains(&id))),
+        ),
+        // The prelude is imported by a glob, but an explicit import takes precedence over it
+        ItemKind::Use(_, UseKind::Glob) if !item.span.from_expansion() => Some(false),
+        _ if item.ident.name.as_str() == name => Some(false),
+        _ => None,
+    }
+}
+
 /// Suggestion results for handling closure
 /// args dereferencing and borrowing
 pub struct DerefClosure {
@@ -5,7 +5,7 @@ use std::path::PathBuf;
 #[derive(Parser, Clone, Debug)]
 #[command(args_conflicts_with_subcommands = true)]
 pub(crate) struct LintcheckConfig {
-    /// Number of threads to use (default: all unless --fix or --recursive)
+    /// Number of threads to use (default: all unless --fix, --check-fixes or --recursive)
     #[clap(
         long = "jobs",
         short = 'j',
@@ -33,6 +33,10 @@ pub(crate) struct LintcheckConfig {
     /// Runs cargo clippy --fix and checks if all suggestions apply
     #[clap(long, conflicts_with("max_jobs"))]
     pub fix: bool,
+    /// Runs cargo clippy --fix on a copy of each crate, checks it again and reports the lints
+    /// whose suggestions caused the errors
+    #[clap(long, conflicts_with_all(["max_jobs", "fix", "recursive", "perf"]))]
+    pub check_fixes: bool,
     /// Apply a filter to only collect specified lints
     #[clap(long = "filter", value_name = "clippy_lint_name", use_value_delimiter = true)]
     pub lint_filter: Vec<String>,
@@ -46,6 +50,10 @@ pub(crate) struct LintcheckConfig {
     /// Run clippy on the dependencies of crates specified in crates-toml
     #[clap(long, conflicts_with("max_jobs"))]
     pub recursive: bool,
+    /// Measure the time spent in each lint pass and report the slowest ones. The crates are
+    /// always checked again instead of using cargo's cache
+    #[clap(long, conflicts_with_all(["fix", "recursive"]))]
+    pub perf: bool,
     #[command(subcommand)]
     pub subcommand: Option<Commands>,
 }
@@ -102,7 +110,7 @@ impl LintcheckConfig {
 
         // look at the --threads arg, if 0 is passed, use the threads count
         if config.max_jobs == 0 {
-            config.max_jobs = if config.fix || config.recursive {
+            config.max_jobs = if config.fix || config.check_fixes || config.recursive {
                 1
             } else {
                 std::thread::available_parallelism().map_or(1, NonZero::get)
@@ -0,0 +1,273 @@
+//! Validates the suggestions applied by `cargo clippy --fix` for `--check-fixes`
+//!
+//! Each crate is copied to a scratch directory so the downloaded sources stay untouched. Clippy
+//! first checks the copy to record its machine applicable suggestions, then `cargo fix` applies
+//! them with `--broken-code` and the copy is checked again. Every error of the second check is
+//! attributed to the lints whose suggestions changed the lines it points at, found by diffing the
+//! fixed file against the original one.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use cargo_metadata::Message;
+use cargo_metadata::diagnostic::{Applicability, Diagnostic, DiagnosticLevel};
+use walkdir::WalkDir;
+
+use crate::{Crate, clippy_project_root, shared_target_dir};
+
+/// A machine applicable suggestion emitted before the fixes were applied
+struct Suggestion {
+    lint: String,
+    file: String,
+    /// The lines of the original file the suggestion replaces
+    lines: Range<usize>,
+}
+
+/// A region of lines which differs between the original and the fixed file
+struct Hunk {
+    original: Range<usize>,
+    fixed: Range<usize>,
+}
+
+/// An error found after applying the fixes to a crate
+pub(crate) struct BrokenFix {
+    krate: String,
+    /// The lints whose suggestions changed the lines of the error, or all the lints which changed
+    /// the file if none of them did
+    lints: BTreeSet<String>,
+    /// Whether the error is on a line changed by one of the `lints`
+    exact: bool,
+    file: String,
+    line: usize,
+    message: String,
+}
+
+/// The directory the crate is copied to before applying the fixes
+fn scratch_path(krate: &Crate) -> PathBuf {
+    clippy_project_root()
+        .join("target/lintcheck/fix")
+        .join(format!("{}-{}", krate.name, krate.version))
+}
+
+/// Applies the fixes to a copy of the crate and returns the errors they caused
+pub(crate) fn check_crate(krate: &Crate, clippy_driver_path: &Path, lint_levels_args: &[String]) -> Vec<BrokenFix> {
+    let path = scratch_path(krate);
+    copy_sources(&krate.path, &path);
+
+    let options = krate.options.iter().flatten().map(String::as_str);
+    let lint_args: Vec<&str> = options
+        .clone()
+        .chain(lint_levels_args.iter().map(String::as_str))
+        .collect();
+    let check_args: Vec<&str> = options.chain(["--cap-lints=warn"]).collect();
+
+    let suggestions: Vec<Suggestion> = check(&path, clippy_driver_path, &lint_args)
+        .iter()
+        .filter(|diag| diag.code.as_ref().is_some_and(|code| code.code.starts_with("clippy::")))
+        .flat_map(|diag| {
+            let lint = &diag.code.as_ref().unwrap().code;
+            machine_applicable_spans(diag).map(move |(file, lines)| Suggestion {
+                lint: lint.clone(),
+                file,
+                lines,
+            })
+        })
+        .collect();
+    if suggestions.is_empty() {
+        return Vec::new();
+    }
+
+    let status = cargo(&path, clippy_driver_path, &lint_args)
+        .args([
+            "fix",
+            "--quiet",
+            "--all-targets",
+            "--broken-code",
+            "--allow-no-vcs",
+            "--allow-dirty",
+        ])
+        .status()
+        .expect("failed to run cargo fix");
+    if !status.success() {
+        eprintln!("WARNING: bad exit status after fixing {} {}", krate.name, krate.version);
+    }
+
+    let mut hunks: HashMap<String, Vec<Hunk>> = HashMap::new();
+    check(&path, clippy_driver_path, &check_args)
+        .into_iter()
+        .filter(|diag| matches!(diag.level, DiagnosticLevel::Error | DiagnosticLevel::Ice))
+        .filter_map(|diag| {
+            let span = diag.spans.iter().find(|span| span.is_primary)?;
+            let hunks = hunks
+                .entry(span.file_name.clone())
+                .or_insert_with(|| diff_file(&krate.path, &path, &span.file_name));
+            let (lints, exact) = attribute(&suggestions, &span.file_name, hunks, span.line_start);
+            Some(BrokenFix {
+                krate: format!("{} {}", krate.name, krate.version),
+                lints,
+                exact,
+                file: span.file_name.clone(),
+                line: span.line_start,
+                message: diag.message.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Copies the crate sources, skipping the `target` directory of local crates
+fn copy_sources(from: &Path, to: &Path) {
+    if to.exists() {
+        fs::remove_dir_all(to).unwrap();
+    }
+    for entry in WalkDir::new(from)
+        .into_iter()
+        .filter_entry(|e| e.depth() != 1 || e.file_name() != "target")
+    {
+        let entry = entry.unwrap();
+        let dest_path = to.join(entry.path().strip_prefix(from).unwrap());
+        let metadata = entry.path().symlink_metadata().unwrap();
+
+        if metadata.is_dir() {
+            fs::create_dir_all(dest_path).unwrap();
+        } else if metadata.is_file() {
+            fs::copy(entry.path(), dest_path).unwrap();
+        }
+    }
+}
+
+/// Creates a `cargo` command running Clippy with the given arguments on the crate at `path`
+fn cargo(path: &Path, clippy_driver_path: &Path, clippy_args: &[&str]) -> Command {
+    let mut cmd = Command::new("cargo");
+    cmd.current_dir(path)
+        .env("CARGO_TARGET_DIR", shared_target_dir("fix"))
+        .env("RUSTC_WORKSPACE_WRAPPER", clippy_driver_path)
+        .env("CLIPPY_ARGS", clippy_args.join("__CLIPPY_HACKERY__"))
+        .env("CLIPPY_DISABLE_DOCS_LINKS", "1");
+    cmd
+}
+
+/// Checks the crate at `path` and returns the emitted diagnostics
+fn check(path: &Path, clippy_driver_path: &Path, clippy_args: &[&str]) -> Vec<Diagnostic> {
+    let output = cargo(path, clippy_driver_path, clippy_args)
+        .args(["check", "--quiet", "--all-targets", "--message-format=json"])
+        .output()
+        .expect("failed to run cargo check");
+    Message::parse_stream(output.stdout.as_slice())
+        .filter_map(|msg| match msg {
+            Ok(Message::CompilerMessage(message)) => Some(message.message),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the file and lines of the machine applicable suggestions of a diagnostic
+fn machine_applicable_spans(diag: &Diagnostic) -> impl Iterator<Item = (String, Range<usize>)> + '_ {
+    diag.spans
+        .iter()
+        .chain(diag.children.iter().flat_map(|child| &child.spans))
+        .filter(|span| matches!(span.suggestion_applicability, Some(Applicability::MachineApplicable)))
+        .map(|span| (span.file_name.clone(), span.line_start..span.line_end + 1))
+}
+
+/// Computes the regions of lines which differ between the original and the fixed version of a file
+fn diff_file(original_root: &Path, fixed_root: &Path, file: &str) -> Vec<Hunk> {
+    let original = fs::read_to_string(original_root.join(file)).unwrap_or_default();
+    let fixed = fs::read_to_string(fixed_root.join(file)).unwrap_or_default();
+
+    let mut hunks = Vec::new();
+    let mut current: Option<Hunk> = None;
+    let (mut original_line, mut fixed_line) = (1, 1);
+    for change in diff::lines(&original, &fixed) {
+        use diff::Result::{Both, Left};
+
+        if let Both(..) = change {
+            hunks.extend(current.take());
+            original_line += 1;
+            fixed_line += 1;
+            continue;
+        }
+        let hunk = current.get_or_insert(Hunk {
+            original: original_line..original_line,
+            fixed: fixed_line..fixed_line,
+        });
+        if let Left(_) = change {
+            original_line += 1;
+            hunk.original.end = original_line;
+        } else {
+            fixed_line += 1;
+            hunk.fixed.end = fixed_line;
+        }
+    }
+    hunks.extend(current);
+    hunks
+}
+
+/// Returns the lints whose suggestions changed the hunk containing `line`, and whether one did.
+/// Falls back to the lints which changed any hunk of the file otherwise, as the error may be
+/// caused by a change elsewhere, e.g. to the signature of a function.
+fn attribute(suggestions: &[Suggestion], file: &str, hunks: &[Hunk], line: usize) -> (BTreeSet<String>, bool) {
+    let lints_of = |hunk: &Hunk| {
+        suggestions
+            .iter()
+            .filter(|sugg| {
+                sugg.file == file
+                    && if hunk.original.is_empty() {
+                        // Lines were only inserted before `hunk.original.start`
+                        (sugg.lines.start..=sugg.lines.end).contains(&hunk.original.start)
+                    } else {
+                        sugg.lines.start < hunk.original.end && hunk.original.start < sugg.lines.end
+                    }
+            })
+            .map(|sugg| sugg.lint.clone())
+            .collect::<Vec<_>>()
+    };
+
+    if let Some(hunk) = hunks
+        .iter()
+        .find(|hunk| hunk.fixed.contains(&line) || (hunk.fixed.is_empty() && hunk.fixed.start == line))
+    {
+        let lints: BTreeSet<_> = lints_of(hunk).into_iter().collect();
+        if !lints.is_empty() {
+            return (lints, true);
+        }
+    }
+    (hunks.iter().flat_map(lints_of).collect(), false)
+}
+
+/// Renders the broken fixes as a markdown table
+pub(crate) fn summarize(broken: &[BrokenFix]) -> String {
+    if broken.is_empty() {
+        return "All the applied suggestions compile\n".into();
+    }
+
+    let mut text = format!("{} errors after applying the suggestions\n\n", broken.len());
+    text.push_str("| Crate | Location | Lints | Error |\n");
+    text.push_str("|-------|----------|-------|-------|\n");
+    for fix in broken {
+        let lints = if fix.lints.is_empty() {
+            "unknown".to_owned()
+        } else {
+            let lints: Vec<_> = fix.lints.iter().map(|lint| format!("`{lint}`")).collect();
+            let lints = lints.join(", ");
+            if fix.exact {
+                lints
+            } else {
+                format!("{lints} (elsewhere in the file)")
+            }
+        };
+        writeln!(
+            text,
+            "| {} | `{}:{}` | {lints} | {} |",
+            fix.krate,
+            fix.file,
+            fix.line,
+            fix.message.replace('|', "\\|").replace('\n', " ")
+        )
+        .unwrap();
+    }
+    text
+}
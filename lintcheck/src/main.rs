@@ -23,9 +23,11 @@
 
 mod config;
 mod driver;
+mod fix;
 mod input;
 mod json;
 mod output;
+mod perf;
 mod popular_crates;
 mod recursive;
 
@@ -152,6 +154,21 @@ impl Crate {
         }
 
         let shared_target_dir = shared_target_dir(&format!("_{thread_index:?}"));
+
+        if config.perf {
+            let timings_path = perf::timings_path(&self.name, &self.version);
+            let _: io::Result<()> = fs::remove_file(&timings_path);
+            // Clippy has to run again instead of cargo replaying the cached warnings
+            let status = Command::new("cargo")
+                .args(["clean", "--quiet", "--package", &self.name])
+                .current_dir(&self.path)
+                .env("CARGO_TARGET_DIR", shared_target_dir.as_os_str())
+                .status()
+                .expect("failed to run cargo clean");
+            assert!(status.success(), "failed to clean {} {}", self.name, self.version);
+            cmd.env("CLIPPY_PASS_TIMINGS", timings_path);
+        }
+
         let all_output = cmd
             // use the looping index to create individual target dirs
             .env("CARGO_TARGET_DIR", shared_target_dir.as_os_str())
@@ -345,6 +362,38 @@ fn lintcheck(config: LintcheckConfig) {
         .build_global()
         .unwrap();
 
+    if config.perf {
+        fs::create_dir_all(perf::timings_path("", "").parent().unwrap()).unwrap();
+    }
+
+    if config.check_fixes {
+        let broken: Vec<_> = crates
+            .iter()
+            .enumerate()
+            .flat_map(|(index, krate)| {
+                println!(
+                    "{}/{} Checking the fixes of {} {}",
+                    index + 1,
+                    crates.len(),
+                    krate.name,
+                    krate.version
+                );
+                fix::check_crate(krate, &clippy_driver_path, &lint_level_args)
+            })
+            .collect();
+        let summary = fix::summarize(&broken);
+        println!("\n{summary}");
+
+        let fix_results_path = config.lintcheck_results_path.with_file_name(format!(
+            "{}_fix.md",
+            config.sources_toml_path.file_stem().unwrap().to_string_lossy()
+        ));
+        println!("Writing the broken fixes to {}", fix_results_path.display());
+        fs::create_dir_all(fix_results_path.parent().unwrap()).unwrap();
+        fs::write(fix_results_path, summary).unwrap();
+        return;
+    }
+
     let server = config.recursive.then(|| {
         let _: io::Result<()> = fs::remove_dir_all("target/lintcheck/shared_target_dir/recursive");
 
@@ -406,6 +455,27 @@ fn lintcheck(config: LintcheckConfig) {
     println!("Writing logs to {}", config.lintcheck_results_path.display());
     fs::create_dir_all(config.lintcheck_results_path.parent().unwrap()).unwrap();
     fs::write(&config.lintcheck_results_path, text).unwrap();
+
+    if config.perf {
+        let timings: Vec<_> = crates
+            .iter()
+            .map(|krate| {
+                (
+                    format!("{} {}", krate.name, krate.version),
+                    perf::timings_path(&krate.name, &krate.version),
+                )
+            })
+            .collect();
+        let summary = perf::summarize(&timings);
+        println!("\n{summary}");
+
+        let perf_results_path = config.lintcheck_results_path.with_file_name(format!(
+            "{}_perf.md",
+            config.sources_toml_path.file_stem().unwrap().to_string_lossy()
+        ));
+        println!("Writing lint pass timings to {}", perf_results_path.display());
+        fs::write(perf_results_path, summary).unwrap();
+    }
 }
 
 /// Returns the path to the Clippy project directory
@@ -0,0 +1,97 @@
+//! Aggregates the time spent in each lint pass for `--perf`
+//!
+//! `clippy-driver` appends a `pass<TAB>nanoseconds` line per lint pass to the file named by
+//! `CLIPPY_PASS_TIMINGS` when it is set. Each crate gets its own file, which are summed up here.
+//! Parts of a pass are timed as `Pass::part`, their time is included in that of the pass.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::clippy_project_root;
+
+/// The number of passes listed in the summary
+const SHOWN_PASSES: usize = 30;
+
+#[derive(Default)]
+struct PassTiming {
+    total: Duration,
+    /// The crate the pass took the longest on, and the time it took
+    slowest_crate: Option<(String, Duration)>,
+}
+
+/// The file the pass timings of a crate are written to
+pub(crate) fn timings_path(name: &str, version: &str) -> PathBuf {
+    clippy_project_root()
+        .join("target/lintcheck/perf")
+        .join(format!("{name}-{version}.tsv"))
+}
+
+/// Sums up the timings of all crates and renders the slowest passes as a markdown table
+pub(crate) fn summarize(crates: &[(String, PathBuf)]) -> String {
+    let mut passes: HashMap<String, PassTiming> = HashMap::new();
+    for (krate, path) in crates {
+        for (pass, elapsed) in read_timings(path) {
+            let timing = passes.entry(pass).or_default();
+            timing.total += elapsed;
+            if timing
+                .slowest_crate
+                .as_ref()
+                .is_none_or(|(_, slowest)| elapsed > *slowest)
+            {
+                timing.slowest_crate = Some((krate.clone(), elapsed));
+            }
+        }
+    }
+
+    let mut passes: Vec<_> = passes.into_iter().collect();
+    passes.sort_unstable_by(|(name1, timing1), (name2, timing2)| {
+        timing2.total.cmp(&timing1.total).then_with(|| name1.cmp(name2))
+    });
+    let total: Duration = passes
+        .iter()
+        .filter(|(name, _)| !name.contains("::"))
+        .map(|(_, timing)| timing.total)
+        .sum();
+
+    let mut text = format!(
+        "Time spent in lint passes across {} crates: {:.2?}\n\n",
+        crates.len(),
+        total
+    );
+    text.push_str("| Pass | Total | Share | Slowest crate |\n");
+    text.push_str("|------|------:|------:|---------------|\n");
+    for (pass, timing) in passes.iter().take(SHOWN_PASSES) {
+        let share = timing.total.as_secs_f64() / total.as_secs_f64().max(f64::EPSILON) * 100.0;
+        let (krate, slowest) = timing.slowest_crate.as_ref().unwrap();
+        writeln!(
+            text,
+            "| `{pass}` | {:.2?} | {share:.1}% | {krate} ({slowest:.2?}) |",
+            timing.total
+        )
+        .unwrap();
+    }
+    text
+}
+
+/// Reads the timings of a crate, a pass can appear several times if the crate has multiple targets
+fn read_timings(path: &Path) -> Vec<(String, Duration)> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        eprintln!("WARNING: no lint pass timings found at {}", path.display());
+        return Vec::new();
+    };
+    let mut timings: HashMap<&str, Duration> = HashMap::new();
+    for line in contents.lines() {
+        if let Some((pass, nanos)) = line.split_once('\t')
+            && let Ok(nanos) = nanos.parse()
+        {
+            *timings.entry(pass).or_default() += Duration::from_nanos(nanos);
+        }
+    }
+    timings
+        .into_iter()
+        .map(|(pass, elapsed)| (pass.to_owned(), elapsed))
+        .collect()
+}
@@ -1,6 +1,7 @@
 #![allow(rustc::diagnostic_outside_of_impl)]
 #![allow(rustc::untranslatable_diagnostic)]
 #![feature(rustc_private)]
+#![feature(if_let_guard)]
 #![feature(let_chains)]
 // warn on lints, that are included in `rust-lang/rust`s bootstrap
 #![warn(rust_2018_idioms, unused_lifetimes)]
@@ -17,6 +18,7 @@ extern crate rustc_span;
 use rustc_interface::interface;
 use rustc_session::EarlyDiagCtxt;
 use rustc_session::config::ErrorOutputType;
+use rustc_session::lint::Level;
 use rustc_session::parse::ParseSess;
 use rustc_span::symbol::Symbol;
 
@@ -138,10 +140,48 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
         let conf_path = clippy_config::lookup_conf_file();
         let previous = config.register_lints.take();
         let clippy_args_var = self.clippy_args_var.take();
+
+        // Lints from `deny-in-ci` are inserted after all other lint flags, so that they are denied
+        // in CI even if the package allows them, e.g. with Cargo's `[lints]` table.
+        let deny_in_ci = clippy_config::read_deny_in_ci(&conf_path);
+        config
+            .opts
+            .lint_opts
+            .extend(deny_in_ci.lints.into_iter().map(|lint| (lint, Level::Deny)));
+
+        // Lints from `[build-script.lints]` are inserted after all other lint flags, so that they
+        // take precedence over the lint levels of the package, e.g. from Cargo's `[lints]` table.
+        if clippy_config::is_build_script(config.opts.crate_name.as_deref()) {
+            config
+                .opts
+                .lint_opts
+                .extend(clippy_config::read_build_script_lints(&conf_path));
+        }
+
         config.psess_created = Some(Box::new(move |psess| {
             track_clippy_args(psess, clippy_args_var.as_deref());
             track_files(psess);
 
+            // Trigger a rebuild if the environment variable used by `deny-in-ci` changes
+            psess.env_depinfo.get_mut().insert((
+                Symbol::intern(&deny_in_ci.env_var),
+                env::var(&deny_in_ci.env_var).ok().map(|value| Symbol::intern(&value)),
+            ));
+
+            // Trigger a rebuild if the suggestions to allow the lints are turned on or off
+            psess.env_depinfo.get_mut().insert((
+                Symbol::intern(SUGGEST_ALLOWS_VAR),
+                env::var(SUGGEST_ALLOWS_VAR).ok().map(|value| Symbol::intern(&value)),
+            ));
+
+            // Trigger a rebuild for every run of `cargo clippy --summary`, the path of the summary
+            // file is unique to each of them. Cargo would otherwise replay the cached diagnostics
+            // of unchanged crates without running Clippy, so they would be missing from the summary
+            psess.env_depinfo.get_mut().insert((
+                Symbol::intern(SUMMARY_VAR),
+                env::var(SUMMARY_VAR).ok().map(|path| Symbol::intern(&path)),
+            ));
+
             // Trigger a rebuild if CLIPPY_CONF_DIR changes. The value must be a valid string so
             // changes between dirs that are invalid UTF-8 will not trigger rebuilds
             psess.env_depinfo.get_mut().insert((
@@ -157,8 +197,13 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
             }
 
             let conf = clippy_config::Conf::read(sess, &conf_path);
+            clippy_lints::set_lints_allowed_in_tests(sess, conf);
+            let (early_start, late_start) = (lint_store.early_passes.len(), lint_store.late_passes.len());
             clippy_lints::register_lints(lint_store, conf);
             clippy_lints::register_pre_expansion_lints(lint_store, conf);
+            if env::var_os(PASS_TIMINGS_VAR).is_some() {
+                clippy_lints::time_passes(lint_store, early_start, late_start);
+            }
         }));
 
         // FIXME: #4825; This is required, because Clippy lints that are based on MIR have to be
@@ -177,6 +222,15 @@ fn display_help() {
     println!("{}", help_message());
 }
 
+/// Set by `cargo lintcheck --perf` to the file the time spent in each lint pass is appended to.
+const PASS_TIMINGS_VAR: &str = "CLIPPY_PASS_TIMINGS";
+
+/// Set by `cargo clippy --summary` to the file the emitted lints are appended to.
+const SUMMARY_VAR: &str = "CLIPPY_SUMMARY_FILE";
+
+/// If set to `1`, every lint gets a suggestion to allow it on the innermost item or statement.
+const SUGGEST_ALLOWS_VAR: &str = "CLIPPY_SUGGEST_ALLOWS";
+
 const BUG_REPORT_URL: &str = "https://github.com/rust-lang/rust-clippy/issues/new?template=ice.yml";
 
 #[allow(clippy::too_many_lines)]
@@ -265,6 +319,10 @@ pub fn main() {
         pass_sysroot_env_if_given(&mut args, sys_root_env);
 
         let mut no_deps = false;
+        let mut fix_maybe_incorrect = false;
+        let mut fix_only = Vec::new();
+        let mut suggestion_alternatives = false;
+        let mut hidden_groups = Vec::new();
         let clippy_args_var = env::var("CLIPPY_ARGS").ok();
         let clippy_args = clippy_args_var
             .as_deref()
@@ -276,6 +334,39 @@ pub fn main() {
                     no_deps = true;
                     None
                 },
+                _ if let Some(level) = s.strip_prefix("--fix-applicability=") => {
+                    fix_maybe_incorrect = match level {
+                        "machine-applicable" => false,
+                        "maybe-incorrect" => true,
+                        _ => early_dcx.early_fatal(format!(
+                            "invalid value `{level}` for `--fix-applicability`, \
+                            expected `machine-applicable` or `maybe-incorrect`"
+                        )),
+                    };
+                    None
+                },
+                "--suggestion-alternatives" => {
+                    suggestion_alternatives = true;
+                    None
+                },
+                _ if let Some(lints) = s.strip_prefix("--fix-only=") => {
+                    fix_only.extend(
+                        lints
+                            .split(',')
+                            .filter(|lint| !lint.is_empty())
+                            .map(ToString::to_string),
+                    );
+                    None
+                },
+                _ if let Some(groups) = s.strip_prefix("--hide=") => {
+                    hidden_groups.extend(
+                        groups
+                            .split(',')
+                            .filter(|group| !group.is_empty())
+                            .map(ToString::to_string),
+                    );
+                    None
+                },
                 _ => Some(s.to_string()),
             })
             .chain(vec!["--cfg".into(), "clippy".into()])
@@ -294,10 +385,42 @@ pub fn main() {
 
         let clippy_enabled = !cap_lints_allow && relevant_package && !info_query;
         if clippy_enabled {
+            if (fix_maybe_incorrect || !fix_only.is_empty())
+                && let Err(lint) = clippy_lints::set_fix_filter(fix_maybe_incorrect, &fix_only)
+            {
+                early_dcx.early_fatal(format!(
+                    "`{lint}` passed to `--fix-only` is neither a Clippy lint nor a Clippy lint group"
+                ));
+            }
+            if suggestion_alternatives {
+                clippy_lints::set_tag_suggestion_alternatives();
+            }
+            if env::var_os(SUGGEST_ALLOWS_VAR).is_some_and(|value| value == "1") {
+                clippy_lints::set_suggest_allows();
+            }
+            if !hidden_groups.is_empty()
+                && let Err(group) = clippy_lints::hide_groups(&hidden_groups)
+            {
+                early_dcx.early_fatal(format!("`{group}` passed to `--hide` is not a Clippy lint group"));
+            }
+            let summary_path = env::var_os(SUMMARY_VAR);
+            if summary_path.is_some() {
+                clippy_lints::record_emissions();
+            }
             args.extend(clippy_args);
             rustc_driver::RunCompiler::new(&args, &mut ClippyCallbacks { clippy_args_var })
                 .set_using_internal_features(using_internal_features)
                 .run();
+            if let Some(path) = env::var_os(PASS_TIMINGS_VAR)
+                && let Err(e) = clippy_lints::write_pass_timings(Path::new(&path))
+            {
+                early_dcx.early_warn(format!("failed to write the lint pass timings: {e}"));
+            }
+            if let Some(path) = summary_path
+                && let Err(e) = clippy_lints::write_emission_summary(Path::new(&path))
+            {
+                early_dcx.early_warn(format!("failed to write the lint summary: {e}"));
+            }
         } else {
             rustc_driver::RunCompiler::new(&args, &mut RustcCallbacks { clippy_args_var })
                 .set_using_internal_features(using_internal_features)
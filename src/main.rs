@@ -4,11 +4,12 @@
 // warn on lints, that are included in `rust-lang/rust`s bootstrap
 #![warn(rust_2018_idioms, unused_lifetimes)]
 
-use std::env;
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::process::{self, Command};
+use std::{env, fs};
 
-use anstream::println;
+use anstream::{eprintln, println};
 
 #[allow(clippy::ignored_unit_patterns)]
 fn show_help() {
@@ -54,25 +55,63 @@ struct ClippyCmd {
     cargo_subcommand: &'static str,
     args: Vec<String>,
     clippy_args: Vec<String>,
+    summary: bool,
+    workspace_deps: bool,
 }
 
 impl ClippyCmd {
-    fn new<I>(mut old_args: I) -> Self
+    fn new<I>(mut old_args: I) -> Result<Self, String>
     where
         I: Iterator<Item = String>,
     {
         let mut cargo_subcommand = "check";
         let mut args = vec![];
         let mut clippy_args: Vec<String> = vec![];
+        let mut summary = false;
+        let mut workspace_deps = false;
 
-        for arg in old_args.by_ref() {
+        while let Some(arg) = old_args.next() {
             match arg.as_str() {
                 "--fix" => {
                     cargo_subcommand = "fix";
                     continue;
                 },
-                "--no-deps" => {
-                    clippy_args.push("--no-deps".into());
+                "--no-deps" | "--suggestion-alternatives" => {
+                    clippy_args.push(arg);
+                    continue;
+                },
+                "--summary" => {
+                    summary = true;
+                    continue;
+                },
+                "--workspace-deps" => {
+                    workspace_deps = true;
+                    continue;
+                },
+                // Passed on to the driver as `--hide=value`
+                "--hide" => {
+                    let Some(value) = old_args.next() else {
+                        return Err(format!("`{arg}` requires a value"));
+                    };
+                    clippy_args.push(format!("{arg}={value}"));
+                    continue;
+                },
+                _ if arg.starts_with("--hide=") => {
+                    clippy_args.push(arg);
+                    continue;
+                },
+                // Both options imply `--fix` and are passed on to the driver as `--option=value`
+                "--fix-applicability" | "--fix-only" => {
+                    let Some(value) = old_args.next() else {
+                        return Err(format!("`{arg}` requires a value"));
+                    };
+                    cargo_subcommand = "fix";
+                    clippy_args.push(format!("{arg}={value}"));
+                    continue;
+                },
+                _ if arg.starts_with("--fix-applicability=") || arg.starts_with("--fix-only=") => {
+                    cargo_subcommand = "fix";
+                    clippy_args.push(arg);
                     continue;
                 },
                 "--" => break,
@@ -87,11 +126,13 @@ impl ClippyCmd {
             clippy_args.push("--no-deps".into());
         }
 
-        Self {
+        Ok(Self {
             cargo_subcommand,
             args,
             clippy_args,
-        }
+            summary,
+            workspace_deps,
+        })
     }
 
     fn path() -> PathBuf {
@@ -116,7 +157,15 @@ impl ClippyCmd {
         // Currently, `CLIPPY_TERMINAL_WIDTH` is used only to format "unknown field" error messages.
         let terminal_width = termize::dimensions().map_or(0, |(w, _)| w);
 
-        cmd.env("RUSTC_WORKSPACE_WRAPPER", Self::path())
+        // Cargo only uses `RUSTC_WORKSPACE_WRAPPER` for workspace members. `RUSTC_WRAPPER` is used for
+        // every crate, the driver then skips the registry and git dependencies as cargo caps their lints.
+        let wrapper = if self.workspace_deps {
+            "RUSTC_WRAPPER"
+        } else {
+            "RUSTC_WORKSPACE_WRAPPER"
+        };
+
+        cmd.env(wrapper, Self::path())
             .env("CLIPPY_ARGS", clippy_args)
             .env("CLIPPY_TERMINAL_WIDTH", terminal_width.to_string())
             .arg(self.cargo_subcommand)
@@ -130,9 +179,31 @@ fn process<I>(old_args: I) -> Result<(), i32>
 where
     I: Iterator<Item = String>,
 {
-    let cmd = ClippyCmd::new(old_args);
+    let cmd = ClippyCmd::new(old_args).map_err(|msg| {
+        eprintln!("error: {msg}");
+        1
+    })?;
+
+    // `--workspace-deps` replaces `RUSTC_WRAPPER`, an existing wrapper would silently be dropped
+    if cmd.workspace_deps {
+        if let Some(wrapper) = env::var_os("RUSTC_WRAPPER").filter(|wrapper| !wrapper.is_empty()) {
+            eprintln!(
+                "error: `--workspace-deps` can't be used together with `RUSTC_WRAPPER` (set to `{}`)",
+                wrapper.to_string_lossy()
+            );
+            return Err(1);
+        }
+    }
+
+    // Every `clippy-driver` invocation appends the lints it emitted to this file
+    let summary_path = cmd
+        .summary
+        .then(|| env::temp_dir().join(format!("clippy-summary-{}.tsv", process::id())));
 
     let mut cmd = cmd.into_std_cmd();
+    if let Some(summary_path) = &summary_path {
+        cmd.env("CLIPPY_SUMMARY_FILE", summary_path);
+    }
 
     let exit_status = cmd
         .spawn()
@@ -140,6 +211,11 @@ where
         .wait()
         .expect("failed to wait for cargo?");
 
+    if let Some(summary_path) = summary_path {
+        print_summary(&summary_path);
+        let _ = fs::remove_file(summary_path);
+    }
+
     if exit_status.success() {
         Ok(())
     } else {
@@ -147,6 +223,54 @@ where
     }
 }
 
+/// Prints the number of emitted warnings per lint and how many of them `--fix` can apply.
+fn print_summary(path: &Path) {
+    // The file is created by the first `clippy-driver` invocation checking a crate
+    let Ok(summary) = fs::read_to_string(path) else {
+        eprintln!("\nClippy summary: no crate was checked, no data was recorded");
+        return;
+    };
+
+    // Lints emitted by several crates, or several targets of a crate, have multiple lines
+    let mut lints: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+    for line in summary.lines() {
+        let mut fields = line.split('\t');
+        if let (Some(lint), Some(Ok(total)), Some(Ok(fixable))) = (
+            fields.next(),
+            fields.next().map(str::parse::<usize>),
+            fields.next().map(str::parse::<usize>),
+        ) {
+            let counts = lints.entry(lint.to_string()).or_default();
+            counts.0 += total;
+            counts.1 += fixable;
+        }
+    }
+    if lints.is_empty() {
+        eprintln!("\nClippy summary: no warnings");
+        return;
+    }
+
+    let mut lints: Vec<_> = lints.into_iter().collect();
+    lints.sort_by(|(_, (total1, _)), (_, (total2, _))| total2.cmp(total1));
+    let total: usize = lints.iter().map(|(_, (total, _))| total).sum();
+    let fixable: usize = lints.iter().map(|(_, (_, fixable))| fixable).sum();
+    let width = lints.iter().map(|(lint, _)| lint.len()).max().unwrap_or_default();
+
+    eprintln!("\nClippy summary: {total} warnings from {} lints", lints.len());
+    for (lint, (total, fixable)) in &lints {
+        if *fixable == 0 {
+            eprintln!("{total:>6}  {lint}");
+        } else {
+            eprintln!("{total:>6}  {lint:<width$}  ({fixable} fixable)");
+        }
+    }
+    if fixable > 0 {
+        eprintln!(
+            "\n{fixable} of the {total} warnings can be fixed automatically, run `cargo clippy --fix` to apply the fixes"
+        );
+    }
+}
+
 #[must_use]
 pub fn help_message() -> &'static str {
     color_print::cstr!(
@@ -157,10 +281,25 @@ pub fn help_message() -> &'static str {
 
 <green,bold>Common options:</>
     <cyan,bold>--no-deps</>                Run Clippy only on the given crate, without linting the dependencies
+    <cyan,bold>--workspace-deps</>         Also run Clippy on path dependencies outside of the workspace, but not on
+                             registry or git dependencies. Can't be combined with <cyan>RUSTC_WRAPPER</>
     <cyan,bold>--fix</>                    Automatically apply lint suggestions. This flag implies <cyan>--no-deps</> and <cyan>--all-targets</>
+    <cyan,bold>--fix-applicability</> <cyan>[LEVEL]</>
+                             Which suggestions <cyan>--fix</> applies: <cyan>machine-applicable</> (default) or
+                             <cyan>maybe-incorrect</>. This flag implies <cyan>--fix</>
+    <cyan,bold>--fix-only</> <cyan>[LINT]</>        Only apply the suggestions of the given lint or lint group, can be
+                             repeated. This flag implies <cyan>--fix</>
+    <cyan,bold>--suggestion-alternatives</>
+                             Tag each alternative of a suggestion with a stable ID in the JSON
+                             diagnostics, e.g. for IDEs
+    <cyan,bold>--hide</> <cyan>[GROUP]</>           Don't show the warnings of the given lint groups, e.g.
+                             <cyan>pedantic,nursery</>. Denied lints are still shown
+    <cyan,bold>--summary</>                Print the number of warnings per lint and how many of them can be
+                             fixed automatically at the end of the run
     <cyan,bold>-h</>, <cyan,bold>--help</>               Print this message
     <cyan,bold>-V</>, <cyan,bold>--version</>            Print version info and exit
-    <cyan,bold>--explain [LINT]</>         Print the documentation for a given lint
+    <cyan,bold>--explain [LINT]</>         Print the documentation for a given lint, or list the lints of a
+                             group with <cyan>group:[GROUP]</>, e.g. <cyan>group:perf</>
 
 See all options with <cyan,bold>cargo check --help</>.
 
@@ -191,7 +330,7 @@ mod tests {
     #[test]
     fn fix() {
         let args = "cargo clippy --fix".split_whitespace().map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args).unwrap();
         assert_eq!("fix", cmd.cargo_subcommand);
         assert!(!cmd.args.iter().any(|arg| arg.ends_with("unstable-options")));
     }
@@ -199,7 +338,7 @@ mod tests {
     #[test]
     fn fix_implies_no_deps() {
         let args = "cargo clippy --fix".split_whitespace().map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args).unwrap();
         assert!(cmd.clippy_args.iter().any(|arg| arg == "--no-deps"));
     }
 
@@ -208,14 +347,92 @@ mod tests {
         let args = "cargo clippy --fix -- --no-deps"
             .split_whitespace()
             .map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args).unwrap();
         assert_eq!(cmd.clippy_args.iter().filter(|arg| *arg == "--no-deps").count(), 1);
     }
 
+    #[test]
+    fn fix_options_imply_fix() {
+        let args = "cargo clippy --fix-only clippy::uninlined_format_args --fix-applicability=maybe-incorrect"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!("fix", cmd.cargo_subcommand);
+        assert_eq!(cmd.args, ["cargo", "clippy"]);
+        assert!(
+            cmd.clippy_args
+                .iter()
+                .any(|arg| arg == "--fix-only=clippy::uninlined_format_args")
+        );
+        assert!(
+            cmd.clippy_args
+                .iter()
+                .any(|arg| arg == "--fix-applicability=maybe-incorrect")
+        );
+    }
+
+    #[test]
+    fn options_require_value() {
+        for args in [
+            "cargo clippy --fix-only",
+            "cargo clippy --fix-applicability",
+            "cargo clippy --hide",
+        ] {
+            let args = args.split_whitespace().map(ToString::to_string);
+            assert!(ClippyCmd::new(args).is_err());
+        }
+    }
+
+    #[test]
+    fn suggestion_alternatives() {
+        let args = "cargo clippy --suggestion-alternatives --message-format=json"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!("check", cmd.cargo_subcommand);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--message-format=json"]);
+        assert_eq!(cmd.clippy_args, ["--suggestion-alternatives"]);
+    }
+
+    #[test]
+    fn summary() {
+        let args = "cargo clippy --summary -- -W clippy::pedantic"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert!(cmd.summary);
+        assert_eq!("check", cmd.cargo_subcommand);
+        assert_eq!(cmd.args, ["cargo", "clippy"]);
+        assert_eq!(cmd.clippy_args, ["-W", "clippy::pedantic"]);
+    }
+
+    #[test]
+    fn hide() {
+        let args = "cargo clippy --hide pedantic,nursery --hide=restriction --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!("check", cmd.cargo_subcommand);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--all-targets"]);
+        assert_eq!(cmd.clippy_args, ["--hide=pedantic,nursery", "--hide=restriction"]);
+    }
+
+    #[test]
+    fn workspace_deps() {
+        let args = "cargo clippy --workspace-deps --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert!(cmd.workspace_deps);
+        assert_eq!("check", cmd.cargo_subcommand);
+        assert_eq!(cmd.args, ["cargo", "clippy", "--all-targets"]);
+        assert!(cmd.clippy_args.is_empty());
+    }
+
     #[test]
     fn check() {
         let args = "cargo clippy".split_whitespace().map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args).unwrap();
         assert_eq!("check", cmd.cargo_subcommand);
     }
 }
@@ -196,22 +196,48 @@ impl TestContext {
     }
 }
 
+/// Configures Clippy inline in UI tests, each header adds a line to the test's `clippy.toml`, e.g.
+/// `//@clippy-conf: too-many-lines-threshold = 1`
+const CLIPPY_CONF_HEADER: &str = "//@clippy-conf:";
+
 fn run_ui(cx: &TestContext) {
     let mut config = cx.base_config("ui");
-    config
-        .program
-        .envs
-        .push(("CLIPPY_CONF_DIR".into(), Some("tests".into())));
+    // The headers are read in the per file config below, this only makes them known to `ui_test`
+    config.custom_comments.insert("clippy-conf", |_, _, _| {});
 
     ui_test::run_tests_generic(
         vec![config],
         ui_test::default_file_filter,
-        ui_test::default_per_file_config,
+        |config, file_contents| {
+            ui_test::default_per_file_config(config, file_contents);
+            let conf_dir = inline_clippy_conf(config, file_contents.span().file.as_path(), file_contents)
+                .unwrap_or_else(|| "tests".into());
+            config.program.envs.push(("CLIPPY_CONF_DIR".into(), Some(conf_dir)));
+        },
         status_emitter::Text::from(cx.args.format),
     )
     .unwrap();
 }
 
+/// Writes the `//@clippy-conf:` headers of a test to a `clippy.toml` in a directory of its own,
+/// returning that directory. Returns `None` if the test has no such headers.
+fn inline_clippy_conf(config: &Config, path: &Path, contents: &[u8]) -> Option<OsString> {
+    let conf: String = String::from_utf8_lossy(contents)
+        .lines()
+        .filter_map(|line| line.trim_start().strip_prefix(CLIPPY_CONF_HEADER))
+        .flat_map(|line| [line.trim(), "\n"])
+        .collect();
+    if conf.is_empty() {
+        return None;
+    }
+
+    let test = path.strip_prefix(&config.root).unwrap_or(path).with_extension("");
+    let conf_dir = config.out_dir.join("clippy-conf").join(test);
+    fs::create_dir_all(&conf_dir).unwrap();
+    fs::write(conf_dir.join("clippy.toml"), conf).unwrap();
+    Some(conf_dir.into())
+}
+
 fn run_internal_tests(cx: &TestContext) {
     if !RUN_INTERNAL_TESTS {
         return;
@@ -0,0 +1,6 @@
+fn main() {
+    let toto = 42;
+    // The default list is replaced
+    let foo = 42;
+    println!("{toto} {foo}");
+}
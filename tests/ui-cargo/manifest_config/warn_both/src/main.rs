@@ -0,0 +1,5 @@
+fn main() {
+    // Only the configuration of `clippy.toml` is used
+    let toto = 42;
+    println!("{toto}");
+}
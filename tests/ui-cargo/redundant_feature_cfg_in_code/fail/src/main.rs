@@ -0,0 +1,16 @@
+#![warn(clippy::redundant_feature_cfg_in_code)]
+
+#[cfg(feature = "std")]
+fn with_std() {}
+
+#[cfg(feature = "alloc")]
+fn with_alloc() {}
+
+#[cfg(not(feature = "extra"))]
+fn without_extra() {}
+
+fn main() {
+    with_std();
+    with_alloc();
+    without_extra();
+}
@@ -0,0 +1,4 @@
+#![warn(clippy::redundant_feature_cfg_in_code)]
+
+#[cfg(feature = "std")]
+pub fn with_std() {}
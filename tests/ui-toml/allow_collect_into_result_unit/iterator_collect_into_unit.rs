@@ -0,0 +1,17 @@
+#![warn(clippy::iterator_collect_into_unit, clippy::map_collect_result_unit)]
+
+fn check(x: i32) -> Result<(), i32> {
+    if x < 0 { Err(x) } else { Ok(()) }
+}
+
+fn main() -> Result<(), i32> {
+    let v = [1, 2, 3];
+    // Collecting into `()` is still linted
+    v.iter().map(|x| println!("{x}")).collect::<()>();
+    //~^ iterator_collect_into_unit
+
+    // `map_collect_result_unit` is still linted
+    v.iter().map(|&x| check(x)).collect::<Result<(), _>>()?;
+    //~^ map_collect_result_unit
+    [Ok(()), Err(1)].into_iter().collect::<Result<(), i32>>()
+}
@@ -0,0 +1,22 @@
+#![warn(clippy::shadow_same, clippy::shadow_reuse)]
+#![allow(clippy::redundant_locals)]
+
+fn parse(x: &str) -> Result<u32, std::num::ParseIntError> {
+    let x = x.parse()?;
+    Ok(x)
+}
+
+fn reference() {
+    let x = 1;
+    let x = &x;
+}
+
+fn same_type() {
+    let x = 1;
+    let x = x + 1;
+    //~^ shadow_reuse
+    let x = x;
+    //~^ shadow_same
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+//@compile-flags: --crate-name build_script_build -D clippy::unwrap_used
+#![warn(clippy::too_many_lines)]
+
+fn main() {
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    println!("{out_dir}");
+}
@@ -0,0 +1,8 @@
+//@compile-flags: --crate-name not_build_script
+#![warn(clippy::too_many_lines)]
+
+fn main() {
+    //~^ too_many_lines
+    let x = Some(1);
+    println!("{x:?}");
+}
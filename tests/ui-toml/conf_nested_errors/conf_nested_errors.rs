@@ -0,0 +1,4 @@
+//@error-in-other-file: invalid type: integer `42`, expected a string
+//@error-in-other-file: unknown field `brac`, expected `name` or `brace`
+
+fn main() {}
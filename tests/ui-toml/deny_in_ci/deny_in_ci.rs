@@ -0,0 +1,8 @@
+//@revisions: ci local
+//@[ci] rustc-env:CLIPPY_TEST_CI=1
+// `deny-in-ci` takes precedence over the command line
+//@compile-flags: -A clippy::dbg_macro
+
+fn main() {
+    let _ = dbg!(1 + 1);
+}
@@ -0,0 +1,39 @@
+//@aux-build:../../ui/auxiliary/proc_macro_attr.rs
+
+#![warn(clippy::disallowed_attributes)]
+
+extern crate proc_macro_attr;
+
+use proc_macro_attr::fake_main;
+
+#[inline]
+//~^ disallowed_attributes
+fn inlined() {}
+
+#[inline(always)]
+//~^ disallowed_attributes
+fn always_inlined() {}
+
+#[rustfmt::skip]
+//~^ disallowed_attributes
+fn skipped() {}
+
+#[cold]
+fn cold() {}
+
+#[derive(Debug)]
+//~^ disallowed_attributes
+struct S;
+
+#[derive(Clone)]
+struct T;
+
+#[fake_main]
+//~^ disallowed_attributes
+async fn fake() {}
+
+#[allow(clippy::disallowed_attributes)]
+#[inline]
+fn allowed() {}
+
+fn main() {}
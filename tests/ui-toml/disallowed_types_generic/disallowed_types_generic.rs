@@ -0,0 +1,42 @@
+#![warn(clippy::disallowed_types)]
+
+use std::collections::HashMap;
+
+struct Config {
+    settings: HashMap<String, String>,
+    //~^ disallowed_types
+    ids: HashMap<String, u32>,
+}
+
+fn grid(_: Vec<Vec<u8>>) {}
+//~^ disallowed_types
+
+fn flat(_: Vec<u8>, _: Vec<Option<Vec<u8>>>) {}
+
+fn byte() -> Option<u8> {
+    //~^ disallowed_types
+    None
+}
+
+fn result() -> Result<(), String> {
+    //~^ disallowed_types
+    Ok(())
+}
+
+fn main() {
+    let _: HashMap<String, String> = HashMap::new();
+    //~^ disallowed_types
+    let _ = HashMap::<String, String>::new();
+    //~^ disallowed_types
+    let _: std::collections::HashMap<std::string::String, std::string::String> = Default::default();
+    //~^ disallowed_types
+    let _: HashMap<String, _> = HashMap::<_, Vec<u8>>::new();
+    let _: Option<u16> = None;
+    let _: Option<_> = Some(1u8);
+    //~^ disallowed_types
+}
+
+type Name = String;
+
+fn aliased(_: HashMap<Name, Name>) {}
+//~^ disallowed_types
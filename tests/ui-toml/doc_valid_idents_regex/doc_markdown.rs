@@ -0,0 +1,13 @@
+#![warn(clippy::doc_markdown)]
+
+/// Runs on ChromeOS and RiscOS.
+fn matched() {}
+
+/// FooBar and FooBaz are allowed, FooBarBaz is not.
+//~^ doc_markdown
+fn whole_word() {}
+
+/// OAuth is still on the default list.
+fn default_name() {}
+
+fn main() {}
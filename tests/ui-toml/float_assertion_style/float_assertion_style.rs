@@ -0,0 +1,19 @@
+//@compile-flags: --test
+//@no-rustfix
+#![warn(clippy::imprecise_float_equality_in_tests)]
+
+fn compute() -> f64 {
+    0.1 + 0.2
+}
+
+#[test]
+fn approx() {
+    assert_eq!(compute(), 0.3);
+    //~^ imprecise_float_equality_in_tests
+    assert_ne!(compute(), 0.4);
+    //~^ imprecise_float_equality_in_tests
+    assert_eq!(compute(), 0.3, "sum");
+    //~^ imprecise_float_equality_in_tests
+    debug_assert_eq!(compute(), 0.3);
+    //~^ imprecise_float_equality_in_tests
+}
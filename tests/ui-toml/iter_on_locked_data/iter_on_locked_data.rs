@@ -0,0 +1,24 @@
+#![warn(clippy::iter_on_locked_data)]
+
+use std::sync::Mutex;
+
+struct Database;
+
+impl Database {
+    fn query(&self, _: u32) {}
+}
+
+fn configured(ids: &Mutex<Vec<u32>>, db: &Database) {
+    for id in ids.lock().unwrap().iter() {
+        //~^ iter_on_locked_data
+        db.query(*id);
+    }
+}
+
+fn default_not_configured(ids: &Mutex<Vec<u64>>) {
+    for id in ids.lock().unwrap().iter() {
+        std::thread::sleep(std::time::Duration::from_millis(*id));
+    }
+}
+
+fn main() {}
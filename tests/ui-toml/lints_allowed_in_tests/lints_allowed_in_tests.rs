@@ -0,0 +1,27 @@
+//@compile-flags: --test
+#![warn(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing, clippy::panic)]
+#![allow(clippy::unnecessary_literal_unwrap)]
+
+fn not_a_test(x: &[u8]) -> u8 {
+    let _ = Some(0).unwrap();
+    let _ = Some(0).expect("");
+    x[0]
+}
+
+fn main() {
+    not_a_test(&[1]);
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_fn() {
+        let x: &[u8] = &[1];
+        let _ = Some(0).unwrap();
+        let _ = Some(0).expect("");
+        let _ = x[0];
+        let _ = &x[1..];
+        // `panic` is not listed in the configuration
+        panic!("oops");
+    }
+}
@@ -0,0 +1,39 @@
+//@compile-flags: --test
+#![warn(
+    clippy::as_conversions,
+    clippy::cast_lossless,
+    clippy::cast_possible_truncation,
+    clippy::else_if_without_else
+)]
+
+fn not_a_test(x: u8, y: u32) {
+    let _ = x as u32;
+    let _ = y as u8;
+    if x == 0 {
+        let _ = x;
+    } else if y == 0 {
+        let _ = y;
+    }
+}
+
+fn main() {
+    not_a_test(1, 1);
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_fn() {
+        let x = 1u8;
+        let y = 1u32;
+        let _ = x as u32;
+        // `cast_possible_truncation` is not listed in the configuration
+        let _ = y as u8;
+        // `else_if_without_else` is an early lint
+        if x == 0 {
+            let _ = x;
+        } else if y == 0 {
+            let _ = y;
+        }
+    }
+}
@@ -0,0 +1,17 @@
+#![warn(clippy::module_inception_depth)]
+#![allow(clippy::module_inception, dead_code)]
+
+mod a {
+    pub struct S;
+
+    mod a {
+        //~^ module_inception_depth
+        pub struct S;
+    }
+}
+
+mod b {
+    pub struct S;
+}
+
+fn main() {}
@@ -18,7 +18,23 @@ fn above_limit() {
     }
 }
 
+fn get_unwrap_below_limit(v: &[u32]) {
+    let first = v.get(1).unwrap();
+    //~^ ERROR: these bindings can be a slice pattern to avoid `get(..).unwrap()`
+    let last = v.get(7).unwrap();
+    println!("{first} {last}");
+}
+
+fn get_unwrap_above_limit(v: &[u32]) {
+    // This will not be linted as 8 is above the limit
+    let first = v.get(1).unwrap();
+    let last = v.get(8).unwrap();
+    println!("{first} {last}");
+}
+
 fn main() {
     below_limit();
     above_limit();
+    get_unwrap_below_limit(&[0; 9]);
+    get_unwrap_above_limit(&[0; 9]);
 }
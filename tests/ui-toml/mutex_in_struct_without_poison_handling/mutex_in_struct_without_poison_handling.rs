@@ -0,0 +1,13 @@
+#![warn(clippy::mutex_in_struct_without_poison_handling)]
+
+use std::sync::Mutex;
+
+struct State {
+    count: Mutex<u32>,
+}
+
+fn main() {
+    let state = State { count: Mutex::new(0) };
+    *state.count.lock().unwrap() += 1;
+    //~^ mutex_in_struct_without_poison_handling
+}
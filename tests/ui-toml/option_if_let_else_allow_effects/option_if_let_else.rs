@@ -0,0 +1,11 @@
+#![warn(clippy::option_if_let_else)]
+
+fn main() {
+    let opt = Some("hello");
+    let _ = if let Some(s) = opt {
+        //~^ option_if_let_else
+        vec![s.to_string()]
+    } else {
+        Vec::new()
+    };
+}
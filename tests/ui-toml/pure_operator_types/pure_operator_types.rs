@@ -0,0 +1,33 @@
+#![warn(clippy::no_effect)]
+
+use std::ops::{Add, Neg};
+
+#[derive(Clone, Copy)]
+struct Meters(u32);
+
+impl Add for Meters {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Meters(self.0 + rhs.0)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Logged(i32);
+
+impl Neg for Logged {
+    type Output = Self;
+    fn neg(self) -> Self {
+        println!("negated");
+        Logged(-self.0)
+    }
+}
+
+fn main() {
+    let m = Meters(1);
+    m + m;
+    //~^ no_effect
+
+    let l = Logged(1);
+    -l;
+}
@@ -0,0 +1,26 @@
+#![warn(clippy::slow_string_comparisons)]
+
+use std::fmt;
+use std::path::Path;
+
+pub struct Symbol(&'static str);
+
+impl Symbol {
+    pub fn as_str(&self) -> &str {
+        self.0
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+fn main() {
+    let sym = Symbol("foo");
+    let _ = sym.to_string() == "foo";
+
+    // Not configured
+    let _ = Path::new("foo").to_string_lossy() == "foo";
+}
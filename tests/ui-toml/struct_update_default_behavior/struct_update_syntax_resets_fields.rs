@@ -0,0 +1,38 @@
+#![warn(clippy::struct_update_syntax_resets_fields)]
+#![allow(clippy::needless_update)]
+
+#[derive(Default)]
+struct Point {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+#[derive(Default)]
+struct Settings {
+    pub verbose: bool,
+    cache: Vec<u8>,
+}
+
+fn main() {
+    let _ = Point {
+        x: 1,
+        ..Default::default()
+    };
+    let _ = Settings {
+        verbose: true,
+        ..Default::default()
+    };
+    // All the fields are listed
+    let _ = Point {
+        x: 1,
+        y: 2,
+        z: 3,
+        ..Default::default()
+    };
+    // Not a local struct
+    let _ = std::ops::Range {
+        start: 1,
+        ..Default::default()
+    };
+}
@@ -0,0 +1,33 @@
+#![warn(clippy::too_many_arguments)]
+
+struct S;
+
+impl S {
+    fn new(a: u8, b: u8, c: u8, d: u8) -> Self {
+        S
+    }
+
+    fn with_all(a: u8, b: u8, c: u8, d: u8) -> Self {
+        S
+    }
+
+    fn method(&self, a: u8, b: u8, c: u8) {}
+
+    fn too_many(&self, a: u8, b: u8, c: u8, d: u8) {}
+    //~^ too_many_arguments
+}
+
+#[rustfmt::skip]
+fn skipped(a: u8, b: u8, c: u8, d: u8) {}
+
+fn renew(a: u8, b: u8, c: u8, d: u8) {}
+//~^ too_many_arguments
+
+trait T {
+    fn with_default(&self, a: u8, b: u8, c: u8, d: u8) {}
+
+    fn required(&self, a: u8, b: u8, c: u8, d: u8);
+    //~^ too_many_arguments
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+#![warn(clippy::unjoined_thread_handle)]
+
+use std::thread;
+
+fn work() {}
+
+mod workers {
+    fn detach() {
+        let _ = std::thread::spawn(super::work);
+    }
+
+    mod pool {
+        fn detach() {
+            let _ = std::thread::spawn(super::super::work);
+        }
+    }
+}
+
+mod workers_extra {
+    fn detach() {
+        let _ = std::thread::spawn(super::work);
+        //~^ unjoined_thread_handle
+    }
+}
+
+mod server {
+    fn detach() {
+        let _ = std::thread::spawn(super::work);
+        //~^ unjoined_thread_handle
+    }
+
+    mod background {
+        fn detach() {
+            let _ = std::thread::spawn(super::super::work);
+        }
+    }
+}
+
+fn detach() {
+    thread::spawn(work);
+    //~^ unjoined_thread_handle
+}
+
+fn main() {}
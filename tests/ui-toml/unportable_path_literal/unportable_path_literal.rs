@@ -0,0 +1,9 @@
+#![warn(clippy::unportable_path_literal)]
+
+use std::fs::File;
+use std::path::Path;
+
+fn main() {
+    let _ = Path::new("data\\input.txt");
+    let _ = File::open("C:\\Windows\\System32");
+}
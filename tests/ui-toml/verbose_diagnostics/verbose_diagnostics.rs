@@ -0,0 +1,9 @@
+#![warn(clippy::eq_op, clippy::needless_return)]
+
+fn f() -> bool {
+    return true;
+}
+
+fn main() {
+    let _ = 1 == 1;
+}
@@ -0,0 +1,129 @@
+#![warn(clippy::arc_mutex_over_channel_heuristic)]
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+struct Jobs {
+    queue: Arc<Mutex<Vec<u32>>>,
+    //~^ arc_mutex_over_channel_heuristic
+    results: Arc<Mutex<HashMap<u32, u32>>>,
+    //~^ arc_mutex_over_channel_heuristic
+    // Don't lint: also read from
+    log: Arc<Mutex<Vec<String>>>,
+    // Don't lint: filled and drained in the same function
+    scratch: Arc<Mutex<Vec<u32>>>,
+    events: Arc<Mutex<Vec<u32>>>,
+    //~^ arc_mutex_over_channel_heuristic
+}
+
+impl Jobs {
+    fn submit(&self, job: u32) {
+        self.queue.lock().unwrap().push(job);
+        self.log.lock().unwrap().push(format!("submitted {job}"));
+    }
+
+    fn run(&self) {
+        let results = Arc::clone(&self.results);
+        let jobs: Vec<u32> = self.queue.lock().unwrap().drain(..).collect();
+        thread::spawn(move || {
+            for job in jobs {
+                results.lock().expect("poisoned").insert(job, job * 2);
+            }
+        });
+        let mut log = self.log.lock().unwrap();
+        log.clear();
+    }
+
+    fn collect(&self) -> Vec<(u32, u32)> {
+        let mut results = self.results.lock().unwrap();
+        results.drain().collect()
+    }
+
+    fn log_len(&self) -> usize {
+        self.log.lock().unwrap().len()
+    }
+
+    fn scratch(&self) {
+        let mut scratch = self.scratch.lock().unwrap();
+        scratch.push(1);
+        scratch.pop();
+    }
+
+    fn record(&self, event: u32) {
+        let events = self.events.clone();
+        thread::spawn(move || events.lock().unwrap().push(event));
+    }
+
+    fn take_events(&self) -> Vec<u32> {
+        self.events.lock().unwrap().drain(..).collect()
+    }
+}
+
+// Used before the struct is defined
+fn fill(tasks: &Tasks) {
+    tasks.pending.lock().unwrap().push_back(1);
+}
+
+fn empty(tasks: &Tasks) -> Option<u32> {
+    tasks.pending.lock().unwrap().pop_front()
+}
+
+struct Tasks {
+    pending: Arc<Mutex<VecDeque<u32>>>,
+    //~^ arc_mutex_over_channel_heuristic
+}
+
+fn inspect(items: &Arc<Mutex<Vec<u32>>>) {}
+
+// Don't lint: the collections escape
+struct Escaping {
+    passed: Arc<Mutex<Vec<u32>>>,
+    cloned_and_passed: Arc<Mutex<Vec<u32>>>,
+    guard_returned: Arc<Mutex<Vec<u32>>>,
+}
+
+impl Escaping {
+    fn add(&self) {
+        self.passed.lock().unwrap().push(1);
+        self.cloned_and_passed.lock().unwrap().push(1);
+        self.guard_returned.lock().unwrap().push(1);
+    }
+
+    fn take(&self) {
+        self.passed.lock().unwrap().pop();
+        self.cloned_and_passed.lock().unwrap().pop();
+        self.guard_returned.lock().unwrap().pop();
+    }
+
+    fn passed(&self) {
+        inspect(&self.passed);
+    }
+
+    fn cloned_and_passed(&self) {
+        let items = Arc::clone(&self.cloned_and_passed);
+        inspect(&items);
+    }
+
+    fn guard_returned(&self) -> std::sync::MutexGuard<'_, Vec<u32>> {
+        let guard = self.guard_returned.lock().unwrap();
+        guard
+    }
+}
+
+// Don't lint: exported
+pub struct Exported {
+    pub queue: Arc<Mutex<Vec<u32>>>,
+}
+
+impl Exported {
+    pub fn submit(&self, job: u32) {
+        self.queue.lock().unwrap().push(job);
+    }
+
+    pub fn take(&self) -> Option<u32> {
+        self.queue.lock().unwrap().pop()
+    }
+}
+
+fn main() {}
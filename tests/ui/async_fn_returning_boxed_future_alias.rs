@@ -0,0 +1,71 @@
+#![warn(clippy::async_fn_returning_boxed_future_alias)]
+#![allow(clippy::manual_async_fn)]
+
+extern crate futures;
+use futures::future::{BoxFuture, LocalBoxFuture};
+
+trait Fetch {
+    fn fetch(&self) -> BoxFuture<'_, u32> {
+        Box::pin(async move { 42 })
+    }
+    //~^^^ async_fn_returning_boxed_future_alias
+}
+
+struct S(u32);
+
+impl Fetch for S {
+    fn fetch(&self) -> BoxFuture<'_, u32> {
+        Box::pin(async move { self.0 })
+    }
+    //~^^^ async_fn_returning_boxed_future_alias
+}
+
+impl S {
+    fn get(&self) -> BoxFuture<'_, u32> {
+        //~^ async_fn_returning_boxed_future_alias
+        Box::pin(async move { self.0 })
+    }
+}
+
+fn local(x: &u32) -> LocalBoxFuture<'_, u32> {
+    //~^ async_fn_returning_boxed_future_alias
+    Box::pin(async move { *x })
+}
+
+fn unit() -> BoxFuture<'static, ()> {
+    //~^ async_fn_returning_boxed_future_alias
+    Box::pin(async {})
+}
+
+// The box is required for recursion
+fn recursive(n: u32) -> BoxFuture<'static, u32> {
+    Box::pin(async move { if n == 0 { 0 } else { recursive(n - 1).await + 1 } })
+}
+
+fn not_only_the_future() -> BoxFuture<'static, u32> {
+    let x = 1;
+    Box::pin(async move { x })
+}
+
+fn not_an_async_block() -> BoxFuture<'static, u32> {
+    Box::pin(futures::future::ready(1))
+}
+
+fn not_an_alias() -> std::pin::Pin<Box<dyn std::future::Future<Output = u32> + Send>> {
+    Box::pin(async { 1 })
+}
+
+#[clippy::msrv = "1.74"]
+trait Old {
+    fn fetch(&self) -> BoxFuture<'_, u32> {
+        Box::pin(async move { 42 })
+    }
+}
+
+#[clippy::msrv = "1.74"]
+fn old(x: &u32) -> BoxFuture<'_, u32> {
+    //~^ async_fn_returning_boxed_future_alias
+    Box::pin(async move { *x })
+}
+
+fn main() {}
@@ -500,3 +500,23 @@ fn issue12721() {
     (255 % 999999u64) as u8;
     //~^ ERROR: casting `u64` to `u8` may truncate the value
 }
+
+fn interval_analysis(x: u32, y: i64, z: usize) {
+    // Don't lint, the values always fit.
+    (x % 100) as u8;
+    x.min(255) as u8;
+    ((x >> 28) + 100) as u8;
+    (y % 100) as i8;
+    (y.clamp(-1000, 1000) / 10) as i8;
+    let small = x & 0xff;
+    small as u8;
+    (z % 1000) as u16;
+    (y.signum() * 100) as i8;
+
+    (x % 1000) as u8;
+    //~^ ERROR: casting `u32` to `u8` may truncate the value
+    (y.clamp(0, 1000) / 2) as i8;
+    //~^ ERROR: casting `i64` to `i8` may truncate the value
+    (y % 1000) as i8;
+    //~^ ERROR: casting `i64` to `i8` may truncate the value
+}
@@ -0,0 +1,61 @@
+#![warn(clippy::char_arithmetic_as_u8)]
+#![allow(clippy::manual_is_ascii_check, clippy::manual_range_contains)]
+
+fn unchecked(c: char, n: u32) {
+    let _ = c as u8 - b'a';
+    //~^ char_arithmetic_as_u8
+    let _ = c as u8 - b'0';
+    //~^ char_arithmetic_as_u8
+    let _ = (n as u8 + b'0') as char;
+    //~^ char_arithmetic_as_u8
+    let _ = (b'A' + n as u8) as char;
+    //~^ char_arithmetic_as_u8
+}
+
+fn wrong_check(c: char) {
+    if c.is_ascii_alphanumeric() {
+        let _ = c as u8 - b'0';
+        //~^ char_arithmetic_as_u8
+    }
+}
+
+fn checked(c: char, n: u32) {
+    if c.is_ascii_lowercase() {
+        let _ = c as u8 - b'a';
+    }
+    if c >= 'A' && c <= 'Z' {
+        let _ = c as u8 - b'A';
+    }
+    if ('0'..='9').contains(&c) {
+        let _ = c as u8 - b'0';
+    }
+    if matches!(c, 'a'..='f') {
+        let _ = c as u8 - b'a';
+    }
+    match c {
+        '0'..='9' => {
+            let _ = c as u8 - b'0';
+        },
+        _ if c.is_ascii_uppercase() => {
+            let _ = c as u8 - b'A';
+        },
+        _ => {},
+    }
+    let _ = ((n % 10) as u8 + b'0') as char;
+    let _ = (b'a' + (n % 26) as u8) as char;
+}
+
+fn early_return(c: char) -> u8 {
+    if !c.is_ascii_digit() {
+        return 0;
+    }
+    c as u8 - b'0'
+}
+
+fn other_literals(c: char) {
+    // Not the start of an ASCII range
+    let _ = c as u8 - b'x';
+    let _ = c as u8 - 32;
+}
+
+fn main() {}
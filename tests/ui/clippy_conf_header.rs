@@ -0,0 +1,19 @@
+//@clippy-conf: max-fn-params-bools = 1
+//@clippy-conf: max-struct-bools = 1
+#![warn(clippy::fn_params_excessive_bools, clippy::struct_excessive_bools)]
+
+fn f(_: bool) {}
+fn g(_: bool, _: bool) {}
+//~^ fn_params_excessive_bools
+
+struct S {
+    a: bool,
+}
+
+struct T {
+    //~^ struct_excessive_bools
+    a: bool,
+    b: bool,
+}
+
+fn main() {}
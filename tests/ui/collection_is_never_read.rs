@@ -236,3 +236,55 @@ fn issue11783() {
     let mut users: Vec<Sender> = vec![];
     users.retain(|user| user.send("hello".to_string()).is_ok());
 }
+
+mod fields {
+    struct Log {
+        entries: Vec<String>,
+        //~^ ERROR: collection field is never read
+        seen: std::collections::HashSet<u32>,
+        name: String,
+    }
+
+    impl Log {
+        fn new() -> Self {
+            Self {
+                entries: Vec::new(),
+                seen: Default::default(),
+                name: String::new(),
+            }
+        }
+
+        fn add(&mut self, entry: String) {
+            self.entries.push(entry);
+            self.seen.insert(1);
+            self.name.push('!');
+        }
+
+        fn has_seen(&self, id: u32) -> bool {
+            self.seen.contains(&id)
+        }
+
+        fn name(&self) -> &str {
+            let Self { name, .. } = self;
+            name
+        }
+    }
+
+    struct Config {
+        flags: Vec<u32>,
+        name: String,
+    }
+
+    impl Config {
+        fn add(&mut self, flag: u32) {
+            self.flags.push(flag);
+        }
+
+        fn renamed(self) -> Self {
+            Self {
+                name: String::from("renamed"),
+                ..self
+            }
+        }
+    }
+}
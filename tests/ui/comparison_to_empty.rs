@@ -41,4 +41,21 @@ fn main() {
     let v = vec![0];
     let _ = v.eq(&[]);
     let _ = v.ne(&[]);
+
+    // Empty string idioms
+    let s = String::from("a");
+    let _ = s.trim() == "";
+    let _ = s.chars().count() == 0;
+    let _ = s.chars().count() > 0;
+    let _ = s.chars().count() < 1;
+    let opt = Some(String::new());
+    let _ = opt == Some(String::new());
+    let _ = opt != Some(String::new());
+    let opt = Some("a");
+    let _ = opt == Some("");
+
+    // Allow other counts and comparisons
+    let _ = s.chars().count() == 1;
+    let _ = s.split(',').count() == 0;
+    let _ = opt == Some("a");
 }
@@ -255,4 +255,47 @@ mod issue12159 {
     }
 }
 
+mod constrained_by_context {
+    use std::collections::HashMap;
+
+    #[repr(i32)]
+    enum Discriminant {
+        // Should NOT lint enum discriminants.
+        A = 1,
+    }
+
+    fn test(x: i32, map: HashMap<i32, i32>, o: Option<i32>) {
+        // Should NOT lint literals whose type is given by the other operand.
+        let _ = x + 1;
+        let _ = 2 * x - 1;
+        let _ = x == 1;
+        let mut y = x;
+        y = 2;
+        y += 1;
+
+        // Should NOT lint indexes.
+        let _ = map[&1];
+
+        // Should NOT lint patterns matched against a typed value.
+        match x {
+            1 => {},
+            2..=5 => {},
+            _ => {},
+        }
+        if let Some(3) = o {}
+
+        // Should NOT lint arguments of type `Self` of methods called on a typed receiver.
+        let _ = x.max(1);
+
+        // Should lint literals whose type only comes from other literals.
+        let _ = 1 + 2;
+        // Should lint shift amounts, which don't get the type of the shifted value.
+        let _ = x << 1;
+        // Should lint assignments to locals whose type comes from the assigned literal.
+        let mut z;
+        z = 2;
+        let _ = z;
+    }
+}
+
 fn main() {}
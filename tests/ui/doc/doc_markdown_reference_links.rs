@@ -0,0 +1,40 @@
+#![warn(clippy::doc_markdown)]
+
+/// See [foo_bar] for details.
+//~^ doc_markdown
+///
+/// [foo_bar]: crate::foo_bar
+fn shortcut() {}
+
+/// See [foo_bar][] for details.
+//~^ doc_markdown
+///
+/// [foo_bar]: crate::foo_bar
+fn collapsed() {}
+
+/// See [foo_bar][link] for details.
+//~^ doc_markdown
+///
+/// [link]: crate::foo_bar
+fn full() {}
+
+/// See [foo_bar] for details.
+//~^ doc_markdown
+fn intra_doc_link() {}
+
+/// | Name | Description |
+/// |------|-------------|
+/// | foo_bar | Does things |
+//~^ doc_markdown
+fn table() {}
+
+/// See [foo_bar and baz_qux] for details.
+//~^ doc_markdown
+//~| doc_markdown
+///
+/// [foo_bar and baz_qux]: crate::foo_bar
+fn two_words() {}
+
+fn foo_bar() {}
+
+fn main() {}
@@ -0,0 +1,56 @@
+#![warn(clippy::duplicate_bounds_across_impls)]
+
+use std::fmt::Debug;
+
+struct Memory;
+struct Disk;
+
+trait Store<T> {
+    //~^ duplicate_bounds_across_impls
+    fn store(&self, value: T);
+}
+
+impl<T> Store<T> for Memory
+where
+    T: Clone + Debug + Default,
+{
+    fn store(&self, _: T) {}
+}
+
+impl<U: Clone + Debug + Default + PartialEq> Store<U> for Disk {
+    fn store(&self, _: U) {}
+}
+
+trait Convert<T>
+where
+    T: Clone + Debug + Default,
+{
+    //~^^^^ duplicate_bounds_across_impls
+}
+
+impl<T> Convert<T> for Memory where T: Clone + Debug + Default {}
+
+impl<T> Convert<T> for Disk where T: Clone + Debug + Default {}
+
+// Not enough bounds
+trait Short<T> {}
+impl<T: Clone + Debug> Short<T> for Memory {}
+impl<T: Clone + Debug> Short<T> for Disk {}
+
+// Not every impl has the bounds
+trait Partial<T> {}
+impl<T: Clone + Debug + Default> Partial<T> for Memory {}
+impl<T> Partial<T> for Disk {}
+
+// A single impl
+trait Single<T> {}
+impl<T: Clone + Debug + Default> Single<T> for Memory {}
+
+// The bounds aren't on a parameter of the trait
+trait Other {}
+struct Wrapper<T>(T);
+struct Boxed<T>(Box<T>);
+impl<T: Clone + Debug + Default> Other for Wrapper<T> {}
+impl<T: Clone + Debug + Default> Other for Boxed<T> {}
+
+fn main() {}
@@ -190,4 +190,20 @@ fn issue12489(map: &mut HashMap<u64, u64>) -> Option<()> {
     Some(())
 }
 
+fn local_import(m: &mut HashMap<u64, u64>) {
+    use std::collections::hash_map::Entry;
+
+    if !m.contains_key(&1) {
+        m.insert(1, 1);
+        foo();
+    }
+}
+
+fn generic_named_entry<Entry: Copy>(m: &mut HashMap<u64, Entry>, v: Entry) {
+    if !m.contains_key(&1) {
+        m.insert(1, v);
+        foo();
+    }
+}
+
 fn main() {}
@@ -0,0 +1,54 @@
+#![warn(clippy::map_entry)]
+
+use std::collections::HashMap;
+
+struct Registry {
+    ids: HashMap<String, u32>,
+    next: u32,
+}
+
+impl Registry {
+    fn default_id(&self) -> u32 {
+        self.next
+    }
+
+    fn intern(&mut self, name: String) {
+        // A closure calling `default_id` would borrow `self` while `self.ids` is borrowed
+        if !self.ids.contains_key(&name) {
+            self.ids.insert(name, self.default_id());
+        }
+    }
+
+    fn intern_field(&mut self, name: String) {
+        // `self.next` is borrowed separately from `self.ids`
+        if !self.ids.contains_key(&name) {
+            self.ids.insert(name, self.next);
+        }
+    }
+}
+
+fn intern_in_closure() {
+    let f = |reg: &mut Registry, name: String| {
+        if !reg.ids.contains_key(&name) {
+            reg.ids.insert(name, reg.default_id());
+        }
+    };
+    // The `if` isn't a statement, so the value can't be computed before it
+    let g = |reg: &mut Registry, name: String| {
+        let () = if !reg.ids.contains_key(&name) {
+            reg.ids.insert(name, reg.default_id());
+        };
+    };
+}
+
+impl Registry {
+    fn intern_shadowed(&mut self, name: String, value: u32) {
+        // `value` is already used in the body
+        if !self.ids.contains_key(&name) {
+            self.ids.insert(name, self.default_id());
+        }
+        self.next = value;
+    }
+}
+
+fn main() {}
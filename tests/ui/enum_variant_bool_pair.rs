@@ -0,0 +1,111 @@
+#![warn(clippy::enum_variant_bool_pair)]
+#![allow(clippy::match_like_matches_macro, dead_code)]
+
+enum Mode {
+    On,
+    Off,
+}
+
+fn is_on(mode: Mode) -> bool {
+    match mode {
+        Mode::On => true,
+        Mode::Off => false,
+    }
+}
+
+enum Answer {
+    No,
+    Yes,
+}
+
+fn is_yes(answer: &Answer) -> bool {
+    matches!(answer, Answer::Yes)
+}
+
+// Don't lint: used for control flow
+enum State {
+    Enabled,
+    Disabled,
+}
+
+fn run(state: State) -> bool {
+    if let State::Disabled = state {
+        return false;
+    }
+    match state {
+        State::Enabled => true,
+        State::Disabled => false,
+    }
+}
+
+// Don't lint: matched to produce something other than a bool
+enum Toggle {
+    True,
+    False,
+}
+
+fn describe(toggle: Toggle) -> &'static str {
+    match toggle {
+        Toggle::True => "true",
+        Toggle::False => "false",
+    }
+}
+
+// Don't lint: not a boolean pair
+enum Direction {
+    Left,
+    Right,
+}
+
+fn is_left(dir: Direction) -> bool {
+    matches!(dir, Direction::Left)
+}
+
+// Don't lint: exported
+pub enum Switch {
+    On,
+    Off,
+}
+
+pub fn is_switched_on(switch: Switch) -> bool {
+    matches!(switch, Switch::On)
+}
+
+// The enums are used before they are defined
+fn is_active(activation: Activation) -> bool {
+    match activation {
+        Activation::Enable => true,
+        Activation::Disable => false,
+    }
+}
+
+enum Activation {
+    Enable,
+    Disable,
+}
+
+// Don't lint: used for control flow before it is defined
+fn check(flag: Flag) -> bool {
+    if let Flag::No = flag {
+        return false;
+    }
+    match flag {
+        Flag::Yes => true,
+        Flag::No => false,
+    }
+}
+
+enum Flag {
+    Yes,
+    No,
+}
+
+fn main() {
+    is_on(Mode::Off);
+    is_yes(&Answer::No);
+    run(State::Enabled);
+    describe(Toggle::False);
+    is_left(Direction::Right);
+    is_active(Activation::Enable);
+    check(Flag::Yes);
+}
@@ -6,6 +6,7 @@
     clippy::needless_pass_by_value,
     clippy::no_effect,
     clippy::option_map_unit_fn,
+    clippy::ptr_arg,
     clippy::redundant_closure_call,
     clippy::uninlined_format_args,
     clippy::useless_vec,
@@ -0,0 +1,29 @@
+#![crate_type = "lib"]
+#![warn(clippy::exit_in_library_code)]
+
+pub fn load(path: &str) -> String {
+    match std::fs::read_to_string(path) {
+        Ok(s) => s,
+        Err(_) => std::process::exit(1),
+    }
+}
+
+pub fn check(ok: bool) {
+    if !ok {
+        std::process::abort();
+    }
+}
+
+pub fn main() {
+    std::process::exit(0);
+}
+
+#[allow(clippy::exit_in_library_code)]
+pub fn allowed() {
+    std::process::exit(2);
+}
+
+#[warn(clippy::exit)]
+pub fn both() {
+    std::process::exit(3);
+}
@@ -0,0 +1,51 @@
+#![warn(clippy::explicit_deref_in_closure_for_copied)]
+
+fn main() {
+    let v = [1, 2, 3];
+    let pairs = [(1, 'a'), (2, 'b')];
+    let strings = [String::from("a")];
+    let named = [(String::from("a"), 1)];
+
+    let _: Vec<i32> = v.iter().filter_map(|x| Some(*x)).collect();
+    //~^ explicit_deref_in_closure_for_copied
+    let _: Vec<i32> = v.iter().flat_map(|&x| Some(x)).collect();
+    //~^ explicit_deref_in_closure_for_copied
+    let _: Vec<String> = strings.iter().filter_map(|s| Some(s.clone())).collect();
+    //~^ explicit_deref_in_closure_for_copied
+    let _: Vec<(i32, char)> = pairs.iter().map(|(a, b)| (*a, *b)).collect();
+    //~^ explicit_deref_in_closure_for_copied
+    let _: Vec<(i32, char)> = pairs.iter().map(|&(a, b)| (a, b)).collect();
+    //~^ explicit_deref_in_closure_for_copied
+    let _: Vec<(String, i32)> = named.iter().map(|(s, n)| (s.clone(), *n)).collect();
+    //~^ explicit_deref_in_closure_for_copied
+    let _: Vec<i32> = v.iter().filter(|&&x| x > 1).copied().collect();
+    //~^ explicit_deref_in_closure_for_copied
+    let _: Vec<i32> = v.iter().filter(|&&x| x > 1).cloned().collect();
+    //~^ explicit_deref_in_closure_for_copied
+
+    // Not a copy of the element
+    let _: Vec<(char, i32)> = pairs.iter().map(|&(a, b)| (b, a)).collect();
+    let _: Vec<i32> = pairs.iter().map(|(a, _)| *a).collect();
+    let _: Vec<i32> = v.iter().filter_map(|x| x.checked_sub(1)).collect();
+    let refs = [(&1, 2)];
+    let _: Vec<(i32, i32)> = refs.iter().map(|&(a, b)| (*a, b)).collect();
+
+    // Already filtering the values
+    let _: Vec<i32> = v.iter().copied().filter(|&x| x > 1).collect();
+    let _: Vec<i32> = v.iter().filter(|x| **x > 1).copied().collect();
+}
+
+#[clippy::msrv = "1.35"]
+fn msrv_1_35() {
+    let v = [1, 2, 3];
+    let _: Vec<i32> = v.iter().filter_map(|x| Some(*x)).collect();
+    //~^ explicit_deref_in_closure_for_copied
+}
+
+#[allow(clippy::explicit_deref_in_closure_for_copied)]
+#[warn(clippy::flat_map_option)]
+fn allowed() {
+    let v = [1, 2, 3];
+    let _: Vec<i32> = v.iter().flat_map(|x| Some(*x)).collect();
+    //~^ flat_map_option
+}
@@ -0,0 +1,74 @@
+#![warn(clippy::field_reassign_outside_constructor)]
+
+struct Config {
+    name: String,
+    verbose: bool,
+    retries: u32,
+}
+
+impl Config {
+    fn new() -> Self {
+        Self {
+            name: String::new(),
+            verbose: false,
+            retries: 3,
+        }
+    }
+
+    fn named(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            ..Self::new()
+        }
+    }
+
+    fn boxed() -> Box<Self> {
+        Box::new(Self::new())
+    }
+}
+
+mod private {
+    pub struct Hidden {
+        pub visible: u32,
+        hidden: u32,
+    }
+
+    impl Hidden {
+        pub(super) fn new() -> Self {
+            Self { visible: 0, hidden: 0 }
+        }
+    }
+}
+
+fn make_config() -> Config {
+    Config::new()
+}
+
+fn main() {
+    let mut a = Config::new();
+    a.verbose = true;
+    //~^ field_reassign_outside_constructor
+    a.retries = 5;
+
+    let mut b = Config::named("b");
+    b.retries = 1;
+    //~^ field_reassign_outside_constructor
+
+    // No lint: the constructor returns a `Box`
+    let mut c = Config::boxed();
+    c.verbose = true;
+
+    // No lint: not an associated function
+    let mut d = make_config();
+    d.verbose = true;
+
+    // No lint: not all fields are accessible here
+    let mut e = private::Hidden::new();
+    e.visible = 1;
+
+    // No lint: the new value depends on the binding
+    let mut f = Config::new();
+    f.verbose = f.retries > 3;
+
+    let _ = (a, b, c, d, e, f);
+}
@@ -0,0 +1,63 @@
+#![allow(unused)]
+#![warn(clippy::hash_map_entry_api_or_default)]
+
+use std::collections::{BTreeMap, HashMap};
+
+fn get_mut_else_insert<'a>(map: &mut HashMap<&'a str, u32>, btree: &mut BTreeMap<u8, i64>, k: &'a str) {
+    if let Some(v) = map.get_mut(&k) {
+        *v += 1;
+    } else {
+        map.insert(k, 1);
+    }
+
+    if let Some(v) = btree.get_mut(&0) {
+        *v |= 4;
+    } else {
+        btree.insert(0, 4);
+    }
+
+    // Don't lint, the inserted value isn't the update applied to `0`
+    if let Some(v) = map.get_mut(&k) {
+        *v += 1;
+    } else {
+        map.insert(k, 2);
+    }
+
+    // Don't lint, `0 - 1` is not `1`
+    if let Some(v) = btree.get_mut(&0) {
+        *v -= 1;
+    } else {
+        btree.insert(0, 1);
+    }
+
+    // Don't lint, different keys
+    if let Some(v) = map.get_mut(&k) {
+        *v += 1;
+    } else {
+        map.insert("other", 1);
+    }
+}
+
+fn get_then_insert<'a>(map: &mut HashMap<&'a str, u32>, k: &'a str, n: u32) {
+    let count = map.get(&k).copied().unwrap_or_default();
+    map.insert(k, count + n);
+
+    let count = map.get(&k).cloned().unwrap_or_default();
+    map.insert(k, count * 2);
+
+    // Don't lint, `count` is used afterwards
+    let count = map.get(&k).copied().unwrap_or_default();
+    map.insert(k, count + 1);
+    println!("{count}");
+
+    // Don't lint, the map is used in the new value
+    let count = map.get(&k).copied().unwrap_or_default();
+    map.insert(k, count + map.len() as u32);
+
+    // Don't lint, different maps
+    let mut other = HashMap::new();
+    let count = map.get(&k).copied().unwrap_or_default();
+    other.insert(k, count + 1);
+}
+
+fn main() {}
@@ -4,7 +4,7 @@
 //@[edition2021] edition:2021
 //@[edition2024] edition:2024
 #![warn(clippy::if_let_mutex)]
-#![allow(clippy::redundant_pattern_matching)]
+#![allow(clippy::possible_deadlock_double_lock, clippy::redundant_pattern_matching)]
 
 use std::ops::Deref;
 use std::sync::Mutex;
@@ -0,0 +1,39 @@
+//@compile-flags: --test
+#![warn(clippy::imprecise_float_equality_in_tests)]
+
+fn compute() -> f64 {
+    0.1 + 0.2
+}
+
+#[test]
+fn first_statement() {
+    assert_eq!(compute(), 0.3);
+    //~^ imprecise_float_equality_in_tests
+    assert_ne!(compute(), 0.4, "values: {}", 0.4);
+    //~^ imprecise_float_equality_in_tests
+}
+
+#[test]
+fn later_statement() {
+    let x = compute();
+    assert_eq!(x, 0.3);
+    //~^ imprecise_float_equality_in_tests
+}
+
+#[test]
+fn debug_f32() {
+    let y: f32 = 0.5;
+    debug_assert_eq!(y * 2.0, 1.0);
+    //~^ imprecise_float_equality_in_tests
+}
+
+#[test]
+fn exact() {
+    assert_eq!(compute() * 0.0, 0.0);
+    assert_ne!(compute(), f64::INFINITY);
+    assert_eq!(1, 1);
+}
+
+fn not_a_test() {
+    assert_eq!(compute(), 0.3);
+}
@@ -0,0 +1,59 @@
+#![deny(clippy::index_refutable_slice)]
+#![allow(clippy::get_first, clippy::get_unwrap)]
+
+fn lintable_examples(v: Vec<String>, slice: &[u32], array: [u8; 4]) {
+    let first = v.get(0).unwrap();
+    //~^ ERROR: these bindings can be a slice pattern to avoid `get(..).unwrap()`
+    let second = v.get(1).unwrap();
+    println!("{first} {second}");
+
+    // Unused indices are skipped with `_`
+    let a = slice.get(2).unwrap();
+    //~^ ERROR: these bindings can be a slice pattern to avoid `get(..).unwrap()`
+    let b = slice.get(0).unwrap();
+    println!("{a} {b}");
+
+    let x = array.get(1).unwrap();
+    //~^ ERROR: these bindings can be a slice pattern to avoid `get(..).unwrap()`
+    let y = array.get(0).unwrap();
+    let z = array.get(2).unwrap();
+    println!("{x} {y} {z}");
+}
+
+fn non_lintable_examples(v: Vec<String>, w: Vec<String>) {
+    // A single binding
+    let first = v.get(0).unwrap();
+    println!("{first}");
+
+    // Different slices
+    let a = v.get(0).unwrap();
+    let b = w.get(1).unwrap();
+    println!("{a} {b}");
+
+    // The same index twice
+    let a = v.get(0).unwrap();
+    let b = v.get(0).unwrap();
+    println!("{a} {b}");
+
+    // Not consecutive
+    let a = v.get(0).unwrap();
+    println!("{a}");
+    let b = v.get(1).unwrap();
+    println!("{b}");
+
+    // Above the default `max-suggested-slice-pattern-length`
+    let a = v.get(0).unwrap();
+    let b = v.get(3).unwrap();
+    println!("{a} {b}");
+
+    // Mutable bindings and type annotations
+    let mut a = v.get(0).unwrap();
+    let mut b = v.get(1).unwrap();
+    std::mem::swap(&mut a, &mut b);
+    println!("{a} {b}");
+    let a: &String = v.get(0).unwrap();
+    let b: &String = v.get(1).unwrap();
+    println!("{a} {b}");
+}
+
+fn main() {}
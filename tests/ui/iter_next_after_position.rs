@@ -0,0 +1,38 @@
+#![warn(clippy::iter_next_after_position)]
+
+fn map(v: &[i32], w: Vec<i32>, a: [i32; 3]) {
+    let _ = v.iter().position(|x| x.is_positive()).map(|i| &v[i]);
+    //~^ iter_next_after_position
+    let _ = w.iter().position(|x| x.is_negative()).map(|idx| &w[idx]);
+    //~^ iter_next_after_position
+    let _ = a.iter().position(|x| x.is_positive()).map(|i| &a[i]);
+    //~^ iter_next_after_position
+    let _ = v.iter().position(|x| *x == 3).map(|i| &v[i]);
+    //~^ iter_next_after_position
+
+    // Different collection
+    let _ = v.iter().position(|x| x.is_positive()).map(|i| &w[i]);
+    // The index is used for something else
+    let _ = v.iter().position(|x| x.is_positive()).map(|i| &v[i + 1]);
+    let _ = v.iter().position(|x| x.is_positive()).map(|i| (i, &v[i]));
+}
+
+fn let_question_mark(v: &[i32]) -> Option<&i32> {
+    let idx = v.iter().position(|x| x.is_positive())?;
+    let item = &v[idx];
+    //~^ iter_next_after_position
+    Some(item)
+}
+
+fn index_used_again(v: &[i32]) -> Option<(usize, &i32)> {
+    let idx = v.iter().position(|x| x.is_positive())?;
+    let item = &v[idx];
+    Some((idx, item))
+}
+
+fn other_collection<'a>(v: &[i32], w: &'a [i32]) -> Option<&'a i32> {
+    let idx = v.iter().position(|x| x.is_positive())?;
+    Some(&w[idx])
+}
+
+fn main() {}
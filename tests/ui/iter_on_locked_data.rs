@@ -0,0 +1,44 @@
+#![warn(clippy::iter_on_locked_data)]
+
+use std::fs::File;
+use std::io::Write;
+use std::sync::{Mutex, RwLock};
+use std::time::Duration;
+
+fn mutex(names: &Mutex<Vec<String>>, file: &mut File) {
+    for name in names.lock().unwrap().iter() {
+        //~^ iter_on_locked_data
+        writeln!(file, "{name}").unwrap();
+    }
+}
+
+fn rwlock(numbers: &RwLock<Vec<u64>>) {
+    for n in numbers.read().unwrap().iter() {
+        //~^ iter_on_locked_data
+        std::thread::sleep(Duration::from_millis(*n));
+    }
+}
+
+fn fast_body(names: &Mutex<Vec<String>>) -> usize {
+    let mut len = 0;
+    for name in names.lock().unwrap().iter() {
+        len += name.len();
+    }
+    len
+}
+
+fn cloned(names: &Mutex<Vec<String>>, file: &mut File) {
+    let names = names.lock().unwrap().clone();
+    for name in &names {
+        writeln!(file, "{name}").unwrap();
+    }
+}
+
+fn lock_in_closure(numbers: &Mutex<Vec<u64>>) {
+    // The lock is released after each call of the closure
+    for n in (0..10).map(|i| numbers.lock().unwrap()[i]) {
+        std::thread::sleep(Duration::from_millis(n));
+    }
+}
+
+fn main() {}
@@ -21,4 +21,7 @@ fn main() {
     with_span! {
         let _ = [1, 2, 3].iter().skip(0);
     }
+
+    let n = 0;
+    let _ = [1, 2, 3].iter().skip(n);
 }
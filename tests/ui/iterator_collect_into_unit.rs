@@ -0,0 +1,26 @@
+#![warn(clippy::iterator_collect_into_unit)]
+#![allow(clippy::map_collect_result_unit)]
+
+fn check(x: i32) -> Result<(), String> {
+    if x < 0 { Err(x.to_string()) } else { Ok(()) }
+}
+
+fn main() -> Result<(), String> {
+    let v = [1, 2, 3];
+    v.iter().map(|x| println!("{x}")).collect::<()>();
+    //~^ iterator_collect_into_unit
+    std::iter::repeat_n((), 3).collect::<()>();
+    //~^ iterator_collect_into_unit
+
+    let results = [Ok(()), Err(String::new())];
+    results.into_iter().collect::<Result<(), String>>()?;
+    //~^ iterator_collect_into_unit
+
+    // Left to `map_collect_result_unit`
+    v.iter().map(|&x| check(x)).collect::<Result<(), _>>()?;
+
+    // Not collected into `()`
+    let _: Vec<()> = v.iter().map(|_| ()).collect();
+    let _ = v.iter().map(|&x| check(x)).collect::<Result<Vec<()>, _>>();
+    Ok(())
+}
@@ -29,9 +29,26 @@ fn main() {
     let v1 = vec![1, 2, 3];
     let _ = v1.iter().step_by(2 / 3);
     //~^ ERROR: `Iterator::step_by(0)` will panic at runtime
+
+    // check `let` bindings and associated constants
+    let step = 0;
+    let _ = (0..1).step_by(step);
+    //~^ ERROR: `Iterator::step_by(0)` will panic at runtime
+    let _ = (0..1).step_by(Config::STEP);
+    //~^ ERROR: `Iterator::step_by(0)` will panic at runtime
+
+    // No error, the binding is mutated
+    let mut step = 0;
+    step += 1;
+    let _ = (0..1).step_by(step);
 }
 
 struct NotIterator;
 impl NotIterator {
     fn step_by(&self, _: u32) {}
 }
+
+struct Config;
+impl Config {
+    const STEP: usize = 0;
+}
@@ -0,0 +1,64 @@
+#![warn(clippy::loop_select_starvation)]
+
+use std::future::{pending, ready};
+
+fn check_state() {}
+
+async fn ready_future() {
+    loop {
+        tokio::select! {
+            _ = pending::<()>() => {},
+            _ = ready(()) => {},
+            //~^ loop_select_starvation
+        }
+    }
+}
+
+async fn async_block_without_await() {
+    loop {
+        tokio::select! {
+            _ = pending::<()>() => {},
+            _ = async { check_state() } => {},
+            //~^ loop_select_starvation
+        }
+    }
+}
+
+async fn biased_not_last() {
+    loop {
+        tokio::select! {
+            biased;
+            _ = ready(()) => {},
+            //~^ loop_select_starvation
+            _ = pending::<()>() => {},
+        }
+    }
+}
+
+async fn biased_last() {
+    loop {
+        tokio::select! {
+            biased;
+            _ = pending::<()>() => {},
+            _ = ready(()) => {},
+        }
+    }
+}
+
+async fn awaiting() {
+    loop {
+        tokio::select! {
+            _ = pending::<()>() => {},
+            _ = async { pending::<()>().await } => {},
+        }
+    }
+}
+
+async fn not_in_loop() {
+    tokio::select! {
+        _ = pending::<()>() => {},
+        _ = ready(()) => {},
+    }
+}
+
+fn main() {}
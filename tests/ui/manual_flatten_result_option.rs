@@ -0,0 +1,49 @@
+#![warn(clippy::manual_flatten_result_option)]
+
+use std::num::ParseIntError;
+
+fn filter_map_ok(input: &[&str]) -> Result<Vec<u32>, ParseIntError> {
+    let numbers: Vec<u32> = input.iter().map(|s| s.parse()).filter_map(Result::ok).collect();
+    //~^ manual_flatten_result_option
+    let numbers: Vec<u32> = input.iter().map(|s| s.parse()).filter_map(|r| r.ok()).collect();
+    //~^ manual_flatten_result_option
+    Ok(numbers)
+}
+
+fn as_ref_ok(results: &[Result<u32, ParseIntError>]) -> Result<Vec<&u32>, ParseIntError> {
+    Ok(results.iter().filter_map(|r| r.as_ref().ok()).collect())
+    //~^ manual_flatten_result_option
+}
+
+fn flatten(results: Vec<Result<u32, ParseIntError>>) -> Result<Vec<u32>, ParseIntError> {
+    Ok(results.into_iter().flatten().collect())
+    //~^ manual_flatten_result_option
+}
+
+fn ok_unwrap(results: Vec<Result<u32, ParseIntError>>) -> Result<Vec<u32>, Box<dyn std::error::Error>> {
+    let numbers = results.into_iter().collect::<Result<Vec<_>, _>>().ok().unwrap();
+    //~^ manual_flatten_result_option
+    Ok(numbers)
+}
+
+fn no_result(input: &[&str], results: Vec<Result<u32, ParseIntError>>) -> Vec<u32> {
+    let _ = results.into_iter().collect::<Result<Vec<_>, _>>().ok().unwrap();
+    input.iter().map(|s| s.parse()).filter_map(Result::ok).collect()
+}
+
+fn closure(input: &[&str]) -> Result<(), ParseIntError> {
+    // `?` would return from the closure
+    let f = || -> Vec<u32> { input.iter().map(|s| s.parse()).filter_map(Result::ok).collect() };
+    Ok(())
+}
+
+fn unconvertible_error(results: Vec<Result<u32, ParseIntError>>) -> Result<Vec<u32>, String> {
+    // `?` can't convert a `ParseIntError` into a `String`
+    Ok(results.into_iter().collect::<Result<Vec<_>, _>>().ok().unwrap())
+}
+
+fn not_the_item(input: &[&str]) -> Result<Vec<u32>, ParseIntError> {
+    Ok(input.iter().filter_map(|s| s.parse().ok()).collect())
+}
+
+fn main() {}
@@ -1,4 +1,4 @@
-#![allow(unused, dead_code)]
+#![allow(unused, dead_code, clippy::manual_range_contains)]
 #![warn(clippy::manual_is_ascii_check)]
 
 fn main() {
@@ -87,3 +87,23 @@ fn adds_type_reference() {
     let digits: Vec<&char> = ['1', 'A'].iter().take_while(|c| ('0'..='9').contains(c)).collect();
     let digits: Vec<&mut char> = ['1', 'A'].iter_mut().take_while(|c| ('0'..='9').contains(c)).collect();
 }
+
+fn comparisons(c: char, b: u8, r: &u8) {
+    let _ = c >= 'a' && c <= 'z';
+    let _ = b'A' <= b && b <= b'Z';
+    let _ = (c >= 'a' && c <= 'f') || (c >= 'A' && c <= 'F') || (c >= '0' && c <= '9');
+    let _ = (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || (c >= '0' && c <= '9');
+    let _ = (b >= b'a' && b <= b'z') || (b >= b'A' && b <= b'Z');
+    let _ = *r >= b'0' && *r <= b'9';
+    assert!(matches!(c, 'a'..='z' | 'A'..='Z' | '0'..='9'));
+    assert!(matches!(c, '0'..='9' | 'A'..='Z' | 'a'..='z'));
+    // Only the digits are linted, lowercase hex digits have no dedicated method
+    let _ = (c >= '0' && c <= '9') || (c >= 'a' && c <= 'f');
+
+    // Don't lint
+    let _ = c >= 'a' && c <= 'y';
+    let _ = c >= 'a' && b <= b'z';
+    let _ = c > 'a' && c < 'z';
+    assert!(matches!(c, 'a'..='f' | 'A'..='F'));
+    assert!(matches!(c, 'a'..='z' | '0'..='9'));
+}
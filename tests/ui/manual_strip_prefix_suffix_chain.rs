@@ -0,0 +1,60 @@
+#![warn(clippy::manual_strip_prefix_suffix_chain)]
+//@no-rustfix
+
+fn literals(s: &str) {
+    if s.starts_with("<<") && s.ends_with(">>") {
+        println!("{}", &s[2..s.len() - 2]);
+        //~^ manual_strip_prefix_suffix_chain
+    }
+
+    if s.ends_with(')') && s.starts_with('(') {
+        let inner = &s['('.len_utf8()..s.len() - ')'.len_utf8()];
+        //~^ manual_strip_prefix_suffix_chain
+        println!("{inner}");
+    }
+}
+
+fn non_literals(s: &str, open: &str, close: char) {
+    if s.starts_with(open) && s.ends_with(close) {
+        println!("{}", &s[open.len()..s.len() - close.len_utf8()]);
+        //~^ manual_strip_prefix_suffix_chain
+    }
+}
+
+fn multibyte(s: &str) {
+    // `«` and `»` are two bytes long each
+    if s.starts_with('«') && s.ends_with('»') {
+        println!("{}", &s[2..s.len() - 2]);
+        //~^ manual_strip_prefix_suffix_chain
+    }
+
+    // Only strips the first byte of the prefix
+    if s.starts_with('«') && s.ends_with('»') {
+        println!("{}", &s[1..s.len() - 2]);
+    }
+}
+
+fn not_linted(s: &str, mut t: String) {
+    // The number of chars is not the length in bytes
+    if s.starts_with("ab") && s.ends_with("cd") {
+        println!("{}", &s["ab".chars().count()..s.len() - 2]);
+    }
+
+    // Only the prefix is stripped
+    if s.starts_with("ab") && s.ends_with("cd") {
+        println!("{}", &s[2..]);
+    }
+
+    // Tests of different strings
+    if s.starts_with("ab") && t.ends_with("cd") {
+        println!("{}", &s[2..s.len() - 2]);
+    }
+
+    // The string is mutated
+    if t.starts_with("ab") && t.ends_with("cd") {
+        t.push('!');
+        println!("{}", &t[2..t.len() - 3]);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,63 @@
+#![warn(clippy::manual_while_let_iterator_enumerate)]
+#![allow(clippy::while_let_on_iterator)]
+//@no-rustfix
+
+fn consume(_: usize, _: u32) {}
+
+fn main() {
+    let v = [1, 2, 3];
+
+    let mut iter = v.iter();
+    let mut i = 0;
+    while let Some(x) = iter.next() {
+        //~^ manual_while_let_iterator_enumerate
+        consume(i, *x);
+        i += 1;
+    }
+
+    let mut iter = v.iter();
+    let mut count = 0;
+    'outer: while let Some(&x) = iter.next() {
+        //~^ manual_while_let_iterator_enumerate
+        consume(count, x);
+        count += 1;
+        if x > 1 {
+            break 'outer;
+        }
+    }
+    consume(0, iter.len() as u32);
+
+    // counter is used after the loop
+    let mut iter = v.iter();
+    let mut i = 0;
+    while let Some(x) = iter.next() {
+        consume(i, *x);
+        i += 1;
+    }
+    consume(i, 0);
+
+    // conditional increment
+    let mut iter = v.iter();
+    let mut i = 0;
+    while let Some(x) = iter.next() {
+        if *x > 1 {
+            i += 1;
+        }
+    }
+
+    // not starting from zero
+    let mut iter = v.iter();
+    let mut i = 1;
+    while let Some(x) = iter.next() {
+        consume(i, *x);
+        i += 1;
+    }
+
+    // not a `usize`
+    let mut iter = v.iter();
+    let mut i: u32 = 0;
+    while let Some(x) = iter.next() {
+        consume(0, *x + i);
+        i += 1;
+    }
+}
@@ -0,0 +1,68 @@
+#![warn(clippy::match_on_discriminant_cast)]
+
+#[derive(Clone, Copy)]
+enum Kind {
+    File,
+    Dir,
+    Link,
+}
+
+#[derive(Clone, Copy)]
+enum Code {
+    Low = -1,
+    Mid = 10,
+    High,
+}
+
+fn describe(kind: Kind) -> &'static str {
+    match kind as u8 {
+        //~^ match_on_discriminant_cast
+        0 => "file",
+        1 => "directory",
+        _ => "link",
+    }
+}
+
+fn exhaustive(kind: Kind) -> u32 {
+    match kind as u8 {
+        //~^ match_on_discriminant_cast
+        0 | 1 => 1,
+        2 => 2,
+        _ => unreachable!(),
+    }
+}
+
+fn explicit(code: Code) -> i32 {
+    match code as i8 {
+        //~^ match_on_discriminant_cast
+        -1 => 0,
+        11 => 2,
+        _ => 1,
+    }
+}
+
+fn not_linted(kind: Kind, code: Code, x: u8) {
+    // Not a discriminant
+    let _ = match kind as u8 {
+        0 => 1,
+        5 => 2,
+        _ => 3,
+    };
+    // Bindings
+    let _ = match kind as u8 {
+        0 => 1,
+        n => n,
+    };
+    // Discriminants that don't fit the type
+    let _ = match code as u8 {
+        10 => 1,
+        _ => 2,
+    };
+    // Not an enum
+    let _ = match x as u32 {
+        0 => 1,
+        _ => 2,
+    };
+}
+
+fn main() {}
@@ -220,3 +220,42 @@ mod with_ty_alias {
         let _: Foo = 1;
     }
 }
+
+mod const_features_stabilized_after_msrv {
+    // Do not lint because mutable references in const fn are stable since 1.83
+    #[clippy::msrv = "1.82"]
+    fn mut_ref(x: &mut u32) -> u32 {
+        *x
+    }
+
+    // Do not lint because floating point arithmetic in const fn is stable since 1.82
+    #[clippy::msrv = "1.81"]
+    fn float_arithmetic(x: f64) -> f64 {
+        x * 2.0
+    }
+
+    // Do not lint because function pointers in const fn are stable since 1.61
+    #[clippy::msrv = "1.60"]
+    fn fn_ptr(f: fn() -> u32) -> fn() -> u32 {
+        f
+    }
+
+    // Do not lint because `impl Trait` in const fn is stable since 1.61
+    #[clippy::msrv = "1.60"]
+    fn impl_trait(x: impl Copy) -> impl Copy {
+        x
+    }
+
+    // Do not lint because async blocks in const fn are unstable
+    #[allow(clippy::manual_async_fn)]
+    fn async_block() -> impl std::future::Future<Output = ()> {
+        async {}
+    }
+
+    async fn async_fn() {}
+
+    // Do not lint because calling a function pointer in const fn is unstable
+    fn call_fn_ptr(f: fn() -> u32) -> u32 {
+        f()
+    }
+}
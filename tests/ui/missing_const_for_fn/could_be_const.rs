@@ -212,3 +212,31 @@ mod extern_fn {
     extern "system-unwind" fn system_unwind() {}
     //~^ ERROR: this could be a `const fn`
 }
+
+mod stabilized_const_features {
+    fn mut_ref(x: &mut u32) -> u32 {
+        //~^ ERROR: this could be a `const fn`
+        *x += 1;
+        *x
+    }
+
+    fn float_arithmetic(x: f64) -> f64 {
+        //~^ ERROR: this could be a `const fn`
+        -x * 2.0
+    }
+
+    fn fn_ptr(f: fn() -> u32) -> fn() -> u32 {
+        //~^ ERROR: this could be a `const fn`
+        f
+    }
+
+    fn impl_trait(x: impl Copy) -> impl Copy {
+        //~^ ERROR: this could be a `const fn`
+        x
+    }
+
+    fn dyn_trait(x: &u32) -> &dyn std::fmt::Debug {
+        //~^ ERROR: this could be a `const fn`
+        x
+    }
+}
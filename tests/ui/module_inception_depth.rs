@@ -0,0 +1,53 @@
+#![warn(clippy::module_inception_depth)]
+#![allow(clippy::module_inception, dead_code)]
+
+mod a {
+    mod a {
+        mod a {
+            //~^ module_inception_depth
+            pub struct S;
+        }
+    }
+
+    mod b {
+        mod b {
+            pub struct S;
+        }
+    }
+}
+
+mod wrapper {
+    //~^ module_inception_depth
+    pub use std::collections::HashMap;
+}
+
+mod private_import {
+    use std::collections::HashMap;
+}
+
+mod with_items {
+    pub use std::collections::HashMap;
+
+    pub struct S;
+}
+
+pub mod exported {
+    pub use std::collections::BTreeMap;
+
+    pub mod exported {
+        pub mod exported {
+            pub struct S;
+        }
+    }
+}
+
+macro_rules! reexport {
+    () => {
+        mod from_macro {
+            pub use std::collections::HashSet;
+        }
+    };
+}
+reexport!();
+
+fn main() {}
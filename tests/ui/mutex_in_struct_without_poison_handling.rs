@@ -0,0 +1,36 @@
+#![crate_type = "lib"]
+#![warn(clippy::mutex_in_struct_without_poison_handling)]
+
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+pub struct Counter {
+    count: Mutex<u32>,
+    shared: Arc<Mutex<Vec<u32>>>,
+    lock: RwLock<u32>,
+}
+
+impl Counter {
+    pub fn increment(&self) {
+        *self.count.lock().unwrap() += 1;
+        //~^ mutex_in_struct_without_poison_handling
+        self.shared.lock().unwrap().push(1);
+        //~^ mutex_in_struct_without_poison_handling
+    }
+
+    pub fn handled(&self) {
+        *self.count.lock().unwrap_or_else(PoisonError::into_inner) += 1;
+        if let Ok(mut shared) = self.shared.lock() {
+            shared.push(1);
+        }
+    }
+
+    pub fn not_a_mutex(&self) -> u32 {
+        *self.lock.read().unwrap()
+    }
+}
+
+pub fn local_mutex() -> u32 {
+    let count = Mutex::new(0);
+    let value = *count.lock().unwrap();
+    value
+}
@@ -0,0 +1,50 @@
+#![warn(clippy::needless_range_loop)]
+//@no-rustfix
+
+fn reversed(vec: &mut [u32]) {
+    for i in (0..vec.len()).rev() {
+        //~^ needless_range_loop
+        println!("{}", vec[i]);
+    }
+
+    for i in (2..vec.len()).rev() {
+        //~^ needless_range_loop
+        vec[i] += 1;
+    }
+
+    for i in (0..vec.len()).rev() {
+        //~^ needless_range_loop
+        println!("{i}: {}", vec[i]);
+    }
+}
+
+fn stepped(vec: &mut [u32], step: usize) {
+    for i in (0..vec.len()).step_by(2) {
+        //~^ needless_range_loop
+        println!("{}", vec[i]);
+    }
+
+    for i in (1..vec.len()).step_by(step) {
+        //~^ needless_range_loop
+        vec[i] = 0;
+    }
+
+    // The step borrows the vector which is iterated mutably
+    for i in (0..vec.len()).step_by(vec.len() / 2) {
+        vec[i] = 0;
+    }
+}
+
+fn not_linted(vec: &[u32], other: &[u32]) {
+    // Two sequences are indexed
+    for i in (0..vec.len()).rev() {
+        println!("{} {}", vec[i], other[i]);
+    }
+
+    // The index is computed from the loop variable
+    for i in (0..vec.len()).rev() {
+        println!("{}", vec[vec.len() - 1 - i]);
+    }
+}
+
+fn main() {}
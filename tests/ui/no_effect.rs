@@ -195,3 +195,59 @@ fn main() {
     Cout << 142;
     -Cout;
 }
+
+#[allow(clippy::unnecessary_operation)]
+fn overloaded_operators() {
+    use std::num::Wrapping;
+    use std::ops::{Add, Deref};
+    use std::rc::Rc;
+
+    #[derive(Clone, Copy)]
+    struct Meters(u32);
+    impl Add for Meters {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            Meters(self.0 + rhs.0)
+        }
+    }
+
+    struct Logged((i32, i32));
+    impl Deref for Logged {
+        type Target = (i32, i32);
+        fn deref(&self) -> &Self::Target {
+            println!("deref");
+            &self.0
+        }
+    }
+
+    // Implemented in the standard library
+    let a = Wrapping(1u32);
+    a + a;
+    //~^ ERROR: statement with no effect
+    let rc = &Rc::new((1, 2));
+    rc.0;
+    //~^ ERROR: statement with no effect
+
+    // May have side effects
+    let m = Meters(1);
+    m + m;
+    (m + m, 1);
+    let logged = &Logged((1, 2));
+    logged.1;
+    **logged;
+}
+
+fn must_use() {
+    #[must_use]
+    struct Token(u32);
+    struct Wrapper(Token);
+    impl std::ops::Deref for Wrapper {
+        type Target = Token;
+        fn deref(&self) -> &Token {
+            &self.0
+        }
+    }
+
+    Token(1);
+    Wrapper(Token(1));
+}
@@ -0,0 +1,48 @@
+#![warn(clippy::non_exhaustive_omitted_patterns_helper)]
+
+use std::io::ErrorKind;
+use std::sync::atomic::Ordering;
+
+fn ordering(ordering: Ordering) -> u8 {
+    match ordering {
+        Ordering::Relaxed => 0,
+        Ordering::Release => 1,
+        Ordering::Acquire => 2,
+        Ordering::AcqRel => 3,
+        Ordering::SeqCst => 4,
+        _ => unreachable!(),
+        //~^ non_exhaustive_omitted_patterns_helper
+    }
+}
+
+fn error_kind(kind: &ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::NotFound => "not found",
+        other => {
+            //~^ non_exhaustive_omitted_patterns_helper
+            panic!("unexpected error kind: {other:?}")
+        },
+    }
+}
+
+fn fallback(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::NotFound => "not found",
+        _ => "other",
+    }
+}
+
+#[non_exhaustive]
+enum Local {
+    A,
+    B,
+}
+
+fn local(local: Local) -> u8 {
+    match local {
+        Local::A => 0,
+        _ => unreachable!(),
+    }
+}
+
+fn main() {}
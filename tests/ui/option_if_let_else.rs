@@ -305,3 +305,14 @@ fn issue11893() {
         panic!("Haven't thought about this condition.");
     }
 }
+
+fn branches_with_effects(opt: Option<u32>) -> Result<u32, std::io::Error> {
+    // Don't lint, the branches allocate or do IO
+    let _ = if let Some(x) = opt { Box::new(x) } else { Box::new(0) };
+    let len = if let Some(x) = opt {
+        x as usize
+    } else {
+        std::fs::read_to_string("file")?.len()
+    };
+    Ok(len as u32)
+}
@@ -0,0 +1,36 @@
+#![warn(clippy::option_map_unit_fn)]
+
+struct Counter {
+    count: usize,
+}
+
+impl Counter {
+    fn increment(&mut self) {
+        self.count += 1;
+    }
+}
+
+fn main() {
+    let mut counter = Some(Counter { count: 0 });
+
+    let _ = counter.as_mut().map(|c| c.increment());
+    //~^ option_map_unit_fn
+
+    let _ = counter.as_mut().map(Counter::increment);
+    //~^ option_map_unit_fn
+
+    counter.as_mut().map(|c| {
+        //~^ option_map_unit_fn
+        c.increment();
+        c.increment();
+    });
+
+    let _ = counter.as_mut().map(|c| {
+        //~^ option_map_unit_fn
+        c.increment();
+        c.increment();
+    });
+
+    // Not linted, the result is used
+    let _x = counter.as_mut().map(|c| c.increment());
+}
@@ -0,0 +1,39 @@
+#![warn(clippy::overflow_prone_duration_arithmetic)]
+
+use std::time::Duration;
+
+fn hours(h: u32) -> Duration {
+    Duration::from_secs((h * 3600) as u64)
+    //~^ overflow_prone_duration_arithmetic
+}
+
+fn millis(m: u16, s: u16) -> Duration {
+    Duration::from_millis(u64::from(m * s * 1000))
+    //~^ overflow_prone_duration_arithmetic
+}
+
+fn days(d: u32) -> Duration {
+    Duration::from_secs((d * 24 * 3600).into())
+    //~^ overflow_prone_duration_arithmetic
+}
+
+fn signed(h: i32) -> Duration {
+    // Widening the operands changes the result for negative values
+    Duration::from_secs((h * 3600) as u64)
+}
+
+fn not_linted(h: u32, x: u64, small: u8) {
+    // Widened before multiplying
+    let _ = Duration::from_secs(u64::from(h) * 3600);
+    let _ = Duration::from_secs(h as u64 * 3600);
+    // Already a `u64`
+    let _ = Duration::from_secs(x * 3600);
+    // The product can't overflow
+    let _ = Duration::from_secs((h % 60 * 60) as u64);
+    let _ = Duration::from_secs((small as u32 * 1000) as u64);
+    let _ = Duration::from_secs((h.min(1000) * 3600) as u64);
+    // Not a multiplication
+    let _ = Duration::from_secs((h + 3600) as u64);
+}
+
+fn main() {}
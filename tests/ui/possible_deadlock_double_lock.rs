@@ -0,0 +1,70 @@
+#![allow(clippy::readonly_write_lock)]
+
+use std::sync::{Arc, Mutex, RwLock};
+
+struct Counter {
+    count: Mutex<u32>,
+}
+
+impl Counter {
+    fn increment_twice(&self) {
+        let mut first = self.count.lock().unwrap();
+        let mut second = self.count.lock().unwrap();
+        //~^ possible_deadlock_double_lock
+        *first += 1;
+        *second += 1;
+    }
+
+    fn increment(&self) {
+        *self.count.lock().unwrap() += 1;
+        *self.count.lock().unwrap() += 1;
+    }
+}
+
+fn if_let(m: &Mutex<Vec<usize>>) {
+    if let Ok(mut v) = m.lock() {
+        let len = m.lock().unwrap().len();
+        //~^ possible_deadlock_double_lock
+        v.push(len);
+    }
+    // The guard of the `if let` is dropped here
+    m.lock().unwrap().clear();
+}
+
+fn arc(m: Arc<Mutex<u32>>) {
+    let guard = m.lock().unwrap();
+    let _other = m.lock();
+    //~^ possible_deadlock_double_lock
+    drop(guard);
+}
+
+fn rw_lock(l: &RwLock<u32>) {
+    let read = l.read().unwrap();
+    let _write = l.write().unwrap();
+    //~^ possible_deadlock_double_lock
+    println!("{}", *read);
+}
+
+fn no_lint(m: &Mutex<u32>, other: &Mutex<u32>, l: &RwLock<u32>) {
+    // The first guard is dropped
+    let guard = m.lock().unwrap();
+    drop(guard);
+    let _guard = m.lock().unwrap();
+
+    // Different mutexes
+    let _other = other.lock().unwrap();
+
+    // Two read locks
+    let a = l.read().unwrap();
+    let b = l.read().unwrap();
+    println!("{} {}", *a, *b);
+
+    // The first guard goes out of scope
+    let n = Mutex::new(1);
+    {
+        let _first = n.lock().unwrap();
+    }
+    let _second = n.lock().unwrap();
+}
+
+fn main() {}
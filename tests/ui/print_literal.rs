@@ -1,5 +1,5 @@
 #![warn(clippy::print_literal)]
-#![allow(clippy::uninlined_format_args, clippy::literal_string_with_formatting_args)]
+#![allow(clippy::uninlined_format_args, clippy::literal_string_with_formatting_args, clippy::unused_format_specs)]
 
 fn main() {
     // these should be fine
@@ -58,7 +58,7 @@ trait Foo {
 
 struct Bar;
 
-// no error, in trait impl (#425)
+// no separate error, the trait impl is changed along with the trait (#425)
 impl Foo for Bar {
     type Item = Vec<u8>;
     fn do_vec(x: &Vec<i64>) {}
@@ -0,0 +1,88 @@
+#![warn(clippy::ptr_arg)]
+#![allow(clippy::iter_count)]
+//@no-rustfix
+
+trait Sum {
+    fn sum(v: &Vec<u32>) -> u32;
+    //~^ ERROR: writing `&Vec` instead of `&[_]` involves a new object where a slice will do
+}
+
+struct Iter;
+impl Sum for Iter {
+    fn sum(v: &Vec<u32>) -> u32 {
+        v.iter().sum()
+    }
+}
+
+struct Index;
+impl Sum for Index {
+    fn sum(v: &Vec<u32>) -> u32 {
+        (0..v.len()).map(|i| v[i]).sum()
+    }
+}
+
+trait Last {
+    fn last(v: &Vec<u32>) -> Option<&u32> {
+        //~^ ERROR: writing `&Vec` instead of `&[_]` involves a new object where a slice will do
+        v.last()
+    }
+}
+
+// Uses the provided method
+impl Last for Iter {}
+
+// No error, an implementation needs a `Vec`
+trait Capacity {
+    fn capacity(v: &Vec<u32>) -> usize;
+}
+
+impl Capacity for Iter {
+    fn capacity(v: &Vec<u32>) -> usize {
+        v.len()
+    }
+}
+
+impl Capacity for Index {
+    fn capacity(v: &Vec<u32>) -> usize {
+        v.capacity()
+    }
+}
+
+fn apply(v: &Vec<u32>, f: impl Fn(&Vec<u32>) -> usize) -> usize {
+    f(v)
+}
+
+fn apply_where<F>(v: &Vec<u32>, f: F) -> usize
+where
+    F: Fn(&Vec<u32>) -> usize,
+{
+    f(v)
+}
+
+fn apply_annotated<F: Fn(&Vec<u32>) -> usize>(v: &Vec<u32>, f: F) -> usize {
+    f(v)
+}
+
+fn apply_capacity<F: Fn(&Vec<u32>) -> usize>(v: &Vec<u32>, f: F) -> usize {
+    f(v)
+}
+
+fn apply_stored<F: Fn(&Vec<u32>) -> usize>(v: &Vec<u32>, f: F) -> usize {
+    let g = f;
+    g(v)
+}
+
+fn main() {
+    let v = vec![1, 2, 3];
+    apply(&v, |v| v.len());
+    //~^ ERROR: writing `&Vec` instead of `&[_]` involves a new object where a slice will do
+    apply_where(&v, |v| v.iter().count());
+    //~^ ERROR: writing `&Vec` instead of `&[_]` involves a new object where a slice will do
+
+    // No error, the type of the parameter is annotated
+    apply_annotated(&v, |v: &Vec<u32>| v.len());
+    // No error, the closure needs a `Vec`
+    apply_capacity(&v, |v| v.capacity());
+    // No error, the bound can't be changed if the closure isn't only called
+    apply_stored(&v, |v| v.len());
+}
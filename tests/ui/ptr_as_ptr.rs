@@ -1,6 +1,7 @@
 //@aux-build:proc_macros.rs
 
 #![warn(clippy::ptr_as_ptr)]
+#![allow(clippy::unsound_from_raw_cast)]
 
 #[macro_use]
 extern crate proc_macros;
@@ -430,3 +430,36 @@ fn issue12412(foo: &Foo, bar: &Bar) -> Option<()> {
     };
     Some(())
 }
+
+struct UnitErr;
+struct OtherErr;
+
+impl From<UnitErr> for OtherErr {
+    fn from(_: UnitErr) -> Self {
+        OtherErr
+    }
+}
+
+fn let_else_ok_unit_err(res: Result<i32, ()>) -> Result<i32, ()> {
+    // lint
+    let Ok(v) = res else {
+        return Err(());
+    };
+    Ok(v)
+}
+
+fn let_else_ok_converted_err(res: Result<i32, UnitErr>) -> Result<i32, OtherErr> {
+    // lint
+    let Ok(v) = res else {
+        return Err(UnitErr.into());
+    };
+    Ok(v)
+}
+
+fn let_else_ok_other_err(res: Result<i32, String>, other: String) -> Result<i32, String> {
+    // Don't lint, `?` would return the original error instead of `other`
+    let Ok(v) = res else {
+        return Err(other);
+    };
+    Ok(v)
+}
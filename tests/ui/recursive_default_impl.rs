@@ -0,0 +1,122 @@
+#![allow(unconditional_recursion, dead_code, clippy::struct_update_syntax_resets_fields)]
+
+// reported by `unconditional_recursion`
+struct Direct {
+    a: u32,
+    b: String,
+}
+
+impl Default for Direct {
+    fn default() -> Self {
+        Direct {
+            a: 1,
+            ..Default::default()
+        }
+    }
+}
+
+struct Outer {
+    inner: Inner,
+    x: u32,
+}
+
+#[derive(Default)]
+struct Inner {
+    back: Box<Outer>,
+}
+
+impl Default for Outer {
+    fn default() -> Self {
+        Self {
+            inner: Default::default(),
+            //~^ recursive_default_impl
+            x: 1,
+        }
+    }
+}
+
+// `Option` doesn't construct its content by default
+struct List {
+    next: Option<Box<List>>,
+    value: u32,
+}
+
+impl Default for List {
+    fn default() -> Self {
+        List {
+            next: Default::default(),
+            value: 1,
+        }
+    }
+}
+
+struct Fine {
+    a: u32,
+    b: String,
+}
+
+impl Default for Fine {
+    fn default() -> Self {
+        Fine {
+            a: 1,
+            b: Default::default(),
+        }
+    }
+}
+
+struct WithNew {
+    a: u32,
+    b: String,
+}
+
+impl WithNew {
+    fn new(a: u32) -> Self {
+        Self {
+            a,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for WithNew {
+    fn default() -> Self {
+        Self::new(1)
+        //~^ recursive_default_impl
+    }
+}
+
+struct WithHelper {
+    a: u32,
+    b: String,
+}
+
+fn make_with_helper() -> WithHelper {
+    WithHelper {
+        a: 1,
+        ..Default::default()
+    }
+}
+
+impl Default for WithHelper {
+    fn default() -> Self {
+        make_with_helper()
+        //~^ recursive_default_impl
+    }
+}
+
+struct FineWithHelper {
+    a: u32,
+    b: String,
+}
+
+fn make_fine_with_helper() -> FineWithHelper {
+    FineWithHelper { a: 1, b: String::new() }
+}
+
+impl Default for FineWithHelper {
+    fn default() -> Self {
+        make_fine_with_helper()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,82 @@
+#![warn(clippy::recursive_format_impl)]
+#![allow(clippy::uninlined_format_args)]
+
+use std::fmt;
+
+struct Pretty;
+
+impl Pretty {
+    fn pretty(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Pretty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pretty())
+        //~^ recursive_format_impl
+    }
+}
+
+struct Nested;
+
+impl Nested {
+    fn describe(&self) -> String {
+        format!("<{}>", self.inner())
+    }
+
+    fn inner(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl fmt::Debug for Nested {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe())
+        //~^ recursive_format_impl
+    }
+}
+
+struct NoRecursion(u32);
+
+impl NoRecursion {
+    fn value(&self) -> String {
+        format!("{}", self.0)
+    }
+
+    fn debug(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl fmt::Display for NoRecursion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.value(), self.debug())
+    }
+}
+
+impl fmt::Debug for NoRecursion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NoRecursion({})", self.0)
+    }
+}
+
+struct Cycle;
+
+impl Cycle {
+    fn a(&self) -> String {
+        self.b()
+    }
+
+    fn b(&self) -> String {
+        self.a()
+    }
+}
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.a())
+    }
+}
+
+fn main() {}
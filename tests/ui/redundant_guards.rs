@@ -326,3 +326,38 @@ mod issue12243 {
         }
     };
 }
+
+fn option_and_result_predicates(x: Option<Option<u32>>, y: Result<u32, ()>, cond: bool) {
+    match x {
+        Some(x) if x.is_some() => {},
+        //~^ ERROR: redundant guard
+        Some(x) if x.is_none() && cond => {},
+        //~^ ERROR: redundant guard
+        _ => {},
+    }
+    match y {
+        y if y.is_err() => {},
+        //~^ ERROR: redundant guard
+        _ => {},
+    }
+}
+
+fn conjunctions(x: Option<u32>, cond: bool) {
+    match x {
+        Some(x) if x == 0 && cond => {},
+        //~^ ERROR: redundant guard
+        Some(x) if cond && x == 1 => {},
+        //~^ ERROR: redundant guard
+        // Shouldn't lint, `x` is used in the rest of the guard
+        Some(x) if x == 2 && x.is_power_of_two() => {},
+        _ => {},
+    }
+}
+
+fn conjunction_side_effects(x: Option<u32>, mut f: impl FnMut() -> bool) {
+    match x {
+        // Shouldn't lint, `f` is called even if `x` isn't `Some(3)`
+        Some(x) if f() && x == 3 => {},
+        _ => {},
+    }
+}
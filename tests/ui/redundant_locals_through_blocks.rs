@@ -0,0 +1,72 @@
+#![allow(unused, clippy::no_effect, clippy::toplevel_ref_arg)]
+#![warn(clippy::redundant_locals_through_blocks)]
+
+fn main() {}
+
+fn block() {
+    let x = 1;
+    let x = { x };
+    //~^ redundant_locals_through_blocks
+    let x = { { x } };
+    //~^ redundant_locals_through_blocks
+}
+
+fn match_arm(opt: Option<i32>) {
+    match opt {
+        Some(x) => {
+            let x = { x };
+            //~^ redundant_locals_through_blocks
+            println!("{x}");
+        },
+        None => println!("none"),
+    }
+}
+
+fn parameter(a: i32, b: Vec<i32>) {
+    let mut b = b;
+    //~^ redundant_locals_through_blocks
+    b.push(a);
+}
+
+fn closure() {
+    let _ = |v: Vec<i32>| {
+        let mut v = v;
+        //~^ redundant_locals_through_blocks
+        v.push(1);
+        v
+    };
+}
+
+fn unsafe_block(x: i32) {
+    let x = unsafe { x };
+}
+
+fn not_first_statement(a: i32, b: Vec<i32>) {
+    println!("{a}");
+    let mut b = b;
+    b.push(a);
+}
+
+fn not_a_parameter() {
+    let v = vec![1];
+    let mut v = v;
+    v.push(2);
+}
+
+fn by_ref_parameter(ref v: Vec<i32>) {
+    let mut v = v;
+    println!("{}", v.len());
+}
+
+fn consequential_drop_order(guard: std::sync::MutexGuard<'_, i32>) {
+    let mut guard = guard;
+    *guard = 1;
+}
+
+fn macros(v: Vec<i32>) {
+    macro_rules! block {
+        ($x:ident) => {{ $x }};
+    }
+
+    let v = block!(v);
+}
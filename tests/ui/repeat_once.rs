@@ -12,4 +12,7 @@ fn main() {
     let d = "hi".repeat(1);
     let e = s.repeat(1);
     let f = string.repeat(1);
+
+    let n = 1;
+    let g = "hello".repeat(n);
 }
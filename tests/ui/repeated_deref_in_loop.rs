@@ -0,0 +1,130 @@
+#![warn(clippy::repeated_deref_in_loop)]
+
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex};
+
+fn lock_in_for(values: &[u32], total: &Mutex<u32>) {
+    for value in values {
+        *total.lock().unwrap() += value;
+        //~^ repeated_deref_in_loop
+    }
+}
+
+fn lock_arc(shared: Arc<Mutex<Vec<u32>>>) {
+    let mut i = 0;
+    while i < 10 {
+        shared.lock().unwrap().push(i);
+        //~^ repeated_deref_in_loop
+        i += 1;
+    }
+}
+
+struct Cache {
+    entries: RefCell<Vec<String>>,
+    hits: u32,
+}
+
+impl Cache {
+    fn count(&mut self, names: &[&str]) -> usize {
+        let mut count = 0;
+        for name in names {
+            self.hits += 1;
+            if self.entries.borrow().iter().any(|e| e == name) {
+                //~^ repeated_deref_in_loop
+                count += 1;
+            }
+        }
+        count
+    }
+
+    fn push_all(&self, names: &[&str]) {
+        for name in names {
+            self.entries.borrow_mut().push(name.to_string());
+            //~^ repeated_deref_in_loop
+        }
+    }
+
+    fn other_use(&self, names: &[&str]) {
+        // `self` may be used to borrow the cell again
+        for name in names {
+            self.entries.borrow_mut().push(name.to_string());
+            self.log();
+        }
+    }
+
+    fn log(&self) {}
+}
+
+fn mixed(cell: &RefCell<Vec<u32>>) {
+    for i in 0..10 {
+        // Hoisting the shared borrow would make `borrow_mut` panic
+        if cell.borrow().contains(&i) {
+            continue;
+        }
+        cell.borrow_mut().push(i);
+    }
+}
+
+fn conditional(values: &[u32], total: &Mutex<u32>) {
+    for value in values {
+        if *value > 10 {
+            *total.lock().unwrap() += value;
+        }
+    }
+}
+
+fn reassigned<'a>(mut cell: &'a RefCell<u32>, other: &'a RefCell<u32>) {
+    for _ in 0..10 {
+        *cell.borrow_mut() += 1;
+        cell = other;
+    }
+}
+
+fn declared_in_loop(cells: &[RefCell<u32>]) {
+    for cell in cells {
+        *cell.borrow_mut() += 1;
+    }
+}
+
+fn passed_elsewhere(total: &Mutex<u32>) {
+    for i in 0..10 {
+        *total.lock().unwrap() += i;
+        std::thread::scope(|_| {
+            drop(total.lock());
+        });
+    }
+}
+
+fn nested(values: &[u32], total: &Mutex<u32>) {
+    for _ in 0..10 {
+        for value in values {
+            *total.lock().unwrap() += value;
+            //~^ repeated_deref_in_loop
+        }
+    }
+}
+
+fn polling(flag: &Mutex<bool>, values: &[u32]) {
+    // The flag is expected to be set by another thread in the meantime
+    let mut i = 0;
+    while i < 10 {
+        if *flag.lock().unwrap() {
+            break;
+        }
+        i += 1;
+    }
+
+    let mut done = false;
+    while !done {
+        done = *flag.lock().unwrap();
+    }
+
+    for value in values {
+        match *flag.lock().unwrap() {
+            true => return,
+            false => println!("{value}"),
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,77 @@
+//@no-rustfix
+#![warn(clippy::result_large_err_map_err_boxing)]
+#![allow(clippy::large_enum_variant, clippy::result_large_err)]
+
+use std::num::{ParseFloatError, ParseIntError};
+
+pub enum IntError {
+    Parse(ParseIntError),
+    Data([u8; 512]),
+}
+
+pub fn parse_int(s: &str) -> Result<u32, IntError> {
+    let n = s.parse::<u32>().map_err(IntError::Parse)?;
+    //~^ result_large_err_map_err_boxing
+    Ok(n)
+}
+
+pub enum BoxedError {
+    Float(ParseFloatError),
+    Data([u8; 512]),
+}
+
+pub fn parse_float(s: &str) -> Result<f32, Box<BoxedError>> {
+    let f = s.parse::<f32>().map_err(BoxedError::Float)?;
+    //~^ result_large_err_map_err_boxing
+    Ok(f)
+}
+
+pub enum ConvertedError {
+    Parse(ParseIntError),
+    Data([u8; 512]),
+}
+
+impl From<ParseIntError> for Box<ConvertedError> {
+    fn from(e: ParseIntError) -> Self {
+        Box::new(ConvertedError::Parse(e))
+    }
+}
+
+pub fn parse_converted(s: &str) -> Result<u32, Box<ConvertedError>> {
+    let n = s.parse::<u32>().map_err(ConvertedError::Parse)?;
+    //~^ result_large_err_map_err_boxing
+    Ok(n)
+}
+
+// No error, the error is small
+pub enum SmallError {
+    Parse(ParseIntError),
+    Data([u8; 16]),
+}
+
+pub fn parse_small(s: &str) -> Result<u32, SmallError> {
+    let n = s.parse::<u32>().map_err(SmallError::Parse)?;
+    Ok(n)
+}
+
+// No error, a `From` implementation can't be written for a generic type
+pub enum GenericError<T> {
+    Parse(ParseIntError),
+    Data(T),
+}
+
+pub fn parse_generic(s: &str) -> Result<u32, GenericError<[u8; 512]>> {
+    let n = s.parse::<u32>().map_err(GenericError::Parse)?;
+    Ok(n)
+}
+
+// No error, the `?` returns from the closure
+pub fn parse_in_closure(s: &str) -> Option<u32> {
+    let parse = || -> Result<u32, IntError> {
+        let n = s.parse::<u32>().map_err(IntError::Parse)?;
+        Ok(n)
+    };
+    parse().ok()
+}
+
+fn main() {}
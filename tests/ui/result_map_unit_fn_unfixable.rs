@@ -27,8 +27,8 @@ fn result_map_unit_fn() {
     x.field.map(|value| if value > 0 { do_nothing(value); do_nothing(value) });
     //~^ ERROR: called `map(f)` on an `Result` value where `f` is a closure that returns t
 
-    // Suggestion for the let block should be `{ ... }` as it's too difficult to build a
-    // proper suggestion for these cases
+    // Blocks with several statements are used as the body of the `if let`, while other
+    // expressions get a `{ ... }` placeholder
     x.field.map(|value| {
     //~^ ERROR: called `map(f)` on an `Result` value where `f` is a closure that returns t
         do_nothing(value);
@@ -141,4 +141,10 @@ fn issue13795(value: Issue13795) {
     let Issue13795 { value, .. } = value;
 }
 
+fn shadow_ref_shorthand(value: i32, other: Issue13795) {
+    let Issue13795 { ref value } = other;
+    let _ = value;
+    //~^^ shadow_unrelated
+}
+
 fn main() {}
@@ -6,7 +6,8 @@
     clippy::match_single_binding,
     clippy::single_match,
     clippy::uninlined_format_args,
-    clippy::needless_lifetimes
+    clippy::needless_lifetimes,
+    clippy::possible_deadlock_double_lock
 )]
 
 use std::num::ParseIntError;
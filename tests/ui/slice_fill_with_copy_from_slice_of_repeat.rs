@@ -0,0 +1,71 @@
+#![warn(clippy::slice_fill_with_copy_from_slice_of_repeat)]
+#![allow(clippy::useless_vec)]
+
+const ZERO: u8 = 0;
+
+fn default_value() -> u8 {
+    42
+}
+
+fn side_effect() -> u8 {
+    println!("called");
+    1
+}
+
+fn main() {
+    let mut buf = [1u8; 16];
+    let slice: &mut [u8] = &mut [1, 2, 3];
+    let mut v = vec![1u8; 8];
+
+    slice.copy_from_slice(&vec![0; slice.len()]);
+    //~^ slice_fill_with_copy_from_slice_of_repeat
+    slice.clone_from_slice(&vec![7; slice.len()]);
+    //~^ slice_fill_with_copy_from_slice_of_repeat
+    buf.clone_from_slice(&[3; 16]);
+    //~^ slice_fill_with_copy_from_slice_of_repeat
+    slice.copy_from_slice(&[0; 3]);
+    //~^ slice_fill_with_copy_from_slice_of_repeat
+
+    for x in buf.iter_mut() {
+        //~^ slice_fill_with_copy_from_slice_of_repeat
+        *x = 0;
+    }
+    for x in &mut v {
+        //~^ slice_fill_with_copy_from_slice_of_repeat
+        *x = ZERO;
+    }
+    for x in slice.iter_mut() {
+        //~^ slice_fill_with_copy_from_slice_of_repeat
+        *x = default_value();
+    }
+
+    // The length differs
+    buf.copy_from_slice(&vec![0; 16]);
+    v.copy_from_slice(&vec![0; buf.len()]);
+
+    // Not a constant
+    for x in buf.iter_mut() {
+        *x = side_effect();
+    }
+    let y = 5;
+    for x in v.iter_mut() {
+        *x = y;
+    }
+    for (i, x) in buf.iter_mut().enumerate() {
+        *x = i as u8;
+    }
+    for x in v.iter_mut() {
+        *x += 1;
+    }
+}
+
+#[clippy::msrv = "1.49"]
+fn msrv_1_49(buf: &mut [u8; 4]) {
+    buf.copy_from_slice(&[0; 4]);
+}
+
+#[clippy::msrv = "1.50"]
+fn msrv_1_50(buf: &mut [u8; 4]) {
+    buf.copy_from_slice(&[0; 4]);
+    //~^ slice_fill_with_copy_from_slice_of_repeat
+}
@@ -0,0 +1,29 @@
+#![warn(clippy::slow_string_comparisons)]
+#![allow(clippy::cmp_owned)]
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let path = Path::new("config.toml");
+    let _ = path.to_string_lossy() == "config.toml";
+    let _ = path.display().to_string() != "config.toml";
+    let _ = "config.toml" == path.to_string_lossy().into_owned();
+
+    let path_buf = PathBuf::from("config.toml");
+    let _ = path_buf.to_string_lossy() == "config.toml";
+
+    let os_str = OsStr::new("foo");
+    let _ = os_str.to_string_lossy() == "foo";
+    let os_str_ref = &os_str;
+    let _ = os_str_ref.to_string_lossy() == "foo";
+
+    let os_string = OsString::from("foo");
+    let _ = os_string.to_string_lossy().to_string() == "foo";
+
+    // Don't lint
+    let _ = path.to_string_lossy() == "\u{FFFD}";
+    let name = "config.toml";
+    let _ = path.to_string_lossy() == name;
+    let _ = "foo".to_string() == "foo";
+}
@@ -0,0 +1,35 @@
+#![warn(clippy::split_then_index)]
+
+#[clippy::msrv = "1.51"]
+fn _msrv_1_51() {
+    let s = "key:value";
+    let _ = s.splitn(2, ':').collect::<Vec<_>>()[1];
+    //~^ split_then_index
+}
+
+fn main() {
+    let s = "key:value:rest";
+
+    let _ = s.split(':').collect::<Vec<_>>()[0];
+    //~^ split_then_index
+    let _ = s.split(':').collect::<Vec<&str>>()[1];
+    //~^ split_then_index
+    let _ = s.rsplit(':').collect::<Vec<_>>()[2];
+    //~^ split_then_index
+    let _ = "a b".split_whitespace().collect::<Vec<_>>()[0];
+    //~^ split_then_index
+    let _ = s.splitn(2, ':').collect::<Vec<_>>()[1];
+    //~^ split_then_index
+    let _ = s.rsplitn(2, ':').collect::<Vec<_>>()[1];
+    //~^ split_then_index
+    let _ = s.splitn(3, ':').collect::<Vec<_>>()[2];
+    //~^ split_then_index
+
+    // No lint: the index is not a constant
+    let i = 1;
+    let _ = s.split(':').collect::<Vec<_>>()[i];
+
+    // No lint: the `Vec` is used more than once
+    let parts: Vec<_> = s.split(':').collect();
+    let _ = (parts[0], parts[1]);
+}
@@ -0,0 +1,10 @@
+#![warn(clippy::std_instead_of_core, clippy::std_instead_of_alloc)]
+#![allow(unused_imports)]
+
+// `alloc` is not in the extern prelude without `extern crate alloc;`
+use std::vec::Vec;
+
+use std::hash::Hasher;
+//~^ std_instead_of_core
+
+fn main() {}
@@ -51,6 +51,9 @@ fn std_instead_of_core() {
     // lint items re-exported from private modules, `core::iter::traits::iterator::Iterator`
     use std::iter::Iterator;
     //~^ ERROR: used import from `std` instead of `core`
+
+    // `c_char` is exported from `core::ffi`, but there is no `core::os`
+    use std::os::raw::c_char;
 }
 
 #[warn(clippy::std_instead_of_alloc)]
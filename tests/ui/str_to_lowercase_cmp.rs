@@ -0,0 +1,30 @@
+#![warn(clippy::str_to_lowercase_cmp)]
+
+const HEADER: &str = "Content-Type";
+
+fn main() {
+    let a = String::from("Hello");
+    let b = "hello";
+
+    let _ = a.to_lowercase() == b.to_lowercase();
+    //~^ str_to_lowercase_cmp
+    let _ = b.to_lowercase() == a.to_lowercase();
+    //~^ str_to_lowercase_cmp
+    let _ = a.to_uppercase().contains(&b.to_uppercase());
+    //~^ str_to_lowercase_cmp
+
+    let _ = HEADER.to_lowercase() == "content-type".to_lowercase();
+    //~^ str_to_lowercase_cmp
+    let _ = "ABC".to_uppercase() != HEADER.to_uppercase();
+    //~^ str_to_lowercase_cmp
+    let _ = HEADER.to_lowercase().contains("type".to_lowercase().as_str());
+    //~^ str_to_lowercase_cmp
+
+    // different conversions, not linted
+    let _ = a.to_lowercase() == b.to_uppercase();
+    // only one side is converted
+    let _ = a.to_lowercase() == b;
+    // non-ASCII literals
+    let _ = "ÄBC".to_lowercase() == "äbc".to_lowercase();
+    let _ = a.to_uppercase().contains(&"Ä".to_uppercase());
+}
@@ -0,0 +1,90 @@
+#![warn(clippy::struct_update_syntax_resets_fields)]
+
+mod table {
+    #[derive(Default)]
+    pub struct Table {
+        pub rows: Vec<String>,
+        pub name: String,
+        index: Vec<usize>,
+        len: usize,
+    }
+
+    impl Table {
+        pub fn with_name(name: String) -> Self {
+            // Methods of the struct are trusted with its fields
+            Self {
+                name,
+                ..Self::default()
+            }
+        }
+    }
+
+    pub fn with_rows(rows: Vec<String>) -> Table {
+        Table {
+            rows,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_rows_and_index(rows: Vec<String>, index: Vec<usize>) -> Table {
+        // The private fields left are `Copy`
+        Table {
+            rows,
+            index,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Settings {
+    pub verbose: bool,
+    cache: Vec<u8>,
+    name: Option<String>,
+}
+
+fn settings() -> Settings {
+    Settings {
+        verbose: true,
+        ..Settings::default()
+    }
+}
+
+struct Marker;
+
+#[derive(Default)]
+pub struct Lazy {
+    pub data: Vec<u8>,
+    // Caches which are filled on demand and zero-sized fields can start out empty
+    len: std::cell::OnceCell<usize>,
+    hash: std::sync::OnceLock<u64>,
+    marker: Marker,
+}
+
+impl Default for Marker {
+    fn default() -> Self {
+        Marker
+    }
+}
+
+fn lazy() -> Lazy {
+    Lazy {
+        data: vec![1],
+        ..Default::default()
+    }
+}
+
+fn public_fields() {
+    #[derive(Default)]
+    struct Point {
+        pub x: Vec<i32>,
+        pub y: Vec<i32>,
+    }
+
+    let _ = Point {
+        x: vec![1],
+        ..Default::default()
+    };
+}
+
+fn main() {}
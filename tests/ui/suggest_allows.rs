@@ -0,0 +1,32 @@
+//@rustc-env:CLIPPY_SUGGEST_ALLOWS=1
+#![warn(clippy::eq_op, clippy::too_many_arguments)]
+
+/// Docs
+fn too_many(one: u8, two: u8, three: u8, four: u8, five: u8, six: u8, seven: u8, eight: u8) {}
+//~^ too_many_arguments
+
+fn main() {
+    let _ = 1 == 1;
+    //~^ eq_op
+    let _ = match 1 {
+        0 => true,
+        _ => 2 == 2,
+        //~^ eq_op
+    };
+}
+
+#[rustfmt::skip]
+fn same_line() {
+    let _ = 3; let _ = 4 == 4;
+    //~^ eq_op
+}
+
+struct S {
+    x: u8,
+}
+
+// `--fix` applies the fix of the lint instead
+fn fixable(x: u8) -> S {
+    S { x: x }
+    //~^ redundant_field_names
+}
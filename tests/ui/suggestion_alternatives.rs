@@ -0,0 +1,10 @@
+//@no-rustfix: overlapping suggestions
+//@rustc-env:CLIPPY_ARGS=--suggestion-alternatives
+#![warn(clippy::octal_escapes, clippy::zero_prefixed_literal)]
+
+fn main() {
+    let _ = "\033[0m";
+    //~^ octal_escapes
+    let _ = 0123;
+    //~^ zero_prefixed_literal
+}
@@ -0,0 +1,62 @@
+#![feature(let_chains)]
+#![warn(clippy::suspicious_let_chain_formatting)]
+#![allow(
+    clippy::no_effect,
+    clippy::unnecessary_operation,
+    clippy::never_loop,
+    clippy::needless_if,
+    unused
+)]
+
+#[rustfmt::skip]
+fn main() {
+    let a = Some(1);
+    let b = Some(2);
+
+    if let Some(x) = a &&
+    let Some(y) = b {
+        //~^ suspicious_let_chain_formatting
+    }
+
+    if let Some(x) = a
+    && x > 0 {
+        //~^ suspicious_let_chain_formatting
+    }
+
+    while let Some(x) = a &&
+    x > 0 {
+        //~^ suspicious_let_chain_formatting
+        break;
+    }
+
+    let ok = a.is_some()
+        && b.is_some();
+        && a.is_none();
+        //~^ suspicious_let_chain_formatting
+
+    // Don't lint
+    if let Some(x) = a
+        && let Some(y) = b
+    {
+    }
+
+    if let Some(x) = a &&
+        let Some(y) = b {
+    }
+
+    if let Some(x) = a && let Some(y) = b {}
+
+    // Indented with tabs, can't be compared with the `if`
+    if let Some(x) = a
+	&& let Some(y) = b
+    {
+    }
+
+    // Not a let-chain
+    if a.is_some() &&
+    b.is_some() {
+    }
+
+    let ok = a.is_some();
+    & &ok;
+}
@@ -0,0 +1,44 @@
+#![warn(clippy::timestamp_millis_truncation)]
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn manual_conversion(d: Duration) {
+    let _ = d.as_secs() * 1000 + d.subsec_millis() as u64;
+    //~^ timestamp_millis_truncation
+    let _ = u64::from(d.subsec_micros()) + 1_000_000 * d.as_secs();
+    //~^ timestamp_millis_truncation
+    let _ = d.as_secs() as u128 * 1_000_000_000 + d.subsec_nanos() as u128;
+    //~^ timestamp_millis_truncation
+
+    // Mismatched units or durations
+    let _ = d.as_secs() * 1000 + d.subsec_micros() as u64;
+    let other = Duration::from_secs(1);
+    let _ = d.as_secs() * 1000 + other.subsec_millis() as u64;
+}
+
+fn truncation() {
+    let _ = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i32;
+    //~^ timestamp_millis_truncation
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time went backwards");
+    let _ = since_epoch.as_millis() as u32;
+    //~^ timestamp_millis_truncation
+    let _ = UNIX_EPOCH.elapsed().unwrap_or_default().as_micros() as u32;
+    //~^ timestamp_millis_truncation
+
+    // Wide enough
+    let _ = since_epoch.as_secs() as i64;
+    let _ = since_epoch.as_millis() as u64;
+    // Not a timestamp
+    let _ = Duration::from_millis(1500).as_secs() as i32;
+}
+
+#[allow(clippy::timestamp_millis_truncation)]
+#[warn(clippy::cast_possible_truncation)]
+fn allowed() {
+    let _ = UNIX_EPOCH.elapsed().unwrap().as_secs() as u32;
+    //~^ cast_possible_truncation
+}
+
+fn main() {}
@@ -5,7 +5,8 @@
     clippy::partialeq_ne_impl,
     clippy::default_constructed_unit_structs,
     clippy::only_used_in_recursion,
-    clippy::needless_lifetimes
+    clippy::needless_lifetimes,
+    clippy::recursive_default_impl
 )]
 
 enum Foo {
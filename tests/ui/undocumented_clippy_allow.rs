@@ -0,0 +1,46 @@
+//@clippy-conf: undocumented-clippy-allow-exempt = ["pedantic", "clippy::too_many_lines"]
+#![warn(clippy::undocumented_clippy_allow)]
+
+#[allow(clippy::needless_return)]
+//~^ undocumented_clippy_allow
+fn no_reason() -> u32 {
+    return 1;
+}
+
+#[allow(clippy::needless_return, reason = "testing")]
+fn with_reason() -> u32 {
+    return 1;
+}
+
+#[allow(clippy::needless_return)] // testing
+fn with_comment() -> u32 {
+    return 1;
+}
+
+#[allow(dead_code)]
+fn not_clippy() {}
+
+#[allow(clippy::too_many_lines)]
+fn exempt_lint() {}
+
+#[allow(clippy::must_use_candidate)]
+fn exempt_group_lint() -> u32 {
+    1
+}
+
+#[allow(clippy::pedantic)]
+fn exempt_group() {}
+
+#[allow(clippy::too_many_lines, clippy::needless_return)]
+//~^ undocumented_clippy_allow
+fn partially_exempt() -> u32 {
+    return 1;
+}
+
+#[expect(clippy::needless_return)]
+//~^ undocumented_clippy_allow
+fn expect_no_reason() -> u32 {
+    return 1;
+}
+
+fn main() {}
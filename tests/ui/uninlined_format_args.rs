@@ -6,7 +6,8 @@
     clippy::eq_op,
     clippy::format_in_format_args,
     clippy::print_literal,
-    clippy::unnecessary_literal_unwrap
+    clippy::unnecessary_literal_unwrap,
+    clippy::unused_format_specs
 )]
 
 extern crate proc_macros;
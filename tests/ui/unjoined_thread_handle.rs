@@ -0,0 +1,57 @@
+#![warn(clippy::unjoined_thread_handle)]
+#![allow(clippy::redundant_closure)]
+
+use std::thread;
+
+fn work() {}
+
+fn dropped_immediately() {
+    let _ = thread::spawn(work);
+    //~^ unjoined_thread_handle
+    thread::spawn(|| work());
+    //~^ unjoined_thread_handle
+    let _: thread::JoinHandle<()> = std::thread::spawn(work);
+    //~^ unjoined_thread_handle
+}
+
+fn dropped_at_end_of_scope() {
+    let handle = thread::spawn(work);
+    //~^ unjoined_thread_handle
+    println!("{:?}", handle.thread().id());
+    let _finished = handle.is_finished();
+    let _borrowed = &handle;
+}
+
+fn joined() {
+    let handle = thread::spawn(work);
+    handle.join().unwrap();
+}
+
+fn moved() -> thread::JoinHandle<()> {
+    let handle = thread::spawn(work);
+    let handles = vec![handle];
+    let handle = thread::spawn(work);
+    drop(handles);
+    handle
+}
+
+fn joined_in_closure() {
+    let handle = thread::spawn(work);
+    let join = move || handle.join();
+    join().unwrap();
+}
+
+fn returned() -> thread::JoinHandle<()> {
+    thread::spawn(work)
+}
+
+fn scoped() {
+    thread::scope(|s| {
+        s.spawn(work);
+    });
+}
+
+fn main() {
+    let _ = thread::spawn(work);
+    thread::spawn(work);
+}
@@ -0,0 +1,53 @@
+#![warn(clippy::unnecessary_box_pin)]
+#![allow(clippy::let_and_return, clippy::redundant_async_block)]
+
+async fn number() -> u32 {
+    42
+}
+
+async fn awaited() -> u32 {
+    let a = Box::pin(async { number().await + 1 }).await;
+    //~^ unnecessary_box_pin
+    let b = Box::pin(async move { a * 2 }).await;
+    //~^ unnecessary_box_pin
+    b
+}
+
+fn recursive(n: u32) -> std::pin::Pin<Box<dyn std::future::Future<Output = u32>>> {
+    Box::pin(async move { if n == 0 { 0 } else { recursive(n - 1).await + 1 } })
+}
+
+async fn recursive_async(n: u32) -> u32 {
+    // The box is required for the recursion
+    if n == 0 {
+        0
+    } else {
+        Box::pin(async move { recursive_async(n - 1).await }).await + 1
+    }
+}
+
+async fn large() {
+    // Boxing large futures is recommended by `large_futures`
+    Box::pin(async {
+        let buf = [0u8; 16 * 1024];
+        number().await;
+        std::hint::black_box(buf);
+    })
+    .await;
+}
+
+async fn not_awaited() -> u32 {
+    let fut = Box::pin(async { 1 });
+    fut.await
+}
+
+async fn mutual_a(n: u32) -> u32 {
+    // The box is required for the recursion through `mutual_b`
+    Box::pin(async move { mutual_b(n).await }).await
+}
+
+async fn mutual_b(n: u32) -> u32 {
+    if n == 0 { 0 } else { mutual_a(n - 1).await + 1 }
+}
+
+fn main() {}
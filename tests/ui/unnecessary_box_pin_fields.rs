@@ -0,0 +1,56 @@
+#![crate_type = "lib"]
+#![warn(clippy::unnecessary_box_pin)]
+#![allow(dead_code)]
+
+use std::future::Future;
+use std::pin::Pin;
+
+async fn work() -> u32 {
+    1
+}
+
+async fn other_work() -> u32 {
+    2
+}
+
+struct Job {
+    task: Pin<Box<dyn Future<Output = u32>>>,
+    //~^ unnecessary_box_pin
+}
+
+fn job() -> Job {
+    Job { task: Box::pin(work()) }
+}
+
+struct Jobs {
+    // Holds the futures of different functions
+    task: Pin<Box<dyn Future<Output = u32>>>,
+}
+
+fn jobs(first: bool) -> Jobs {
+    if first {
+        Jobs { task: Box::pin(work()) }
+    } else {
+        Jobs {
+            task: Box::pin(other_work()),
+        }
+    }
+}
+
+struct Replaced {
+    task: Pin<Box<dyn Future<Output = u32>>>,
+}
+
+fn replaced() -> Replaced {
+    let mut replaced = Replaced { task: Box::pin(work()) };
+    replaced.task = Box::pin(async { 3 });
+    replaced
+}
+
+pub struct Exported {
+    task: Pin<Box<dyn Future<Output = u32>>>,
+}
+
+pub fn exported() -> Exported {
+    Exported { task: Box::pin(work()) }
+}
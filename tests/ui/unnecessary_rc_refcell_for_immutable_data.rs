@@ -0,0 +1,41 @@
+#![warn(clippy::unnecessary_rc_refcell_for_immutable_data)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, RwLock};
+
+struct Names {
+    list: Rc<RefCell<Vec<String>>>,
+    //~^ unnecessary_rc_refcell_for_immutable_data
+    counter: Rc<RefCell<u32>>,
+}
+
+struct Shared {
+    config: Arc<RwLock<String>>,
+    //~^ unnecessary_rc_refcell_for_immutable_data
+    state: Arc<RwLock<u64>>,
+}
+
+fn read_names(names: &Names) -> usize {
+    *names.counter.borrow_mut() += 1;
+    names.list.borrow().len()
+}
+
+fn read_shared(shared: &Shared) -> usize {
+    *shared.state.write().unwrap() += 1;
+    shared.config.read().unwrap().len()
+}
+
+fn main() {
+    let local: Rc<RefCell<i64>> = Rc::new(RefCell::new(1));
+    //~^ unnecessary_rc_refcell_for_immutable_data
+    let _ = *local.borrow();
+
+    // No lint: mutated through `RefCell::replace`
+    let flag = Rc::new(RefCell::new(false));
+    flag.replace(true);
+
+    // No lint: mutated through a path to `RefCell::borrow_mut`
+    let text = Rc::new(RefCell::new('a'));
+    *RefCell::borrow_mut(&text) = 'b';
+}
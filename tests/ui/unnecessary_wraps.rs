@@ -144,6 +144,16 @@ fn issue_6640_4() -> Result<(), ()> {
     if true { Ok(()) } else { Err(()) }
 }
 
+// should be linted, but the uses have to be updated manually
+fn used_as_value(x: i32) -> Option<i32> {
+    //~^ ERROR: this function's return value is unnecessarily wrapped by `Option`
+    Some(x)
+}
+
+fn uses_as_value() -> Vec<i32> {
+    [1, 2].into_iter().filter_map(used_as_value).collect()
+}
+
 fn main() {
     // method calls are not linted
     func1(true, true);
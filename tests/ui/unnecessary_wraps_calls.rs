@@ -0,0 +1,35 @@
+#![warn(clippy::unnecessary_wraps)]
+
+fn add_one(x: i32) -> Option<i32> {
+    //~^ unnecessary_wraps
+    Some(x + 1)
+}
+
+fn double(x: i32) -> Result<i32, String> {
+    //~^ unnecessary_wraps
+    Ok(x * 2)
+}
+
+fn option_caller(n: i32) -> Option<i32> {
+    if n < 0 {
+        return None;
+    }
+    let a = add_one(n)?;
+    let b = add_one(a).unwrap();
+    let c = add_one(b).expect("cannot fail");
+    add_one(c)
+}
+
+fn result_caller() -> Result<i32, String> {
+    let x = double(2)?;
+    if x > 3 {
+        return Err(String::from("too large"));
+    }
+    double(x)
+}
+
+fn main() {
+    add_one(0);
+    let _ = option_caller(1);
+    let _ = result_caller();
+}
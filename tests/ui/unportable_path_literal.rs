@@ -0,0 +1,38 @@
+#![warn(clippy::unportable_path_literal)]
+
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn separators() {
+    let _ = Path::new("data\\input.txt");
+    //~^ unportable_path_literal
+    let _ = PathBuf::from("data\\input.txt");
+    //~^ unportable_path_literal
+    let _ = File::open("config\\settings.toml");
+    //~^ unportable_path_literal
+    let _ = fs::read_to_string(r"logs\today.log");
+    //~^ unportable_path_literal
+    let _ = Path::new("data").join("nested\\file.txt");
+    //~^ unportable_path_literal
+}
+
+fn absolute() {
+    let _ = Path::new("C:\\Windows\\System32");
+    //~^ unportable_path_literal
+    let _ = File::open("d:/data/input.txt");
+    //~^ unportable_path_literal
+    let _ = fs::read_dir(r"\\server\share");
+    //~^ unportable_path_literal
+}
+
+fn portable() {
+    let _ = Path::new("data/input.txt");
+    let _ = File::open("/etc/hosts");
+    let _ = fs::read("relative.txt");
+    // Not a path
+    let _ = String::from("data\\input.txt");
+    let _ = Command::new("cmd").arg("C:\\Windows");
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+#![warn(clippy::unsound_from_raw_cast)]
+
+use std::mem::ManuallyDrop;
+
+fn vec_cast(v: Vec<u16>) {
+    let mut v = ManuallyDrop::new(v);
+    let _ = unsafe { Vec::from_raw_parts(v.as_mut_ptr() as *mut u32, v.len(), v.capacity()) };
+    //~^ unsound_from_raw_cast
+
+    let ptr = v.as_mut_ptr();
+    let _ = unsafe { Vec::from_raw_parts(ptr.cast::<u8>(), v.len(), v.capacity()) };
+    //~^ unsound_from_raw_cast
+
+    // same size and alignment
+    let _ = unsafe { Vec::from_raw_parts(v.as_mut_ptr() as *mut i16, v.len(), v.capacity()) };
+    // no cast
+    let _ = unsafe { Vec::from_raw_parts(v.as_mut_ptr(), v.len(), v.capacity()) };
+}
+
+fn box_cast(a: Box<u64>, b: Box<[u8; 8]>, c: Box<u64>) {
+    let _ = unsafe { Box::from_raw(Box::into_raw(a) as *mut [u32; 4]) };
+    //~^ unsound_from_raw_cast
+
+    // same size, but a different alignment
+    let _ = unsafe { Box::from_raw(Box::into_raw(b) as *mut u64) };
+    //~^ unsound_from_raw_cast
+
+    // same size and alignment
+    let _ = unsafe { Box::from_raw(Box::into_raw(c) as *mut i64) };
+}
+
+fn unknown_source(p: *mut u8, len: usize) {
+    // the pointer doesn't come from a `Vec`
+    let _ = unsafe { Vec::from_raw_parts(p as *mut u32, len, len) };
+}
+
+fn vec_cast_scaled(v: Vec<[u8; 4]>, w: Vec<[u8; 3]>) {
+    let mut v = ManuallyDrop::new(v);
+    // the elements are split, the length and capacity are scaled accordingly
+    let _ = unsafe { Vec::from_raw_parts(v.as_mut_ptr() as *mut u8, v.len() * 4, v.capacity() * 4) };
+
+    let mut w = ManuallyDrop::new(w);
+    let _ = unsafe { Vec::from_raw_parts(w.as_mut_ptr() as *mut [u8; 2], w.len(), w.capacity()) };
+    //~^ unsound_from_raw_cast
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+#![warn(clippy::unused_format_specs)]
+
+const ZERO: usize = 0;
+
+fn main() {
+    let x = 5;
+    println!("{:.2}", x);
+    println!("{:>5.2}", x);
+    println!("{:.2?}", x);
+    println!("{:#.3x}", 255u8);
+    println!("{:ZERO$}", x);
+    println!("{0:.1$} {1}", x, 2);
+    println!("{:1$}", x, ZERO);
+    println!("{:.*}", 2, x);
+
+    // Don't lint
+    println!("{:.2}", 1.0);
+    println!("{:.2e}", 1234);
+    println!("{:1$}", x, 3);
+    println!("{:.2}", "abc");
+}
@@ -0,0 +1,102 @@
+#![warn(clippy::unused_pub_crate)]
+
+mod only_inside {
+    pub(crate) fn helper() -> u32 {
+        //~^ unused_pub_crate
+        1
+    }
+
+    pub(crate) const LIMIT: u32 = 10;
+    //~^ unused_pub_crate
+
+    pub(crate) trait Shape {
+        //~^ unused_pub_crate
+        fn area(&self) -> u32;
+    }
+
+    pub(crate) struct Square(u32);
+    //~^ unused_pub_crate
+
+    impl Shape for Square {
+        fn area(&self) -> u32 {
+            self.0 * self.0
+        }
+    }
+
+    pub(crate) mod nested {
+        //~^ unused_pub_crate
+        pub(crate) fn double(x: u32) -> u32 {
+            x * 2
+        }
+    }
+
+    mod child {
+        pub fn call() -> u32 {
+            super::helper()
+        }
+    }
+
+    #[allow(clippy::unused_pub_crate)]
+    pub(crate) fn allowed() {}
+
+    pub fn run() -> u32 {
+        nested::double(helper() + LIMIT + Square(2).area() + child::call())
+    }
+}
+
+mod used_elsewhere {
+    pub(crate) fn helper() {}
+
+    pub(crate) struct Config {
+        pub(crate) verbose: bool,
+    }
+
+    impl Config {
+        pub(crate) fn new() -> Self {
+            Self { verbose: false }
+        }
+
+        pub(crate) fn validate(&self) -> bool {
+            //~^ unused_pub_crate
+            true
+        }
+
+        pub(crate) fn is_verbose(&self) -> bool {
+            self.validate() && self.verbose
+        }
+    }
+
+    pub(crate) struct Token;
+    pub(crate) fn token() -> Token {
+        Token
+    }
+}
+
+mod in_signature {
+    pub(crate) struct Id(pub u32);
+
+    pub fn make_id() -> Id {
+        Id(0)
+    }
+}
+
+mod reexported {
+    mod inner {
+        pub(crate) fn reexported() {}
+    }
+
+    pub(crate) use self::inner::reexported;
+}
+
+pub(crate) fn at_crate_root() {}
+
+fn main() {
+    only_inside::run();
+    used_elsewhere::helper();
+    let config = used_elsewhere::Config::new();
+    let _ = config.is_verbose();
+    let _token = used_elsewhere::token();
+    let _ = in_signature::make_id();
+    reexported::reexported();
+    at_crate_root();
+}
@@ -0,0 +1,51 @@
+use std::io;
+
+#[derive(Debug)]
+struct MyError;
+
+impl From<io::Error> for MyError {
+    fn from(_: io::Error) -> Self {
+        Self
+    }
+}
+
+fn read() -> Result<u32, io::Error> {
+    Ok(0)
+}
+
+fn question_mark() -> Result<u32, MyError> {
+    let a = read().map_err(MyError::from)?;
+    //~^ useless_conversion
+    let b = read().map_err(Into::<MyError>::into)?;
+    //~^ useless_conversion
+
+    // No lint: not a `From` or `Into` conversion
+    let c = read().map_err(|_| MyError)?;
+    Ok(a + b + c)
+}
+
+fn question_mark_closure() {
+    let _ = || -> Result<u32, MyError> {
+        let v = read().map_err(MyError::from)?;
+        //~^ useless_conversion
+        Ok(v)
+    };
+}
+
+fn from_chains(x: u8, y: u16) {
+    let _ = i64::from(i32::from(x));
+    //~^ useless_conversion
+    let _ = u64::from(u32::from(y));
+    //~^ useless_conversion
+    let _ = i64::from(x as i32);
+    //~^ useless_conversion
+    let _ = f64::from(u32::from(y));
+    //~^ useless_conversion
+
+    // No lint: the inner cast truncates
+    let _ = i64::from(y as u8);
+    // No lint: the inner cast is not lossless
+    let _ = i64::from(-1i32 as u32);
+}
+
+fn main() {}
@@ -0,0 +1,118 @@
+#![warn(clippy::vec_of_boxed_trait_objects_iteration_dyn_dispatch)]
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+struct Square(f64);
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+struct Circle(f64);
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        3.0 * self.0 * self.0
+    }
+}
+
+trait Single {
+    fn get(&self) -> u32;
+}
+impl Single for u32 {
+    fn get(&self) -> u32 {
+        *self
+    }
+}
+
+trait Blanket {
+    fn value(&self) -> u32;
+}
+impl<T: Copy + Into<u32>> Blanket for T {
+    fn value(&self) -> u32 {
+        (*self).into()
+    }
+}
+
+fn nested(scenes: &[Vec<Box<dyn Shape>>]) -> f64 {
+    let mut total = 0.0;
+    for scene in scenes {
+        for shape in scene {
+            total += shape.area();
+            //~^ vec_of_boxed_trait_objects_iteration_dyn_dispatch
+        }
+    }
+    total
+}
+
+#[inline]
+fn inlined(shapes: &Vec<Box<dyn Shape>>) -> f64 {
+    let mut total = 0.0;
+    for shape in shapes.iter() {
+        total += shape.area();
+        //~^ vec_of_boxed_trait_objects_iteration_dyn_dispatch
+    }
+    total
+}
+
+fn single_implementor(values: &[Box<dyn Single>], n: usize) -> u32 {
+    let mut total = 0;
+    for _ in 0..n {
+        for value in values {
+            total += value.get();
+            //~^ vec_of_boxed_trait_objects_iteration_dyn_dispatch
+        }
+    }
+    total
+}
+
+fn not_hot(shapes: &[Box<dyn Shape>]) -> f64 {
+    let mut total = 0.0;
+    for shape in shapes {
+        total += shape.area();
+    }
+    total
+}
+
+fn blanket_impl(values: &[Box<dyn Blanket>], n: usize) -> u32 {
+    let mut total = 0;
+    for _ in 0..n {
+        for value in values {
+            total += value.value();
+        }
+    }
+    total
+}
+
+fn foreign_trait(values: &[Box<dyn std::error::Error>], n: usize) -> usize {
+    let mut total = 0;
+    for _ in 0..n {
+        for value in values {
+            total += usize::from(value.source().is_some());
+        }
+    }
+    total
+}
+
+pub trait Exported {
+    fn size(&self) -> usize;
+}
+impl Exported for usize {
+    fn size(&self) -> usize {
+        *self
+    }
+}
+
+fn exported_trait(values: &[Box<dyn Exported>], n: usize) -> usize {
+    let mut total = 0;
+    for _ in 0..n {
+        for value in values {
+            total += value.size();
+        }
+    }
+    total
+}
+
+fn main() {}
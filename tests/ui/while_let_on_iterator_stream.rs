@@ -0,0 +1,70 @@
+#![warn(clippy::while_let_on_iterator)]
+
+use futures::stream::{self, Stream, StreamExt};
+use std::pin::pin;
+
+fn numbers() -> impl Stream<Item = u32> {
+    stream::iter([1, 2, 3])
+}
+
+async fn recreated() {
+    while let Some(x) = numbers().next().await {
+        //~^ while_let_on_iterator
+        println!("{x}");
+    }
+
+    let s = stream::iter(vec![1, 2, 3]);
+    'outer: while let Some(x) = s.clone().next().await {
+        //~^ while_let_on_iterator
+        if x == 2 {
+            break 'outer;
+        }
+    }
+}
+
+async fn same_stream() {
+    let mut s = numbers();
+    while let Some(x) = s.next().await {
+        println!("{x}");
+    }
+
+    let mut s = numbers();
+    while let Some(x) = s.by_ref().next().await {
+        println!("{x}");
+    }
+
+    let mut s = pin!(stream::iter([1, 2, 3]));
+    while let Some(x) = s.as_mut().next().await {
+        println!("{x}");
+    }
+}
+
+async fn recreated_from_state() {
+    let mut start = 0;
+    while let Some(x) = stream::iter(start..10).next().await {
+        start = x + 1;
+    }
+}
+
+async fn used_by_loop() {
+    let s = stream::iter(vec![1, 2, 3]);
+    while let Some(x) = s.clone().next().await {
+        println!("{x} {:?}", s.size_hint());
+    }
+}
+
+struct Channel;
+
+impl Channel {
+    fn stream(&self) -> impl Stream<Item = u32> + '_ {
+        stream::iter([1, 2, 3])
+    }
+}
+
+async fn shared_handle(rx: Channel) {
+    while let Some(x) = rx.stream().next().await {
+        println!("{x}");
+    }
+}
+
+fn main() {}
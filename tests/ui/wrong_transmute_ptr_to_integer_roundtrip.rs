@@ -0,0 +1,72 @@
+#![warn(clippy::wrong_transmute_ptr_to_integer_roundtrip)]
+
+fn roundtrip(p: *const u8, q: *mut u32) {
+    let _ = p as usize as *const u8;
+    //~^ wrong_transmute_ptr_to_integer_roundtrip
+    let _ = p as usize as *const u16;
+    //~^ wrong_transmute_ptr_to_integer_roundtrip
+    let _ = q as usize as *const u32;
+    //~^ wrong_transmute_ptr_to_integer_roundtrip
+}
+
+fn modified(p: *const u8, buf: &[u8]) {
+    let _ = (p as usize + 8) as *const u8;
+    //~^ wrong_transmute_ptr_to_integer_roundtrip
+    let _ = (p as usize & !7) as *const u64;
+    //~^ wrong_transmute_ptr_to_integer_roundtrip
+    let _ = (buf.as_ptr() as usize).wrapping_add(1) as *const u8;
+    //~^ wrong_transmute_ptr_to_integer_roundtrip
+}
+
+fn through_bindings(p: *mut u8) {
+    let addr = p as usize;
+    let _ = addr as *mut u8;
+    //~^ wrong_transmute_ptr_to_integer_roundtrip
+    let aligned = (addr + 7) & !7;
+    let _ = aligned as *mut u8;
+    //~^ wrong_transmute_ptr_to_integer_roundtrip
+}
+
+fn in_unsafe_block(addr: usize) -> u8 {
+    // Not in an unsafe block
+    let _ = addr as *const u8;
+
+    unsafe { *(addr as *const u8) }
+    //~^ wrong_transmute_ptr_to_integer_roundtrip
+}
+
+fn in_unsafe_block_mut(addr: usize) {
+    unsafe {
+        *(addr as *mut u32) = 0;
+        //~^ wrong_transmute_ptr_to_integer_roundtrip
+    }
+}
+
+fn no_lint(p: *const u8, bits: u64) {
+    unsafe {
+        // Constant addresses
+        let _ = 0x1000 as *const u8;
+        const BASE: usize = 0x2000;
+        let _ = BASE as *mut u32;
+        // Not a `usize`
+        let _ = bits as *const u8;
+    }
+
+    // The address isn't derived from the pointer by offsetting it
+    let _ = (p as usize * 2) as *const u8;
+}
+
+#[clippy::msrv = "1.83"]
+fn msrv_1_83(p: *const u8) {
+    let _ = (p as usize + 8) as *const u8;
+    let _ = p as usize as *const u8;
+    //~^ wrong_transmute_ptr_to_integer_roundtrip
+}
+
+#[clippy::msrv = "1.84"]
+fn msrv_1_84(p: *const u8) {
+    let _ = (p as usize + 8) as *const u8;
+    //~^ wrong_transmute_ptr_to_integer_roundtrip
+}
+
+fn main() {}
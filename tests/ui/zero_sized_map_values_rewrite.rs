@@ -0,0 +1,49 @@
+#![warn(clippy::zero_sized_map_values)]
+#![allow(unused_imports)]
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+fn rewrite(words: &[&str]) -> usize {
+    let mut seen: HashMap<&str, ()> = HashMap::new();
+    //~^ zero_sized_map_values
+    for word in words {
+        if !seen.contains_key(word) {
+            seen.insert(word, ());
+        }
+    }
+    seen.remove("the");
+    for word in seen.keys() {
+        println!("{word}");
+    }
+    seen.len()
+}
+
+fn rewrite_btree() -> bool {
+    let mut ids: BTreeMap<u32, ()> = BTreeMap::new();
+    //~^ zero_sized_map_values
+    ids.insert(1, ());
+    ids.is_empty()
+}
+
+fn too_complex() {
+    let mut seen: HashMap<&str, ()> = HashMap::new();
+    //~^ zero_sized_map_values
+    //~| zero_sized_map_values
+    if seen.insert("a", ()).is_some() {
+        seen.clear();
+    }
+    let _ = seen.get("a");
+}
+
+mod set_not_imported {
+    use std::collections::HashMap;
+
+    pub fn rewrite() -> bool {
+        let mut seen: HashMap<u8, ()> = HashMap::new();
+        //~^ zero_sized_map_values
+        seen.insert(1, ());
+        seen.is_empty()
+    }
+}
+
+fn main() {}